@@ -1,4 +1,3 @@
-use tonic_build;
 use std::fs;
 use std::process::Command;
 use std::env;
@@ -50,7 +49,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Perform debug-specific checks
         log("cargo:rerun-if-changed=proto/arsmedicatech/fhir_sync.proto");
 
-        if !fs::metadata("proto/arsmedicatech/fhir_sync.proto").is_ok() {
+        if fs::metadata("proto/arsmedicatech/fhir_sync.proto").is_err() {
             panic!("File not found: proto/arsmedicatech/fhir_sync.proto");
         } else {
             log("Found proto file, proceeding with build.");
@@ -73,9 +72,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         log("protoc succeeded, proceeding to compile with tonic_build");
     }
 
-    // Force set protoc path
-    let protoc_path = "/usr/bin/protoc"; // or `which protoc` output
-    env::set_var("PROTOC", protoc_path);
+    // Default to the system protoc unless PROTOC points elsewhere
+    let protoc_path = env::var("PROTOC").unwrap_or_else(|_| "/usr/bin/protoc".to_string());
+    env::set_var("PROTOC", &protoc_path);
 
     log(&format!("Using protoc at: {}", protoc_path));
 
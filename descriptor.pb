@@ -0,0 +1,35 @@
+
+
+arsmedicatech/fhir_sync.protoarsmedicatech.fhir_sync.v1google/protobuf/any.protogoogle/protobuf/timestamp.proto"
+Patient
+id (	RidF
+
+identifier (2&.arsmedicatech.fhir_sync.v1.IdentifierR
+identifier9
+name (2%.arsmedicatech.fhir_sync.v1.HumanNameRname
+gender (	Rgender0
+birth (2.google.protobuf.TimestampRbirth":
+
+Identifier
+system (	Rsystem
+value (	Rvalue"9
+	HumanName
+family (	Rfamily
+given (	Rgiven"
+
+PatientRef
+id (	Rid"4
+
+PatientAck
+id (	Rid
+status (	Rstatus"=
+	ChangeSet0
+resource (2.google.protobuf.AnyRresource"
+Ack
+message (	Rmessage2
+FhirSync\
+UpsertPatient#.arsmedicatech.fhir_sync.v1.Patient&.arsmedicatech.fhir_sync.v1.PatientAckY
+
+GetPatient&.arsmedicatech.fhir_sync.v1.PatientRef#.arsmedicatech.fhir_sync.v1.Patient[
+StreamChanges%.arsmedicatech.fhir_sync.v1.ChangeSet.arsmedicatech.fhir_sync.v1.Ack(0BS
+com.arsmedicatech.sync.v1PZ4github.com/arsmedicatech/fhir-sync/proto;fhir_syncpbbproto3
\ No newline at end of file
@@ -1,7 +1,7 @@
 use chrono::NaiveDate;
 //use prost::Message;
 
-use crate::domain::patient::{DomainAddress, DomainPatient};
+use crate::domain::patient::DomainPatient;
 
 // Generated modules ---------------------------------------------
 // (path depends on how you configured `tonic_build`)
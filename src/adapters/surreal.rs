@@ -1,32 +1,34 @@
-use prost::Message as _; // Bring the trait into scope for method resolution
+#[cfg(test)]
+mod tests {
+    use prost::Message as _; // Bring the trait into scope for method resolution
 
-use crate::proto::google::fhir::proto::r5::core::Patient as ProtoPatient;
-use crate::domain::patient::{AddressKind, AddressUse, DomainAddress, DomainPatient};
+    use crate::proto::google::fhir::proto::r5::core::Patient as ProtoPatient;
+    use crate::domain::patient::{AddressKind, AddressUse, DomainAddress, DomainPatient};
 
-fn example() {
-    let domain_obj = DomainPatient {
-        demographic_no: "DEM-123".into(),
-        first_name: Some("Jane".into()),
-        last_name:  Some("Doe".into()),
-        date_of_birth: Some("1990-02-20".into()),
-        addresses: vec![DomainAddress {
-            line: Some("123 Main St".into()),
-            city: Some("Vancouver".into()),
-            province: Some("BC".into()),
-            postal: Some("V5K0A1".into()),
-            use_: AddressUse::Home,
-            kind: AddressKind::Postal,
-        }],
-        patient_status: None,
-        merged_to: None,
-        sex: Some("female".into()),
-        phone: Some("+1-604-123-4567".into()),
-        email: Some("jane.doe@example.com".into()),
-        hin: None,
-    };
+    #[test]
+    fn domain_patient_encodes_to_proto() {
+        let domain_obj = DomainPatient {
+            demographic_no: "DEM-123".into(),
+            first_name: Some("Jane".into()),
+            last_name:  Some("Doe".into()),
+            date_of_birth: Some("1990-02-20".into()),
+            addresses: vec![DomainAddress {
+                line: Some("123 Main St".into()),
+                city: Some("Vancouver".into()),
+                province: Some("BC".into()),
+                postal: Some("V5K0A1".into()),
+                use_: AddressUse::Home,
+                kind: AddressKind::Postal,
+            }],
+            sex: Some("female".into()),
+            phone: Some("+1-604-123-4567".into()),
+            email: Some("jane.doe@example.com".into()),
+            ..Default::default()
+        };
 
-    let proto_msg: ProtoPatient = domain_obj.into();
+        let proto_msg: ProtoPatient = domain_obj.into();
 
-    // ready to send over gRPC
-    let _bytes = proto_msg.encode_to_vec();
+        // ready to send over gRPC
+        assert!(!proto_msg.encode_to_vec().is_empty());
+    }
 }
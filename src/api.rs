@@ -5,8 +5,6 @@ use tonic::{transport::Server as TonicServer};
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 
-use tracing_subscriber::{fmt, EnvFilter};
-
 use crate::service::fhir_sync::MyFhirSyncService as ServiceImpl;
 
 use crate::proto::fhir_sync::fhir_sync_server::FhirSyncServer;
@@ -22,7 +20,7 @@ pub async fn run_grpc_server(
         .set_serving::<FhirSyncServer<ServiceImpl>>()
         .await;
 
-    let fhir_service = FhirSyncServer::new(ServiceImpl::default());
+    let fhir_service = FhirSyncServer::new(ServiceImpl);
 
     async fn health_check() -> &'static str {
         "ok"
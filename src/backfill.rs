@@ -10,26 +10,46 @@
 //! idempotent and safe to re-run (spec acceptance: running twice changes
 //! nothing).
 
-use std::collections::HashMap;
-
 use anyhow::{Context, Result};
 use mysql_async::{prelude::*, Conn, Row, Value};
-use tokio::sync::mpsc::Sender;
 use tracing::{info, warn};
 
 use crate::checkpoint::{self, Checkpoint};
 use crate::config::{Config, DatabaseConfig};
+use crate::domain::claim::BillingSource;
 use crate::domain::resource::DomainResource;
 use crate::event::{Op, Source as EventSource, SyncEvent};
+use crate::mapping::admission::row_to_domain_episode_of_care;
 use crate::mapping::appointment::row_to_domain_appointment;
-use crate::mapping::care_team::row_to_domain_care_team;
+use crate::mapping::billing::{claim_resources, load_claim_details, row_to_domain_claim};
+use crate::mapping::care_team::{load_care_team_roles, row_to_domain_care_team};
 use crate::mapping::casemgmt_note::row_to_casemgmt_note_resources;
+use crate::mapping::column_override::column_override_values;
+use crate::mapping::client_image::load_patient_photo;
+use crate::mapping::clinic::row_to_clinic_organization;
+use crate::mapping::connection_url;
 use crate::mapping::consultation_response::row_to_domain_diagnostic_report;
 use crate::mapping::demographic::{row_to_domain_patient, row_to_merged_patient, ColumnMap};
+use crate::mapping::demographic_contact::{load_patient_contacts, row_to_domain_related_person};
 use crate::mapping::dxresearch::row_to_domain_condition;
-use crate::mapping::provider::row_to_domain_practitioner;
+use crate::mapping::eform::{
+    load_eform_values, load_questionnaire_items, row_to_domain_questionnaire, row_to_domain_questionnaire_response,
+};
+use crate::mapping::message::{load_message_links, row_to_domain_communication};
+use crate::mapping::professional_specialist::row_to_specialist_resources;
+use crate::mapping::program::row_to_program_organization;
+use crate::mapping::provider::{
+    load_practitioner_role_links, row_to_domain_practitioner, row_to_domain_practitioner_role,
+};
+use crate::mapping::referral_doctor::resolve_family_doctor;
+use crate::mapping::schedule::{load_provider_day, scheduledate_provider_day};
+use crate::mapping::site::{resolve_site_id, row_to_site_location};
+use crate::mapping::waiting_list::{
+    load_waiting_list_details, row_to_domain_waiting_list_entry, waiting_list_resources,
+};
 use crate::metrics::SharedMetrics;
-use crate::sources::mariadb_binlog::{self, resolve_column_map_for_table};
+use crate::queue::EventSender;
+use crate::sources::mariadb_binlog::{self, resolve_column_maps};
 use crate::sources::{RowChange, RowOp, SourcePosition};
 
 const BATCH_SIZE: u64 = 500;
@@ -39,21 +59,44 @@ const BATCH_SIZE: u64 = 500;
 /// scanned last so their targets have already been sent to the sink.
 type Mapper = fn(&RowChange, &ColumnMap, &Config) -> Vec<DomainResource>;
 const CONSULTATION_RESPONSE_TABLE: &str = "consultationResponse";
-const BACKFILL_STEPS: &[(&str, &str, Mapper)] = &[
-    ("provider", "provider_no", practitioner_mapper),
-    ("demographic", "demographic_no", patient_mapper),
-    ("demographic_merged", "id", merged_patient_mapper),
-    ("appointment", "appointment_no", appointment_mapper),
-    ("dxresearch", "dxresearch_no", dxresearch_mapper),
-    ("casemgmt_note", "note_id", casemgmt_note_mapper),
-    (CONSULTATION_RESPONSE_TABLE, "responseId", consultation_response_mapper),
+const DEMOGRAPHIC_CONTACT_TABLE: &str = "DemographicContact";
+const EFORM_TABLE: &str = "eform";
+const EFORM_DATA_TABLE: &str = "eform_data";
+const MESSAGE_TABLE: &str = "messagetbl";
+const BILLING_TABLE: &str = "billing";
+const BILLING_ON_CHEADER1_TABLE: &str = "billing_on_cheader1";
+const WAITING_LIST_TABLE: &str = "waitingList";
+const SCHEDULEDATE_TABLE: &str = "scheduledate";
+const SITE_TABLE: &str = "site";
+/// Tables without a `Mapper` need live queries and are mapped in `map_row`.
+const BACKFILL_STEPS: &[(&str, &str, Option<Mapper>)] = &[
+    ("clinic", "clinic_no", Some(clinic_mapper)),
+    (SITE_TABLE, "site_id", None),
+    ("provider", "provider_no", Some(practitioner_mapper)),
+    ("professionalSpecialists", "specId", Some(specialist_mapper)),
+    ("program", "id", Some(program_mapper)),
+    (EFORM_TABLE, "fid", None),
+    ("demographic", "demographic_no", Some(patient_mapper)),
+    ("demographic_merged", "id", Some(merged_patient_mapper)),
+    ("admission", "am_id", Some(admission_mapper)),
+    (DEMOGRAPHIC_CONTACT_TABLE, "id", None),
+    ("appointment", "appointment_no", Some(appointment_mapper)),
+    (WAITING_LIST_TABLE, "id", None),
+    (SCHEDULEDATE_TABLE, "id", None),
+    ("dxresearch", "dxresearch_no", Some(dxresearch_mapper)),
+    ("casemgmt_note", "note_id", Some(casemgmt_note_mapper)),
+    (CONSULTATION_RESPONSE_TABLE, "responseId", None),
+    (EFORM_DATA_TABLE, "fdid", None),
+    (MESSAGE_TABLE, "messageid", None),
+    (BILLING_TABLE, "billing_no", None),
+    (BILLING_ON_CHEADER1_TABLE, "id", None),
 ];
 
 /// Runs one dependency-ordered backfill pass, sending every row through `tx`
 /// as an `Upsert` `SyncEvent`. Returns the total number of resources sent.
 pub async fn run(
     cfg: &Config,
-    tx: &Sender<SyncEvent>,
+    tx: &EventSender,
     metrics: &SharedMetrics,
 ) -> Result<usize> {
     let db = &cfg.database;
@@ -71,18 +114,10 @@ pub async fn run(
         },
     )?;
 
-    let mut column_maps = HashMap::new();
-    for (table, _, _) in BACKFILL_STEPS {
-        column_maps.insert(
-            table.to_string(),
-            resolve_column_map_for_table(db, table).await?,
-        );
-    }
+    let tables: Vec<&str> = BACKFILL_STEPS.iter().map(|(table, _, _)| *table).collect();
+    let column_maps = resolve_column_maps(db, &cfg.oscar, &tables).await?;
 
-    let url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        db.user, db.password, db.host, db.port, db.schema
-    );
+    let url = connection_url(db);
     let pool = mysql_async::Pool::new(url.as_str());
     let mut conn = pool
         .get_conn()
@@ -92,8 +127,11 @@ pub async fn run(
     let mut total = 0usize;
 
     for (table, order_col, mapper) in BACKFILL_STEPS {
-        let columns = column_maps.get(*table).expect("resolved column map");
-        total += scan_table(&mut conn, db, cfg, *table, *order_col, columns, tx, metrics, *mapper)
+        // Switched off, or missing from this install.
+        let Some(columns) = column_maps.get(*table) else {
+            continue;
+        };
+        total += scan_table(&mut conn, db, cfg, table, order_col, columns, tx, metrics, *mapper)
             .await?;
     }
 
@@ -107,7 +145,7 @@ pub async fn run(
 fn patient_mapper(change: &RowChange, columns: &ColumnMap, cfg: &Config) -> Vec<DomainResource> {
     let mut out: Vec<DomainResource> = row_to_domain_patient(change, columns)
         .into_iter()
-        .map(DomainResource::Patient)
+        .map(|patient| DomainResource::Patient(Box::new(patient)))
         .collect();
     if cfg.oscar.care_team_enabled {
         if let Some(ct) = row_to_domain_care_team(change, columns, &cfg.oscar) {
@@ -120,11 +158,56 @@ fn patient_mapper(change: &RowChange, columns: &ColumnMap, cfg: &Config) -> Vec<
 fn practitioner_mapper(
     change: &RowChange,
     columns: &ColumnMap,
-    _cfg: &Config,
+    cfg: &Config,
 ) -> Vec<DomainResource> {
-    row_to_domain_practitioner(change, columns)
+    let mut out: Vec<DomainResource> = row_to_domain_practitioner(change, columns)
         .into_iter()
         .map(DomainResource::Practitioner)
+        .collect();
+    if cfg.oscar.practitioner_role_enabled {
+        out.extend(row_to_domain_practitioner_role(change, columns).map(DomainResource::PractitionerRole));
+    }
+    out
+}
+
+fn specialist_mapper(
+    change: &RowChange,
+    columns: &ColumnMap,
+    _cfg: &Config,
+) -> Vec<DomainResource> {
+    row_to_specialist_resources(change, columns)
+}
+
+fn clinic_mapper(
+    change: &RowChange,
+    columns: &ColumnMap,
+    _cfg: &Config,
+) -> Vec<DomainResource> {
+    row_to_clinic_organization(change, columns)
+        .into_iter()
+        .map(DomainResource::Organization)
+        .collect()
+}
+
+fn program_mapper(
+    change: &RowChange,
+    columns: &ColumnMap,
+    _cfg: &Config,
+) -> Vec<DomainResource> {
+    row_to_program_organization(change, columns)
+        .into_iter()
+        .map(DomainResource::Organization)
+        .collect()
+}
+
+fn admission_mapper(
+    change: &RowChange,
+    columns: &ColumnMap,
+    _cfg: &Config,
+) -> Vec<DomainResource> {
+    row_to_domain_episode_of_care(change, columns)
+        .into_iter()
+        .map(DomainResource::EpisodeOfCare)
         .collect()
 }
 
@@ -135,7 +218,7 @@ fn merged_patient_mapper(
 ) -> Vec<DomainResource> {
     row_to_merged_patient(change, columns)
         .into_iter()
-        .map(DomainResource::Patient)
+        .map(|patient| DomainResource::Patient(Box::new(patient)))
         .collect()
 }
 
@@ -170,13 +253,231 @@ fn casemgmt_note_mapper(
     row_to_casemgmt_note_resources(change, columns, None)
 }
 
-fn consultation_response_mapper(
-    _change: &RowChange,
-    _columns: &ColumnMap,
-    _cfg: &Config,
+/// Maps one scanned row. Tables whose mapping needs live queries are handled
+/// here; everything else goes through the step's synchronous `mapper`, with
+/// Patients then enriched with their `Patient.contact` list and photo.
+async fn map_row(
+    db: &DatabaseConfig,
+    cfg: &Config,
+    table: &str,
+    change: &RowChange,
+    columns: &ColumnMap,
+    mapper: Option<Mapper>,
 ) -> Vec<DomainResource> {
-    // Backfill uses the async `scan_consultation_response_table` path instead.
-    Vec::new()
+    match table {
+        CONSULTATION_RESPONSE_TABLE => match row_to_domain_diagnostic_report(change, columns, db).await {
+            Ok(Some(report)) => vec![DomainResource::DiagnosticReport(report)],
+            Ok(None) => Vec::new(),
+            Err(e) => {
+                warn!("backfill: failed to map consultationResponse row: {e:?}");
+                Vec::new()
+            }
+        },
+        DEMOGRAPHIC_CONTACT_TABLE => {
+            if !cfg.oscar.related_person_enabled {
+                return Vec::new();
+            }
+            match row_to_domain_related_person(change, columns, db).await {
+                Ok(Some(related)) => vec![DomainResource::RelatedPerson(related)],
+                Ok(None) => Vec::new(),
+                Err(e) => {
+                    warn!("backfill: failed to map DemographicContact row: {e:?}");
+                    Vec::new()
+                }
+            }
+        }
+        SITE_TABLE => match row_to_site_location(change, columns, db).await {
+            Ok(location) => location.into_iter().map(DomainResource::Location).collect(),
+            Err(e) => {
+                warn!("backfill: failed to map site row: {e:?}");
+                Vec::new()
+            }
+        },
+        EFORM_TABLE => {
+            if !cfg.oscar.eform_enabled {
+                return Vec::new();
+            }
+            let Some(mut questionnaire) = row_to_domain_questionnaire(change, columns)
+                .filter(|q| !cfg.oscar.eform_questionnaire_map.contains_key(&q.fid))
+            else {
+                return Vec::new();
+            };
+            if let Err(e) = load_questionnaire_items(db, &mut questionnaire).await {
+                warn!("backfill: failed to load eform fields for fid={}: {e:?}", questionnaire.fid);
+            }
+            vec![DomainResource::Questionnaire(questionnaire)]
+        }
+        EFORM_DATA_TABLE => {
+            if !cfg.oscar.eform_enabled {
+                return Vec::new();
+            }
+            let Some(mut response) = row_to_domain_questionnaire_response(change, columns) else {
+                return Vec::new();
+            };
+            if let Err(e) = load_eform_values(db, &mut response).await {
+                warn!("backfill: failed to load eform_values for fdid={}: {e:?}", response.fdid);
+            }
+            vec![DomainResource::QuestionnaireResponse(response)]
+        }
+        MESSAGE_TABLE => {
+            if !cfg.oscar.communication_enabled {
+                return Vec::new();
+            }
+            let Some(mut communication) = row_to_domain_communication(change, columns) else {
+                return Vec::new();
+            };
+            if let Err(e) = load_message_links(db, &mut communication).await {
+                warn!("backfill: failed to load links for messageid={}: {e:?}", communication.message_id);
+            }
+            if !communication.is_patient_linked() && cfg.oscar.exclude_unlinked_messages {
+                return Vec::new();
+            }
+            vec![DomainResource::Communication(communication)]
+        }
+        BILLING_TABLE | BILLING_ON_CHEADER1_TABLE => {
+            if !cfg.oscar.billing_enabled {
+                return Vec::new();
+            }
+            let source = if table == BILLING_TABLE { BillingSource::Bc } else { BillingSource::On };
+            let Some(mut claim) = row_to_domain_claim(change, columns, source) else {
+                return Vec::new();
+            };
+            if let Err(e) = load_claim_details(db, &mut claim).await {
+                warn!("backfill: failed to load {table} {} details: {e:?}", claim.claim_id);
+            }
+            claim_resources(claim, &cfg.oscar)
+        }
+        WAITING_LIST_TABLE => {
+            if !cfg.oscar.waiting_list_enabled {
+                return Vec::new();
+            }
+            let Some(mut entry) = row_to_domain_waiting_list_entry(change, columns) else {
+                return Vec::new();
+            };
+            if let Err(e) = load_waiting_list_details(db, &mut entry).await {
+                warn!("backfill: failed to load waiting list details for id={}: {e:?}", entry.entry_id);
+            }
+            waiting_list_resources(entry, &cfg.oscar)
+        }
+        SCHEDULEDATE_TABLE => {
+            // Withdrawn days have nothing to publish on a snapshot; a day's
+            // active row (if any) expands it.
+            let Some(day) = scheduledate_provider_day(change, columns).filter(|d| !d.withdrawn) else {
+                return Vec::new();
+            };
+            if !cfg.oscar.schedule_enabled {
+                return Vec::new();
+            }
+            match load_provider_day(db, &cfg.oscar, &day).await {
+                Ok(resources) => resources,
+                Err(e) => {
+                    warn!(
+                        "backfill: failed to expand schedule for provider_no={} on {}: {e:?}",
+                        day.provider_no, day.date
+                    );
+                    Vec::new()
+                }
+            }
+        }
+        _ => {
+            let Some(mapper) = mapper else {
+                warn!("backfill: no mapping for {table} rows");
+                return Vec::new();
+            };
+            let mut resources = mapper(change, columns, cfg);
+            for resource in &mut resources {
+                let DomainResource::Appointment(appointment) = resource else {
+                    continue;
+                };
+                let Some(location) = appointment.location.clone() else {
+                    continue;
+                };
+                match resolve_site_id(db, &location).await {
+                    Ok(site_id) => appointment.site_id = site_id,
+                    Err(e) => warn!(
+                        "backfill: failed to resolve site for appointment_no={}: {e:?}",
+                        appointment.appointment_no
+                    ),
+                }
+            }
+            // Unresolved family doctors are synced ahead of the Patient that
+            // references them.
+            let mut doctors = Vec::new();
+            for resource in &mut resources {
+                let DomainResource::Patient(patient) = resource else {
+                    continue;
+                };
+                match resolve_family_doctor(db, patient).await {
+                    Ok(doctor) => doctors.extend(doctor.map(DomainResource::ReferralDoctor)),
+                    Err(e) => warn!(
+                        "backfill: failed to resolve family doctor for demographic_no={}: {e:?}",
+                        patient.demographic_no
+                    ),
+                }
+            }
+            if !doctors.is_empty() {
+                doctors.append(&mut resources);
+                resources = doctors;
+            }
+            for resource in &mut resources {
+                let DomainResource::PractitionerRole(role) = resource else {
+                    continue;
+                };
+                if let Err(e) = load_practitioner_role_links(db, role).await {
+                    warn!(
+                        "backfill: failed to load role links for provider_no={}: {e:?}",
+                        role.provider_no
+                    );
+                }
+            }
+            for resource in &mut resources {
+                let DomainResource::CareTeam(care_team) = resource else {
+                    continue;
+                };
+                if let Err(e) = load_care_team_roles(db, care_team).await {
+                    warn!(
+                        "backfill: failed to load care-team roles for demographic_no={}: {e:?}",
+                        care_team.demographic_no
+                    );
+                }
+            }
+            if cfg.oscar.related_person_enabled {
+                for resource in &mut resources {
+                    let DomainResource::Patient(patient) = resource else {
+                        continue;
+                    };
+                    if patient.merged_to.is_some() {
+                        continue;
+                    }
+                    match load_patient_contacts(db, &patient.demographic_no).await {
+                        Ok(contacts) => patient.contacts = contacts,
+                        Err(e) => warn!(
+                            "backfill: failed to load contacts for demographic_no={}: {e:?}",
+                            patient.demographic_no
+                        ),
+                    }
+                }
+            }
+            if cfg.oscar.patient_photo_enabled {
+                for resource in &mut resources {
+                    let DomainResource::Patient(patient) = resource else {
+                        continue;
+                    };
+                    if patient.merged_to.is_some() {
+                        continue;
+                    }
+                    match load_patient_photo(db, &cfg.oscar, &patient.demographic_no).await {
+                        Ok(photo) => patient.photo = photo,
+                        Err(e) => warn!(
+                            "backfill: failed to load photo for demographic_no={}: {e:?}",
+                            patient.demographic_no
+                        ),
+                    }
+                }
+            }
+            resources
+        }
+    }
 }
 
 async fn scan_table(
@@ -186,14 +487,10 @@ async fn scan_table(
     table: &str,
     order_col: &str,
     columns: &ColumnMap,
-    tx: &Sender<SyncEvent>,
+    tx: &EventSender,
     metrics: &SharedMetrics,
-    mapper: fn(&RowChange, &ColumnMap, &Config) -> Vec<DomainResource>,
+    mapper: Option<Mapper>,
 ) -> Result<usize> {
-    if table == CONSULTATION_RESPONSE_TABLE {
-        return scan_consultation_response_table(conn, db, cfg, table, order_col, columns, tx, metrics).await;
-    }
-
     let mut offset: u64 = 0;
     let mut total = 0usize;
 
@@ -230,18 +527,21 @@ async fn scan_table(
                 },
             };
 
-            let resources = mapper(&change, columns, cfg);
+            let resources = map_row(db, cfg, table, &change, columns, mapper).await;
             if resources.is_empty() {
                 continue;
             }
 
             for resource in resources {
+                let column_values =
+                    column_override_values(&cfg.oscar.column_overrides, &change, columns, resource.resource_type());
                 let sync_event = SyncEvent::new(
                     EventSource::OscarBackfill { table: table.to_string() },
                     Op::Upsert,
                     resource,
                     chrono::Utc::now(),
-                );
+                )
+                .with_column_values(column_values);
 
                 metrics.inc_received();
                 if tx.send(sync_event).await.is_err() {
@@ -258,83 +558,10 @@ async fn scan_table(
     Ok(total)
 }
 
-async fn scan_consultation_response_table(
-    conn: &mut Conn,
-    db: &DatabaseConfig,
-    _cfg: &Config,
-    table: &str,
-    order_col: &str,
-    columns: &ColumnMap,
-    tx: &Sender<SyncEvent>,
-    metrics: &SharedMetrics,
-) -> Result<usize> {
-    let mut offset: u64 = 0;
-    let mut total = 0usize;
-
-    loop {
-        let sql = format!(
-            "SELECT * FROM {table} ORDER BY {order_col} LIMIT {BATCH_SIZE} OFFSET {offset}"
-        );
-        let rows: Vec<Row> = conn
-            .query(sql)
-            .await
-            .with_context(|| format!("scanning {table} batch"))?;
-
-        if rows.is_empty() {
-            break;
-        }
-
-        let batch_len = rows.len() as u64;
-
-        for row in rows {
-            let after: Vec<Option<String>> = row
-                .unwrap()
-                .into_iter()
-                .map(|v| mysql_value_to_string(&v))
-                .collect();
-
-            let change = RowChange {
-                schema: db.schema.clone(),
-                table: table.to_string(),
-                op: RowOp::Insert,
-                after,
-                position: SourcePosition::FilePos {
-                    file: String::new(),
-                    pos: 0,
-                },
-            };
-
-            match row_to_domain_diagnostic_report(&change, columns, db).await {
-                Ok(Some(report)) => {
-                    let sync_event = SyncEvent::new(
-                        EventSource::OscarBackfill { table: table.to_string() },
-                        Op::Upsert,
-                        DomainResource::DiagnosticReport(report),
-                        chrono::Utc::now(),
-                    );
-
-                    metrics.inc_received();
-                    if tx.send(sync_event).await.is_err() {
-                        warn!("backfill: sink channel closed mid-scan, stopping early");
-                        return Ok(total);
-                    }
-                    total += 1;
-                }
-                Ok(None) => {}
-                Err(e) => warn!("backfill: failed to map consultationResponse row: {e:?}"),
-            }
-        }
-
-        offset += batch_len;
-    }
-
-    Ok(total)
-}
-
 /// Converts a raw `mysql_async::Value` to its string form, mirroring
 /// `mariadb_binlog::column_value_to_string` for the binlog path — the two
 /// must treat the same underlying columns consistently.
-fn mysql_value_to_string(value: &Value) -> Option<String> {
+pub(crate) fn mysql_value_to_string(value: &Value) -> Option<String> {
     match value {
         Value::NULL => None,
         Value::Bytes(b) => Some(String::from_utf8_lossy(b).into_owned()),
@@ -366,7 +593,7 @@ mod tests {
     #[test]
     fn backfill_steps_are_in_dependency_order() {
         let names: Vec<_> = BACKFILL_STEPS.iter().map(|(t, _, _)| *t).collect();
-        assert_eq!(names, vec!["provider", "demographic", "demographic_merged", "appointment", "dxresearch", "casemgmt_note", CONSULTATION_RESPONSE_TABLE]);
+        assert_eq!(names, vec!["clinic", SITE_TABLE, "provider", "professionalSpecialists", "program", EFORM_TABLE, "demographic", "demographic_merged", "admission", DEMOGRAPHIC_CONTACT_TABLE, "appointment", WAITING_LIST_TABLE, SCHEDULEDATE_TABLE, "dxresearch", "casemgmt_note", CONSULTATION_RESPONSE_TABLE, EFORM_DATA_TABLE, MESSAGE_TABLE, BILLING_TABLE, BILLING_ON_CHEADER1_TABLE]);
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
 
@@ -28,6 +28,9 @@ pub struct Config {
     /// `TASK_FEATURES_SPEC_OSCAR_WRITEBACK.md`.
     #[serde(default)]
     pub writeback: WritebackConfig,
+    /// Local code translation tables for coded Oscar data.
+    #[serde(default)]
+    pub terminology: TerminologyConfig,
 }
 
 use std::collections::HashMap;
@@ -47,7 +50,8 @@ pub struct OscarConfig {
     #[serde(default = "default_appointment_status_map")]
     pub appointment_status_map: HashMap<String, String>,
     /// Fallback MRP when `demographic.provider_no` is null, empty, or `-1`.
-    /// When omitted, patients with no usable MRP produce no CareTeam.
+    /// When omitted, a patient with no usable MRP gets a CareTeam only if
+    /// another role (resident, nurse, midwife, referral doctor) is set.
     #[serde(default)]
     pub default_mrp_provider_no: Option<String>,
     /// Default Oscar `appointment.location` for AMT-authored appointments.
@@ -59,6 +63,83 @@ pub struct OscarConfig {
     /// Master switch for the Oscar → FHIR CareTeam sync.
     #[serde(default = "default_true")]
     pub care_team_enabled: bool,
+    /// Master switch for the Oscar `DemographicContact` → FHIR
+    /// `RelatedPerson` / `Patient.contact` sync.
+    #[serde(default = "default_true")]
+    pub related_person_enabled: bool,
+    /// Master switch for publishing provider schedules as FHIR `Schedule`
+    /// and `Slot` resources.
+    #[serde(default = "default_true")]
+    pub schedule_enabled: bool,
+    /// How many days ahead of today (in `timezone`) slots are published.
+    /// Days outside the window are neither expanded nor refreshed.
+    #[serde(default = "default_schedule_horizon_days")]
+    pub schedule_horizon_days: u32,
+    /// Master switch for publishing a `PractitionerRole` per provider.
+    #[serde(default = "default_true")]
+    pub practitioner_role_enabled: bool,
+    /// Oscar `provider.provider_type` -> code in
+    /// `FhirConfig.practitioner_role_code_system`. Unmapped types keep only
+    /// the raw type as `PractitionerRole.code.text`.
+    #[serde(default = "default_provider_type_role_map")]
+    pub provider_type_role_map: HashMap<String, String>,
+    /// Master switch for the Oscar eForm → FHIR `Questionnaire` /
+    /// `QuestionnaireResponse` sync.
+    #[serde(default = "default_true")]
+    pub eform_enabled: bool,
+    /// `eform.fid` -> canonical URL of an externally maintained
+    /// `Questionnaire` (e.g. a published PHQ-9). Mapped forms reference that
+    /// canonical and get no generated Questionnaire.
+    #[serde(default)]
+    pub eform_questionnaire_map: HashMap<String, String>,
+    /// Master switch for the Oscar messenger → FHIR `Communication` sync.
+    #[serde(default = "default_true")]
+    pub communication_enabled: bool,
+    /// Skip messages not attached to any patient chart (`msgDemoMap`).
+    /// Clinician-only chatter has no patient to show it against in AMT.
+    #[serde(default = "default_true")]
+    pub exclude_unlinked_messages: bool,
+    /// Master switch for the Oscar billing → FHIR `Claim` / `ChargeItem` sync.
+    #[serde(default = "default_true")]
+    pub billing_enabled: bool,
+    /// Whether a billing header is published as one `Claim`, or each of its
+    /// lines as a `ChargeItem`.
+    #[serde(default)]
+    pub billing_resource: BillingResource,
+    /// Oscar billing status code (`billing.status`,
+    /// `billingmaster.billingstatus`, `billing_on_cheader1.status`) → FHIR
+    /// `ChargeItem.status`. `Claim.status` is derived from the same value.
+    /// Unmapped codes dead-letter.
+    #[serde(default = "default_billing_status_map")]
+    pub billing_status_map: HashMap<String, String>,
+    /// Master switch for the Oscar waiting list → FHIR sync.
+    #[serde(default = "default_true")]
+    pub waiting_list_enabled: bool,
+    /// Whether a waiting-list entry is published as a `waitlist`
+    /// `Appointment` or as a `ServiceRequest`.
+    #[serde(default)]
+    pub waiting_list_resource: WaitingListResource,
+    /// Publish the latest `client_image` row as `Patient.photo`.
+    #[serde(default = "default_true")]
+    pub patient_photo_enabled: bool,
+    /// Largest photo, in bytes, sent inline in `Patient.photo`. Larger
+    /// images are thumbnailed when `patient_photo_thumbnail_px` is set, and
+    /// left off the Patient otherwise.
+    #[serde(default = "default_patient_photo_max_bytes")]
+    pub patient_photo_max_bytes: usize,
+    /// Longest edge, in pixels, of the JPEG thumbnail sent in place of an
+    /// oversized photo. Needs the `photo-thumbnails` feature.
+    #[serde(default)]
+    pub patient_photo_thumbnail_px: Option<u32>,
+    /// Site-specific Oscar columns surfaced on the FHIR resource built from
+    /// their row, applied after the standard mapping.
+    #[serde(default)]
+    pub column_overrides: Vec<ColumnOverride>,
+    /// `scheduletemplatecode` codes that mark a template cell as closed
+    /// (its Slot is published `busy-unavailable`). Oscar uses `_` for "no
+    /// booking".
+    #[serde(default = "default_schedule_unavailable_codes")]
+    pub schedule_unavailable_codes: Vec<String>,
     /// Oscar `consultationResponse.status` → FHIR `DiagnosticReport.status`.
     /// Shipped empty intentionally; unmapped statuses dead-letter rather than
     /// defaulting.
@@ -91,12 +172,132 @@ impl Default for OscarConfig {
             default_appointment_type: None,
             default_program_id: None,
             care_team_enabled: true,
+            related_person_enabled: true,
+            schedule_enabled: true,
+            schedule_horizon_days: default_schedule_horizon_days(),
+            schedule_unavailable_codes: default_schedule_unavailable_codes(),
+            practitioner_role_enabled: true,
+            provider_type_role_map: default_provider_type_role_map(),
+            eform_enabled: true,
+            eform_questionnaire_map: HashMap::new(),
+            communication_enabled: true,
+            exclude_unlinked_messages: true,
+            billing_enabled: true,
+            billing_resource: BillingResource::default(),
+            billing_status_map: default_billing_status_map(),
+            waiting_list_enabled: true,
+            waiting_list_resource: WaitingListResource::default(),
+            patient_photo_enabled: true,
+            patient_photo_max_bytes: default_patient_photo_max_bytes(),
+            patient_photo_thumbnail_px: None,
+            column_overrides: Vec::new(),
             consult_response_status_map: default_consult_response_status_map(),
             consult_request_status_map: default_consult_request_status_map(),
         }
     }
 }
 
+fn default_schedule_horizon_days() -> u32 {
+    90
+}
+
+fn default_patient_photo_max_bytes() -> usize {
+    256 * 1024
+}
+
+fn default_schedule_unavailable_codes() -> Vec<String> {
+    vec!["_".to_string()]
+}
+
+/// Stock Oscar provider types onto the HL7 `practitioner-role` codes.
+/// Clerical types (receptionist, admin, billing) have no code there.
+fn default_provider_type_role_map() -> HashMap<String, String> {
+    [("doctor", "doctor"), ("resident", "doctor"), ("nurse", "nurse"), ("pharmacist", "pharmacist")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// Defaults cover the BC MSP and Ontario OHIP codes Oscar ships with.
+fn default_billing_status_map() -> HashMap<String, String> {
+    [
+        ("O", "billable"),
+        ("P", "billable"),
+        ("W", "billable"),
+        ("Z", "billable"),
+        ("B", "billed"),
+        ("C", "billed"),
+        ("E", "billed"),
+        ("R", "billed"),
+        ("S", "billed"),
+        ("X", "billed"),
+        ("H", "not-billable"),
+        ("N", "not-billable"),
+        ("D", "entered-in-error"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+/// FHIR resource a billing header is published as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingResource {
+    #[default]
+    Claim,
+    ChargeItem,
+}
+
+/// FHIR resource a waiting-list entry is published as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitingListResource {
+    #[default]
+    Appointment,
+    ServiceRequest,
+}
+
+/// Maps one locally added Oscar column to a FHIR element or extension.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ColumnOverride {
+    /// `{table}.{column}`, e.g. "demographic.pronouns".
+    pub column: String,
+    /// Resource type it applies to, e.g. "Patient"; a row can yield several.
+    /// CareTeams only reconcile their roles and are never overridden.
+    pub resource: String,
+    /// Dotted element path relative to the resource, e.g. "comment".
+    #[serde(default)]
+    pub element: Option<String>,
+    /// Extension URL; the value is added as a top-level extension.
+    #[serde(default)]
+    pub extension_url: Option<String>,
+    #[serde(default)]
+    pub value_type: ColumnValueType,
+}
+
+impl ColumnOverride {
+    pub fn table(&self) -> &str {
+        self.column.split_once('.').map_or("", |(t, _)| t)
+    }
+
+    pub fn column_name(&self) -> &str {
+        self.column.split_once('.').map_or("", |(_, c)| c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnValueType {
+    #[default]
+    String,
+    Code,
+    /// Oscar date or datetime, emitted as a FHIR `date`.
+    Date,
+    /// `1`/`0`, `Y`/`N`, `true`/`false`.
+    Boolean,
+}
+
 fn default_appointment_status_map() -> HashMap<String, String> {
     let mut m = HashMap::new();
     m.insert("t".to_string(), "booked".to_string());
@@ -325,17 +526,195 @@ pub struct FhirConfig {
     pub oscar_consult_response_system: String,
     #[serde(default = "default_oscar_cpp_condition_system")]
     pub oscar_cpp_condition_system: String,
+    #[serde(default = "default_oscar_demographic_contact_system")]
+    pub oscar_demographic_contact_system: String,
+    #[serde(default = "default_oscar_specialist_system")]
+    pub oscar_specialist_system: String,
+    #[serde(default = "default_oscar_referral_doctor_system")]
+    pub oscar_referral_doctor_system: String,
+    #[serde(default = "default_oscar_specialist_clinic_system")]
+    pub oscar_specialist_clinic_system: String,
+    #[serde(default = "default_oscar_schedule_system")]
+    pub oscar_schedule_system: String,
+    #[serde(default = "default_oscar_slot_system")]
+    pub oscar_slot_system: String,
+    #[serde(default = "default_oscar_program_system")]
+    pub oscar_program_system: String,
+    #[serde(default = "default_oscar_admission_system")]
+    pub oscar_admission_system: String,
+    #[serde(default = "default_oscar_chart_no_system")]
+    pub oscar_chart_no_system: String,
+    #[serde(default = "default_oscar_clinic_system")]
+    pub oscar_clinic_system: String,
+    #[serde(default = "default_oscar_site_system")]
+    pub oscar_site_system: String,
+    #[serde(default = "default_oscar_practitioner_role_system")]
+    pub oscar_practitioner_role_system: String,
+    #[serde(default = "default_oscar_eform_system")]
+    pub oscar_eform_system: String,
+    #[serde(default = "default_oscar_eform_data_system")]
+    pub oscar_eform_data_system: String,
+    /// Base of generated eForm `Questionnaire.url`s; the `fid` is appended.
+    #[serde(default = "default_eform_questionnaire_base_url")]
+    pub eform_questionnaire_base_url: String,
+    #[serde(default = "default_oscar_message_system")]
+    pub oscar_message_system: String,
+    /// Keys BC `billing.billing_no` Claims.
+    #[serde(default = "default_oscar_billing_system")]
+    pub oscar_billing_system: String,
+    /// Keys BC `billingmaster_no` ChargeItems.
+    #[serde(default = "default_oscar_billingmaster_system")]
+    pub oscar_billingmaster_system: String,
+    /// Keys Ontario `billing_on_cheader1.id` Claims.
+    #[serde(default = "default_oscar_billing_on_system")]
+    pub oscar_billing_on_system: String,
+    /// Keys Ontario `billing_on_item.id` ChargeItems.
+    #[serde(default = "default_oscar_billing_on_item_system")]
+    pub oscar_billing_on_item_system: String,
+    #[serde(default = "default_ohip_service_code_system")]
+    pub ohip_service_code_system: String,
+    /// System for billing diagnostic codes (MSP / OHIP, both ICD-9 based).
+    #[serde(default = "default_billing_diagnosis_code_system")]
+    pub billing_diagnosis_code_system: String,
+    #[serde(default = "default_oscar_waiting_list_system")]
+    pub oscar_waiting_list_system: String,
+    /// CodeSystem for `PractitionerRole.code`, holding the values of
+    /// `oscar.provider_type_role_map`.
+    #[serde(default = "default_practitioner_role_code_system")]
+    pub practitioner_role_code_system: String,
     #[serde(default = "default_icd9_system")]
     pub icd9_system: String,
     #[serde(default = "default_bc_phn_system")]
     pub bc_phn_system: String,
+    /// Oscar `demographic.hc_type` (province code) -> health card
+    /// NamingSystem. `BC` uses `bc_phn_system` unless listed here, as does a
+    /// patient with no `hc_type`. Configured entries are merged over the
+    /// default province table, keys upper-cased.
+    #[serde(
+        default = "default_health_card_systems",
+        deserialize_with = "health_card_systems_over_defaults"
+    )]
+    pub health_card_systems: HashMap<String, String>,
+    /// Health card system for an `hc_type` not in `health_card_systems`,
+    /// e.g. Oscar's "OT" (out of country).
+    #[serde(default = "default_out_of_country_health_card_system")]
+    pub out_of_country_health_card_system: String,
     #[serde(default = "default_bc_msp_practitioner_system")]
     pub bc_msp_practitioner_system: String,
+    /// Upsert a `CodeSystem` of Oscar's raw status codes and a `ConceptMap`
+    /// per status map (`oscar.*_status_map`, `writeback.appointment_status_map`)
+    /// at startup.
+    #[serde(default = "default_true")]
+    pub publish_status_maps: bool,
+    /// Read the status maps from those ConceptMaps at startup instead of
+    /// TOML, so they can be maintained on the FHIR server. Nothing is
+    /// published in this mode; a map with no ConceptMap keeps its TOML value.
+    #[serde(default)]
+    pub status_maps_from_server: bool,
+    /// Patient and Practitioner writes read the current resource first and
+    /// PUT with `If-Match` on its version, so edits made outside Oscar are
+    /// not silently overwritten.
+    #[serde(default)]
+    pub if_match_writes: bool,
+    /// What an `if_match_writes` conflict does: the resource was last
+    /// written by someone else, or changed between the read and the write.
+    #[serde(default)]
+    pub write_conflict_policy: WriteConflictPolicy,
+    /// FHIR resource type -> what an Oscar row delete does to it. Types not
+    /// listed use `DeletePolicy::default_for`.
+    #[serde(default)]
+    pub delete_policy: HashMap<String, DeletePolicy>,
     pub token_env: Option<String>,
     #[serde(default)]
     pub keycloak: Option<KeycloakConfig>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteConflictPolicy {
+    /// Overwrite the server version with Oscar's.
+    OscarWins,
+    /// Leave the server version in place and drop the event.
+    Skip,
+    /// Dead-letter the event with both version ids.
+    #[default]
+    DeadLetter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeletePolicy {
+    /// Keep the resource as inactive: `active=false`, or its type's
+    /// withdrawn status (`cancelled`, `revoked`, clinical status `inactive`).
+    SoftDelete,
+    /// Keep the resource with status `entered-in-error`.
+    EnteredInError,
+    /// Remove the resource with a FHIR DELETE.
+    HardDelete,
+    /// Leave the resource as it was.
+    Ignore,
+    /// Re-PUT the resource as Oscar last had it, with no tombstone.
+    Upsert,
+}
+
+impl DeletePolicy {
+    /// The delete behaviour for a resource type `fhir.delete_policy` doesn't
+    /// list, or `None` for a type the sink never writes. Each type keeps what
+    /// it did before delete policies existed: Encounter, DocumentReference,
+    /// Condition and FamilyMemberHistory are re-PUT unchanged.
+    pub fn default_for(resource_type: &str) -> Option<Self> {
+        Some(match resource_type {
+            "Patient" | "Practitioner" | "PractitionerRole" | "Appointment" | "ServiceRequest" | "RelatedPerson"
+            | "Organization" | "Schedule" | "Slot" | "Location" | "Questionnaire" => DeletePolicy::SoftDelete,
+            "Encounter" | "DocumentReference" | "Condition" | "FamilyMemberHistory" => DeletePolicy::Upsert,
+            "DiagnosticReport" | "EpisodeOfCare" | "QuestionnaireResponse" | "Communication" | "Claim"
+            | "ChargeItem" => DeletePolicy::EnteredInError,
+            // Retiring a CareTeam is a human decision in AMT-Social (D2).
+            "CareTeam" => DeletePolicy::Ignore,
+            _ => return None,
+        })
+    }
+
+    /// Whether `resource_type` can be configured with this policy. Only the
+    /// resources built in `sink::fhir` and `sink::fhir::oscar2` have a
+    /// choice of tombstone; the others keep their builder's.
+    pub fn supports(self, resource_type: &str) -> bool {
+        let Some(default) = Self::default_for(resource_type) else {
+            return false;
+        };
+        match self {
+            _ if self == default => true,
+            DeletePolicy::HardDelete | DeletePolicy::Ignore => resource_type != "CareTeam",
+            DeletePolicy::Upsert => false,
+            DeletePolicy::SoftDelete => matches!(
+                resource_type,
+                "Appointment" | "Encounter" | "Condition" | "DiagnosticReport" | "ServiceRequest"
+            ),
+            DeletePolicy::EnteredInError => matches!(
+                resource_type,
+                "Appointment"
+                    | "Encounter"
+                    | "DocumentReference"
+                    | "Condition"
+                    | "FamilyMemberHistory"
+                    | "DiagnosticReport"
+                    | "ServiceRequest"
+            ),
+        }
+    }
+}
+
+impl FhirConfig {
+    /// What an Oscar row delete does to a `resource_type` resource.
+    pub fn delete_policy_for(&self, resource_type: &str) -> DeletePolicy {
+        self.delete_policy
+            .get(resource_type)
+            .copied()
+            .or_else(|| DeletePolicy::default_for(resource_type))
+            .unwrap_or(DeletePolicy::SoftDelete)
+    }
+}
+
 impl Default for FhirConfig {
     fn default() -> Self {
         Self {
@@ -352,9 +731,40 @@ impl Default for FhirConfig {
             oscar_dxresearch_system: default_oscar_dxresearch_system(),
             oscar_consult_response_system: default_oscar_consult_response_system(),
             oscar_cpp_condition_system: default_oscar_cpp_condition_system(),
+            oscar_demographic_contact_system: default_oscar_demographic_contact_system(),
+            oscar_specialist_system: default_oscar_specialist_system(),
+            oscar_referral_doctor_system: default_oscar_referral_doctor_system(),
+            oscar_specialist_clinic_system: default_oscar_specialist_clinic_system(),
+            oscar_schedule_system: default_oscar_schedule_system(),
+            oscar_slot_system: default_oscar_slot_system(),
+            oscar_program_system: default_oscar_program_system(),
+            oscar_admission_system: default_oscar_admission_system(),
+            oscar_chart_no_system: default_oscar_chart_no_system(),
+            oscar_clinic_system: default_oscar_clinic_system(),
+            oscar_site_system: default_oscar_site_system(),
+            oscar_practitioner_role_system: default_oscar_practitioner_role_system(),
+            practitioner_role_code_system: default_practitioner_role_code_system(),
+            oscar_eform_system: default_oscar_eform_system(),
+            oscar_eform_data_system: default_oscar_eform_data_system(),
+            eform_questionnaire_base_url: default_eform_questionnaire_base_url(),
+            oscar_message_system: default_oscar_message_system(),
+            oscar_billing_system: default_oscar_billing_system(),
+            oscar_billingmaster_system: default_oscar_billingmaster_system(),
+            oscar_billing_on_system: default_oscar_billing_on_system(),
+            oscar_billing_on_item_system: default_oscar_billing_on_item_system(),
+            ohip_service_code_system: default_ohip_service_code_system(),
+            billing_diagnosis_code_system: default_billing_diagnosis_code_system(),
+            oscar_waiting_list_system: default_oscar_waiting_list_system(),
             icd9_system: default_icd9_system(),
             bc_phn_system: default_bc_phn_system(),
+            health_card_systems: default_health_card_systems(),
+            out_of_country_health_card_system: default_out_of_country_health_card_system(),
             bc_msp_practitioner_system: default_bc_msp_practitioner_system(),
+            publish_status_maps: true,
+            status_maps_from_server: false,
+            if_match_writes: false,
+            write_conflict_policy: WriteConflictPolicy::default(),
+            delete_policy: HashMap::new(),
             token_env: None,
             keycloak: None,
         }
@@ -413,6 +823,102 @@ fn default_oscar_cpp_condition_system() -> String {
     "https://arsmedicatech.com/fhir/sid/oscar-cpp-condition".to_string()
 }
 
+fn default_oscar_demographic_contact_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-demographic-contact".to_string()
+}
+
+fn default_oscar_specialist_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-professional-specialist".to_string()
+}
+
+fn default_oscar_referral_doctor_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-referral-doctor".to_string()
+}
+
+fn default_oscar_specialist_clinic_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-professional-specialist-clinic".to_string()
+}
+
+fn default_oscar_schedule_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-schedule".to_string()
+}
+
+fn default_oscar_slot_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-slot".to_string()
+}
+
+fn default_oscar_program_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-program".to_string()
+}
+
+fn default_oscar_admission_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-admission".to_string()
+}
+
+fn default_oscar_chart_no_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-chart-no".to_string()
+}
+
+fn default_oscar_clinic_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-clinic".to_string()
+}
+
+fn default_oscar_site_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-site".to_string()
+}
+
+fn default_oscar_practitioner_role_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-practitioner-role".to_string()
+}
+
+fn default_oscar_eform_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-eform".to_string()
+}
+
+fn default_oscar_eform_data_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-eform-data".to_string()
+}
+
+fn default_eform_questionnaire_base_url() -> String {
+    "https://arsmedicatech.com/fhir/Questionnaire/oscar-eform".to_string()
+}
+
+fn default_oscar_message_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-message".to_string()
+}
+
+fn default_oscar_billing_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-billing".to_string()
+}
+
+fn default_oscar_billingmaster_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-billingmaster".to_string()
+}
+
+fn default_oscar_billing_on_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-billing-on".to_string()
+}
+
+fn default_oscar_billing_on_item_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-billing-on-item".to_string()
+}
+
+fn default_ohip_service_code_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/on-ohip-service-code".to_string()
+}
+
+fn default_billing_diagnosis_code_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-billing-dx".to_string()
+}
+
+fn default_oscar_waiting_list_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/oscar-waiting-list".to_string()
+}
+
+fn default_practitioner_role_code_system() -> String {
+    "http://terminology.hl7.org/CodeSystem/practitioner-role".to_string()
+}
+
 fn default_icd9_system() -> String {
     "http://hl7.org/fhir/sid/icd-9-cm".to_string()
 }
@@ -421,6 +927,42 @@ fn default_bc_phn_system() -> String {
     "https://fhir.infoway-inforoute.ca/NamingSystem/ca-bc-patient-healthcare-id".to_string()
 }
 
+fn default_health_card_systems() -> HashMap<String, String> {
+    const INFOWAY: &str = "https://fhir.infoway-inforoute.ca/NamingSystem";
+    [
+        ("AB", "ca-ab-patient-healthcare-id"),
+        ("MB", "ca-mb-patient-healthcare-id"),
+        ("NB", "ca-nb-patient-healthcare-id"),
+        ("NL", "ca-nl-patient-healthcare-id"),
+        ("NS", "ca-ns-patient-healthcare-id"),
+        ("NT", "ca-nt-patient-healthcare-id"),
+        ("NU", "ca-nu-patient-healthcare-id"),
+        ("ON", "ca-on-patient-hcn"),
+        ("PE", "ca-pe-patient-healthcare-id"),
+        ("QC", "ca-qc-patient-healthcare-id"),
+        ("SK", "ca-sk-patient-healthcare-id"),
+        ("YT", "ca-yt-patient-healthcare-id"),
+    ]
+    .into_iter()
+    .map(|(province, id)| (province.to_string(), format!("{INFOWAY}/{id}")))
+    .collect()
+}
+
+fn health_card_systems_over_defaults<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut systems = default_health_card_systems();
+    for (province, system) in HashMap::<String, String>::deserialize(deserializer)? {
+        systems.insert(province.to_ascii_uppercase(), system);
+    }
+    Ok(systems)
+}
+
+fn default_out_of_country_health_card_system() -> String {
+    "https://arsmedicatech.com/fhir/sid/out-of-country-health-card".to_string()
+}
+
 fn default_bc_msp_practitioner_system() -> String {
     "https://fhir.infoway-inforoute.ca/NamingSystem/ca-bc-provider-billing-number".to_string()
 }
@@ -442,6 +984,44 @@ pub struct SyncConfig {
     pub retry_base_ms: u64,
     #[serde(default = "default_dead_letter_path")]
     pub dead_letter_path: String,
+    /// Most events sent in one transaction Bundle; 1 sends each on its own.
+    #[serde(default = "default_batch_max_events")]
+    pub batch_max_events: usize,
+    /// How long the sink waits for more events before sending a partial batch.
+    #[serde(default = "default_batch_max_wait_ms")]
+    pub batch_max_wait_ms: u64,
+    /// Sink workers. Events are partitioned by patient / provider, so each
+    /// entity's events stay in order while unrelated ones sync concurrently.
+    #[serde(default = "default_workers")]
+    pub workers: usize,
+    /// Events queued per worker before the source is made to wait.
+    #[serde(default = "default_worker_queue_capacity")]
+    pub worker_queue_capacity: usize,
+    /// Hashes of the last resource written to each target; unchanged
+    /// resources are not re-sent. Unset disables the check.
+    #[serde(default)]
+    pub content_hash_path: Option<String>,
+    /// Write every event even when its hash is unchanged (`--force-resync`).
+    #[serde(default)]
+    pub force_resync: bool,
+    /// Queue events on disk between the sources and the sink, so a HAPI
+    /// outage doesn't stall the binlog and a restart loses nothing.
+    #[serde(default)]
+    pub durable_queue: bool,
+    /// Holds the durable queue's segment files, under `queue/`.
+    #[serde(default = "default_state_dir")]
+    pub state_dir: String,
+    /// Size at which the durable queue starts a new segment file.
+    #[serde(default = "default_queue_segment_bytes")]
+    pub queue_segment_bytes: u64,
+    /// Holds events whose conditional references HAPI couldn't resolve yet
+    /// (HAPI-1091) until the referenced resource is synced. Unset
+    /// dead-letters them straight away.
+    #[serde(default)]
+    pub parking_path: Option<String>,
+    /// How long an event stays parked before it is dead-lettered.
+    #[serde(default = "default_parking_ttl_secs")]
+    pub parking_ttl_secs: u64,
 }
 
 impl Default for SyncConfig {
@@ -451,10 +1031,29 @@ impl Default for SyncConfig {
             retry_max_attempts: default_retry_max_attempts(),
             retry_base_ms: default_retry_base_ms(),
             dead_letter_path: default_dead_letter_path(),
+            batch_max_events: default_batch_max_events(),
+            batch_max_wait_ms: default_batch_max_wait_ms(),
+            workers: default_workers(),
+            worker_queue_capacity: default_worker_queue_capacity(),
+            content_hash_path: None,
+            force_resync: false,
+            durable_queue: false,
+            state_dir: default_state_dir(),
+            queue_segment_bytes: default_queue_segment_bytes(),
+            parking_path: None,
+            parking_ttl_secs: default_parking_ttl_secs(),
         }
     }
 }
 
+fn default_queue_segment_bytes() -> u64 {
+    64 * 1024 * 1024
+}
+
+fn default_parking_ttl_secs() -> u64 {
+    24 * 60 * 60
+}
+
 fn default_checkpoint_path() -> String {
     "/var/lib/fhir-sync/checkpoint.json".to_string()
 }
@@ -471,6 +1070,22 @@ fn default_retry_base_ms() -> u64 {
     500
 }
 
+fn default_batch_max_events() -> usize {
+    1
+}
+
+fn default_batch_max_wait_ms() -> u64 {
+    50
+}
+
+fn default_workers() -> usize {
+    1
+}
+
+fn default_worker_queue_capacity() -> usize {
+    256
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ReplicationConfig {
     #[serde(default)]
@@ -560,32 +1175,22 @@ pub struct ReplicationLink {
     pub subscription_doorbell: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum ReplicationMode {
+    #[default]
     Mirror,
     Federate,
 }
 
-impl Default for ReplicationMode {
-    fn default() -> Self {
-        ReplicationMode::Mirror
-    }
-}
-
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ConflictPolicy {
+    #[default]
     DeadLetter,
     SourceWins,
 }
 
-impl Default for ConflictPolicy {
-    fn default() -> Self {
-        ConflictPolicy::DeadLetter
-    }
-}
-
 fn default_link_mode() -> ReplicationMode {
     ReplicationMode::Mirror
 }
@@ -599,7 +1204,34 @@ fn default_link_conflict_policy() -> ConflictPolicy {
 }
 
 const KNOWN_DISPATCH_RESOURCE_TYPES: &[&str] = &["Patient", "Practitioner", "Appointment", "ServiceRequest"];
-const KNOWN_DISPATCH_OPS: &[&str] = &["upsert", "delete"];
+const KNOWN_DISPATCH_OPS: &[&str] = &["upsert", "delete", "soft_delete", "entered_in_error"];
+
+/// Local terminology: translation tables loaded from disk at startup and
+/// applied to `dxresearch` Condition codes.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct TerminologyConfig {
+    #[serde(default)]
+    pub translations: Vec<TranslationTableConfig>,
+    /// Dead-letter a `dxresearch` code that is neither in `diagnosticcode`
+    /// nor the source side of a translation table for its coding system.
+    /// Codes in a coding system with no reference data are never rejected,
+    /// and neither are deletes. Off by default.
+    #[serde(default)]
+    pub validate_source_codes: bool,
+}
+
+/// One translation table file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TranslationTableConfig {
+    /// Path to a CSV file or a FHIR `ConceptMap` JSON file (`.json`).
+    pub path: String,
+    /// Oscar `dxresearch.coding_system` the table translates from, e.g. "icd9".
+    pub source: String,
+    /// Target code system URL for CSV rows without a `target_system` column.
+    /// Ignored for ConceptMaps, whose groups name their own target.
+    #[serde(default)]
+    pub target_system: Option<String>,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct DispatchConfig {
@@ -808,6 +1440,30 @@ pub fn validate_replication(cfg: &ReplicationConfig, dispatch: &DispatchConfig)
     Ok(())
 }
 
+/// Column names are interpolated into refresh queries, so only plain
+/// identifiers are accepted.
+fn validate_column_override(o: &ColumnOverride) -> anyhow::Result<()> {
+    let is_identifier = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !is_identifier(o.table()) || !is_identifier(o.column_name()) {
+        anyhow::bail!("[oscar.column_overrides] column '{}' must be `table.column`", o.column);
+    }
+    if o.resource.is_empty() || o.resource == "CareTeam" {
+        anyhow::bail!(
+            "[oscar.column_overrides] column '{}' has an unsupported resource '{}'",
+            o.column,
+            o.resource
+        );
+    }
+    match (&o.element, &o.extension_url) {
+        (Some(path), None) if path.split('.').all(|p| !p.is_empty()) => Ok(()),
+        (None, Some(url)) if !url.is_empty() => Ok(()),
+        _ => anyhow::bail!(
+            "[oscar.column_overrides] column '{}' needs exactly one of `element` or `extension_url`",
+            o.column
+        ),
+    }
+}
+
 /// Validates Oscar-specific config. Fatal on error when `oscar_enabled`.
 pub fn validate_oscar(cfg: &Config) -> anyhow::Result<()> {
     if !cfg.oscar_enabled {
@@ -837,6 +1493,16 @@ pub fn validate_oscar(cfg: &Config) -> anyhow::Result<()> {
         }
     }
 
+    for o in &cfg.oscar.column_overrides {
+        validate_column_override(o)?;
+    }
+
+    for (resource_type, policy) in &cfg.fhir.delete_policy {
+        if !policy.supports(resource_type) {
+            anyhow::bail!("[fhir.delete_policy] {resource_type} does not support {policy:?}");
+        }
+    }
+
     Ok(())
 }
 
@@ -1053,6 +1719,7 @@ mod tests {
             oscar: OscarConfig::default(),
             debug: None,
             writeback: WritebackConfig::default(),
+            terminology: TerminologyConfig::default(),
         };
         let err = validate_oscar(&cfg).unwrap_err().to_string();
         assert!(err.contains("timezone is required"));
@@ -1077,11 +1744,31 @@ mod tests {
                 default_appointment_type: None,
                 default_program_id: None,
                 care_team_enabled: true,
+                related_person_enabled: true,
+                schedule_enabled: true,
+                schedule_horizon_days: default_schedule_horizon_days(),
+                schedule_unavailable_codes: default_schedule_unavailable_codes(),
+                practitioner_role_enabled: true,
+                provider_type_role_map: default_provider_type_role_map(),
+                eform_enabled: true,
+                eform_questionnaire_map: HashMap::new(),
+                communication_enabled: true,
+                exclude_unlinked_messages: true,
+                billing_enabled: true,
+                billing_resource: BillingResource::Claim,
+                billing_status_map: default_billing_status_map(),
+                waiting_list_enabled: true,
+                waiting_list_resource: WaitingListResource::Appointment,
+                patient_photo_enabled: true,
+                patient_photo_max_bytes: 262_144,
+                patient_photo_thumbnail_px: None,
+                column_overrides: Vec::new(),
                 consult_response_status_map: OscarConfig::default().consult_response_status_map,
                 consult_request_status_map: OscarConfig::default().consult_request_status_map,
             },
             debug: None,
             writeback: WritebackConfig::default(),
+            terminology: TerminologyConfig::default(),
         };
         let err = validate_oscar(&cfg).unwrap_err().to_string();
         assert!(err.contains("not a valid IANA timezone"));
@@ -1106,15 +1793,130 @@ mod tests {
                 default_appointment_type: None,
                 default_program_id: None,
                 care_team_enabled: true,
+                related_person_enabled: true,
+                schedule_enabled: true,
+                schedule_horizon_days: default_schedule_horizon_days(),
+                schedule_unavailable_codes: default_schedule_unavailable_codes(),
+                practitioner_role_enabled: true,
+                provider_type_role_map: default_provider_type_role_map(),
+                eform_enabled: true,
+                eform_questionnaire_map: HashMap::new(),
+                communication_enabled: true,
+                exclude_unlinked_messages: true,
+                billing_enabled: true,
+                billing_resource: BillingResource::Claim,
+                billing_status_map: default_billing_status_map(),
+                waiting_list_enabled: true,
+                waiting_list_resource: WaitingListResource::Appointment,
+                patient_photo_enabled: true,
+                patient_photo_max_bytes: 262_144,
+                patient_photo_thumbnail_px: None,
+                column_overrides: Vec::new(),
                 consult_response_status_map: OscarConfig::default().consult_response_status_map,
                 consult_request_status_map: OscarConfig::default().consult_request_status_map,
             },
             debug: None,
             writeback: WritebackConfig::default(),
+            terminology: TerminologyConfig::default(),
         };
         assert!(validate_oscar(&cfg).is_ok());
     }
 
+    #[test]
+    fn validate_column_override_requires_one_target() {
+        let o = ColumnOverride {
+            column: "demographic.pronouns".to_string(),
+            resource: "Patient".to_string(),
+            element: None,
+            extension_url: Some("http://hl7.org/fhir/StructureDefinition/individual-pronouns".to_string()),
+            value_type: ColumnValueType::String,
+        };
+        assert!(validate_column_override(&o).is_ok());
+
+        let both = ColumnOverride {
+            element: Some("gender".to_string()),
+            ..o.clone()
+        };
+        assert!(validate_column_override(&both).is_err());
+
+        let bad_column = ColumnOverride {
+            column: "demographic.pronouns; DROP".to_string(),
+            ..o
+        };
+        assert!(validate_column_override(&bad_column).is_err());
+    }
+
+    #[test]
+    fn health_card_systems_merge_over_defaults() {
+        let cfg: FhirConfig = toml::from_str(
+            r#"
+            [health_card_systems]
+            on = "urn:on"
+            OT = "urn:ot"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.health_card_systems["ON"], "urn:on");
+        assert_eq!(cfg.health_card_systems["OT"], "urn:ot");
+        assert!(cfg.health_card_systems["AB"].ends_with("/ca-ab-patient-healthcare-id"));
+        assert!(!cfg.health_card_systems.contains_key("on"));
+    }
+
+    #[test]
+    fn delete_policy_defaults_and_support() {
+        let cfg: FhirConfig = toml::from_str(
+            r#"
+            [delete_policy]
+            Appointment = "entered_in_error"
+            Patient = "hard_delete"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.delete_policy_for("Appointment"), DeletePolicy::EnteredInError);
+        assert_eq!(cfg.delete_policy_for("Patient"), DeletePolicy::HardDelete);
+        assert_eq!(cfg.delete_policy_for("Practitioner"), DeletePolicy::SoftDelete);
+        assert_eq!(cfg.delete_policy_for("Encounter"), DeletePolicy::Upsert);
+
+        assert!(DeletePolicy::Ignore.supports("Communication"));
+        assert!(!DeletePolicy::EnteredInError.supports("Patient"));
+        assert!(!DeletePolicy::HardDelete.supports("CareTeam"));
+        assert!(!DeletePolicy::Ignore.supports("patient"));
+        assert!(DeletePolicy::Upsert.supports("Condition"));
+        assert!(!DeletePolicy::Upsert.supports("Appointment"));
+    }
+
+    #[test]
+    fn delete_policy_default_per_type() {
+        let expected = [
+            ("Patient", DeletePolicy::SoftDelete),
+            ("Practitioner", DeletePolicy::SoftDelete),
+            ("PractitionerRole", DeletePolicy::SoftDelete),
+            ("Appointment", DeletePolicy::SoftDelete),
+            ("ServiceRequest", DeletePolicy::SoftDelete),
+            ("RelatedPerson", DeletePolicy::SoftDelete),
+            ("Organization", DeletePolicy::SoftDelete),
+            ("Schedule", DeletePolicy::SoftDelete),
+            ("Slot", DeletePolicy::SoftDelete),
+            ("Location", DeletePolicy::SoftDelete),
+            ("Questionnaire", DeletePolicy::SoftDelete),
+            ("Encounter", DeletePolicy::Upsert),
+            ("DocumentReference", DeletePolicy::Upsert),
+            ("Condition", DeletePolicy::Upsert),
+            ("FamilyMemberHistory", DeletePolicy::Upsert),
+            ("DiagnosticReport", DeletePolicy::EnteredInError),
+            ("EpisodeOfCare", DeletePolicy::EnteredInError),
+            ("QuestionnaireResponse", DeletePolicy::EnteredInError),
+            ("Communication", DeletePolicy::EnteredInError),
+            ("Claim", DeletePolicy::EnteredInError),
+            ("ChargeItem", DeletePolicy::EnteredInError),
+            ("CareTeam", DeletePolicy::Ignore),
+        ];
+        for (resource_type, policy) in expected {
+            assert_eq!(DeletePolicy::default_for(resource_type), Some(policy), "{resource_type}");
+        }
+        assert_eq!(DeletePolicy::default_for("Observation"), None);
+    }
+
     #[test]
     fn validate_oscar_skipped_when_disabled() {
         let cfg = Config {
@@ -1128,6 +1930,7 @@ mod tests {
             oscar: OscarConfig::default(),
             debug: None,
             writeback: WritebackConfig::default(),
+            terminology: TerminologyConfig::default(),
         };
         assert!(validate_oscar(&cfg).is_ok());
     }
@@ -253,15 +253,15 @@ fn write_dlq(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::DispatchConsumer;
-    use crate::event::{Op, Source};
+    use crate::dispatch::DispatchOp;
+    use crate::event::Source;
 
     fn sample_notification() -> DispatchNotification {
         DispatchNotification {
             resource_type: "Patient".to_string(),
             fhir_id: "123".to_string(),
             fhir_version_id: Some("4".to_string()),
-            op: Op::Upsert,
+            op: DispatchOp::Upsert,
             source: Source::OscarBinlog { table: "demographic".to_string() },
             idempotency_key: "oscar:demographic:42".to_string(),
             occurred_at: Utc::now(),
@@ -21,19 +21,43 @@ use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
 use crate::config::DispatchConfig;
-use crate::event::{Op, Source};
+use crate::event::Source;
 use crate::metrics::SharedMetrics;
 use crate::replication::poller::{HistoryEvent, HistoryOp};
 
 pub mod delivery;
 
+/// What happened to the resource. An Oscar delete is reported as whatever
+/// `fhir.delete_policy` did with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchOp {
+    Upsert,
+    /// The resource was removed from the server.
+    Delete,
+    /// A new version marks the resource inactive, cancelled or revoked.
+    SoftDelete,
+    /// A new version marks the resource `entered-in-error`.
+    EnteredInError,
+}
+
+impl DispatchOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DispatchOp::Upsert => "upsert",
+            DispatchOp::Delete => "delete",
+            DispatchOp::SoftDelete => "soft_delete",
+            DispatchOp::EnteredInError => "entered_in_error",
+        }
+    }
+}
+
 /// Reference-only notification emitted after a successful HAPI commit.
 #[derive(Debug, Clone)]
 pub struct DispatchNotification {
     pub resource_type: String,
     pub fhir_id: String,
     pub fhir_version_id: Option<String>,
-    pub op: Op,
+    pub op: DispatchOp,
     pub source: Source,
     pub idempotency_key: String,
     pub occurred_at: DateTime<Utc>,
@@ -41,7 +65,7 @@ pub struct DispatchNotification {
 }
 
 impl DispatchNotification {
-    pub fn from_history_event(
+    pub(crate) fn from_history_event(
         event: &HistoryEvent,
         fhir_base_url: &str,
         link_name: &str,
@@ -52,8 +76,8 @@ impl DispatchNotification {
             fhir_version_id: (!event.version_id.is_empty())
                 .then(|| event.version_id.clone()),
             op: match event.op {
-                HistoryOp::Create | HistoryOp::Update => Op::Upsert,
-                HistoryOp::Delete => Op::Delete,
+                HistoryOp::Create | HistoryOp::Update => DispatchOp::Upsert,
+                HistoryOp::Delete => DispatchOp::Delete,
             },
             source: Source::FhirHistory,
             // Opaque to consumers. Stable across redeliveries of the same
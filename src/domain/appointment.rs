@@ -19,7 +19,10 @@ pub struct DomainAppointment {
     pub remarks:         Option<String>,
     pub urgency:         Option<String>,
     pub createdatetime:  Option<String>,  // ISO 8601 local-ish datetime, "YYYY-MM-DD HH:MM:SS"
-    pub location:        Option<String>,  // deferred to Phase 3+; carried for logging/TODO
+    pub location:        Option<String>,  // raw `appointment.location`: a site name on multi-site clinics
+    /// `site.site_id` resolved from `location`; drives the Location participant.
+    #[serde(default)]
+    pub site_id:         Option<String>,
     pub booking_source:  Option<String>,
     #[serde(rename = "type")]
     pub type_:           Option<String>,
@@ -45,6 +48,7 @@ mod tests {
             urgency: None,
             createdatetime: None,
             location: None,
+            site_id: None,
             booking_source: None,
             type_: None,
         };
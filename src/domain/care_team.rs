@@ -1,7 +1,116 @@
-/// Oscar-side representation of the CareTeam that links a patient to their
-/// most-responsible provider (MRP). See `TASK_FEATURES_SPEC_CARE_TEAM.md`.
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+/// Oscar-side representation of the CareTeam that links a patient to the
+/// providers Oscar records against them: the most-responsible provider (MRP),
+/// resident, nurse, midwife and referral doctor. See
+/// `TASK_FEATURES_SPEC_CARE_TEAM.md`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DomainCareTeam {
     pub demographic_no: String,
-    pub provider_no: String,
+    /// At most one participant per role.
+    pub participants: Vec<DomainCareTeamParticipant>,
+}
+
+impl DomainCareTeam {
+    pub fn member_for(&self, role: CareTeamRole) -> Option<&CareTeamMember> {
+        self.participants.iter().find(|p| p.role == role).map(|p| &p.member)
+    }
+
+    /// Sets the member for `role` unless that role is already filled.
+    pub fn fill_role(&mut self, role: CareTeamRole, member: CareTeamMember) {
+        if self.member_for(role).is_none() {
+            self.participants.push(DomainCareTeamParticipant { role, member });
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DomainCareTeamParticipant {
+    pub role: CareTeamRole,
+    pub member: CareTeamMember,
+}
+
+/// A per-patient provider role in Oscar, carried as the participant's
+/// SNOMED CT role code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CareTeamRole {
+    Mrp,
+    Resident,
+    Nurse,
+    Midwife,
+    ReferralDoctor,
+}
+
+impl CareTeamRole {
+    pub const ALL: [CareTeamRole; 5] = [
+        CareTeamRole::Mrp,
+        CareTeamRole::Resident,
+        CareTeamRole::Nurse,
+        CareTeamRole::Midwife,
+        CareTeamRole::ReferralDoctor,
+    ];
+
+    pub fn snomed_code(self) -> &'static str {
+        match self {
+            CareTeamRole::Mrp => "446050000",
+            CareTeamRole::Resident => "405277009",
+            CareTeamRole::Nurse => "224535009",
+            CareTeamRole::Midwife => "309453006",
+            CareTeamRole::ReferralDoctor => "158965000",
+        }
+    }
+
+    pub fn snomed_display(self) -> &'static str {
+        match self {
+            CareTeamRole::Mrp => "Primary care physician",
+            CareTeamRole::Resident => "Resident physician",
+            CareTeamRole::Nurse => "Registered nurse",
+            CareTeamRole::Midwife => "Registered midwife",
+            CareTeamRole::ReferralDoctor => "Medical practitioner",
+        }
+    }
+
+    pub fn from_snomed_code(code: &str) -> Option<CareTeamRole> {
+        Self::ALL.into_iter().find(|r| r.snomed_code() == code)
+    }
+}
+
+/// Who fills a role. Each variant is a Practitioner under a different
+/// identifier system, so they never collide.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CareTeamMember {
+    /// An Oscar user, by `provider_no`.
+    Provider(String),
+    /// A `professionalSpecialists` entry, by `specId`.
+    Specialist(String),
+    /// An external referral doctor, by billing number.
+    ReferralDoctor(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_role_keeps_first_member() {
+        let mut ct = DomainCareTeam {
+            demographic_no: "1".to_string(),
+            participants: Vec::new(),
+        };
+        ct.fill_role(CareTeamRole::Nurse, CareTeamMember::Provider("201".to_string()));
+        ct.fill_role(CareTeamRole::Nurse, CareTeamMember::Provider("202".to_string()));
+        assert_eq!(ct.participants.len(), 1);
+        assert_eq!(
+            ct.member_for(CareTeamRole::Nurse),
+            Some(&CareTeamMember::Provider("201".to_string()))
+        );
+    }
+
+    #[test]
+    fn snomed_codes_round_trip() {
+        for role in CareTeamRole::ALL {
+            assert_eq!(CareTeamRole::from_snomed_code(role.snomed_code()), Some(role));
+        }
+        assert_eq!(CareTeamRole::from_snomed_code("123"), None);
+    }
 }
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+/// Which Oscar billing module a claim came from. BC (MSP) claims are a
+/// `billing` header with `billingmaster` lines; Ontario (OHIP) claims are a
+/// `billing_on_cheader1` header with `billing_on_item` lines. The two id
+/// spaces overlap, so each gets its own identifier systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BillingSource {
+    Bc,
+    On,
+}
+
+impl BillingSource {
+    pub fn header_table(self) -> &'static str {
+        match self {
+            BillingSource::Bc => "billing",
+            BillingSource::On => "billing_on_cheader1",
+        }
+    }
+}
+
+/// Domain model for a FHIR `Claim` sourced from one Oscar billing header and
+/// its service lines.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainClaim {
+    /// `billing.billing_no` or `billing_on_cheader1.id`, used as the natural key.
+    pub claim_id: String,
+    pub source: BillingSource,
+    pub demographic_no: String,
+    /// The billing provider.
+    pub provider_no: Option<String>,
+    pub appointment_no: Option<String>,
+    /// Identifier value of the Encounter for `appointment_no`, when a note
+    /// was written against that appointment.
+    #[serde(default)]
+    pub encounter_value: Option<String>,
+    pub service_date: Option<String>,
+    /// When the claim was entered or last updated, falling back to
+    /// `service_date`.
+    pub created: Option<String>,
+    /// Raw Oscar billing status code; see `oscar.billing_status_map`.
+    pub status_code: Option<String>,
+    pub total: Option<String>,
+    #[serde(default)]
+    pub items: Vec<DomainClaimItem>,
+    /// `false` once the header row is deleted.
+    pub active: bool,
+}
+
+/// One service line (`billingmaster` / `billing_on_item` row).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DomainClaimItem {
+    /// `billingmaster_no` or `billing_on_item.id`.
+    pub line_id: String,
+    pub service_code: String,
+    /// Diagnostic codes in billing order.
+    #[serde(default)]
+    pub dx_codes: Vec<String>,
+    pub fee: Option<String>,
+    pub units: Option<String>,
+    pub service_date: Option<String>,
+    /// Line-level status code, where the billing module keeps one.
+    pub status_code: Option<String>,
+}
+
+/// Domain model for a FHIR `ChargeItem`: one claim line together with the
+/// header fields it needs to stand alone.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainChargeItem {
+    pub source: BillingSource,
+    pub claim_id: String,
+    pub demographic_no: String,
+    pub provider_no: Option<String>,
+    pub encounter_value: Option<String>,
+    /// The line's own status, falling back to the header's.
+    pub status_code: Option<String>,
+    pub item: DomainClaimItem,
+    pub active: bool,
+}
+
+impl DomainClaim {
+    /// Splits the claim into one `DomainChargeItem` per line.
+    pub fn into_charge_items(self) -> Vec<DomainChargeItem> {
+        let DomainClaim {
+            claim_id,
+            source,
+            demographic_no,
+            provider_no,
+            encounter_value,
+            service_date,
+            status_code,
+            items,
+            active,
+            ..
+        } = self;
+        items
+            .into_iter()
+            .map(|mut item| {
+                if item.service_date.is_none() {
+                    item.service_date = service_date.clone();
+                }
+                DomainChargeItem {
+                    source,
+                    claim_id: claim_id.clone(),
+                    demographic_no: demographic_no.clone(),
+                    provider_no: provider_no.clone(),
+                    encounter_value: encounter_value.clone(),
+                    status_code: item.status_code.clone().or_else(|| status_code.clone()),
+                    item,
+                    active,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_charge_items_inherits_header_fields() {
+        let claim = DomainClaim {
+            claim_id: "500".to_string(),
+            source: BillingSource::Bc,
+            demographic_no: "123".to_string(),
+            provider_no: Some("1001".to_string()),
+            appointment_no: None,
+            encounter_value: None,
+            service_date: Some("2026-03-02".to_string()),
+            created: None,
+            status_code: Some("O".to_string()),
+            total: Some("60.00".to_string()),
+            items: vec![
+                DomainClaimItem {
+                    line_id: "900".to_string(),
+                    service_code: "00100".to_string(),
+                    dx_codes: vec!["250".to_string()],
+                    fee: Some("30.00".to_string()),
+                    units: Some("1".to_string()),
+                    service_date: None,
+                    status_code: None,
+                },
+                DomainClaimItem {
+                    line_id: "901".to_string(),
+                    service_code: "00101".to_string(),
+                    dx_codes: Vec::new(),
+                    fee: Some("30.00".to_string()),
+                    units: None,
+                    service_date: Some("2026-03-03".to_string()),
+                    status_code: Some("S".to_string()),
+                },
+            ],
+            active: true,
+        };
+        let charges = claim.into_charge_items();
+        assert_eq!(charges.len(), 2);
+        assert_eq!(charges[0].item.service_date.as_deref(), Some("2026-03-02"));
+        assert_eq!(charges[0].status_code.as_deref(), Some("O"));
+        assert_eq!(charges[1].item.service_date.as_deref(), Some("2026-03-03"));
+        assert_eq!(charges[1].status_code.as_deref(), Some("S"));
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `Communication` sourced from one Oscar messenger
+/// message (`messagetbl`), with its recipients (`messagelisttbl`) and the
+/// patient charts it is attached to (`msgDemoMap`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainCommunication {
+    /// `messagetbl.messageid`, used as the natural key.
+    pub message_id: String,
+    /// `sentbyNo`; `None` for system-sent messages.
+    pub sender_provider_no: Option<String>,
+    /// `messagelisttbl.provider_no` for each recipient.
+    #[serde(default)]
+    pub recipient_provider_nos: Vec<String>,
+    /// `msgDemoMap.demographic_no` for each attached chart.
+    #[serde(default)]
+    pub demographic_nos: Vec<String>,
+    /// `thedate` / `theime`: local date and time the message was sent.
+    pub sent_date: Option<String>,
+    pub sent_time: Option<String>,
+    pub subject: Option<String>,
+    pub body: Option<String>,
+    /// `false` once the message row is deleted.
+    pub active: bool,
+}
+
+impl DomainCommunication {
+    pub fn is_patient_linked(&self) -> bool {
+        !self.demographic_nos.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_communication_links_default_empty() {
+        let json = r#"{
+            "message_id": "77",
+            "sender_provider_no": "1001",
+            "sent_date": "2026-02-01",
+            "sent_time": "09:30:00",
+            "subject": "Lab results",
+            "body": "Please call the patient.",
+            "active": true
+        }"#;
+        let c: DomainCommunication = serde_json::from_str(json).unwrap();
+        assert!(c.recipient_provider_nos.is_empty());
+        assert!(!c.is_patient_linked());
+    }
+}
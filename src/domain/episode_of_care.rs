@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `EpisodeOfCare` sourced from an Oscar CAISI
+/// `admission` row: one patient's enrolment in one program.
+///
+/// Dates are naive local `admission_date` / `discharge_date` values
+/// ("YYYY-MM-DD HH:MM:SS"); conversion to an aware instant happens in the
+/// sink (D5).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainEpisodeOfCare {
+    /// `admission.am_id`, used as the natural key.
+    pub admission_id:    String,
+    /// `admission.client_id`.
+    pub demographic_no:  String,
+    pub program_id:      String,
+    /// Admitting provider; `None` for the system actor or an empty column.
+    pub provider_no:     Option<String>,
+    pub admission_date:  Option<String>,
+    pub discharge_date:  Option<String>,
+    /// Raw Oscar `admission_status`, e.g. "current", "discharged".
+    pub status:          Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_episode_of_care_optional_fields_default_none() {
+        let json = r#"{
+            "admission_id": "7",
+            "demographic_no": "123",
+            "program_id": "10016",
+            "provider_no": null,
+            "admission_date": null,
+            "discharge_date": null,
+            "status": null
+        }"#;
+
+        let e: DomainEpisodeOfCare = serde_json::from_str(json).unwrap();
+        assert_eq!(e.admission_id, "7");
+        assert_eq!(e.program_id, "10016");
+        assert_eq!(e.discharge_date, None);
+    }
+}
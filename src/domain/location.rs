@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `Location` sourced from Oscar's multi-site
+/// `site` table. Each site is managed by the clinic `Organization` built
+/// from the `clinic` table.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainLocation {
+    /// `site.site_id`, used as the natural key.
+    pub site_id:      String,
+    /// Full site name; what `appointment.location` holds on multi-site clinics.
+    pub name:         Option<String>,
+    /// `short_name`, shown in Oscar's schedule grid; carried as an alias.
+    pub short_name:   Option<String>,
+    pub address_line: Option<String>,
+    pub city:         Option<String>,
+    pub province:     Option<String>,
+    pub postal:       Option<String>,
+    pub phone:        Option<String>,
+    pub fax:          Option<String>,
+    /// `clinic.clinic_no` of the managing clinic, when one exists.
+    pub clinic_no:    Option<String>,
+    /// `false` once the site is disabled (`status` 0) or deleted.
+    pub active:       bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_location_round_trips() {
+        let json = r#"{
+            "site_id": "2",
+            "name": "Downtown",
+            "short_name": "DT",
+            "address_line": null,
+            "city": null,
+            "province": null,
+            "postal": null,
+            "phone": null,
+            "fax": null,
+            "clinic_no": "1",
+            "active": true
+        }"#;
+
+        let l: DomainLocation = serde_json::from_str(json).unwrap();
+        assert_eq!(l.site_id, "2");
+        assert_eq!(l.short_name.as_deref(), Some("DT"));
+        assert!(l.active);
+    }
+}
@@ -1,10 +1,22 @@
 pub mod appointment;
 pub mod care_team;
+pub mod claim;
+pub mod communication;
 pub mod condition;
 pub mod diagnostic_report;
 pub mod document_reference;
 pub mod encounter;
+pub mod episode_of_care;
+pub mod location;
+pub mod organization;
 pub mod patient;
 pub mod practitioner;
+pub mod practitioner_role;
+pub mod questionnaire;
+pub mod referral_doctor;
+pub mod related_person;
 pub mod resource;
+pub mod schedule;
 pub mod service_request;
+pub mod specialist;
+pub mod waiting_list;
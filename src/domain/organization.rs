@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `Organization`. Oscar has no single organization
+/// table, so `source_table` records which one the row came from and selects
+/// the identifier system at the sink.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainOrganization {
+    pub source_table: String,
+    pub source_id:    String,
+    pub name:         Option<String>,
+    /// Street line, or the whole address when Oscar stores it as free text.
+    pub address_line: Option<String>,
+    pub city:         Option<String>,
+    pub province:     Option<String>,
+    pub postal:       Option<String>,
+    pub phone:        Option<String>,
+    pub fax:          Option<String>,
+    pub email:        Option<String>,
+    pub active:       bool,
+}
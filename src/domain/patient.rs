@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::referral_doctor::DomainReferralDoctor;
+use crate::domain::related_person::DomainRelatedPerson;
+
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
 pub enum AddressUse {
     #[serde(rename = "home")]
@@ -46,11 +49,23 @@ pub struct DomainAddress {
     pub kind:     AddressKind,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct DomainPatient {
     pub demographic_no: String,
     pub first_name:    Option<String>,
     pub last_name:     Option<String>,
+    /// Space-separated `middleNames`; emitted as additional given names.
+    #[serde(default)]
+    pub middle_names:  Option<String>,
+    /// `pref_name`, emitted as a `usual` HumanName.
+    #[serde(default)]
+    pub pref_name:     Option<String>,
+    /// `title`, e.g. "MR", "DR"; emitted as a name prefix.
+    #[serde(default)]
+    pub title:         Option<String>,
+    /// Free-text `alias` ("also known as").
+    #[serde(default)]
+    pub alias:         Option<String>,
     pub date_of_birth: Option<String>, // ISO "YYYY-MM-DD"
     #[serde(default)]
     pub addresses:     Vec<DomainAddress>,
@@ -59,10 +74,62 @@ pub struct DomainPatient {
     pub email:         Option<String>,
     /// Provincial health insurance number
     pub hin:           Option<String>,
+    /// Issuing province of `hin` (`hc_type`), e.g. "BC", "ON"; "OT" out of country.
+    #[serde(default)]
+    pub hc_type:       Option<String>,
+    /// Health card version code (`ver`), used by Ontario.
+    #[serde(default)]
+    pub hc_version:    Option<String>,
+    /// Health card expiry (`hc_renew_date`), "YYYY-MM-DD".
+    #[serde(default)]
+    pub hc_renew_date: Option<String>,
+    /// `official_lang`: "English" or "French".
+    #[serde(default)]
+    pub official_lang: Option<String>,
+    /// Free-text `spoken_lang`, e.g. "Punjabi".
+    #[serde(default)]
+    pub spoken_lang:   Option<String>,
+    /// Clinic chart number (`chart_no`).
+    #[serde(default)]
+    pub chart_no:      Option<String>,
+    /// Oscar `roster_status`: RO (rostered), NR, TE (terminated), FS, etc.
+    #[serde(default)]
+    pub roster_status: Option<String>,
+    /// `roster_date`, "YYYY-MM-DD".
+    #[serde(default)]
+    pub roster_date:   Option<String>,
+    /// `date_joined`: when the patient joined the practice, "YYYY-MM-DD".
+    #[serde(default)]
+    pub date_joined:   Option<String>,
     /// Oscar `patient_status`: AC, IN, DE, etc.
     pub patient_status: Option<String>,
     /// From `demographic_merged.merged_to`; when present this is a merge-loser record.
     pub merged_to:     Option<String>,
+    /// Most responsible provider (`demographic.provider_no`).
+    #[serde(default)]
+    pub mrp_provider_no: Option<String>,
+    /// Referring / family doctor from `demographic.family_doctor`.
+    #[serde(default)]
+    pub family_doctor: Option<DomainReferralDoctor>,
+    /// Non-demographic `DemographicContact` links, emitted as `Patient.contact`.
+    #[serde(default)]
+    pub contacts:      Vec<DomainRelatedPerson>,
+    /// Latest `client_image` row, already size-capped; emitted as `Patient.photo`.
+    #[serde(default)]
+    pub photo:         Option<DomainPatientPhoto>,
+}
+
+/// A patient photo ready to inline as a FHIR `Attachment`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DomainPatientPhoto {
+    /// MIME type, e.g. "image/jpeg".
+    pub content_type: String,
+    /// Base64-encoded image bytes.
+    pub data:         String,
+    /// Size of the decoded image in bytes.
+    pub size:         usize,
+    /// `client_image.update_date`.
+    pub creation:     Option<String>,
 }
 
 #[cfg(test)]
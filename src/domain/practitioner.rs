@@ -2,10 +2,9 @@ use serde::{Deserialize, Serialize};
 
 /// Domain model for a FHIR `Practitioner` sourced from Oscar's `provider` table.
 ///
-/// Role attributes (`provider_type`, `specialty`, `team`, `supervisor`,
-/// `job_title`, `init`, `hso_no`, `rma_no`, `comments`, `provider_activity`,
-/// `signed_confidentiality`) are intentionally excluded (D8); this struct
-/// models the person only.
+/// Role attributes are intentionally excluded (D8); this struct models the
+/// person only. `provider_type`, `specialty`, `team`, `supervisor` and
+/// `job_title` are carried by `DomainPractitionerRole` instead.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DomainPractitioner {
     pub provider_no: String,
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `PractitionerRole` sourced from the role columns
+/// of Oscar's `provider` table, which `DomainPractitioner` leaves out (D8).
+/// One role per provider, keyed on `provider_no`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainPractitionerRole {
+    pub provider_no:   String,
+    /// `provider_type`, e.g. "doctor", "nurse", "receptionist".
+    pub provider_type: Option<String>,
+    /// Free-text `specialty`.
+    pub specialty:     Option<String>,
+    pub team:          Option<String>,
+    /// `provider_no` of the supervising provider.
+    pub supervisor:    Option<String>,
+    pub job_title:     Option<String>,
+    /// Managing clinic; filled from the `clinic` table.
+    #[serde(default)]
+    pub clinic_no:     Option<String>,
+    /// Sites the provider works at; filled from `providersite`.
+    #[serde(default)]
+    pub site_ids:      Vec<String>,
+    /// `false` once `provider.status` goes to 0 or the row is deleted.
+    pub active:        bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_practitioner_role_links_default_empty() {
+        let json = r#"{
+            "provider_no": "1001",
+            "provider_type": "doctor",
+            "specialty": null,
+            "team": null,
+            "supervisor": null,
+            "job_title": null,
+            "active": true
+        }"#;
+
+        let r: DomainPractitionerRole = serde_json::from_str(json).unwrap();
+        assert_eq!(r.provider_type.as_deref(), Some("doctor"));
+        assert_eq!(r.clinic_no, None);
+        assert!(r.site_ids.is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for the FHIR `Questionnaire` generated from an Oscar `eform`
+/// definition. Not built for `fid`s mapped to an externally maintained
+/// canonical in `oscar.eform_questionnaire_map`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainQuestionnaire {
+    /// `eform.fid`, used as the natural key.
+    pub fid:       String,
+    pub form_name: Option<String>,
+    /// `eform.subject`: the form's description line.
+    pub subject:   Option<String>,
+    /// Field names seen in `eform_values` for this form, in first-seen order.
+    #[serde(default)]
+    pub var_names: Vec<String>,
+    /// `false` once the form is disabled (`status` 0) or deleted.
+    pub active:    bool,
+}
+
+/// Domain model for a FHIR `QuestionnaireResponse` sourced from one
+/// `eform_data` row and its `eform_values` name/value pairs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainQuestionnaireResponse {
+    /// `eform_data.fdid`, used as the natural key.
+    pub fdid:                String,
+    pub fid:                 String,
+    pub form_name:           Option<String>,
+    pub demographic_no:      Option<String>,
+    /// `form_provider`: the provider who filled in the form.
+    pub provider_no:         Option<String>,
+    pub form_date:           Option<String>,
+    pub form_time:           Option<String>,
+    /// `status` 1; Oscar sets 0 when a form is deleted from the chart.
+    pub current:             bool,
+    /// `patient_independent` forms are not about a patient and get no subject.
+    pub patient_independent: bool,
+    #[serde(default)]
+    pub answers:             Vec<DomainQuestionnaireAnswer>,
+}
+
+/// One `eform_values` row.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DomainQuestionnaireAnswer {
+    pub var_name:  String,
+    pub var_value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_questionnaire_response_answers_default_empty() {
+        let json = r#"{
+            "fdid": "10",
+            "fid": "3",
+            "form_name": "PHQ-9",
+            "demographic_no": "123",
+            "provider_no": "1001",
+            "form_date": "2026-01-05",
+            "form_time": "10:15:00",
+            "current": true,
+            "patient_independent": false
+        }"#;
+
+        let qr: DomainQuestionnaireResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(qr.fdid, "10");
+        assert!(qr.answers.is_empty());
+    }
+}
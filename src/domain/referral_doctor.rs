@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// A patient's referring / family doctor, parsed from the XML fragment in
+/// `demographic.family_doctor` (`<rdohip>` billing number, `<rd>` name).
+///
+/// When the billing number matches a `professionalSpecialists.referralNo`
+/// the doctor is referenced as that specialist (`spec_id`); otherwise it is
+/// synced as an external `Practitioner` keyed on the billing number.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DomainReferralDoctor {
+    /// `<rdohip>`: MSP / OHIP billing number.
+    pub billing_no: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name:  Option<String>,
+    /// Set once resolved against `professionalSpecialists.referralNo`.
+    #[serde(default)]
+    pub spec_id:    Option<String>,
+}
+
+/// A family doctor synced as its own external `Practitioner`, keyed on its
+/// billing number. Only built from a doctor that has one.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DomainExternalDoctor {
+    pub billing_no: String,
+    pub first_name: Option<String>,
+    pub last_name:  Option<String>,
+}
+
+impl DomainReferralDoctor {
+    /// `None` without a billing number: there is nothing to key the
+    /// Practitioner on, and an empty one would match every such doctor.
+    pub fn to_external(&self) -> Option<DomainExternalDoctor> {
+        let billing_no = self.billing_no.as_deref().map(str::trim).filter(|b| !b.is_empty())?;
+        Some(DomainExternalDoctor {
+            billing_no: billing_no.to_string(),
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_referral_doctor_spec_id_defaults_none() {
+        let json = r#"{
+            "billing_no": "12345",
+            "first_name": "John",
+            "last_name": "Smith"
+        }"#;
+
+        let d: DomainReferralDoctor = serde_json::from_str(json).unwrap();
+        assert_eq!(d.billing_no.as_deref(), Some("12345"));
+        assert_eq!(d.spec_id, None);
+    }
+
+    #[test]
+    fn test_external_doctor_needs_billing_number() {
+        let mut d = DomainReferralDoctor {
+            billing_no: Some(" 12345 ".to_string()),
+            first_name: None,
+            last_name: Some("Smith".to_string()),
+            spec_id: None,
+        };
+        assert_eq!(d.to_external().unwrap().billing_no, "12345");
+        d.billing_no = Some("  ".to_string());
+        assert!(d.to_external().is_none());
+        d.billing_no = None;
+        assert!(d.to_external().is_none());
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `RelatedPerson` sourced from Oscar's
+/// `DemographicContact` table (emergency contacts, substitute decision
+/// makers, next of kin).
+///
+/// `DemographicContact` only links a patient to a contact record; the
+/// person's name and phone numbers live in Oscar's `Contact` table
+/// (`contact_type` 2) or in another `demographic` row (`contact_type` 1).
+/// The mapper resolves those into the flat fields below. Internal providers
+/// (type 0) and professional specialists (type 3) are not RelatedPersons and
+/// never reach this struct.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainRelatedPerson {
+    /// `DemographicContact.id`, used as the natural key.
+    pub contact_id:       String,
+    pub demographic_no:   String,
+    /// Raw Oscar `DemographicContact.type` ("1" demographic, "2" contact).
+    pub contact_type:     String,
+    /// Set when the contact is itself an Oscar patient (type 1).
+    pub linked_demographic_no: Option<String>,
+    /// Free-text relationship as entered in Oscar, e.g. "Mother", "Spouse".
+    pub role:             Option<String>,
+    /// Oscar `category`: "personal" or "professional".
+    pub category:         Option<String>,
+    pub first_name:       Option<String>,
+    pub last_name:        Option<String>,
+    pub home_phone:       Option<String>,
+    pub work_phone:       Option<String>,
+    pub cell_phone:       Option<String>,
+    pub email:            Option<String>,
+    /// Substitute decision maker (`sdm`).
+    #[serde(default)]
+    pub substitute_decision_maker: bool,
+    /// Emergency contact (`ec`).
+    #[serde(default)]
+    pub emergency_contact: bool,
+    /// `false` once the link is soft-deleted or deactivated in Oscar.
+    #[serde(default)]
+    pub active:           bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_related_person_flags_default_false() {
+        let json = r#"{
+            "contact_id": "5",
+            "demographic_no": "123",
+            "contact_type": "2"
+        }"#;
+
+        let rp: DomainRelatedPerson = serde_json::from_str(json).unwrap();
+        assert_eq!(rp.contact_id, "5");
+        assert!(!rp.substitute_decision_maker);
+        assert!(!rp.emergency_contact);
+        assert!(!rp.active);
+        assert_eq!(rp.linked_demographic_no, None);
+    }
+}
@@ -1,19 +1,34 @@
+use serde::{Deserialize, Serialize};
+
 use crate::domain::appointment::DomainAppointment;
 use crate::domain::care_team::DomainCareTeam;
+use crate::domain::claim::{BillingSource, DomainChargeItem, DomainClaim};
+use crate::domain::communication::DomainCommunication;
 use crate::domain::condition::{DomainCondition, DomainFamilyMemberHistory};
 use crate::domain::diagnostic_report::DomainDiagnosticReport;
 use crate::domain::document_reference::DomainDocumentReference;
 use crate::domain::encounter::DomainEncounter;
+use crate::domain::episode_of_care::DomainEpisodeOfCare;
+use crate::domain::location::DomainLocation;
+use crate::domain::organization::DomainOrganization;
 use crate::domain::patient::DomainPatient;
 use crate::domain::practitioner::DomainPractitioner;
+use crate::domain::practitioner_role::DomainPractitionerRole;
+use crate::domain::questionnaire::{DomainQuestionnaire, DomainQuestionnaireResponse};
+use crate::domain::referral_doctor::DomainExternalDoctor;
+use crate::domain::related_person::DomainRelatedPerson;
+use crate::domain::schedule::{DomainSchedule, DomainSlot};
 use crate::domain::service_request::DomainServiceRequest;
+use crate::domain::specialist::DomainSpecialist;
+use crate::domain::waiting_list::DomainWaitingListEntry;
 use crate::event::ResourceType;
 
 /// Multi-resource payload carried by `SyncEvent`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum DomainResource {
-    Patient(DomainPatient),
+    Patient(Box<DomainPatient>),
     Practitioner(DomainPractitioner),
+    PractitionerRole(DomainPractitionerRole),
     Appointment(DomainAppointment),
     Encounter(DomainEncounter),
     DocumentReference(DomainDocumentReference),
@@ -22,6 +37,26 @@ pub enum DomainResource {
     FamilyMemberHistory(DomainFamilyMemberHistory),
     CareTeam(DomainCareTeam),
     ServiceRequest(DomainServiceRequest),
+    RelatedPerson(DomainRelatedPerson),
+    /// An external specialist; synced as a `Practitioner`.
+    Specialist(DomainSpecialist),
+    Organization(DomainOrganization),
+    Schedule(DomainSchedule),
+    Slot(DomainSlot),
+    EpisodeOfCare(DomainEpisodeOfCare),
+    Location(DomainLocation),
+    /// A patient's unresolved family doctor; synced as a `Practitioner`
+    /// keyed on the billing number.
+    ReferralDoctor(DomainExternalDoctor),
+    Questionnaire(DomainQuestionnaire),
+    QuestionnaireResponse(DomainQuestionnaireResponse),
+    Communication(DomainCommunication),
+    Claim(DomainClaim),
+    ChargeItem(DomainChargeItem),
+    /// A waiting-list entry published as a `waitlist` Appointment.
+    WaitingListAppointment(DomainWaitingListEntry),
+    /// A waiting-list entry published as a ServiceRequest.
+    WaitingListRequest(DomainWaitingListEntry),
 }
 
 impl DomainResource {
@@ -30,6 +65,7 @@ impl DomainResource {
         match self {
             DomainResource::Patient(_) => ResourceType::Patient,
             DomainResource::Practitioner(_) => ResourceType::Practitioner,
+            DomainResource::PractitionerRole(_) => ResourceType::PractitionerRole,
             DomainResource::Appointment(_) => ResourceType::Appointment,
             DomainResource::Encounter(_) => ResourceType::Encounter,
             DomainResource::DocumentReference(_) => ResourceType::DocumentReference,
@@ -38,6 +74,21 @@ impl DomainResource {
             DomainResource::FamilyMemberHistory(_) => ResourceType::FamilyMemberHistory,
             DomainResource::CareTeam(_) => ResourceType::CareTeam,
             DomainResource::ServiceRequest(_) => ResourceType::ServiceRequest,
+            DomainResource::RelatedPerson(_) => ResourceType::RelatedPerson,
+            DomainResource::Specialist(_) => ResourceType::Practitioner,
+            DomainResource::Organization(_) => ResourceType::Organization,
+            DomainResource::Schedule(_) => ResourceType::Schedule,
+            DomainResource::Slot(_) => ResourceType::Slot,
+            DomainResource::EpisodeOfCare(_) => ResourceType::EpisodeOfCare,
+            DomainResource::Location(_) => ResourceType::Location,
+            DomainResource::ReferralDoctor(_) => ResourceType::Practitioner,
+            DomainResource::Questionnaire(_) => ResourceType::Questionnaire,
+            DomainResource::QuestionnaireResponse(_) => ResourceType::QuestionnaireResponse,
+            DomainResource::Communication(_) => ResourceType::Communication,
+            DomainResource::Claim(_) => ResourceType::Claim,
+            DomainResource::ChargeItem(_) => ResourceType::ChargeItem,
+            DomainResource::WaitingListAppointment(_) => ResourceType::Appointment,
+            DomainResource::WaitingListRequest(_) => ResourceType::ServiceRequest,
         }
     }
 
@@ -47,6 +98,7 @@ impl DomainResource {
         match self {
             DomainResource::Patient(p) => &p.demographic_no,
             DomainResource::Practitioner(p) => &p.provider_no,
+            DomainResource::PractitionerRole(r) => &r.provider_no,
             DomainResource::Appointment(a) => &a.appointment_no,
             DomainResource::Encounter(e) => e.uuid.as_deref().unwrap_or(&e.note_id),
             DomainResource::DocumentReference(d) => d.uuid.as_deref().unwrap_or(&d.note_id),
@@ -55,6 +107,20 @@ impl DomainResource {
             DomainResource::FamilyMemberHistory(f) => &f.note_id,
             DomainResource::CareTeam(c) => &c.demographic_no,
             DomainResource::ServiceRequest(r) => &r.request_id,
+            DomainResource::RelatedPerson(r) => &r.contact_id,
+            DomainResource::Specialist(s) => &s.spec_id,
+            DomainResource::Organization(o) => &o.source_id,
+            DomainResource::Schedule(s) => &s.provider_no,
+            DomainResource::Slot(s) => &s.slot_id,
+            DomainResource::EpisodeOfCare(e) => &e.admission_id,
+            DomainResource::Location(l) => &l.site_id,
+            DomainResource::ReferralDoctor(d) => &d.billing_no,
+            DomainResource::Questionnaire(q) => &q.fid,
+            DomainResource::QuestionnaireResponse(r) => &r.fdid,
+            DomainResource::Communication(c) => &c.message_id,
+            DomainResource::Claim(c) => &c.claim_id,
+            DomainResource::ChargeItem(c) => &c.item.line_id,
+            DomainResource::WaitingListAppointment(e) | DomainResource::WaitingListRequest(e) => &e.entry_id,
         }
     }
 
@@ -63,6 +129,7 @@ impl DomainResource {
         match self {
             DomainResource::Patient(_) => "demographic",
             DomainResource::Practitioner(_) => "provider",
+            DomainResource::PractitionerRole(_) => "provider",
             DomainResource::Appointment(_) => "appointment",
             DomainResource::Encounter(_) => "casemgmt_note",
             DomainResource::DocumentReference(_) => "casemgmt_note",
@@ -74,6 +141,65 @@ impl DomainResource {
             DomainResource::FamilyMemberHistory(_) => "casemgmt_note",
             DomainResource::CareTeam(_) => "demographic",
             DomainResource::ServiceRequest(_) => "consultationRequests",
+            DomainResource::RelatedPerson(_) => "DemographicContact",
+            DomainResource::Specialist(_) => "professionalSpecialists",
+            DomainResource::Organization(o) => match o.source_table.as_str() {
+                "program" => "program",
+                "clinic" => "clinic",
+                _ => "professionalSpecialists",
+            },
+            DomainResource::Schedule(_) => "scheduledate",
+            DomainResource::Slot(_) => "scheduledate",
+            DomainResource::EpisodeOfCare(_) => "admission",
+            DomainResource::Location(_) => "site",
+            DomainResource::ReferralDoctor(_) => "demographic",
+            DomainResource::Questionnaire(_) => "eform",
+            DomainResource::QuestionnaireResponse(_) => "eform_data",
+            DomainResource::Communication(_) => "messagetbl",
+            DomainResource::Claim(c) => c.source.header_table(),
+            DomainResource::ChargeItem(c) => match c.source {
+                BillingSource::Bc => "billingmaster",
+                BillingSource::On => "billing_on_item",
+            },
+            DomainResource::WaitingListAppointment(_) | DomainResource::WaitingListRequest(_) => "waitingList",
+        }
+    }
+
+    /// Key whose events must be synced in order: the patient for
+    /// chart-scoped resources, the provider for practitioner-scoped ones,
+    /// otherwise the resource itself.
+    pub fn ordering_key(&self) -> String {
+        let patient = match self {
+            DomainResource::Patient(p) => Some(p.demographic_no.as_str()),
+            DomainResource::CareTeam(c) => Some(c.demographic_no.as_str()),
+            DomainResource::Condition(c) => Some(c.demographic_no.as_str()),
+            DomainResource::FamilyMemberHistory(f) => Some(f.demographic_no.as_str()),
+            DomainResource::DiagnosticReport(r) => Some(r.demographic_no.as_str()),
+            DomainResource::DocumentReference(d) => Some(d.demographic_no.as_str()),
+            DomainResource::Encounter(e) => Some(e.demographic_no.as_str()),
+            DomainResource::EpisodeOfCare(e) => Some(e.demographic_no.as_str()),
+            DomainResource::RelatedPerson(r) => Some(r.demographic_no.as_str()),
+            DomainResource::Claim(c) => Some(c.demographic_no.as_str()),
+            DomainResource::ChargeItem(c) => Some(c.demographic_no.as_str()),
+            DomainResource::WaitingListAppointment(e) | DomainResource::WaitingListRequest(e) => {
+                Some(e.demographic_no.as_str())
+            }
+            DomainResource::Appointment(a) => a.demographic_no.as_deref(),
+            DomainResource::ServiceRequest(r) => r.demographic_no.as_deref(),
+            DomainResource::QuestionnaireResponse(r) => r.demographic_no.as_deref(),
+            DomainResource::Communication(c) => c.demographic_nos.first().map(String::as_str),
+            _ => None,
+        };
+        if let Some(demographic_no) = patient.filter(|d| !d.is_empty()) {
+            return format!("patient/{demographic_no}");
+        }
+
+        match self {
+            DomainResource::Practitioner(p) => format!("provider/{}", p.provider_no),
+            DomainResource::PractitionerRole(r) => format!("provider/{}", r.provider_no),
+            DomainResource::Schedule(s) => format!("provider/{}", s.provider_no),
+            DomainResource::Slot(s) => format!("provider/{}", s.provider_no),
+            _ => format!("{}/{}", self.source_table(), self.source_id()),
         }
     }
 }
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a provider's FHIR `Schedule`. Oscar has no schedule
+/// entity of its own; one Schedule per provider anchors the `Slot`s expanded
+/// from `scheduledate` + `scheduletemplate`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainSchedule {
+    pub provider_no: String,
+}
+
+/// Availability of one template cell, mirroring FHIR `Slot.status`.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SlotStatus {
+    #[serde(rename = "free")]
+    Free,
+    #[serde(rename = "busy")]
+    Busy,
+    /// The provider-day was withdrawn (`scheduledate` deleted or unavailable).
+    #[serde(rename = "busy-unavailable")]
+    BusyUnavailable,
+}
+
+impl SlotStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SlotStatus::Free => "free",
+            SlotStatus::Busy => "busy",
+            SlotStatus::BusyUnavailable => "busy-unavailable",
+        }
+    }
+}
+
+/// One cell of a provider-day template, expanded into a FHIR `Slot`.
+///
+/// Times are naive local wall-clock strings, like `DomainAppointment`;
+/// conversion to an aware instant happens in the sink (D5).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainSlot {
+    /// `{provider_no}:{YYYY-MM-DD}:{HHMM}`, stable across re-expansion.
+    pub slot_id:     String,
+    pub provider_no: String,
+    pub start_date:  String, // "YYYY-MM-DD"
+    pub start_time:  String, // "HH:MM:SS"
+    pub end_date:    String, // "YYYY-MM-DD"; differs from start_date for the last cell
+    pub end_time:    String, // "HH:MM:SS"
+    pub status:      SlotStatus,
+    /// The `scheduletemplatecode.code` character for this cell.
+    pub code:        String,
+    pub description: Option<String>,
+}
@@ -23,6 +23,7 @@ pub struct DomainServiceRequest {
     pub referal_date:   Option<String>, // ISO 8601 date, "YYYY-MM-DD"
     pub urgency:        Option<String>, // raw Oscar urgency code
     pub status:         Option<String>, // raw Oscar status code (case-sensitive)
+    pub spec_id:        Option<String>, // `professionalSpecialists.specId`, the performer
 }
 
 #[cfg(test)]
@@ -40,6 +41,7 @@ mod tests {
             referal_date: None,
             urgency: None,
             status: None,
+            spec_id: None,
         };
         assert_eq!(r.request_id, "7");
     }
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for a FHIR `Practitioner` sourced from Oscar's
+/// `professionalSpecialists` address book — the external specialists that
+/// `consultationRequests.specId` refers to.
+///
+/// These are not Oscar users, so they are keyed on `spec_id` under their
+/// own identifier system rather than sharing `provider_no`'s.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainSpecialist {
+    pub spec_id:      String,
+    pub first_name:   Option<String>,
+    pub last_name:    Option<String>,
+    /// Post-nominal letters, e.g. "MD, FRCPC".
+    pub pro_letters:  Option<String>,
+    /// Free-text `specType`, e.g. "Cardiology".
+    pub specialty:    Option<String>,
+    /// Free-text, possibly multi-line clinic address.
+    pub address:      Option<String>,
+    pub phone:        Option<String>,
+    pub fax:          Option<String>,
+    pub email:        Option<String>,
+    /// `referralNo`: the specialist's MSP billing / CPSID number.
+    pub referral_no:  Option<String>,
+    /// `false` when the row is soft-deleted or hidden from Oscar's picker.
+    pub active:       bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_specialist_minimal() {
+        let s = DomainSpecialist {
+            spec_id: "12".to_string(),
+            first_name: None,
+            last_name: None,
+            pro_letters: None,
+            specialty: None,
+            address: None,
+            phone: None,
+            fax: None,
+            email: None,
+            referral_no: None,
+            active: true,
+        };
+        assert_eq!(s.spec_id, "12");
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// Domain model for one Oscar waiting-list entry (`waitingList` row),
+/// joined to its list (`waitingListName`). Published as a `waitlist`
+/// Appointment or an active ServiceRequest, per `oscar.waiting_list_resource`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DomainWaitingListEntry {
+    /// `waitingList.id`, used as the natural key.
+    pub entry_id: String,
+    /// `waitingList.listID`.
+    pub list_id: String,
+    /// `waitingListName.name`.
+    #[serde(default)]
+    pub list_name: Option<String>,
+    /// `waitingListName.provider_no`: the provider who owns the list.
+    #[serde(default)]
+    pub list_provider_no: Option<String>,
+    pub demographic_no: String,
+    /// `position`: 1 is next in line.
+    pub position: Option<u32>,
+    pub note: Option<String>,
+    /// `onListSince`, as stored.
+    pub on_list_since: Option<String>,
+    pub state: WaitingListState,
+}
+
+/// Where an entry stands. Oscar only records that an entry left the list
+/// (`is_history = 'Y'` or a deleted row); `Booked` is inferred from an
+/// appointment made for the patient after they were listed.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum WaitingListState {
+    Waiting,
+    Booked { appointment_no: String },
+    Removed,
+}
+
+impl DomainWaitingListEntry {
+    pub fn is_waiting(&self) -> bool {
+        self.state == WaitingListState::Waiting
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn booked_state_round_trips() {
+        let json = r#"{
+            "entry_id": "5",
+            "list_id": "2",
+            "demographic_no": "123",
+            "position": 1,
+            "note": null,
+            "on_list_since": "2026-01-10 09:00:00",
+            "state": { "Booked": { "appointment_no": "88" } }
+        }"#;
+        let e: DomainWaitingListEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(e.state, WaitingListState::Booked { appointment_no: "88".to_string() });
+        assert!(!e.is_waiting());
+        assert_eq!(e.list_name, None);
+    }
+}
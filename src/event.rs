@@ -1,5 +1,7 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::config::ColumnOverride;
 use crate::domain::resource::DomainResource;
 
 /// Where a `SyncEvent` originated. See D1 in TASK_FEATURES_SPEC_OSCAR_1.md.
@@ -7,7 +9,7 @@ use crate::domain::resource::DomainResource;
 /// The `OscarBinlog` and `OscarBackfill` variants carry the source Oscar table
 /// name, which is needed for the `oscar:{table}:{source_id}` idempotency-key
 /// convention.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Source {
     OscarBinlog { table: String },
     OscarBackfill { table: String },
@@ -36,7 +38,7 @@ impl Source {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Op {
     Upsert,
     Delete,
@@ -55,6 +57,7 @@ impl Op {
 pub enum ResourceType {
     Patient,
     Practitioner,
+    PractitionerRole,
     Appointment,
     Encounter,
     DocumentReference,
@@ -63,6 +66,17 @@ pub enum ResourceType {
     FamilyMemberHistory,
     CareTeam,
     ServiceRequest,
+    RelatedPerson,
+    Organization,
+    Schedule,
+    Slot,
+    EpisodeOfCare,
+    Location,
+    Questionnaire,
+    QuestionnaireResponse,
+    Communication,
+    Claim,
+    ChargeItem,
 }
 
 impl ResourceType {
@@ -71,6 +85,7 @@ impl ResourceType {
         match self {
             ResourceType::Patient => "Patient",
             ResourceType::Practitioner => "Practitioner",
+            ResourceType::PractitionerRole => "PractitionerRole",
             ResourceType::Appointment => "Appointment",
             ResourceType::Encounter => "Encounter",
             ResourceType::DocumentReference => "DocumentReference",
@@ -79,6 +94,17 @@ impl ResourceType {
             ResourceType::FamilyMemberHistory => "FamilyMemberHistory",
             ResourceType::CareTeam => "CareTeam",
             ResourceType::ServiceRequest => "ServiceRequest",
+            ResourceType::RelatedPerson => "RelatedPerson",
+            ResourceType::Organization => "Organization",
+            ResourceType::Schedule => "Schedule",
+            ResourceType::Slot => "Slot",
+            ResourceType::EpisodeOfCare => "EpisodeOfCare",
+            ResourceType::Location => "Location",
+            ResourceType::Questionnaire => "Questionnaire",
+            ResourceType::QuestionnaireResponse => "QuestionnaireResponse",
+            ResourceType::Communication => "Communication",
+            ResourceType::Claim => "Claim",
+            ResourceType::ChargeItem => "ChargeItem",
         }
     }
 
@@ -87,6 +113,7 @@ impl ResourceType {
         match self {
             ResourceType::Patient => "Patient",
             ResourceType::Practitioner => "Practitioner",
+            ResourceType::PractitionerRole => "PractitionerRole",
             ResourceType::Appointment => "Appointment",
             ResourceType::Encounter => "Encounter",
             ResourceType::DocumentReference => "DocumentReference",
@@ -95,10 +122,29 @@ impl ResourceType {
             ResourceType::FamilyMemberHistory => "FamilyMemberHistory",
             ResourceType::CareTeam => "CareTeam",
             ResourceType::ServiceRequest => "ServiceRequest",
+            ResourceType::RelatedPerson => "RelatedPerson",
+            ResourceType::Organization => "Organization",
+            ResourceType::Schedule => "Schedule",
+            ResourceType::Slot => "Slot",
+            ResourceType::EpisodeOfCare => "EpisodeOfCare",
+            ResourceType::Location => "Location",
+            ResourceType::Questionnaire => "Questionnaire",
+            ResourceType::QuestionnaireResponse => "QuestionnaireResponse",
+            ResourceType::Communication => "Communication",
+            ResourceType::Claim => "Claim",
+            ResourceType::ChargeItem => "ChargeItem",
         }
     }
 }
 
+/// A configured custom column's value from the row behind an event, for the
+/// sink to apply after the standard mapping.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ColumnOverrideValue {
+    pub spec: ColumnOverride,
+    pub value: String,
+}
+
 /// Canonical multi-resource event envelope (D1).
 ///
 /// Construction must go through `SyncEvent::new` so that `resource_type` and
@@ -113,6 +159,14 @@ pub struct SyncEvent {
     pub(crate) idempotency_key: String,
     pub(crate) payload: DomainResource,
     pub(crate) occurred_at: DateTime<Utc>,
+    pub(crate) column_values: Vec<ColumnOverrideValue>,
+    /// Position in the durable queue (`sync.durable_queue`) the event was
+    /// read from, acknowledged once the sink is done with it.
+    pub(crate) queue_offset: Option<u64>,
+    /// The parking-lot entry (`sync.parking_path`) a replayed event was
+    /// released from: the reference it waited on and when it was parked.
+    /// Removed from the lot once the sink is done with the replay.
+    pub(crate) parked: Option<(String, DateTime<Utc>)>,
 }
 
 impl SyncEvent {
@@ -147,9 +201,32 @@ impl SyncEvent {
             idempotency_key,
             payload,
             occurred_at,
+            column_values: Vec::new(),
+            queue_offset: None,
+            parked: None,
         }
     }
 
+    /// Attaches custom column values (`oscar.column_overrides`) read from
+    /// the row behind this event.
+    pub fn with_column_values(mut self, column_values: Vec<ColumnOverrideValue>) -> Self {
+        self.column_values = column_values;
+        self
+    }
+
+    /// Marks the event as read from `offset` in the durable queue.
+    pub fn with_queue_offset(mut self, offset: u64) -> Self {
+        self.queue_offset = Some(offset);
+        self
+    }
+
+    /// Marks the event as a replay of the entry parked on `reference` at
+    /// `parked_at`.
+    pub(crate) fn with_parked(mut self, reference: String, parked_at: DateTime<Utc>) -> Self {
+        self.parked = Some((reference, parked_at));
+        self
+    }
+
     pub fn source(&self) -> &Source {
         &self.source
     }
@@ -173,4 +250,16 @@ impl SyncEvent {
     pub fn occurred_at(&self) -> DateTime<Utc> {
         self.occurred_at
     }
+
+    pub fn column_values(&self) -> &[ColumnOverrideValue] {
+        &self.column_values
+    }
+
+    pub fn queue_offset(&self) -> Option<u64> {
+        self.queue_offset
+    }
+
+    pub(crate) fn parked(&self) -> Option<(&str, DateTime<Utc>)> {
+        self.parked.as_ref().map(|(reference, at)| (reference.as_str(), *at))
+    }
 }
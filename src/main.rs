@@ -1,3 +1,7 @@
+// Resources are built field by field on a `Default` value, and the source
+// and writeback loops pass their state as plain arguments.
+#![allow(clippy::field_reassign_with_default, clippy::too_many_arguments)]
+
 use tokio::{select, signal, sync::mpsc};
 use tracing::{info, error};
 
@@ -17,10 +21,14 @@ pub mod backfill;
 pub mod metrics;
 pub mod replication;
 pub mod dispatch;
+pub mod queue;
+pub mod terminology;
 mod writeback;
 
 mod auth;
 
+// Generated by tonic_build.
+#[allow(clippy::all)]
 pub mod proto;
 
 
@@ -30,7 +38,10 @@ async fn main() -> anyhow::Result<()> {
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
         .init();
 
-    let cfg = config::load_config()?;
+    let mut cfg = config::load_config()?;
+    if std::env::args().any(|a| a == "--force-resync") {
+        cfg.sync.force_resync = true;
+    }
 
     if cfg.oscar_enabled && cfg.database.host.is_empty() {
         anyhow::bail!("oscar_enabled = true but [database] is missing or has no host");
@@ -38,6 +49,12 @@ async fn main() -> anyhow::Result<()> {
 
     if cfg.oscar_enabled {
         mapping::dxresearch::load_diagnostic_codes(&cfg.database, &cfg.oscar).await?;
+        terminology::load_terminology(&cfg.terminology)?;
+    }
+
+    // Before anything clones `cfg`: the maps may be replaced from the server.
+    if cfg.oscar_enabled || cfg.writeback.enabled {
+        sink::fhir::sync_status_maps(&mut cfg).await;
     }
 
     let metrics = metrics::Metrics::new();
@@ -45,7 +62,15 @@ async fn main() -> anyhow::Result<()> {
 
     // Shared channel: producers (source, webhook) push events; the fhir
     // sink is the single consumer (D4). No tokio::broadcast in this phase.
-    let (tx, rx) = mpsc::channel::<event::SyncEvent>(1024);
+    // With `sync.durable_queue` producers append to disk instead and a
+    // reader feeds the sink from there.
+    let (tx, rx, queue) = if cfg.sync.durable_queue {
+        let queue = queue::DurableQueue::open(&cfg.sync)?;
+        (queue.sender(), queue.reader(), Some(queue))
+    } else {
+        let (tx, rx) = mpsc::channel::<event::SyncEvent>(1024);
+        (queue::EventSender::from(tx), rx, None)
+    };
 
     let never = || tokio::spawn(std::future::pending::<anyhow::Result<()>>());
 
@@ -64,7 +89,7 @@ async fn main() -> anyhow::Result<()> {
     // no consumer yet running.
 
     let sink_task = if cfg.oscar_enabled {
-        tokio::spawn(sink::fhir::run(cfg.clone(), rx, metrics.clone(), dispatch_tx.clone()))
+        tokio::spawn(sink::fhir::run(cfg.clone(), rx, metrics.clone(), dispatch_tx.clone(), queue))
     } else { never() };
 
     // `--backfill` snapshot mode. Captures the pre-scan
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use tracing::info;
+
+use crate::domain::episode_of_care::DomainEpisodeOfCare;
+use crate::mapping::syncable_provider;
+use crate::sources::RowChange;
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Maps one CAISI `admission` row to a `DomainEpisodeOfCare`, keyed on
+/// `am_id`.
+///
+/// Returns `None` if the row is missing its key, patient or program.
+pub fn row_to_domain_episode_of_care(change: &RowChange, columns: &ColumnMap) -> Option<DomainEpisodeOfCare> {
+    let (Some(admission_id), Some(demographic_no), Some(program_id)) = (
+        lookup(change, columns, "am_id"),
+        lookup(change, columns, "client_id"),
+        lookup(change, columns, "program_id"),
+    ) else {
+        info!("admission mapping: skipping row with no am_id, client_id or program_id");
+        return None;
+    };
+
+    Some(DomainEpisodeOfCare {
+        admission_id: admission_id.trim().to_string(),
+        demographic_no: demographic_no.trim().to_string(),
+        program_id: program_id.trim().to_string(),
+        provider_no: syncable_provider(lookup(change, columns, "provider_no")),
+        admission_date: lookup(change, columns, "admission_date").map(str::to_string),
+        discharge_date: lookup(change, columns, "discharge_date").map(str::to_string),
+        status: lookup(change, columns, "admission_status").map(|s| s.trim().to_ascii_lowercase()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::RowOp;
+
+    fn change(values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "admission".to_string(),
+            op: RowOp::Insert,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: crate::sources::SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns() -> ColumnMap {
+        [
+            "am_id", "client_id", "program_id", "provider_no", "admission_date", "discharge_date",
+            "admission_status",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.to_string(), i))
+        .collect()
+    }
+
+    #[test]
+    fn maps_admission_row() {
+        let e = row_to_domain_episode_of_care(
+            &change(vec![
+                Some("7"),
+                Some("123"),
+                Some("10016"),
+                Some("-1"),
+                Some("2024-01-02 09:30:00"),
+                None,
+                Some("Current"),
+            ]),
+            &columns(),
+        )
+        .unwrap();
+        assert_eq!(e.admission_id, "7");
+        assert_eq!(e.demographic_no, "123");
+        assert_eq!(e.program_id, "10016");
+        assert_eq!(e.provider_no, None);
+        assert_eq!(e.admission_date.as_deref(), Some("2024-01-02 09:30:00"));
+        assert_eq!(e.status.as_deref(), Some("current"));
+    }
+
+    #[test]
+    fn missing_program_is_skipped() {
+        let values = vec![Some("7"), Some("123"), None, None, None, None, None];
+        assert!(row_to_domain_episode_of_care(&change(values), &columns()).is_none());
+    }
+}
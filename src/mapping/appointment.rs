@@ -56,6 +56,8 @@ pub fn row_to_domain_appointment(change: &RowChange, columns: &ColumnMap) -> Opt
         urgency: lookup(change, columns, "urgency").map(str::to_string),
         createdatetime: lookup(change, columns, "createdatetime").map(str::to_string),
         location: lookup(change, columns, "location").map(str::to_string),
+        // Resolved against `site` by the caller; needs a live query.
+        site_id: None,
         booking_source: lookup_any(change, columns, &["bookingSource", "booking_source"]).map(str::to_string),
         type_: lookup(change, columns, "type").map(str::to_string),
     })
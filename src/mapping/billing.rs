@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+use tracing::info;
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::{BillingResource, DatabaseConfig, OscarConfig};
+use crate::domain::claim::{BillingSource, DomainClaim, DomainClaimItem};
+use crate::domain::resource::DomainResource;
+use crate::mapping::shared_pool;
+use crate::mapping::syncable_provider;
+use crate::sources::{RowChange, RowOp, SourcePosition};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Oscar writes `0` into `appointment_no` for claims not billed from the
+/// schedule.
+fn appointment_no(change: &RowChange, columns: &ColumnMap) -> Option<String> {
+    lookup(change, columns, "appointment_no")
+        .filter(|a| *a != "0")
+        .map(str::to_string)
+}
+
+/// Date part of a date or datetime column.
+fn date_part(value: &str) -> String {
+    value.get(..10).unwrap_or(value).to_string()
+}
+
+/// `billingmaster.service_date` is stored as `yyyyMMdd`; other billing dates
+/// are already ISO.
+fn normalize_service_date(value: &str) -> String {
+    if value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit()) {
+        format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..])
+    } else {
+        date_part(value)
+    }
+}
+
+/// Maps one billing header row (`billing` for BC, `billing_on_cheader1` for
+/// Ontario) to a `DomainClaim`. Lines and the Encounter link are loaded
+/// separately by `load_claim_items` and `resolve_claim_encounter`.
+///
+/// Returns `None` if the row has no id or no `demographic_no`.
+pub fn row_to_domain_claim(change: &RowChange, columns: &ColumnMap, source: BillingSource) -> Option<DomainClaim> {
+    let id_column = match source {
+        BillingSource::Bc => "billing_no",
+        BillingSource::On => "id",
+    };
+    let Some(claim_id) = lookup(change, columns, id_column) else {
+        info!("billing mapping: skipping {} row with no {id_column}", source.header_table());
+        return None;
+    };
+    let Some(demographic_no) = lookup(change, columns, "demographic_no").filter(|d| *d != "0") else {
+        info!("billing mapping: skipping {} {claim_id} with no demographic_no", source.header_table());
+        return None;
+    };
+
+    let service_date = lookup(change, columns, "billing_date").map(date_part);
+    let created = match source {
+        BillingSource::Bc => lookup(change, columns, "update_date"),
+        BillingSource::On => lookup(change, columns, "timestamp"),
+    }
+    .map(date_part)
+    .or_else(|| service_date.clone());
+
+    Some(DomainClaim {
+        claim_id: claim_id.to_string(),
+        source,
+        demographic_no: demographic_no.to_string(),
+        provider_no: syncable_provider(lookup(change, columns, "provider_no")),
+        appointment_no: appointment_no(change, columns),
+        encounter_value: None,
+        service_date,
+        created,
+        status_code: lookup(change, columns, "status").map(str::to_string),
+        total: lookup(change, columns, "total").map(str::to_string),
+        items: Vec::new(),
+        active: change.op != RowOp::Delete,
+    })
+}
+
+/// Loads the claim's service lines. BC lines carry up to three diagnostic
+/// codes and their own MSP status; Ontario lines up to three codes and an
+/// item status.
+pub async fn load_claim_items(db: &DatabaseConfig, claim: &mut DomainClaim) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load billing lines")?;
+
+    let query = match claim.source {
+        BillingSource::Bc => {
+            "SELECT billingmaster_no, billing_code, dx_code1, dx_code2, dx_code3, bill_amount, billing_unit, \
+             service_date, billingstatus FROM billingmaster WHERE billing_no = :claim_id ORDER BY billingmaster_no"
+        }
+        BillingSource::On => {
+            "SELECT id, service_code, dx, dx1, dx2, fee, ser_num, service_date, status \
+             FROM billing_on_item WHERE ch1_id = :claim_id ORDER BY id"
+        }
+    };
+    let rows: Vec<Row> = conn
+        .exec(query, params! { "claim_id" => &claim.claim_id })
+        .await
+        .context("selecting billing lines")?;
+
+    drop(conn);
+
+    claim.items = rows
+        .into_iter()
+        .filter_map(|row| {
+            let values: Vec<Option<String>> = row
+                .unwrap()
+                .iter()
+                .map(|v| mysql_value_to_string(v).map(|s| s.trim().to_string()).filter(|s| !s.is_empty()))
+                .collect();
+            let mut values = values.into_iter();
+            let mut next = move || values.next().flatten();
+            let line_id = next()?;
+            let service_code = next()?;
+            let dx_codes = [next(), next(), next()].into_iter().flatten().collect();
+            Some(DomainClaimItem {
+                line_id,
+                service_code,
+                dx_codes,
+                fee: next(),
+                units: next(),
+                service_date: next().as_deref().map(normalize_service_date),
+                status_code: next(),
+            })
+        })
+        .collect();
+    Ok(())
+}
+
+/// Links the claim to the Encounter Oscar recorded for its appointment: the
+/// latest `casemgmt_note` revision written against `appointment_no`, keyed
+/// the way the Encounter sink keys it (`uuid`, else `oscar-note-<note_id>`).
+pub async fn resolve_claim_encounter(db: &DatabaseConfig, claim: &mut DomainClaim) -> Result<()> {
+    let Some(appointment_no) = claim.appointment_no.clone() else {
+        return Ok(());
+    };
+
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to resolve claim encounter")?;
+
+    let row: Option<(Option<String>, String)> = conn
+        .exec_first(
+            "SELECT uuid, CAST(note_id AS CHAR) FROM casemgmt_note \
+             WHERE appointment_no = :appointment_no AND demographic_no = :demographic_no \
+             ORDER BY note_id DESC LIMIT 1",
+            params! { "appointment_no" => &appointment_no, "demographic_no" => &claim.demographic_no },
+        )
+        .await
+        .context("selecting casemgmt_note for claim appointment")?;
+
+    drop(conn);
+
+    claim.encounter_value = row.map(|(uuid, note_id)| {
+        uuid.filter(|u| !u.is_empty())
+            .unwrap_or_else(|| format!("oscar-note-{note_id}"))
+    });
+    Ok(())
+}
+
+/// Loads everything a mapped header needs before it is sent: its lines and
+/// its Encounter link.
+pub async fn load_claim_details(db: &DatabaseConfig, claim: &mut DomainClaim) -> Result<()> {
+    load_claim_items(db, claim).await?;
+    resolve_claim_encounter(db, claim).await
+}
+
+/// Re-reads a billing header and maps it with its current lines, so a line
+/// change refreshes the whole claim. `Ok(None)` if the header is gone.
+pub async fn reload_claim(
+    db: &DatabaseConfig,
+    header_columns: &ColumnMap,
+    source: BillingSource,
+    claim_id: &str,
+) -> Result<Option<DomainClaim>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to reload billing header")?;
+
+    let query = match source {
+        BillingSource::Bc => "SELECT * FROM billing WHERE billing_no = :claim_id",
+        BillingSource::On => "SELECT * FROM billing_on_cheader1 WHERE id = :claim_id",
+    };
+    let row: Option<Row> = conn
+        .exec_first(query, params! { "claim_id" => claim_id })
+        .await
+        .context("selecting billing header for refresh")?;
+
+    drop(conn);
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    let change = RowChange {
+        schema: db.schema.clone(),
+        table: source.header_table().to_string(),
+        op: RowOp::Update,
+        after: row.unwrap().iter().map(mysql_value_to_string).collect(),
+        position: SourcePosition::FilePos {
+            file: String::new(),
+            pos: 0,
+        },
+    };
+
+    let Some(mut claim) = row_to_domain_claim(&change, header_columns, source) else {
+        return Ok(None);
+    };
+    load_claim_details(db, &mut claim).await?;
+    Ok(Some(claim))
+}
+
+/// Wraps a loaded claim as the configured resource: one `Claim`, or one
+/// `ChargeItem` per line.
+pub fn claim_resources(claim: DomainClaim, oscar: &OscarConfig) -> Vec<DomainResource> {
+    match oscar.billing_resource {
+        BillingResource::Claim => vec![DomainResource::Claim(claim)],
+        BillingResource::ChargeItem => claim
+            .into_charge_items()
+            .into_iter()
+            .map(DomainResource::ChargeItem)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(table: &str, op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: table.to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns(names: &[&str]) -> ColumnMap {
+        names.iter().enumerate().map(|(i, n)| (n.to_string(), i)).collect()
+    }
+
+    #[test]
+    fn maps_bc_billing_header() {
+        let cols = columns(&[
+            "billing_no", "demographic_no", "provider_no", "appointment_no", "billing_date", "update_date", "total",
+            "status",
+        ]);
+        let claim = row_to_domain_claim(
+            &change(
+                "billing",
+                RowOp::Insert,
+                vec![
+                    Some("500"),
+                    Some("123"),
+                    Some("1001"),
+                    Some("0"),
+                    Some("2026-03-02"),
+                    Some("2026-03-04"),
+                    Some("60.00"),
+                    Some("O"),
+                ],
+            ),
+            &cols,
+            BillingSource::Bc,
+        )
+        .unwrap();
+
+        assert_eq!(claim.claim_id, "500");
+        assert_eq!(claim.appointment_no, None);
+        assert_eq!(claim.service_date.as_deref(), Some("2026-03-02"));
+        assert_eq!(claim.created.as_deref(), Some("2026-03-04"));
+        assert_eq!(claim.status_code.as_deref(), Some("O"));
+        assert!(claim.active);
+    }
+
+    #[test]
+    fn maps_on_billing_header_and_skips_unattached() {
+        let cols = columns(&["id", "demographic_no", "provider_no", "appointment_no", "billing_date", "status"]);
+        let claim = row_to_domain_claim(
+            &change(
+                "billing_on_cheader1",
+                RowOp::Delete,
+                vec![Some("42"), Some("123"), Some("-1"), Some("88"), Some("2026-03-02"), Some("B")],
+            ),
+            &cols,
+            BillingSource::On,
+        )
+        .unwrap();
+        assert_eq!(claim.claim_id, "42");
+        assert_eq!(claim.provider_no, None);
+        assert_eq!(claim.appointment_no.as_deref(), Some("88"));
+        assert_eq!(claim.created.as_deref(), Some("2026-03-02"));
+        assert!(!claim.active);
+
+        assert!(row_to_domain_claim(
+            &change("billing_on_cheader1", RowOp::Insert, vec![Some("43"), Some("0"), None, None, None, None]),
+            &cols,
+            BillingSource::On,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn normalizes_msp_service_dates() {
+        assert_eq!(normalize_service_date("20260302"), "2026-03-02");
+        assert_eq!(normalize_service_date("2026-03-02 00:00:00"), "2026-03-02");
+    }
+}
@@ -1,13 +1,33 @@
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
 use tracing::info;
 
-use crate::config::OscarConfig;
-use crate::domain::care_team::DomainCareTeam;
-use crate::mapping::demographic::{lookup, ColumnMap};
+use crate::config::{DatabaseConfig, OscarConfig};
+use crate::domain::care_team::{CareTeamMember, CareTeamRole, DomainCareTeam};
+use crate::mapping::demographic::{lookup, lookup_any, ColumnMap};
+use crate::mapping::demographic_contact::reload_demographic_row;
+use crate::mapping::referral_doctor::{find_specialist_by_referral_no, parse_family_doctor};
+use crate::mapping::shared_pool;
 use crate::mapping::syncable_provider;
 use crate::sources::RowChange;
 
-/// Maps a `demographic` row change into a `DomainCareTeam` for the patient's
-/// most-responsible provider (MRP), applying the D3 missing/unusable-MRP rules.
+/// Roles stored as a `provider_no`, by `demographic` column / `demographicExt`
+/// key (older schemas have the columns, newer ones the ext keys).
+const PROVIDER_ROLE_KEYS: &[(CareTeamRole, &str)] = &[
+    (CareTeamRole::Resident, "resident"),
+    (CareTeamRole::Nurse, "nurse"),
+    (CareTeamRole::Midwife, "midwife"),
+];
+
+/// True for the `demographicExt.key_val`s that feed the CareTeam.
+pub fn is_care_team_ext_key(key: &str) -> bool {
+    PROVIDER_ROLE_KEYS.iter().any(|(_, k)| *k == key)
+}
+
+/// Maps a `demographic` row change into a `DomainCareTeam` with a participant
+/// per role present on the row. The MRP applies the D3 missing/unusable-MRP
+/// rules; the referral doctor is keyed on its billing number until
+/// `load_care_team_roles` resolves it.
 pub fn row_to_domain_care_team(
     change: &RowChange,
     cols: &ColumnMap,
@@ -15,22 +35,101 @@ pub fn row_to_domain_care_team(
 ) -> Option<DomainCareTeam> {
     let demographic_no = lookup(change, cols, "demographic_no")?.to_string();
 
-    let provider_no = match syncable_provider(lookup(change, cols, "provider_no")) {
-        Some(p) => p,
-        None => oscar_cfg.default_mrp_provider_no.clone()?,
+    let mut care_team = DomainCareTeam {
+        demographic_no,
+        participants: Vec::new(),
     };
 
-    if provider_no.trim().is_empty() || provider_no == "-1" {
+    let mrp = syncable_provider(lookup(change, cols, "provider_no"))
+        .or_else(|| oscar_cfg.default_mrp_provider_no.clone())
+        .filter(|p| !p.trim().is_empty() && p != "-1");
+    if let Some(provider_no) = mrp {
+        care_team.fill_role(CareTeamRole::Mrp, CareTeamMember::Provider(provider_no));
+    }
+
+    for (role, column) in PROVIDER_ROLE_KEYS {
+        if let Some(provider_no) = syncable_provider(lookup(change, cols, column)) {
+            care_team.fill_role(*role, CareTeamMember::Provider(provider_no));
+        }
+    }
+
+    let referral_billing_no = lookup_any(change, cols, &["family_doctor", "family_physician"])
+        .and_then(parse_family_doctor)
+        .and_then(|d| d.billing_no);
+    if let Some(billing_no) = referral_billing_no {
+        care_team.fill_role(CareTeamRole::ReferralDoctor, CareTeamMember::ReferralDoctor(billing_no));
+    }
+
+    if care_team.participants.is_empty() {
         info!(
-            "care_team: demographic_no={demographic_no} has no usable MRP and no fallback; skipping CareTeam"
+            "care_team: demographic_no={} has no usable MRP, no other care-team roles and no fallback; skipping CareTeam",
+            care_team.demographic_no
         );
         return None;
     }
 
-    Some(DomainCareTeam {
-        demographic_no,
-        provider_no,
-    })
+    Some(care_team)
+}
+
+/// Completes a row-mapped CareTeam from the database: fills roles kept in
+/// `demographicExt` (latest entry per key) and re-keys a referral doctor
+/// that matches a `professionalSpecialists` entry onto that specialist.
+pub async fn load_care_team_roles(db: &DatabaseConfig, care_team: &mut DomainCareTeam) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load demographicExt care-team roles")?;
+
+    let rows: Vec<(String, Option<String>)> = conn
+        .exec(
+            "SELECT key_val, value FROM demographicExt \
+             WHERE demographic_no = :demographic_no AND key_val IN ('resident', 'nurse', 'midwife') \
+             ORDER BY date_time DESC, id DESC",
+            params! { "demographic_no" => &care_team.demographic_no },
+        )
+        .await
+        .context("selecting demographicExt care-team roles")?;
+
+    drop(conn);
+
+    for (role, key) in PROVIDER_ROLE_KEYS {
+        // Only the latest entry per key counts, even if it clears the role.
+        let latest = rows.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_deref());
+        if let Some(provider_no) = latest.and_then(syncable_provider) {
+            care_team.fill_role(*role, CareTeamMember::Provider(provider_no));
+        }
+    }
+
+    for participant in &mut care_team.participants {
+        let CareTeamMember::ReferralDoctor(billing_no) = &participant.member else {
+            continue;
+        };
+        if let Some(spec_id) = find_specialist_by_referral_no(db, billing_no).await? {
+            participant.member = CareTeamMember::Specialist(spec_id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-reads a patient's `demographic` row and rebuilds its CareTeam, so a
+/// `demographicExt` role change refreshes the CareTeam. `Ok(None)` if the
+/// demographic is gone or has no care-team roles.
+pub async fn reload_care_team(
+    db: &DatabaseConfig,
+    demographic_columns: &ColumnMap,
+    oscar_cfg: &OscarConfig,
+    demographic_no: &str,
+) -> Result<Option<DomainCareTeam>> {
+    let Some(change) = reload_demographic_row(db, demographic_no).await? else {
+        return Ok(None);
+    };
+    let Some(mut care_team) = row_to_domain_care_team(&change, demographic_columns, oscar_cfg) else {
+        return Ok(None);
+    };
+    load_care_team_roles(db, &mut care_team).await?;
+    Ok(Some(care_team))
 }
 
 #[cfg(test)]
@@ -68,18 +167,44 @@ mod tests {
             default_appointment_type: None,
             default_program_id: None,
             care_team_enabled: true,
+            related_person_enabled: true,
+            schedule_enabled: true,
+            schedule_horizon_days: 90,
+            schedule_unavailable_codes: vec!["_".to_string()],
+            practitioner_role_enabled: true,
+            provider_type_role_map: OscarConfig::default().provider_type_role_map,
+            eform_enabled: true,
+            eform_questionnaire_map: Default::default(),
+            communication_enabled: true,
+            exclude_unlinked_messages: true,
+            billing_enabled: true,
+            billing_resource: Default::default(),
+            billing_status_map: Default::default(),
+            waiting_list_enabled: true,
+            waiting_list_resource: Default::default(),
+            patient_photo_enabled: true,
+            patient_photo_max_bytes: 262_144,
+            patient_photo_thumbnail_px: None,
+            column_overrides: Vec::new(),
             consult_response_status_map: OscarConfig::default().consult_response_status_map,
             consult_request_status_map: OscarConfig::default().consult_request_status_map,
         }
     }
 
+    fn mrp(ct: &DomainCareTeam) -> Option<&str> {
+        match ct.member_for(CareTeamRole::Mrp)? {
+            CareTeamMember::Provider(p) => Some(p),
+            _ => None,
+        }
+    }
+
     #[test]
     fn valid_mrp_maps_to_participant() {
         let cols = columns();
         let row = change(vec![Some("123"), Some("999998")]);
         let ct = row_to_domain_care_team(&row, &cols, &cfg(None)).unwrap();
         assert_eq!(ct.demographic_no, "123");
-        assert_eq!(ct.provider_no, "999998");
+        assert_eq!(mrp(&ct), Some("999998"));
     }
 
     #[test]
@@ -87,7 +212,7 @@ mod tests {
         let cols = columns();
         let row = change(vec![Some("123"), Some("ABC12")]);
         let ct = row_to_domain_care_team(&row, &cols, &cfg(None)).unwrap();
-        assert_eq!(ct.provider_no, "ABC12");
+        assert_eq!(mrp(&ct), Some("ABC12"));
     }
 
     #[test]
@@ -116,7 +241,7 @@ mod tests {
         let cols = columns();
         let row = change(vec![Some("123"), Some("-1")]);
         let ct = row_to_domain_care_team(&row, &cols, &cfg(Some("999998"))).unwrap();
-        assert_eq!(ct.provider_no, "999998");
+        assert_eq!(mrp(&ct), Some("999998"));
     }
 
     #[test]
@@ -124,7 +249,7 @@ mod tests {
         let cols = columns();
         let row = change(vec![Some("123"), None]);
         let ct = row_to_domain_care_team(&row, &cols, &cfg(Some("999998"))).unwrap();
-        assert_eq!(ct.provider_no, "999998");
+        assert_eq!(mrp(&ct), Some("999998"));
     }
 
     #[test]
@@ -132,6 +257,57 @@ mod tests {
         let cols = columns();
         let row = change(vec![Some("123"), Some("")]);
         let ct = row_to_domain_care_team(&row, &cols, &cfg(Some("999998"))).unwrap();
-        assert_eq!(ct.provider_no, "999998");
+        assert_eq!(mrp(&ct), Some("999998"));
+    }
+
+    #[test]
+    fn maps_a_participant_per_role() {
+        let cols: ColumnMap = ["demographic_no", "provider_no", "resident", "nurse", "midwife", "family_doctor"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.to_string(), i))
+            .collect();
+        let row = change(vec![
+            Some("123"),
+            Some("101"),
+            Some("201"),
+            Some("-1"),
+            Some("301"),
+            Some("<rdohip>12345</rdohip><rd>Smith, John</rd>"),
+        ]);
+        let ct = row_to_domain_care_team(&row, &cols, &cfg(None)).unwrap();
+        assert_eq!(ct.participants.len(), 4);
+        assert_eq!(mrp(&ct), Some("101"));
+        assert_eq!(
+            ct.member_for(CareTeamRole::Resident),
+            Some(&CareTeamMember::Provider("201".to_string()))
+        );
+        assert_eq!(ct.member_for(CareTeamRole::Nurse), None);
+        assert_eq!(
+            ct.member_for(CareTeamRole::Midwife),
+            Some(&CareTeamMember::Provider("301".to_string()))
+        );
+        assert_eq!(
+            ct.member_for(CareTeamRole::ReferralDoctor),
+            Some(&CareTeamMember::ReferralDoctor("12345".to_string()))
+        );
+    }
+
+    #[test]
+    fn other_roles_keep_care_team_without_mrp() {
+        let cols: ColumnMap = [("demographic_no", 0), ("provider_no", 1), ("nurse", 2)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let row = change(vec![Some("123"), Some("-1"), Some("201")]);
+        let ct = row_to_domain_care_team(&row, &cols, &cfg(None)).unwrap();
+        assert_eq!(mrp(&ct), None);
+        assert_eq!(ct.participants.len(), 1);
+    }
+
+    #[test]
+    fn recognises_care_team_ext_keys() {
+        assert!(is_care_team_ext_key("nurse"));
+        assert!(!is_care_team_ext_key("cellPhone"));
     }
 }
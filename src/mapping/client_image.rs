@@ -0,0 +1,183 @@
+use anyhow::{Context, Result};
+use base64ct::{Base64, Encoding};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+use tracing::{info, warn};
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::{DatabaseConfig, OscarConfig};
+use crate::domain::patient::DomainPatientPhoto;
+use crate::mapping::shared_pool;
+
+/// Loads a patient's most recent `client_image` row as a photo, capped at
+/// `oscar.patient_photo_max_bytes`. Oscar keeps old rows when a new photo is
+/// uploaded, so the latest `update_date` wins. `Ok(None)` if the patient has
+/// no photo, or it is too large and cannot be thumbnailed.
+pub async fn load_patient_photo(
+    db: &DatabaseConfig,
+    oscar: &OscarConfig,
+    demographic_no: &str,
+) -> Result<Option<DomainPatientPhoto>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load client_image")?;
+
+    let row: Option<Row> = conn
+        .exec_first(
+            "SELECT image_type, image_data, update_date FROM client_image \
+             WHERE demographic_no = :demographic_no \
+             ORDER BY update_date DESC, image_id DESC LIMIT 1",
+            params! { "demographic_no" => demographic_no },
+        )
+        .await
+        .context("selecting client_image")?;
+
+    drop(conn);
+
+    let Some(mut row) = row else {
+        return Ok(None);
+    };
+    let image_type: Option<String> = row.take::<Option<String>, _>(0).flatten();
+    let data: Vec<u8> = row.take::<Option<Vec<u8>>, _>(1).flatten().unwrap_or_default();
+    let update_date = row.as_ref(2).and_then(mysql_value_to_string);
+    if data.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(prepare_photo(demographic_no, data, image_type.as_deref(), update_date.as_deref(), oscar))
+}
+
+/// Applies the size cap (thumbnailing if configured) and encodes the image.
+fn prepare_photo(
+    demographic_no: &str,
+    data: Vec<u8>,
+    image_type: Option<&str>,
+    update_date: Option<&str>,
+    oscar: &OscarConfig,
+) -> Option<DomainPatientPhoto> {
+    let Some(mut content_type) = photo_content_type(image_type, &data) else {
+        info!("client_image mapping: skipping photo of unknown type for demographic_no={demographic_no}");
+        return None;
+    };
+
+    let mut data = data;
+    if data.len() > oscar.patient_photo_max_bytes {
+        let Some(max_px) = oscar.patient_photo_thumbnail_px else {
+            info!(
+                "client_image mapping: photo for demographic_no={demographic_no} is {} bytes, over the {} byte cap; omitting",
+                data.len(),
+                oscar.patient_photo_max_bytes
+            );
+            return None;
+        };
+        match thumbnail(&data, max_px) {
+            Ok(thumb) if thumb.len() <= oscar.patient_photo_max_bytes => {
+                data = thumb;
+                content_type = "image/jpeg";
+            }
+            Ok(thumb) => {
+                info!(
+                    "client_image mapping: thumbnail for demographic_no={demographic_no} is still {} bytes; omitting",
+                    thumb.len()
+                );
+                return None;
+            }
+            Err(e) => {
+                warn!("client_image mapping: failed to thumbnail photo for demographic_no={demographic_no}: {e:?}");
+                return None;
+            }
+        }
+    }
+
+    Some(DomainPatientPhoto {
+        content_type: content_type.to_string(),
+        size: data.len(),
+        data: Base64::encode_string(&data),
+        // `Attachment.creation` is a FHIR dateTime; the date alone needs no
+        // timezone.
+        creation: update_date.and_then(|d| d.get(..10)).map(str::to_string),
+    })
+}
+
+/// MIME type from `client_image.image_type` ("jpg", "png", or already a MIME
+/// type), falling back to the image's magic bytes.
+fn photo_content_type(image_type: Option<&str>, data: &[u8]) -> Option<&'static str> {
+    let declared = match image_type.map(|t| t.trim().to_ascii_lowercase()).as_deref() {
+        Some("jpg" | "jpeg" | "image/jpeg" | "image/jpg" | "image/pjpeg") => Some("image/jpeg"),
+        Some("png" | "image/png") => Some("image/png"),
+        Some("gif" | "image/gif") => Some("image/gif"),
+        Some("bmp" | "image/bmp") => Some("image/bmp"),
+        _ => None,
+    };
+    declared.or_else(|| {
+        if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some("image/jpeg")
+        } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some("image/png")
+        } else if data.starts_with(b"GIF8") {
+            Some("image/gif")
+        } else if data.starts_with(b"BM") {
+            Some("image/bmp")
+        } else {
+            None
+        }
+    })
+}
+
+/// Re-encodes the image as a JPEG no larger than `max_px` on its longest edge.
+#[cfg(feature = "photo-thumbnails")]
+fn thumbnail(data: &[u8], max_px: u32) -> Result<Vec<u8>> {
+    let image = image::load_from_memory(data).context("decoding client_image")?;
+    let thumb = image::DynamicImage::ImageRgb8(image.thumbnail(max_px, max_px).to_rgb8());
+    let mut out = std::io::Cursor::new(Vec::new());
+    thumb
+        .write_to(&mut out, image::ImageFormat::Jpeg)
+        .context("encoding thumbnail")?;
+    Ok(out.into_inner())
+}
+
+#[cfg(not(feature = "photo-thumbnails"))]
+fn thumbnail(_data: &[u8], _max_px: u32) -> Result<Vec<u8>> {
+    anyhow::bail!("oscar.patient_photo_thumbnail_px needs the `photo-thumbnails` feature")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JPEG: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+
+    #[test]
+    fn encodes_photo_under_cap() {
+        let photo = prepare_photo(
+            "123",
+            JPEG.to_vec(),
+            Some("jpg"),
+            Some("2026-01-05 10:15:00"),
+            &OscarConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(photo.content_type, "image/jpeg");
+        assert_eq!(photo.size, JPEG.len());
+        assert_eq!(Base64::decode_vec(&photo.data).unwrap(), JPEG);
+        assert_eq!(photo.creation.as_deref(), Some("2026-01-05"));
+    }
+
+    #[test]
+    fn oversized_or_unknown_photo_is_omitted() {
+        let oscar = OscarConfig {
+            patient_photo_max_bytes: 4,
+            ..Default::default()
+        };
+        assert_eq!(prepare_photo("123", JPEG.to_vec(), None, None, &oscar), None);
+
+        // Sniffed from the bytes when `image_type` is blank.
+        assert!(prepare_photo("123", JPEG.to_vec(), None, None, &OscarConfig::default()).is_some());
+        assert_eq!(
+            prepare_photo("123", b"not an image".to_vec(), Some("tiff"), None, &OscarConfig::default()),
+            None
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use tracing::info;
+
+use crate::domain::organization::DomainOrganization;
+use crate::sources::{RowChange, RowOp};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .filter(|s| !s.trim().is_empty())
+}
+
+fn text(change: &RowChange, columns: &ColumnMap, name: &str) -> Option<String> {
+    lookup(change, columns, name).map(|s| s.trim().to_string())
+}
+
+/// Maps Oscar's `clinic` row to the clinic `Organization`, keyed on
+/// `clinic_no`. Multi-site `Location`s name it as their managing
+/// organization.
+///
+/// Returns `None` if the row has no `clinic_no`.
+pub fn row_to_clinic_organization(change: &RowChange, columns: &ColumnMap) -> Option<DomainOrganization> {
+    let Some(clinic_no) = text(change, columns, "clinic_no") else {
+        info!("clinic mapping: skipping row with no clinic_no");
+        return None;
+    };
+
+    Some(DomainOrganization {
+        source_table: "clinic".to_string(),
+        source_id: clinic_no,
+        name: text(change, columns, "clinic_name"),
+        address_line: text(change, columns, "clinic_address"),
+        city: text(change, columns, "clinic_city"),
+        province: text(change, columns, "clinic_province"),
+        postal: text(change, columns, "clinic_postal"),
+        phone: text(change, columns, "clinic_phone"),
+        fax: text(change, columns, "clinic_fax"),
+        email: None,
+        active: change.op != RowOp::Delete,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_clinic_row() {
+        let cols: ColumnMap = ["clinic_no", "clinic_name", "clinic_city", "clinic_province", "clinic_phone"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.to_string(), i))
+            .collect();
+        let change = RowChange {
+            schema: "oscar".to_string(),
+            table: "clinic".to_string(),
+            op: RowOp::Update,
+            after: vec![Some("1"), Some("Main Street Clinic"), Some("Vancouver"), Some("BC"), Some("604-555-0100")]
+                .into_iter()
+                .map(|v| v.map(str::to_string))
+                .collect(),
+            position: crate::sources::SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        };
+
+        let org = row_to_clinic_organization(&change, &cols).unwrap();
+        assert_eq!(org.source_table, "clinic");
+        assert_eq!(org.source_id, "1");
+        assert_eq!(org.name.as_deref(), Some("Main Street Clinic"));
+        assert_eq!(org.province.as_deref(), Some("BC"));
+        assert!(org.active);
+    }
+}
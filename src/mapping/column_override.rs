@@ -0,0 +1,160 @@
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::{ColumnOverride, DatabaseConfig};
+use crate::domain::claim::BillingSource;
+use crate::domain::resource::DomainResource;
+use crate::event::{ColumnOverrideValue, ResourceType};
+use crate::mapping::demographic::{lookup, ColumnMap};
+use crate::mapping::shared_pool;
+use crate::sources::RowChange;
+
+/// Values of the overrides for `resource_type` whose column is on the
+/// changed row's table. Blank columns are left out.
+pub fn column_override_values(
+    overrides: &[ColumnOverride],
+    change: &RowChange,
+    columns: &ColumnMap,
+    resource_type: ResourceType,
+) -> Vec<ColumnOverrideValue> {
+    overrides
+        .iter()
+        .filter(|o| o.table() == change.table && o.resource == resource_type.as_path())
+        .filter_map(|o| {
+            let value = lookup(change, columns, o.column_name())?.trim();
+            (!value.is_empty()).then(|| ColumnOverrideValue {
+                spec: o.clone(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Re-reads the override columns for a resource rebuilt by a refresh, so
+/// re-sending it doesn't drop them. Only resources keyed on their source
+/// row's primary key can be re-read; others get no values.
+pub async fn load_column_override_values(
+    db: &DatabaseConfig,
+    overrides: &[ColumnOverride],
+    resource: &DomainResource,
+) -> Result<Vec<ColumnOverrideValue>> {
+    let table = resource.source_table();
+    let resource_type = resource.resource_type();
+    let wanted: Vec<&ColumnOverride> = overrides
+        .iter()
+        .filter(|o| o.table() == table && o.resource == resource_type.as_path())
+        .collect();
+    let Some(key_column) = refresh_key_column(resource).filter(|_| !wanted.is_empty()) else {
+        return Ok(Vec::new());
+    };
+
+    // Table and column names are validated identifiers (`validate_oscar`).
+    let select: Vec<String> = wanted.iter().map(|o| format!("`{}`", o.column_name())).collect();
+    let query = format!(
+        "SELECT {} FROM `{table}` WHERE `{key_column}` = :id",
+        select.join(", ")
+    );
+
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load override columns")?;
+
+    let row: Option<Row> = conn
+        .exec_first(query, params! { "id" => resource.source_id() })
+        .await
+        .with_context(|| format!("selecting override columns from {table}"))?;
+
+    drop(conn);
+
+    let Some(row) = row else {
+        return Ok(Vec::new());
+    };
+    let values = row.unwrap();
+    Ok(wanted
+        .into_iter()
+        .zip(values.iter())
+        .filter_map(|(o, v)| {
+            let value = mysql_value_to_string(v)?.trim().to_string();
+            (!value.is_empty()).then(|| ColumnOverrideValue {
+                spec: o.clone(),
+                value,
+            })
+        })
+        .collect())
+}
+
+/// Primary key of the row a refreshed resource's `source_id` names.
+fn refresh_key_column(resource: &DomainResource) -> Option<&'static str> {
+    match resource {
+        DomainResource::Patient(_) => Some("demographic_no"),
+        DomainResource::QuestionnaireResponse(_) => Some("fdid"),
+        DomainResource::Communication(_) => Some("messageid"),
+        DomainResource::Claim(c) => Some(match c.source {
+            BillingSource::Bc => "billing_no",
+            BillingSource::On => "id",
+        }),
+        DomainResource::ChargeItem(c) => Some(match c.source {
+            BillingSource::Bc => "billingmaster_no",
+            BillingSource::On => "id",
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ColumnValueType;
+    use crate::sources::{RowOp, SourcePosition};
+
+    #[test]
+    fn picks_overrides_for_table_and_resource() {
+        let pronouns = ColumnOverride {
+            column: "demographic.pronouns".to_string(),
+            resource: "Patient".to_string(),
+            element: None,
+            extension_url: Some("http://hl7.org/fhir/StructureDefinition/individual-pronouns".to_string()),
+            value_type: ColumnValueType::String,
+        };
+        let overrides = vec![
+            pronouns.clone(),
+            ColumnOverride {
+                column: "demographic.vip".to_string(),
+                resource: "CareTeam".to_string(),
+                ..pronouns.clone()
+            },
+            ColumnOverride {
+                column: "appointment.flag".to_string(),
+                resource: "Appointment".to_string(),
+                ..pronouns.clone()
+            },
+        ];
+        let columns: ColumnMap = [("demographic_no", 0), ("pronouns", 1), ("vip", 2)]
+            .into_iter()
+            .map(|(n, i)| (n.to_string(), i))
+            .collect();
+        let change = RowChange {
+            schema: "oscar".to_string(),
+            table: "demographic".to_string(),
+            op: RowOp::Update,
+            after: vec![Some("123".to_string()), Some(" they/them ".to_string()), Some("1".to_string())],
+            position: SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        };
+
+        let values = column_override_values(&overrides, &change, &columns, ResourceType::Patient);
+        assert_eq!(
+            values,
+            vec![ColumnOverrideValue {
+                spec: pronouns,
+                value: "they/them".to_string(),
+            }]
+        );
+    }
+}
@@ -73,6 +73,11 @@ pub fn row_to_domain_service_request(change: &RowChange, columns: &ColumnMap) ->
         referal_date: lookup(change, columns, "referalDate").map(str::to_string),
         urgency: lookup(change, columns, "urgency").map(str::to_string),
         status: lookup(change, columns, "status").map(str::to_string),
+        // Oscar stores 0 when no specialist has been picked yet.
+        spec_id: lookup(change, columns, "specId")
+            .map(str::trim)
+            .filter(|s| *s != "0")
+            .map(str::to_string),
     })
 }
 
@@ -140,6 +145,15 @@ mod tests {
         assert_eq!(r.status, Some("4".to_string()));
     }
 
+    #[test]
+    fn spec_id_maps_to_performer_unless_unset() {
+        let cols = columns(&["requestId", "demographicNo", "specId"]);
+        let r = row_to_domain_service_request(&change(vec![Some("13"), Some("118"), Some("42")]), &cols).unwrap();
+        assert_eq!(r.spec_id, Some("42".to_string()));
+        let r = row_to_domain_service_request(&change(vec![Some("13"), Some("118"), Some("0")]), &cols).unwrap();
+        assert_eq!(r.spec_id, None);
+    }
+
     #[test]
     fn missing_request_id_is_skipped() {
         let cols = columns(&["requestId", "demographicNo"]);
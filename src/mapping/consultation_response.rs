@@ -7,6 +7,7 @@ use tracing::{warn};
 
 use crate::config::DatabaseConfig;
 use crate::domain::diagnostic_report::DomainDiagnosticReport;
+use crate::mapping::shared_pool;
 use crate::mapping::syncable_provider;
 use crate::sources::RowChange;
 
@@ -89,11 +90,7 @@ async fn resolve_based_on(
     demographic_no: &str,
     referral_date: Option<&str>,
 ) -> Result<Option<String>> {
-    let url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        db.user, db.password, db.host, db.port, db.schema
-    );
-    let pool = mysql_async::Pool::new(url.as_str());
+    let pool = shared_pool(db);
     let mut conn = pool
         .get_conn()
         .await
@@ -132,7 +129,6 @@ async fn resolve_based_on(
             "consultation_response mapping: no AMT-eReferral consultationRequest for demographic_no={demographic_no} referalDate={referral_date:?}"
         );
         drop(conn);
-        let _ = pool.disconnect().await;
         return Ok(None);
     }
 
@@ -142,7 +138,6 @@ async fn resolve_based_on(
             "consultation_response mapping: ambiguous originating consultationRequest for demographic_no={demographic_no} referalDate={referral_date:?}: {ids:?}"
         );
         drop(conn);
-        let _ = pool.disconnect().await;
         return Ok(None);
     }
 
@@ -160,7 +155,6 @@ async fn resolve_based_on(
         .context("selecting amt.fhirServiceRequestId ext row")?;
 
     drop(conn);
-    let _ = pool.disconnect().await;
 
     if ext_rows.is_empty() {
         warn!(
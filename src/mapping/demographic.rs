@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use chrono::{Datelike, NaiveDate};
+
 use crate::domain::patient::{AddressKind, AddressUse, DomainAddress, DomainPatient};
 use crate::sources::RowChange;
 
@@ -16,10 +18,27 @@ pub(crate) fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str)
         .filter(|s| !s.is_empty())
 }
 
-fn lookup_any<'a>(change: &'a RowChange, columns: &ColumnMap, names: &[&str]) -> Option<&'a str> {
+pub(crate) fn lookup_any<'a>(change: &'a RowChange, columns: &ColumnMap, names: &[&str]) -> Option<&'a str> {
     names.iter().find_map(|n| lookup(change, columns, n))
 }
 
+/// Trimmed, non-blank text from the first present column in `names`.
+fn text(change: &RowChange, columns: &ColumnMap, names: &[&str]) -> Option<String> {
+    lookup_any(change, columns, names)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// The `YYYY-MM-DD` part of a date/datetime column. Oscar's "no date"
+/// placeholders (`0000-00-00`, `0001-01-01`) and unparseable values are
+/// `None`.
+fn date_only(change: &RowChange, columns: &ColumnMap, name: &str) -> Option<String> {
+    let date = lookup(change, columns, name)?.trim().get(..10)?;
+    let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    (parsed.year() > 1).then(|| date.to_string())
+}
+
 /// Composes an ISO `YYYY-MM-DD` birth date from Oscar's three DOB columns
 /// (F5). Returns `None` unless all three parts are present and valid.
 fn compose_birth_date(change: &RowChange, columns: &ColumnMap) -> Option<String> {
@@ -114,14 +133,32 @@ pub fn row_to_domain_patient(change: &RowChange, columns: &ColumnMap) -> Option<
         demographic_no,
         first_name: lookup(change, columns, "first_name").map(str::to_string),
         last_name: lookup(change, columns, "last_name").map(str::to_string),
+        middle_names: text(change, columns, &["middleNames", "middle_names"]),
+        pref_name: text(change, columns, &["pref_name"]),
+        title: text(change, columns, &["title"]),
+        alias: text(change, columns, &["alias"]),
         date_of_birth: compose_birth_date(change, columns),
         addresses: compose_addresses(change, columns),
         sex: lookup(change, columns, "sex").map(str::to_string),
         phone: lookup_any(change, columns, &["phone", "phone1"]).map(str::to_string),
         email: lookup(change, columns, "email").map(str::to_string),
         hin: lookup(change, columns, "hin").map(str::to_string),
+        hc_type: lookup(change, columns, "hc_type").map(|s| s.trim().to_ascii_uppercase()),
+        hc_version: lookup(change, columns, "ver").map(|s| s.trim().to_string()),
+        hc_renew_date: date_only(change, columns, "hc_renew_date"),
+        official_lang: text(change, columns, &["official_lang"]),
+        spoken_lang: text(change, columns, &["spoken_lang"]),
+        chart_no: text(change, columns, &["chart_no"]),
+        roster_status: text(change, columns, &["roster_status"]).map(|s| s.to_ascii_uppercase()),
+        roster_date: date_only(change, columns, "roster_date"),
+        date_joined: date_only(change, columns, "date_joined"),
         patient_status: lookup(change, columns, "patient_status").map(str::to_string),
         merged_to: None,
+        mrp_provider_no: crate::mapping::syncable_provider(lookup(change, columns, "provider_no")),
+        family_doctor: lookup_any(change, columns, &["family_doctor", "family_physician"])
+            .and_then(crate::mapping::referral_doctor::parse_family_doctor),
+        contacts: Vec::new(),
+        photo: None,
     })
 }
 
@@ -144,14 +181,31 @@ pub fn row_to_merged_patient(change: &RowChange, columns: &ColumnMap) -> Option<
         demographic_no,
         first_name: None,
         last_name: None,
+        middle_names: None,
+        pref_name: None,
+        title: None,
+        alias: None,
         date_of_birth: None,
         addresses: Vec::new(),
         sex: None,
         phone: None,
         email: None,
         hin: None,
+        hc_type: None,
+        hc_version: None,
+        hc_renew_date: None,
+        official_lang: None,
+        spoken_lang: None,
+        chart_no: None,
+        roster_status: None,
+        roster_date: None,
+        date_joined: None,
         patient_status: None,
         merged_to: Some(merged_to),
+        mrp_provider_no: None,
+        family_doctor: None,
+        contacts: Vec::new(),
+        photo: None,
     })
 }
 
@@ -242,6 +296,89 @@ mod tests {
         assert!(row_to_domain_patient(&row, &cols).is_none());
     }
 
+    #[test]
+    fn maps_health_card_type_version_and_expiry() {
+        let cols: ColumnMap = [("demographic_no", 0), ("hin", 1), ("hc_type", 2), ("ver", 3), ("hc_renew_date", 4)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let row = change(vec![
+            Some("123"),
+            Some("1234567890"),
+            Some("on"),
+            Some("AB"),
+            Some("2027-05-31 00:00:00"),
+        ]);
+
+        let patient = row_to_domain_patient(&row, &cols).unwrap();
+        assert_eq!(patient.hc_type.as_deref(), Some("ON"));
+        assert_eq!(patient.hc_version.as_deref(), Some("AB"));
+        assert_eq!(patient.hc_renew_date.as_deref(), Some("2027-05-31"));
+
+        let row = change(vec![Some("123"), Some("1234567890"), Some("ON"), None, Some("0000-00-00")]);
+        assert_eq!(row_to_domain_patient(&row, &cols).unwrap().hc_renew_date, None);
+    }
+
+    #[test]
+    fn maps_names_languages_chart_and_roster() {
+        let cols: ColumnMap = [
+            "demographic_no", "middleNames", "pref_name", "title", "alias", "official_lang", "spoken_lang",
+            "chart_no", "roster_status", "roster_date", "date_joined",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.to_string(), i))
+        .collect();
+        let row = change(vec![
+            Some("123"),
+            Some("Jane Marie"),
+            Some("Al"),
+            Some("MS"),
+            Some(" "),
+            Some("English"),
+            Some("Punjabi"),
+            Some("C-42"),
+            Some("ro"),
+            Some("2020-01-15"),
+            Some("0001-01-01"),
+        ]);
+
+        let patient = row_to_domain_patient(&row, &cols).unwrap();
+        assert_eq!(patient.middle_names.as_deref(), Some("Jane Marie"));
+        assert_eq!(patient.pref_name.as_deref(), Some("Al"));
+        assert_eq!(patient.title.as_deref(), Some("MS"));
+        assert_eq!(patient.alias, None);
+        assert_eq!(patient.spoken_lang.as_deref(), Some("Punjabi"));
+        assert_eq!(patient.chart_no.as_deref(), Some("C-42"));
+        assert_eq!(patient.roster_status.as_deref(), Some("RO"));
+        assert_eq!(patient.roster_date.as_deref(), Some("2020-01-15"));
+        assert_eq!(patient.date_joined, None);
+    }
+
+    #[test]
+    fn maps_mrp_and_family_doctor() {
+        let cols: ColumnMap = [("demographic_no", 0), ("provider_no", 1), ("family_doctor", 2)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let row = change(vec![
+            Some("123"),
+            Some("101"),
+            Some("<rdohip>12345</rdohip><rd>Smith, John</rd>"),
+        ]);
+
+        let patient = row_to_domain_patient(&row, &cols).unwrap();
+        assert_eq!(patient.mrp_provider_no.as_deref(), Some("101"));
+        let doctor = patient.family_doctor.unwrap();
+        assert_eq!(doctor.billing_no.as_deref(), Some("12345"));
+        assert_eq!(doctor.last_name.as_deref(), Some("Smith"));
+
+        let row = change(vec![Some("123"), Some("-1"), Some("<rdohip></rdohip><rd></rd>")]);
+        let patient = row_to_domain_patient(&row, &cols).unwrap();
+        assert_eq!(patient.mrp_provider_no, None);
+        assert_eq!(patient.family_doctor, None);
+    }
+
     fn address_columns() -> ColumnMap {
         [
             ("demographic_no", 0),
@@ -0,0 +1,353 @@
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+use tracing::warn;
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::{DatabaseConfig, OscarConfig};
+use crate::domain::patient::DomainPatient;
+use crate::domain::related_person::DomainRelatedPerson;
+use crate::mapping::client_image::load_patient_photo;
+use crate::mapping::demographic::{lookup, lookup_any, row_to_domain_patient, ColumnMap};
+use crate::mapping::referral_doctor::resolve_family_doctor;
+use crate::mapping::shared_pool;
+use crate::sources::{RowChange, RowOp, SourcePosition};
+
+/// `DemographicContact.type` values. Only demographic- and `Contact`-backed
+/// links describe a related person; internal providers (0) and professional
+/// specialists (3) are care-team members, not RelatedPersons.
+const CONTACT_TYPE_DEMOGRAPHIC: &str = "1";
+const CONTACT_TYPE_CONTACT: &str = "2";
+
+/// First name, last name, home, work and cell phone, and email, in the
+/// column order both contact lookups select.
+type ContactDetails = (Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>);
+
+/// Parses Oscar's loosely-typed boolean columns. `sdm`/`ec` are varchars
+/// holding "true" (or "" when unset); `deleted`/`active` are tinyints.
+fn flag(change: &RowChange, columns: &ColumnMap, name: &str) -> Option<bool> {
+    lookup(change, columns, name).map(|v| {
+        matches!(
+            v.trim().to_ascii_lowercase().as_str(),
+            "true" | "1" | "y" | "yes"
+        )
+    })
+}
+
+/// Maps the link columns of one `DemographicContact` row. Name and telecom
+/// are left empty; `row_to_domain_related_person` resolves them from the
+/// referenced `Contact` or `demographic` row.
+///
+/// Returns `None` for provider/specialist links and rows with no natural key.
+pub fn row_to_demographic_contact(
+    change: &RowChange,
+    columns: &ColumnMap,
+) -> Option<DomainRelatedPerson> {
+    let contact_id = lookup(change, columns, "id")?.to_string();
+    let demographic_no = lookup_any(change, columns, &["demographicNo", "demographic_no"])?.to_string();
+    let contact_type = lookup(change, columns, "type")?.trim().to_string();
+
+    let linked_demographic_no = match contact_type.as_str() {
+        CONTACT_TYPE_DEMOGRAPHIC => lookup_any(change, columns, &["contactId", "contact_id"]).map(str::to_string),
+        CONTACT_TYPE_CONTACT => None,
+        _ => return None,
+    };
+
+    let deleted = flag(change, columns, "deleted").unwrap_or(false);
+    // Older Oscar builds predate the `active` column; absent means active.
+    let active = flag(change, columns, "active").unwrap_or(true);
+
+    Some(DomainRelatedPerson {
+        contact_id,
+        demographic_no,
+        contact_type,
+        linked_demographic_no,
+        role: lookup(change, columns, "role").map(|s| s.trim().to_string()),
+        category: lookup(change, columns, "category").map(str::to_string),
+        first_name: None,
+        last_name: None,
+        home_phone: None,
+        work_phone: None,
+        cell_phone: None,
+        email: None,
+        substitute_decision_maker: flag(change, columns, "sdm").unwrap_or(false),
+        emergency_contact: flag(change, columns, "ec").unwrap_or(false),
+        active: active && !deleted && change.op != RowOp::Delete,
+    })
+}
+
+/// Maps one `DemographicContact` row to a `DomainRelatedPerson`, resolving
+/// the contact's name and phone numbers with a live query against `Contact`
+/// (type 2) or `demographic` (type 1). A dangling reference still yields the
+/// link, just without a name, so deletes and role changes are not lost.
+pub async fn row_to_domain_related_person(
+    change: &RowChange,
+    columns: &ColumnMap,
+    db: &DatabaseConfig,
+) -> Result<Option<DomainRelatedPerson>> {
+    let Some(mut related) = row_to_demographic_contact(change, columns) else {
+        return Ok(None);
+    };
+    let Some(target_id) = lookup_any(change, columns, &["contactId", "contact_id"]) else {
+        warn!(
+            "demographic_contact mapping: id={} has no contactId; syncing link without name",
+            related.contact_id
+        );
+        return Ok(Some(related));
+    };
+
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to resolve demographic contact details")?;
+
+    let row: Option<ContactDetails> =
+        if related.contact_type == CONTACT_TYPE_CONTACT {
+            conn.exec_first(
+                "SELECT firstName, lastName, residencePhone, workPhone, cellPhone, email \
+                 FROM Contact WHERE id = :id",
+                params! { "id" => target_id },
+            )
+            .await
+            .context("selecting Contact row for demographic contact")?
+        } else {
+            conn.exec_first(
+                "SELECT first_name, last_name, phone, phone2, NULL, email \
+                 FROM demographic WHERE demographic_no = :id",
+                params! { "id" => target_id },
+            )
+            .await
+            .context("selecting linked demographic row for demographic contact")?
+        };
+
+    drop(conn);
+
+    match row {
+        Some((first, last, home, work, cell, email)) => {
+            related.first_name = non_empty(first);
+            related.last_name = non_empty(last);
+            related.home_phone = non_empty(home);
+            related.work_phone = non_empty(work);
+            related.cell_phone = non_empty(cell);
+            related.email = non_empty(email);
+        }
+        None => warn!(
+            "demographic_contact mapping: id={} references missing contactId={target_id} (type {})",
+            related.contact_id, related.contact_type
+        ),
+    }
+
+    Ok(Some(related))
+}
+
+/// Loads the patient's active `Contact`-backed links for `Patient.contact`.
+/// Demographic-backed links are excluded: those people have their own
+/// Patient resource and are represented only as RelatedPersons.
+pub async fn load_patient_contacts(
+    db: &DatabaseConfig,
+    demographic_no: &str,
+) -> Result<Vec<DomainRelatedPerson>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load patient contacts")?;
+
+    let rows: Vec<Row> = conn
+        .exec(
+            "SELECT dc.id, dc.role, dc.category, dc.sdm, dc.ec, \
+                    c.firstName, c.lastName, c.residencePhone, c.workPhone, c.cellPhone, c.email \
+             FROM DemographicContact dc \
+             JOIN Contact c ON c.id = dc.contactId \
+             WHERE dc.demographicNo = :demographic_no \
+               AND dc.type = 2 \
+               AND dc.deleted = 0 \
+             ORDER BY dc.id",
+            params! { "demographic_no" => demographic_no },
+        )
+        .await
+        .context("selecting DemographicContact rows for patient")?;
+
+    drop(conn);
+
+    let contacts = rows
+        .into_iter()
+        .filter_map(|row| {
+            let values: Vec<Option<String>> =
+                row.unwrap().iter().map(mysql_value_to_string).collect();
+            let get = |i: usize| values.get(i).cloned().flatten().filter(|s| !s.trim().is_empty());
+            let is_set = |i: usize| {
+                get(i).is_some_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1"))
+            };
+            Some(DomainRelatedPerson {
+                contact_id: get(0)?,
+                demographic_no: demographic_no.to_string(),
+                contact_type: CONTACT_TYPE_CONTACT.to_string(),
+                linked_demographic_no: None,
+                role: get(1).map(|s| s.trim().to_string()),
+                category: get(2),
+                first_name: get(5),
+                last_name: get(6),
+                home_phone: get(7),
+                work_phone: get(8),
+                cell_phone: get(9),
+                email: get(10),
+                substitute_decision_maker: is_set(3),
+                emergency_contact: is_set(4),
+                active: true,
+            })
+        })
+        .collect();
+
+    Ok(contacts)
+}
+
+/// Re-reads a `demographic` row and maps it with its current contacts and
+/// photo, so a `DemographicContact` or `client_image` change can refresh the
+/// owning Patient. `Ok(None)` if the demographic no longer exists.
+pub async fn reload_patient(
+    db: &DatabaseConfig,
+    oscar: &OscarConfig,
+    demographic_columns: &ColumnMap,
+    demographic_no: &str,
+) -> Result<Option<DomainPatient>> {
+    let Some(change) = reload_demographic_row(db, demographic_no).await? else {
+        return Ok(None);
+    };
+
+    let Some(mut patient) = row_to_domain_patient(&change, demographic_columns) else {
+        return Ok(None);
+    };
+    if oscar.related_person_enabled {
+        patient.contacts = load_patient_contacts(db, demographic_no).await?;
+    }
+    if oscar.patient_photo_enabled {
+        patient.photo = load_patient_photo(db, oscar, demographic_no).await?;
+    }
+    // Only the reference matters here; the demographic row's own event
+    // already synced any external Practitioner.
+    resolve_family_doctor(db, &mut patient).await?;
+    Ok(Some(patient))
+}
+
+/// Re-reads a `demographic` row as a synthetic `Update` `RowChange`, for
+/// refreshes triggered by a change to some other table. `Ok(None)` if the
+/// demographic no longer exists.
+pub async fn reload_demographic_row(db: &DatabaseConfig, demographic_no: &str) -> Result<Option<RowChange>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to reload demographic row")?;
+
+    let row: Option<Row> = conn
+        .exec_first(
+            "SELECT * FROM demographic WHERE demographic_no = :demographic_no",
+            params! { "demographic_no" => demographic_no },
+        )
+        .await
+        .context("selecting demographic row for refresh")?;
+
+    drop(conn);
+
+    Ok(row.map(|row| RowChange {
+        schema: db.schema.clone(),
+        table: "demographic".to_string(),
+        op: RowOp::Update,
+        after: row.unwrap().iter().map(mysql_value_to_string).collect(),
+        position: SourcePosition::FilePos {
+            file: String::new(),
+            pos: 0,
+        },
+    }))
+}
+
+fn non_empty(v: Option<String>) -> Option<String> {
+    v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns() -> ColumnMap {
+        [
+            ("id", 0),
+            ("demographicNo", 1),
+            ("contactId", 2),
+            ("role", 3),
+            ("type", 4),
+            ("category", 5),
+            ("sdm", 6),
+            ("ec", 7),
+            ("deleted", 8),
+            ("active", 9),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect()
+    }
+
+    fn change(op: RowOp, after: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "DemographicContact".to_string(),
+            op,
+            after: after.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: SourcePosition::FilePos {
+                file: "mysql-bin.000001".to_string(),
+                pos: 4,
+            },
+        }
+    }
+
+    fn row<'a>(contact_type: &'a str, sdm: &'a str, ec: &'a str, deleted: &'a str) -> Vec<Option<&'a str>> {
+        vec![
+            Some("5"),
+            Some("123"),
+            Some("77"),
+            Some(" Mother "),
+            Some(contact_type),
+            Some("personal"),
+            Some(sdm),
+            Some(ec),
+            Some(deleted),
+            Some("1"),
+        ]
+    }
+
+    #[test]
+    fn contact_backed_link_maps_flags_and_role() {
+        let rp = row_to_demographic_contact(&change(RowOp::Insert, row("2", "true", "true", "0")), &columns()).unwrap();
+        assert_eq!(rp.contact_id, "5");
+        assert_eq!(rp.demographic_no, "123");
+        assert_eq!(rp.role.as_deref(), Some("Mother"));
+        assert_eq!(rp.linked_demographic_no, None);
+        assert!(rp.substitute_decision_maker);
+        assert!(rp.emergency_contact);
+        assert!(rp.active);
+    }
+
+    #[test]
+    fn demographic_backed_link_records_linked_demographic() {
+        let rp = row_to_demographic_contact(&change(RowOp::Insert, row("1", "", "", "0")), &columns()).unwrap();
+        assert_eq!(rp.linked_demographic_no.as_deref(), Some("77"));
+        assert!(!rp.substitute_decision_maker);
+        assert!(!rp.emergency_contact);
+    }
+
+    #[test]
+    fn provider_and_specialist_links_are_skipped() {
+        assert!(row_to_demographic_contact(&change(RowOp::Insert, row("0", "", "", "0")), &columns()).is_none());
+        assert!(row_to_demographic_contact(&change(RowOp::Insert, row("3", "", "", "0")), &columns()).is_none());
+    }
+
+    #[test]
+    fn soft_deleted_or_binlog_deleted_rows_are_inactive() {
+        let soft = row_to_demographic_contact(&change(RowOp::Update, row("2", "", "", "1")), &columns()).unwrap();
+        assert!(!soft.active);
+        let hard = row_to_demographic_contact(&change(RowOp::Delete, row("2", "", "", "0")), &columns()).unwrap();
+        assert!(!hard.active);
+    }
+}
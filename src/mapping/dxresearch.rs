@@ -7,6 +7,7 @@ use tracing::{info, warn};
 
 use crate::config::{DatabaseConfig, OscarConfig};
 use crate::domain::condition::DomainCondition;
+use crate::mapping::shared_pool;
 use crate::sources::RowChange;
 use crate::mapping::syncable_provider;
 
@@ -17,11 +18,7 @@ static DIAGNOSTIC_CODES: OnceLock<HashMap<String, String>> = OnceLock::new();
 /// Loads `diagnosticcode` descriptions into a startup cache and initialises the
 /// lookup table used by `row_to_domain_condition` (D7).
 pub async fn load_diagnostic_codes(db: &DatabaseConfig, oscar: &OscarConfig) -> Result<()> {
-    let url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        db.user, db.password, db.host, db.port, db.schema
-    );
-    let pool = mysql_async::Pool::new(url.as_str());
+    let pool = shared_pool(db);
     let mut conn = pool
         .get_conn()
         .await
@@ -38,7 +35,6 @@ pub async fn load_diagnostic_codes(db: &DatabaseConfig, oscar: &OscarConfig) ->
         .context("selecting diagnosticcode")?;
 
     drop(conn);
-    let _ = pool.disconnect().await;
 
     let want_region = oscar.region.as_deref();
     let mut map: HashMap<String, String> = HashMap::with_capacity(rows.len());
@@ -77,6 +73,14 @@ pub async fn load_diagnostic_codes(db: &DatabaseConfig, oscar: &OscarConfig) ->
     Ok(())
 }
 
+/// Whether an ICD-9 code (dotted or not) is an active `diagnosticcode` row.
+/// `None` if the cache was never loaded.
+pub fn is_known_diagnostic_code(code: &str) -> Option<bool> {
+    let codes = DIAGNOSTIC_CODES.get()?;
+    let raw: String = code.trim().chars().filter(|c| *c != '.').collect();
+    Some(codes.contains_key(&raw))
+}
+
 #[cfg(test)]
 pub fn set_diagnostic_codes_for_test(codes: HashMap<String, String>) {
     let _ = DIAGNOSTIC_CODES.set(codes);
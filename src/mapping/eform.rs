@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+use tracing::info;
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::DatabaseConfig;
+use crate::domain::questionnaire::{DomainQuestionnaire, DomainQuestionnaireAnswer, DomainQuestionnaireResponse};
+use crate::mapping::shared_pool;
+use crate::mapping::syncable_provider;
+use crate::sources::{RowChange, RowOp, SourcePosition};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn flag(change: &RowChange, columns: &ColumnMap, name: &str) -> Option<bool> {
+    lookup(change, columns, name).map(|s| s != "0")
+}
+
+/// Maps one `eform` row to a `DomainQuestionnaire`. `var_names` is left
+/// empty; `load_questionnaire_items` fills it.
+///
+/// Returns `None` if the row has no `fid`.
+pub fn row_to_domain_questionnaire(change: &RowChange, columns: &ColumnMap) -> Option<DomainQuestionnaire> {
+    let Some(fid) = lookup(change, columns, "fid") else {
+        info!("eform mapping: skipping eform row with no fid");
+        return None;
+    };
+
+    Some(DomainQuestionnaire {
+        fid: fid.to_string(),
+        form_name: lookup(change, columns, "form_name").map(str::to_string),
+        subject: lookup(change, columns, "subject").map(str::to_string),
+        var_names: Vec::new(),
+        active: flag(change, columns, "status").unwrap_or(true) && change.op != RowOp::Delete,
+    })
+}
+
+/// Maps one `eform_data` row to a `DomainQuestionnaireResponse`. `answers`
+/// is left empty; `load_eform_values` fills it.
+///
+/// Returns `None` if the row has no `fdid` or `fid`.
+pub fn row_to_domain_questionnaire_response(
+    change: &RowChange,
+    columns: &ColumnMap,
+) -> Option<DomainQuestionnaireResponse> {
+    let (Some(fdid), Some(fid)) = (lookup(change, columns, "fdid"), lookup(change, columns, "fid")) else {
+        info!("eform mapping: skipping eform_data row with no fdid/fid");
+        return None;
+    };
+
+    Some(DomainQuestionnaireResponse {
+        fdid: fdid.to_string(),
+        fid: fid.to_string(),
+        form_name: lookup(change, columns, "form_name").map(str::to_string),
+        // Patient-independent forms are stored against demographic 0/-1.
+        demographic_no: lookup(change, columns, "demographic_no")
+            .filter(|d| *d != "0" && *d != "-1")
+            .map(str::to_string),
+        provider_no: syncable_provider(lookup(change, columns, "form_provider")),
+        form_date: lookup(change, columns, "form_date").map(str::to_string),
+        form_time: lookup(change, columns, "form_time").map(str::to_string),
+        current: flag(change, columns, "status").unwrap_or(true) && change.op != RowOp::Delete,
+        patient_independent: flag(change, columns, "patient_independent").unwrap_or(false),
+        answers: Vec::new(),
+    })
+}
+
+/// Loads a form instance's `eform_values` in entry order. Blank values are
+/// kept out: an empty field is an unanswered question.
+pub async fn load_eform_values(db: &DatabaseConfig, response: &mut DomainQuestionnaireResponse) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load eform_values")?;
+
+    let rows: Vec<(String, Option<String>)> = conn
+        .exec(
+            "SELECT var_name, var_value FROM eform_values WHERE fdid = :fdid ORDER BY id",
+            params! { "fdid" => &response.fdid },
+        )
+        .await
+        .context("selecting eform_values")?;
+
+    drop(conn);
+
+    response.answers = rows
+        .into_iter()
+        .filter_map(|(var_name, var_value)| {
+            let var_value = var_value.filter(|v| !v.trim().is_empty())?;
+            Some(DomainQuestionnaireAnswer { var_name, var_value })
+        })
+        .collect();
+    Ok(())
+}
+
+/// Loads the distinct field names recorded for a form, as the generated
+/// Questionnaire's items. Oscar eForms are free HTML, so the stored values
+/// are the only reliable field list.
+pub async fn load_questionnaire_items(db: &DatabaseConfig, questionnaire: &mut DomainQuestionnaire) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load eform field names")?;
+
+    questionnaire.var_names = conn
+        .exec(
+            "SELECT var_name FROM eform_values WHERE fid = :fid GROUP BY var_name ORDER BY MIN(id)",
+            params! { "fid" => &questionnaire.fid },
+        )
+        .await
+        .context("selecting eform field names")?;
+
+    drop(conn);
+
+    Ok(())
+}
+
+/// Re-reads an `eform_data` row and maps it with its current values, so an
+/// `eform_values` change refreshes the response. `Ok(None)` if the row is
+/// gone.
+pub async fn reload_questionnaire_response(
+    db: &DatabaseConfig,
+    eform_data_columns: &ColumnMap,
+    fdid: &str,
+) -> Result<Option<DomainQuestionnaireResponse>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to reload eform_data row")?;
+
+    let row: Option<Row> = conn
+        .exec_first(
+            "SELECT * FROM eform_data WHERE fdid = :fdid",
+            params! { "fdid" => fdid },
+        )
+        .await
+        .context("selecting eform_data row for refresh")?;
+
+    drop(conn);
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    let change = RowChange {
+        schema: db.schema.clone(),
+        table: "eform_data".to_string(),
+        op: RowOp::Update,
+        after: row.unwrap().iter().map(mysql_value_to_string).collect(),
+        position: SourcePosition::FilePos {
+            file: String::new(),
+            pos: 0,
+        },
+    };
+
+    let Some(mut response) = row_to_domain_questionnaire_response(&change, eform_data_columns) else {
+        return Ok(None);
+    };
+    load_eform_values(db, &mut response).await?;
+    Ok(Some(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(table: &str, op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: table.to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns(names: &[&str]) -> ColumnMap {
+        names.iter().enumerate().map(|(i, n)| (n.to_string(), i)).collect()
+    }
+
+    #[test]
+    fn maps_eform_data_row() {
+        let cols = columns(&[
+            "fdid", "fid", "form_name", "demographic_no", "status", "form_date", "form_time", "form_provider",
+            "patient_independent",
+        ]);
+        let qr = row_to_domain_questionnaire_response(
+            &change(
+                "eform_data",
+                RowOp::Insert,
+                vec![
+                    Some("10"),
+                    Some("3"),
+                    Some("PHQ-9"),
+                    Some("123"),
+                    Some("1"),
+                    Some("2026-01-05"),
+                    Some("10:15:00"),
+                    Some("1001"),
+                    Some("0"),
+                ],
+            ),
+            &cols,
+        )
+        .unwrap();
+
+        assert_eq!(qr.fdid, "10");
+        assert_eq!(qr.fid, "3");
+        assert_eq!(qr.demographic_no.as_deref(), Some("123"));
+        assert_eq!(qr.provider_no.as_deref(), Some("1001"));
+        assert!(qr.current);
+        assert!(!qr.patient_independent);
+    }
+
+    #[test]
+    fn deleted_or_patient_independent_eform_data() {
+        let cols = columns(&["fdid", "fid", "demographic_no", "status", "patient_independent"]);
+        let qr = row_to_domain_questionnaire_response(
+            &change(
+                "eform_data",
+                RowOp::Update,
+                vec![Some("10"), Some("3"), Some("0"), Some("0"), Some("1")],
+            ),
+            &cols,
+        )
+        .unwrap();
+        assert!(!qr.current);
+        assert!(qr.patient_independent);
+        assert_eq!(qr.demographic_no, None);
+    }
+
+    #[test]
+    fn maps_eform_row() {
+        let cols = columns(&["fid", "form_name", "subject", "status"]);
+        let q = row_to_domain_questionnaire(
+            &change(
+                "eform",
+                RowOp::Insert,
+                vec![Some("3"), Some("PHQ-9"), Some("Depression screen"), Some("1")],
+            ),
+            &cols,
+        )
+        .unwrap();
+        assert_eq!(q.fid, "3");
+        assert_eq!(q.subject.as_deref(), Some("Depression screen"));
+        assert!(q.active);
+
+        let q = row_to_domain_questionnaire(
+            &change("eform", RowOp::Delete, vec![Some("3"), None, None, Some("1")]),
+            &cols,
+        )
+        .unwrap();
+        assert!(!q.active);
+    }
+}
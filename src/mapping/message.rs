@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+use tracing::info;
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::DatabaseConfig;
+use crate::domain::communication::DomainCommunication;
+use crate::mapping::shared_pool;
+use crate::mapping::syncable_provider;
+use crate::sources::{RowChange, RowOp, SourcePosition};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Maps one `messagetbl` row to a `DomainCommunication`. Recipients and
+/// attached charts live in other tables; `load_message_links` fills them.
+///
+/// Returns `None` if the row has no `messageid`.
+pub fn row_to_domain_communication(change: &RowChange, columns: &ColumnMap) -> Option<DomainCommunication> {
+    let Some(message_id) = lookup(change, columns, "messageid") else {
+        info!("message mapping: skipping messagetbl row with no messageid");
+        return None;
+    };
+
+    Some(DomainCommunication {
+        message_id: message_id.to_string(),
+        sender_provider_no: syncable_provider(lookup(change, columns, "sentbyNo")),
+        recipient_provider_nos: Vec::new(),
+        demographic_nos: Vec::new(),
+        sent_date: lookup(change, columns, "thedate").map(str::to_string),
+        // Oscar's column really is spelled `theime`.
+        sent_time: lookup(change, columns, "theime").map(str::to_string),
+        subject: lookup(change, columns, "thesubject").map(str::to_string),
+        // `themessage` is stored with Windows line endings from the web form.
+        body: lookup(change, columns, "themessage").map(|b| b.replace("\r\n", "\n")),
+        active: change.op != RowOp::Delete,
+    })
+}
+
+/// Loads a message's recipients from `messagelisttbl` and its attached
+/// patient charts from `msgDemoMap`. A recipient who deleted their copy
+/// (`status` `del`) still received it and is kept.
+pub async fn load_message_links(db: &DatabaseConfig, communication: &mut DomainCommunication) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load message links")?;
+
+    let recipients: Vec<Option<String>> = conn
+        .exec(
+            "SELECT provider_no FROM messagelisttbl WHERE message = :message_id ORDER BY id",
+            params! { "message_id" => &communication.message_id },
+        )
+        .await
+        .context("selecting messagelisttbl recipients")?;
+
+    let demographics: Vec<Option<String>> = conn
+        .exec(
+            "SELECT demographic_no FROM msgDemoMap WHERE messageID = :message_id ORDER BY id",
+            params! { "message_id" => &communication.message_id },
+        )
+        .await
+        .context("selecting msgDemoMap charts")?;
+
+    drop(conn);
+
+    communication.recipient_provider_nos = dedup(recipients.iter().filter_map(|p| syncable_provider(p.as_deref())));
+    communication.demographic_nos = dedup(
+        demographics
+            .iter()
+            .filter_map(|d| d.as_deref().map(str::trim))
+            .filter(|d| !d.is_empty() && *d != "0")
+            .map(str::to_string),
+    );
+    Ok(())
+}
+
+/// Re-reads a `messagetbl` row and maps it with its current links, so a
+/// `messagelisttbl` or `msgDemoMap` change refreshes the Communication.
+/// `Ok(None)` if the message is gone.
+pub async fn reload_communication(
+    db: &DatabaseConfig,
+    message_columns: &ColumnMap,
+    message_id: &str,
+) -> Result<Option<DomainCommunication>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to reload messagetbl row")?;
+
+    let row: Option<Row> = conn
+        .exec_first(
+            "SELECT * FROM messagetbl WHERE messageid = :message_id",
+            params! { "message_id" => message_id },
+        )
+        .await
+        .context("selecting messagetbl row for refresh")?;
+
+    drop(conn);
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    let change = RowChange {
+        schema: db.schema.clone(),
+        table: "messagetbl".to_string(),
+        op: RowOp::Update,
+        after: row.unwrap().iter().map(mysql_value_to_string).collect(),
+        position: SourcePosition::FilePos {
+            file: String::new(),
+            pos: 0,
+        },
+    };
+
+    let Some(mut communication) = row_to_domain_communication(&change, message_columns) else {
+        return Ok(None);
+    };
+    load_message_links(db, &mut communication).await?;
+    Ok(Some(communication))
+}
+
+fn dedup(values: impl Iterator<Item = String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for v in values {
+        if !out.contains(&v) {
+            out.push(v);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "messagetbl".to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns(names: &[&str]) -> ColumnMap {
+        names.iter().enumerate().map(|(i, n)| (n.to_string(), i)).collect()
+    }
+
+    #[test]
+    fn maps_messagetbl_row() {
+        let cols = columns(&["messageid", "thedate", "theime", "themessage", "thesubject", "sentby", "sentbyNo"]);
+        let c = row_to_domain_communication(
+            &change(
+                RowOp::Insert,
+                vec![
+                    Some("77"),
+                    Some("2026-02-01"),
+                    Some("09:30:00"),
+                    Some("Please call.\r\nThanks"),
+                    Some("Lab results"),
+                    Some("Smith, Jane"),
+                    Some("1001"),
+                ],
+            ),
+            &cols,
+        )
+        .unwrap();
+
+        assert_eq!(c.message_id, "77");
+        assert_eq!(c.sender_provider_no.as_deref(), Some("1001"));
+        assert_eq!(c.sent_time.as_deref(), Some("09:30:00"));
+        assert_eq!(c.subject.as_deref(), Some("Lab results"));
+        assert_eq!(c.body.as_deref(), Some("Please call.\nThanks"));
+        assert!(c.active);
+    }
+
+    #[test]
+    fn system_sender_and_deleted_message() {
+        let cols = columns(&["messageid", "sentbyNo"]);
+        let c = row_to_domain_communication(&change(RowOp::Delete, vec![Some("77"), Some("-1")]), &cols).unwrap();
+        assert_eq!(c.sender_provider_no, None);
+        assert!(!c.active);
+
+        assert!(row_to_domain_communication(&change(RowOp::Insert, vec![None, Some("1001")]), &cols).is_none());
+    }
+}
@@ -1,12 +1,54 @@
+pub mod admission;
 pub mod appointment;
+pub mod billing;
 pub mod care_team;
 pub mod casemgmt_note;
 pub mod casemgmt_note_ext;
+pub mod client_image;
+pub mod clinic;
+pub mod column_override;
 pub mod consultation_request;
 pub mod consultation_response;
 pub mod demographic;
+pub mod demographic_contact;
 pub mod dxresearch;
+pub mod eform;
+pub mod message;
+pub mod professional_specialist;
+pub mod program;
 pub mod provider;
+pub mod referral_doctor;
+pub mod schedule;
+pub mod site;
+pub mod waiting_list;
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::config::DatabaseConfig;
+
+/// `mysql://` URL for `db`.
+pub fn connection_url(db: &DatabaseConfig) -> String {
+    format!(
+        "mysql://{}:{}@{}:{}/{}",
+        db.user, db.password, db.host, db.port, db.schema
+    )
+}
+
+/// One pool per database for the row loaders, so mapping a row borrows a
+/// connection instead of opening and tearing down a pool of its own. Cheap
+/// to clone; connections go back to it when dropped.
+pub fn shared_pool(db: &DatabaseConfig) -> mysql_async::Pool {
+    static POOLS: OnceLock<Mutex<HashMap<String, mysql_async::Pool>>> = OnceLock::new();
+    let url = connection_url(db);
+    POOLS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(url)
+        .or_insert_with_key(|url| mysql_async::Pool::new(url.as_str()))
+        .clone()
+}
 
 /// Oscar's system actor (`provider_no = '-1'`) is never synced as a
 /// Practitioner (D3/D5), so any FHIR reference to it is unsatisfiable and
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use tracing::info;
+
+use crate::domain::organization::DomainOrganization;
+use crate::domain::resource::DomainResource;
+use crate::domain::specialist::DomainSpecialist;
+use crate::sources::{RowChange, RowOp};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .filter(|s| !s.trim().is_empty())
+}
+
+fn lookup_any<'a>(change: &'a RowChange, columns: &ColumnMap, names: &[&str]) -> Option<&'a str> {
+    names.iter().find_map(|n| lookup(change, columns, n))
+}
+
+fn bool_flag(change: &RowChange, columns: &ColumnMap, name: &str) -> bool {
+    lookup(change, columns, name).map(|s| s.trim() == "1").unwrap_or(false)
+}
+
+fn text(change: &RowChange, columns: &ColumnMap, names: &[&str]) -> Option<String> {
+    lookup_any(change, columns, names).map(|s| s.trim().to_string())
+}
+
+/// Maps one `professionalSpecialists` row into a specialist `Practitioner`
+/// plus, when the row carries any clinic contact details, an `Organization`
+/// for the clinic. Both are keyed on `specId`.
+///
+/// Returns an empty `Vec` if the row has no `specId`.
+pub fn row_to_specialist_resources(change: &RowChange, columns: &ColumnMap) -> Vec<DomainResource> {
+    let Some(spec_id) = lookup(change, columns, "specId").map(|s| s.trim().to_string()) else {
+        info!("professionalSpecialists mapping: skipping row with no specId");
+        return Vec::new();
+    };
+
+    let active = change.op != RowOp::Delete
+        && !bool_flag(change, columns, "deleted")
+        && !bool_flag(change, columns, "hideFromView");
+
+    let address = text(change, columns, &["streetAddress", "address"]);
+    let phone = text(change, columns, &["phoneNumber", "phone"]);
+    let fax = text(change, columns, &["faxNumber", "fax"]);
+    let email = text(change, columns, &["emailAddress", "email"]);
+
+    let specialist = DomainSpecialist {
+        spec_id: spec_id.clone(),
+        first_name: text(change, columns, &["fName", "firstName"]),
+        last_name: text(change, columns, &["lName", "lastName"]),
+        pro_letters: text(change, columns, &["proLetters"]),
+        specialty: text(change, columns, &["specType"]),
+        address: address.clone(),
+        phone: phone.clone(),
+        fax: fax.clone(),
+        email: email.clone(),
+        referral_no: text(change, columns, &["referralNo", "cpsid", "CPSID"]),
+        active,
+    };
+
+    let mut out = vec![DomainResource::Specialist(specialist)];
+
+    if address.is_some() || phone.is_some() || fax.is_some() {
+        out.push(DomainResource::Organization(DomainOrganization {
+            source_table: "professionalSpecialists".to_string(),
+            source_id: spec_id,
+            // Oscar keeps no clinic name for specialists; the identifier
+            // satisfies Organization's name-or-identifier invariant.
+            name: None,
+            address_line: address,
+            city: None,
+            province: None,
+            postal: None,
+            phone,
+            fax,
+            email,
+            active,
+        }));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "professionalSpecialists".to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: crate::sources::SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns(names: &[&str]) -> ColumnMap {
+        names.iter().enumerate().map(|(i, n)| (n.to_string(), i)).collect()
+    }
+
+    #[test]
+    fn maps_specialist_and_clinic() {
+        let cols = columns(&[
+            "specId", "fName", "lName", "proLetters", "streetAddress", "phoneNumber", "faxNumber",
+            "specType", "referralNo", "deleted",
+        ]);
+        let out = row_to_specialist_resources(
+            &change(
+                RowOp::Insert,
+                vec![
+                    Some("12"),
+                    Some("Grace"),
+                    Some("Hopper"),
+                    Some("MD, FRCPC"),
+                    Some("1 Main St\nVancouver BC"),
+                    Some("604-555-0101"),
+                    Some("604-555-0102"),
+                    Some("Cardiology"),
+                    Some("12345"),
+                    Some("0"),
+                ],
+            ),
+            &cols,
+        );
+
+        assert_eq!(out.len(), 2);
+        let DomainResource::Specialist(s) = &out[0] else { panic!("expected specialist") };
+        assert_eq!(s.spec_id, "12");
+        assert_eq!(s.specialty.as_deref(), Some("Cardiology"));
+        assert_eq!(s.referral_no.as_deref(), Some("12345"));
+        assert!(s.active);
+        let DomainResource::Organization(o) = &out[1] else { panic!("expected organization") };
+        assert_eq!(o.source_id, "12");
+        assert_eq!(o.fax.as_deref(), Some("604-555-0102"));
+    }
+
+    #[test]
+    fn no_clinic_details_yields_practitioner_only() {
+        let cols = columns(&["specId", "lName"]);
+        let out = row_to_specialist_resources(&change(RowOp::Insert, vec![Some("12"), Some("Hopper")]), &cols);
+        assert_eq!(out.len(), 1);
+    }
+
+    #[test]
+    fn deleted_or_hidden_rows_are_inactive() {
+        let cols = columns(&["specId", "phoneNumber", "deleted", "hideFromView"]);
+        for values in [
+            vec![Some("12"), Some("604"), Some("1"), Some("0")],
+            vec![Some("12"), Some("604"), Some("0"), Some("1")],
+        ] {
+            let out = row_to_specialist_resources(&change(RowOp::Update, values), &cols);
+            assert!(out.iter().all(|r| match r {
+                DomainResource::Specialist(s) => !s.active,
+                DomainResource::Organization(o) => !o.active,
+                _ => false,
+            }));
+        }
+        let out = row_to_specialist_resources(&change(RowOp::Delete, vec![Some("12"), Some("604"), Some("0"), Some("0")]), &cols);
+        assert!(matches!(&out[0], DomainResource::Specialist(s) if !s.active));
+    }
+
+    #[test]
+    fn missing_spec_id_is_skipped() {
+        let cols = columns(&["specId", "lName"]);
+        assert!(row_to_specialist_resources(&change(RowOp::Insert, vec![None, Some("Hopper")]), &cols).is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use tracing::info;
+
+use crate::domain::organization::DomainOrganization;
+use crate::sources::{RowChange, RowOp};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .filter(|s| !s.trim().is_empty())
+}
+
+fn text(change: &RowChange, columns: &ColumnMap, name: &str) -> Option<String> {
+    lookup(change, columns, name).map(|s| s.trim().to_string())
+}
+
+/// Maps one CAISI `program` row to an `Organization`, keyed on `program.id`.
+/// `admission`-backed EpisodeOfCares point at it as their managing
+/// organization.
+///
+/// Returns `None` if the row has no `id`.
+pub fn row_to_program_organization(change: &RowChange, columns: &ColumnMap) -> Option<DomainOrganization> {
+    let Some(id) = text(change, columns, "id") else {
+        info!("program mapping: skipping row with no id");
+        return None;
+    };
+
+    let active = change.op != RowOp::Delete
+        && lookup(change, columns, "programStatus")
+            .map(|s| s.trim().eq_ignore_ascii_case("active"))
+            .unwrap_or(true);
+
+    Some(DomainOrganization {
+        source_table: "program".to_string(),
+        source_id: id,
+        name: text(change, columns, "name"),
+        address_line: text(change, columns, "address"),
+        city: None,
+        province: None,
+        postal: None,
+        phone: text(change, columns, "phone"),
+        fax: text(change, columns, "fax"),
+        email: text(change, columns, "email"),
+        active,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "program".to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: crate::sources::SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns() -> ColumnMap {
+        ["id", "name", "address", "phone", "programStatus"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.to_string(), i))
+            .collect()
+    }
+
+    #[test]
+    fn maps_program_to_organization() {
+        let org = row_to_program_organization(
+            &change(
+                RowOp::Insert,
+                vec![Some("10016"), Some("OSCAR"), Some("1 Main St"), Some("604-555-0100"), Some("active")],
+            ),
+            &columns(),
+        )
+        .unwrap();
+        assert_eq!(org.source_table, "program");
+        assert_eq!(org.source_id, "10016");
+        assert_eq!(org.name.as_deref(), Some("OSCAR"));
+        assert!(org.active);
+    }
+
+    #[test]
+    fn inactive_or_deleted_program_is_inactive() {
+        let values = || vec![Some("10016"), Some("OSCAR"), None, None, Some("inactive")];
+        assert!(!row_to_program_organization(&change(RowOp::Update, values()), &columns()).unwrap().active);
+        let mut active = values();
+        active[4] = Some("active");
+        assert!(!row_to_program_organization(&change(RowOp::Delete, active), &columns()).unwrap().active);
+    }
+}
@@ -1,9 +1,15 @@
 use std::collections::HashMap;
 
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+
+use crate::config::DatabaseConfig;
 use crate::domain::practitioner::DomainPractitioner;
-use crate::sources::RowChange;
+use crate::domain::practitioner_role::DomainPractitionerRole;
+use crate::mapping::shared_pool;
+use crate::sources::{RowChange, RowOp};
 use crate::mapping::syncable_provider;
-use tracing::warn;
+use tracing::{debug, warn};
 
 pub type ColumnMap = HashMap<String, usize>;
 
@@ -27,9 +33,7 @@ pub fn row_to_domain_practitioner(change: &RowChange, columns: &ColumnMap) -> Op
     let provider_no = lookup(change, columns, "provider_no")?;
 
     // D3: the synthetic system actor must never become a Practitioner.
-    if syncable_provider(Some(provider_no)).is_none() {
-        return None;
-    }
+    syncable_provider(Some(provider_no))?;
 
     // D3: `ohip_no` is a data-migration artifact in BC builds; warn but do not map.
     if let Some(_ohip) = lookup_any(change, columns, &["ohip_no", "ohip"]) {
@@ -57,6 +61,68 @@ pub fn row_to_domain_practitioner(change: &RowChange, columns: &ColumnMap) -> Op
     })
 }
 
+/// Maps the role columns of one `provider` row to a `DomainPractitionerRole`.
+/// Clinic and site links are left empty; `load_practitioner_role_links`
+/// fills them.
+///
+/// Returns `None` for the same rows `row_to_domain_practitioner` skips.
+pub fn row_to_domain_practitioner_role(change: &RowChange, columns: &ColumnMap) -> Option<DomainPractitionerRole> {
+    let provider_no = lookup(change, columns, "provider_no")?;
+    syncable_provider(Some(provider_no))?;
+
+    let text = |names: &[&str]| {
+        lookup_any(change, columns, names)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let enabled = lookup(change, columns, "status").map(|s| s.trim() != "0").unwrap_or(true);
+
+    Some(DomainPractitionerRole {
+        provider_no: provider_no.to_string(),
+        provider_type: text(&["provider_type"]).map(|t| t.to_ascii_lowercase()),
+        specialty: text(&["specialty"]),
+        team: text(&["team"]),
+        supervisor: syncable_provider(lookup(change, columns, "supervisor")),
+        job_title: text(&["job_title", "jobTitle"]),
+        clinic_no: None,
+        site_ids: Vec::new(),
+        active: enabled && change.op != RowOp::Delete,
+    })
+}
+
+/// Fills a role's organization and location links: the install's clinic
+/// (lowest `clinic_no`, as for sites) and the provider's `providersite`
+/// rows. Single-site installs may not have `providersite` at all, which
+/// leaves `site_ids` empty.
+pub async fn load_practitioner_role_links(db: &DatabaseConfig, role: &mut DomainPractitionerRole) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load practitioner role links")?;
+
+    role.clinic_no = conn
+        .query_first("SELECT CAST(clinic_no AS CHAR) FROM clinic ORDER BY clinic_no LIMIT 1")
+        .await
+        .context("selecting clinic row for practitioner role")?;
+
+    let site_ids: Result<Vec<String>, _> = conn
+        .exec(
+            "SELECT CAST(site_id AS CHAR) FROM providersite WHERE provider_no = :provider_no ORDER BY site_id",
+            params! { "provider_no" => &role.provider_no },
+        )
+        .await;
+    match site_ids {
+        Ok(site_ids) => role.site_ids = site_ids,
+        Err(e) => debug!("provider mapping: no providersite links for provider_no={}: {e}", role.provider_no),
+    }
+
+    drop(conn);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +204,35 @@ mod tests {
         let cols = columns(&["provider_no", "first_name"]);
         assert!(row_to_domain_practitioner(&change(vec![None, Some("Alice")]), &cols).is_none());
     }
+
+    #[test]
+    fn maps_role_columns() {
+        let cols = columns(&["provider_no", "provider_type", "specialty", "team", "supervisor", "job_title", "status"]);
+        let r = row_to_domain_practitioner_role(
+            &change(vec![
+                Some("1001"),
+                Some("Doctor"),
+                Some("Family Medicine"),
+                Some("Blue"),
+                Some("-1"),
+                Some(" "),
+                Some("0"),
+            ]),
+            &cols,
+        )
+        .unwrap();
+
+        assert_eq!(r.provider_type.as_deref(), Some("doctor"));
+        assert_eq!(r.specialty.as_deref(), Some("Family Medicine"));
+        assert_eq!(r.team.as_deref(), Some("Blue"));
+        assert_eq!(r.supervisor, None);
+        assert_eq!(r.job_title, None);
+        assert!(!r.active);
+    }
+
+    #[test]
+    fn system_provider_has_no_role() {
+        let cols = columns(&["provider_no", "provider_type"]);
+        assert!(row_to_domain_practitioner_role(&change(vec![Some("-1"), Some("doctor")]), &cols).is_none());
+    }
 }
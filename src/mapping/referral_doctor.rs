@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+
+use crate::config::DatabaseConfig;
+use crate::domain::patient::DomainPatient;
+use crate::domain::referral_doctor::{DomainExternalDoctor, DomainReferralDoctor};
+use crate::mapping::shared_pool;
+
+/// Parses Oscar's `demographic.family_doctor` fragment, e.g.
+/// `<rdohip>12345</rdohip><rd>Smith, John</rd>`. The name is "Last, First"
+/// by convention; anything without a comma is kept as the last name.
+///
+/// Returns `None` when neither a billing number nor a name is present.
+pub fn parse_family_doctor(xml: &str) -> Option<DomainReferralDoctor> {
+    let billing_no = tag_text(xml, "rdohip");
+    let name = tag_text(xml, "rd");
+
+    if billing_no.is_none() && name.is_none() {
+        return None;
+    }
+
+    let (last_name, first_name) = match name.as_deref().and_then(|n| n.split_once(',')) {
+        Some((last, first)) => (non_empty(last), non_empty(first)),
+        None => (name, None),
+    };
+
+    Some(DomainReferralDoctor {
+        billing_no,
+        first_name,
+        last_name,
+        spec_id: None,
+    })
+}
+
+fn tag_text(xml: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&close)?;
+    non_empty(&unescape(&xml[start..end]))
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    Some(s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Resolves the patient's family doctor against `professionalSpecialists`
+/// by billing number. Returns the doctor when it did *not* resolve, i.e.
+/// when it must be synced as an external Practitioner before the Patient
+/// that references it. Doctors without a billing number are never synced.
+pub async fn resolve_family_doctor(
+    db: &DatabaseConfig,
+    patient: &mut DomainPatient,
+) -> Result<Option<DomainExternalDoctor>> {
+    let Some(doctor) = patient.family_doctor.as_mut() else {
+        return Ok(None);
+    };
+    let Some(external) = doctor.to_external() else {
+        return Ok(None);
+    };
+
+    match find_specialist_by_referral_no(db, &external.billing_no).await? {
+        Some(spec_id) => {
+            doctor.spec_id = Some(spec_id);
+            Ok(None)
+        }
+        None => Ok(Some(external)),
+    }
+}
+
+/// Looks up the `professionalSpecialists.specId` whose `referralNo` is
+/// `billing_no`, ignoring deleted entries.
+pub async fn find_specialist_by_referral_no(db: &DatabaseConfig, billing_no: &str) -> Result<Option<String>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to resolve referral doctor")?;
+
+    let spec_id: Option<String> = conn
+        .exec_first(
+            "SELECT CAST(specId AS CHAR) FROM professionalSpecialists \
+             WHERE referralNo = :billing_no AND deleted = 0 ORDER BY specId LIMIT 1",
+            params! { "billing_no" => billing_no },
+        )
+        .await
+        .context("selecting professionalSpecialists row for referral doctor")?;
+
+    drop(conn);
+
+    Ok(spec_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_billing_number_and_name() {
+        let d = parse_family_doctor("<rdohip>12345</rdohip><rd>Smith, John</rd>").unwrap();
+        assert_eq!(d.billing_no.as_deref(), Some("12345"));
+        assert_eq!(d.last_name.as_deref(), Some("Smith"));
+        assert_eq!(d.first_name.as_deref(), Some("John"));
+        assert_eq!(d.spec_id, None);
+    }
+
+    #[test]
+    fn name_without_comma_is_last_name() {
+        let d = parse_family_doctor("<rdohip></rdohip><rd>Dr O&apos;Neil</rd>").unwrap();
+        assert_eq!(d.billing_no, None);
+        assert_eq!(d.last_name.as_deref(), Some("Dr O'Neil"));
+        assert_eq!(d.first_name, None);
+    }
+
+    #[test]
+    fn empty_fragment_yields_none() {
+        assert!(parse_family_doctor("<rdohip></rdohip><rd></rd>").is_none());
+        assert!(parse_family_doctor("").is_none());
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDate, NaiveTime, Utc};
+use mysql_async::prelude::*;
+use mysql_async::Row;
+use tracing::{info, warn};
+
+use crate::backfill::mysql_value_to_string;
+use crate::config::{DatabaseConfig, OscarConfig};
+use crate::domain::resource::DomainResource;
+use crate::domain::schedule::{DomainSchedule, DomainSlot, SlotStatus};
+use crate::mapping::shared_pool;
+use crate::mapping::syncable_provider;
+use crate::sources::{RowChange, RowOp};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .filter(|s| !s.is_empty())
+}
+
+/// One provider's schedule for one local date — the unit slots are
+/// (re)computed in. Any change to the day's `scheduledate` row, its
+/// template, or an appointment on it re-expands the whole day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderDay {
+    pub provider_no: String,
+    pub date: String,
+    /// Template name (`scheduledate.hour`) when known from the triggering
+    /// row; otherwise looked up from the day's active `scheduledate`.
+    pub template: Option<String>,
+    /// The day was deleted or marked unavailable: every cell is re-sent as
+    /// `busy-unavailable` so AMT stops offering it.
+    pub withdrawn: bool,
+}
+
+/// A `scheduletemplatecode` row, keyed by its one-character `code`.
+#[derive(Debug, Clone)]
+pub struct TemplateCode {
+    pub description: Option<String>,
+}
+
+/// Provider-day affected by a `scheduledate` row change.
+pub fn scheduledate_provider_day(change: &RowChange, columns: &ColumnMap) -> Option<ProviderDay> {
+    let provider_no = syncable_provider(lookup(change, columns, "provider_no"))?;
+    let date = lookup(change, columns, "sdate")?.to_string();
+    let status = lookup(change, columns, "status").map(str::trim);
+    let available = lookup(change, columns, "available").map(str::trim);
+
+    Some(ProviderDay {
+        provider_no,
+        date,
+        template: lookup(change, columns, "hour").map(|s| s.trim().to_string()),
+        withdrawn: change.op == RowOp::Delete || status == Some("D") || available == Some("0"),
+    })
+}
+
+/// Provider-day whose free/busy state an `appointment` row change affects.
+pub fn appointment_provider_day(change: &RowChange, columns: &ColumnMap) -> Option<ProviderDay> {
+    Some(ProviderDay {
+        provider_no: syncable_provider(lookup(change, columns, "provider_no"))?,
+        date: lookup(change, columns, "appointment_date")?.to_string(),
+        template: None,
+        withdrawn: false,
+    })
+}
+
+/// Whether `date` falls in the window AMT books into: today through
+/// `horizon_days` ahead. Past days are never re-published.
+pub fn within_horizon(date: NaiveDate, today: NaiveDate, horizon_days: u32) -> bool {
+    date >= today && date <= today + Duration::days(i64::from(horizon_days))
+}
+
+/// Expands a template `timecode` into one `DomainSlot` per cell.
+///
+/// Oscar divides the day evenly across the timecode string (96 characters is
+/// a 15-minute grid). Cells whose code is listed in `unavailable_codes` are
+/// `busy-unavailable`, so a cell closed by a template edit overwrites the
+/// Slot published for it earlier; the rest are `busy` when any non-cancelled
+/// appointment overlaps them and `free` otherwise.
+pub fn expand_template(
+    day: &ProviderDay,
+    timecode: &str,
+    codes: &HashMap<char, TemplateCode>,
+    unavailable_codes: &[String],
+    appointments: &[(NaiveTime, NaiveTime)],
+) -> Vec<DomainSlot> {
+    let cells: Vec<char> = timecode.chars().collect();
+    if cells.is_empty() || 1440 % cells.len() != 0 {
+        warn!(
+            "schedule mapping: template {:?} for provider_no={} has unusable timecode length {}",
+            day.template, day.provider_no, cells.len()
+        );
+        return Vec::new();
+    }
+    let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+        warn!("schedule mapping: invalid sdate {:?} for provider_no={}", day.date, day.provider_no);
+        return Vec::new();
+    };
+    let cell_minutes = (1440 / cells.len()) as i64;
+
+    cells
+        .iter()
+        .enumerate()
+        .map(|(i, code)| {
+            let start = date.and_hms_opt(0, 0, 0).expect("midnight") + Duration::minutes(i as i64 * cell_minutes);
+            let end = start + Duration::minutes(cell_minutes);
+
+            let closed = unavailable_codes.iter().any(|u| u.chars().eq([*code]));
+            let status = if day.withdrawn || closed {
+                SlotStatus::BusyUnavailable
+            } else if appointments
+                .iter()
+                .any(|(a_start, a_end)| date.and_time(*a_start) < end && date.and_time(*a_end) > start)
+            {
+                SlotStatus::Busy
+            } else {
+                SlotStatus::Free
+            };
+
+            DomainSlot {
+                slot_id: format!("{}:{}:{}", day.provider_no, day.date, start.format("%H%M")),
+                provider_no: day.provider_no.clone(),
+                start_date: start.date().format("%Y-%m-%d").to_string(),
+                start_time: start.time().format("%H:%M:%S").to_string(),
+                end_date: end.date().format("%Y-%m-%d").to_string(),
+                end_time: end.time().format("%H:%M:%S").to_string(),
+                status,
+                code: code.to_string(),
+                description: codes.get(code).and_then(|c| c.description.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Loads and expands one provider-day into its `Schedule` and `Slot`s.
+///
+/// Requires live queries against `scheduledate`, `scheduletemplate`,
+/// `scheduletemplatecode` and `appointment`. Days outside the booking
+/// horizon, or with no active schedule, yield nothing.
+pub async fn load_provider_day(
+    db: &DatabaseConfig,
+    oscar_cfg: &OscarConfig,
+    day: &ProviderDay,
+) -> Result<Vec<DomainResource>> {
+    let Some(tz) = oscar_cfg.timezone.as_deref().and_then(|t| t.parse::<chrono_tz::Tz>().ok()) else {
+        warn!("schedule mapping: no valid oscar timezone; skipping provider-day expansion");
+        return Ok(Vec::new());
+    };
+    let today = Utc::now().with_timezone(&tz).date_naive();
+    match NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") {
+        Ok(date) if within_horizon(date, today, oscar_cfg.schedule_horizon_days) => {}
+        _ => return Ok(Vec::new()),
+    }
+
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to expand provider schedule")?;
+
+    let template = match &day.template {
+        Some(t) => Some(t.clone()),
+        None => conn
+            .exec_first::<Option<String>, _, _>(
+                "SELECT hour FROM scheduledate \
+                 WHERE provider_no = :provider_no AND sdate = :sdate AND status = 'A' AND available = '1' \
+                 ORDER BY id DESC LIMIT 1",
+                params! { "provider_no" => &day.provider_no, "sdate" => &day.date },
+            )
+            .await
+            .context("selecting active scheduledate row")?
+            .flatten(),
+    };
+
+    let Some(template) = template else {
+        drop(conn);
+        info!(
+            "schedule mapping: provider_no={} has no schedule on {}; no slots to publish",
+            day.provider_no, day.date
+        );
+        return Ok(Vec::new());
+    };
+
+    // A provider's own template wins over the shared "Public" one.
+    let timecode: Option<String> = conn
+        .exec_first::<Option<String>, _, _>(
+            "SELECT timecode FROM scheduletemplate \
+             WHERE name = :name AND provider_no IN (:provider_no, 'Public') \
+             ORDER BY provider_no = 'Public' LIMIT 1",
+            params! { "name" => &template, "provider_no" => &day.provider_no },
+        )
+        .await
+        .context("selecting scheduletemplate timecode")?
+        .flatten();
+
+    let code_rows: Vec<Row> = conn
+        .query("SELECT code, description FROM scheduletemplatecode")
+        .await
+        .context("selecting scheduletemplatecode rows")?;
+
+    let appointment_rows: Vec<Row> = conn
+        .exec(
+            "SELECT start_time, end_time, status FROM appointment \
+             WHERE provider_no = :provider_no AND appointment_date = :date",
+            params! { "provider_no" => &day.provider_no, "date" => &day.date },
+        )
+        .await
+        .context("selecting appointments for provider-day")?;
+
+    drop(conn);
+
+    let Some(timecode) = timecode else {
+        warn!(
+            "schedule mapping: template {template:?} not found for provider_no={}",
+            day.provider_no
+        );
+        return Ok(Vec::new());
+    };
+
+    let codes: HashMap<char, TemplateCode> = code_rows
+        .into_iter()
+        .filter_map(|row| {
+            let values: Vec<Option<String>> = row.unwrap().iter().map(mysql_value_to_string).collect();
+            let code = values.first().cloned().flatten()?.chars().next()?;
+            let description = values.get(1).cloned().flatten().filter(|s| !s.trim().is_empty());
+            Some((code, TemplateCode { description }))
+        })
+        .collect();
+
+    let appointments: Vec<(NaiveTime, NaiveTime)> = appointment_rows
+        .into_iter()
+        .filter_map(|row| {
+            let values: Vec<Option<String>> = row.unwrap().iter().map(mysql_value_to_string).collect();
+            // Cancelled appointments ("C", "CS", ...) free their time back up.
+            if values.get(2).cloned().flatten().is_some_and(|s| s.starts_with('C')) {
+                return None;
+            }
+            let start = NaiveTime::parse_from_str(values.first().cloned().flatten()?.as_str(), "%H:%M:%S").ok()?;
+            let end = NaiveTime::parse_from_str(values.get(1).cloned().flatten()?.as_str(), "%H:%M:%S").ok()?;
+            Some((start, end))
+        })
+        .collect();
+
+    let day = ProviderDay {
+        template: Some(template),
+        ..day.clone()
+    };
+    let slots = expand_template(&day, &timecode, &codes, &oscar_cfg.schedule_unavailable_codes, &appointments);
+
+    let mut out = vec![DomainResource::Schedule(DomainSchedule {
+        provider_no: day.provider_no.clone(),
+    })];
+    out.extend(slots.into_iter().map(DomainResource::Slot));
+    Ok(out)
+}
+
+/// Provider-days inside the booking horizon that use the template touched
+/// by a `scheduletemplate` row change. A "Public" template is shared, so
+/// every provider scheduled on it is affected.
+pub async fn provider_days_for_template(
+    db: &DatabaseConfig,
+    oscar_cfg: &OscarConfig,
+    change: &RowChange,
+    columns: &ColumnMap,
+) -> Result<Vec<ProviderDay>> {
+    let (Some(owner), Some(name)) = (lookup(change, columns, "provider_no"), lookup(change, columns, "name")) else {
+        return Ok(Vec::new());
+    };
+    let Some(tz) = oscar_cfg.timezone.as_deref().and_then(|t| t.parse::<chrono_tz::Tz>().ok()) else {
+        return Ok(Vec::new());
+    };
+    let today = Utc::now().with_timezone(&tz).date_naive();
+    let until = today + Duration::days(i64::from(oscar_cfg.schedule_horizon_days));
+
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to find provider-days for template")?;
+
+    let rows: Vec<Row> = conn
+        .exec(
+            "SELECT provider_no, sdate FROM scheduledate \
+             WHERE hour = :name AND status = 'A' \
+               AND (provider_no = :owner OR :owner = 'Public') \
+               AND sdate BETWEEN :today AND :until",
+            params! {
+                "name" => name,
+                "owner" => owner,
+                "today" => today.format("%Y-%m-%d").to_string(),
+                "until" => until.format("%Y-%m-%d").to_string(),
+            },
+        )
+        .await
+        .context("selecting scheduledate rows for template")?;
+
+    drop(conn);
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            let values: Vec<Option<String>> = row.unwrap().iter().map(mysql_value_to_string).collect();
+            Some(ProviderDay {
+                provider_no: syncable_provider(values.first().cloned().flatten().as_deref())?,
+                date: values.get(1).cloned().flatten()?,
+                template: Some(name.to_string()),
+                withdrawn: false,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(withdrawn: bool) -> ProviderDay {
+        ProviderDay {
+            provider_no: "101".to_string(),
+            date: "2026-03-08".to_string(),
+            template: Some("AM clinic".to_string()),
+            withdrawn,
+        }
+    }
+
+    fn codes() -> HashMap<char, TemplateCode> {
+        [('1', TemplateCode { description: Some("15 min visit".to_string()) })]
+            .into_iter()
+            .collect()
+    }
+
+    fn t(s: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(s, "%H:%M:%S").unwrap()
+    }
+
+    /// 96-cell template with visits 09:00-10:00 and everything else closed.
+    fn timecode() -> String {
+        let mut cells = vec!['_'; 96];
+        for cell in cells.iter_mut().skip(36).take(4) {
+            *cell = '1';
+        }
+        cells.into_iter().collect()
+    }
+
+    /// The four bookable cells, 09:00-10:00.
+    fn bookable(slots: &[DomainSlot]) -> &[DomainSlot] {
+        &slots[36..40]
+    }
+
+    #[test]
+    fn expands_bookable_cells_on_fifteen_minute_grid() {
+        let all = expand_template(&day(false), &timecode(), &codes(), &["_".to_string()], &[]);
+        assert_eq!(all.len(), 96);
+        let slots = bookable(&all);
+        assert_eq!(slots[0].slot_id, "101:2026-03-08:0900");
+        assert_eq!(slots[0].start_time, "09:00:00");
+        assert_eq!(slots[0].end_time, "09:15:00");
+        assert_eq!(slots[3].end_time, "10:00:00");
+        assert_eq!(slots[0].description.as_deref(), Some("15 min visit"));
+        assert!(slots.iter().all(|s| s.status == SlotStatus::Free));
+    }
+
+    #[test]
+    fn closed_cells_are_published_unavailable() {
+        let slots = expand_template(&day(false), &timecode(), &codes(), &["_".to_string()], &[]);
+        assert_eq!(slots[35].slot_id, "101:2026-03-08:0845");
+        assert_eq!(slots[35].code, "_");
+        assert_eq!(slots[35].status, SlotStatus::BusyUnavailable);
+        assert_eq!(
+            slots.iter().filter(|s| s.status == SlotStatus::BusyUnavailable).count(),
+            92
+        );
+        // A multi-character entry isn't a prefix match on the cell code.
+        let slots = expand_template(&day(false), &timecode(), &codes(), &["_x".to_string()], &[]);
+        assert_eq!(slots[35].status, SlotStatus::Free);
+    }
+
+    #[test]
+    fn overlapping_appointment_marks_slot_busy() {
+        // Oscar end times are inclusive (09:29:59), so only two cells are busy.
+        let appts = [(t("09:15:00"), t("09:29:59")), (t("09:45:00"), t("09:59:59"))];
+        let slots = expand_template(&day(false), &timecode(), &codes(), &["_".to_string()], &appts);
+        let statuses: Vec<_> = bookable(&slots).iter().map(|s| s.status).collect();
+        assert_eq!(
+            statuses,
+            vec![SlotStatus::Free, SlotStatus::Busy, SlotStatus::Free, SlotStatus::Busy]
+        );
+    }
+
+    #[test]
+    fn withdrawn_day_marks_every_slot_unavailable() {
+        let slots = expand_template(&day(true), &timecode(), &codes(), &["_".to_string()], &[]);
+        assert_eq!(slots.len(), 96);
+        assert!(slots.iter().all(|s| s.status == SlotStatus::BusyUnavailable));
+    }
+
+    #[test]
+    fn last_cell_ends_at_next_midnight() {
+        let slots = expand_template(&day(false), &"1".repeat(48), &codes(), &["_".to_string()], &[]);
+        let last = slots.last().unwrap();
+        assert_eq!(last.start_time, "23:30:00");
+        assert_eq!(last.end_date, "2026-03-09");
+        assert_eq!(last.end_time, "00:00:00");
+    }
+
+    #[test]
+    fn unusable_timecode_length_yields_nothing() {
+        assert!(expand_template(&day(false), "1111111", &codes(), &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn horizon_excludes_past_and_far_future() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        assert!(within_horizon(today, today, 90));
+        assert!(!within_horizon(NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(), today, 90));
+        assert!(!within_horizon(today + Duration::days(91), today, 90));
+    }
+
+    #[test]
+    fn scheduledate_delete_withdraws_day() {
+        let cols: ColumnMap = ["provider_no", "sdate", "hour", "status", "available"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.to_string(), i))
+            .collect();
+        let change = |op: RowOp, status: &str| RowChange {
+            schema: "oscar".to_string(),
+            table: "scheduledate".to_string(),
+            op,
+            after: vec![Some("101"), Some("2026-03-08"), Some("AM clinic"), Some(status), Some("1")]
+                .into_iter()
+                .map(|v| v.map(str::to_string))
+                .collect(),
+            position: crate::sources::SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        };
+
+        let active = scheduledate_provider_day(&change(RowOp::Insert, "A"), &cols).unwrap();
+        assert!(!active.withdrawn);
+        assert_eq!(active.template.as_deref(), Some("AM clinic"));
+        assert!(scheduledate_provider_day(&change(RowOp::Update, "D"), &cols).unwrap().withdrawn);
+        assert!(scheduledate_provider_day(&change(RowOp::Delete, "A"), &cols).unwrap().withdrawn);
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use tracing::info;
+
+use crate::config::DatabaseConfig;
+use crate::domain::location::DomainLocation;
+use crate::mapping::shared_pool;
+use crate::sources::{RowChange, RowOp};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .filter(|s| !s.trim().is_empty())
+}
+
+fn text(change: &RowChange, columns: &ColumnMap, name: &str) -> Option<String> {
+    lookup(change, columns, name).map(|s| s.trim().to_string())
+}
+
+/// Maps one `site` row to a `DomainLocation`. `clinic_no` is left empty;
+/// `row_to_site_location` fills it from the `clinic` table.
+///
+/// Returns `None` if the row has no `site_id`.
+pub fn row_to_domain_location(change: &RowChange, columns: &ColumnMap) -> Option<DomainLocation> {
+    let Some(site_id) = text(change, columns, "site_id") else {
+        info!("site mapping: skipping row with no site_id");
+        return None;
+    };
+
+    // Older Oscar builds have no `status` column; absent means enabled.
+    let enabled = lookup(change, columns, "status").map(|s| s.trim() != "0").unwrap_or(true);
+
+    Some(DomainLocation {
+        site_id,
+        name: text(change, columns, "name"),
+        short_name: text(change, columns, "short_name"),
+        address_line: text(change, columns, "address"),
+        city: text(change, columns, "city"),
+        province: text(change, columns, "province"),
+        postal: text(change, columns, "postal"),
+        phone: text(change, columns, "phone"),
+        fax: text(change, columns, "fax"),
+        clinic_no: None,
+        active: enabled && change.op != RowOp::Delete,
+    })
+}
+
+/// Maps one `site` row and resolves its managing clinic with a live query.
+/// Oscar keeps a single `clinic` row per install, so the lowest `clinic_no`
+/// is taken.
+pub async fn row_to_site_location(
+    change: &RowChange,
+    columns: &ColumnMap,
+    db: &DatabaseConfig,
+) -> Result<Option<DomainLocation>> {
+    let Some(mut location) = row_to_domain_location(change, columns) else {
+        return Ok(None);
+    };
+
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to resolve site clinic")?;
+
+    let clinic_no: Option<String> = conn
+        .query_first("SELECT CAST(clinic_no AS CHAR) FROM clinic ORDER BY clinic_no LIMIT 1")
+        .await
+        .context("selecting clinic row for site")?;
+
+    drop(conn);
+
+    location.clinic_no = clinic_no;
+    Ok(Some(location))
+}
+
+/// Resolves an `appointment.location` value to a `site.site_id`. Oscar's
+/// multi-site scheduler writes the site name; some screens write the short
+/// name, so both are matched. `Ok(None)` for single-site clinics, where
+/// `location` is free text.
+pub async fn resolve_site_id(db: &DatabaseConfig, location: &str) -> Result<Option<String>> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to resolve appointment site")?;
+
+    let site_id: Option<String> = conn
+        .exec_first(
+            "SELECT CAST(site_id AS CHAR) FROM site \
+             WHERE name = :location OR short_name = :location \
+             ORDER BY name = :location DESC, site_id LIMIT 1",
+            params! { "location" => location.trim() },
+        )
+        .await
+        .context("selecting site for appointment location")?;
+
+    drop(conn);
+
+    Ok(site_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "site".to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: crate::sources::SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns() -> ColumnMap {
+        ["site_id", "name", "short_name", "phone", "city", "status"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.to_string(), i))
+            .collect()
+    }
+
+    #[test]
+    fn maps_site_row() {
+        let l = row_to_domain_location(
+            &change(
+                RowOp::Insert,
+                vec![Some("2"), Some("Downtown"), Some("DT"), Some("604-555-0100"), Some("Vancouver"), Some("1")],
+            ),
+            &columns(),
+        )
+        .unwrap();
+        assert_eq!(l.site_id, "2");
+        assert_eq!(l.name.as_deref(), Some("Downtown"));
+        assert_eq!(l.short_name.as_deref(), Some("DT"));
+        assert_eq!(l.clinic_no, None);
+        assert!(l.active);
+    }
+
+    #[test]
+    fn disabled_or_deleted_site_is_inactive() {
+        let values = |status| vec![Some("2"), Some("Downtown"), None, None, None, Some(status)];
+        assert!(!row_to_domain_location(&change(RowOp::Update, values("0")), &columns()).unwrap().active);
+        assert!(!row_to_domain_location(&change(RowOp::Delete, values("1")), &columns()).unwrap().active);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use mysql_async::prelude::*;
+use tracing::info;
+
+use crate::config::{DatabaseConfig, OscarConfig, WaitingListResource};
+use crate::domain::resource::DomainResource;
+use crate::domain::waiting_list::{DomainWaitingListEntry, WaitingListState};
+use crate::mapping::shared_pool;
+use crate::mapping::syncable_provider;
+use crate::sources::{RowChange, RowOp};
+
+pub type ColumnMap = HashMap<String, usize>;
+
+fn lookup<'a>(change: &'a RowChange, columns: &ColumnMap, name: &str) -> Option<&'a str> {
+    let idx = *columns.get(name)?;
+    change
+        .after
+        .get(idx)
+        .and_then(|v| v.as_deref())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Maps one `waitingList` row to a `DomainWaitingListEntry`. A row marked
+/// `is_history = 'Y'` or deleted has left the list and maps as `Removed`;
+/// `load_waiting_list_details` upgrades it to `Booked` when it can.
+///
+/// Returns `None` if the row has no `id`, `listID` or `demographic_no`.
+pub fn row_to_domain_waiting_list_entry(change: &RowChange, columns: &ColumnMap) -> Option<DomainWaitingListEntry> {
+    let (Some(entry_id), Some(list_id), Some(demographic_no)) = (
+        lookup(change, columns, "id"),
+        lookup(change, columns, "listID"),
+        lookup(change, columns, "demographic_no"),
+    ) else {
+        info!("waiting list mapping: skipping waitingList row with no id/listID/demographic_no");
+        return None;
+    };
+
+    let left_list = change.op == RowOp::Delete
+        || lookup(change, columns, "is_history").is_some_and(|h| h.eq_ignore_ascii_case("Y"));
+
+    Some(DomainWaitingListEntry {
+        entry_id: entry_id.to_string(),
+        list_id: list_id.to_string(),
+        list_name: None,
+        list_provider_no: None,
+        demographic_no: demographic_no.to_string(),
+        position: lookup(change, columns, "position").and_then(|p| p.parse().ok()),
+        note: lookup(change, columns, "note").map(str::to_string),
+        on_list_since: lookup(change, columns, "onListSince").map(str::to_string),
+        state: if left_list { WaitingListState::Removed } else { WaitingListState::Waiting },
+    })
+}
+
+/// Fills in the list's name and owner from `waitingListName` and, for an
+/// entry that has left the list, looks for the appointment it was booked
+/// into: the first non-cancelled, non-no-show appointment created for the
+/// patient since they were listed.
+pub async fn load_waiting_list_details(db: &DatabaseConfig, entry: &mut DomainWaitingListEntry) -> Result<()> {
+    let pool = shared_pool(db);
+    let mut conn = pool
+        .get_conn()
+        .await
+        .context("connecting to load waiting list details")?;
+
+    let list: Option<(Option<String>, Option<String>)> = conn
+        .exec_first(
+            "SELECT name, provider_no FROM waitingListName WHERE ID = :list_id",
+            params! { "list_id" => &entry.list_id },
+        )
+        .await
+        .context("selecting waitingListName")?;
+
+    let booked: Option<String> = match (&entry.state, &entry.on_list_since) {
+        (WaitingListState::Removed, Some(since)) => conn
+            .exec_first(
+                "SELECT CAST(appointment_no AS CHAR) FROM appointment \
+                 WHERE demographic_no = :demographic_no AND createdatetime >= :since \
+                 AND LEFT(status, 1) NOT IN ('C', 'N') \
+                 ORDER BY createdatetime, appointment_no LIMIT 1",
+                params! { "demographic_no" => &entry.demographic_no, "since" => since },
+            )
+            .await
+            .context("selecting appointment booked from waiting list")?,
+        _ => None,
+    };
+
+    drop(conn);
+
+    if let Some((name, provider_no)) = list {
+        entry.list_name = name.filter(|n| !n.trim().is_empty());
+        entry.list_provider_no = syncable_provider(provider_no.as_deref());
+    }
+    if let Some(appointment_no) = booked {
+        entry.state = WaitingListState::Booked { appointment_no };
+    }
+    Ok(())
+}
+
+/// Wraps an entry as the configured resource.
+pub fn waiting_list_resources(entry: DomainWaitingListEntry, oscar: &OscarConfig) -> Vec<DomainResource> {
+    match oscar.waiting_list_resource {
+        WaitingListResource::Appointment => vec![DomainResource::WaitingListAppointment(entry)],
+        WaitingListResource::ServiceRequest => vec![DomainResource::WaitingListRequest(entry)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sources::SourcePosition;
+
+    fn change(op: RowOp, values: Vec<Option<&str>>) -> RowChange {
+        RowChange {
+            schema: "oscar".to_string(),
+            table: "waitingList".to_string(),
+            op,
+            after: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            position: SourcePosition::FilePos {
+                file: String::new(),
+                pos: 0,
+            },
+        }
+    }
+
+    fn columns() -> ColumnMap {
+        ["id", "listID", "demographic_no", "note", "position", "onListSince", "is_history"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.to_string(), i))
+            .collect()
+    }
+
+    #[test]
+    fn maps_waiting_entry() {
+        let e = row_to_domain_waiting_list_entry(
+            &change(
+                RowOp::Insert,
+                vec![
+                    Some("5"),
+                    Some("2"),
+                    Some("123"),
+                    Some("Prefers mornings"),
+                    Some("3"),
+                    Some("2026-01-10 09:00:00"),
+                    Some("N"),
+                ],
+            ),
+            &columns(),
+        )
+        .unwrap();
+        assert_eq!(e.entry_id, "5");
+        assert_eq!(e.list_id, "2");
+        assert_eq!(e.position, Some(3));
+        assert_eq!(e.note.as_deref(), Some("Prefers mornings"));
+        assert!(e.is_waiting());
+    }
+
+    #[test]
+    fn history_or_deleted_entry_is_removed() {
+        let values = vec![Some("5"), Some("2"), Some("123"), None, Some("1"), None, Some("Y")];
+        let e = row_to_domain_waiting_list_entry(&change(RowOp::Update, values.clone()), &columns()).unwrap();
+        assert_eq!(e.state, WaitingListState::Removed);
+
+        let mut values = values;
+        values[6] = Some("N");
+        let e = row_to_domain_waiting_list_entry(&change(RowOp::Delete, values), &columns()).unwrap();
+        assert_eq!(e.state, WaitingListState::Removed);
+    }
+}
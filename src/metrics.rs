@@ -16,6 +16,9 @@ pub struct Metrics {
     retried: AtomicU64,
     dead_lettered: AtomicU64,
     dispatch_dropped: AtomicU64,
+    batch_fallbacks: AtomicU64,
+    unchanged: AtomicU64,
+    parked: AtomicU64,
     position: Mutex<String>,
 }
 
@@ -46,6 +49,21 @@ impl Metrics {
         self.dispatch_dropped.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// A transaction batch failed and its events were re-sent one by one.
+    pub fn inc_batch_fallbacks(&self) {
+        self.batch_fallbacks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// An event was skipped because its resource matched the last write.
+    pub fn inc_unchanged(&self) {
+        self.unchanged.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// An event was parked until a resource it references is synced.
+    pub fn inc_parked(&self) {
+        self.parked.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Records the current source position, e.g. `"mysql-bin.000002:4"`.
     pub fn set_position(&self, position: impl Into<String>) {
         if let Ok(mut p) = self.position.lock() {
@@ -53,13 +71,16 @@ impl Metrics {
         }
     }
 
-    fn snapshot(&self) -> (u64, u64, u64, u64, u64, String) {
+    fn snapshot(&self) -> (u64, u64, u64, u64, u64, u64, u64, u64, String) {
         (
             self.received.load(Ordering::Relaxed),
             self.synced.load(Ordering::Relaxed),
             self.retried.load(Ordering::Relaxed),
             self.dead_lettered.load(Ordering::Relaxed),
             self.dispatch_dropped.load(Ordering::Relaxed),
+            self.batch_fallbacks.load(Ordering::Relaxed),
+            self.unchanged.load(Ordering::Relaxed),
+            self.parked.load(Ordering::Relaxed),
             self.position.lock().map(|p| p.clone()).unwrap_or_default(),
         )
     }
@@ -71,10 +92,21 @@ pub fn spawn_reporter(metrics: SharedMetrics) -> tokio::task::JoinHandle<()> {
         let mut interval = tokio::time::interval(Duration::from_secs(60));
         loop {
             interval.tick().await;
-            let (received, synced, retried, dead_lettered, dispatch_dropped, position) = metrics.snapshot();
+            let (
+                received,
+                synced,
+                retried,
+                dead_lettered,
+                dispatch_dropped,
+                batch_fallbacks,
+                unchanged,
+                parked,
+                position,
+            ) = metrics.snapshot();
             info!(
                 "metrics: received={received} synced={synced} retried={retried} \
-                 dead_lettered={dead_lettered} dispatch_dropped={dispatch_dropped} position={position}"
+                 dead_lettered={dead_lettered} dispatch_dropped={dispatch_dropped} \
+                 batch_fallbacks={batch_fallbacks} unchanged={unchanged} parked={parked} position={position}"
             );
         }
     })
@@ -0,0 +1,161 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchParameter {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "SearchParameterType", tag = "2")]
+    pub r#type: i32,
+    #[prost(string, tag = "3")]
+    pub expression: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FhirVersion {
+    Unknown = 0,
+    Dstu2 = 1,
+    Stu3 = 2,
+    R4 = 4,
+    R4b = 45,
+    R5 = 5,
+}
+impl FhirVersion {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "FHIR_VERSION_UNKNOWN",
+            Self::Dstu2 => "DSTU2",
+            Self::Stu3 => "STU3",
+            Self::R4 => "R4",
+            Self::R4b => "R4B",
+            Self::R5 => "R5",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FHIR_VERSION_UNKNOWN" => Some(Self::Unknown),
+            "DSTU2" => Some(Self::Dstu2),
+            "STU3" => Some(Self::Stu3),
+            "R4" => Some(Self::R4),
+            "R4B" => Some(Self::R4b),
+            "R5" => Some(Self::R5),
+            _ => None,
+        }
+    }
+}
+/// TODO(b/244184211): Unify with StructureDefinitionKindCode
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StructureDefinitionKindValue {
+    KindUnknown = 0,
+    KindPrimitiveType = 1,
+    KindComplexType = 2,
+    KindResource = 3,
+    KindLogical = 4,
+}
+impl StructureDefinitionKindValue {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::KindUnknown => "KIND_UNKNOWN",
+            Self::KindPrimitiveType => "KIND_PRIMITIVE_TYPE",
+            Self::KindComplexType => "KIND_COMPLEX_TYPE",
+            Self::KindResource => "KIND_RESOURCE",
+            Self::KindLogical => "KIND_LOGICAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "KIND_UNKNOWN" => Some(Self::KindUnknown),
+            "KIND_PRIMITIVE_TYPE" => Some(Self::KindPrimitiveType),
+            "KIND_COMPLEX_TYPE" => Some(Self::KindComplexType),
+            "KIND_RESOURCE" => Some(Self::KindResource),
+            "KIND_LOGICAL" => Some(Self::KindLogical),
+            _ => None,
+        }
+    }
+}
+/// To annotate cardinality constraints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Requirement {
+    NotRequired = 0,
+    RequiredByFhir = 1,
+}
+impl Requirement {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::NotRequired => "NOT_REQUIRED",
+            Self::RequiredByFhir => "REQUIRED_BY_FHIR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOT_REQUIRED" => Some(Self::NotRequired),
+            "REQUIRED_BY_FHIR" => Some(Self::RequiredByFhir),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SearchParameterType {
+    InvalidSearchParameterType = 0,
+    Number = 1,
+    Date = 2,
+    String = 3,
+    Token = 4,
+    Reference = 5,
+    Composite = 6,
+    Quantity = 7,
+    Uri = 8,
+    Special = 9,
+}
+impl SearchParameterType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::InvalidSearchParameterType => "INVALID_SEARCH_PARAMETER_TYPE",
+            Self::Number => "NUMBER",
+            Self::Date => "DATE",
+            Self::String => "STRING",
+            Self::Token => "TOKEN",
+            Self::Reference => "REFERENCE",
+            Self::Composite => "COMPOSITE",
+            Self::Quantity => "QUANTITY",
+            Self::Uri => "URI",
+            Self::Special => "SPECIAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INVALID_SEARCH_PARAMETER_TYPE" => Some(Self::InvalidSearchParameterType),
+            "NUMBER" => Some(Self::Number),
+            "DATE" => Some(Self::Date),
+            "STRING" => Some(Self::String),
+            "TOKEN" => Some(Self::Token),
+            "REFERENCE" => Some(Self::Reference),
+            "COMPOSITE" => Some(Self::Composite),
+            "QUANTITY" => Some(Self::Quantity),
+            "URI" => Some(Self::Uri),
+            "SPECIAL" => Some(Self::Special),
+            _ => None,
+        }
+    }
+}
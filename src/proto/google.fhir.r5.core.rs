@@ -0,0 +1,24588 @@
+// This file is @generated by prost-build.
+/// Indicates whether the account is available to be used.
+/// See <http://hl7.org/fhir/account-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AccountStatusCode {}
+/// Nested message and enum types in `AccountStatusCode`.
+pub mod account_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+        OnHold = 4,
+        Unknown = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::OnHold => "ON_HOLD",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "ON_HOLD" => Some(Self::OnHold),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines behavior for an action or a group for how many times that item may be
+/// repeated. See <http://hl7.org/fhir/action-cardinality-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionCardinalityBehaviorCode {}
+/// Nested message and enum types in `ActionCardinalityBehaviorCode`.
+pub mod action_cardinality_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Single = 1,
+        Multiple = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Single => "SINGLE",
+                Self::Multiple => "MULTIPLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SINGLE" => Some(Self::Single),
+                "MULTIPLE" => Some(Self::Multiple),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines the kinds of conditions that can appear on actions.
+/// See <http://hl7.org/fhir/action-condition-kind>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionConditionKindCode {}
+/// Nested message and enum types in `ActionConditionKindCode`.
+pub mod action_condition_kind_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Applicability = 1,
+        Start = 2,
+        Stop = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Applicability => "APPLICABILITY",
+                Self::Start => "START",
+                Self::Stop => "STOP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "APPLICABILITY" => Some(Self::Applicability),
+                "START" => Some(Self::Start),
+                "STOP" => Some(Self::Stop),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines organization behavior of a group.
+/// See <http://hl7.org/fhir/action-grouping-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionGroupingBehaviorCode {}
+/// Nested message and enum types in `ActionGroupingBehaviorCode`.
+pub mod action_grouping_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        VisualGroup = 1,
+        LogicalGroup = 2,
+        SentenceGroup = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::VisualGroup => "VISUAL_GROUP",
+                Self::LogicalGroup => "LOGICAL_GROUP",
+                Self::SentenceGroup => "SENTENCE_GROUP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "VISUAL_GROUP" => Some(Self::VisualGroup),
+                "LOGICAL_GROUP" => Some(Self::LogicalGroup),
+                "SENTENCE_GROUP" => Some(Self::SentenceGroup),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of participant for the action.
+/// See <http://hl7.org/fhir/action-participant-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionParticipantTypeCode {}
+/// Nested message and enum types in `ActionParticipantTypeCode`.
+pub mod action_participant_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Careteam = 5,
+        Device = 4,
+        Group = 6,
+        Healthcareservice = 7,
+        Location = 8,
+        Organization = 9,
+        Patient = 1,
+        Practitioner = 2,
+        Practitionerrole = 10,
+        Relatedperson = 11,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Careteam => "CARETEAM",
+                Self::Device => "DEVICE",
+                Self::Group => "GROUP",
+                Self::Healthcareservice => "HEALTHCARESERVICE",
+                Self::Location => "LOCATION",
+                Self::Organization => "ORGANIZATION",
+                Self::Patient => "PATIENT",
+                Self::Practitioner => "PRACTITIONER",
+                Self::Practitionerrole => "PRACTITIONERROLE",
+                Self::Relatedperson => "RELATEDPERSON",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CARETEAM" => Some(Self::Careteam),
+                "DEVICE" => Some(Self::Device),
+                "GROUP" => Some(Self::Group),
+                "HEALTHCARESERVICE" => Some(Self::Healthcareservice),
+                "LOCATION" => Some(Self::Location),
+                "ORGANIZATION" => Some(Self::Organization),
+                "PATIENT" => Some(Self::Patient),
+                "PRACTITIONER" => Some(Self::Practitioner),
+                "PRACTITIONERROLE" => Some(Self::Practitionerrole),
+                "RELATEDPERSON" => Some(Self::Relatedperson),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines selection frequency behavior for an action or group.
+/// See <http://hl7.org/fhir/action-precheck-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionPrecheckBehaviorCode {}
+/// Nested message and enum types in `ActionPrecheckBehaviorCode`.
+pub mod action_precheck_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Yes = 1,
+        No = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Yes => "YES",
+                Self::No => "NO",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "YES" => Some(Self::Yes),
+                "NO" => Some(Self::No),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines the types of relationships between actions.
+/// See <http://hl7.org/fhir/action-relationship-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionRelationshipTypeCode {}
+/// Nested message and enum types in `ActionRelationshipTypeCode`.
+pub mod action_relationship_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Before = 2,
+        BeforeStart = 1,
+        BeforeEnd = 3,
+        Concurrent = 5,
+        ConcurrentWithStart = 4,
+        ConcurrentWithEnd = 6,
+        After = 8,
+        AfterStart = 7,
+        AfterEnd = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Before => "BEFORE",
+                Self::BeforeStart => "BEFORE_START",
+                Self::BeforeEnd => "BEFORE_END",
+                Self::Concurrent => "CONCURRENT",
+                Self::ConcurrentWithStart => "CONCURRENT_WITH_START",
+                Self::ConcurrentWithEnd => "CONCURRENT_WITH_END",
+                Self::After => "AFTER",
+                Self::AfterStart => "AFTER_START",
+                Self::AfterEnd => "AFTER_END",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BEFORE" => Some(Self::Before),
+                "BEFORE_START" => Some(Self::BeforeStart),
+                "BEFORE_END" => Some(Self::BeforeEnd),
+                "CONCURRENT" => Some(Self::Concurrent),
+                "CONCURRENT_WITH_START" => Some(Self::ConcurrentWithStart),
+                "CONCURRENT_WITH_END" => Some(Self::ConcurrentWithEnd),
+                "AFTER" => Some(Self::After),
+                "AFTER_START" => Some(Self::AfterStart),
+                "AFTER_END" => Some(Self::AfterEnd),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines expectations around whether an action or action group is required.
+/// See <http://hl7.org/fhir/action-required-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionRequiredBehaviorCode {}
+/// Nested message and enum types in `ActionRequiredBehaviorCode`.
+pub mod action_required_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Must = 1,
+        Could = 2,
+        MustUnlessDocumented = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Must => "MUST",
+                Self::Could => "COULD",
+                Self::MustUnlessDocumented => "MUST_UNLESS_DOCUMENTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MUST" => Some(Self::Must),
+                "COULD" => Some(Self::Could),
+                "MUST_UNLESS_DOCUMENTED" => Some(Self::MustUnlessDocumented),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines selection behavior of a group.
+/// See <http://hl7.org/fhir/action-selection-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ActionSelectionBehaviorCode {}
+/// Nested message and enum types in `ActionSelectionBehaviorCode`.
+pub mod action_selection_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Any = 1,
+        All = 2,
+        AllOrNone = 3,
+        ExactlyOne = 4,
+        AtMostOne = 5,
+        OneOrMore = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Any => "ANY",
+                Self::All => "ALL",
+                Self::AllOrNone => "ALL_OR_NONE",
+                Self::ExactlyOne => "EXACTLY_ONE",
+                Self::AtMostOne => "AT_MOST_ONE",
+                Self::OneOrMore => "ONE_OR_MORE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ANY" => Some(Self::Any),
+                "ALL" => Some(Self::All),
+                "ALL_OR_NONE" => Some(Self::AllOrNone),
+                "EXACTLY_ONE" => Some(Self::ExactlyOne),
+                "AT_MOST_ONE" => Some(Self::AtMostOne),
+                "ONE_OR_MORE" => Some(Self::OneOrMore),
+                _ => None,
+            }
+        }
+    }
+}
+/// Additional Binding Purpose
+/// See <http://hl7.org/fhir/CodeSystem/additional-binding-purpose>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AdditionalBindingPurposeCode {}
+/// Nested message and enum types in `AdditionalBindingPurposeCode`.
+pub mod additional_binding_purpose_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Maximum = 1,
+        Minimum = 2,
+        Required = 3,
+        Extensible = 4,
+        Candidate = 5,
+        Current = 6,
+        Preferred = 7,
+        Ui = 8,
+        Starter = 9,
+        Component = 10,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Maximum => "MAXIMUM",
+                Self::Minimum => "MINIMUM",
+                Self::Required => "REQUIRED",
+                Self::Extensible => "EXTENSIBLE",
+                Self::Candidate => "CANDIDATE",
+                Self::Current => "CURRENT",
+                Self::Preferred => "PREFERRED",
+                Self::Ui => "UI",
+                Self::Starter => "STARTER",
+                Self::Component => "COMPONENT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MAXIMUM" => Some(Self::Maximum),
+                "MINIMUM" => Some(Self::Minimum),
+                "REQUIRED" => Some(Self::Required),
+                "EXTENSIBLE" => Some(Self::Extensible),
+                "CANDIDATE" => Some(Self::Candidate),
+                "CURRENT" => Some(Self::Current),
+                "PREFERRED" => Some(Self::Preferred),
+                "UI" => Some(Self::Ui),
+                "STARTER" => Some(Self::Starter),
+                "COMPONENT" => Some(Self::Component),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of an address (physical / postal).
+/// See <http://hl7.org/fhir/address-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AddressTypeCode {}
+/// Nested message and enum types in `AddressTypeCode`.
+pub mod address_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Postal = 1,
+        Physical = 2,
+        Both = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Postal => "POSTAL",
+                Self::Physical => "PHYSICAL",
+                Self::Both => "BOTH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "POSTAL" => Some(Self::Postal),
+                "PHYSICAL" => Some(Self::Physical),
+                "BOTH" => Some(Self::Both),
+                _ => None,
+            }
+        }
+    }
+}
+/// The use of an address.
+/// See <http://hl7.org/fhir/address-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AddressUseCode {}
+/// Nested message and enum types in `AddressUseCode`.
+pub mod address_use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Home = 1,
+        Work = 2,
+        Temp = 3,
+        Old = 4,
+        Billing = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Home => "HOME",
+                Self::Work => "WORK",
+                Self::Temp => "TEMP",
+                Self::Old => "OLD",
+                Self::Billing => "BILLING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "HOME" => Some(Self::Home),
+                "WORK" => Some(Self::Work),
+                "TEMP" => Some(Self::Temp),
+                "OLD" => Some(Self::Old),
+                "BILLING" => Some(Self::Billing),
+                _ => None,
+            }
+        }
+    }
+}
+/// The gender of a person used for administrative purposes.
+/// See <http://hl7.org/fhir/administrative-gender>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AdministrativeGenderCode {}
+/// Nested message and enum types in `AdministrativeGenderCode`.
+pub mod administrative_gender_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Male = 1,
+        Female = 2,
+        Other = 3,
+        Unknown = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Male => "MALE",
+                Self::Female => "FEMALE",
+                Self::Other => "OTHER",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MALE" => Some(Self::Male),
+                "FEMALE" => Some(Self::Female),
+                "OTHER" => Some(Self::Other),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Overall nature of the adverse event, e.g. real or potential.
+/// See <http://hl7.org/fhir/adverse-event-actuality>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AdverseEventActualityCode {}
+/// Nested message and enum types in `AdverseEventActualityCode`.
+pub mod adverse_event_actuality_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Actual = 1,
+        Potential = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Actual => "ACTUAL",
+                Self::Potential => "POTENTIAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTUAL" => Some(Self::Actual),
+                "POTENTIAL" => Some(Self::Potential),
+                _ => None,
+            }
+        }
+    }
+}
+/// How resource references can be aggregated.
+/// See <http://hl7.org/fhir/resource-aggregation-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AggregationModeCode {}
+/// Nested message and enum types in `AggregationModeCode`.
+pub mod aggregation_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Contained = 1,
+        Referenced = 2,
+        Bundled = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Contained => "CONTAINED",
+                Self::Referenced => "REFERENCED",
+                Self::Bundled => "BUNDLED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CONTAINED" => Some(Self::Contained),
+                "REFERENCED" => Some(Self::Referenced),
+                "BUNDLED" => Some(Self::Bundled),
+                _ => None,
+            }
+        }
+    }
+}
+/// Category of an identified substance associated with allergies or
+/// intolerances. See <http://hl7.org/fhir/allergy-intolerance-category>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AllergyIntoleranceCategoryCode {}
+/// Nested message and enum types in `AllergyIntoleranceCategoryCode`.
+pub mod allergy_intolerance_category_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Food = 1,
+        Medication = 2,
+        Environment = 3,
+        Biologic = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Food => "FOOD",
+                Self::Medication => "MEDICATION",
+                Self::Environment => "ENVIRONMENT",
+                Self::Biologic => "BIOLOGIC",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FOOD" => Some(Self::Food),
+                "MEDICATION" => Some(Self::Medication),
+                "ENVIRONMENT" => Some(Self::Environment),
+                "BIOLOGIC" => Some(Self::Biologic),
+                _ => None,
+            }
+        }
+    }
+}
+/// Estimate of the potential clinical harm, or seriousness, of a reaction to an
+/// identified substance. See <http://hl7.org/fhir/allergy-intolerance-criticality>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AllergyIntoleranceCriticalityCode {}
+/// Nested message and enum types in `AllergyIntoleranceCriticalityCode`.
+pub mod allergy_intolerance_criticality_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Low = 1,
+        High = 2,
+        UnableToAssess = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Low => "LOW",
+                Self::High => "HIGH",
+                Self::UnableToAssess => "UNABLE_TO_ASSESS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "LOW" => Some(Self::Low),
+                "HIGH" => Some(Self::High),
+                "UNABLE_TO_ASSESS" => Some(Self::UnableToAssess),
+                _ => None,
+            }
+        }
+    }
+}
+/// Clinical assessment of the severity of a reaction event as a whole,
+/// potentially considering multiple different manifestations. See
+/// <http://hl7.org/fhir/reaction-event-severity>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AllergyIntoleranceSeverityCode {}
+/// Nested message and enum types in `AllergyIntoleranceSeverityCode`.
+pub mod allergy_intolerance_severity_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Mild = 1,
+        Moderate = 2,
+        Severe = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Mild => "MILD",
+                Self::Moderate => "MODERATE",
+                Self::Severe => "SEVERE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MILD" => Some(Self::Mild),
+                "MODERATE" => Some(Self::Moderate),
+                "SEVERE" => Some(Self::Severe),
+                _ => None,
+            }
+        }
+    }
+}
+/// The free/busy status of an appointment.
+/// See <http://hl7.org/fhir/appointmentstatus>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AppointmentStatusCode {}
+/// Nested message and enum types in `AppointmentStatusCode`.
+pub mod appointment_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Proposed = 1,
+        Pending = 2,
+        Booked = 3,
+        Arrived = 4,
+        Fulfilled = 5,
+        Cancelled = 6,
+        Noshow = 7,
+        EnteredInError = 8,
+        CheckedIn = 9,
+        Waitlist = 10,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Proposed => "PROPOSED",
+                Self::Pending => "PENDING",
+                Self::Booked => "BOOKED",
+                Self::Arrived => "ARRIVED",
+                Self::Fulfilled => "FULFILLED",
+                Self::Cancelled => "CANCELLED",
+                Self::Noshow => "NOSHOW",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::CheckedIn => "CHECKED_IN",
+                Self::Waitlist => "WAITLIST",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PROPOSED" => Some(Self::Proposed),
+                "PENDING" => Some(Self::Pending),
+                "BOOKED" => Some(Self::Booked),
+                "ARRIVED" => Some(Self::Arrived),
+                "FULFILLED" => Some(Self::Fulfilled),
+                "CANCELLED" => Some(Self::Cancelled),
+                "NOSHOW" => Some(Self::Noshow),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "CHECKED_IN" => Some(Self::CheckedIn),
+                "WAITLIST" => Some(Self::Waitlist),
+                _ => None,
+            }
+        }
+    }
+}
+/// Possible values for the disposition of a comment or change request, typically
+/// used for comments and change requests, to indicate the disposition of the
+/// responsible party towards the changes suggested by the comment or change
+/// request. See <http://hl7.org/fhir/artifactassessment-disposition>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ArtifactAssessmentDispositionCode {}
+/// Nested message and enum types in `ArtifactAssessmentDispositionCode`.
+pub mod artifact_assessment_disposition_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Unresolved = 1,
+        NotPersuasive = 2,
+        Persuasive = 3,
+        PersuasiveWithModification = 4,
+        NotPersuasiveWithModification = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Unresolved => "UNRESOLVED",
+                Self::NotPersuasive => "NOT_PERSUASIVE",
+                Self::Persuasive => "PERSUASIVE",
+                Self::PersuasiveWithModification => "PERSUASIVE_WITH_MODIFICATION",
+                Self::NotPersuasiveWithModification => "NOT_PERSUASIVE_WITH_MODIFICATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UNRESOLVED" => Some(Self::Unresolved),
+                "NOT_PERSUASIVE" => Some(Self::NotPersuasive),
+                "PERSUASIVE" => Some(Self::Persuasive),
+                "PERSUASIVE_WITH_MODIFICATION" => Some(Self::PersuasiveWithModification),
+                "NOT_PERSUASIVE_WITH_MODIFICATION" => {
+                    Some(Self::NotPersuasiveWithModification)
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of information contained in a component of an artifact assessment.
+/// See <http://hl7.org/fhir/artifactassessment-information-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ArtifactAssessmentInformationTypeCode {}
+/// Nested message and enum types in `ArtifactAssessmentInformationTypeCode`.
+pub mod artifact_assessment_information_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Comment = 1,
+        Classifier = 2,
+        Rating = 3,
+        Container = 4,
+        Response = 5,
+        ChangeRequest = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Comment => "COMMENT",
+                Self::Classifier => "CLASSIFIER",
+                Self::Rating => "RATING",
+                Self::Container => "CONTAINER",
+                Self::Response => "RESPONSE",
+                Self::ChangeRequest => "CHANGE_REQUEST",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "COMMENT" => Some(Self::Comment),
+                "CLASSIFIER" => Some(Self::Classifier),
+                "RATING" => Some(Self::Rating),
+                "CONTAINER" => Some(Self::Container),
+                "RESPONSE" => Some(Self::Response),
+                "CHANGE_REQUEST" => Some(Self::ChangeRequest),
+                _ => None,
+            }
+        }
+    }
+}
+/// Possible values for the workflow status of the comment or assessment,
+/// typically used to coordinate workflow around the process of accepting and
+/// rejecting changes and comments on the artifact. See
+/// <http://hl7.org/fhir/artifactassessment-workflow-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ArtifactAssessmentWorkflowStatusCode {}
+/// Nested message and enum types in `ArtifactAssessmentWorkflowStatusCode`.
+pub mod artifact_assessment_workflow_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Submitted = 1,
+        Triaged = 2,
+        WaitingForInput = 3,
+        ResolvedNoChange = 4,
+        ResolvedChangeRequired = 5,
+        Deferred = 6,
+        Duplicate = 7,
+        Applied = 8,
+        Published = 9,
+        EnteredInError = 10,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Submitted => "SUBMITTED",
+                Self::Triaged => "TRIAGED",
+                Self::WaitingForInput => "WAITING_FOR_INPUT",
+                Self::ResolvedNoChange => "RESOLVED_NO_CHANGE",
+                Self::ResolvedChangeRequired => "RESOLVED_CHANGE_REQUIRED",
+                Self::Deferred => "DEFERRED",
+                Self::Duplicate => "DUPLICATE",
+                Self::Applied => "APPLIED",
+                Self::Published => "PUBLISHED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SUBMITTED" => Some(Self::Submitted),
+                "TRIAGED" => Some(Self::Triaged),
+                "WAITING_FOR_INPUT" => Some(Self::WaitingForInput),
+                "RESOLVED_NO_CHANGE" => Some(Self::ResolvedNoChange),
+                "RESOLVED_CHANGE_REQUIRED" => Some(Self::ResolvedChangeRequired),
+                "DEFERRED" => Some(Self::Deferred),
+                "DUPLICATE" => Some(Self::Duplicate),
+                "APPLIED" => Some(Self::Applied),
+                "PUBLISHED" => Some(Self::Published),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of direction to use for assertion.
+/// See <http://hl7.org/fhir/assert-direction-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AssertionDirectionTypeCode {}
+/// Nested message and enum types in `AssertionDirectionTypeCode`.
+pub mod assertion_direction_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Response = 1,
+        Request = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Response => "RESPONSE",
+                Self::Request => "REQUEST",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "RESPONSE" => Some(Self::Response),
+                "REQUEST" => Some(Self::Request),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of manual completion to use for assertion.
+/// See <http://hl7.org/fhir/assert-manual-completion-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AssertionManualCompletionTypeCode {}
+/// Nested message and enum types in `AssertionManualCompletionTypeCode`.
+pub mod assertion_manual_completion_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Fail = 1,
+        Pass = 2,
+        Skip = 3,
+        Stop = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Fail => "FAIL",
+                Self::Pass => "PASS",
+                Self::Skip => "SKIP",
+                Self::Stop => "STOP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FAIL" => Some(Self::Fail),
+                "PASS" => Some(Self::Pass),
+                "SKIP" => Some(Self::Skip),
+                "STOP" => Some(Self::Stop),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of operator to use for assertion.
+/// See <http://hl7.org/fhir/assert-operator-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AssertionOperatorTypeCode {}
+/// Nested message and enum types in `AssertionOperatorTypeCode`.
+pub mod assertion_operator_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Equals = 1,
+        NotEquals = 2,
+        In = 3,
+        NotIn = 4,
+        GreaterThan = 5,
+        LessThan = 6,
+        Empty = 7,
+        NotEmpty = 8,
+        Contains = 9,
+        NotContains = 10,
+        Eval = 11,
+        ManualEval = 12,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Equals => "EQUALS",
+                Self::NotEquals => "NOT_EQUALS",
+                Self::In => "IN",
+                Self::NotIn => "NOT_IN",
+                Self::GreaterThan => "GREATER_THAN",
+                Self::LessThan => "LESS_THAN",
+                Self::Empty => "EMPTY",
+                Self::NotEmpty => "NOT_EMPTY",
+                Self::Contains => "CONTAINS",
+                Self::NotContains => "NOT_CONTAINS",
+                Self::Eval => "EVAL",
+                Self::ManualEval => "MANUAL_EVAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EQUALS" => Some(Self::Equals),
+                "NOT_EQUALS" => Some(Self::NotEquals),
+                "IN" => Some(Self::In),
+                "NOT_IN" => Some(Self::NotIn),
+                "GREATER_THAN" => Some(Self::GreaterThan),
+                "LESS_THAN" => Some(Self::LessThan),
+                "EMPTY" => Some(Self::Empty),
+                "NOT_EMPTY" => Some(Self::NotEmpty),
+                "CONTAINS" => Some(Self::Contains),
+                "NOT_CONTAINS" => Some(Self::NotContains),
+                "EVAL" => Some(Self::Eval),
+                "MANUAL_EVAL" => Some(Self::ManualEval),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of response code to use for assertion.
+/// See <http://hl7.org/fhir/assert-response-code-types>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AssertionResponseTypesCode {}
+/// Nested message and enum types in `AssertionResponseTypesCode`.
+pub mod assertion_response_types_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Continue = 13,
+        SwitchingProtocols = 14,
+        Okay = 1,
+        Created = 2,
+        Accepted = 15,
+        NonAuthoritativeInformation = 16,
+        NoContent = 3,
+        ResetContent = 17,
+        PartialContent = 18,
+        MultipleChoices = 19,
+        MovedPermanently = 20,
+        Found = 21,
+        SeeOther = 22,
+        NotModified = 4,
+        UseProxy = 23,
+        TemporaryRedirect = 24,
+        PermanentRedirect = 25,
+        BadRequest = 26,
+        Unauthorized = 27,
+        PaymentRequired = 28,
+        Forbidden = 6,
+        NotFound = 7,
+        MethodNotAllowed = 8,
+        NotAcceptable = 29,
+        ProxyAuthenticationRequired = 30,
+        RequestTimeout = 31,
+        Conflict = 9,
+        Gone = 10,
+        LengthRequired = 32,
+        PreconditionFailed = 11,
+        ContentTooLarge = 33,
+        UriTooLong = 34,
+        UnsupportedMediaType = 35,
+        RangeNotSatisfiable = 36,
+        ExpectationFailed = 37,
+        MisdirectedRequest = 38,
+        UnprocessableContent = 39,
+        UpgradeRequired = 40,
+        InternalServerError = 41,
+        NotImplemented = 42,
+        BadGateway = 43,
+        ServiceUnavailable = 44,
+        GatewayTimeout = 45,
+        HttpVersionNotSupported = 46,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Continue => "CONTINUE",
+                Self::SwitchingProtocols => "SWITCHING_PROTOCOLS",
+                Self::Okay => "OKAY",
+                Self::Created => "CREATED",
+                Self::Accepted => "ACCEPTED",
+                Self::NonAuthoritativeInformation => "NON_AUTHORITATIVE_INFORMATION",
+                Self::NoContent => "NO_CONTENT",
+                Self::ResetContent => "RESET_CONTENT",
+                Self::PartialContent => "PARTIAL_CONTENT",
+                Self::MultipleChoices => "MULTIPLE_CHOICES",
+                Self::MovedPermanently => "MOVED_PERMANENTLY",
+                Self::Found => "FOUND",
+                Self::SeeOther => "SEE_OTHER",
+                Self::NotModified => "NOT_MODIFIED",
+                Self::UseProxy => "USE_PROXY",
+                Self::TemporaryRedirect => "TEMPORARY_REDIRECT",
+                Self::PermanentRedirect => "PERMANENT_REDIRECT",
+                Self::BadRequest => "BAD_REQUEST",
+                Self::Unauthorized => "UNAUTHORIZED",
+                Self::PaymentRequired => "PAYMENT_REQUIRED",
+                Self::Forbidden => "FORBIDDEN",
+                Self::NotFound => "NOT_FOUND",
+                Self::MethodNotAllowed => "METHOD_NOT_ALLOWED",
+                Self::NotAcceptable => "NOT_ACCEPTABLE",
+                Self::ProxyAuthenticationRequired => "PROXY_AUTHENTICATION_REQUIRED",
+                Self::RequestTimeout => "REQUEST_TIMEOUT",
+                Self::Conflict => "CONFLICT",
+                Self::Gone => "GONE",
+                Self::LengthRequired => "LENGTH_REQUIRED",
+                Self::PreconditionFailed => "PRECONDITION_FAILED",
+                Self::ContentTooLarge => "CONTENT_TOO_LARGE",
+                Self::UriTooLong => "URI_TOO_LONG",
+                Self::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+                Self::RangeNotSatisfiable => "RANGE_NOT_SATISFIABLE",
+                Self::ExpectationFailed => "EXPECTATION_FAILED",
+                Self::MisdirectedRequest => "MISDIRECTED_REQUEST",
+                Self::UnprocessableContent => "UNPROCESSABLE_CONTENT",
+                Self::UpgradeRequired => "UPGRADE_REQUIRED",
+                Self::InternalServerError => "INTERNAL_SERVER_ERROR",
+                Self::NotImplemented => "NOT_IMPLEMENTED",
+                Self::BadGateway => "BAD_GATEWAY",
+                Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+                Self::GatewayTimeout => "GATEWAY_TIMEOUT",
+                Self::HttpVersionNotSupported => "HTTP_VERSION_NOT_SUPPORTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CONTINUE" => Some(Self::Continue),
+                "SWITCHING_PROTOCOLS" => Some(Self::SwitchingProtocols),
+                "OKAY" => Some(Self::Okay),
+                "CREATED" => Some(Self::Created),
+                "ACCEPTED" => Some(Self::Accepted),
+                "NON_AUTHORITATIVE_INFORMATION" => {
+                    Some(Self::NonAuthoritativeInformation)
+                }
+                "NO_CONTENT" => Some(Self::NoContent),
+                "RESET_CONTENT" => Some(Self::ResetContent),
+                "PARTIAL_CONTENT" => Some(Self::PartialContent),
+                "MULTIPLE_CHOICES" => Some(Self::MultipleChoices),
+                "MOVED_PERMANENTLY" => Some(Self::MovedPermanently),
+                "FOUND" => Some(Self::Found),
+                "SEE_OTHER" => Some(Self::SeeOther),
+                "NOT_MODIFIED" => Some(Self::NotModified),
+                "USE_PROXY" => Some(Self::UseProxy),
+                "TEMPORARY_REDIRECT" => Some(Self::TemporaryRedirect),
+                "PERMANENT_REDIRECT" => Some(Self::PermanentRedirect),
+                "BAD_REQUEST" => Some(Self::BadRequest),
+                "UNAUTHORIZED" => Some(Self::Unauthorized),
+                "PAYMENT_REQUIRED" => Some(Self::PaymentRequired),
+                "FORBIDDEN" => Some(Self::Forbidden),
+                "NOT_FOUND" => Some(Self::NotFound),
+                "METHOD_NOT_ALLOWED" => Some(Self::MethodNotAllowed),
+                "NOT_ACCEPTABLE" => Some(Self::NotAcceptable),
+                "PROXY_AUTHENTICATION_REQUIRED" => {
+                    Some(Self::ProxyAuthenticationRequired)
+                }
+                "REQUEST_TIMEOUT" => Some(Self::RequestTimeout),
+                "CONFLICT" => Some(Self::Conflict),
+                "GONE" => Some(Self::Gone),
+                "LENGTH_REQUIRED" => Some(Self::LengthRequired),
+                "PRECONDITION_FAILED" => Some(Self::PreconditionFailed),
+                "CONTENT_TOO_LARGE" => Some(Self::ContentTooLarge),
+                "URI_TOO_LONG" => Some(Self::UriTooLong),
+                "UNSUPPORTED_MEDIA_TYPE" => Some(Self::UnsupportedMediaType),
+                "RANGE_NOT_SATISFIABLE" => Some(Self::RangeNotSatisfiable),
+                "EXPECTATION_FAILED" => Some(Self::ExpectationFailed),
+                "MISDIRECTED_REQUEST" => Some(Self::MisdirectedRequest),
+                "UNPROCESSABLE_CONTENT" => Some(Self::UnprocessableContent),
+                "UPGRADE_REQUIRED" => Some(Self::UpgradeRequired),
+                "INTERNAL_SERVER_ERROR" => Some(Self::InternalServerError),
+                "NOT_IMPLEMENTED" => Some(Self::NotImplemented),
+                "BAD_GATEWAY" => Some(Self::BadGateway),
+                "SERVICE_UNAVAILABLE" => Some(Self::ServiceUnavailable),
+                "GATEWAY_TIMEOUT" => Some(Self::GatewayTimeout),
+                "HTTP_VERSION_NOT_SUPPORTED" => Some(Self::HttpVersionNotSupported),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicator for type of action performed during the event that generated the
+/// event. See <http://hl7.org/fhir/audit-event-action>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AuditEventActionCode {}
+/// Nested message and enum types in `AuditEventActionCode`.
+pub mod audit_event_action_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        C = 1,
+        R = 2,
+        U = 3,
+        D = 4,
+        E = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::C => "C",
+                Self::R => "R",
+                Self::U => "U",
+                Self::D => "D",
+                Self::E => "E",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "C" => Some(Self::C),
+                "R" => Some(Self::R),
+                "U" => Some(Self::U),
+                "D" => Some(Self::D),
+                "E" => Some(Self::E),
+                _ => None,
+            }
+        }
+    }
+}
+/// The severity of the audit entry.
+/// See <http://hl7.org/fhir/audit-event-severity>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AuditEventSeverityCode {}
+/// Nested message and enum types in `AuditEventSeverityCode`.
+pub mod audit_event_severity_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Emergency = 1,
+        Alert = 2,
+        Critical = 3,
+        Error = 4,
+        Warning = 5,
+        Notice = 6,
+        Informational = 7,
+        Debug = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Emergency => "EMERGENCY",
+                Self::Alert => "ALERT",
+                Self::Critical => "CRITICAL",
+                Self::Error => "ERROR",
+                Self::Warning => "WARNING",
+                Self::Notice => "NOTICE",
+                Self::Informational => "INFORMATIONAL",
+                Self::Debug => "DEBUG",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EMERGENCY" => Some(Self::Emergency),
+                "ALERT" => Some(Self::Alert),
+                "CRITICAL" => Some(Self::Critical),
+                "ERROR" => Some(Self::Error),
+                "WARNING" => Some(Self::Warning),
+                "NOTICE" => Some(Self::Notice),
+                "INFORMATIONAL" => Some(Self::Informational),
+                "DEBUG" => Some(Self::Debug),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indication of the degree of conformance expectations associated with a
+/// binding. See <http://hl7.org/fhir/binding-strength>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BindingStrengthCode {}
+/// Nested message and enum types in `BindingStrengthCode`.
+pub mod binding_strength_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Required = 1,
+        Extensible = 2,
+        Preferred = 3,
+        Example = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Required => "REQUIRED",
+                Self::Extensible => "EXTENSIBLE",
+                Self::Preferred => "PREFERRED",
+                Self::Example => "EXAMPLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REQUIRED" => Some(Self::Required),
+                "EXTENSIBLE" => Some(Self::Extensible),
+                "PREFERRED" => Some(Self::Preferred),
+                "EXAMPLE" => Some(Self::Example),
+                _ => None,
+            }
+        }
+    }
+}
+/// BiologicallyDerivedProductDispense Status Codes
+/// See <http://hl7.org/fhir/biologicallyderivedproductdispense-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BiologicallyDerivedProductDispenseCode {}
+/// Nested message and enum types in `BiologicallyDerivedProductDispenseCode`.
+pub mod biologically_derived_product_dispense_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preparation = 1,
+        InProgress = 2,
+        Allocated = 3,
+        Issued = 4,
+        Unfulfilled = 5,
+        Returned = 6,
+        EnteredInError = 7,
+        Unknown = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preparation => "PREPARATION",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Allocated => "ALLOCATED",
+                Self::Issued => "ISSUED",
+                Self::Unfulfilled => "UNFULFILLED",
+                Self::Returned => "RETURNED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PREPARATION" => Some(Self::Preparation),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "ALLOCATED" => Some(Self::Allocated),
+                "ISSUED" => Some(Self::Issued),
+                "UNFULFILLED" => Some(Self::Unfulfilled),
+                "RETURNED" => Some(Self::Returned),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates the purpose of a bundle - how it is intended to be used.
+/// See <http://hl7.org/fhir/bundle-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BundleTypeCode {}
+/// Nested message and enum types in `BundleTypeCode`.
+pub mod bundle_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Document = 1,
+        Message = 2,
+        Transaction = 3,
+        TransactionResponse = 4,
+        Batch = 5,
+        BatchResponse = 6,
+        History = 7,
+        Searchset = 8,
+        Collection = 9,
+        SubscriptionNotification = 10,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Document => "DOCUMENT",
+                Self::Message => "MESSAGE",
+                Self::Transaction => "TRANSACTION",
+                Self::TransactionResponse => "TRANSACTION_RESPONSE",
+                Self::Batch => "BATCH",
+                Self::BatchResponse => "BATCH_RESPONSE",
+                Self::History => "HISTORY",
+                Self::Searchset => "SEARCHSET",
+                Self::Collection => "COLLECTION",
+                Self::SubscriptionNotification => "SUBSCRIPTION_NOTIFICATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DOCUMENT" => Some(Self::Document),
+                "MESSAGE" => Some(Self::Message),
+                "TRANSACTION" => Some(Self::Transaction),
+                "TRANSACTION_RESPONSE" => Some(Self::TransactionResponse),
+                "BATCH" => Some(Self::Batch),
+                "BATCH_RESPONSE" => Some(Self::BatchResponse),
+                "HISTORY" => Some(Self::History),
+                "SEARCHSET" => Some(Self::Searchset),
+                "COLLECTION" => Some(Self::Collection),
+                "SUBSCRIPTION_NOTIFICATION" => Some(Self::SubscriptionNotification),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a capability statement is intended to be used.
+/// See <http://hl7.org/fhir/capability-statement-kind>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CapabilityStatementKindCode {}
+/// Nested message and enum types in `CapabilityStatementKindCode`.
+pub mod capability_statement_kind_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Instance = 1,
+        Capability = 2,
+        Requirements = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Instance => "INSTANCE",
+                Self::Capability => "CAPABILITY",
+                Self::Requirements => "REQUIREMENTS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INSTANCE" => Some(Self::Instance),
+                "CAPABILITY" => Some(Self::Capability),
+                "REQUIREMENTS" => Some(Self::Requirements),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates the status of the care team.
+/// See <http://hl7.org/fhir/care-team-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CareTeamStatusCode {}
+/// Nested message and enum types in `CareTeamStatusCode`.
+pub mod care_team_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Proposed = 1,
+        Active = 2,
+        Suspended = 3,
+        Inactive = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Proposed => "PROPOSED",
+                Self::Active => "ACTIVE",
+                Self::Suspended => "SUSPENDED",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PROPOSED" => Some(Self::Proposed),
+                "ACTIVE" => Some(Self::Active),
+                "SUSPENDED" => Some(Self::Suspended),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Logical grouping of characteristics.
+/// See <http://hl7.org/fhir/characteristic-combination>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CharacteristicCombinationCode {}
+/// Nested message and enum types in `CharacteristicCombinationCode`.
+pub mod characteristic_combination_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        AllOf = 1,
+        AnyOf = 2,
+        AtLeast = 3,
+        AtMost = 4,
+        Statistical = 5,
+        NetEffect = 6,
+        Dataset = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::AllOf => "ALL_OF",
+                Self::AnyOf => "ANY_OF",
+                Self::AtLeast => "AT_LEAST",
+                Self::AtMost => "AT_MOST",
+                Self::Statistical => "STATISTICAL",
+                Self::NetEffect => "NET_EFFECT",
+                Self::Dataset => "DATASET",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ALL_OF" => Some(Self::AllOf),
+                "ANY_OF" => Some(Self::AnyOf),
+                "AT_LEAST" => Some(Self::AtLeast),
+                "AT_MOST" => Some(Self::AtMost),
+                "STATISTICAL" => Some(Self::Statistical),
+                "NET_EFFECT" => Some(Self::NetEffect),
+                "DATASET" => Some(Self::Dataset),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of a ChargeItem.
+/// See <http://hl7.org/fhir/chargeitem-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ChargeItemStatusCode {}
+/// Nested message and enum types in `ChargeItemStatusCode`.
+pub mod charge_item_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Planned = 1,
+        Billable = 2,
+        NotBillable = 3,
+        Aborted = 4,
+        Billed = 5,
+        EnteredInError = 6,
+        Unknown = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Planned => "PLANNED",
+                Self::Billable => "BILLABLE",
+                Self::NotBillable => "NOT_BILLABLE",
+                Self::Aborted => "ABORTED",
+                Self::Billed => "BILLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PLANNED" => Some(Self::Planned),
+                "BILLABLE" => Some(Self::Billable),
+                "NOT_BILLABLE" => Some(Self::NotBillable),
+                "ABORTED" => Some(Self::Aborted),
+                "BILLED" => Some(Self::Billed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes Claim Processing Outcome codes.
+/// See <http://hl7.org/fhir/claim-outcome>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ClaimProcessingOutcomeCode {}
+/// Nested message and enum types in `ClaimProcessingOutcomeCode`.
+pub mod claim_processing_outcome_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Queued = 1,
+        Complete = 2,
+        Error = 3,
+        Partial = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Queued => "QUEUED",
+                Self::Complete => "COMPLETE",
+                Self::Error => "ERROR",
+                Self::Partial => "PARTIAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "QUEUED" => Some(Self::Queued),
+                "COMPLETE" => Some(Self::Complete),
+                "ERROR" => Some(Self::Error),
+                "PARTIAL" => Some(Self::Partial),
+                _ => None,
+            }
+        }
+    }
+}
+/// Overall defining type of this clinical use definition.
+/// See <http://hl7.org/fhir/clinical-use-definition-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ClinicalUseDefinitionTypeCode {}
+/// Nested message and enum types in `ClinicalUseDefinitionTypeCode`.
+pub mod clinical_use_definition_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Indication = 1,
+        Contraindication = 2,
+        Interaction = 3,
+        UndesirableEffect = 4,
+        Warning = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Indication => "INDICATION",
+                Self::Contraindication => "CONTRAINDICATION",
+                Self::Interaction => "INTERACTION",
+                Self::UndesirableEffect => "UNDESIRABLE_EFFECT",
+                Self::Warning => "WARNING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INDICATION" => Some(Self::Indication),
+                "CONTRAINDICATION" => Some(Self::Contraindication),
+                "INTERACTION" => Some(Self::Interaction),
+                "UNDESIRABLE_EFFECT" => Some(Self::UndesirableEffect),
+                "WARNING" => Some(Self::Warning),
+                _ => None,
+            }
+        }
+    }
+}
+/// The degree to which the server supports the code search parameter on
+/// ValueSet, if it is supported. See <http://hl7.org/fhir/code-search-support>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CodeSearchSupportCode {}
+/// Nested message and enum types in `CodeSearchSupportCode`.
+pub mod code_search_support_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        InCompose = 3,
+        InExpansion = 4,
+        InComposeOrExpansion = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::InCompose => "IN_COMPOSE",
+                Self::InExpansion => "IN_EXPANSION",
+                Self::InComposeOrExpansion => "IN_COMPOSE_OR_EXPANSION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN_COMPOSE" => Some(Self::InCompose),
+                "IN_EXPANSION" => Some(Self::InExpansion),
+                "IN_COMPOSE_OR_EXPANSION" => Some(Self::InComposeOrExpansion),
+                _ => None,
+            }
+        }
+    }
+}
+/// The extent of the content of the code system (the concepts and codes it
+/// defines) are represented in a code system resource. See
+/// <http://hl7.org/fhir/codesystem-content-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CodeSystemContentModeCode {}
+/// Nested message and enum types in `CodeSystemContentModeCode`.
+pub mod code_system_content_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NotPresent = 1,
+        Example = 2,
+        Fragment = 3,
+        Complete = 4,
+        Supplement = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NotPresent => "NOT_PRESENT",
+                Self::Example => "EXAMPLE",
+                Self::Fragment => "FRAGMENT",
+                Self::Complete => "COMPLETE",
+                Self::Supplement => "SUPPLEMENT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NOT_PRESENT" => Some(Self::NotPresent),
+                "EXAMPLE" => Some(Self::Example),
+                "FRAGMENT" => Some(Self::Fragment),
+                "COMPLETE" => Some(Self::Complete),
+                "SUPPLEMENT" => Some(Self::Supplement),
+                _ => None,
+            }
+        }
+    }
+}
+/// The meaning of the hierarchy of concepts in a code system.
+/// See <http://hl7.org/fhir/codesystem-hierarchy-meaning>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CodeSystemHierarchyMeaningCode {}
+/// Nested message and enum types in `CodeSystemHierarchyMeaningCode`.
+pub mod code_system_hierarchy_meaning_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        GroupedBy = 1,
+        IsA = 2,
+        PartOf = 3,
+        ClassifiedWith = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::GroupedBy => "GROUPED_BY",
+                Self::IsA => "IS_A",
+                Self::PartOf => "PART_OF",
+                Self::ClassifiedWith => "CLASSIFIED_WITH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "GROUPED_BY" => Some(Self::GroupedBy),
+                "IS_A" => Some(Self::IsA),
+                "PART_OF" => Some(Self::PartOf),
+                "CLASSIFIED_WITH" => Some(Self::ClassifiedWith),
+                _ => None,
+            }
+        }
+    }
+}
+/// This code system represents that named RGB colors found in the [CSS4
+/// specification](<https://www.w3.org/TR/css-color-4/>). The names are not case
+/// sensitive and different cases are encountered in common use (e.g. AliceBlue
+/// vs aliceblue. See <http://hl7.org/fhir/color-names>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ColorCode {}
+/// Nested message and enum types in `ColorCode`.
+pub mod color_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Aliceblue = 1,
+        Antiquewhite = 2,
+        Aqua = 3,
+        Aquamarine = 4,
+        Azure = 5,
+        Beige = 6,
+        Bisque = 7,
+        Black = 8,
+        Blanchedalmond = 9,
+        Blue = 10,
+        Blueviolet = 11,
+        Brown = 12,
+        Burlywood = 13,
+        Cadetblue = 14,
+        Chartreuse = 15,
+        Chocolate = 16,
+        Coral = 17,
+        Cornflowerblue = 18,
+        Cornsilk = 19,
+        Crimson = 20,
+        Cyan = 21,
+        Darkblue = 22,
+        Darkcyan = 23,
+        Darkgoldenrod = 24,
+        Darkgray = 25,
+        Darkgreen = 26,
+        Darkgrey = 27,
+        Darkkhaki = 28,
+        Darkmagenta = 29,
+        Darkolivegreen = 30,
+        Darkorange = 31,
+        Darkorchid = 32,
+        Darkred = 33,
+        Darksalmon = 34,
+        Darkseagreen = 35,
+        Darkslateblue = 36,
+        Darkslategray = 37,
+        Darkslategrey = 38,
+        Darkturquoise = 39,
+        Darkviolet = 40,
+        Deeppink = 41,
+        Deepskyblue = 42,
+        Dimgray = 43,
+        Dimgrey = 44,
+        Dodgerblue = 45,
+        Firebrick = 46,
+        Floralwhite = 47,
+        Forestgreen = 48,
+        Fuchsia = 49,
+        Gainsboro = 50,
+        Ghostwhite = 51,
+        Gold = 52,
+        Goldenrod = 53,
+        Gray = 54,
+        Green = 55,
+        Greenyellow = 56,
+        Grey = 57,
+        Honeydew = 58,
+        Hotpink = 59,
+        Indianred = 60,
+        Indigo = 61,
+        Ivory = 62,
+        Khaki = 63,
+        Lavender = 64,
+        Lavenderblush = 65,
+        Lawngreen = 66,
+        Lemonchiffon = 67,
+        Lightblue = 68,
+        Lightcoral = 69,
+        Lightcyan = 70,
+        Lightgoldenrodyellow = 71,
+        Lightgray = 72,
+        Lightgreen = 73,
+        Lightgrey = 74,
+        Lightpink = 75,
+        Lightsalmon = 76,
+        Lightseagreen = 77,
+        Lightskyblue = 78,
+        Lightslategray = 79,
+        Lightslategrey = 80,
+        Lightsteelblue = 81,
+        Lightyellow = 82,
+        Lime = 83,
+        Limegreen = 84,
+        Linen = 85,
+        Magenta = 86,
+        Maroon = 87,
+        Mediumaquamarine = 88,
+        Mediumblue = 89,
+        Mediumorchid = 90,
+        Mediumpurple = 91,
+        Mediumseagreen = 92,
+        Mediumslateblue = 93,
+        Mediumspringgreen = 94,
+        Mediumturquoise = 95,
+        Mediumvioletred = 96,
+        Midnightblue = 97,
+        Mintcream = 98,
+        Mistyrose = 99,
+        Moccasin = 100,
+        Navajowhite = 101,
+        Navy = 102,
+        Oldlace = 103,
+        Olive = 104,
+        Olivedrab = 105,
+        Orange = 106,
+        Orangered = 107,
+        Orchid = 108,
+        Palegoldenrod = 109,
+        Palegreen = 110,
+        Paleturquoise = 111,
+        Palevioletred = 112,
+        Papayawhip = 113,
+        Peachpuff = 114,
+        Peru = 115,
+        Pink = 116,
+        Plum = 117,
+        Powderblue = 118,
+        Purple = 119,
+        Rebeccapurple = 120,
+        Red = 121,
+        Rosybrown = 122,
+        Royalblue = 123,
+        Saddlebrown = 124,
+        Salmon = 125,
+        Sandybrown = 126,
+        Seagreen = 127,
+        Seashell = 128,
+        Sienna = 129,
+        Silver = 130,
+        Skyblue = 131,
+        Slateblue = 132,
+        Slategray = 133,
+        Slategrey = 134,
+        Snow = 135,
+        Springgreen = 136,
+        Steelblue = 137,
+        Tan = 138,
+        Teal = 139,
+        Thistle = 140,
+        Tomato = 141,
+        Turquoise = 142,
+        Violet = 143,
+        Wheat = 144,
+        White = 145,
+        Whitesmoke = 146,
+        Yellow = 147,
+        Yellowgreen = 148,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Aliceblue => "ALICEBLUE",
+                Self::Antiquewhite => "ANTIQUEWHITE",
+                Self::Aqua => "AQUA",
+                Self::Aquamarine => "AQUAMARINE",
+                Self::Azure => "AZURE",
+                Self::Beige => "BEIGE",
+                Self::Bisque => "BISQUE",
+                Self::Black => "BLACK",
+                Self::Blanchedalmond => "BLANCHEDALMOND",
+                Self::Blue => "BLUE",
+                Self::Blueviolet => "BLUEVIOLET",
+                Self::Brown => "BROWN",
+                Self::Burlywood => "BURLYWOOD",
+                Self::Cadetblue => "CADETBLUE",
+                Self::Chartreuse => "CHARTREUSE",
+                Self::Chocolate => "CHOCOLATE",
+                Self::Coral => "CORAL",
+                Self::Cornflowerblue => "CORNFLOWERBLUE",
+                Self::Cornsilk => "CORNSILK",
+                Self::Crimson => "CRIMSON",
+                Self::Cyan => "CYAN",
+                Self::Darkblue => "DARKBLUE",
+                Self::Darkcyan => "DARKCYAN",
+                Self::Darkgoldenrod => "DARKGOLDENROD",
+                Self::Darkgray => "DARKGRAY",
+                Self::Darkgreen => "DARKGREEN",
+                Self::Darkgrey => "DARKGREY",
+                Self::Darkkhaki => "DARKKHAKI",
+                Self::Darkmagenta => "DARKMAGENTA",
+                Self::Darkolivegreen => "DARKOLIVEGREEN",
+                Self::Darkorange => "DARKORANGE",
+                Self::Darkorchid => "DARKORCHID",
+                Self::Darkred => "DARKRED",
+                Self::Darksalmon => "DARKSALMON",
+                Self::Darkseagreen => "DARKSEAGREEN",
+                Self::Darkslateblue => "DARKSLATEBLUE",
+                Self::Darkslategray => "DARKSLATEGRAY",
+                Self::Darkslategrey => "DARKSLATEGREY",
+                Self::Darkturquoise => "DARKTURQUOISE",
+                Self::Darkviolet => "DARKVIOLET",
+                Self::Deeppink => "DEEPPINK",
+                Self::Deepskyblue => "DEEPSKYBLUE",
+                Self::Dimgray => "DIMGRAY",
+                Self::Dimgrey => "DIMGREY",
+                Self::Dodgerblue => "DODGERBLUE",
+                Self::Firebrick => "FIREBRICK",
+                Self::Floralwhite => "FLORALWHITE",
+                Self::Forestgreen => "FORESTGREEN",
+                Self::Fuchsia => "FUCHSIA",
+                Self::Gainsboro => "GAINSBORO",
+                Self::Ghostwhite => "GHOSTWHITE",
+                Self::Gold => "GOLD",
+                Self::Goldenrod => "GOLDENROD",
+                Self::Gray => "GRAY",
+                Self::Green => "GREEN",
+                Self::Greenyellow => "GREENYELLOW",
+                Self::Grey => "GREY",
+                Self::Honeydew => "HONEYDEW",
+                Self::Hotpink => "HOTPINK",
+                Self::Indianred => "INDIANRED",
+                Self::Indigo => "INDIGO",
+                Self::Ivory => "IVORY",
+                Self::Khaki => "KHAKI",
+                Self::Lavender => "LAVENDER",
+                Self::Lavenderblush => "LAVENDERBLUSH",
+                Self::Lawngreen => "LAWNGREEN",
+                Self::Lemonchiffon => "LEMONCHIFFON",
+                Self::Lightblue => "LIGHTBLUE",
+                Self::Lightcoral => "LIGHTCORAL",
+                Self::Lightcyan => "LIGHTCYAN",
+                Self::Lightgoldenrodyellow => "LIGHTGOLDENRODYELLOW",
+                Self::Lightgray => "LIGHTGRAY",
+                Self::Lightgreen => "LIGHTGREEN",
+                Self::Lightgrey => "LIGHTGREY",
+                Self::Lightpink => "LIGHTPINK",
+                Self::Lightsalmon => "LIGHTSALMON",
+                Self::Lightseagreen => "LIGHTSEAGREEN",
+                Self::Lightskyblue => "LIGHTSKYBLUE",
+                Self::Lightslategray => "LIGHTSLATEGRAY",
+                Self::Lightslategrey => "LIGHTSLATEGREY",
+                Self::Lightsteelblue => "LIGHTSTEELBLUE",
+                Self::Lightyellow => "LIGHTYELLOW",
+                Self::Lime => "LIME",
+                Self::Limegreen => "LIMEGREEN",
+                Self::Linen => "LINEN",
+                Self::Magenta => "MAGENTA",
+                Self::Maroon => "MAROON",
+                Self::Mediumaquamarine => "MEDIUMAQUAMARINE",
+                Self::Mediumblue => "MEDIUMBLUE",
+                Self::Mediumorchid => "MEDIUMORCHID",
+                Self::Mediumpurple => "MEDIUMPURPLE",
+                Self::Mediumseagreen => "MEDIUMSEAGREEN",
+                Self::Mediumslateblue => "MEDIUMSLATEBLUE",
+                Self::Mediumspringgreen => "MEDIUMSPRINGGREEN",
+                Self::Mediumturquoise => "MEDIUMTURQUOISE",
+                Self::Mediumvioletred => "MEDIUMVIOLETRED",
+                Self::Midnightblue => "MIDNIGHTBLUE",
+                Self::Mintcream => "MINTCREAM",
+                Self::Mistyrose => "MISTYROSE",
+                Self::Moccasin => "MOCCASIN",
+                Self::Navajowhite => "NAVAJOWHITE",
+                Self::Navy => "NAVY",
+                Self::Oldlace => "OLDLACE",
+                Self::Olive => "OLIVE",
+                Self::Olivedrab => "OLIVEDRAB",
+                Self::Orange => "ORANGE",
+                Self::Orangered => "ORANGERED",
+                Self::Orchid => "ORCHID",
+                Self::Palegoldenrod => "PALEGOLDENROD",
+                Self::Palegreen => "PALEGREEN",
+                Self::Paleturquoise => "PALETURQUOISE",
+                Self::Palevioletred => "PALEVIOLETRED",
+                Self::Papayawhip => "PAPAYAWHIP",
+                Self::Peachpuff => "PEACHPUFF",
+                Self::Peru => "PERU",
+                Self::Pink => "PINK",
+                Self::Plum => "PLUM",
+                Self::Powderblue => "POWDERBLUE",
+                Self::Purple => "PURPLE",
+                Self::Rebeccapurple => "REBECCAPURPLE",
+                Self::Red => "RED",
+                Self::Rosybrown => "ROSYBROWN",
+                Self::Royalblue => "ROYALBLUE",
+                Self::Saddlebrown => "SADDLEBROWN",
+                Self::Salmon => "SALMON",
+                Self::Sandybrown => "SANDYBROWN",
+                Self::Seagreen => "SEAGREEN",
+                Self::Seashell => "SEASHELL",
+                Self::Sienna => "SIENNA",
+                Self::Silver => "SILVER",
+                Self::Skyblue => "SKYBLUE",
+                Self::Slateblue => "SLATEBLUE",
+                Self::Slategray => "SLATEGRAY",
+                Self::Slategrey => "SLATEGREY",
+                Self::Snow => "SNOW",
+                Self::Springgreen => "SPRINGGREEN",
+                Self::Steelblue => "STEELBLUE",
+                Self::Tan => "TAN",
+                Self::Teal => "TEAL",
+                Self::Thistle => "THISTLE",
+                Self::Tomato => "TOMATO",
+                Self::Turquoise => "TURQUOISE",
+                Self::Violet => "VIOLET",
+                Self::Wheat => "WHEAT",
+                Self::White => "WHITE",
+                Self::Whitesmoke => "WHITESMOKE",
+                Self::Yellow => "YELLOW",
+                Self::Yellowgreen => "YELLOWGREEN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ALICEBLUE" => Some(Self::Aliceblue),
+                "ANTIQUEWHITE" => Some(Self::Antiquewhite),
+                "AQUA" => Some(Self::Aqua),
+                "AQUAMARINE" => Some(Self::Aquamarine),
+                "AZURE" => Some(Self::Azure),
+                "BEIGE" => Some(Self::Beige),
+                "BISQUE" => Some(Self::Bisque),
+                "BLACK" => Some(Self::Black),
+                "BLANCHEDALMOND" => Some(Self::Blanchedalmond),
+                "BLUE" => Some(Self::Blue),
+                "BLUEVIOLET" => Some(Self::Blueviolet),
+                "BROWN" => Some(Self::Brown),
+                "BURLYWOOD" => Some(Self::Burlywood),
+                "CADETBLUE" => Some(Self::Cadetblue),
+                "CHARTREUSE" => Some(Self::Chartreuse),
+                "CHOCOLATE" => Some(Self::Chocolate),
+                "CORAL" => Some(Self::Coral),
+                "CORNFLOWERBLUE" => Some(Self::Cornflowerblue),
+                "CORNSILK" => Some(Self::Cornsilk),
+                "CRIMSON" => Some(Self::Crimson),
+                "CYAN" => Some(Self::Cyan),
+                "DARKBLUE" => Some(Self::Darkblue),
+                "DARKCYAN" => Some(Self::Darkcyan),
+                "DARKGOLDENROD" => Some(Self::Darkgoldenrod),
+                "DARKGRAY" => Some(Self::Darkgray),
+                "DARKGREEN" => Some(Self::Darkgreen),
+                "DARKGREY" => Some(Self::Darkgrey),
+                "DARKKHAKI" => Some(Self::Darkkhaki),
+                "DARKMAGENTA" => Some(Self::Darkmagenta),
+                "DARKOLIVEGREEN" => Some(Self::Darkolivegreen),
+                "DARKORANGE" => Some(Self::Darkorange),
+                "DARKORCHID" => Some(Self::Darkorchid),
+                "DARKRED" => Some(Self::Darkred),
+                "DARKSALMON" => Some(Self::Darksalmon),
+                "DARKSEAGREEN" => Some(Self::Darkseagreen),
+                "DARKSLATEBLUE" => Some(Self::Darkslateblue),
+                "DARKSLATEGRAY" => Some(Self::Darkslategray),
+                "DARKSLATEGREY" => Some(Self::Darkslategrey),
+                "DARKTURQUOISE" => Some(Self::Darkturquoise),
+                "DARKVIOLET" => Some(Self::Darkviolet),
+                "DEEPPINK" => Some(Self::Deeppink),
+                "DEEPSKYBLUE" => Some(Self::Deepskyblue),
+                "DIMGRAY" => Some(Self::Dimgray),
+                "DIMGREY" => Some(Self::Dimgrey),
+                "DODGERBLUE" => Some(Self::Dodgerblue),
+                "FIREBRICK" => Some(Self::Firebrick),
+                "FLORALWHITE" => Some(Self::Floralwhite),
+                "FORESTGREEN" => Some(Self::Forestgreen),
+                "FUCHSIA" => Some(Self::Fuchsia),
+                "GAINSBORO" => Some(Self::Gainsboro),
+                "GHOSTWHITE" => Some(Self::Ghostwhite),
+                "GOLD" => Some(Self::Gold),
+                "GOLDENROD" => Some(Self::Goldenrod),
+                "GRAY" => Some(Self::Gray),
+                "GREEN" => Some(Self::Green),
+                "GREENYELLOW" => Some(Self::Greenyellow),
+                "GREY" => Some(Self::Grey),
+                "HONEYDEW" => Some(Self::Honeydew),
+                "HOTPINK" => Some(Self::Hotpink),
+                "INDIANRED" => Some(Self::Indianred),
+                "INDIGO" => Some(Self::Indigo),
+                "IVORY" => Some(Self::Ivory),
+                "KHAKI" => Some(Self::Khaki),
+                "LAVENDER" => Some(Self::Lavender),
+                "LAVENDERBLUSH" => Some(Self::Lavenderblush),
+                "LAWNGREEN" => Some(Self::Lawngreen),
+                "LEMONCHIFFON" => Some(Self::Lemonchiffon),
+                "LIGHTBLUE" => Some(Self::Lightblue),
+                "LIGHTCORAL" => Some(Self::Lightcoral),
+                "LIGHTCYAN" => Some(Self::Lightcyan),
+                "LIGHTGOLDENRODYELLOW" => Some(Self::Lightgoldenrodyellow),
+                "LIGHTGRAY" => Some(Self::Lightgray),
+                "LIGHTGREEN" => Some(Self::Lightgreen),
+                "LIGHTGREY" => Some(Self::Lightgrey),
+                "LIGHTPINK" => Some(Self::Lightpink),
+                "LIGHTSALMON" => Some(Self::Lightsalmon),
+                "LIGHTSEAGREEN" => Some(Self::Lightseagreen),
+                "LIGHTSKYBLUE" => Some(Self::Lightskyblue),
+                "LIGHTSLATEGRAY" => Some(Self::Lightslategray),
+                "LIGHTSLATEGREY" => Some(Self::Lightslategrey),
+                "LIGHTSTEELBLUE" => Some(Self::Lightsteelblue),
+                "LIGHTYELLOW" => Some(Self::Lightyellow),
+                "LIME" => Some(Self::Lime),
+                "LIMEGREEN" => Some(Self::Limegreen),
+                "LINEN" => Some(Self::Linen),
+                "MAGENTA" => Some(Self::Magenta),
+                "MAROON" => Some(Self::Maroon),
+                "MEDIUMAQUAMARINE" => Some(Self::Mediumaquamarine),
+                "MEDIUMBLUE" => Some(Self::Mediumblue),
+                "MEDIUMORCHID" => Some(Self::Mediumorchid),
+                "MEDIUMPURPLE" => Some(Self::Mediumpurple),
+                "MEDIUMSEAGREEN" => Some(Self::Mediumseagreen),
+                "MEDIUMSLATEBLUE" => Some(Self::Mediumslateblue),
+                "MEDIUMSPRINGGREEN" => Some(Self::Mediumspringgreen),
+                "MEDIUMTURQUOISE" => Some(Self::Mediumturquoise),
+                "MEDIUMVIOLETRED" => Some(Self::Mediumvioletred),
+                "MIDNIGHTBLUE" => Some(Self::Midnightblue),
+                "MINTCREAM" => Some(Self::Mintcream),
+                "MISTYROSE" => Some(Self::Mistyrose),
+                "MOCCASIN" => Some(Self::Moccasin),
+                "NAVAJOWHITE" => Some(Self::Navajowhite),
+                "NAVY" => Some(Self::Navy),
+                "OLDLACE" => Some(Self::Oldlace),
+                "OLIVE" => Some(Self::Olive),
+                "OLIVEDRAB" => Some(Self::Olivedrab),
+                "ORANGE" => Some(Self::Orange),
+                "ORANGERED" => Some(Self::Orangered),
+                "ORCHID" => Some(Self::Orchid),
+                "PALEGOLDENROD" => Some(Self::Palegoldenrod),
+                "PALEGREEN" => Some(Self::Palegreen),
+                "PALETURQUOISE" => Some(Self::Paleturquoise),
+                "PALEVIOLETRED" => Some(Self::Palevioletred),
+                "PAPAYAWHIP" => Some(Self::Papayawhip),
+                "PEACHPUFF" => Some(Self::Peachpuff),
+                "PERU" => Some(Self::Peru),
+                "PINK" => Some(Self::Pink),
+                "PLUM" => Some(Self::Plum),
+                "POWDERBLUE" => Some(Self::Powderblue),
+                "PURPLE" => Some(Self::Purple),
+                "REBECCAPURPLE" => Some(Self::Rebeccapurple),
+                "RED" => Some(Self::Red),
+                "ROSYBROWN" => Some(Self::Rosybrown),
+                "ROYALBLUE" => Some(Self::Royalblue),
+                "SADDLEBROWN" => Some(Self::Saddlebrown),
+                "SALMON" => Some(Self::Salmon),
+                "SANDYBROWN" => Some(Self::Sandybrown),
+                "SEAGREEN" => Some(Self::Seagreen),
+                "SEASHELL" => Some(Self::Seashell),
+                "SIENNA" => Some(Self::Sienna),
+                "SILVER" => Some(Self::Silver),
+                "SKYBLUE" => Some(Self::Skyblue),
+                "SLATEBLUE" => Some(Self::Slateblue),
+                "SLATEGRAY" => Some(Self::Slategray),
+                "SLATEGREY" => Some(Self::Slategrey),
+                "SNOW" => Some(Self::Snow),
+                "SPRINGGREEN" => Some(Self::Springgreen),
+                "STEELBLUE" => Some(Self::Steelblue),
+                "TAN" => Some(Self::Tan),
+                "TEAL" => Some(Self::Teal),
+                "THISTLE" => Some(Self::Thistle),
+                "TOMATO" => Some(Self::Tomato),
+                "TURQUOISE" => Some(Self::Turquoise),
+                "VIOLET" => Some(Self::Violet),
+                "WHEAT" => Some(Self::Wheat),
+                "WHITE" => Some(Self::White),
+                "WHITESMOKE" => Some(Self::Whitesmoke),
+                "YELLOW" => Some(Self::Yellow),
+                "YELLOWGREEN" => Some(Self::Yellowgreen),
+                _ => None,
+            }
+        }
+    }
+}
+/// RGB values for color. Valid codes are #RRGGBB where RR, GG, and BB are two
+/// letter hex codes, values 00..ff. codes are case insensitive. This code system
+/// does not define the 16million+ valid RGB codes (terminology servers will need
+/// code to support the RGB grammar). See <http://hl7.org/fhir/color-rgb>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ColorRgbCode {}
+/// Nested message and enum types in `ColorRGBCode`.
+pub mod color_rgb_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                _ => None,
+            }
+        }
+    }
+}
+/// Which type a compartment definition describes.
+/// See <http://hl7.org/fhir/compartment-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CompartmentTypeCode {}
+/// Nested message and enum types in `CompartmentTypeCode`.
+pub mod compartment_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Patient = 1,
+        Encounter = 2,
+        RelatedPerson = 3,
+        Practitioner = 4,
+        Device = 5,
+        EpisodeOfCare = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Patient => "PATIENT",
+                Self::Encounter => "ENCOUNTER",
+                Self::RelatedPerson => "RELATED_PERSON",
+                Self::Practitioner => "PRACTITIONER",
+                Self::Device => "DEVICE",
+                Self::EpisodeOfCare => "EPISODE_OF_CARE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PATIENT" => Some(Self::Patient),
+                "ENCOUNTER" => Some(Self::Encounter),
+                "RELATED_PERSON" => Some(Self::RelatedPerson),
+                "PRACTITIONER" => Some(Self::Practitioner),
+                "DEVICE" => Some(Self::Device),
+                "EPISODE_OF_CARE" => Some(Self::EpisodeOfCare),
+                _ => None,
+            }
+        }
+    }
+}
+/// The workflow/clinical status of the composition.
+/// See <http://hl7.org/fhir/composition-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CompositionStatusCode {}
+/// Nested message and enum types in `CompositionStatusCode`.
+pub mod composition_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Registered = 5,
+        Partial = 6,
+        Preliminary = 1,
+        Final = 2,
+        Amended = 3,
+        Corrected = 7,
+        Appended = 8,
+        Cancelled = 9,
+        EnteredInError = 4,
+        Deprecated = 10,
+        Unknown = 11,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Registered => "REGISTERED",
+                Self::Partial => "PARTIAL",
+                Self::Preliminary => "PRELIMINARY",
+                Self::Final => "FINAL",
+                Self::Amended => "AMENDED",
+                Self::Corrected => "CORRECTED",
+                Self::Appended => "APPENDED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Deprecated => "DEPRECATED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REGISTERED" => Some(Self::Registered),
+                "PARTIAL" => Some(Self::Partial),
+                "PRELIMINARY" => Some(Self::Preliminary),
+                "FINAL" => Some(Self::Final),
+                "AMENDED" => Some(Self::Amended),
+                "CORRECTED" => Some(Self::Corrected),
+                "APPENDED" => Some(Self::Appended),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "DEPRECATED" => Some(Self::Deprecated),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of a ConceptMap map attribute value.
+/// See <http://hl7.org/fhir/conceptmap-attribute-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConceptMapAttributeTypeCode {}
+/// Nested message and enum types in `ConceptMapAttributeTypeCode`.
+pub mod concept_map_attribute_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Code = 1,
+        Coding = 2,
+        String = 3,
+        Boolean = 4,
+        Quantity = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Code => "CODE",
+                Self::Coding => "CODING",
+                Self::String => "STRING",
+                Self::Boolean => "BOOLEAN",
+                Self::Quantity => "QUANTITY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CODE" => Some(Self::Code),
+                "CODING" => Some(Self::Coding),
+                "STRING" => Some(Self::String),
+                "BOOLEAN" => Some(Self::Boolean),
+                "QUANTITY" => Some(Self::Quantity),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines which action to take if there is no match in the group.
+/// See <http://hl7.org/fhir/conceptmap-unmapped-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConceptMapGroupUnmappedModeCode {}
+/// Nested message and enum types in `ConceptMapGroupUnmappedModeCode`.
+pub mod concept_map_group_unmapped_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        UseSourceCode = 4,
+        Fixed = 2,
+        OtherMap = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::UseSourceCode => "USE_SOURCE_CODE",
+                Self::Fixed => "FIXED",
+                Self::OtherMap => "OTHER_MAP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "USE_SOURCE_CODE" => Some(Self::UseSourceCode),
+                "FIXED" => Some(Self::Fixed),
+                "OTHER_MAP" => Some(Self::OtherMap),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of a ConceptMap mapping property value.
+/// See <http://hl7.org/fhir/conceptmap-property-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConceptMapPropertyTypeCode {}
+/// Nested message and enum types in `ConceptMapPropertyTypeCode`.
+pub mod concept_map_property_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Coding = 1,
+        String = 2,
+        Integer = 3,
+        Boolean = 4,
+        DateTime = 5,
+        Decimal = 6,
+        Code = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Coding => "CODING",
+                Self::String => "STRING",
+                Self::Integer => "INTEGER",
+                Self::Boolean => "BOOLEAN",
+                Self::DateTime => "DATE_TIME",
+                Self::Decimal => "DECIMAL",
+                Self::Code => "CODE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CODING" => Some(Self::Coding),
+                "STRING" => Some(Self::String),
+                "INTEGER" => Some(Self::Integer),
+                "BOOLEAN" => Some(Self::Boolean),
+                "DATE_TIME" => Some(Self::DateTime),
+                "DECIMAL" => Some(Self::Decimal),
+                "CODE" => Some(Self::Code),
+                _ => None,
+            }
+        }
+    }
+}
+/// The relationship between concepts.
+/// See <http://hl7.org/fhir/concept-map-relationship>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConceptMapRelationshipCode {}
+/// Nested message and enum types in `ConceptMapRelationshipCode`.
+pub mod concept_map_relationship_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        RelatedTo = 1,
+        Equivalent = 2,
+        SourceIsNarrowerThanTarget = 3,
+        SourceIsBroaderThanTarget = 4,
+        NotRelatedTo = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::RelatedTo => "RELATED_TO",
+                Self::Equivalent => "EQUIVALENT",
+                Self::SourceIsNarrowerThanTarget => "SOURCE_IS_NARROWER_THAN_TARGET",
+                Self::SourceIsBroaderThanTarget => "SOURCE_IS_BROADER_THAN_TARGET",
+                Self::NotRelatedTo => "NOT_RELATED_TO",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "RELATED_TO" => Some(Self::RelatedTo),
+                "EQUIVALENT" => Some(Self::Equivalent),
+                "SOURCE_IS_NARROWER_THAN_TARGET" => {
+                    Some(Self::SourceIsNarrowerThanTarget)
+                }
+                "SOURCE_IS_BROADER_THAN_TARGET" => Some(Self::SourceIsBroaderThanTarget),
+                "NOT_RELATED_TO" => Some(Self::NotRelatedTo),
+                _ => None,
+            }
+        }
+    }
+}
+/// Kind of precondition for the condition.
+/// See <http://hl7.org/fhir/condition-precondition-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConditionPreconditionTypeCode {}
+/// Nested message and enum types in `ConditionPreconditionTypeCode`.
+pub mod condition_precondition_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Sensitive = 1,
+        Specific = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Sensitive => "SENSITIVE",
+                Self::Specific => "SPECIFIC",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SENSITIVE" => Some(Self::Sensitive),
+                "SPECIFIC" => Some(Self::Specific),
+                _ => None,
+            }
+        }
+    }
+}
+/// The use of a questionnaire.
+/// See <http://hl7.org/fhir/condition-questionnaire-purpose>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConditionQuestionnairePurposeCode {}
+/// Nested message and enum types in `ConditionQuestionnairePurposeCode`.
+pub mod condition_questionnaire_purpose_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preadmit = 1,
+        DiffDiagnosis = 2,
+        Outcome = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preadmit => "PREADMIT",
+                Self::DiffDiagnosis => "DIFF_DIAGNOSIS",
+                Self::Outcome => "OUTCOME",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PREADMIT" => Some(Self::Preadmit),
+                "DIFF_DIAGNOSIS" => Some(Self::DiffDiagnosis),
+                "OUTCOME" => Some(Self::Outcome),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code that indicates how the server supports conditional delete.
+/// See <http://hl7.org/fhir/conditional-delete-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConditionalDeleteStatusCode {}
+/// Nested message and enum types in `ConditionalDeleteStatusCode`.
+pub mod conditional_delete_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NotSupported = 1,
+        Single = 2,
+        Multiple = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NotSupported => "NOT_SUPPORTED",
+                Self::Single => "SINGLE",
+                Self::Multiple => "MULTIPLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NOT_SUPPORTED" => Some(Self::NotSupported),
+                "SINGLE" => Some(Self::Single),
+                "MULTIPLE" => Some(Self::Multiple),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code that indicates how the server supports conditional read.
+/// See <http://hl7.org/fhir/conditional-read-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConditionalReadStatusCode {}
+/// Nested message and enum types in `ConditionalReadStatusCode`.
+pub mod conditional_read_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NotSupported = 1,
+        ModifiedSince = 2,
+        NotMatch = 3,
+        FullSupport = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NotSupported => "NOT_SUPPORTED",
+                Self::ModifiedSince => "MODIFIED_SINCE",
+                Self::NotMatch => "NOT_MATCH",
+                Self::FullSupport => "FULL_SUPPORT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NOT_SUPPORTED" => Some(Self::NotSupported),
+                "MODIFIED_SINCE" => Some(Self::ModifiedSince),
+                "NOT_MATCH" => Some(Self::NotMatch),
+                "FULL_SUPPORT" => Some(Self::FullSupport),
+                _ => None,
+            }
+        }
+    }
+}
+/// ConformanceExpectation
+/// See <http://hl7.org/fhir/conformance-expectation>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConformanceExpectationCode {}
+/// Nested message and enum types in `ConformanceExpectationCode`.
+pub mod conformance_expectation_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Shall = 1,
+        Should = 2,
+        May = 3,
+        ShouldNot = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Shall => "SHALL",
+                Self::Should => "SHOULD",
+                Self::May => "MAY",
+                Self::ShouldNot => "SHOULD_NOT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SHALL" => Some(Self::Shall),
+                "SHOULD" => Some(Self::Should),
+                "MAY" => Some(Self::May),
+                "SHOULD_NOT" => Some(Self::ShouldNot),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a resource reference is interpreted when testing consent restrictions.
+/// See <http://hl7.org/fhir/consent-data-meaning>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConsentDataMeaningCode {}
+/// Nested message and enum types in `ConsentDataMeaningCode`.
+pub mod consent_data_meaning_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Instance = 1,
+        Related = 2,
+        Dependents = 3,
+        Authoredby = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Instance => "INSTANCE",
+                Self::Related => "RELATED",
+                Self::Dependents => "DEPENDENTS",
+                Self::Authoredby => "AUTHOREDBY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INSTANCE" => Some(Self::Instance),
+                "RELATED" => Some(Self::Related),
+                "DEPENDENTS" => Some(Self::Dependents),
+                "AUTHOREDBY" => Some(Self::Authoredby),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a rule statement is applied, such as adding additional consent or
+/// removing consent. See <http://hl7.org/fhir/consent-provision-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConsentProvisionTypeCode {}
+/// Nested message and enum types in `ConsentProvisionTypeCode`.
+pub mod consent_provision_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Deny = 1,
+        Permit = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Deny => "DENY",
+                Self::Permit => "PERMIT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DENY" => Some(Self::Deny),
+                "PERMIT" => Some(Self::Permit),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates the state of the consent.
+/// See <http://hl7.org/fhir/consent-state-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConsentStateCode {}
+/// Nested message and enum types in `ConsentStateCode`.
+pub mod consent_state_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Active = 3,
+        Inactive = 5,
+        NotDone = 7,
+        EnteredInError = 6,
+        Unknown = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::NotDone => "NOT_DONE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "NOT_DONE" => Some(Self::NotDone),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// SHALL applications comply with this constraint?
+/// See <http://hl7.org/fhir/constraint-severity>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConstraintSeverityCode {}
+/// Nested message and enum types in `ConstraintSeverityCode`.
+pub mod constraint_severity_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Error = 1,
+        Warning = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Error => "ERROR",
+                Self::Warning => "WARNING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ERROR" => Some(Self::Error),
+                "WARNING" => Some(Self::Warning),
+                _ => None,
+            }
+        }
+    }
+}
+/// Telecommunications form for contact point.
+/// See <http://hl7.org/fhir/contact-point-system>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContactPointSystemCode {}
+/// Nested message and enum types in `ContactPointSystemCode`.
+pub mod contact_point_system_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Phone = 1,
+        Fax = 2,
+        Email = 3,
+        Pager = 4,
+        Url = 5,
+        Sms = 6,
+        Other = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Phone => "PHONE",
+                Self::Fax => "FAX",
+                Self::Email => "EMAIL",
+                Self::Pager => "PAGER",
+                Self::Url => "URL",
+                Self::Sms => "SMS",
+                Self::Other => "OTHER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PHONE" => Some(Self::Phone),
+                "FAX" => Some(Self::Fax),
+                "EMAIL" => Some(Self::Email),
+                "PAGER" => Some(Self::Pager),
+                "URL" => Some(Self::Url),
+                "SMS" => Some(Self::Sms),
+                "OTHER" => Some(Self::Other),
+                _ => None,
+            }
+        }
+    }
+}
+/// Use of contact point.
+/// See <http://hl7.org/fhir/contact-point-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContactPointUseCode {}
+/// Nested message and enum types in `ContactPointUseCode`.
+pub mod contact_point_use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Home = 1,
+        Work = 2,
+        Temp = 3,
+        Old = 4,
+        Mobile = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Home => "HOME",
+                Self::Work => "WORK",
+                Self::Temp => "TEMP",
+                Self::Old => "OLD",
+                Self::Mobile => "MOBILE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "HOME" => Some(Self::Home),
+                "WORK" => Some(Self::Work),
+                "TEMP" => Some(Self::Temp),
+                "OLD" => Some(Self::Old),
+                "MOBILE" => Some(Self::Mobile),
+                _ => None,
+            }
+        }
+    }
+}
+/// This CodeSystem contains FHIR-defined contract publication status types. Each
+/// definition includes usage notes explaining the precedence order in contract
+/// publication lifecycle - i.e., while only some stages are required, the order
+/// in which they may occur is deterministic. See
+/// <http://hl7.org/fhir/contract-publicationstatus>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContractPublicationStatusCode {}
+/// Nested message and enum types in `ContractPublicationStatusCode`.
+pub mod contract_publication_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Amended = 1,
+        Appended = 2,
+        Cancelled = 3,
+        Disputed = 4,
+        EnteredInError = 5,
+        Executable = 6,
+        Executed = 7,
+        Negotiable = 8,
+        Offered = 9,
+        Policy = 10,
+        Rejected = 11,
+        Renewed = 12,
+        Revoked = 13,
+        Resolved = 14,
+        Terminated = 15,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Amended => "AMENDED",
+                Self::Appended => "APPENDED",
+                Self::Cancelled => "CANCELLED",
+                Self::Disputed => "DISPUTED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Executable => "EXECUTABLE",
+                Self::Executed => "EXECUTED",
+                Self::Negotiable => "NEGOTIABLE",
+                Self::Offered => "OFFERED",
+                Self::Policy => "POLICY",
+                Self::Rejected => "REJECTED",
+                Self::Renewed => "RENEWED",
+                Self::Revoked => "REVOKED",
+                Self::Resolved => "RESOLVED",
+                Self::Terminated => "TERMINATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AMENDED" => Some(Self::Amended),
+                "APPENDED" => Some(Self::Appended),
+                "CANCELLED" => Some(Self::Cancelled),
+                "DISPUTED" => Some(Self::Disputed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "EXECUTABLE" => Some(Self::Executable),
+                "EXECUTED" => Some(Self::Executed),
+                "NEGOTIABLE" => Some(Self::Negotiable),
+                "OFFERED" => Some(Self::Offered),
+                "POLICY" => Some(Self::Policy),
+                "REJECTED" => Some(Self::Rejected),
+                "RENEWED" => Some(Self::Renewed),
+                "REVOKED" => Some(Self::Revoked),
+                "RESOLVED" => Some(Self::Resolved),
+                "TERMINATED" => Some(Self::Terminated),
+                _ => None,
+            }
+        }
+    }
+}
+/// This CodeSystem contains FHIR-defined contract status types. Each definition
+/// includes usage notes explaining the precedence order in contract lifecycle -
+/// i.e., while only some stages are required, the order in which they may occur
+/// is deterministic; and a map to comparable FHIR and v.3 status codes. It
+/// follows guidance about use of status codes in FHIR in [the Status Codes
+/// Grid](sc.html). See <http://hl7.org/fhir/contract-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContractStatusCode {}
+/// Nested message and enum types in `ContractStatusCode`.
+pub mod contract_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Amended = 1,
+        Appended = 2,
+        Cancelled = 3,
+        Disputed = 4,
+        EnteredInError = 5,
+        Executable = 6,
+        Executed = 7,
+        Negotiable = 8,
+        Offered = 9,
+        Policy = 10,
+        Rejected = 11,
+        Renewed = 12,
+        Revoked = 13,
+        Resolved = 14,
+        Terminated = 15,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Amended => "AMENDED",
+                Self::Appended => "APPENDED",
+                Self::Cancelled => "CANCELLED",
+                Self::Disputed => "DISPUTED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Executable => "EXECUTABLE",
+                Self::Executed => "EXECUTED",
+                Self::Negotiable => "NEGOTIABLE",
+                Self::Offered => "OFFERED",
+                Self::Policy => "POLICY",
+                Self::Rejected => "REJECTED",
+                Self::Renewed => "RENEWED",
+                Self::Revoked => "REVOKED",
+                Self::Resolved => "RESOLVED",
+                Self::Terminated => "TERMINATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AMENDED" => Some(Self::Amended),
+                "APPENDED" => Some(Self::Appended),
+                "CANCELLED" => Some(Self::Cancelled),
+                "DISPUTED" => Some(Self::Disputed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "EXECUTABLE" => Some(Self::Executable),
+                "EXECUTED" => Some(Self::Executed),
+                "NEGOTIABLE" => Some(Self::Negotiable),
+                "OFFERED" => Some(Self::Offered),
+                "POLICY" => Some(Self::Policy),
+                "REJECTED" => Some(Self::Rejected),
+                "RENEWED" => Some(Self::Renewed),
+                "REVOKED" => Some(Self::Revoked),
+                "RESOLVED" => Some(Self::Resolved),
+                "TERMINATED" => Some(Self::Terminated),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of contributor.
+/// See <http://hl7.org/fhir/contributor-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContributorTypeCode {}
+/// Nested message and enum types in `ContributorTypeCode`.
+pub mod contributor_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Author = 1,
+        Editor = 2,
+        Reviewer = 3,
+        Endorser = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Author => "AUTHOR",
+                Self::Editor => "EDITOR",
+                Self::Reviewer => "REVIEWER",
+                Self::Endorser => "ENDORSER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AUTHOR" => Some(Self::Author),
+                "EDITOR" => Some(Self::Editor),
+                "REVIEWER" => Some(Self::Reviewer),
+                "ENDORSER" => Some(Self::Endorser),
+                _ => None,
+            }
+        }
+    }
+}
+/// Behavior a server can exhibit when a criteria state does not exist (e.g.,
+/// state prior to a create or after a delete). See
+/// <http://hl7.org/fhir/subscriptiontopic-cr-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CriteriaNotExistsBehaviorCode {}
+/// Nested message and enum types in `CriteriaNotExistsBehaviorCode`.
+pub mod criteria_not_exists_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        TestPasses = 1,
+        TestFails = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::TestPasses => "TEST_PASSES",
+                Self::TestFails => "TEST_FAILS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "TEST_PASSES" => Some(Self::TestPasses),
+                "TEST_FAILS" => Some(Self::TestFails),
+                _ => None,
+            }
+        }
+    }
+}
+/// The days of the week.
+/// See <http://hl7.org/fhir/days-of-week>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DaysOfWeekCode {}
+/// Nested message and enum types in `DaysOfWeekCode`.
+pub mod days_of_week_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Mon = 1,
+        Tue = 2,
+        Wed = 3,
+        Thu = 4,
+        Fri = 5,
+        Sat = 6,
+        Sun = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Mon => "MON",
+                Self::Tue => "TUE",
+                Self::Wed => "WED",
+                Self::Thu => "THU",
+                Self::Fri => "FRI",
+                Self::Sat => "SAT",
+                Self::Sun => "SUN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MON" => Some(Self::Mon),
+                "TUE" => Some(Self::Tue),
+                "WED" => Some(Self::Wed),
+                "THU" => Some(Self::Thu),
+                "FRI" => Some(Self::Fri),
+                "SAT" => Some(Self::Sat),
+                "SUN" => Some(Self::Sun),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates the potential degree of impact of the identified issue on the
+/// patient. See <http://hl7.org/fhir/detectedissue-severity>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DetectedIssueSeverityCode {}
+/// Nested message and enum types in `DetectedIssueSeverityCode`.
+pub mod detected_issue_severity_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        High = 1,
+        Moderate = 2,
+        Low = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::High => "HIGH",
+                Self::Moderate => "MODERATE",
+                Self::Low => "LOW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "HIGH" => Some(Self::High),
+                "MODERATE" => Some(Self::Moderate),
+                "LOW" => Some(Self::Low),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates the status of the detected issue. This code system contains only
+/// status codes that are not already defined and used from the ObservationStatus
+/// code system. See <http://hl7.org/fhir/detectedissue-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DetectedIssueStatusCode {}
+/// Nested message and enum types in `DetectedIssueStatusCode`.
+pub mod detected_issue_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Mitigated = 1,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Mitigated => "MITIGATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MITIGATED" => Some(Self::Mitigated),
+                _ => None,
+            }
+        }
+    }
+}
+/// DeviceAssociation Status Codes
+/// See <http://hl7.org/fhir/deviceassociation-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceAssociationCode {}
+/// Nested message and enum types in `DeviceAssociationCode`.
+pub mod device_association_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Implanted = 1,
+        Explanted = 2,
+        EnteredInError = 3,
+        Attached = 4,
+        Unknown = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Implanted => "IMPLANTED",
+                Self::Explanted => "EXPLANTED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Attached => "ATTACHED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IMPLANTED" => Some(Self::Implanted),
+                "EXPLANTED" => Some(Self::Explanted),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "ATTACHED" => Some(Self::Attached),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// DeviceAssociation Status Reason Codes
+/// See <http://hl7.org/fhir/deviceassociation-status-reason>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceAssociationStatusReason {}
+/// Nested message and enum types in `DeviceAssociationStatusReason`.
+pub mod device_association_status_reason {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Attached = 1,
+        Disconnected = 2,
+        Failed = 3,
+        Placed = 4,
+        Replaced = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Attached => "ATTACHED",
+                Self::Disconnected => "DISCONNECTED",
+                Self::Failed => "FAILED",
+                Self::Placed => "PLACED",
+                Self::Replaced => "REPLACED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ATTACHED" => Some(Self::Attached),
+                "DISCONNECTED" => Some(Self::Disconnected),
+                "FAILED" => Some(Self::Failed),
+                "PLACED" => Some(Self::Placed),
+                "REPLACED" => Some(Self::Replaced),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of relation between devices.
+/// See <http://hl7.org/fhir/device-correctiveactionscope>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceCorrectiveActionScopeCode {}
+/// Nested message and enum types in `DeviceCorrectiveActionScopeCode`.
+pub mod device_corrective_action_scope_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Model = 1,
+        LotNumbers = 2,
+        SerialNumbers = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Model => "MODEL",
+                Self::LotNumbers => "LOT_NUMBERS",
+                Self::SerialNumbers => "SERIAL_NUMBERS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MODEL" => Some(Self::Model),
+                "LOT_NUMBERS" => Some(Self::LotNumbers),
+                "SERIAL_NUMBERS" => Some(Self::SerialNumbers),
+                _ => None,
+            }
+        }
+    }
+}
+/// DeviceDispense Status Codes
+/// See <http://hl7.org/fhir/devicedispense-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceDispenseStatusCode {}
+/// Nested message and enum types in `DeviceDispenseStatusCode`.
+pub mod device_dispense_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preparation = 1,
+        InProgress = 2,
+        Cancelled = 3,
+        OnHold = 4,
+        Completed = 5,
+        EnteredInError = 6,
+        Stopped = 7,
+        Declined = 8,
+        Unknown = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preparation => "PREPARATION",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Cancelled => "CANCELLED",
+                Self::OnHold => "ON_HOLD",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Stopped => "STOPPED",
+                Self::Declined => "DECLINED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PREPARATION" => Some(Self::Preparation),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ON_HOLD" => Some(Self::OnHold),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "STOPPED" => Some(Self::Stopped),
+                "DECLINED" => Some(Self::Declined),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Describes the state of a metric calibration.
+/// See <http://hl7.org/fhir/metric-calibration-state>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceMetricCalibrationStateCode {}
+/// Nested message and enum types in `DeviceMetricCalibrationStateCode`.
+pub mod device_metric_calibration_state_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NotCalibrated = 1,
+        CalibrationRequired = 2,
+        Calibrated = 3,
+        Unspecified = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NotCalibrated => "NOT_CALIBRATED",
+                Self::CalibrationRequired => "CALIBRATION_REQUIRED",
+                Self::Calibrated => "CALIBRATED",
+                Self::Unspecified => "UNSPECIFIED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NOT_CALIBRATED" => Some(Self::NotCalibrated),
+                "CALIBRATION_REQUIRED" => Some(Self::CalibrationRequired),
+                "CALIBRATED" => Some(Self::Calibrated),
+                "UNSPECIFIED" => Some(Self::Unspecified),
+                _ => None,
+            }
+        }
+    }
+}
+/// Describes the type of a metric calibration.
+/// See <http://hl7.org/fhir/metric-calibration-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceMetricCalibrationTypeCode {}
+/// Nested message and enum types in `DeviceMetricCalibrationTypeCode`.
+pub mod device_metric_calibration_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Unspecified = 1,
+        Offset = 2,
+        Gain = 3,
+        TwoPoint = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Unspecified => "UNSPECIFIED",
+                Self::Offset => "OFFSET",
+                Self::Gain => "GAIN",
+                Self::TwoPoint => "TWO_POINT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UNSPECIFIED" => Some(Self::Unspecified),
+                "OFFSET" => Some(Self::Offset),
+                "GAIN" => Some(Self::Gain),
+                "TWO_POINT" => Some(Self::TwoPoint),
+                _ => None,
+            }
+        }
+    }
+}
+/// Describes the category of the metric.
+/// See <http://hl7.org/fhir/metric-category>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceMetricCategoryCode {}
+/// Nested message and enum types in `DeviceMetricCategoryCode`.
+pub mod device_metric_category_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Measurement = 1,
+        Setting = 2,
+        Calculation = 3,
+        Unspecified = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Measurement => "MEASUREMENT",
+                Self::Setting => "SETTING",
+                Self::Calculation => "CALCULATION",
+                Self::Unspecified => "UNSPECIFIED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MEASUREMENT" => Some(Self::Measurement),
+                "SETTING" => Some(Self::Setting),
+                "CALCULATION" => Some(Self::Calculation),
+                "UNSPECIFIED" => Some(Self::Unspecified),
+                _ => None,
+            }
+        }
+    }
+}
+/// Describes the operational status of the DeviceMetric.
+/// See <http://hl7.org/fhir/metric-operational-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceMetricOperationalStatusCode {}
+/// Nested message and enum types in `DeviceMetricOperationalStatusCode`.
+pub mod device_metric_operational_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        On = 1,
+        Off = 2,
+        Standby = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::On => "ON",
+                Self::Off => "OFF",
+                Self::Standby => "STANDBY",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ON" => Some(Self::On),
+                "OFF" => Some(Self::Off),
+                "STANDBY" => Some(Self::Standby),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of name the device is referred by.
+/// See <http://hl7.org/fhir/device-nametype>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceNameTypeCode {}
+/// Nested message and enum types in `DeviceNameTypeCode`.
+pub mod device_name_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        RegisteredName = 7,
+        UserFriendlyName = 2,
+        PatientReportedName = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::RegisteredName => "REGISTERED_NAME",
+                Self::UserFriendlyName => "USER_FRIENDLY_NAME",
+                Self::PatientReportedName => "PATIENT_REPORTED_NAME",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REGISTERED_NAME" => Some(Self::RegisteredName),
+                "USER_FRIENDLY_NAME" => Some(Self::UserFriendlyName),
+                "PATIENT_REPORTED_NAME" => Some(Self::PatientReportedName),
+                _ => None,
+            }
+        }
+    }
+}
+/// The production identifier(s) that are expected to appear in the UDI carrier.
+/// See <http://hl7.org/fhir/device-productidentifierinudi>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceProductionIdentifierInUdiCode {}
+/// Nested message and enum types in `DeviceProductionIdentifierInUDICode`.
+pub mod device_production_identifier_in_udi_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        LotNumber = 1,
+        ManufacturedDate = 2,
+        SerialNumber = 3,
+        ExpirationDate = 4,
+        BiologicalSource = 5,
+        SoftwareVersion = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::LotNumber => "LOT_NUMBER",
+                Self::ManufacturedDate => "MANUFACTURED_DATE",
+                Self::SerialNumber => "SERIAL_NUMBER",
+                Self::ExpirationDate => "EXPIRATION_DATE",
+                Self::BiologicalSource => "BIOLOGICAL_SOURCE",
+                Self::SoftwareVersion => "SOFTWARE_VERSION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "LOT_NUMBER" => Some(Self::LotNumber),
+                "MANUFACTURED_DATE" => Some(Self::ManufacturedDate),
+                "SERIAL_NUMBER" => Some(Self::SerialNumber),
+                "EXPIRATION_DATE" => Some(Self::ExpirationDate),
+                "BIOLOGICAL_SOURCE" => Some(Self::BiologicalSource),
+                "SOFTWARE_VERSION" => Some(Self::SoftwareVersion),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of regulatory identifier.
+/// See <http://hl7.org/fhir/devicedefinition-regulatory-identifier-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceRegulatoryIdentifierTypeCode {}
+/// Nested message and enum types in `DeviceRegulatoryIdentifierTypeCode`.
+pub mod device_regulatory_identifier_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Basic = 1,
+        Master = 2,
+        License = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Basic => "BASIC",
+                Self::Master => "MASTER",
+                Self::License => "LICENSE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BASIC" => Some(Self::Basic),
+                "MASTER" => Some(Self::Master),
+                "LICENSE" => Some(Self::License),
+                _ => None,
+            }
+        }
+    }
+}
+/// A coded concept indicating the current status of the Device Usage.
+/// See <http://hl7.org/fhir/deviceusage-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeviceUsageStatusCode {}
+/// Nested message and enum types in `DeviceUsageStatusCode`.
+pub mod device_usage_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Completed = 2,
+        NotDone = 3,
+        EnteredInError = 4,
+        Intended = 5,
+        Stopped = 6,
+        OnHold = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Completed => "COMPLETED",
+                Self::NotDone => "NOT_DONE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Intended => "INTENDED",
+                Self::Stopped => "STOPPED",
+                Self::OnHold => "ON_HOLD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "COMPLETED" => Some(Self::Completed),
+                "NOT_DONE" => Some(Self::NotDone),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "INTENDED" => Some(Self::Intended),
+                "STOPPED" => Some(Self::Stopped),
+                "ON_HOLD" => Some(Self::OnHold),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the diagnostic report.
+/// See <http://hl7.org/fhir/diagnostic-report-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DiagnosticReportStatusCode {}
+/// Nested message and enum types in `DiagnosticReportStatusCode`.
+pub mod diagnostic_report_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Registered = 1,
+        Partial = 2,
+        Preliminary = 3,
+        Modified = 11,
+        Final = 4,
+        Amended = 5,
+        Corrected = 6,
+        Appended = 7,
+        Cancelled = 8,
+        EnteredInError = 9,
+        Unknown = 10,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Registered => "REGISTERED",
+                Self::Partial => "PARTIAL",
+                Self::Preliminary => "PRELIMINARY",
+                Self::Modified => "MODIFIED",
+                Self::Final => "FINAL",
+                Self::Amended => "AMENDED",
+                Self::Corrected => "CORRECTED",
+                Self::Appended => "APPENDED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REGISTERED" => Some(Self::Registered),
+                "PARTIAL" => Some(Self::Partial),
+                "PRELIMINARY" => Some(Self::Preliminary),
+                "MODIFIED" => Some(Self::Modified),
+                "FINAL" => Some(Self::Final),
+                "AMENDED" => Some(Self::Amended),
+                "CORRECTED" => Some(Self::Corrected),
+                "APPENDED" => Some(Self::Appended),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// How an element value is interpreted when discrimination is evaluated.
+/// See <http://hl7.org/fhir/discriminator-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DiscriminatorTypeCode {}
+/// Nested message and enum types in `DiscriminatorTypeCode`.
+pub mod discriminator_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Value = 1,
+        Exists = 2,
+        Pattern = 3,
+        Type = 4,
+        Profile = 5,
+        Position = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Value => "VALUE",
+                Self::Exists => "EXISTS",
+                Self::Pattern => "PATTERN",
+                Self::Type => "TYPE",
+                Self::Profile => "PROFILE",
+                Self::Position => "POSITION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "VALUE" => Some(Self::Value),
+                "EXISTS" => Some(Self::Exists),
+                "PATTERN" => Some(Self::Pattern),
+                "TYPE" => Some(Self::Type),
+                "PROFILE" => Some(Self::Profile),
+                "POSITION" => Some(Self::Position),
+                _ => None,
+            }
+        }
+    }
+}
+/// Whether the application produces or consumes documents.
+/// See <http://hl7.org/fhir/document-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DocumentModeCode {}
+/// Nested message and enum types in `DocumentModeCode`.
+pub mod document_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Producer = 1,
+        Consumer = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Producer => "PRODUCER",
+                Self::Consumer => "CONSUMER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PRODUCER" => Some(Self::Producer),
+                "CONSUMER" => Some(Self::Consumer),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the document reference.
+/// See <http://hl7.org/fhir/document-reference-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DocumentReferenceStatusCode {}
+/// Nested message and enum types in `DocumentReferenceStatusCode`.
+pub mod document_reference_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Current = 1,
+        Superseded = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Current => "CURRENT",
+                Self::Superseded => "SUPERSEDED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CURRENT" => Some(Self::Current),
+                "SUPERSEDED" => Some(Self::Superseded),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes Claim Processing Outcome codes.
+/// See <http://hl7.org/fhir/eligibility-outcome>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EligibilityOutcomeCode {}
+/// Nested message and enum types in `EligibilityOutcomeCode`.
+pub mod eligibility_outcome_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Queued = 1,
+        Complete = 2,
+        Error = 3,
+        Partial = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Queued => "QUEUED",
+                Self::Complete => "COMPLETE",
+                Self::Error => "ERROR",
+                Self::Partial => "PARTIAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "QUEUED" => Some(Self::Queued),
+                "COMPLETE" => Some(Self::Complete),
+                "ERROR" => Some(Self::Error),
+                "PARTIAL" => Some(Self::Partial),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code specifying the types of information being requested.
+/// See <http://hl7.org/fhir/eligibilityrequest-purpose>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EligibilityRequestPurposeCode {}
+/// Nested message and enum types in `EligibilityRequestPurposeCode`.
+pub mod eligibility_request_purpose_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        AuthRequirements = 1,
+        Benefits = 2,
+        Discovery = 3,
+        Validation = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::AuthRequirements => "AUTH_REQUIREMENTS",
+                Self::Benefits => "BENEFITS",
+                Self::Discovery => "DISCOVERY",
+                Self::Validation => "VALIDATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AUTH_REQUIREMENTS" => Some(Self::AuthRequirements),
+                "BENEFITS" => Some(Self::Benefits),
+                "DISCOVERY" => Some(Self::Discovery),
+                "VALIDATION" => Some(Self::Validation),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code specifying the types of information being requested.
+/// See <http://hl7.org/fhir/eligibilityresponse-purpose>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EligibilityResponsePurposeCode {}
+/// Nested message and enum types in `EligibilityResponsePurposeCode`.
+pub mod eligibility_response_purpose_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        AuthRequirements = 1,
+        Benefits = 2,
+        Discovery = 3,
+        Validation = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::AuthRequirements => "AUTH_REQUIREMENTS",
+                Self::Benefits => "BENEFITS",
+                Self::Discovery => "DISCOVERY",
+                Self::Validation => "VALIDATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AUTH_REQUIREMENTS" => Some(Self::AuthRequirements),
+                "BENEFITS" => Some(Self::Benefits),
+                "DISCOVERY" => Some(Self::Discovery),
+                "VALIDATION" => Some(Self::Validation),
+                _ => None,
+            }
+        }
+    }
+}
+/// Controls how multiple enableWhen values are interpreted -  whether all or any
+/// must be true. See <http://hl7.org/fhir/questionnaire-enable-behavior>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EnableWhenBehaviorCode {}
+/// Nested message and enum types in `EnableWhenBehaviorCode`.
+pub mod enable_when_behavior_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        All = 1,
+        Any = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::All => "ALL",
+                Self::Any => "ANY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ALL" => Some(Self::All),
+                "ANY" => Some(Self::Any),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the location.
+/// See <http://hl7.org/fhir/encounter-location-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EncounterLocationStatusCode {}
+/// Nested message and enum types in `EncounterLocationStatusCode`.
+pub mod encounter_location_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Planned = 1,
+        Active = 2,
+        Reserved = 3,
+        Completed = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Planned => "PLANNED",
+                Self::Active => "ACTIVE",
+                Self::Reserved => "RESERVED",
+                Self::Completed => "COMPLETED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PLANNED" => Some(Self::Planned),
+                "ACTIVE" => Some(Self::Active),
+                "RESERVED" => Some(Self::Reserved),
+                "COMPLETED" => Some(Self::Completed),
+                _ => None,
+            }
+        }
+    }
+}
+/// Current state of the encounter.
+/// See <http://hl7.org/fhir/encounter-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EncounterStatusCode {}
+/// Nested message and enum types in `EncounterStatusCode`.
+pub mod encounter_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Planned = 1,
+        InProgress = 4,
+        OnHold = 10,
+        Discharged = 11,
+        Completed = 12,
+        Cancelled = 7,
+        Discontinued = 13,
+        EnteredInError = 8,
+        Unknown = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Planned => "PLANNED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::OnHold => "ON_HOLD",
+                Self::Discharged => "DISCHARGED",
+                Self::Completed => "COMPLETED",
+                Self::Cancelled => "CANCELLED",
+                Self::Discontinued => "DISCONTINUED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PLANNED" => Some(Self::Planned),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "ON_HOLD" => Some(Self::OnHold),
+                "DISCHARGED" => Some(Self::Discharged),
+                "COMPLETED" => Some(Self::Completed),
+                "CANCELLED" => Some(Self::Cancelled),
+                "DISCONTINUED" => Some(Self::Discontinued),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the endpoint.
+/// See <http://hl7.org/fhir/endpoint-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EndpointStatusCode {}
+/// Nested message and enum types in `EndpointStatusCode`.
+pub mod endpoint_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Suspended = 2,
+        Error = 3,
+        Off = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Suspended => "SUSPENDED",
+                Self::Error => "ERROR",
+                Self::Off => "OFF",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "SUSPENDED" => Some(Self::Suspended),
+                "ERROR" => Some(Self::Error),
+                "OFF" => Some(Self::Off),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes Claim Processing Outcome codes.
+/// See <http://hl7.org/fhir/enrollment-outcome>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EnrollmentOutcomeCode {}
+/// Nested message and enum types in `EnrollmentOutcomeCode`.
+pub mod enrollment_outcome_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Queued = 1,
+        Complete = 2,
+        Error = 3,
+        Partial = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Queued => "QUEUED",
+                Self::Complete => "COMPLETE",
+                Self::Error => "ERROR",
+                Self::Partial => "PARTIAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "QUEUED" => Some(Self::Queued),
+                "COMPLETE" => Some(Self::Complete),
+                "ERROR" => Some(Self::Error),
+                "PARTIAL" => Some(Self::Partial),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the episode of care.
+/// See <http://hl7.org/fhir/episode-of-care-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EpisodeOfCareStatusCode {}
+/// Nested message and enum types in `EpisodeOfCareStatusCode`.
+pub mod episode_of_care_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Planned = 1,
+        Waitlist = 2,
+        Active = 3,
+        Onhold = 4,
+        Finished = 5,
+        Cancelled = 6,
+        EnteredInError = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Planned => "PLANNED",
+                Self::Waitlist => "WAITLIST",
+                Self::Active => "ACTIVE",
+                Self::Onhold => "ONHOLD",
+                Self::Finished => "FINISHED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PLANNED" => Some(Self::Planned),
+                "WAITLIST" => Some(Self::Waitlist),
+                "ACTIVE" => Some(Self::Active),
+                "ONHOLD" => Some(Self::Onhold),
+                "FINISHED" => Some(Self::Finished),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The mode of a message capability statement.
+/// See <http://hl7.org/fhir/event-capability-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EventCapabilityModeCode {}
+/// Nested message and enum types in `EventCapabilityModeCode`.
+pub mod event_capability_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Sender = 1,
+        Receiver = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Sender => "SENDER",
+                Self::Receiver => "RECEIVER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SENDER" => Some(Self::Sender),
+                "RECEIVER" => Some(Self::Receiver),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of an event.
+/// See <http://hl7.org/fhir/event-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EventStatusCode {}
+/// Nested message and enum types in `EventStatusCode`.
+pub mod event_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preparation = 1,
+        InProgress = 2,
+        NotDone = 3,
+        OnHold = 4,
+        Stopped = 5,
+        Completed = 6,
+        EnteredInError = 7,
+        Unknown = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preparation => "PREPARATION",
+                Self::InProgress => "IN_PROGRESS",
+                Self::NotDone => "NOT_DONE",
+                Self::OnHold => "ON_HOLD",
+                Self::Stopped => "STOPPED",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PREPARATION" => Some(Self::Preparation),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "NOT_DONE" => Some(Self::NotDone),
+                "ON_HOLD" => Some(Self::OnHold),
+                "STOPPED" => Some(Self::Stopped),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Real-world event relating to the schedule.
+/// See <http://hl7.org/fhir/event-timing>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EventTimingCode {}
+/// Nested message and enum types in `EventTimingCode`.
+pub mod event_timing_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Morn = 1,
+        MornEarly = 2,
+        MornLate = 3,
+        Noon = 4,
+        Aft = 5,
+        AftEarly = 6,
+        AftLate = 7,
+        Eve = 8,
+        EveEarly = 9,
+        EveLate = 10,
+        Night = 11,
+        Phs = 12,
+        Imd = 13,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Morn => "MORN",
+                Self::MornEarly => "MORN_EARLY",
+                Self::MornLate => "MORN_LATE",
+                Self::Noon => "NOON",
+                Self::Aft => "AFT",
+                Self::AftEarly => "AFT_EARLY",
+                Self::AftLate => "AFT_LATE",
+                Self::Eve => "EVE",
+                Self::EveEarly => "EVE_EARLY",
+                Self::EveLate => "EVE_LATE",
+                Self::Night => "NIGHT",
+                Self::Phs => "PHS",
+                Self::Imd => "IMD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MORN" => Some(Self::Morn),
+                "MORN_EARLY" => Some(Self::MornEarly),
+                "MORN_LATE" => Some(Self::MornLate),
+                "NOON" => Some(Self::Noon),
+                "AFT" => Some(Self::Aft),
+                "AFT_EARLY" => Some(Self::AftEarly),
+                "AFT_LATE" => Some(Self::AftLate),
+                "EVE" => Some(Self::Eve),
+                "EVE_EARLY" => Some(Self::EveEarly),
+                "EVE_LATE" => Some(Self::EveLate),
+                "NIGHT" => Some(Self::Night),
+                "PHS" => Some(Self::Phs),
+                "IMD" => Some(Self::Imd),
+                _ => None,
+            }
+        }
+    }
+}
+/// The handling of the variable in statistical analysis for exposures or
+/// outcomes (E.g. Dichotomous, Continuous, Descriptive). See
+/// <http://hl7.org/fhir/variable-handling>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EvidenceVariableHandlingCode {}
+/// Nested message and enum types in `EvidenceVariableHandlingCode`.
+pub mod evidence_variable_handling_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Continuous = 1,
+        Dichotomous = 2,
+        Ordinal = 3,
+        Polychotomous = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Continuous => "CONTINUOUS",
+                Self::Dichotomous => "DICHOTOMOUS",
+                Self::Ordinal => "ORDINAL",
+                Self::Polychotomous => "POLYCHOTOMOUS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CONTINUOUS" => Some(Self::Continuous),
+                "DICHOTOMOUS" => Some(Self::Dichotomous),
+                "ORDINAL" => Some(Self::Ordinal),
+                "POLYCHOTOMOUS" => Some(Self::Polychotomous),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of actor - system or human.
+/// See <http://hl7.org/fhir/examplescenario-actor-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExampleScenarioActorTypeCode {}
+/// Nested message and enum types in `ExampleScenarioActorTypeCode`.
+pub mod example_scenario_actor_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Person = 1,
+        System = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Person => "PERSON",
+                Self::System => "SYSTEM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PERSON" => Some(Self::Person),
+                "SYSTEM" => Some(Self::System),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code specifying the state of the resource instance.
+/// See <http://hl7.org/fhir/explanationofbenefit-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExplanationOfBenefitStatusCode {}
+/// Nested message and enum types in `ExplanationOfBenefitStatusCode`.
+pub mod explanation_of_benefit_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Cancelled = 2,
+        Draft = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Cancelled => "CANCELLED",
+                Self::Draft => "DRAFT",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "CANCELLED" => Some(Self::Cancelled),
+                "DRAFT" => Some(Self::Draft),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// How an extension context is interpreted.
+/// See <http://hl7.org/fhir/extension-context-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExtensionContextTypeCode {}
+/// Nested message and enum types in `ExtensionContextTypeCode`.
+pub mod extension_context_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Fhirpath = 1,
+        Element = 2,
+        Extension = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Fhirpath => "FHIRPATH",
+                Self::Element => "ELEMENT",
+                Self::Extension => "EXTENSION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FHIRPATH" => Some(Self::Fhirpath),
+                "ELEMENT" => Some(Self::Element),
+                "EXTENSION" => Some(Self::Extension),
+                _ => None,
+            }
+        }
+    }
+}
+/// The master set of status codes used throughout FHIR. All status codes are
+/// mapped to one of these codes. See <http://hl7.org/fhir/resource-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirCanonicalStatusCode {}
+/// Nested message and enum types in `FHIRCanonicalStatusCode`.
+pub mod fhir_canonical_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Error = 1,
+        Proposed = 2,
+        Planned = 3,
+        Draft = 4,
+        Requested = 5,
+        Received = 6,
+        Declined = 7,
+        Accepted = 8,
+        Arrived = 9,
+        Active = 10,
+        Suspended = 11,
+        Failed = 12,
+        Replaced = 13,
+        Complete = 14,
+        Inactive = 15,
+        Abandoned = 16,
+        Unknown = 17,
+        Unconfirmed = 18,
+        Confirmed = 19,
+        Resolved = 20,
+        Refuted = 21,
+        Differential = 22,
+        Partial = 23,
+        BusyUnavailable = 24,
+        Free = 25,
+        OnTarget = 26,
+        AheadOfTarget = 27,
+        BehindTarget = 28,
+        NotReady = 29,
+        TransducDiscon = 30,
+        HwDiscon = 31,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Error => "ERROR",
+                Self::Proposed => "PROPOSED",
+                Self::Planned => "PLANNED",
+                Self::Draft => "DRAFT",
+                Self::Requested => "REQUESTED",
+                Self::Received => "RECEIVED",
+                Self::Declined => "DECLINED",
+                Self::Accepted => "ACCEPTED",
+                Self::Arrived => "ARRIVED",
+                Self::Active => "ACTIVE",
+                Self::Suspended => "SUSPENDED",
+                Self::Failed => "FAILED",
+                Self::Replaced => "REPLACED",
+                Self::Complete => "COMPLETE",
+                Self::Inactive => "INACTIVE",
+                Self::Abandoned => "ABANDONED",
+                Self::Unknown => "UNKNOWN",
+                Self::Unconfirmed => "UNCONFIRMED",
+                Self::Confirmed => "CONFIRMED",
+                Self::Resolved => "RESOLVED",
+                Self::Refuted => "REFUTED",
+                Self::Differential => "DIFFERENTIAL",
+                Self::Partial => "PARTIAL",
+                Self::BusyUnavailable => "BUSY_UNAVAILABLE",
+                Self::Free => "FREE",
+                Self::OnTarget => "ON_TARGET",
+                Self::AheadOfTarget => "AHEAD_OF_TARGET",
+                Self::BehindTarget => "BEHIND_TARGET",
+                Self::NotReady => "NOT_READY",
+                Self::TransducDiscon => "TRANSDUC_DISCON",
+                Self::HwDiscon => "HW_DISCON",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ERROR" => Some(Self::Error),
+                "PROPOSED" => Some(Self::Proposed),
+                "PLANNED" => Some(Self::Planned),
+                "DRAFT" => Some(Self::Draft),
+                "REQUESTED" => Some(Self::Requested),
+                "RECEIVED" => Some(Self::Received),
+                "DECLINED" => Some(Self::Declined),
+                "ACCEPTED" => Some(Self::Accepted),
+                "ARRIVED" => Some(Self::Arrived),
+                "ACTIVE" => Some(Self::Active),
+                "SUSPENDED" => Some(Self::Suspended),
+                "FAILED" => Some(Self::Failed),
+                "REPLACED" => Some(Self::Replaced),
+                "COMPLETE" => Some(Self::Complete),
+                "INACTIVE" => Some(Self::Inactive),
+                "ABANDONED" => Some(Self::Abandoned),
+                "UNKNOWN" => Some(Self::Unknown),
+                "UNCONFIRMED" => Some(Self::Unconfirmed),
+                "CONFIRMED" => Some(Self::Confirmed),
+                "RESOLVED" => Some(Self::Resolved),
+                "REFUTED" => Some(Self::Refuted),
+                "DIFFERENTIAL" => Some(Self::Differential),
+                "PARTIAL" => Some(Self::Partial),
+                "BUSY_UNAVAILABLE" => Some(Self::BusyUnavailable),
+                "FREE" => Some(Self::Free),
+                "ON_TARGET" => Some(Self::OnTarget),
+                "AHEAD_OF_TARGET" => Some(Self::AheadOfTarget),
+                "BEHIND_TARGET" => Some(Self::BehindTarget),
+                "NOT_READY" => Some(Self::NotReady),
+                "TRANSDUC_DISCON" => Some(Self::TransducDiscon),
+                "HW_DISCON" => Some(Self::HwDiscon),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the Device record.
+/// See <http://hl7.org/fhir/device-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirDeviceStatusCode {}
+/// Nested message and enum types in `FHIRDeviceStatusCode`.
+pub mod fhir_device_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// An old resource name no longer used in this version of FHIR (deleted or
+/// renamed). See <http://hl7.org/fhir/fhir-old-types>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirOldTypesCode {}
+/// Nested message and enum types in `FHIROldTypesCode`.
+pub mod fhir_old_types_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        BodySite = 1,
+        CatalogEntry = 2,
+        Conformance = 3,
+        DataElement = 4,
+        DeviceComponent = 5,
+        DeviceUseRequest = 6,
+        DeviceUseStatement = 7,
+        DiagnosticOrder = 8,
+        DocumentManifest = 9,
+        EffectEvidenceSynthesis = 10,
+        EligibilityRequest = 11,
+        EligibilityResponse = 12,
+        ExpansionProfile = 13,
+        ImagingManifest = 14,
+        ImagingObjectSelection = 15,
+        Media = 16,
+        MedicationOrder = 17,
+        MedicationUsage = 18,
+        MedicinalProduct = 19,
+        MedicinalProductAuthorization = 20,
+        MedicinalProductContraindication = 21,
+        MedicinalProductIndication = 22,
+        MedicinalProductIngredient = 23,
+        MedicinalProductInteraction = 24,
+        MedicinalProductManufactured = 25,
+        MedicinalProductPackaged = 26,
+        MedicinalProductPharmaceutical = 27,
+        MedicinalProductUndesirableEffect = 28,
+        Order = 29,
+        OrderResponse = 30,
+        ProcedureRequest = 31,
+        ProcessRequest = 32,
+        ProcessResponse = 33,
+        ReferralRequest = 34,
+        RequestGroup = 35,
+        ResearchDefinition = 36,
+        ResearchElementDefinition = 37,
+        RiskEvidenceSynthesis = 38,
+        Sequence = 39,
+        ServiceDefinition = 40,
+        SubstanceSpecification = 41,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::BodySite => "BODY_SITE",
+                Self::CatalogEntry => "CATALOG_ENTRY",
+                Self::Conformance => "CONFORMANCE",
+                Self::DataElement => "DATA_ELEMENT",
+                Self::DeviceComponent => "DEVICE_COMPONENT",
+                Self::DeviceUseRequest => "DEVICE_USE_REQUEST",
+                Self::DeviceUseStatement => "DEVICE_USE_STATEMENT",
+                Self::DiagnosticOrder => "DIAGNOSTIC_ORDER",
+                Self::DocumentManifest => "DOCUMENT_MANIFEST",
+                Self::EffectEvidenceSynthesis => "EFFECT_EVIDENCE_SYNTHESIS",
+                Self::EligibilityRequest => "ELIGIBILITY_REQUEST",
+                Self::EligibilityResponse => "ELIGIBILITY_RESPONSE",
+                Self::ExpansionProfile => "EXPANSION_PROFILE",
+                Self::ImagingManifest => "IMAGING_MANIFEST",
+                Self::ImagingObjectSelection => "IMAGING_OBJECT_SELECTION",
+                Self::Media => "MEDIA",
+                Self::MedicationOrder => "MEDICATION_ORDER",
+                Self::MedicationUsage => "MEDICATION_USAGE",
+                Self::MedicinalProduct => "MEDICINAL_PRODUCT",
+                Self::MedicinalProductAuthorization => "MEDICINAL_PRODUCT_AUTHORIZATION",
+                Self::MedicinalProductContraindication => {
+                    "MEDICINAL_PRODUCT_CONTRAINDICATION"
+                }
+                Self::MedicinalProductIndication => "MEDICINAL_PRODUCT_INDICATION",
+                Self::MedicinalProductIngredient => "MEDICINAL_PRODUCT_INGREDIENT",
+                Self::MedicinalProductInteraction => "MEDICINAL_PRODUCT_INTERACTION",
+                Self::MedicinalProductManufactured => "MEDICINAL_PRODUCT_MANUFACTURED",
+                Self::MedicinalProductPackaged => "MEDICINAL_PRODUCT_PACKAGED",
+                Self::MedicinalProductPharmaceutical => {
+                    "MEDICINAL_PRODUCT_PHARMACEUTICAL"
+                }
+                Self::MedicinalProductUndesirableEffect => {
+                    "MEDICINAL_PRODUCT_UNDESIRABLE_EFFECT"
+                }
+                Self::Order => "ORDER",
+                Self::OrderResponse => "ORDER_RESPONSE",
+                Self::ProcedureRequest => "PROCEDURE_REQUEST",
+                Self::ProcessRequest => "PROCESS_REQUEST",
+                Self::ProcessResponse => "PROCESS_RESPONSE",
+                Self::ReferralRequest => "REFERRAL_REQUEST",
+                Self::RequestGroup => "REQUEST_GROUP",
+                Self::ResearchDefinition => "RESEARCH_DEFINITION",
+                Self::ResearchElementDefinition => "RESEARCH_ELEMENT_DEFINITION",
+                Self::RiskEvidenceSynthesis => "RISK_EVIDENCE_SYNTHESIS",
+                Self::Sequence => "SEQUENCE",
+                Self::ServiceDefinition => "SERVICE_DEFINITION",
+                Self::SubstanceSpecification => "SUBSTANCE_SPECIFICATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BODY_SITE" => Some(Self::BodySite),
+                "CATALOG_ENTRY" => Some(Self::CatalogEntry),
+                "CONFORMANCE" => Some(Self::Conformance),
+                "DATA_ELEMENT" => Some(Self::DataElement),
+                "DEVICE_COMPONENT" => Some(Self::DeviceComponent),
+                "DEVICE_USE_REQUEST" => Some(Self::DeviceUseRequest),
+                "DEVICE_USE_STATEMENT" => Some(Self::DeviceUseStatement),
+                "DIAGNOSTIC_ORDER" => Some(Self::DiagnosticOrder),
+                "DOCUMENT_MANIFEST" => Some(Self::DocumentManifest),
+                "EFFECT_EVIDENCE_SYNTHESIS" => Some(Self::EffectEvidenceSynthesis),
+                "ELIGIBILITY_REQUEST" => Some(Self::EligibilityRequest),
+                "ELIGIBILITY_RESPONSE" => Some(Self::EligibilityResponse),
+                "EXPANSION_PROFILE" => Some(Self::ExpansionProfile),
+                "IMAGING_MANIFEST" => Some(Self::ImagingManifest),
+                "IMAGING_OBJECT_SELECTION" => Some(Self::ImagingObjectSelection),
+                "MEDIA" => Some(Self::Media),
+                "MEDICATION_ORDER" => Some(Self::MedicationOrder),
+                "MEDICATION_USAGE" => Some(Self::MedicationUsage),
+                "MEDICINAL_PRODUCT" => Some(Self::MedicinalProduct),
+                "MEDICINAL_PRODUCT_AUTHORIZATION" => {
+                    Some(Self::MedicinalProductAuthorization)
+                }
+                "MEDICINAL_PRODUCT_CONTRAINDICATION" => {
+                    Some(Self::MedicinalProductContraindication)
+                }
+                "MEDICINAL_PRODUCT_INDICATION" => Some(Self::MedicinalProductIndication),
+                "MEDICINAL_PRODUCT_INGREDIENT" => Some(Self::MedicinalProductIngredient),
+                "MEDICINAL_PRODUCT_INTERACTION" => {
+                    Some(Self::MedicinalProductInteraction)
+                }
+                "MEDICINAL_PRODUCT_MANUFACTURED" => {
+                    Some(Self::MedicinalProductManufactured)
+                }
+                "MEDICINAL_PRODUCT_PACKAGED" => Some(Self::MedicinalProductPackaged),
+                "MEDICINAL_PRODUCT_PHARMACEUTICAL" => {
+                    Some(Self::MedicinalProductPharmaceutical)
+                }
+                "MEDICINAL_PRODUCT_UNDESIRABLE_EFFECT" => {
+                    Some(Self::MedicinalProductUndesirableEffect)
+                }
+                "ORDER" => Some(Self::Order),
+                "ORDER_RESPONSE" => Some(Self::OrderResponse),
+                "PROCEDURE_REQUEST" => Some(Self::ProcedureRequest),
+                "PROCESS_REQUEST" => Some(Self::ProcessRequest),
+                "PROCESS_RESPONSE" => Some(Self::ProcessResponse),
+                "REFERRAL_REQUEST" => Some(Self::ReferralRequest),
+                "REQUEST_GROUP" => Some(Self::RequestGroup),
+                "RESEARCH_DEFINITION" => Some(Self::ResearchDefinition),
+                "RESEARCH_ELEMENT_DEFINITION" => Some(Self::ResearchElementDefinition),
+                "RISK_EVIDENCE_SYNTHESIS" => Some(Self::RiskEvidenceSynthesis),
+                "SEQUENCE" => Some(Self::Sequence),
+                "SERVICE_DEFINITION" => Some(Self::ServiceDefinition),
+                "SUBSTANCE_SPECIFICATION" => Some(Self::SubstanceSpecification),
+                _ => None,
+            }
+        }
+    }
+}
+/// The set of interactions defined by the RESTful part of the FHIR
+/// specification. See <http://hl7.org/fhir/restful-interaction>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirRestfulInteractionsCode {}
+/// Nested message and enum types in `FHIRRestfulInteractionsCode`.
+pub mod fhir_restful_interactions_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Read = 1,
+        Vread = 2,
+        Update = 3,
+        Patch = 4,
+        Delete = 5,
+        History = 6,
+        HistoryInstance = 7,
+        HistoryType = 8,
+        HistorySystem = 9,
+        Create = 10,
+        Search = 11,
+        SearchType = 12,
+        SearchSystem = 13,
+        SearchCompartment = 18,
+        Capabilities = 14,
+        Transaction = 15,
+        Batch = 16,
+        Operation = 17,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Read => "READ",
+                Self::Vread => "VREAD",
+                Self::Update => "UPDATE",
+                Self::Patch => "PATCH",
+                Self::Delete => "DELETE",
+                Self::History => "HISTORY",
+                Self::HistoryInstance => "HISTORY_INSTANCE",
+                Self::HistoryType => "HISTORY_TYPE",
+                Self::HistorySystem => "HISTORY_SYSTEM",
+                Self::Create => "CREATE",
+                Self::Search => "SEARCH",
+                Self::SearchType => "SEARCH_TYPE",
+                Self::SearchSystem => "SEARCH_SYSTEM",
+                Self::SearchCompartment => "SEARCH_COMPARTMENT",
+                Self::Capabilities => "CAPABILITIES",
+                Self::Transaction => "TRANSACTION",
+                Self::Batch => "BATCH",
+                Self::Operation => "OPERATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "READ" => Some(Self::Read),
+                "VREAD" => Some(Self::Vread),
+                "UPDATE" => Some(Self::Update),
+                "PATCH" => Some(Self::Patch),
+                "DELETE" => Some(Self::Delete),
+                "HISTORY" => Some(Self::History),
+                "HISTORY_INSTANCE" => Some(Self::HistoryInstance),
+                "HISTORY_TYPE" => Some(Self::HistoryType),
+                "HISTORY_SYSTEM" => Some(Self::HistorySystem),
+                "CREATE" => Some(Self::Create),
+                "SEARCH" => Some(Self::Search),
+                "SEARCH_TYPE" => Some(Self::SearchType),
+                "SEARCH_SYSTEM" => Some(Self::SearchSystem),
+                "SEARCH_COMPARTMENT" => Some(Self::SearchCompartment),
+                "CAPABILITIES" => Some(Self::Capabilities),
+                "TRANSACTION" => Some(Self::Transaction),
+                "BATCH" => Some(Self::Batch),
+                "OPERATION" => Some(Self::Operation),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code to indicate if the substance is actively used.
+/// See <http://hl7.org/fhir/substance-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirSubstanceStatusCode {}
+/// Nested message and enum types in `FHIRSubstanceStatusCode`.
+pub mod fhir_substance_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// One of the types defined as part of this version of FHIR.
+/// See <http://hl7.org/fhir/fhir-types>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirTypesCode {}
+/// Nested message and enum types in `FHIRTypesCode`.
+pub mod fhir_types_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Base = 1,
+        Element = 2,
+        BackboneElement = 3,
+        DataType = 4,
+        Address = 5,
+        Annotation = 6,
+        Attachment = 7,
+        Availability = 8,
+        BackboneType = 9,
+        Dosage = 10,
+        ElementDefinition = 11,
+        MarketingStatus = 12,
+        ProductShelfLife = 13,
+        Timing = 14,
+        CodeableConcept = 15,
+        CodeableReference = 16,
+        Coding = 17,
+        ContactDetail = 18,
+        ContactPoint = 19,
+        Contributor = 20,
+        DataRequirement = 21,
+        Expression = 22,
+        ExtendedContactDetail = 23,
+        Extension = 24,
+        HumanName = 25,
+        Identifier = 26,
+        Meta = 27,
+        MonetaryComponent = 28,
+        Money = 29,
+        Narrative = 30,
+        ParameterDefinition = 31,
+        Period = 32,
+        PrimitiveType = 33,
+        Base64Binary = 34,
+        Boolean = 35,
+        Date = 36,
+        DateTime = 37,
+        Decimal = 38,
+        Instant = 39,
+        Integer = 40,
+        PositiveInt = 41,
+        UnsignedInt = 42,
+        Integer64 = 43,
+        String = 44,
+        Code = 45,
+        Id = 46,
+        Markdown = 47,
+        Time = 48,
+        Uri = 49,
+        Canonical = 50,
+        Oid = 51,
+        Url = 52,
+        Uuid = 53,
+        Quantity = 54,
+        Age = 55,
+        Count = 56,
+        Distance = 57,
+        Duration = 58,
+        Range = 59,
+        Ratio = 60,
+        RatioRange = 61,
+        Reference = 62,
+        RelatedArtifact = 63,
+        SampledData = 64,
+        Signature = 65,
+        TriggerDefinition = 66,
+        UsageContext = 67,
+        VirtualServiceDetail = 68,
+        Xhtml = 69,
+        Resource = 70,
+        Binary = 71,
+        Bundle = 72,
+        DomainResource = 73,
+        Account = 74,
+        ActivityDefinition = 75,
+        ActorDefinition = 76,
+        AdministrableProductDefinition = 77,
+        AdverseEvent = 78,
+        AllergyIntolerance = 79,
+        Appointment = 80,
+        AppointmentResponse = 81,
+        ArtifactAssessment = 82,
+        AuditEvent = 83,
+        Basic = 84,
+        BiologicallyDerivedProduct = 85,
+        BiologicallyDerivedProductDispense = 86,
+        BodyStructure = 87,
+        CanonicalResource = 88,
+        CapabilityStatement = 89,
+        CarePlan = 90,
+        CareTeam = 91,
+        ChargeItem = 92,
+        ChargeItemDefinition = 93,
+        Citation = 94,
+        Claim = 95,
+        ClaimResponse = 96,
+        ClinicalImpression = 97,
+        ClinicalUseDefinition = 98,
+        CodeSystem = 99,
+        Communication = 100,
+        CommunicationRequest = 101,
+        CompartmentDefinition = 102,
+        Composition = 103,
+        ConceptMap = 104,
+        Condition = 105,
+        ConditionDefinition = 106,
+        Consent = 107,
+        Contract = 108,
+        Coverage = 109,
+        CoverageEligibilityRequest = 110,
+        CoverageEligibilityResponse = 111,
+        DetectedIssue = 112,
+        Device = 113,
+        DeviceAssociation = 114,
+        DeviceDefinition = 115,
+        DeviceDispense = 116,
+        DeviceMetric = 117,
+        DeviceRequest = 118,
+        DeviceUsage = 119,
+        DiagnosticReport = 120,
+        DocumentReference = 121,
+        Encounter = 122,
+        EncounterHistory = 123,
+        Endpoint = 124,
+        EnrollmentRequest = 125,
+        EnrollmentResponse = 126,
+        EpisodeOfCare = 127,
+        EventDefinition = 128,
+        Evidence = 129,
+        EvidenceReport = 130,
+        EvidenceVariable = 131,
+        ExampleScenario = 132,
+        ExplanationOfBenefit = 133,
+        FamilyMemberHistory = 134,
+        Flag = 135,
+        FormularyItem = 136,
+        GenomicStudy = 137,
+        Goal = 138,
+        GraphDefinition = 139,
+        Group = 140,
+        GuidanceResponse = 141,
+        HealthcareService = 142,
+        ImagingSelection = 143,
+        ImagingStudy = 144,
+        Immunization = 145,
+        ImmunizationEvaluation = 146,
+        ImmunizationRecommendation = 147,
+        ImplementationGuide = 148,
+        Ingredient = 149,
+        InsurancePlan = 150,
+        InventoryItem = 151,
+        InventoryReport = 152,
+        Invoice = 153,
+        Library = 154,
+        Linkage = 155,
+        List = 156,
+        Location = 157,
+        ManufacturedItemDefinition = 158,
+        Measure = 159,
+        MeasureReport = 160,
+        Medication = 161,
+        MedicationAdministration = 162,
+        MedicationDispense = 163,
+        MedicationKnowledge = 164,
+        MedicationRequest = 165,
+        MedicationStatement = 166,
+        MedicinalProductDefinition = 167,
+        MessageDefinition = 168,
+        MessageHeader = 169,
+        MetadataResource = 170,
+        MolecularSequence = 171,
+        NamingSystem = 172,
+        NutritionIntake = 173,
+        NutritionOrder = 174,
+        NutritionProduct = 175,
+        Observation = 176,
+        ObservationDefinition = 177,
+        OperationDefinition = 178,
+        OperationOutcome = 179,
+        Organization = 180,
+        OrganizationAffiliation = 181,
+        PackagedProductDefinition = 182,
+        Patient = 183,
+        PaymentNotice = 184,
+        PaymentReconciliation = 185,
+        Permission = 186,
+        Person = 187,
+        PlanDefinition = 188,
+        Practitioner = 189,
+        PractitionerRole = 190,
+        Procedure = 191,
+        Provenance = 192,
+        Questionnaire = 193,
+        QuestionnaireResponse = 194,
+        RegulatedAuthorization = 195,
+        RelatedPerson = 196,
+        RequestOrchestration = 197,
+        Requirements = 198,
+        ResearchStudy = 199,
+        ResearchSubject = 200,
+        RiskAssessment = 201,
+        Schedule = 202,
+        SearchParameter = 203,
+        ServiceRequest = 204,
+        Slot = 205,
+        Specimen = 206,
+        SpecimenDefinition = 207,
+        StructureDefinition = 208,
+        StructureMap = 209,
+        Subscription = 210,
+        SubscriptionStatus = 211,
+        SubscriptionTopic = 212,
+        Substance = 213,
+        SubstanceDefinition = 214,
+        SubstanceNucleicAcid = 215,
+        SubstancePolymer = 216,
+        SubstanceProtein = 217,
+        SubstanceReferenceInformation = 218,
+        SubstanceSourceMaterial = 219,
+        SupplyDelivery = 220,
+        SupplyRequest = 221,
+        Task = 222,
+        TerminologyCapabilities = 223,
+        TestPlan = 224,
+        TestReport = 225,
+        TestScript = 226,
+        Transport = 227,
+        Set = 228,
+        VerificationResult = 229,
+        VisionPrescription = 230,
+        Parameters = 231,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Base => "BASE",
+                Self::Element => "ELEMENT",
+                Self::BackboneElement => "BACKBONE_ELEMENT",
+                Self::DataType => "DATA_TYPE",
+                Self::Address => "ADDRESS",
+                Self::Annotation => "ANNOTATION",
+                Self::Attachment => "ATTACHMENT",
+                Self::Availability => "AVAILABILITY",
+                Self::BackboneType => "BACKBONE_TYPE",
+                Self::Dosage => "DOSAGE",
+                Self::ElementDefinition => "ELEMENT_DEFINITION",
+                Self::MarketingStatus => "MARKETING_STATUS",
+                Self::ProductShelfLife => "PRODUCT_SHELF_LIFE",
+                Self::Timing => "TIMING",
+                Self::CodeableConcept => "CODEABLE_CONCEPT",
+                Self::CodeableReference => "CODEABLE_REFERENCE",
+                Self::Coding => "CODING",
+                Self::ContactDetail => "CONTACT_DETAIL",
+                Self::ContactPoint => "CONTACT_POINT",
+                Self::Contributor => "CONTRIBUTOR",
+                Self::DataRequirement => "DATA_REQUIREMENT",
+                Self::Expression => "EXPRESSION",
+                Self::ExtendedContactDetail => "EXTENDED_CONTACT_DETAIL",
+                Self::Extension => "EXTENSION",
+                Self::HumanName => "HUMAN_NAME",
+                Self::Identifier => "IDENTIFIER",
+                Self::Meta => "META",
+                Self::MonetaryComponent => "MONETARY_COMPONENT",
+                Self::Money => "MONEY",
+                Self::Narrative => "NARRATIVE",
+                Self::ParameterDefinition => "PARAMETER_DEFINITION",
+                Self::Period => "PERIOD",
+                Self::PrimitiveType => "PRIMITIVE_TYPE",
+                Self::Base64Binary => "BASE64_BINARY",
+                Self::Boolean => "BOOLEAN",
+                Self::Date => "DATE",
+                Self::DateTime => "DATE_TIME",
+                Self::Decimal => "DECIMAL",
+                Self::Instant => "INSTANT",
+                Self::Integer => "INTEGER",
+                Self::PositiveInt => "POSITIVE_INT",
+                Self::UnsignedInt => "UNSIGNED_INT",
+                Self::Integer64 => "INTEGER64",
+                Self::String => "STRING",
+                Self::Code => "CODE",
+                Self::Id => "ID",
+                Self::Markdown => "MARKDOWN",
+                Self::Time => "TIME",
+                Self::Uri => "URI",
+                Self::Canonical => "CANONICAL",
+                Self::Oid => "OID",
+                Self::Url => "URL",
+                Self::Uuid => "UUID",
+                Self::Quantity => "QUANTITY",
+                Self::Age => "AGE",
+                Self::Count => "COUNT",
+                Self::Distance => "DISTANCE",
+                Self::Duration => "DURATION",
+                Self::Range => "RANGE",
+                Self::Ratio => "RATIO",
+                Self::RatioRange => "RATIO_RANGE",
+                Self::Reference => "REFERENCE",
+                Self::RelatedArtifact => "RELATED_ARTIFACT",
+                Self::SampledData => "SAMPLED_DATA",
+                Self::Signature => "SIGNATURE",
+                Self::TriggerDefinition => "TRIGGER_DEFINITION",
+                Self::UsageContext => "USAGE_CONTEXT",
+                Self::VirtualServiceDetail => "VIRTUAL_SERVICE_DETAIL",
+                Self::Xhtml => "XHTML",
+                Self::Resource => "RESOURCE",
+                Self::Binary => "BINARY",
+                Self::Bundle => "BUNDLE",
+                Self::DomainResource => "DOMAIN_RESOURCE",
+                Self::Account => "ACCOUNT",
+                Self::ActivityDefinition => "ACTIVITY_DEFINITION",
+                Self::ActorDefinition => "ACTOR_DEFINITION",
+                Self::AdministrableProductDefinition => {
+                    "ADMINISTRABLE_PRODUCT_DEFINITION"
+                }
+                Self::AdverseEvent => "ADVERSE_EVENT",
+                Self::AllergyIntolerance => "ALLERGY_INTOLERANCE",
+                Self::Appointment => "APPOINTMENT",
+                Self::AppointmentResponse => "APPOINTMENT_RESPONSE",
+                Self::ArtifactAssessment => "ARTIFACT_ASSESSMENT",
+                Self::AuditEvent => "AUDIT_EVENT",
+                Self::Basic => "BASIC",
+                Self::BiologicallyDerivedProduct => "BIOLOGICALLY_DERIVED_PRODUCT",
+                Self::BiologicallyDerivedProductDispense => {
+                    "BIOLOGICALLY_DERIVED_PRODUCT_DISPENSE"
+                }
+                Self::BodyStructure => "BODY_STRUCTURE",
+                Self::CanonicalResource => "CANONICAL_RESOURCE",
+                Self::CapabilityStatement => "CAPABILITY_STATEMENT",
+                Self::CarePlan => "CARE_PLAN",
+                Self::CareTeam => "CARE_TEAM",
+                Self::ChargeItem => "CHARGE_ITEM",
+                Self::ChargeItemDefinition => "CHARGE_ITEM_DEFINITION",
+                Self::Citation => "CITATION",
+                Self::Claim => "CLAIM",
+                Self::ClaimResponse => "CLAIM_RESPONSE",
+                Self::ClinicalImpression => "CLINICAL_IMPRESSION",
+                Self::ClinicalUseDefinition => "CLINICAL_USE_DEFINITION",
+                Self::CodeSystem => "CODE_SYSTEM",
+                Self::Communication => "COMMUNICATION",
+                Self::CommunicationRequest => "COMMUNICATION_REQUEST",
+                Self::CompartmentDefinition => "COMPARTMENT_DEFINITION",
+                Self::Composition => "COMPOSITION",
+                Self::ConceptMap => "CONCEPT_MAP",
+                Self::Condition => "CONDITION",
+                Self::ConditionDefinition => "CONDITION_DEFINITION",
+                Self::Consent => "CONSENT",
+                Self::Contract => "CONTRACT",
+                Self::Coverage => "COVERAGE",
+                Self::CoverageEligibilityRequest => "COVERAGE_ELIGIBILITY_REQUEST",
+                Self::CoverageEligibilityResponse => "COVERAGE_ELIGIBILITY_RESPONSE",
+                Self::DetectedIssue => "DETECTED_ISSUE",
+                Self::Device => "DEVICE",
+                Self::DeviceAssociation => "DEVICE_ASSOCIATION",
+                Self::DeviceDefinition => "DEVICE_DEFINITION",
+                Self::DeviceDispense => "DEVICE_DISPENSE",
+                Self::DeviceMetric => "DEVICE_METRIC",
+                Self::DeviceRequest => "DEVICE_REQUEST",
+                Self::DeviceUsage => "DEVICE_USAGE",
+                Self::DiagnosticReport => "DIAGNOSTIC_REPORT",
+                Self::DocumentReference => "DOCUMENT_REFERENCE",
+                Self::Encounter => "ENCOUNTER",
+                Self::EncounterHistory => "ENCOUNTER_HISTORY",
+                Self::Endpoint => "ENDPOINT",
+                Self::EnrollmentRequest => "ENROLLMENT_REQUEST",
+                Self::EnrollmentResponse => "ENROLLMENT_RESPONSE",
+                Self::EpisodeOfCare => "EPISODE_OF_CARE",
+                Self::EventDefinition => "EVENT_DEFINITION",
+                Self::Evidence => "EVIDENCE",
+                Self::EvidenceReport => "EVIDENCE_REPORT",
+                Self::EvidenceVariable => "EVIDENCE_VARIABLE",
+                Self::ExampleScenario => "EXAMPLE_SCENARIO",
+                Self::ExplanationOfBenefit => "EXPLANATION_OF_BENEFIT",
+                Self::FamilyMemberHistory => "FAMILY_MEMBER_HISTORY",
+                Self::Flag => "FLAG",
+                Self::FormularyItem => "FORMULARY_ITEM",
+                Self::GenomicStudy => "GENOMIC_STUDY",
+                Self::Goal => "GOAL",
+                Self::GraphDefinition => "GRAPH_DEFINITION",
+                Self::Group => "GROUP",
+                Self::GuidanceResponse => "GUIDANCE_RESPONSE",
+                Self::HealthcareService => "HEALTHCARE_SERVICE",
+                Self::ImagingSelection => "IMAGING_SELECTION",
+                Self::ImagingStudy => "IMAGING_STUDY",
+                Self::Immunization => "IMMUNIZATION",
+                Self::ImmunizationEvaluation => "IMMUNIZATION_EVALUATION",
+                Self::ImmunizationRecommendation => "IMMUNIZATION_RECOMMENDATION",
+                Self::ImplementationGuide => "IMPLEMENTATION_GUIDE",
+                Self::Ingredient => "INGREDIENT",
+                Self::InsurancePlan => "INSURANCE_PLAN",
+                Self::InventoryItem => "INVENTORY_ITEM",
+                Self::InventoryReport => "INVENTORY_REPORT",
+                Self::Invoice => "INVOICE",
+                Self::Library => "LIBRARY",
+                Self::Linkage => "LINKAGE",
+                Self::List => "LIST",
+                Self::Location => "LOCATION",
+                Self::ManufacturedItemDefinition => "MANUFACTURED_ITEM_DEFINITION",
+                Self::Measure => "MEASURE",
+                Self::MeasureReport => "MEASURE_REPORT",
+                Self::Medication => "MEDICATION",
+                Self::MedicationAdministration => "MEDICATION_ADMINISTRATION",
+                Self::MedicationDispense => "MEDICATION_DISPENSE",
+                Self::MedicationKnowledge => "MEDICATION_KNOWLEDGE",
+                Self::MedicationRequest => "MEDICATION_REQUEST",
+                Self::MedicationStatement => "MEDICATION_STATEMENT",
+                Self::MedicinalProductDefinition => "MEDICINAL_PRODUCT_DEFINITION",
+                Self::MessageDefinition => "MESSAGE_DEFINITION",
+                Self::MessageHeader => "MESSAGE_HEADER",
+                Self::MetadataResource => "METADATA_RESOURCE",
+                Self::MolecularSequence => "MOLECULAR_SEQUENCE",
+                Self::NamingSystem => "NAMING_SYSTEM",
+                Self::NutritionIntake => "NUTRITION_INTAKE",
+                Self::NutritionOrder => "NUTRITION_ORDER",
+                Self::NutritionProduct => "NUTRITION_PRODUCT",
+                Self::Observation => "OBSERVATION",
+                Self::ObservationDefinition => "OBSERVATION_DEFINITION",
+                Self::OperationDefinition => "OPERATION_DEFINITION",
+                Self::OperationOutcome => "OPERATION_OUTCOME",
+                Self::Organization => "ORGANIZATION",
+                Self::OrganizationAffiliation => "ORGANIZATION_AFFILIATION",
+                Self::PackagedProductDefinition => "PACKAGED_PRODUCT_DEFINITION",
+                Self::Patient => "PATIENT",
+                Self::PaymentNotice => "PAYMENT_NOTICE",
+                Self::PaymentReconciliation => "PAYMENT_RECONCILIATION",
+                Self::Permission => "PERMISSION",
+                Self::Person => "PERSON",
+                Self::PlanDefinition => "PLAN_DEFINITION",
+                Self::Practitioner => "PRACTITIONER",
+                Self::PractitionerRole => "PRACTITIONER_ROLE",
+                Self::Procedure => "PROCEDURE",
+                Self::Provenance => "PROVENANCE",
+                Self::Questionnaire => "QUESTIONNAIRE",
+                Self::QuestionnaireResponse => "QUESTIONNAIRE_RESPONSE",
+                Self::RegulatedAuthorization => "REGULATED_AUTHORIZATION",
+                Self::RelatedPerson => "RELATED_PERSON",
+                Self::RequestOrchestration => "REQUEST_ORCHESTRATION",
+                Self::Requirements => "REQUIREMENTS",
+                Self::ResearchStudy => "RESEARCH_STUDY",
+                Self::ResearchSubject => "RESEARCH_SUBJECT",
+                Self::RiskAssessment => "RISK_ASSESSMENT",
+                Self::Schedule => "SCHEDULE",
+                Self::SearchParameter => "SEARCH_PARAMETER",
+                Self::ServiceRequest => "SERVICE_REQUEST",
+                Self::Slot => "SLOT",
+                Self::Specimen => "SPECIMEN",
+                Self::SpecimenDefinition => "SPECIMEN_DEFINITION",
+                Self::StructureDefinition => "STRUCTURE_DEFINITION",
+                Self::StructureMap => "STRUCTURE_MAP",
+                Self::Subscription => "SUBSCRIPTION",
+                Self::SubscriptionStatus => "SUBSCRIPTION_STATUS",
+                Self::SubscriptionTopic => "SUBSCRIPTION_TOPIC",
+                Self::Substance => "SUBSTANCE",
+                Self::SubstanceDefinition => "SUBSTANCE_DEFINITION",
+                Self::SubstanceNucleicAcid => "SUBSTANCE_NUCLEIC_ACID",
+                Self::SubstancePolymer => "SUBSTANCE_POLYMER",
+                Self::SubstanceProtein => "SUBSTANCE_PROTEIN",
+                Self::SubstanceReferenceInformation => "SUBSTANCE_REFERENCE_INFORMATION",
+                Self::SubstanceSourceMaterial => "SUBSTANCE_SOURCE_MATERIAL",
+                Self::SupplyDelivery => "SUPPLY_DELIVERY",
+                Self::SupplyRequest => "SUPPLY_REQUEST",
+                Self::Task => "TASK",
+                Self::TerminologyCapabilities => "TERMINOLOGY_CAPABILITIES",
+                Self::TestPlan => "TEST_PLAN",
+                Self::TestReport => "TEST_REPORT",
+                Self::TestScript => "TEST_SCRIPT",
+                Self::Transport => "TRANSPORT",
+                Self::Set => "VALUE_SET",
+                Self::VerificationResult => "VERIFICATION_RESULT",
+                Self::VisionPrescription => "VISION_PRESCRIPTION",
+                Self::Parameters => "PARAMETERS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BASE" => Some(Self::Base),
+                "ELEMENT" => Some(Self::Element),
+                "BACKBONE_ELEMENT" => Some(Self::BackboneElement),
+                "DATA_TYPE" => Some(Self::DataType),
+                "ADDRESS" => Some(Self::Address),
+                "ANNOTATION" => Some(Self::Annotation),
+                "ATTACHMENT" => Some(Self::Attachment),
+                "AVAILABILITY" => Some(Self::Availability),
+                "BACKBONE_TYPE" => Some(Self::BackboneType),
+                "DOSAGE" => Some(Self::Dosage),
+                "ELEMENT_DEFINITION" => Some(Self::ElementDefinition),
+                "MARKETING_STATUS" => Some(Self::MarketingStatus),
+                "PRODUCT_SHELF_LIFE" => Some(Self::ProductShelfLife),
+                "TIMING" => Some(Self::Timing),
+                "CODEABLE_CONCEPT" => Some(Self::CodeableConcept),
+                "CODEABLE_REFERENCE" => Some(Self::CodeableReference),
+                "CODING" => Some(Self::Coding),
+                "CONTACT_DETAIL" => Some(Self::ContactDetail),
+                "CONTACT_POINT" => Some(Self::ContactPoint),
+                "CONTRIBUTOR" => Some(Self::Contributor),
+                "DATA_REQUIREMENT" => Some(Self::DataRequirement),
+                "EXPRESSION" => Some(Self::Expression),
+                "EXTENDED_CONTACT_DETAIL" => Some(Self::ExtendedContactDetail),
+                "EXTENSION" => Some(Self::Extension),
+                "HUMAN_NAME" => Some(Self::HumanName),
+                "IDENTIFIER" => Some(Self::Identifier),
+                "META" => Some(Self::Meta),
+                "MONETARY_COMPONENT" => Some(Self::MonetaryComponent),
+                "MONEY" => Some(Self::Money),
+                "NARRATIVE" => Some(Self::Narrative),
+                "PARAMETER_DEFINITION" => Some(Self::ParameterDefinition),
+                "PERIOD" => Some(Self::Period),
+                "PRIMITIVE_TYPE" => Some(Self::PrimitiveType),
+                "BASE64_BINARY" => Some(Self::Base64Binary),
+                "BOOLEAN" => Some(Self::Boolean),
+                "DATE" => Some(Self::Date),
+                "DATE_TIME" => Some(Self::DateTime),
+                "DECIMAL" => Some(Self::Decimal),
+                "INSTANT" => Some(Self::Instant),
+                "INTEGER" => Some(Self::Integer),
+                "POSITIVE_INT" => Some(Self::PositiveInt),
+                "UNSIGNED_INT" => Some(Self::UnsignedInt),
+                "INTEGER64" => Some(Self::Integer64),
+                "STRING" => Some(Self::String),
+                "CODE" => Some(Self::Code),
+                "ID" => Some(Self::Id),
+                "MARKDOWN" => Some(Self::Markdown),
+                "TIME" => Some(Self::Time),
+                "URI" => Some(Self::Uri),
+                "CANONICAL" => Some(Self::Canonical),
+                "OID" => Some(Self::Oid),
+                "URL" => Some(Self::Url),
+                "UUID" => Some(Self::Uuid),
+                "QUANTITY" => Some(Self::Quantity),
+                "AGE" => Some(Self::Age),
+                "COUNT" => Some(Self::Count),
+                "DISTANCE" => Some(Self::Distance),
+                "DURATION" => Some(Self::Duration),
+                "RANGE" => Some(Self::Range),
+                "RATIO" => Some(Self::Ratio),
+                "RATIO_RANGE" => Some(Self::RatioRange),
+                "REFERENCE" => Some(Self::Reference),
+                "RELATED_ARTIFACT" => Some(Self::RelatedArtifact),
+                "SAMPLED_DATA" => Some(Self::SampledData),
+                "SIGNATURE" => Some(Self::Signature),
+                "TRIGGER_DEFINITION" => Some(Self::TriggerDefinition),
+                "USAGE_CONTEXT" => Some(Self::UsageContext),
+                "VIRTUAL_SERVICE_DETAIL" => Some(Self::VirtualServiceDetail),
+                "XHTML" => Some(Self::Xhtml),
+                "RESOURCE" => Some(Self::Resource),
+                "BINARY" => Some(Self::Binary),
+                "BUNDLE" => Some(Self::Bundle),
+                "DOMAIN_RESOURCE" => Some(Self::DomainResource),
+                "ACCOUNT" => Some(Self::Account),
+                "ACTIVITY_DEFINITION" => Some(Self::ActivityDefinition),
+                "ACTOR_DEFINITION" => Some(Self::ActorDefinition),
+                "ADMINISTRABLE_PRODUCT_DEFINITION" => {
+                    Some(Self::AdministrableProductDefinition)
+                }
+                "ADVERSE_EVENT" => Some(Self::AdverseEvent),
+                "ALLERGY_INTOLERANCE" => Some(Self::AllergyIntolerance),
+                "APPOINTMENT" => Some(Self::Appointment),
+                "APPOINTMENT_RESPONSE" => Some(Self::AppointmentResponse),
+                "ARTIFACT_ASSESSMENT" => Some(Self::ArtifactAssessment),
+                "AUDIT_EVENT" => Some(Self::AuditEvent),
+                "BASIC" => Some(Self::Basic),
+                "BIOLOGICALLY_DERIVED_PRODUCT" => Some(Self::BiologicallyDerivedProduct),
+                "BIOLOGICALLY_DERIVED_PRODUCT_DISPENSE" => {
+                    Some(Self::BiologicallyDerivedProductDispense)
+                }
+                "BODY_STRUCTURE" => Some(Self::BodyStructure),
+                "CANONICAL_RESOURCE" => Some(Self::CanonicalResource),
+                "CAPABILITY_STATEMENT" => Some(Self::CapabilityStatement),
+                "CARE_PLAN" => Some(Self::CarePlan),
+                "CARE_TEAM" => Some(Self::CareTeam),
+                "CHARGE_ITEM" => Some(Self::ChargeItem),
+                "CHARGE_ITEM_DEFINITION" => Some(Self::ChargeItemDefinition),
+                "CITATION" => Some(Self::Citation),
+                "CLAIM" => Some(Self::Claim),
+                "CLAIM_RESPONSE" => Some(Self::ClaimResponse),
+                "CLINICAL_IMPRESSION" => Some(Self::ClinicalImpression),
+                "CLINICAL_USE_DEFINITION" => Some(Self::ClinicalUseDefinition),
+                "CODE_SYSTEM" => Some(Self::CodeSystem),
+                "COMMUNICATION" => Some(Self::Communication),
+                "COMMUNICATION_REQUEST" => Some(Self::CommunicationRequest),
+                "COMPARTMENT_DEFINITION" => Some(Self::CompartmentDefinition),
+                "COMPOSITION" => Some(Self::Composition),
+                "CONCEPT_MAP" => Some(Self::ConceptMap),
+                "CONDITION" => Some(Self::Condition),
+                "CONDITION_DEFINITION" => Some(Self::ConditionDefinition),
+                "CONSENT" => Some(Self::Consent),
+                "CONTRACT" => Some(Self::Contract),
+                "COVERAGE" => Some(Self::Coverage),
+                "COVERAGE_ELIGIBILITY_REQUEST" => Some(Self::CoverageEligibilityRequest),
+                "COVERAGE_ELIGIBILITY_RESPONSE" => {
+                    Some(Self::CoverageEligibilityResponse)
+                }
+                "DETECTED_ISSUE" => Some(Self::DetectedIssue),
+                "DEVICE" => Some(Self::Device),
+                "DEVICE_ASSOCIATION" => Some(Self::DeviceAssociation),
+                "DEVICE_DEFINITION" => Some(Self::DeviceDefinition),
+                "DEVICE_DISPENSE" => Some(Self::DeviceDispense),
+                "DEVICE_METRIC" => Some(Self::DeviceMetric),
+                "DEVICE_REQUEST" => Some(Self::DeviceRequest),
+                "DEVICE_USAGE" => Some(Self::DeviceUsage),
+                "DIAGNOSTIC_REPORT" => Some(Self::DiagnosticReport),
+                "DOCUMENT_REFERENCE" => Some(Self::DocumentReference),
+                "ENCOUNTER" => Some(Self::Encounter),
+                "ENCOUNTER_HISTORY" => Some(Self::EncounterHistory),
+                "ENDPOINT" => Some(Self::Endpoint),
+                "ENROLLMENT_REQUEST" => Some(Self::EnrollmentRequest),
+                "ENROLLMENT_RESPONSE" => Some(Self::EnrollmentResponse),
+                "EPISODE_OF_CARE" => Some(Self::EpisodeOfCare),
+                "EVENT_DEFINITION" => Some(Self::EventDefinition),
+                "EVIDENCE" => Some(Self::Evidence),
+                "EVIDENCE_REPORT" => Some(Self::EvidenceReport),
+                "EVIDENCE_VARIABLE" => Some(Self::EvidenceVariable),
+                "EXAMPLE_SCENARIO" => Some(Self::ExampleScenario),
+                "EXPLANATION_OF_BENEFIT" => Some(Self::ExplanationOfBenefit),
+                "FAMILY_MEMBER_HISTORY" => Some(Self::FamilyMemberHistory),
+                "FLAG" => Some(Self::Flag),
+                "FORMULARY_ITEM" => Some(Self::FormularyItem),
+                "GENOMIC_STUDY" => Some(Self::GenomicStudy),
+                "GOAL" => Some(Self::Goal),
+                "GRAPH_DEFINITION" => Some(Self::GraphDefinition),
+                "GROUP" => Some(Self::Group),
+                "GUIDANCE_RESPONSE" => Some(Self::GuidanceResponse),
+                "HEALTHCARE_SERVICE" => Some(Self::HealthcareService),
+                "IMAGING_SELECTION" => Some(Self::ImagingSelection),
+                "IMAGING_STUDY" => Some(Self::ImagingStudy),
+                "IMMUNIZATION" => Some(Self::Immunization),
+                "IMMUNIZATION_EVALUATION" => Some(Self::ImmunizationEvaluation),
+                "IMMUNIZATION_RECOMMENDATION" => Some(Self::ImmunizationRecommendation),
+                "IMPLEMENTATION_GUIDE" => Some(Self::ImplementationGuide),
+                "INGREDIENT" => Some(Self::Ingredient),
+                "INSURANCE_PLAN" => Some(Self::InsurancePlan),
+                "INVENTORY_ITEM" => Some(Self::InventoryItem),
+                "INVENTORY_REPORT" => Some(Self::InventoryReport),
+                "INVOICE" => Some(Self::Invoice),
+                "LIBRARY" => Some(Self::Library),
+                "LINKAGE" => Some(Self::Linkage),
+                "LIST" => Some(Self::List),
+                "LOCATION" => Some(Self::Location),
+                "MANUFACTURED_ITEM_DEFINITION" => Some(Self::ManufacturedItemDefinition),
+                "MEASURE" => Some(Self::Measure),
+                "MEASURE_REPORT" => Some(Self::MeasureReport),
+                "MEDICATION" => Some(Self::Medication),
+                "MEDICATION_ADMINISTRATION" => Some(Self::MedicationAdministration),
+                "MEDICATION_DISPENSE" => Some(Self::MedicationDispense),
+                "MEDICATION_KNOWLEDGE" => Some(Self::MedicationKnowledge),
+                "MEDICATION_REQUEST" => Some(Self::MedicationRequest),
+                "MEDICATION_STATEMENT" => Some(Self::MedicationStatement),
+                "MEDICINAL_PRODUCT_DEFINITION" => Some(Self::MedicinalProductDefinition),
+                "MESSAGE_DEFINITION" => Some(Self::MessageDefinition),
+                "MESSAGE_HEADER" => Some(Self::MessageHeader),
+                "METADATA_RESOURCE" => Some(Self::MetadataResource),
+                "MOLECULAR_SEQUENCE" => Some(Self::MolecularSequence),
+                "NAMING_SYSTEM" => Some(Self::NamingSystem),
+                "NUTRITION_INTAKE" => Some(Self::NutritionIntake),
+                "NUTRITION_ORDER" => Some(Self::NutritionOrder),
+                "NUTRITION_PRODUCT" => Some(Self::NutritionProduct),
+                "OBSERVATION" => Some(Self::Observation),
+                "OBSERVATION_DEFINITION" => Some(Self::ObservationDefinition),
+                "OPERATION_DEFINITION" => Some(Self::OperationDefinition),
+                "OPERATION_OUTCOME" => Some(Self::OperationOutcome),
+                "ORGANIZATION" => Some(Self::Organization),
+                "ORGANIZATION_AFFILIATION" => Some(Self::OrganizationAffiliation),
+                "PACKAGED_PRODUCT_DEFINITION" => Some(Self::PackagedProductDefinition),
+                "PATIENT" => Some(Self::Patient),
+                "PAYMENT_NOTICE" => Some(Self::PaymentNotice),
+                "PAYMENT_RECONCILIATION" => Some(Self::PaymentReconciliation),
+                "PERMISSION" => Some(Self::Permission),
+                "PERSON" => Some(Self::Person),
+                "PLAN_DEFINITION" => Some(Self::PlanDefinition),
+                "PRACTITIONER" => Some(Self::Practitioner),
+                "PRACTITIONER_ROLE" => Some(Self::PractitionerRole),
+                "PROCEDURE" => Some(Self::Procedure),
+                "PROVENANCE" => Some(Self::Provenance),
+                "QUESTIONNAIRE" => Some(Self::Questionnaire),
+                "QUESTIONNAIRE_RESPONSE" => Some(Self::QuestionnaireResponse),
+                "REGULATED_AUTHORIZATION" => Some(Self::RegulatedAuthorization),
+                "RELATED_PERSON" => Some(Self::RelatedPerson),
+                "REQUEST_ORCHESTRATION" => Some(Self::RequestOrchestration),
+                "REQUIREMENTS" => Some(Self::Requirements),
+                "RESEARCH_STUDY" => Some(Self::ResearchStudy),
+                "RESEARCH_SUBJECT" => Some(Self::ResearchSubject),
+                "RISK_ASSESSMENT" => Some(Self::RiskAssessment),
+                "SCHEDULE" => Some(Self::Schedule),
+                "SEARCH_PARAMETER" => Some(Self::SearchParameter),
+                "SERVICE_REQUEST" => Some(Self::ServiceRequest),
+                "SLOT" => Some(Self::Slot),
+                "SPECIMEN" => Some(Self::Specimen),
+                "SPECIMEN_DEFINITION" => Some(Self::SpecimenDefinition),
+                "STRUCTURE_DEFINITION" => Some(Self::StructureDefinition),
+                "STRUCTURE_MAP" => Some(Self::StructureMap),
+                "SUBSCRIPTION" => Some(Self::Subscription),
+                "SUBSCRIPTION_STATUS" => Some(Self::SubscriptionStatus),
+                "SUBSCRIPTION_TOPIC" => Some(Self::SubscriptionTopic),
+                "SUBSTANCE" => Some(Self::Substance),
+                "SUBSTANCE_DEFINITION" => Some(Self::SubstanceDefinition),
+                "SUBSTANCE_NUCLEIC_ACID" => Some(Self::SubstanceNucleicAcid),
+                "SUBSTANCE_POLYMER" => Some(Self::SubstancePolymer),
+                "SUBSTANCE_PROTEIN" => Some(Self::SubstanceProtein),
+                "SUBSTANCE_REFERENCE_INFORMATION" => {
+                    Some(Self::SubstanceReferenceInformation)
+                }
+                "SUBSTANCE_SOURCE_MATERIAL" => Some(Self::SubstanceSourceMaterial),
+                "SUPPLY_DELIVERY" => Some(Self::SupplyDelivery),
+                "SUPPLY_REQUEST" => Some(Self::SupplyRequest),
+                "TASK" => Some(Self::Task),
+                "TERMINOLOGY_CAPABILITIES" => Some(Self::TerminologyCapabilities),
+                "TEST_PLAN" => Some(Self::TestPlan),
+                "TEST_REPORT" => Some(Self::TestReport),
+                "TEST_SCRIPT" => Some(Self::TestScript),
+                "TRANSPORT" => Some(Self::Transport),
+                "VALUE_SET" => Some(Self::Set),
+                "VERIFICATION_RESULT" => Some(Self::VerificationResult),
+                "VISION_PRESCRIPTION" => Some(Self::VisionPrescription),
+                "PARAMETERS" => Some(Self::Parameters),
+                _ => None,
+            }
+        }
+    }
+}
+/// All published FHIR Versions.
+/// See <http://hl7.org/fhir/FHIR-version>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FhirVersionCode {}
+/// Nested message and enum types in `FHIRVersionCode`.
+pub mod fhir_version_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        V001 = 1,
+        V005 = 2,
+        V006 = 3,
+        V011 = 4,
+        V00 = 23,
+        V0080 = 5,
+        V0081 = 6,
+        V0082 = 7,
+        V04 = 24,
+        V040 = 8,
+        V05 = 25,
+        V050 = 9,
+        V10 = 26,
+        V100 = 10,
+        V101 = 11,
+        V102 = 12,
+        V11 = 27,
+        V110 = 13,
+        V14 = 28,
+        V140 = 14,
+        V16 = 29,
+        V160 = 15,
+        V18 = 30,
+        V180 = 16,
+        V30 = 31,
+        V300 = 17,
+        V301 = 18,
+        V302 = 32,
+        V33 = 33,
+        V330 = 19,
+        V35 = 34,
+        V350 = 20,
+        V40 = 35,
+        V400 = 21,
+        V401 = 22,
+        V41 = 36,
+        V410 = 37,
+        V42 = 38,
+        V420 = 39,
+        V43 = 40,
+        V430 = 41,
+        V430Cibuild = 42,
+        V430Snapshot1 = 43,
+        V44 = 44,
+        V440 = 45,
+        V45 = 46,
+        V450 = 47,
+        V46 = 48,
+        V460 = 49,
+        V50 = 50,
+        V500 = 51,
+        V500Cibuild = 52,
+        V500Snapshot1 = 53,
+        V500Snapshot2 = 54,
+        V500Ballot = 55,
+        V500Snapshot3 = 56,
+        V500DraftFinal = 57,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::V001 => "V_0_01",
+                Self::V005 => "V_0_05",
+                Self::V006 => "V_0_06",
+                Self::V011 => "V_0_11",
+                Self::V00 => "V_0_0",
+                Self::V0080 => "V_0_0_80",
+                Self::V0081 => "V_0_0_81",
+                Self::V0082 => "V_0_0_82",
+                Self::V04 => "V_0_4",
+                Self::V040 => "V_0_4_0",
+                Self::V05 => "V_0_5",
+                Self::V050 => "V_0_5_0",
+                Self::V10 => "V_1_0",
+                Self::V100 => "V_1_0_0",
+                Self::V101 => "V_1_0_1",
+                Self::V102 => "V_1_0_2",
+                Self::V11 => "V_1_1",
+                Self::V110 => "V_1_1_0",
+                Self::V14 => "V_1_4",
+                Self::V140 => "V_1_4_0",
+                Self::V16 => "V_1_6",
+                Self::V160 => "V_1_6_0",
+                Self::V18 => "V_1_8",
+                Self::V180 => "V_1_8_0",
+                Self::V30 => "V_3_0",
+                Self::V300 => "V_3_0_0",
+                Self::V301 => "V_3_0_1",
+                Self::V302 => "V_3_0_2",
+                Self::V33 => "V_3_3",
+                Self::V330 => "V_3_3_0",
+                Self::V35 => "V_3_5",
+                Self::V350 => "V_3_5_0",
+                Self::V40 => "V_4_0",
+                Self::V400 => "V_4_0_0",
+                Self::V401 => "V_4_0_1",
+                Self::V41 => "V_4_1",
+                Self::V410 => "V_4_1_0",
+                Self::V42 => "V_4_2",
+                Self::V420 => "V_4_2_0",
+                Self::V43 => "V_4_3",
+                Self::V430 => "V_4_3_0",
+                Self::V430Cibuild => "V_4_3_0_CIBUILD",
+                Self::V430Snapshot1 => "V_4_3_0_SNAPSHOT1",
+                Self::V44 => "V_4_4",
+                Self::V440 => "V_4_4_0",
+                Self::V45 => "V_4_5",
+                Self::V450 => "V_4_5_0",
+                Self::V46 => "V_4_6",
+                Self::V460 => "V_4_6_0",
+                Self::V50 => "V_5_0",
+                Self::V500 => "V_5_0_0",
+                Self::V500Cibuild => "V_5_0_0_CIBUILD",
+                Self::V500Snapshot1 => "V_5_0_0_SNAPSHOT1",
+                Self::V500Snapshot2 => "V_5_0_0_SNAPSHOT2",
+                Self::V500Ballot => "V_5_0_0_BALLOT",
+                Self::V500Snapshot3 => "V_5_0_0_SNAPSHOT3",
+                Self::V500DraftFinal => "V_5_0_0_DRAFT_FINAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "V_0_01" => Some(Self::V001),
+                "V_0_05" => Some(Self::V005),
+                "V_0_06" => Some(Self::V006),
+                "V_0_11" => Some(Self::V011),
+                "V_0_0" => Some(Self::V00),
+                "V_0_0_80" => Some(Self::V0080),
+                "V_0_0_81" => Some(Self::V0081),
+                "V_0_0_82" => Some(Self::V0082),
+                "V_0_4" => Some(Self::V04),
+                "V_0_4_0" => Some(Self::V040),
+                "V_0_5" => Some(Self::V05),
+                "V_0_5_0" => Some(Self::V050),
+                "V_1_0" => Some(Self::V10),
+                "V_1_0_0" => Some(Self::V100),
+                "V_1_0_1" => Some(Self::V101),
+                "V_1_0_2" => Some(Self::V102),
+                "V_1_1" => Some(Self::V11),
+                "V_1_1_0" => Some(Self::V110),
+                "V_1_4" => Some(Self::V14),
+                "V_1_4_0" => Some(Self::V140),
+                "V_1_6" => Some(Self::V16),
+                "V_1_6_0" => Some(Self::V160),
+                "V_1_8" => Some(Self::V18),
+                "V_1_8_0" => Some(Self::V180),
+                "V_3_0" => Some(Self::V30),
+                "V_3_0_0" => Some(Self::V300),
+                "V_3_0_1" => Some(Self::V301),
+                "V_3_0_2" => Some(Self::V302),
+                "V_3_3" => Some(Self::V33),
+                "V_3_3_0" => Some(Self::V330),
+                "V_3_5" => Some(Self::V35),
+                "V_3_5_0" => Some(Self::V350),
+                "V_4_0" => Some(Self::V40),
+                "V_4_0_0" => Some(Self::V400),
+                "V_4_0_1" => Some(Self::V401),
+                "V_4_1" => Some(Self::V41),
+                "V_4_1_0" => Some(Self::V410),
+                "V_4_2" => Some(Self::V42),
+                "V_4_2_0" => Some(Self::V420),
+                "V_4_3" => Some(Self::V43),
+                "V_4_3_0" => Some(Self::V430),
+                "V_4_3_0_CIBUILD" => Some(Self::V430Cibuild),
+                "V_4_3_0_SNAPSHOT1" => Some(Self::V430Snapshot1),
+                "V_4_4" => Some(Self::V44),
+                "V_4_4_0" => Some(Self::V440),
+                "V_4_5" => Some(Self::V45),
+                "V_4_5_0" => Some(Self::V450),
+                "V_4_6" => Some(Self::V46),
+                "V_4_6_0" => Some(Self::V460),
+                "V_5_0" => Some(Self::V50),
+                "V_5_0_0" => Some(Self::V500),
+                "V_5_0_0_CIBUILD" => Some(Self::V500Cibuild),
+                "V_5_0_0_SNAPSHOT1" => Some(Self::V500Snapshot1),
+                "V_5_0_0_SNAPSHOT2" => Some(Self::V500Snapshot2),
+                "V_5_0_0_BALLOT" => Some(Self::V500Ballot),
+                "V_5_0_0_SNAPSHOT3" => Some(Self::V500Snapshot3),
+                "V_5_0_0_DRAFT_FINAL" => Some(Self::V500DraftFinal),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code that identifies the status of the family history record.
+/// See <http://hl7.org/fhir/history-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FamilyHistoryStatusCode {}
+/// Nested message and enum types in `FamilyHistoryStatusCode`.
+pub mod family_history_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Partial = 1,
+        Completed = 2,
+        EnteredInError = 3,
+        HealthUnknown = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Partial => "PARTIAL",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::HealthUnknown => "HEALTH_UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PARTIAL" => Some(Self::Partial),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "HEALTH_UNKNOWN" => Some(Self::HealthUnknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The kind of operation to perform as a part of a property based filter.
+/// See <http://hl7.org/fhir/filter-operator>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FilterOperatorCode {}
+/// Nested message and enum types in `FilterOperatorCode`.
+pub mod filter_operator_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Equals = 1,
+        IsA = 2,
+        DescendentOf = 3,
+        IsNotA = 4,
+        Regex = 5,
+        In = 6,
+        NotIn = 7,
+        Generalizes = 8,
+        ChildOf = 10,
+        DescendentLeaf = 11,
+        Exists = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Equals => "EQUALS",
+                Self::IsA => "IS_A",
+                Self::DescendentOf => "DESCENDENT_OF",
+                Self::IsNotA => "IS_NOT_A",
+                Self::Regex => "REGEX",
+                Self::In => "IN",
+                Self::NotIn => "NOT_IN",
+                Self::Generalizes => "GENERALIZES",
+                Self::ChildOf => "CHILD_OF",
+                Self::DescendentLeaf => "DESCENDENT_LEAF",
+                Self::Exists => "EXISTS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EQUALS" => Some(Self::Equals),
+                "IS_A" => Some(Self::IsA),
+                "DESCENDENT_OF" => Some(Self::DescendentOf),
+                "IS_NOT_A" => Some(Self::IsNotA),
+                "REGEX" => Some(Self::Regex),
+                "IN" => Some(Self::In),
+                "NOT_IN" => Some(Self::NotIn),
+                "GENERALIZES" => Some(Self::Generalizes),
+                "CHILD_OF" => Some(Self::ChildOf),
+                "DESCENDENT_LEAF" => Some(Self::DescendentLeaf),
+                "EXISTS" => Some(Self::Exists),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes Status codes.
+/// See <http://hl7.org/fhir/fm-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FinancialResourceStatusCode {}
+/// Nested message and enum types in `FinancialResourceStatusCode`.
+pub mod financial_resource_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Cancelled = 2,
+        Draft = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Cancelled => "CANCELLED",
+                Self::Draft => "DRAFT",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "CANCELLED" => Some(Self::Cancelled),
+                "DRAFT" => Some(Self::Draft),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates whether this flag is active and needs to be displayed to a user, or
+/// whether it is no longer needed or was entered in error. See
+/// <http://hl7.org/fhir/flag-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FlagStatusCode {}
+/// Nested message and enum types in `FlagStatusCode`.
+pub mod flag_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// FormularyItem Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/formularyitem-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FormularyItemStatusCode {}
+/// Nested message and enum types in `FormularyItemStatusCode`.
+pub mod formulary_item_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        EnteredInError = 2,
+        Inactive = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Inactive => "INACTIVE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "INACTIVE" => Some(Self::Inactive),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the GenomicStudy.
+/// See <http://hl7.org/fhir/genomicstudy-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GenomicStudyStatusCode {}
+/// Nested message and enum types in `GenomicStudyStatusCode`.
+pub mod genomic_study_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Registered = 1,
+        Available = 2,
+        Cancelled = 3,
+        EnteredInError = 4,
+        Unknown = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Registered => "REGISTERED",
+                Self::Available => "AVAILABLE",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REGISTERED" => Some(Self::Registered),
+                "AVAILABLE" => Some(Self::Available),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes that reflect the current state of a goal and whether the goal is still
+/// being targeted. See <http://hl7.org/fhir/goal-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GoalLifecycleStatusCode {}
+/// Nested message and enum types in `GoalLifecycleStatusCode`.
+pub mod goal_lifecycle_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Proposed = 1,
+        Planned = 2,
+        Accepted = 3,
+        Active = 4,
+        OnHold = 5,
+        Completed = 6,
+        Cancelled = 7,
+        EnteredInError = 8,
+        Rejected = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Proposed => "PROPOSED",
+                Self::Planned => "PLANNED",
+                Self::Accepted => "ACCEPTED",
+                Self::Active => "ACTIVE",
+                Self::OnHold => "ON_HOLD",
+                Self::Completed => "COMPLETED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Rejected => "REJECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PROPOSED" => Some(Self::Proposed),
+                "PLANNED" => Some(Self::Planned),
+                "ACCEPTED" => Some(Self::Accepted),
+                "ACTIVE" => Some(Self::Active),
+                "ON_HOLD" => Some(Self::OnHold),
+                "COMPLETED" => Some(Self::Completed),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "REJECTED" => Some(Self::Rejected),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a compartment must be linked.
+/// See <http://hl7.org/fhir/graph-compartment-rule>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GraphCompartmentRuleCode {}
+/// Nested message and enum types in `GraphCompartmentRuleCode`.
+pub mod graph_compartment_rule_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Identical = 1,
+        Matching = 2,
+        Different = 3,
+        Custom = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Identical => "IDENTICAL",
+                Self::Matching => "MATCHING",
+                Self::Different => "DIFFERENT",
+                Self::Custom => "CUSTOM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IDENTICAL" => Some(Self::Identical),
+                "MATCHING" => Some(Self::Matching),
+                "DIFFERENT" => Some(Self::Different),
+                "CUSTOM" => Some(Self::Custom),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines how a compartment rule is used.
+/// See <http://hl7.org/fhir/graph-compartment-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GraphCompartmentUseCode {}
+/// Nested message and enum types in `GraphCompartmentUseCode`.
+pub mod graph_compartment_use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Where = 3,
+        Requires = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Where => "WHERE",
+                Self::Requires => "REQUIRES",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "WHERE" => Some(Self::Where),
+                "REQUIRES" => Some(Self::Requires),
+                _ => None,
+            }
+        }
+    }
+}
+/// Basis for membership in a group
+/// See <http://hl7.org/fhir/group-membership-basis>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GroupMembershipBasisCode {}
+/// Nested message and enum types in `GroupMembershipBasisCode`.
+pub mod group_membership_basis_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Definitional = 1,
+        Enumerated = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Definitional => "DEFINITIONAL",
+                Self::Enumerated => "ENUMERATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DEFINITIONAL" => Some(Self::Definitional),
+                "ENUMERATED" => Some(Self::Enumerated),
+                _ => None,
+            }
+        }
+    }
+}
+/// Types of resources that are part of group.
+/// See <http://hl7.org/fhir/group-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GroupTypeCode {}
+/// Nested message and enum types in `GroupTypeCode`.
+pub mod group_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Person = 1,
+        Animal = 2,
+        Practitioner = 3,
+        Device = 4,
+        Careteam = 7,
+        Healthcareservice = 8,
+        Location = 9,
+        Organization = 10,
+        Relatedperson = 11,
+        Specimen = 12,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Person => "PERSON",
+                Self::Animal => "ANIMAL",
+                Self::Practitioner => "PRACTITIONER",
+                Self::Device => "DEVICE",
+                Self::Careteam => "CARETEAM",
+                Self::Healthcareservice => "HEALTHCARESERVICE",
+                Self::Location => "LOCATION",
+                Self::Organization => "ORGANIZATION",
+                Self::Relatedperson => "RELATEDPERSON",
+                Self::Specimen => "SPECIMEN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PERSON" => Some(Self::Person),
+                "ANIMAL" => Some(Self::Animal),
+                "PRACTITIONER" => Some(Self::Practitioner),
+                "DEVICE" => Some(Self::Device),
+                "CARETEAM" => Some(Self::Careteam),
+                "HEALTHCARESERVICE" => Some(Self::Healthcareservice),
+                "LOCATION" => Some(Self::Location),
+                "ORGANIZATION" => Some(Self::Organization),
+                "RELATEDPERSON" => Some(Self::Relatedperson),
+                "SPECIMEN" => Some(Self::Specimen),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of a guidance response.
+/// See <http://hl7.org/fhir/guidance-response-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GuidanceResponseStatusCode {}
+/// Nested message and enum types in `GuidanceResponseStatusCode`.
+pub mod guidance_response_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Success = 1,
+        DataRequested = 2,
+        DataRequired = 3,
+        InProgress = 4,
+        Failure = 5,
+        EnteredInError = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Success => "SUCCESS",
+                Self::DataRequested => "DATA_REQUESTED",
+                Self::DataRequired => "DATA_REQUIRED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Failure => "FAILURE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SUCCESS" => Some(Self::Success),
+                "DATA_REQUESTED" => Some(Self::DataRequested),
+                "DATA_REQUIRED" => Some(Self::DataRequired),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "FAILURE" => Some(Self::Failure),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code that indicates how the page is generated.
+/// See <http://hl7.org/fhir/guide-page-generation>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GuidePageGenerationCode {}
+/// Nested message and enum types in `GuidePageGenerationCode`.
+pub mod guide_page_generation_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Html = 1,
+        Markdown = 2,
+        Xml = 3,
+        Generated = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Html => "HTML",
+                Self::Markdown => "MARKDOWN",
+                Self::Xml => "XML",
+                Self::Generated => "GENERATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "HTML" => Some(Self::Html),
+                "MARKDOWN" => Some(Self::Markdown),
+                "XML" => Some(Self::Xml),
+                "GENERATED" => Some(Self::Generated),
+                _ => None,
+            }
+        }
+    }
+}
+/// HTTP verbs (in the HTTP command line). See [HTTP
+/// rfc](<https://tools.ietf.org/html/rfc7231>) for details. See
+/// <http://hl7.org/fhir/http-verb>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HttpVerbCode {}
+/// Nested message and enum types in `HTTPVerbCode`.
+pub mod http_verb_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Get = 1,
+        Head = 2,
+        Post = 3,
+        Put = 4,
+        Delete = 5,
+        Patch = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Get => "GET",
+                Self::Head => "HEAD",
+                Self::Post => "POST",
+                Self::Put => "PUT",
+                Self::Delete => "DELETE",
+                Self::Patch => "PATCH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "GET" => Some(Self::Get),
+                "HEAD" => Some(Self::Head),
+                "POST" => Some(Self::Post),
+                "PUT" => Some(Self::Put),
+                "DELETE" => Some(Self::Delete),
+                "PATCH" => Some(Self::Patch),
+                _ => None,
+            }
+        }
+    }
+}
+/// Identifies the purpose for this identifier, if known .
+/// See <http://hl7.org/fhir/identifier-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IdentifierUseCode {}
+/// Nested message and enum types in `IdentifierUseCode`.
+pub mod identifier_use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Usual = 1,
+        Official = 2,
+        Temp = 3,
+        Secondary = 4,
+        Old = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Usual => "USUAL",
+                Self::Official => "OFFICIAL",
+                Self::Temp => "TEMP",
+                Self::Secondary => "SECONDARY",
+                Self::Old => "OLD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "USUAL" => Some(Self::Usual),
+                "OFFICIAL" => Some(Self::Official),
+                "TEMP" => Some(Self::Temp),
+                "SECONDARY" => Some(Self::Secondary),
+                "OLD" => Some(Self::Old),
+                _ => None,
+            }
+        }
+    }
+}
+/// The level of confidence that this link represents the same actual person,
+/// based on NIST Authentication Levels. See
+/// <http://hl7.org/fhir/identity-assuranceLevel>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IdentityAssuranceLevelCode {}
+/// Nested message and enum types in `IdentityAssuranceLevelCode`.
+pub mod identity_assurance_level_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Level1 = 1,
+        Level2 = 2,
+        Level3 = 3,
+        Level4 = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Level1 => "LEVEL1",
+                Self::Level2 => "LEVEL2",
+                Self::Level3 => "LEVEL3",
+                Self::Level4 => "LEVEL4",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "LEVEL1" => Some(Self::Level1),
+                "LEVEL2" => Some(Self::Level2),
+                "LEVEL3" => Some(Self::Level3),
+                "LEVEL4" => Some(Self::Level4),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of coordinates describing a 2D image region.
+/// See <http://hl7.org/fhir/imagingselection-2dgraphictype>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ImagingSelection2DGraphicTypeCode {}
+/// Nested message and enum types in `ImagingSelection2DGraphicTypeCode`.
+pub mod imaging_selection2_d_graphic_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Point = 1,
+        Polyline = 2,
+        Interpolated = 3,
+        Circle = 4,
+        Ellipse = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Point => "POINT",
+                Self::Polyline => "POLYLINE",
+                Self::Interpolated => "INTERPOLATED",
+                Self::Circle => "CIRCLE",
+                Self::Ellipse => "ELLIPSE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "POINT" => Some(Self::Point),
+                "POLYLINE" => Some(Self::Polyline),
+                "INTERPOLATED" => Some(Self::Interpolated),
+                "CIRCLE" => Some(Self::Circle),
+                "ELLIPSE" => Some(Self::Ellipse),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of coordinates describing an image region.
+/// See <http://hl7.org/fhir/imagingselection-3dgraphictype>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ImagingSelection3DGraphicTypeCode {}
+/// Nested message and enum types in `ImagingSelection3DGraphicTypeCode`.
+pub mod imaging_selection3_d_graphic_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Point = 1,
+        Multipoint = 2,
+        Polyline = 3,
+        Polygon = 4,
+        Ellipse = 5,
+        Ellipsoid = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Point => "POINT",
+                Self::Multipoint => "MULTIPOINT",
+                Self::Polyline => "POLYLINE",
+                Self::Polygon => "POLYGON",
+                Self::Ellipse => "ELLIPSE",
+                Self::Ellipsoid => "ELLIPSOID",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "POINT" => Some(Self::Point),
+                "MULTIPOINT" => Some(Self::Multipoint),
+                "POLYLINE" => Some(Self::Polyline),
+                "POLYGON" => Some(Self::Polygon),
+                "ELLIPSE" => Some(Self::Ellipse),
+                "ELLIPSOID" => Some(Self::Ellipsoid),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the ImagingSelection.
+/// See <http://hl7.org/fhir/imagingselection-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ImagingSelectionStatusCode {}
+/// Nested message and enum types in `ImagingSelectionStatusCode`.
+pub mod imaging_selection_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Available = 1,
+        EnteredInError = 2,
+        Unknown = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Available => "AVAILABLE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AVAILABLE" => Some(Self::Available),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the ImagingStudy.
+/// See <http://hl7.org/fhir/imagingstudy-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ImagingStudyStatusCode {}
+/// Nested message and enum types in `ImagingStudyStatusCode`.
+pub mod imaging_study_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Registered = 1,
+        Available = 2,
+        Cancelled = 3,
+        EnteredInError = 4,
+        Unknown = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Registered => "REGISTERED",
+                Self::Available => "AVAILABLE",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REGISTERED" => Some(Self::Registered),
+                "AVAILABLE" => Some(Self::Available),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The way in which this manufacturer is associated with the ingredient. For
+/// example whether it is a possible one (others allowed), or an exclusive
+/// authorized one for this ingredient. Note that this is not the manufacturing
+/// process role See <http://hl7.org/fhir/ingredient-manufacturer-role>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IngredientManufacturerRoleCode {}
+/// Nested message and enum types in `IngredientManufacturerRoleCode`.
+pub mod ingredient_manufacturer_role_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Allowed = 1,
+        Possible = 2,
+        Actual = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Allowed => "ALLOWED",
+                Self::Possible => "POSSIBLE",
+                Self::Actual => "ACTUAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ALLOWED" => Some(Self::Allowed),
+                "POSSIBLE" => Some(Self::Possible),
+                "ACTUAL" => Some(Self::Actual),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of count.
+/// See <http://hl7.org/fhir/inventoryreport-counttype>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InventoryCountTypeCode {}
+/// Nested message and enum types in `InventoryCountTypeCode`.
+pub mod inventory_count_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Snapshot = 1,
+        Difference = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Snapshot => "SNAPSHOT",
+                Self::Difference => "DIFFERENCE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SNAPSHOT" => Some(Self::Snapshot),
+                "DIFFERENCE" => Some(Self::Difference),
+                _ => None,
+            }
+        }
+    }
+}
+/// InventoryItem Status Codes
+/// See <http://hl7.org/fhir/inventoryitem-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InventoryItemStatusCode {}
+/// Nested message and enum types in `InventoryItemStatusCode`.
+pub mod inventory_item_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+        Unknown = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the InventoryReport.
+/// See <http://hl7.org/fhir/inventoryreport-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InventoryReportStatusCode {}
+/// Nested message and enum types in `InventoryReportStatusCode`.
+pub mod inventory_report_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Requested = 2,
+        Active = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Requested => "REQUESTED",
+                Self::Active => "ACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "REQUESTED" => Some(Self::Requested),
+                "ACTIVE" => Some(Self::Active),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of an Invoice.
+/// See <http://hl7.org/fhir/invoice-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InvoiceStatusCode {}
+/// Nested message and enum types in `InvoiceStatusCode`.
+pub mod invoice_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Issued = 2,
+        Balanced = 3,
+        Cancelled = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Issued => "ISSUED",
+                Self::Balanced => "BALANCED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "ISSUED" => Some(Self::Issued),
+                "BALANCED" => Some(Self::Balanced),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// How the issue affects the success of the action.
+/// See <http://hl7.org/fhir/issue-severity>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IssueSeverityCode {}
+/// Nested message and enum types in `IssueSeverityCode`.
+pub mod issue_severity_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Fatal = 1,
+        Error = 2,
+        Warning = 3,
+        Information = 4,
+        Success = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Fatal => "FATAL",
+                Self::Error => "ERROR",
+                Self::Warning => "WARNING",
+                Self::Information => "INFORMATION",
+                Self::Success => "SUCCESS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FATAL" => Some(Self::Fatal),
+                "ERROR" => Some(Self::Error),
+                "WARNING" => Some(Self::Warning),
+                "INFORMATION" => Some(Self::Information),
+                "SUCCESS" => Some(Self::Success),
+                _ => None,
+            }
+        }
+    }
+}
+/// A code that describes the type of issue.
+/// See <http://hl7.org/fhir/issue-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IssueTypeCode {}
+/// Nested message and enum types in `IssueTypeCode`.
+pub mod issue_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Invalid = 1,
+        Structure = 2,
+        Required = 3,
+        Value = 4,
+        Invariant = 5,
+        Security = 6,
+        Login = 7,
+        Unknown = 8,
+        Expired = 9,
+        Forbidden = 10,
+        Suppressed = 11,
+        Processing = 12,
+        NotSupported = 13,
+        Duplicate = 14,
+        MultipleMatches = 15,
+        NotFound = 16,
+        Deleted = 17,
+        TooLong = 18,
+        CodeInvalid = 19,
+        Extension = 20,
+        TooCostly = 21,
+        BusinessRule = 22,
+        Conflict = 23,
+        LimitedFilter = 32,
+        Transient = 24,
+        LockError = 25,
+        NoStore = 26,
+        Exception = 27,
+        Timeout = 28,
+        Incomplete = 29,
+        Throttled = 30,
+        Informational = 31,
+        Success = 33,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Invalid => "INVALID",
+                Self::Structure => "STRUCTURE",
+                Self::Required => "REQUIRED",
+                Self::Value => "VALUE",
+                Self::Invariant => "INVARIANT",
+                Self::Security => "SECURITY",
+                Self::Login => "LOGIN",
+                Self::Unknown => "UNKNOWN",
+                Self::Expired => "EXPIRED",
+                Self::Forbidden => "FORBIDDEN",
+                Self::Suppressed => "SUPPRESSED",
+                Self::Processing => "PROCESSING",
+                Self::NotSupported => "NOT_SUPPORTED",
+                Self::Duplicate => "DUPLICATE",
+                Self::MultipleMatches => "MULTIPLE_MATCHES",
+                Self::NotFound => "NOT_FOUND",
+                Self::Deleted => "DELETED",
+                Self::TooLong => "TOO_LONG",
+                Self::CodeInvalid => "CODE_INVALID",
+                Self::Extension => "EXTENSION",
+                Self::TooCostly => "TOO_COSTLY",
+                Self::BusinessRule => "BUSINESS_RULE",
+                Self::Conflict => "CONFLICT",
+                Self::LimitedFilter => "LIMITED_FILTER",
+                Self::Transient => "TRANSIENT",
+                Self::LockError => "LOCK_ERROR",
+                Self::NoStore => "NO_STORE",
+                Self::Exception => "EXCEPTION",
+                Self::Timeout => "TIMEOUT",
+                Self::Incomplete => "INCOMPLETE",
+                Self::Throttled => "THROTTLED",
+                Self::Informational => "INFORMATIONAL",
+                Self::Success => "SUCCESS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INVALID" => Some(Self::Invalid),
+                "STRUCTURE" => Some(Self::Structure),
+                "REQUIRED" => Some(Self::Required),
+                "VALUE" => Some(Self::Value),
+                "INVARIANT" => Some(Self::Invariant),
+                "SECURITY" => Some(Self::Security),
+                "LOGIN" => Some(Self::Login),
+                "UNKNOWN" => Some(Self::Unknown),
+                "EXPIRED" => Some(Self::Expired),
+                "FORBIDDEN" => Some(Self::Forbidden),
+                "SUPPRESSED" => Some(Self::Suppressed),
+                "PROCESSING" => Some(Self::Processing),
+                "NOT_SUPPORTED" => Some(Self::NotSupported),
+                "DUPLICATE" => Some(Self::Duplicate),
+                "MULTIPLE_MATCHES" => Some(Self::MultipleMatches),
+                "NOT_FOUND" => Some(Self::NotFound),
+                "DELETED" => Some(Self::Deleted),
+                "TOO_LONG" => Some(Self::TooLong),
+                "CODE_INVALID" => Some(Self::CodeInvalid),
+                "EXTENSION" => Some(Self::Extension),
+                "TOO_COSTLY" => Some(Self::TooCostly),
+                "BUSINESS_RULE" => Some(Self::BusinessRule),
+                "CONFLICT" => Some(Self::Conflict),
+                "LIMITED_FILTER" => Some(Self::LimitedFilter),
+                "TRANSIENT" => Some(Self::Transient),
+                "LOCK_ERROR" => Some(Self::LockError),
+                "NO_STORE" => Some(Self::NoStore),
+                "EXCEPTION" => Some(Self::Exception),
+                "TIMEOUT" => Some(Self::Timeout),
+                "INCOMPLETE" => Some(Self::Incomplete),
+                "THROTTLED" => Some(Self::Throttled),
+                "INFORMATIONAL" => Some(Self::Informational),
+                "SUCCESS" => Some(Self::Success),
+                _ => None,
+            }
+        }
+    }
+}
+/// The nature of the Coverage details which convey who is paying potentially for
+/// health services. See <http://hl7.org/fhir/coverage-kind>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct KindCode {}
+/// Nested message and enum types in `KindCode`.
+pub mod kind_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Insurance = 1,
+        SelfPay = 2,
+        Other = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Insurance => "INSURANCE",
+                Self::SelfPay => "SELF_PAY",
+                Self::Other => "OTHER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INSURANCE" => Some(Self::Insurance),
+                "SELF_PAY" => Some(Self::SelfPay),
+                "OTHER" => Some(Self::Other),
+                _ => None,
+            }
+        }
+    }
+}
+/// A knowledge representation level, narrative, semi-structured, structured, and
+/// executable See <http://hl7.org/fhir/CodeSystem/knowledge-representation-level>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct KnowledgeRepresentationLevelCode {}
+/// Nested message and enum types in `KnowledgeRepresentationLevelCode`.
+pub mod knowledge_representation_level_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Narrative = 1,
+        SemiStructured = 2,
+        Structured = 3,
+        Executable = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Narrative => "NARRATIVE",
+                Self::SemiStructured => "SEMI_STRUCTURED",
+                Self::Structured => "STRUCTURED",
+                Self::Executable => "EXECUTABLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NARRATIVE" => Some(Self::Narrative),
+                "SEMI_STRUCTURED" => Some(Self::SemiStructured),
+                "STRUCTURED" => Some(Self::Structured),
+                "EXECUTABLE" => Some(Self::Executable),
+                _ => None,
+            }
+        }
+    }
+}
+/// Link Relation Types defined at
+/// <https://www.iana.org/assignments/link-relations/link-relations.xhtml#link-relations-1>
+/// See <http://hl7.org/fhir/CodeSystem/iana-link-relations>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LinkRelationTypesCode {}
+/// Nested message and enum types in `LinkRelationTypesCode`.
+pub mod link_relation_types_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        About = 1,
+        Acl = 2,
+        Alternate = 3,
+        Amphtml = 4,
+        Appendix = 5,
+        AppleTouchIcon = 6,
+        AppleTouchStartupImage = 7,
+        Archives = 8,
+        Author = 9,
+        BlockedBy = 10,
+        Bookmark = 11,
+        Canonical = 12,
+        Chapter = 13,
+        CiteAs = 14,
+        Collection = 15,
+        Contents = 16,
+        ConvertedFrom = 17,
+        Copyright = 18,
+        CreateForm = 19,
+        Current = 20,
+        Describedby = 21,
+        Describes = 22,
+        Disclosure = 23,
+        DnsPrefetch = 24,
+        Duplicate = 25,
+        Edit = 26,
+        EditForm = 27,
+        EditMedia = 28,
+        Enclosure = 29,
+        External = 30,
+        First = 31,
+        Glossary = 32,
+        Help = 33,
+        Hosts = 34,
+        Hub = 35,
+        Icon = 36,
+        Index = 37,
+        IntervalAfter = 38,
+        IntervalBefore = 39,
+        IntervalContains = 40,
+        IntervalDisjoint = 41,
+        IntervalDuring = 42,
+        IntervalEquals = 43,
+        IntervalFinishedBy = 44,
+        IntervalFinishes = 45,
+        IntervalIn = 46,
+        IntervalMeets = 47,
+        IntervalMetBy = 48,
+        IntervalOverlappedBy = 49,
+        IntervalOverlaps = 50,
+        IntervalStartedBy = 51,
+        IntervalStarts = 52,
+        Item = 53,
+        Last = 54,
+        LatestVersion = 55,
+        License = 56,
+        Linkset = 57,
+        Lrdd = 58,
+        Manifest = 59,
+        MaskIcon = 60,
+        MediaFeed = 61,
+        Memento = 62,
+        Micropub = 63,
+        Modulepreload = 64,
+        Monitor = 65,
+        MonitorGroup = 66,
+        Next = 67,
+        NextArchive = 68,
+        Nofollow = 69,
+        Noopener = 70,
+        Noreferrer = 71,
+        Opener = 72,
+        Openid2LocalId = 73,
+        Openid2Provider = 74,
+        Original = 75,
+        P3Pv1 = 76,
+        Payment = 77,
+        Pingback = 78,
+        Preconnect = 79,
+        PredecessorVersion = 80,
+        Prefetch = 81,
+        Preload = 82,
+        Prerender = 83,
+        Prev = 84,
+        Preview = 85,
+        Previous = 86,
+        PrevArchive = 87,
+        PrivacyPolicy = 88,
+        Profile = 89,
+        Publication = 90,
+        Related = 91,
+        Restconf = 92,
+        Replies = 93,
+        Ruleinput = 94,
+        Search = 95,
+        Section = 96,
+        Self_ = 97,
+        Service = 98,
+        ServiceDesc = 99,
+        ServiceDoc = 100,
+        ServiceMeta = 101,
+        Sponsored = 102,
+        Start = 103,
+        Status = 104,
+        Stylesheet = 105,
+        Subsection = 106,
+        SuccessorVersion = 107,
+        Sunset = 108,
+        Tag = 109,
+        TermsOfService = 110,
+        Timegate = 111,
+        Timemap = 112,
+        Type = 113,
+        Ugc = 114,
+        Up = 115,
+        VersionHistory = 116,
+        Via = 117,
+        Webmention = 118,
+        WorkingCopy = 119,
+        WorkingCopyOf = 120,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::About => "ABOUT",
+                Self::Acl => "ACL",
+                Self::Alternate => "ALTERNATE",
+                Self::Amphtml => "AMPHTML",
+                Self::Appendix => "APPENDIX",
+                Self::AppleTouchIcon => "APPLE_TOUCH_ICON",
+                Self::AppleTouchStartupImage => "APPLE_TOUCH_STARTUP_IMAGE",
+                Self::Archives => "ARCHIVES",
+                Self::Author => "AUTHOR",
+                Self::BlockedBy => "BLOCKED_BY",
+                Self::Bookmark => "BOOKMARK",
+                Self::Canonical => "CANONICAL",
+                Self::Chapter => "CHAPTER",
+                Self::CiteAs => "CITE_AS",
+                Self::Collection => "COLLECTION",
+                Self::Contents => "CONTENTS",
+                Self::ConvertedFrom => "CONVERTED_FROM",
+                Self::Copyright => "COPYRIGHT",
+                Self::CreateForm => "CREATE_FORM",
+                Self::Current => "CURRENT",
+                Self::Describedby => "DESCRIBEDBY",
+                Self::Describes => "DESCRIBES",
+                Self::Disclosure => "DISCLOSURE",
+                Self::DnsPrefetch => "DNS_PREFETCH",
+                Self::Duplicate => "DUPLICATE",
+                Self::Edit => "EDIT",
+                Self::EditForm => "EDIT_FORM",
+                Self::EditMedia => "EDIT_MEDIA",
+                Self::Enclosure => "ENCLOSURE",
+                Self::External => "EXTERNAL",
+                Self::First => "FIRST",
+                Self::Glossary => "GLOSSARY",
+                Self::Help => "HELP",
+                Self::Hosts => "HOSTS",
+                Self::Hub => "HUB",
+                Self::Icon => "ICON",
+                Self::Index => "INDEX",
+                Self::IntervalAfter => "INTERVAL_AFTER",
+                Self::IntervalBefore => "INTERVAL_BEFORE",
+                Self::IntervalContains => "INTERVAL_CONTAINS",
+                Self::IntervalDisjoint => "INTERVAL_DISJOINT",
+                Self::IntervalDuring => "INTERVAL_DURING",
+                Self::IntervalEquals => "INTERVAL_EQUALS",
+                Self::IntervalFinishedBy => "INTERVAL_FINISHED_BY",
+                Self::IntervalFinishes => "INTERVAL_FINISHES",
+                Self::IntervalIn => "INTERVAL_IN",
+                Self::IntervalMeets => "INTERVAL_MEETS",
+                Self::IntervalMetBy => "INTERVAL_MET_BY",
+                Self::IntervalOverlappedBy => "INTERVAL_OVERLAPPED_BY",
+                Self::IntervalOverlaps => "INTERVAL_OVERLAPS",
+                Self::IntervalStartedBy => "INTERVAL_STARTED_BY",
+                Self::IntervalStarts => "INTERVAL_STARTS",
+                Self::Item => "ITEM",
+                Self::Last => "LAST",
+                Self::LatestVersion => "LATEST_VERSION",
+                Self::License => "LICENSE",
+                Self::Linkset => "LINKSET",
+                Self::Lrdd => "LRDD",
+                Self::Manifest => "MANIFEST",
+                Self::MaskIcon => "MASK_ICON",
+                Self::MediaFeed => "MEDIA_FEED",
+                Self::Memento => "MEMENTO",
+                Self::Micropub => "MICROPUB",
+                Self::Modulepreload => "MODULEPRELOAD",
+                Self::Monitor => "MONITOR",
+                Self::MonitorGroup => "MONITOR_GROUP",
+                Self::Next => "NEXT",
+                Self::NextArchive => "NEXT_ARCHIVE",
+                Self::Nofollow => "NOFOLLOW",
+                Self::Noopener => "NOOPENER",
+                Self::Noreferrer => "NOREFERRER",
+                Self::Opener => "OPENER",
+                Self::Openid2LocalId => "OPENID2_LOCAL_ID",
+                Self::Openid2Provider => "OPENID2_PROVIDER",
+                Self::Original => "ORIGINAL",
+                Self::P3Pv1 => "P3_PV1",
+                Self::Payment => "PAYMENT",
+                Self::Pingback => "PINGBACK",
+                Self::Preconnect => "PRECONNECT",
+                Self::PredecessorVersion => "PREDECESSOR_VERSION",
+                Self::Prefetch => "PREFETCH",
+                Self::Preload => "PRELOAD",
+                Self::Prerender => "PRERENDER",
+                Self::Prev => "PREV",
+                Self::Preview => "PREVIEW",
+                Self::Previous => "PREVIOUS",
+                Self::PrevArchive => "PREV_ARCHIVE",
+                Self::PrivacyPolicy => "PRIVACY_POLICY",
+                Self::Profile => "PROFILE",
+                Self::Publication => "PUBLICATION",
+                Self::Related => "RELATED",
+                Self::Restconf => "RESTCONF",
+                Self::Replies => "REPLIES",
+                Self::Ruleinput => "RULEINPUT",
+                Self::Search => "SEARCH",
+                Self::Section => "SECTION",
+                Self::Self_ => "SELF",
+                Self::Service => "SERVICE",
+                Self::ServiceDesc => "SERVICE_DESC",
+                Self::ServiceDoc => "SERVICE_DOC",
+                Self::ServiceMeta => "SERVICE_META",
+                Self::Sponsored => "SPONSORED",
+                Self::Start => "START",
+                Self::Status => "STATUS",
+                Self::Stylesheet => "STYLESHEET",
+                Self::Subsection => "SUBSECTION",
+                Self::SuccessorVersion => "SUCCESSOR_VERSION",
+                Self::Sunset => "SUNSET",
+                Self::Tag => "TAG",
+                Self::TermsOfService => "TERMS_OF_SERVICE",
+                Self::Timegate => "TIMEGATE",
+                Self::Timemap => "TIMEMAP",
+                Self::Type => "TYPE",
+                Self::Ugc => "UGC",
+                Self::Up => "UP",
+                Self::VersionHistory => "VERSION_HISTORY",
+                Self::Via => "VIA",
+                Self::Webmention => "WEBMENTION",
+                Self::WorkingCopy => "WORKING_COPY",
+                Self::WorkingCopyOf => "WORKING_COPY_OF",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ABOUT" => Some(Self::About),
+                "ACL" => Some(Self::Acl),
+                "ALTERNATE" => Some(Self::Alternate),
+                "AMPHTML" => Some(Self::Amphtml),
+                "APPENDIX" => Some(Self::Appendix),
+                "APPLE_TOUCH_ICON" => Some(Self::AppleTouchIcon),
+                "APPLE_TOUCH_STARTUP_IMAGE" => Some(Self::AppleTouchStartupImage),
+                "ARCHIVES" => Some(Self::Archives),
+                "AUTHOR" => Some(Self::Author),
+                "BLOCKED_BY" => Some(Self::BlockedBy),
+                "BOOKMARK" => Some(Self::Bookmark),
+                "CANONICAL" => Some(Self::Canonical),
+                "CHAPTER" => Some(Self::Chapter),
+                "CITE_AS" => Some(Self::CiteAs),
+                "COLLECTION" => Some(Self::Collection),
+                "CONTENTS" => Some(Self::Contents),
+                "CONVERTED_FROM" => Some(Self::ConvertedFrom),
+                "COPYRIGHT" => Some(Self::Copyright),
+                "CREATE_FORM" => Some(Self::CreateForm),
+                "CURRENT" => Some(Self::Current),
+                "DESCRIBEDBY" => Some(Self::Describedby),
+                "DESCRIBES" => Some(Self::Describes),
+                "DISCLOSURE" => Some(Self::Disclosure),
+                "DNS_PREFETCH" => Some(Self::DnsPrefetch),
+                "DUPLICATE" => Some(Self::Duplicate),
+                "EDIT" => Some(Self::Edit),
+                "EDIT_FORM" => Some(Self::EditForm),
+                "EDIT_MEDIA" => Some(Self::EditMedia),
+                "ENCLOSURE" => Some(Self::Enclosure),
+                "EXTERNAL" => Some(Self::External),
+                "FIRST" => Some(Self::First),
+                "GLOSSARY" => Some(Self::Glossary),
+                "HELP" => Some(Self::Help),
+                "HOSTS" => Some(Self::Hosts),
+                "HUB" => Some(Self::Hub),
+                "ICON" => Some(Self::Icon),
+                "INDEX" => Some(Self::Index),
+                "INTERVAL_AFTER" => Some(Self::IntervalAfter),
+                "INTERVAL_BEFORE" => Some(Self::IntervalBefore),
+                "INTERVAL_CONTAINS" => Some(Self::IntervalContains),
+                "INTERVAL_DISJOINT" => Some(Self::IntervalDisjoint),
+                "INTERVAL_DURING" => Some(Self::IntervalDuring),
+                "INTERVAL_EQUALS" => Some(Self::IntervalEquals),
+                "INTERVAL_FINISHED_BY" => Some(Self::IntervalFinishedBy),
+                "INTERVAL_FINISHES" => Some(Self::IntervalFinishes),
+                "INTERVAL_IN" => Some(Self::IntervalIn),
+                "INTERVAL_MEETS" => Some(Self::IntervalMeets),
+                "INTERVAL_MET_BY" => Some(Self::IntervalMetBy),
+                "INTERVAL_OVERLAPPED_BY" => Some(Self::IntervalOverlappedBy),
+                "INTERVAL_OVERLAPS" => Some(Self::IntervalOverlaps),
+                "INTERVAL_STARTED_BY" => Some(Self::IntervalStartedBy),
+                "INTERVAL_STARTS" => Some(Self::IntervalStarts),
+                "ITEM" => Some(Self::Item),
+                "LAST" => Some(Self::Last),
+                "LATEST_VERSION" => Some(Self::LatestVersion),
+                "LICENSE" => Some(Self::License),
+                "LINKSET" => Some(Self::Linkset),
+                "LRDD" => Some(Self::Lrdd),
+                "MANIFEST" => Some(Self::Manifest),
+                "MASK_ICON" => Some(Self::MaskIcon),
+                "MEDIA_FEED" => Some(Self::MediaFeed),
+                "MEMENTO" => Some(Self::Memento),
+                "MICROPUB" => Some(Self::Micropub),
+                "MODULEPRELOAD" => Some(Self::Modulepreload),
+                "MONITOR" => Some(Self::Monitor),
+                "MONITOR_GROUP" => Some(Self::MonitorGroup),
+                "NEXT" => Some(Self::Next),
+                "NEXT_ARCHIVE" => Some(Self::NextArchive),
+                "NOFOLLOW" => Some(Self::Nofollow),
+                "NOOPENER" => Some(Self::Noopener),
+                "NOREFERRER" => Some(Self::Noreferrer),
+                "OPENER" => Some(Self::Opener),
+                "OPENID2_LOCAL_ID" => Some(Self::Openid2LocalId),
+                "OPENID2_PROVIDER" => Some(Self::Openid2Provider),
+                "ORIGINAL" => Some(Self::Original),
+                "P3_PV1" => Some(Self::P3Pv1),
+                "PAYMENT" => Some(Self::Payment),
+                "PINGBACK" => Some(Self::Pingback),
+                "PRECONNECT" => Some(Self::Preconnect),
+                "PREDECESSOR_VERSION" => Some(Self::PredecessorVersion),
+                "PREFETCH" => Some(Self::Prefetch),
+                "PRELOAD" => Some(Self::Preload),
+                "PRERENDER" => Some(Self::Prerender),
+                "PREV" => Some(Self::Prev),
+                "PREVIEW" => Some(Self::Preview),
+                "PREVIOUS" => Some(Self::Previous),
+                "PREV_ARCHIVE" => Some(Self::PrevArchive),
+                "PRIVACY_POLICY" => Some(Self::PrivacyPolicy),
+                "PROFILE" => Some(Self::Profile),
+                "PUBLICATION" => Some(Self::Publication),
+                "RELATED" => Some(Self::Related),
+                "RESTCONF" => Some(Self::Restconf),
+                "REPLIES" => Some(Self::Replies),
+                "RULEINPUT" => Some(Self::Ruleinput),
+                "SEARCH" => Some(Self::Search),
+                "SECTION" => Some(Self::Section),
+                "SELF" => Some(Self::Self_),
+                "SERVICE" => Some(Self::Service),
+                "SERVICE_DESC" => Some(Self::ServiceDesc),
+                "SERVICE_DOC" => Some(Self::ServiceDoc),
+                "SERVICE_META" => Some(Self::ServiceMeta),
+                "SPONSORED" => Some(Self::Sponsored),
+                "START" => Some(Self::Start),
+                "STATUS" => Some(Self::Status),
+                "STYLESHEET" => Some(Self::Stylesheet),
+                "SUBSECTION" => Some(Self::Subsection),
+                "SUCCESSOR_VERSION" => Some(Self::SuccessorVersion),
+                "SUNSET" => Some(Self::Sunset),
+                "TAG" => Some(Self::Tag),
+                "TERMS_OF_SERVICE" => Some(Self::TermsOfService),
+                "TIMEGATE" => Some(Self::Timegate),
+                "TIMEMAP" => Some(Self::Timemap),
+                "TYPE" => Some(Self::Type),
+                "UGC" => Some(Self::Ugc),
+                "UP" => Some(Self::Up),
+                "VERSION_HISTORY" => Some(Self::VersionHistory),
+                "VIA" => Some(Self::Via),
+                "WEBMENTION" => Some(Self::Webmention),
+                "WORKING_COPY" => Some(Self::WorkingCopy),
+                "WORKING_COPY_OF" => Some(Self::WorkingCopyOf),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of link between this Patient resource and other
+/// Patient/RelatedPerson resource(s). See <http://hl7.org/fhir/link-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LinkTypeCode {}
+/// Nested message and enum types in `LinkTypeCode`.
+pub mod link_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        ReplacedBy = 1,
+        Replaces = 2,
+        Refer = 3,
+        Seealso = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::ReplacedBy => "REPLACED_BY",
+                Self::Replaces => "REPLACES",
+                Self::Refer => "REFER",
+                Self::Seealso => "SEEALSO",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REPLACED_BY" => Some(Self::ReplacedBy),
+                "REPLACES" => Some(Self::Replaces),
+                "REFER" => Some(Self::Refer),
+                "SEEALSO" => Some(Self::Seealso),
+                _ => None,
+            }
+        }
+    }
+}
+/// Used to distinguish different roles a resource can play within a set of
+/// linked resources. See <http://hl7.org/fhir/linkage-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LinkageTypeCode {}
+/// Nested message and enum types in `LinkageTypeCode`.
+pub mod linkage_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Source = 1,
+        Alternate = 2,
+        Historical = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Source => "SOURCE",
+                Self::Alternate => "ALTERNATE",
+                Self::Historical => "HISTORICAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SOURCE" => Some(Self::Source),
+                "ALTERNATE" => Some(Self::Alternate),
+                "HISTORICAL" => Some(Self::Historical),
+                _ => None,
+            }
+        }
+    }
+}
+/// The processing mode that applies to this list.
+/// See <http://hl7.org/fhir/list-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListModeCode {}
+/// Nested message and enum types in `ListModeCode`.
+pub mod list_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Working = 1,
+        Snapshot = 2,
+        Changes = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Working => "WORKING",
+                Self::Snapshot => "SNAPSHOT",
+                Self::Changes => "CHANGES",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "WORKING" => Some(Self::Working),
+                "SNAPSHOT" => Some(Self::Snapshot),
+                "CHANGES" => Some(Self::Changes),
+                _ => None,
+            }
+        }
+    }
+}
+/// The current state of the list.
+/// See <http://hl7.org/fhir/list-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListStatusCode {}
+/// Nested message and enum types in `ListStatusCode`.
+pub mod list_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Current = 1,
+        Retired = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Current => "CURRENT",
+                Self::Retired => "RETIRED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CURRENT" => Some(Self::Current),
+                "RETIRED" => Some(Self::Retired),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates whether a resource instance represents a specific location or a
+/// class of locations. See <http://hl7.org/fhir/location-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LocationModeCode {}
+/// Nested message and enum types in `LocationModeCode`.
+pub mod location_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Instance = 1,
+        Kind = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Instance => "INSTANCE",
+                Self::Kind => "KIND",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INSTANCE" => Some(Self::Instance),
+                "KIND" => Some(Self::Kind),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates whether the location is still in use.
+/// See <http://hl7.org/fhir/location-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LocationStatusCode {}
+/// Nested message and enum types in `LocationStatusCode`.
+pub mod location_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Suspended = 2,
+        Inactive = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Suspended => "SUSPENDED",
+                Self::Inactive => "INACTIVE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "SUSPENDED" => Some(Self::Suspended),
+                "INACTIVE" => Some(Self::Inactive),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of the measure report.
+/// See <http://hl7.org/fhir/measure-report-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MeasureReportStatusCode {}
+/// Nested message and enum types in `MeasureReportStatusCode`.
+pub mod measure_report_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Complete = 1,
+        Pending = 2,
+        Error = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Complete => "COMPLETE",
+                Self::Pending => "PENDING",
+                Self::Error => "ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "COMPLETE" => Some(Self::Complete),
+                "PENDING" => Some(Self::Pending),
+                "ERROR" => Some(Self::Error),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of the measure report.
+/// See <http://hl7.org/fhir/measure-report-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MeasureReportTypeCode {}
+/// Nested message and enum types in `MeasureReportTypeCode`.
+pub mod measure_report_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Individual = 1,
+        SubjectList = 2,
+        Summary = 3,
+        DataExchange = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Individual => "INDIVIDUAL",
+                Self::SubjectList => "SUBJECT_LIST",
+                Self::Summary => "SUMMARY",
+                Self::DataExchange => "DATA_EXCHANGE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INDIVIDUAL" => Some(Self::Individual),
+                "SUBJECT_LIST" => Some(Self::SubjectList),
+                "SUMMARY" => Some(Self::Summary),
+                "DATA_EXCHANGE" => Some(Self::DataExchange),
+                _ => None,
+            }
+        }
+    }
+}
+/// MedicationAdministration Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/medication-admin-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationAdministrationStatusCode {}
+/// Nested message and enum types in `MedicationAdministrationStatusCode`.
+pub mod medication_administration_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        InProgress = 1,
+        NotDone = 2,
+        OnHold = 3,
+        Completed = 4,
+        EnteredInError = 5,
+        Stopped = 6,
+        Unknown = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::NotDone => "NOT_DONE",
+                Self::OnHold => "ON_HOLD",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Stopped => "STOPPED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "NOT_DONE" => Some(Self::NotDone),
+                "ON_HOLD" => Some(Self::OnHold),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "STOPPED" => Some(Self::Stopped),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Medication Dispense Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/medicationdispense-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationDispenseStatusCode {}
+/// Nested message and enum types in `MedicationDispenseStatusCode`.
+pub mod medication_dispense_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preparation = 1,
+        InProgress = 2,
+        Cancelled = 3,
+        OnHold = 4,
+        Completed = 5,
+        EnteredInError = 6,
+        Stopped = 7,
+        Declined = 8,
+        Unknown = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preparation => "PREPARATION",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Cancelled => "CANCELLED",
+                Self::OnHold => "ON_HOLD",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Stopped => "STOPPED",
+                Self::Declined => "DECLINED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PREPARATION" => Some(Self::Preparation),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ON_HOLD" => Some(Self::OnHold),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "STOPPED" => Some(Self::Stopped),
+                "DECLINED" => Some(Self::Declined),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// MedicationKnowledge Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/medicationknowledge-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationKnowledgeStatusCode {}
+/// Nested message and enum types in `MedicationKnowledgeStatusCode`.
+pub mod medication_knowledge_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        EnteredInError = 3,
+        Inactive = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Inactive => "INACTIVE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "INACTIVE" => Some(Self::Inactive),
+                _ => None,
+            }
+        }
+    }
+}
+/// MedicationRequest Intent Codes
+/// See <http://hl7.org/fhir/CodeSystem/medicationrequest-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationRequestIntentCode {}
+/// Nested message and enum types in `MedicationRequestIntentCode`.
+pub mod medication_request_intent_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Proposal = 1,
+        Plan = 2,
+        Order = 3,
+        OriginalOrder = 4,
+        ReflexOrder = 5,
+        FillerOrder = 6,
+        InstanceOrder = 7,
+        Option = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Proposal => "PROPOSAL",
+                Self::Plan => "PLAN",
+                Self::Order => "ORDER",
+                Self::OriginalOrder => "ORIGINAL_ORDER",
+                Self::ReflexOrder => "REFLEX_ORDER",
+                Self::FillerOrder => "FILLER_ORDER",
+                Self::InstanceOrder => "INSTANCE_ORDER",
+                Self::Option => "OPTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PROPOSAL" => Some(Self::Proposal),
+                "PLAN" => Some(Self::Plan),
+                "ORDER" => Some(Self::Order),
+                "ORIGINAL_ORDER" => Some(Self::OriginalOrder),
+                "REFLEX_ORDER" => Some(Self::ReflexOrder),
+                "FILLER_ORDER" => Some(Self::FillerOrder),
+                "INSTANCE_ORDER" => Some(Self::InstanceOrder),
+                "OPTION" => Some(Self::Option),
+                _ => None,
+            }
+        }
+    }
+}
+/// MedicationRequest Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/medicationrequest-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationRequestStatusCode {}
+/// Nested message and enum types in `MedicationRequestStatusCode`.
+pub mod medication_request_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        OnHold = 2,
+        Ended = 9,
+        Stopped = 6,
+        Completed = 4,
+        Cancelled = 3,
+        EnteredInError = 5,
+        Draft = 7,
+        Unknown = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::OnHold => "ON_HOLD",
+                Self::Ended => "ENDED",
+                Self::Stopped => "STOPPED",
+                Self::Completed => "COMPLETED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Draft => "DRAFT",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "ON_HOLD" => Some(Self::OnHold),
+                "ENDED" => Some(Self::Ended),
+                "STOPPED" => Some(Self::Stopped),
+                "COMPLETED" => Some(Self::Completed),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "DRAFT" => Some(Self::Draft),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// MedicationStatement Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/medication-statement-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationStatementStatusCodes {}
+/// Nested message and enum types in `MedicationStatementStatusCodes`.
+pub mod medication_statement_status_codes {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Recorded = 9,
+        EnteredInError = 3,
+        Draft = 10,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Recorded => "RECORDED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Draft => "DRAFT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "RECORDED" => Some(Self::Recorded),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "DRAFT" => Some(Self::Draft),
+                _ => None,
+            }
+        }
+    }
+}
+/// Medication Status Codes
+/// See <http://hl7.org/fhir/CodeSystem/medication-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MedicationStatusCode {}
+/// Nested message and enum types in `MedicationStatusCode`.
+pub mod medication_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The impact of the content of a message.
+/// See <http://hl7.org/fhir/message-significance-category>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MessageSignificanceCategoryCode {}
+/// Nested message and enum types in `MessageSignificanceCategoryCode`.
+pub mod message_significance_category_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Consequence = 1,
+        Currency = 2,
+        Notification = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Consequence => "CONSEQUENCE",
+                Self::Currency => "CURRENCY",
+                Self::Notification => "NOTIFICATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CONSEQUENCE" => Some(Self::Consequence),
+                "CURRENCY" => Some(Self::Currency),
+                "NOTIFICATION" => Some(Self::Notification),
+                _ => None,
+            }
+        }
+    }
+}
+/// HL7-defined table of codes which identify conditions under which
+/// acknowledgments are required to be returned in response to a message. See
+/// <http://hl7.org/fhir/messageheader-response-request>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MessageheaderResponseRequestCode {}
+/// Nested message and enum types in `MessageheaderResponseRequestCode`.
+pub mod messageheader_response_request_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Always = 1,
+        OnError = 2,
+        Never = 3,
+        OnSuccess = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Always => "ALWAYS",
+                Self::OnError => "ON_ERROR",
+                Self::Never => "NEVER",
+                Self::OnSuccess => "ON_SUCCESS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ALWAYS" => Some(Self::Always),
+                "ON_ERROR" => Some(Self::OnError),
+                "NEVER" => Some(Self::Never),
+                "ON_SUCCESS" => Some(Self::OnSuccess),
+                _ => None,
+            }
+        }
+    }
+}
+/// The use of a human name.
+/// See <http://hl7.org/fhir/name-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NameUseCode {}
+/// Nested message and enum types in `NameUseCode`.
+pub mod name_use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Usual = 1,
+        Official = 2,
+        Temp = 3,
+        Nickname = 4,
+        Anonymous = 5,
+        Old = 6,
+        Maiden = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Usual => "USUAL",
+                Self::Official => "OFFICIAL",
+                Self::Temp => "TEMP",
+                Self::Nickname => "NICKNAME",
+                Self::Anonymous => "ANONYMOUS",
+                Self::Old => "OLD",
+                Self::Maiden => "MAIDEN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "USUAL" => Some(Self::Usual),
+                "OFFICIAL" => Some(Self::Official),
+                "TEMP" => Some(Self::Temp),
+                "NICKNAME" => Some(Self::Nickname),
+                "ANONYMOUS" => Some(Self::Anonymous),
+                "OLD" => Some(Self::Old),
+                "MAIDEN" => Some(Self::Maiden),
+                _ => None,
+            }
+        }
+    }
+}
+/// Identifies the style of unique identifier used to identify a namespace.
+/// See <http://hl7.org/fhir/namingsystem-identifier-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NamingSystemIdentifierTypeCode {}
+/// Nested message and enum types in `NamingSystemIdentifierTypeCode`.
+pub mod naming_system_identifier_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Oid = 1,
+        Uuid = 2,
+        Uri = 3,
+        IriStem = 5,
+        V2csmnemonic = 6,
+        Other = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Oid => "OID",
+                Self::Uuid => "UUID",
+                Self::Uri => "URI",
+                Self::IriStem => "IRI_STEM",
+                Self::V2csmnemonic => "V2CSMNEMONIC",
+                Self::Other => "OTHER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "OID" => Some(Self::Oid),
+                "UUID" => Some(Self::Uuid),
+                "URI" => Some(Self::Uri),
+                "IRI_STEM" => Some(Self::IriStem),
+                "V2CSMNEMONIC" => Some(Self::V2csmnemonic),
+                "OTHER" => Some(Self::Other),
+                _ => None,
+            }
+        }
+    }
+}
+/// Identifies the purpose of the naming system.
+/// See <http://hl7.org/fhir/namingsystem-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NamingSystemTypeCode {}
+/// Nested message and enum types in `NamingSystemTypeCode`.
+pub mod naming_system_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Codesystem = 1,
+        Identifier = 2,
+        Root = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Codesystem => "CODESYSTEM",
+                Self::Identifier => "IDENTIFIER",
+                Self::Root => "ROOT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CODESYSTEM" => Some(Self::Codesystem),
+                "IDENTIFIER" => Some(Self::Identifier),
+                "ROOT" => Some(Self::Root),
+                _ => None,
+            }
+        }
+    }
+}
+/// The status of a resource narrative.
+/// See <http://hl7.org/fhir/narrative-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NarrativeStatusCode {}
+/// Nested message and enum types in `NarrativeStatusCode`.
+pub mod narrative_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Generated = 1,
+        Extensions = 2,
+        Additional = 3,
+        Empty = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Generated => "GENERATED",
+                Self::Extensions => "EXTENSIONS",
+                Self::Additional => "ADDITIONAL",
+                Self::Empty => "EMPTY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "GENERATED" => Some(Self::Generated),
+                "EXTENSIONS" => Some(Self::Extensions),
+                "ADDITIONAL" => Some(Self::Additional),
+                "EMPTY" => Some(Self::Empty),
+                _ => None,
+            }
+        }
+    }
+}
+/// The presentation types of notes.
+/// See <http://hl7.org/fhir/note-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NoteTypeCode {}
+/// Nested message and enum types in `NoteTypeCode`.
+pub mod note_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Display = 1,
+        Print = 2,
+        Printoper = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Display => "DISPLAY",
+                Self::Print => "PRINT",
+                Self::Printoper => "PRINTOPER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DISPLAY" => Some(Self::Display),
+                "PRINT" => Some(Self::Print),
+                "PRINTOPER" => Some(Self::Printoper),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of a product.
+/// See <http://hl7.org/fhir/nutritionproduct-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NutritionProductStatusCode {}
+/// Nested message and enum types in `NutritionProductStatusCode`.
+pub mod nutrition_product_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        Inactive = 2,
+        EnteredInError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::Inactive => "INACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "INACTIVE" => Some(Self::Inactive),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Permitted data type for observation value.
+/// See <http://hl7.org/fhir/permitted-data-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ObservationDataTypeCode {}
+/// Nested message and enum types in `ObservationDataTypeCode`.
+pub mod observation_data_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Quantity = 1,
+        CodeableConcept = 2,
+        String = 3,
+        Boolean = 4,
+        Integer = 5,
+        Range = 6,
+        Ratio = 7,
+        SampledData = 8,
+        Time = 9,
+        DateTime = 10,
+        Period = 11,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Quantity => "QUANTITY",
+                Self::CodeableConcept => "CODEABLE_CONCEPT",
+                Self::String => "STRING",
+                Self::Boolean => "BOOLEAN",
+                Self::Integer => "INTEGER",
+                Self::Range => "RANGE",
+                Self::Ratio => "RATIO",
+                Self::SampledData => "SAMPLED_DATA",
+                Self::Time => "TIME",
+                Self::DateTime => "DATE_TIME",
+                Self::Period => "PERIOD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "QUANTITY" => Some(Self::Quantity),
+                "CODEABLE_CONCEPT" => Some(Self::CodeableConcept),
+                "STRING" => Some(Self::String),
+                "BOOLEAN" => Some(Self::Boolean),
+                "INTEGER" => Some(Self::Integer),
+                "RANGE" => Some(Self::Range),
+                "RATIO" => Some(Self::Ratio),
+                "SAMPLED_DATA" => Some(Self::SampledData),
+                "TIME" => Some(Self::Time),
+                "DATE_TIME" => Some(Self::DateTime),
+                "PERIOD" => Some(Self::Period),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the category of observation range.
+/// See <http://hl7.org/fhir/observation-range-category>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ObservationRangeCategoryCode {}
+/// Nested message and enum types in `ObservationRangeCategoryCode`.
+pub mod observation_range_category_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Reference = 1,
+        Critical = 2,
+        Absolute = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Reference => "REFERENCE",
+                Self::Critical => "CRITICAL",
+                Self::Absolute => "ABSOLUTE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REFERENCE" => Some(Self::Reference),
+                "CRITICAL" => Some(Self::Critical),
+                "ABSOLUTE" => Some(Self::Absolute),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes providing the status of an observation.
+/// See <http://hl7.org/fhir/observation-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ObservationStatusCode {}
+/// Nested message and enum types in `ObservationStatusCode`.
+pub mod observation_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Registered = 1,
+        Preliminary = 2,
+        Final = 3,
+        Amended = 4,
+        Corrected = 5,
+        Cancelled = 6,
+        EnteredInError = 7,
+        Unknown = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Registered => "REGISTERED",
+                Self::Preliminary => "PRELIMINARY",
+                Self::Final => "FINAL",
+                Self::Amended => "AMENDED",
+                Self::Corrected => "CORRECTED",
+                Self::Cancelled => "CANCELLED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REGISTERED" => Some(Self::Registered),
+                "PRELIMINARY" => Some(Self::Preliminary),
+                "FINAL" => Some(Self::Final),
+                "AMENDED" => Some(Self::Amended),
+                "CORRECTED" => Some(Self::Corrected),
+                "CANCELLED" => Some(Self::Cancelled),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Whether an operation is a normal operation or a query.
+/// See <http://hl7.org/fhir/operation-kind>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OperationKindCode {}
+/// Nested message and enum types in `OperationKindCode`.
+pub mod operation_kind_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Operation = 1,
+        Query = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Operation => "OPERATION",
+                Self::Query => "QUERY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "OPERATION" => Some(Self::Operation),
+                "QUERY" => Some(Self::Query),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates that a parameter applies when the operation is being invoked at the
+/// specified level See <http://hl7.org/fhir/operation-parameter-scope>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OperationParameterScopeCode {}
+/// Nested message and enum types in `OperationParameterScopeCode`.
+pub mod operation_parameter_scope_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Instance = 1,
+        Type = 2,
+        System = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Instance => "INSTANCE",
+                Self::Type => "TYPE",
+                Self::System => "SYSTEM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INSTANCE" => Some(Self::Instance),
+                "TYPE" => Some(Self::Type),
+                "SYSTEM" => Some(Self::System),
+                _ => None,
+            }
+        }
+    }
+}
+/// Whether an operation parameter is an input or an output parameter.
+/// See <http://hl7.org/fhir/operation-parameter-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OperationParameterUseCode {}
+/// Nested message and enum types in `OperationParameterUseCode`.
+pub mod operation_parameter_use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        In = 1,
+        Out = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::In => "IN",
+                Self::Out => "OUT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN" => Some(Self::In),
+                "OUT" => Some(Self::Out),
+                _ => None,
+            }
+        }
+    }
+}
+/// Type for orientation.
+/// See <http://hl7.org/fhir/orientation-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OrientationTypeCode {}
+/// Nested message and enum types in `OrientationTypeCode`.
+pub mod orientation_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Sense = 1,
+        Antisense = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Sense => "SENSE",
+                Self::Antisense => "ANTISENSE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SENSE" => Some(Self::Sense),
+                "ANTISENSE" => Some(Self::Antisense),
+                _ => None,
+            }
+        }
+    }
+}
+/// The Participation status of an appointment.
+/// See <http://hl7.org/fhir/participationstatus>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ParticipationStatusCode {}
+/// Nested message and enum types in `ParticipationStatusCode`.
+pub mod participation_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Accepted = 1,
+        Declined = 2,
+        Tentative = 3,
+        NeedsAction = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Accepted => "ACCEPTED",
+                Self::Declined => "DECLINED",
+                Self::Tentative => "TENTATIVE",
+                Self::NeedsAction => "NEEDS_ACTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACCEPTED" => Some(Self::Accepted),
+                "DECLINED" => Some(Self::Declined),
+                "TENTATIVE" => Some(Self::Tentative),
+                "NEEDS_ACTION" => Some(Self::NeedsAction),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes Claim Processing Outcome codes.
+/// See <http://hl7.org/fhir/payment-outcome>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PaymentOutcomeCode {}
+/// Nested message and enum types in `PaymentOutcomeCode`.
+pub mod payment_outcome_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Queued = 1,
+        Complete = 2,
+        Error = 3,
+        Partial = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Queued => "QUEUED",
+                Self::Complete => "COMPLETE",
+                Self::Error => "ERROR",
+                Self::Partial => "PARTIAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "QUEUED" => Some(Self::Queued),
+                "COMPLETE" => Some(Self::Complete),
+                "ERROR" => Some(Self::Error),
+                "PARTIAL" => Some(Self::Partial),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the rule combining. See XACML Combining algorithms
+/// <http://docs.oasis-open.org/xacml/3.0/xacml-3.0-core-spec-cos01-en.html> See
+/// <http://hl7.org/fhir/permission-rule-combining>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PermissionRuleCombiningCode {}
+/// Nested message and enum types in `PermissionRuleCombiningCode`.
+pub mod permission_rule_combining_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        DenyOverrides = 1,
+        PermitOverrides = 2,
+        OrderedDenyOverrides = 3,
+        OrderedPermitOverrides = 4,
+        DenyUnlessPermit = 5,
+        PermitUnlessDeny = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::DenyOverrides => "DENY_OVERRIDES",
+                Self::PermitOverrides => "PERMIT_OVERRIDES",
+                Self::OrderedDenyOverrides => "ORDERED_DENY_OVERRIDES",
+                Self::OrderedPermitOverrides => "ORDERED_PERMIT_OVERRIDES",
+                Self::DenyUnlessPermit => "DENY_UNLESS_PERMIT",
+                Self::PermitUnlessDeny => "PERMIT_UNLESS_DENY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DENY_OVERRIDES" => Some(Self::DenyOverrides),
+                "PERMIT_OVERRIDES" => Some(Self::PermitOverrides),
+                "ORDERED_DENY_OVERRIDES" => Some(Self::OrderedDenyOverrides),
+                "ORDERED_PERMIT_OVERRIDES" => Some(Self::OrderedPermitOverrides),
+                "DENY_UNLESS_PERMIT" => Some(Self::DenyUnlessPermit),
+                "PERMIT_UNLESS_DENY" => Some(Self::PermitUnlessDeny),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of a product.
+/// See <http://hl7.org/fhir/permission-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PermissionStatusCode {}
+/// Nested message and enum types in `PermissionStatusCode`.
+pub mod permission_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 1,
+        EnteredInError = 2,
+        Draft = 3,
+        Rejected = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Draft => "DRAFT",
+                Self::Rejected => "REJECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "DRAFT" => Some(Self::Draft),
+                "REJECTED" => Some(Self::Rejected),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes indicating the kind of the price component.
+/// See <http://hl7.org/fhir/price-component-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PriceComponentTypeCode {}
+/// Nested message and enum types in `PriceComponentTypeCode`.
+pub mod price_component_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Base = 1,
+        Surcharge = 2,
+        Deduction = 3,
+        Discount = 4,
+        Tax = 5,
+        Informational = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Base => "BASE",
+                Self::Surcharge => "SURCHARGE",
+                Self::Deduction => "DEDUCTION",
+                Self::Discount => "DISCOUNT",
+                Self::Tax => "TAX",
+                Self::Informational => "INFORMATIONAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BASE" => Some(Self::Base),
+                "SURCHARGE" => Some(Self::Surcharge),
+                "DEDUCTION" => Some(Self::Deduction),
+                "DISCOUNT" => Some(Self::Discount),
+                "TAX" => Some(Self::Tax),
+                "INFORMATIONAL" => Some(Self::Informational),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of a product.
+/// See <http://hl7.org/fhir/product-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProductStatusCode {}
+/// Nested message and enum types in `ProductStatusCode`.
+pub mod product_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Active = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Active => "ACTIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACTIVE" => Some(Self::Active),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a property is represented when serialized.
+/// See <http://hl7.org/fhir/property-representation>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PropertyRepresentationCode {}
+/// Nested message and enum types in `PropertyRepresentationCode`.
+pub mod property_representation_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        XmlAttr = 1,
+        XmlText = 2,
+        TypeAttr = 3,
+        CdaText = 4,
+        Xhtml = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::XmlAttr => "XML_ATTR",
+                Self::XmlText => "XML_TEXT",
+                Self::TypeAttr => "TYPE_ATTR",
+                Self::CdaText => "CDA_TEXT",
+                Self::Xhtml => "XHTML",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "XML_ATTR" => Some(Self::XmlAttr),
+                "XML_TEXT" => Some(Self::XmlText),
+                "TYPE_ATTR" => Some(Self::TypeAttr),
+                "CDA_TEXT" => Some(Self::CdaText),
+                "XHTML" => Some(Self::Xhtml),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of a property value.
+/// See <http://hl7.org/fhir/concept-property-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PropertyTypeCode {}
+/// Nested message and enum types in `PropertyTypeCode`.
+pub mod property_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Code = 1,
+        Coding = 2,
+        String = 3,
+        Integer = 4,
+        Boolean = 5,
+        DateTime = 6,
+        Decimal = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Code => "CODE",
+                Self::Coding => "CODING",
+                Self::String => "STRING",
+                Self::Integer => "INTEGER",
+                Self::Boolean => "BOOLEAN",
+                Self::DateTime => "DATE_TIME",
+                Self::Decimal => "DECIMAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CODE" => Some(Self::Code),
+                "CODING" => Some(Self::Coding),
+                "STRING" => Some(Self::String),
+                "INTEGER" => Some(Self::Integer),
+                "BOOLEAN" => Some(Self::Boolean),
+                "DATE_TIME" => Some(Self::DateTime),
+                "DECIMAL" => Some(Self::Decimal),
+                _ => None,
+            }
+        }
+    }
+}
+/// How an entity was used in an activity.
+/// See <http://hl7.org/fhir/provenance-entity-role>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProvenanceEntityRoleCode {}
+/// Nested message and enum types in `ProvenanceEntityRoleCode`.
+pub mod provenance_entity_role_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Revision = 2,
+        Quotation = 3,
+        Source = 4,
+        Instantiates = 6,
+        Removal = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Revision => "REVISION",
+                Self::Quotation => "QUOTATION",
+                Self::Source => "SOURCE",
+                Self::Instantiates => "INSTANTIATES",
+                Self::Removal => "REMOVAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REVISION" => Some(Self::Revision),
+                "QUOTATION" => Some(Self::Quotation),
+                "SOURCE" => Some(Self::Source),
+                "INSTANTIATES" => Some(Self::Instantiates),
+                "REMOVAL" => Some(Self::Removal),
+                _ => None,
+            }
+        }
+    }
+}
+/// The lifecycle status of an artifact.
+/// See <http://hl7.org/fhir/publication-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PublicationStatusCode {}
+/// Nested message and enum types in `PublicationStatusCode`.
+pub mod publication_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Active = 2,
+        Retired = 3,
+        Unknown = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Active => "ACTIVE",
+                Self::Retired => "RETIRED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "ACTIVE" => Some(Self::Active),
+                "RETIRED" => Some(Self::Retired),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// How the Quantity should be understood and represented.
+/// See <http://hl7.org/fhir/quantity-comparator>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QuantityComparatorCode {}
+/// Nested message and enum types in `QuantityComparatorCode`.
+pub mod quantity_comparator_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        LessThan = 1,
+        LessThanOrEqualTo = 2,
+        GreaterThanOrEqualTo = 3,
+        GreaterThan = 4,
+        Ad = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::LessThan => "LESS_THAN",
+                Self::LessThanOrEqualTo => "LESS_THAN_OR_EQUAL_TO",
+                Self::GreaterThanOrEqualTo => "GREATER_THAN_OR_EQUAL_TO",
+                Self::GreaterThan => "GREATER_THAN",
+                Self::Ad => "AD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "LESS_THAN" => Some(Self::LessThan),
+                "LESS_THAN_OR_EQUAL_TO" => Some(Self::LessThanOrEqualTo),
+                "GREATER_THAN_OR_EQUAL_TO" => Some(Self::GreaterThanOrEqualTo),
+                "GREATER_THAN" => Some(Self::GreaterThan),
+                "AD" => Some(Self::Ad),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes that describe the types of constraints possible on a question item that
+/// has a list of permitted answers See
+/// <http://hl7.org/fhir/questionnaire-answer-constraint>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QuestionnaireAnswerConstraintCode {}
+/// Nested message and enum types in `QuestionnaireAnswerConstraintCode`.
+pub mod questionnaire_answer_constraint_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        OptionsOnly = 1,
+        OptionsOrType = 2,
+        OptionsOrString = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::OptionsOnly => "OPTIONS_ONLY",
+                Self::OptionsOrType => "OPTIONS_OR_TYPE",
+                Self::OptionsOrString => "OPTIONS_OR_STRING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "OPTIONS_ONLY" => Some(Self::OptionsOnly),
+                "OPTIONS_OR_TYPE" => Some(Self::OptionsOrType),
+                "OPTIONS_OR_STRING" => Some(Self::OptionsOrString),
+                _ => None,
+            }
+        }
+    }
+}
+/// QuestionnaireItemDisabledDisplay
+/// See <http://hl7.org/fhir/questionnaire-disabled-display>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QuestionnaireItemDisabledDisplayCode {}
+/// Nested message and enum types in `QuestionnaireItemDisabledDisplayCode`.
+pub mod questionnaire_item_disabled_display_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Hidden = 1,
+        Protected = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Hidden => "HIDDEN",
+                Self::Protected => "PROTECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "HIDDEN" => Some(Self::Hidden),
+                "PROTECTED" => Some(Self::Protected),
+                _ => None,
+            }
+        }
+    }
+}
+/// The criteria by which a question is enabled.
+/// See <http://hl7.org/fhir/questionnaire-enable-operator>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QuestionnaireItemOperatorCode {}
+/// Nested message and enum types in `QuestionnaireItemOperatorCode`.
+pub mod questionnaire_item_operator_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Exists = 1,
+        Equals = 2,
+        NotEqualTo = 3,
+        GreaterThan = 4,
+        LessThan = 5,
+        GreaterThanOrEqualTo = 6,
+        LessThanOrEqualTo = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Exists => "EXISTS",
+                Self::Equals => "EQUALS",
+                Self::NotEqualTo => "NOT_EQUAL_TO",
+                Self::GreaterThan => "GREATER_THAN",
+                Self::LessThan => "LESS_THAN",
+                Self::GreaterThanOrEqualTo => "GREATER_THAN_OR_EQUAL_TO",
+                Self::LessThanOrEqualTo => "LESS_THAN_OR_EQUAL_TO",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EXISTS" => Some(Self::Exists),
+                "EQUALS" => Some(Self::Equals),
+                "NOT_EQUAL_TO" => Some(Self::NotEqualTo),
+                "GREATER_THAN" => Some(Self::GreaterThan),
+                "LESS_THAN" => Some(Self::LessThan),
+                "GREATER_THAN_OR_EQUAL_TO" => Some(Self::GreaterThanOrEqualTo),
+                "LESS_THAN_OR_EQUAL_TO" => Some(Self::LessThanOrEqualTo),
+                _ => None,
+            }
+        }
+    }
+}
+/// Distinguishes groups from questions and display text and indicates data type
+/// for questions. See <http://hl7.org/fhir/item-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QuestionnaireItemTypeCode {}
+/// Nested message and enum types in `QuestionnaireItemTypeCode`.
+pub mod questionnaire_item_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Group = 1,
+        Display = 2,
+        Question = 3,
+        Boolean = 4,
+        Decimal = 5,
+        Integer = 6,
+        Date = 7,
+        DateTime = 8,
+        Time = 9,
+        String = 10,
+        Text = 11,
+        Url = 12,
+        Coding = 18,
+        Attachment = 15,
+        Reference = 16,
+        Quantity = 17,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Group => "GROUP",
+                Self::Display => "DISPLAY",
+                Self::Question => "QUESTION",
+                Self::Boolean => "BOOLEAN",
+                Self::Decimal => "DECIMAL",
+                Self::Integer => "INTEGER",
+                Self::Date => "DATE",
+                Self::DateTime => "DATE_TIME",
+                Self::Time => "TIME",
+                Self::String => "STRING",
+                Self::Text => "TEXT",
+                Self::Url => "URL",
+                Self::Coding => "CODING",
+                Self::Attachment => "ATTACHMENT",
+                Self::Reference => "REFERENCE",
+                Self::Quantity => "QUANTITY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "GROUP" => Some(Self::Group),
+                "DISPLAY" => Some(Self::Display),
+                "QUESTION" => Some(Self::Question),
+                "BOOLEAN" => Some(Self::Boolean),
+                "DECIMAL" => Some(Self::Decimal),
+                "INTEGER" => Some(Self::Integer),
+                "DATE" => Some(Self::Date),
+                "DATE_TIME" => Some(Self::DateTime),
+                "TIME" => Some(Self::Time),
+                "STRING" => Some(Self::String),
+                "TEXT" => Some(Self::Text),
+                "URL" => Some(Self::Url),
+                "CODING" => Some(Self::Coding),
+                "ATTACHMENT" => Some(Self::Attachment),
+                "REFERENCE" => Some(Self::Reference),
+                "QUANTITY" => Some(Self::Quantity),
+                _ => None,
+            }
+        }
+    }
+}
+/// Lifecycle status of the questionnaire response.
+/// See <http://hl7.org/fhir/questionnaire-answers-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QuestionnaireResponseStatusCode {}
+/// Nested message and enum types in `QuestionnaireResponseStatusCode`.
+pub mod questionnaire_response_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        InProgress = 1,
+        Completed = 2,
+        Amended = 3,
+        EnteredInError = 4,
+        Stopped = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Completed => "COMPLETED",
+                Self::Amended => "AMENDED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Stopped => "STOPPED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "COMPLETED" => Some(Self::Completed),
+                "AMENDED" => Some(Self::Amended),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "STOPPED" => Some(Self::Stopped),
+                _ => None,
+            }
+        }
+    }
+}
+/// A set of flags that defines how references are supported.
+/// See <http://hl7.org/fhir/reference-handling-policy>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReferenceHandlingPolicyCode {}
+/// Nested message and enum types in `ReferenceHandlingPolicyCode`.
+pub mod reference_handling_policy_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Literal = 1,
+        Logical = 2,
+        Resolves = 3,
+        Enforced = 4,
+        Local = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Literal => "LITERAL",
+                Self::Logical => "LOGICAL",
+                Self::Resolves => "RESOLVES",
+                Self::Enforced => "ENFORCED",
+                Self::Local => "LOCAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "LITERAL" => Some(Self::Literal),
+                "LOGICAL" => Some(Self::Logical),
+                "RESOLVES" => Some(Self::Resolves),
+                "ENFORCED" => Some(Self::Enforced),
+                "LOCAL" => Some(Self::Local),
+                _ => None,
+            }
+        }
+    }
+}
+/// Whether a reference needs to be version specific or version independent, or
+/// whether either can be used. See <http://hl7.org/fhir/reference-version-rules>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReferenceVersionRulesCode {}
+/// Nested message and enum types in `ReferenceVersionRulesCode`.
+pub mod reference_version_rules_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Either = 1,
+        Independent = 2,
+        Specific = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Either => "EITHER",
+                Self::Independent => "INDEPENDENT",
+                Self::Specific => "SPECIFIC",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EITHER" => Some(Self::Either),
+                "INDEPENDENT" => Some(Self::Independent),
+                "SPECIFIC" => Some(Self::Specific),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of relationship to the related artifact.
+/// See <http://hl7.org/fhir/related-artifact-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RelatedArtifactTypeCode {}
+/// Nested message and enum types in `RelatedArtifactTypeCode`.
+pub mod related_artifact_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Documentation = 1,
+        Justification = 2,
+        Citation = 3,
+        Predecessor = 4,
+        Successor = 5,
+        DerivedFrom = 6,
+        DependsOn = 7,
+        ComposedOf = 8,
+        PartOf = 9,
+        Amends = 10,
+        AmendedWith = 11,
+        Appends = 12,
+        AppendedWith = 13,
+        Cites = 14,
+        CitedBy = 15,
+        CommentsOn = 16,
+        CommentIn = 17,
+        Contains = 18,
+        ContainedIn = 19,
+        Corrects = 20,
+        CorrectionIn = 21,
+        Replaces = 22,
+        ReplacedWith = 23,
+        Retracts = 24,
+        RetractedBy = 25,
+        Signs = 26,
+        SimilarTo = 27,
+        Supports = 28,
+        SupportedWith = 29,
+        Transforms = 30,
+        TransformedInto = 31,
+        TransformedWith = 32,
+        Documents = 33,
+        SpecificationOf = 34,
+        CreatedWith = 35,
+        CiteAs = 36,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Documentation => "DOCUMENTATION",
+                Self::Justification => "JUSTIFICATION",
+                Self::Citation => "CITATION",
+                Self::Predecessor => "PREDECESSOR",
+                Self::Successor => "SUCCESSOR",
+                Self::DerivedFrom => "DERIVED_FROM",
+                Self::DependsOn => "DEPENDS_ON",
+                Self::ComposedOf => "COMPOSED_OF",
+                Self::PartOf => "PART_OF",
+                Self::Amends => "AMENDS",
+                Self::AmendedWith => "AMENDED_WITH",
+                Self::Appends => "APPENDS",
+                Self::AppendedWith => "APPENDED_WITH",
+                Self::Cites => "CITES",
+                Self::CitedBy => "CITED_BY",
+                Self::CommentsOn => "COMMENTS_ON",
+                Self::CommentIn => "COMMENT_IN",
+                Self::Contains => "CONTAINS",
+                Self::ContainedIn => "CONTAINED_IN",
+                Self::Corrects => "CORRECTS",
+                Self::CorrectionIn => "CORRECTION_IN",
+                Self::Replaces => "REPLACES",
+                Self::ReplacedWith => "REPLACED_WITH",
+                Self::Retracts => "RETRACTS",
+                Self::RetractedBy => "RETRACTED_BY",
+                Self::Signs => "SIGNS",
+                Self::SimilarTo => "SIMILAR_TO",
+                Self::Supports => "SUPPORTS",
+                Self::SupportedWith => "SUPPORTED_WITH",
+                Self::Transforms => "TRANSFORMS",
+                Self::TransformedInto => "TRANSFORMED_INTO",
+                Self::TransformedWith => "TRANSFORMED_WITH",
+                Self::Documents => "DOCUMENTS",
+                Self::SpecificationOf => "SPECIFICATION_OF",
+                Self::CreatedWith => "CREATED_WITH",
+                Self::CiteAs => "CITE_AS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DOCUMENTATION" => Some(Self::Documentation),
+                "JUSTIFICATION" => Some(Self::Justification),
+                "CITATION" => Some(Self::Citation),
+                "PREDECESSOR" => Some(Self::Predecessor),
+                "SUCCESSOR" => Some(Self::Successor),
+                "DERIVED_FROM" => Some(Self::DerivedFrom),
+                "DEPENDS_ON" => Some(Self::DependsOn),
+                "COMPOSED_OF" => Some(Self::ComposedOf),
+                "PART_OF" => Some(Self::PartOf),
+                "AMENDS" => Some(Self::Amends),
+                "AMENDED_WITH" => Some(Self::AmendedWith),
+                "APPENDS" => Some(Self::Appends),
+                "APPENDED_WITH" => Some(Self::AppendedWith),
+                "CITES" => Some(Self::Cites),
+                "CITED_BY" => Some(Self::CitedBy),
+                "COMMENTS_ON" => Some(Self::CommentsOn),
+                "COMMENT_IN" => Some(Self::CommentIn),
+                "CONTAINS" => Some(Self::Contains),
+                "CONTAINED_IN" => Some(Self::ContainedIn),
+                "CORRECTS" => Some(Self::Corrects),
+                "CORRECTION_IN" => Some(Self::CorrectionIn),
+                "REPLACES" => Some(Self::Replaces),
+                "REPLACED_WITH" => Some(Self::ReplacedWith),
+                "RETRACTS" => Some(Self::Retracts),
+                "RETRACTED_BY" => Some(Self::RetractedBy),
+                "SIGNS" => Some(Self::Signs),
+                "SIMILAR_TO" => Some(Self::SimilarTo),
+                "SUPPORTS" => Some(Self::Supports),
+                "SUPPORTED_WITH" => Some(Self::SupportedWith),
+                "TRANSFORMS" => Some(Self::Transforms),
+                "TRANSFORMED_INTO" => Some(Self::TransformedInto),
+                "TRANSFORMED_WITH" => Some(Self::TransformedWith),
+                "DOCUMENTS" => Some(Self::Documents),
+                "SPECIFICATION_OF" => Some(Self::SpecificationOf),
+                "CREATED_WITH" => Some(Self::CreatedWith),
+                "CITE_AS" => Some(Self::CiteAs),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of relationship to the cited artifact.
+/// See <http://hl7.org/fhir/related-artifact-type-expanded>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RelatedArtifactTypeExpandedCode {}
+/// Nested message and enum types in `RelatedArtifactTypeExpandedCode`.
+pub mod related_artifact_type_expanded_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Reprint = 1,
+        ReprintOf = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Reprint => "REPRINT",
+                Self::ReprintOf => "REPRINT_OF",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REPRINT" => Some(Self::Reprint),
+                "REPRINT_OF" => Some(Self::ReprintOf),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of relationship between reports.
+/// See <http://hl7.org/fhir/report-relation-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReportRelationshipTypeCode {}
+/// Nested message and enum types in `ReportRelationshipTypeCode`.
+pub mod report_relationship_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Replaces = 1,
+        Amends = 2,
+        Appends = 3,
+        Transforms = 4,
+        ReplacedWith = 5,
+        AmendedWith = 6,
+        AppendedWith = 7,
+        TransformedWith = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Replaces => "REPLACES",
+                Self::Amends => "AMENDS",
+                Self::Appends => "APPENDS",
+                Self::Transforms => "TRANSFORMS",
+                Self::ReplacedWith => "REPLACED_WITH",
+                Self::AmendedWith => "AMENDED_WITH",
+                Self::AppendedWith => "APPENDED_WITH",
+                Self::TransformedWith => "TRANSFORMED_WITH",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REPLACES" => Some(Self::Replaces),
+                "AMENDS" => Some(Self::Amends),
+                "APPENDS" => Some(Self::Appends),
+                "TRANSFORMS" => Some(Self::Transforms),
+                "REPLACED_WITH" => Some(Self::ReplacedWith),
+                "AMENDED_WITH" => Some(Self::AmendedWith),
+                "APPENDED_WITH" => Some(Self::AppendedWith),
+                "TRANSFORMED_WITH" => Some(Self::TransformedWith),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes indicating the degree of authority/intentionality associated with a
+/// request. See <http://hl7.org/fhir/request-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequestIntentCode {}
+/// Nested message and enum types in `RequestIntentCode`.
+pub mod request_intent_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Proposal = 1,
+        Plan = 2,
+        Directive = 3,
+        Order = 4,
+        OriginalOrder = 5,
+        ReflexOrder = 6,
+        FillerOrder = 7,
+        InstanceOrder = 8,
+        Option = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Proposal => "PROPOSAL",
+                Self::Plan => "PLAN",
+                Self::Directive => "DIRECTIVE",
+                Self::Order => "ORDER",
+                Self::OriginalOrder => "ORIGINAL_ORDER",
+                Self::ReflexOrder => "REFLEX_ORDER",
+                Self::FillerOrder => "FILLER_ORDER",
+                Self::InstanceOrder => "INSTANCE_ORDER",
+                Self::Option => "OPTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PROPOSAL" => Some(Self::Proposal),
+                "PLAN" => Some(Self::Plan),
+                "DIRECTIVE" => Some(Self::Directive),
+                "ORDER" => Some(Self::Order),
+                "ORIGINAL_ORDER" => Some(Self::OriginalOrder),
+                "REFLEX_ORDER" => Some(Self::ReflexOrder),
+                "FILLER_ORDER" => Some(Self::FillerOrder),
+                "INSTANCE_ORDER" => Some(Self::InstanceOrder),
+                "OPTION" => Some(Self::Option),
+                _ => None,
+            }
+        }
+    }
+}
+/// Identifies the level of importance to be assigned to actioning the request.
+/// See <http://hl7.org/fhir/request-priority>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequestPriorityCode {}
+/// Nested message and enum types in `RequestPriorityCode`.
+pub mod request_priority_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Routine = 1,
+        Urgent = 2,
+        Asap = 3,
+        Stat = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Routine => "ROUTINE",
+                Self::Urgent => "URGENT",
+                Self::Asap => "ASAP",
+                Self::Stat => "STAT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ROUTINE" => Some(Self::Routine),
+                "URGENT" => Some(Self::Urgent),
+                "ASAP" => Some(Self::Asap),
+                "STAT" => Some(Self::Stat),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of a request.
+/// See <http://hl7.org/fhir/request-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequestStatusCode {}
+/// Nested message and enum types in `RequestStatusCode`.
+pub mod request_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Active = 2,
+        OnHold = 3,
+        Revoked = 4,
+        Completed = 5,
+        EnteredInError = 6,
+        Unknown = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Active => "ACTIVE",
+                Self::OnHold => "ON_HOLD",
+                Self::Revoked => "REVOKED",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "ACTIVE" => Some(Self::Active),
+                "ON_HOLD" => Some(Self::OnHold),
+                "REVOKED" => Some(Self::Revoked),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// How the system supports versioning for a resource.
+/// See <http://hl7.org/fhir/versioning-policy>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ResourceVersionPolicyCode {}
+/// Nested message and enum types in `ResourceVersionPolicyCode`.
+pub mod resource_version_policy_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NoVersion = 1,
+        Versioned = 2,
+        VersionedUpdate = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NoVersion => "NO_VERSION",
+                Self::Versioned => "VERSIONED",
+                Self::VersionedUpdate => "VERSIONED_UPDATE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NO_VERSION" => Some(Self::NoVersion),
+                "VERSIONED" => Some(Self::Versioned),
+                "VERSIONED_UPDATE" => Some(Self::VersionedUpdate),
+                _ => None,
+            }
+        }
+    }
+}
+/// The kind of response to a message.
+/// See <http://hl7.org/fhir/response-code>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ResponseTypeCode {}
+/// Nested message and enum types in `ResponseTypeCode`.
+pub mod response_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Ok = 1,
+        TransientError = 2,
+        FatalError = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Ok => "OK",
+                Self::TransientError => "TRANSIENT_ERROR",
+                Self::FatalError => "FATAL_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "OK" => Some(Self::Ok),
+                "TRANSIENT_ERROR" => Some(Self::TransientError),
+                "FATAL_ERROR" => Some(Self::FatalError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The mode of a RESTful capability statement.
+/// See <http://hl7.org/fhir/restful-capability-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RestfulCapabilityModeCode {}
+/// Nested message and enum types in `RestfulCapabilityModeCode`.
+pub mod restful_capability_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Client = 1,
+        Server = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Client => "CLIENT",
+                Self::Server => "SERVER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CLIENT" => Some(Self::Client),
+                "SERVER" => Some(Self::Server),
+                _ => None,
+            }
+        }
+    }
+}
+/// The license that applies to an Implementation Guide (using an SPDX license
+/// Identifiers, or 'not-open-source'). The binding is required but new SPDX
+/// license Identifiers are allowed to be used (<https://spdx.org/licenses/>). See
+/// <http://hl7.org/fhir/spdx-license>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SpdxLicenseCode {}
+/// Nested message and enum types in `SPDXLicenseCode`.
+pub mod spdx_license_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NotOpenSource = 1,
+        BsdZeroClauseLicense = 2,
+        Aal = 3,
+        Abstyles = 4,
+        Adobe2006 = 5,
+        AdobeGlyph = 6,
+        Adsl = 7,
+        Afl11 = 8,
+        Afl12 = 9,
+        Afl20 = 10,
+        Afl21 = 11,
+        Afl30 = 12,
+        Afmparse = 13,
+        Agpl10Only = 14,
+        Agpl10OrLater = 15,
+        Agpl30Only = 16,
+        Agpl30OrLater = 17,
+        Aladdin = 18,
+        Amdplpa = 19,
+        Aml = 20,
+        Ampas = 21,
+        AntlrPd = 22,
+        Apache10 = 23,
+        Apache11 = 24,
+        Apache20 = 25,
+        Apafml = 26,
+        Apl10 = 27,
+        Apsl10 = 28,
+        Apsl11 = 29,
+        Apsl12 = 30,
+        Apsl20 = 31,
+        Artistic10Cl8 = 32,
+        Artistic10Perl = 33,
+        Artistic10 = 34,
+        Artistic20 = 35,
+        Bahyph = 36,
+        Barr = 37,
+        Beerware = 38,
+        BitTorrent10 = 39,
+        BitTorrent11 = 40,
+        Borceux = 41,
+        Bsd1Clause = 42,
+        Bsd2ClauseFreeBsd = 43,
+        Bsd2ClauseNetBsd = 44,
+        Bsd2ClausePatent = 45,
+        Bsd2Clause = 46,
+        Bsd3ClauseAttribution = 47,
+        Bsd3ClauseClear = 48,
+        Bsd3ClauseLbnl = 49,
+        Bsd3ClauseNoNuclearLicense2014 = 50,
+        Bsd3ClauseNoNuclearLicense = 51,
+        Bsd3ClauseNoNuclearWarranty = 52,
+        Bsd3Clause = 53,
+        Bsd4ClauseUc = 54,
+        Bsd4Clause = 55,
+        BsdProtection = 56,
+        BsdSourceCode = 57,
+        Bsl10 = 58,
+        Bzip2105 = 59,
+        Bzip2106 = 60,
+        Caldera = 61,
+        Catosl11 = 62,
+        CcBy10 = 63,
+        CcBy20 = 64,
+        CcBy25 = 65,
+        CcBy30 = 66,
+        CcBy40 = 67,
+        CcByNc10 = 68,
+        CcByNc20 = 69,
+        CcByNc25 = 70,
+        CcByNc30 = 71,
+        CcByNc40 = 72,
+        CcByNcNd10 = 73,
+        CcByNcNd20 = 74,
+        CcByNcNd25 = 75,
+        CcByNcNd30 = 76,
+        CcByNcNd40 = 77,
+        CcByNcSa10 = 78,
+        CcByNcSa20 = 79,
+        CcByNcSa25 = 80,
+        CcByNcSa30 = 81,
+        CcByNcSa40 = 82,
+        CcByNd10 = 83,
+        CcByNd20 = 84,
+        CcByNd25 = 85,
+        CcByNd30 = 86,
+        CcByNd40 = 87,
+        CcBySa10 = 88,
+        CcBySa20 = 89,
+        CcBySa25 = 90,
+        CcBySa30 = 91,
+        CcBySa40 = 92,
+        Cc010 = 93,
+        Cddl10 = 94,
+        Cddl11 = 95,
+        CdlaPermissive10 = 96,
+        CdlaSharing10 = 97,
+        Cecill10 = 98,
+        Cecill11 = 99,
+        Cecill20 = 100,
+        Cecill21 = 101,
+        CecillB = 102,
+        CecillC = 103,
+        ClArtistic = 104,
+        CnriJython = 105,
+        CnriPythonGplCompatible = 106,
+        CnriPython = 107,
+        Condor11 = 108,
+        Cpal10 = 109,
+        Cpl10 = 110,
+        Cpol102 = 111,
+        Crossword = 112,
+        CrystalStacker = 113,
+        CuaOpl10 = 114,
+        Cube = 115,
+        Curl = 116,
+        DFsl10 = 117,
+        Diffmark = 118,
+        Doc = 119,
+        Dotseqn = 120,
+        Dsdp = 121,
+        Dvipdfm = 122,
+        Ecl10 = 123,
+        Ecl20 = 124,
+        Efl10 = 125,
+        Efl20 = 126,
+        EGenix = 127,
+        Entessa = 128,
+        Epl10 = 129,
+        Epl20 = 130,
+        ErlPl11 = 131,
+        EuDatagrid = 132,
+        Eupl10 = 133,
+        Eupl11 = 134,
+        Eupl12 = 135,
+        Eurosym = 136,
+        Fair = 137,
+        Frameworx10 = 138,
+        FreeImage = 139,
+        Fsfap = 140,
+        Fsful = 141,
+        Fsfullr = 142,
+        Ftl = 143,
+        Gfdl11Only = 144,
+        Gfdl11OrLater = 145,
+        Gfdl12Only = 146,
+        Gfdl12OrLater = 147,
+        Gfdl13Only = 148,
+        Gfdl13OrLater = 149,
+        Giftware = 150,
+        Gl2ps = 151,
+        Glide = 152,
+        Glulxe = 153,
+        Gnuplot = 154,
+        Gpl10Only = 155,
+        Gpl10OrLater = 156,
+        Gpl20Only = 157,
+        Gpl20OrLater = 158,
+        Gpl30Only = 159,
+        Gpl30OrLater = 160,
+        GSoap13b = 161,
+        HaskellReport = 162,
+        Hpnd = 163,
+        IbmPibs = 164,
+        Icu = 165,
+        Ijg = 166,
+        ImageMagick = 167,
+        IMatix = 168,
+        Imlib2 = 169,
+        InfoZip = 170,
+        IntelAcpi = 171,
+        Intel = 172,
+        Interbase10 = 173,
+        Ipa = 174,
+        Ipl10 = 175,
+        Isc = 176,
+        JasPer20 = 177,
+        Json = 178,
+        Lal12 = 179,
+        Lal13 = 180,
+        Latex2e = 181,
+        Leptonica = 182,
+        Lgpl20Only = 183,
+        Lgpl20OrLater = 184,
+        Lgpl21Only = 185,
+        Lgpl21OrLater = 186,
+        Lgpl30Only = 187,
+        Lgpl30OrLater = 188,
+        Lgpllr = 189,
+        Libpng = 190,
+        Libtiff = 191,
+        LiLiQP11 = 192,
+        LiLiQR11 = 193,
+        LiLiQRplus11 = 194,
+        LinuxOpenIb = 195,
+        Lpl10 = 196,
+        Lpl102 = 197,
+        Lppl10 = 198,
+        Lppl11 = 199,
+        Lppl12 = 200,
+        Lppl13a = 201,
+        Lppl13c = 202,
+        MakeIndex = 203,
+        MirOs = 204,
+        Mit0 = 205,
+        MitAdvertising = 206,
+        MitCmu = 207,
+        MitEnna = 208,
+        MitFeh = 209,
+        Mit = 210,
+        Mitnfa = 211,
+        Motosoto = 212,
+        Mpich2 = 213,
+        Mpl10 = 214,
+        Mpl11 = 215,
+        Mpl20NoCopyleftException = 216,
+        Mpl20 = 217,
+        MsPl = 218,
+        MsRl = 219,
+        Mtll = 220,
+        Multics = 221,
+        Mup = 222,
+        Nasa13 = 223,
+        Naumen = 224,
+        Nbpl10 = 225,
+        Ncsa = 226,
+        NetSnmp = 227,
+        NetCdf = 228,
+        Newsletr = 229,
+        Ngpl = 230,
+        Nlod10 = 231,
+        Nlpl = 232,
+        Nokia = 233,
+        Nosl = 234,
+        Noweb = 235,
+        Npl10 = 236,
+        Npl11 = 237,
+        Nposl30 = 238,
+        Nrl = 239,
+        Ntp = 240,
+        OcctPl = 241,
+        Oclc20 = 242,
+        ODbL10 = 243,
+        Ofl10 = 244,
+        Ofl11 = 245,
+        Ogtsl = 246,
+        Oldap11 = 247,
+        Oldap12 = 248,
+        Oldap13 = 249,
+        Oldap14 = 250,
+        Oldap201 = 251,
+        Oldap20 = 252,
+        Oldap21 = 253,
+        Oldap221 = 254,
+        Oldap222 = 255,
+        Oldap22 = 256,
+        Oldap23 = 257,
+        Oldap24 = 258,
+        Oldap25 = 259,
+        Oldap26 = 260,
+        Oldap27 = 261,
+        Oldap28 = 262,
+        Oml = 263,
+        OpenSsl = 264,
+        Opl10 = 265,
+        OsetPl21 = 266,
+        Osl10 = 267,
+        Osl11 = 268,
+        Osl20 = 269,
+        Osl21 = 270,
+        Osl30 = 271,
+        Pddl10 = 272,
+        Php30 = 273,
+        Php301 = 274,
+        Plexus = 275,
+        PostgreSql = 276,
+        Psfrag = 277,
+        Psutils = 278,
+        Python20 = 279,
+        Qhull = 280,
+        Qpl10 = 281,
+        Rdisc = 282,
+        RHeCos11 = 283,
+        Rpl11 = 284,
+        Rpl15 = 285,
+        Rpsl10 = 286,
+        RsaMd = 287,
+        Rscpl = 288,
+        Ruby = 289,
+        SaxPd = 290,
+        Saxpath = 291,
+        Scea = 292,
+        Sendmail = 293,
+        SgiB10 = 294,
+        SgiB11 = 295,
+        SgiB20 = 296,
+        SimPl20 = 297,
+        Sissl12 = 298,
+        Sissl = 299,
+        Sleepycat = 300,
+        Smlnj = 301,
+        Smppl = 302,
+        Snia = 303,
+        Spencer86 = 304,
+        Spencer94 = 305,
+        Spencer99 = 306,
+        Spl10 = 307,
+        SugarCrm113 = 308,
+        Swl = 309,
+        Tcl = 310,
+        TcpWrappers = 311,
+        TMate = 312,
+        Torque11 = 313,
+        Tosl = 314,
+        UnicodeDfs2015 = 315,
+        UnicodeDfs2016 = 316,
+        UnicodeTou = 317,
+        Unlicense = 318,
+        Upl10 = 319,
+        Vim = 320,
+        Vostrom = 321,
+        Vsl10 = 322,
+        W3c19980720 = 323,
+        W3c20150513 = 324,
+        W3c = 325,
+        Watcom10 = 326,
+        Wsuipa = 327,
+        Wtfpl = 328,
+        X11 = 329,
+        Xerox = 330,
+        XFree8611 = 331,
+        Xinetd = 332,
+        Xnet = 333,
+        Xpp = 334,
+        XSkat = 335,
+        Ypl10 = 336,
+        Ypl11 = 337,
+        Zed = 338,
+        Zend20 = 339,
+        Zimbra13 = 340,
+        Zimbra14 = 341,
+        ZlibAcknowledgement = 342,
+        Zlib = 343,
+        Zpl11 = 344,
+        Zpl20 = 345,
+        Zpl21 = 346,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NotOpenSource => "NOT_OPEN_SOURCE",
+                Self::BsdZeroClauseLicense => "BSD_ZERO_CLAUSE_LICENSE",
+                Self::Aal => "AAL",
+                Self::Abstyles => "ABSTYLES",
+                Self::Adobe2006 => "ADOBE_2006",
+                Self::AdobeGlyph => "ADOBE_GLYPH",
+                Self::Adsl => "ADSL",
+                Self::Afl11 => "AFL_1_1",
+                Self::Afl12 => "AFL_1_2",
+                Self::Afl20 => "AFL_2_0",
+                Self::Afl21 => "AFL_2_1",
+                Self::Afl30 => "AFL_3_0",
+                Self::Afmparse => "AFMPARSE",
+                Self::Agpl10Only => "AGPL_1_0_ONLY",
+                Self::Agpl10OrLater => "AGPL_1_0_OR_LATER",
+                Self::Agpl30Only => "AGPL_3_0_ONLY",
+                Self::Agpl30OrLater => "AGPL_3_0_OR_LATER",
+                Self::Aladdin => "ALADDIN",
+                Self::Amdplpa => "AMDPLPA",
+                Self::Aml => "AML",
+                Self::Ampas => "AMPAS",
+                Self::AntlrPd => "ANTLR_PD",
+                Self::Apache10 => "APACHE_1_0",
+                Self::Apache11 => "APACHE_1_1",
+                Self::Apache20 => "APACHE_2_0",
+                Self::Apafml => "APAFML",
+                Self::Apl10 => "APL_1_0",
+                Self::Apsl10 => "APSL_1_0",
+                Self::Apsl11 => "APSL_1_1",
+                Self::Apsl12 => "APSL_1_2",
+                Self::Apsl20 => "APSL_2_0",
+                Self::Artistic10Cl8 => "ARTISTIC_1_0_CL8",
+                Self::Artistic10Perl => "ARTISTIC_1_0_PERL",
+                Self::Artistic10 => "ARTISTIC_1_0",
+                Self::Artistic20 => "ARTISTIC_2_0",
+                Self::Bahyph => "BAHYPH",
+                Self::Barr => "BARR",
+                Self::Beerware => "BEERWARE",
+                Self::BitTorrent10 => "BIT_TORRENT_1_0",
+                Self::BitTorrent11 => "BIT_TORRENT_1_1",
+                Self::Borceux => "BORCEUX",
+                Self::Bsd1Clause => "BSD_1_CLAUSE",
+                Self::Bsd2ClauseFreeBsd => "BSD_2_CLAUSE_FREE_BSD",
+                Self::Bsd2ClauseNetBsd => "BSD_2_CLAUSE_NET_BSD",
+                Self::Bsd2ClausePatent => "BSD_2_CLAUSE_PATENT",
+                Self::Bsd2Clause => "BSD_2_CLAUSE",
+                Self::Bsd3ClauseAttribution => "BSD_3_CLAUSE_ATTRIBUTION",
+                Self::Bsd3ClauseClear => "BSD_3_CLAUSE_CLEAR",
+                Self::Bsd3ClauseLbnl => "BSD_3_CLAUSE_LBNL",
+                Self::Bsd3ClauseNoNuclearLicense2014 => {
+                    "BSD_3_CLAUSE_NO_NUCLEAR_LICENSE_2014"
+                }
+                Self::Bsd3ClauseNoNuclearLicense => "BSD_3_CLAUSE_NO_NUCLEAR_LICENSE",
+                Self::Bsd3ClauseNoNuclearWarranty => "BSD_3_CLAUSE_NO_NUCLEAR_WARRANTY",
+                Self::Bsd3Clause => "BSD_3_CLAUSE",
+                Self::Bsd4ClauseUc => "BSD_4_CLAUSE_UC",
+                Self::Bsd4Clause => "BSD_4_CLAUSE",
+                Self::BsdProtection => "BSD_PROTECTION",
+                Self::BsdSourceCode => "BSD_SOURCE_CODE",
+                Self::Bsl10 => "BSL_1_0",
+                Self::Bzip2105 => "BZIP2_1_0_5",
+                Self::Bzip2106 => "BZIP2_1_0_6",
+                Self::Caldera => "CALDERA",
+                Self::Catosl11 => "CATOSL_1_1",
+                Self::CcBy10 => "CC_BY_1_0",
+                Self::CcBy20 => "CC_BY_2_0",
+                Self::CcBy25 => "CC_BY_2_5",
+                Self::CcBy30 => "CC_BY_3_0",
+                Self::CcBy40 => "CC_BY_4_0",
+                Self::CcByNc10 => "CC_BY_NC_1_0",
+                Self::CcByNc20 => "CC_BY_NC_2_0",
+                Self::CcByNc25 => "CC_BY_NC_2_5",
+                Self::CcByNc30 => "CC_BY_NC_3_0",
+                Self::CcByNc40 => "CC_BY_NC_4_0",
+                Self::CcByNcNd10 => "CC_BY_NC_ND_1_0",
+                Self::CcByNcNd20 => "CC_BY_NC_ND_2_0",
+                Self::CcByNcNd25 => "CC_BY_NC_ND_2_5",
+                Self::CcByNcNd30 => "CC_BY_NC_ND_3_0",
+                Self::CcByNcNd40 => "CC_BY_NC_ND_4_0",
+                Self::CcByNcSa10 => "CC_BY_NC_SA_1_0",
+                Self::CcByNcSa20 => "CC_BY_NC_SA_2_0",
+                Self::CcByNcSa25 => "CC_BY_NC_SA_2_5",
+                Self::CcByNcSa30 => "CC_BY_NC_SA_3_0",
+                Self::CcByNcSa40 => "CC_BY_NC_SA_4_0",
+                Self::CcByNd10 => "CC_BY_ND_1_0",
+                Self::CcByNd20 => "CC_BY_ND_2_0",
+                Self::CcByNd25 => "CC_BY_ND_2_5",
+                Self::CcByNd30 => "CC_BY_ND_3_0",
+                Self::CcByNd40 => "CC_BY_ND_4_0",
+                Self::CcBySa10 => "CC_BY_SA_1_0",
+                Self::CcBySa20 => "CC_BY_SA_2_0",
+                Self::CcBySa25 => "CC_BY_SA_2_5",
+                Self::CcBySa30 => "CC_BY_SA_3_0",
+                Self::CcBySa40 => "CC_BY_SA_4_0",
+                Self::Cc010 => "CC0_1_0",
+                Self::Cddl10 => "CDDL_1_0",
+                Self::Cddl11 => "CDDL_1_1",
+                Self::CdlaPermissive10 => "CDLA_PERMISSIVE_1_0",
+                Self::CdlaSharing10 => "CDLA_SHARING_1_0",
+                Self::Cecill10 => "CECILL_1_0",
+                Self::Cecill11 => "CECILL_1_1",
+                Self::Cecill20 => "CECILL_2_0",
+                Self::Cecill21 => "CECILL_2_1",
+                Self::CecillB => "CECILL_B",
+                Self::CecillC => "CECILL_C",
+                Self::ClArtistic => "CL_ARTISTIC",
+                Self::CnriJython => "CNRI_JYTHON",
+                Self::CnriPythonGplCompatible => "CNRI_PYTHON_GPL_COMPATIBLE",
+                Self::CnriPython => "CNRI_PYTHON",
+                Self::Condor11 => "CONDOR_1_1",
+                Self::Cpal10 => "CPAL_1_0",
+                Self::Cpl10 => "CPL_1_0",
+                Self::Cpol102 => "CPOL_1_02",
+                Self::Crossword => "CROSSWORD",
+                Self::CrystalStacker => "CRYSTAL_STACKER",
+                Self::CuaOpl10 => "CUA_OPL_1_0",
+                Self::Cube => "CUBE",
+                Self::Curl => "CURL",
+                Self::DFsl10 => "D_FSL_1_0",
+                Self::Diffmark => "DIFFMARK",
+                Self::Doc => "DOC",
+                Self::Dotseqn => "DOTSEQN",
+                Self::Dsdp => "DSDP",
+                Self::Dvipdfm => "DVIPDFM",
+                Self::Ecl10 => "ECL_1_0",
+                Self::Ecl20 => "ECL_2_0",
+                Self::Efl10 => "EFL_1_0",
+                Self::Efl20 => "EFL_2_0",
+                Self::EGenix => "E_GENIX",
+                Self::Entessa => "ENTESSA",
+                Self::Epl10 => "EPL_1_0",
+                Self::Epl20 => "EPL_2_0",
+                Self::ErlPl11 => "ERL_PL_1_1",
+                Self::EuDatagrid => "EU_DATAGRID",
+                Self::Eupl10 => "EUPL_1_0",
+                Self::Eupl11 => "EUPL_1_1",
+                Self::Eupl12 => "EUPL_1_2",
+                Self::Eurosym => "EUROSYM",
+                Self::Fair => "FAIR",
+                Self::Frameworx10 => "FRAMEWORX_1_0",
+                Self::FreeImage => "FREE_IMAGE",
+                Self::Fsfap => "FSFAP",
+                Self::Fsful => "FSFUL",
+                Self::Fsfullr => "FSFULLR",
+                Self::Ftl => "FTL",
+                Self::Gfdl11Only => "GFDL_1_1_ONLY",
+                Self::Gfdl11OrLater => "GFDL_1_1_OR_LATER",
+                Self::Gfdl12Only => "GFDL_1_2_ONLY",
+                Self::Gfdl12OrLater => "GFDL_1_2_OR_LATER",
+                Self::Gfdl13Only => "GFDL_1_3_ONLY",
+                Self::Gfdl13OrLater => "GFDL_1_3_OR_LATER",
+                Self::Giftware => "GIFTWARE",
+                Self::Gl2ps => "GL2PS",
+                Self::Glide => "GLIDE",
+                Self::Glulxe => "GLULXE",
+                Self::Gnuplot => "GNUPLOT",
+                Self::Gpl10Only => "GPL_1_0_ONLY",
+                Self::Gpl10OrLater => "GPL_1_0_OR_LATER",
+                Self::Gpl20Only => "GPL_2_0_ONLY",
+                Self::Gpl20OrLater => "GPL_2_0_OR_LATER",
+                Self::Gpl30Only => "GPL_3_0_ONLY",
+                Self::Gpl30OrLater => "GPL_3_0_OR_LATER",
+                Self::GSoap13b => "G_SOAP_1_3B",
+                Self::HaskellReport => "HASKELL_REPORT",
+                Self::Hpnd => "HPND",
+                Self::IbmPibs => "IBM_PIBS",
+                Self::Icu => "ICU",
+                Self::Ijg => "IJG",
+                Self::ImageMagick => "IMAGE_MAGICK",
+                Self::IMatix => "I_MATIX",
+                Self::Imlib2 => "IMLIB2",
+                Self::InfoZip => "INFO_ZIP",
+                Self::IntelAcpi => "INTEL_ACPI",
+                Self::Intel => "INTEL",
+                Self::Interbase10 => "INTERBASE_1_0",
+                Self::Ipa => "IPA",
+                Self::Ipl10 => "IPL_1_0",
+                Self::Isc => "ISC",
+                Self::JasPer20 => "JAS_PER_2_0",
+                Self::Json => "JSON",
+                Self::Lal12 => "LAL_1_2",
+                Self::Lal13 => "LAL_1_3",
+                Self::Latex2e => "LATEX2E",
+                Self::Leptonica => "LEPTONICA",
+                Self::Lgpl20Only => "LGPL_2_0_ONLY",
+                Self::Lgpl20OrLater => "LGPL_2_0_OR_LATER",
+                Self::Lgpl21Only => "LGPL_2_1_ONLY",
+                Self::Lgpl21OrLater => "LGPL_2_1_OR_LATER",
+                Self::Lgpl30Only => "LGPL_3_0_ONLY",
+                Self::Lgpl30OrLater => "LGPL_3_0_OR_LATER",
+                Self::Lgpllr => "LGPLLR",
+                Self::Libpng => "LIBPNG",
+                Self::Libtiff => "LIBTIFF",
+                Self::LiLiQP11 => "LI_LI_Q_P_1_1",
+                Self::LiLiQR11 => "LI_LI_Q_R_1_1",
+                Self::LiLiQRplus11 => "LI_LI_Q_RPLUS_1_1",
+                Self::LinuxOpenIb => "LINUX_OPEN_IB",
+                Self::Lpl10 => "LPL_1_0",
+                Self::Lpl102 => "LPL_1_02",
+                Self::Lppl10 => "LPPL_1_0",
+                Self::Lppl11 => "LPPL_1_1",
+                Self::Lppl12 => "LPPL_1_2",
+                Self::Lppl13a => "LPPL_1_3A",
+                Self::Lppl13c => "LPPL_1_3C",
+                Self::MakeIndex => "MAKE_INDEX",
+                Self::MirOs => "MIR_OS",
+                Self::Mit0 => "MIT_0",
+                Self::MitAdvertising => "MIT_ADVERTISING",
+                Self::MitCmu => "MIT_CMU",
+                Self::MitEnna => "MIT_ENNA",
+                Self::MitFeh => "MIT_FEH",
+                Self::Mit => "MIT",
+                Self::Mitnfa => "MITNFA",
+                Self::Motosoto => "MOTOSOTO",
+                Self::Mpich2 => "MPICH2",
+                Self::Mpl10 => "MPL_1_0",
+                Self::Mpl11 => "MPL_1_1",
+                Self::Mpl20NoCopyleftException => "MPL_2_0_NO_COPYLEFT_EXCEPTION",
+                Self::Mpl20 => "MPL_2_0",
+                Self::MsPl => "MS_PL",
+                Self::MsRl => "MS_RL",
+                Self::Mtll => "MTLL",
+                Self::Multics => "MULTICS",
+                Self::Mup => "MUP",
+                Self::Nasa13 => "NASA_1_3",
+                Self::Naumen => "NAUMEN",
+                Self::Nbpl10 => "NBPL_1_0",
+                Self::Ncsa => "NCSA",
+                Self::NetSnmp => "NET_SNMP",
+                Self::NetCdf => "NET_CDF",
+                Self::Newsletr => "NEWSLETR",
+                Self::Ngpl => "NGPL",
+                Self::Nlod10 => "NLOD_1_0",
+                Self::Nlpl => "NLPL",
+                Self::Nokia => "NOKIA",
+                Self::Nosl => "NOSL",
+                Self::Noweb => "NOWEB",
+                Self::Npl10 => "NPL_1_0",
+                Self::Npl11 => "NPL_1_1",
+                Self::Nposl30 => "NPOSL_3_0",
+                Self::Nrl => "NRL",
+                Self::Ntp => "NTP",
+                Self::OcctPl => "OCCT_PL",
+                Self::Oclc20 => "OCLC_2_0",
+                Self::ODbL10 => "O_DB_L_1_0",
+                Self::Ofl10 => "OFL_1_0",
+                Self::Ofl11 => "OFL_1_1",
+                Self::Ogtsl => "OGTSL",
+                Self::Oldap11 => "OLDAP_1_1",
+                Self::Oldap12 => "OLDAP_1_2",
+                Self::Oldap13 => "OLDAP_1_3",
+                Self::Oldap14 => "OLDAP_1_4",
+                Self::Oldap201 => "OLDAP_2_0_1",
+                Self::Oldap20 => "OLDAP_2_0",
+                Self::Oldap21 => "OLDAP_2_1",
+                Self::Oldap221 => "OLDAP_2_2_1",
+                Self::Oldap222 => "OLDAP_2_2_2",
+                Self::Oldap22 => "OLDAP_2_2",
+                Self::Oldap23 => "OLDAP_2_3",
+                Self::Oldap24 => "OLDAP_2_4",
+                Self::Oldap25 => "OLDAP_2_5",
+                Self::Oldap26 => "OLDAP_2_6",
+                Self::Oldap27 => "OLDAP_2_7",
+                Self::Oldap28 => "OLDAP_2_8",
+                Self::Oml => "OML",
+                Self::OpenSsl => "OPEN_SSL",
+                Self::Opl10 => "OPL_1_0",
+                Self::OsetPl21 => "OSET_PL_2_1",
+                Self::Osl10 => "OSL_1_0",
+                Self::Osl11 => "OSL_1_1",
+                Self::Osl20 => "OSL_2_0",
+                Self::Osl21 => "OSL_2_1",
+                Self::Osl30 => "OSL_3_0",
+                Self::Pddl10 => "PDDL_1_0",
+                Self::Php30 => "PHP_3_0",
+                Self::Php301 => "PHP_3_01",
+                Self::Plexus => "PLEXUS",
+                Self::PostgreSql => "POSTGRE_SQL",
+                Self::Psfrag => "PSFRAG",
+                Self::Psutils => "PSUTILS",
+                Self::Python20 => "PYTHON_2_0",
+                Self::Qhull => "QHULL",
+                Self::Qpl10 => "QPL_1_0",
+                Self::Rdisc => "RDISC",
+                Self::RHeCos11 => "R_HE_COS_1_1",
+                Self::Rpl11 => "RPL_1_1",
+                Self::Rpl15 => "RPL_1_5",
+                Self::Rpsl10 => "RPSL_1_0",
+                Self::RsaMd => "RSA_MD",
+                Self::Rscpl => "RSCPL",
+                Self::Ruby => "RUBY",
+                Self::SaxPd => "SAX_PD",
+                Self::Saxpath => "SAXPATH",
+                Self::Scea => "SCEA",
+                Self::Sendmail => "SENDMAIL",
+                Self::SgiB10 => "SGI_B_1_0",
+                Self::SgiB11 => "SGI_B_1_1",
+                Self::SgiB20 => "SGI_B_2_0",
+                Self::SimPl20 => "SIM_PL_2_0",
+                Self::Sissl12 => "SISSL_1_2",
+                Self::Sissl => "SISSL",
+                Self::Sleepycat => "SLEEPYCAT",
+                Self::Smlnj => "SMLNJ",
+                Self::Smppl => "SMPPL",
+                Self::Snia => "SNIA",
+                Self::Spencer86 => "SPENCER_86",
+                Self::Spencer94 => "SPENCER_94",
+                Self::Spencer99 => "SPENCER_99",
+                Self::Spl10 => "SPL_1_0",
+                Self::SugarCrm113 => "SUGAR_CRM_1_1_3",
+                Self::Swl => "SWL",
+                Self::Tcl => "TCL",
+                Self::TcpWrappers => "TCP_WRAPPERS",
+                Self::TMate => "T_MATE",
+                Self::Torque11 => "TORQUE_1_1",
+                Self::Tosl => "TOSL",
+                Self::UnicodeDfs2015 => "UNICODE_DFS_2015",
+                Self::UnicodeDfs2016 => "UNICODE_DFS_2016",
+                Self::UnicodeTou => "UNICODE_TOU",
+                Self::Unlicense => "UNLICENSE",
+                Self::Upl10 => "UPL_1_0",
+                Self::Vim => "VIM",
+                Self::Vostrom => "VOSTROM",
+                Self::Vsl10 => "VSL_1_0",
+                Self::W3c19980720 => "W3C_19980720",
+                Self::W3c20150513 => "W3C_20150513",
+                Self::W3c => "W3C",
+                Self::Watcom10 => "WATCOM_1_0",
+                Self::Wsuipa => "WSUIPA",
+                Self::Wtfpl => "WTFPL",
+                Self::X11 => "X11",
+                Self::Xerox => "XEROX",
+                Self::XFree8611 => "X_FREE86_1_1",
+                Self::Xinetd => "XINETD",
+                Self::Xnet => "XNET",
+                Self::Xpp => "XPP",
+                Self::XSkat => "X_SKAT",
+                Self::Ypl10 => "YPL_1_0",
+                Self::Ypl11 => "YPL_1_1",
+                Self::Zed => "ZED",
+                Self::Zend20 => "ZEND_2_0",
+                Self::Zimbra13 => "ZIMBRA_1_3",
+                Self::Zimbra14 => "ZIMBRA_1_4",
+                Self::ZlibAcknowledgement => "ZLIB_ACKNOWLEDGEMENT",
+                Self::Zlib => "ZLIB",
+                Self::Zpl11 => "ZPL_1_1",
+                Self::Zpl20 => "ZPL_2_0",
+                Self::Zpl21 => "ZPL_2_1",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NOT_OPEN_SOURCE" => Some(Self::NotOpenSource),
+                "BSD_ZERO_CLAUSE_LICENSE" => Some(Self::BsdZeroClauseLicense),
+                "AAL" => Some(Self::Aal),
+                "ABSTYLES" => Some(Self::Abstyles),
+                "ADOBE_2006" => Some(Self::Adobe2006),
+                "ADOBE_GLYPH" => Some(Self::AdobeGlyph),
+                "ADSL" => Some(Self::Adsl),
+                "AFL_1_1" => Some(Self::Afl11),
+                "AFL_1_2" => Some(Self::Afl12),
+                "AFL_2_0" => Some(Self::Afl20),
+                "AFL_2_1" => Some(Self::Afl21),
+                "AFL_3_0" => Some(Self::Afl30),
+                "AFMPARSE" => Some(Self::Afmparse),
+                "AGPL_1_0_ONLY" => Some(Self::Agpl10Only),
+                "AGPL_1_0_OR_LATER" => Some(Self::Agpl10OrLater),
+                "AGPL_3_0_ONLY" => Some(Self::Agpl30Only),
+                "AGPL_3_0_OR_LATER" => Some(Self::Agpl30OrLater),
+                "ALADDIN" => Some(Self::Aladdin),
+                "AMDPLPA" => Some(Self::Amdplpa),
+                "AML" => Some(Self::Aml),
+                "AMPAS" => Some(Self::Ampas),
+                "ANTLR_PD" => Some(Self::AntlrPd),
+                "APACHE_1_0" => Some(Self::Apache10),
+                "APACHE_1_1" => Some(Self::Apache11),
+                "APACHE_2_0" => Some(Self::Apache20),
+                "APAFML" => Some(Self::Apafml),
+                "APL_1_0" => Some(Self::Apl10),
+                "APSL_1_0" => Some(Self::Apsl10),
+                "APSL_1_1" => Some(Self::Apsl11),
+                "APSL_1_2" => Some(Self::Apsl12),
+                "APSL_2_0" => Some(Self::Apsl20),
+                "ARTISTIC_1_0_CL8" => Some(Self::Artistic10Cl8),
+                "ARTISTIC_1_0_PERL" => Some(Self::Artistic10Perl),
+                "ARTISTIC_1_0" => Some(Self::Artistic10),
+                "ARTISTIC_2_0" => Some(Self::Artistic20),
+                "BAHYPH" => Some(Self::Bahyph),
+                "BARR" => Some(Self::Barr),
+                "BEERWARE" => Some(Self::Beerware),
+                "BIT_TORRENT_1_0" => Some(Self::BitTorrent10),
+                "BIT_TORRENT_1_1" => Some(Self::BitTorrent11),
+                "BORCEUX" => Some(Self::Borceux),
+                "BSD_1_CLAUSE" => Some(Self::Bsd1Clause),
+                "BSD_2_CLAUSE_FREE_BSD" => Some(Self::Bsd2ClauseFreeBsd),
+                "BSD_2_CLAUSE_NET_BSD" => Some(Self::Bsd2ClauseNetBsd),
+                "BSD_2_CLAUSE_PATENT" => Some(Self::Bsd2ClausePatent),
+                "BSD_2_CLAUSE" => Some(Self::Bsd2Clause),
+                "BSD_3_CLAUSE_ATTRIBUTION" => Some(Self::Bsd3ClauseAttribution),
+                "BSD_3_CLAUSE_CLEAR" => Some(Self::Bsd3ClauseClear),
+                "BSD_3_CLAUSE_LBNL" => Some(Self::Bsd3ClauseLbnl),
+                "BSD_3_CLAUSE_NO_NUCLEAR_LICENSE_2014" => {
+                    Some(Self::Bsd3ClauseNoNuclearLicense2014)
+                }
+                "BSD_3_CLAUSE_NO_NUCLEAR_LICENSE" => {
+                    Some(Self::Bsd3ClauseNoNuclearLicense)
+                }
+                "BSD_3_CLAUSE_NO_NUCLEAR_WARRANTY" => {
+                    Some(Self::Bsd3ClauseNoNuclearWarranty)
+                }
+                "BSD_3_CLAUSE" => Some(Self::Bsd3Clause),
+                "BSD_4_CLAUSE_UC" => Some(Self::Bsd4ClauseUc),
+                "BSD_4_CLAUSE" => Some(Self::Bsd4Clause),
+                "BSD_PROTECTION" => Some(Self::BsdProtection),
+                "BSD_SOURCE_CODE" => Some(Self::BsdSourceCode),
+                "BSL_1_0" => Some(Self::Bsl10),
+                "BZIP2_1_0_5" => Some(Self::Bzip2105),
+                "BZIP2_1_0_6" => Some(Self::Bzip2106),
+                "CALDERA" => Some(Self::Caldera),
+                "CATOSL_1_1" => Some(Self::Catosl11),
+                "CC_BY_1_0" => Some(Self::CcBy10),
+                "CC_BY_2_0" => Some(Self::CcBy20),
+                "CC_BY_2_5" => Some(Self::CcBy25),
+                "CC_BY_3_0" => Some(Self::CcBy30),
+                "CC_BY_4_0" => Some(Self::CcBy40),
+                "CC_BY_NC_1_0" => Some(Self::CcByNc10),
+                "CC_BY_NC_2_0" => Some(Self::CcByNc20),
+                "CC_BY_NC_2_5" => Some(Self::CcByNc25),
+                "CC_BY_NC_3_0" => Some(Self::CcByNc30),
+                "CC_BY_NC_4_0" => Some(Self::CcByNc40),
+                "CC_BY_NC_ND_1_0" => Some(Self::CcByNcNd10),
+                "CC_BY_NC_ND_2_0" => Some(Self::CcByNcNd20),
+                "CC_BY_NC_ND_2_5" => Some(Self::CcByNcNd25),
+                "CC_BY_NC_ND_3_0" => Some(Self::CcByNcNd30),
+                "CC_BY_NC_ND_4_0" => Some(Self::CcByNcNd40),
+                "CC_BY_NC_SA_1_0" => Some(Self::CcByNcSa10),
+                "CC_BY_NC_SA_2_0" => Some(Self::CcByNcSa20),
+                "CC_BY_NC_SA_2_5" => Some(Self::CcByNcSa25),
+                "CC_BY_NC_SA_3_0" => Some(Self::CcByNcSa30),
+                "CC_BY_NC_SA_4_0" => Some(Self::CcByNcSa40),
+                "CC_BY_ND_1_0" => Some(Self::CcByNd10),
+                "CC_BY_ND_2_0" => Some(Self::CcByNd20),
+                "CC_BY_ND_2_5" => Some(Self::CcByNd25),
+                "CC_BY_ND_3_0" => Some(Self::CcByNd30),
+                "CC_BY_ND_4_0" => Some(Self::CcByNd40),
+                "CC_BY_SA_1_0" => Some(Self::CcBySa10),
+                "CC_BY_SA_2_0" => Some(Self::CcBySa20),
+                "CC_BY_SA_2_5" => Some(Self::CcBySa25),
+                "CC_BY_SA_3_0" => Some(Self::CcBySa30),
+                "CC_BY_SA_4_0" => Some(Self::CcBySa40),
+                "CC0_1_0" => Some(Self::Cc010),
+                "CDDL_1_0" => Some(Self::Cddl10),
+                "CDDL_1_1" => Some(Self::Cddl11),
+                "CDLA_PERMISSIVE_1_0" => Some(Self::CdlaPermissive10),
+                "CDLA_SHARING_1_0" => Some(Self::CdlaSharing10),
+                "CECILL_1_0" => Some(Self::Cecill10),
+                "CECILL_1_1" => Some(Self::Cecill11),
+                "CECILL_2_0" => Some(Self::Cecill20),
+                "CECILL_2_1" => Some(Self::Cecill21),
+                "CECILL_B" => Some(Self::CecillB),
+                "CECILL_C" => Some(Self::CecillC),
+                "CL_ARTISTIC" => Some(Self::ClArtistic),
+                "CNRI_JYTHON" => Some(Self::CnriJython),
+                "CNRI_PYTHON_GPL_COMPATIBLE" => Some(Self::CnriPythonGplCompatible),
+                "CNRI_PYTHON" => Some(Self::CnriPython),
+                "CONDOR_1_1" => Some(Self::Condor11),
+                "CPAL_1_0" => Some(Self::Cpal10),
+                "CPL_1_0" => Some(Self::Cpl10),
+                "CPOL_1_02" => Some(Self::Cpol102),
+                "CROSSWORD" => Some(Self::Crossword),
+                "CRYSTAL_STACKER" => Some(Self::CrystalStacker),
+                "CUA_OPL_1_0" => Some(Self::CuaOpl10),
+                "CUBE" => Some(Self::Cube),
+                "CURL" => Some(Self::Curl),
+                "D_FSL_1_0" => Some(Self::DFsl10),
+                "DIFFMARK" => Some(Self::Diffmark),
+                "DOC" => Some(Self::Doc),
+                "DOTSEQN" => Some(Self::Dotseqn),
+                "DSDP" => Some(Self::Dsdp),
+                "DVIPDFM" => Some(Self::Dvipdfm),
+                "ECL_1_0" => Some(Self::Ecl10),
+                "ECL_2_0" => Some(Self::Ecl20),
+                "EFL_1_0" => Some(Self::Efl10),
+                "EFL_2_0" => Some(Self::Efl20),
+                "E_GENIX" => Some(Self::EGenix),
+                "ENTESSA" => Some(Self::Entessa),
+                "EPL_1_0" => Some(Self::Epl10),
+                "EPL_2_0" => Some(Self::Epl20),
+                "ERL_PL_1_1" => Some(Self::ErlPl11),
+                "EU_DATAGRID" => Some(Self::EuDatagrid),
+                "EUPL_1_0" => Some(Self::Eupl10),
+                "EUPL_1_1" => Some(Self::Eupl11),
+                "EUPL_1_2" => Some(Self::Eupl12),
+                "EUROSYM" => Some(Self::Eurosym),
+                "FAIR" => Some(Self::Fair),
+                "FRAMEWORX_1_0" => Some(Self::Frameworx10),
+                "FREE_IMAGE" => Some(Self::FreeImage),
+                "FSFAP" => Some(Self::Fsfap),
+                "FSFUL" => Some(Self::Fsful),
+                "FSFULLR" => Some(Self::Fsfullr),
+                "FTL" => Some(Self::Ftl),
+                "GFDL_1_1_ONLY" => Some(Self::Gfdl11Only),
+                "GFDL_1_1_OR_LATER" => Some(Self::Gfdl11OrLater),
+                "GFDL_1_2_ONLY" => Some(Self::Gfdl12Only),
+                "GFDL_1_2_OR_LATER" => Some(Self::Gfdl12OrLater),
+                "GFDL_1_3_ONLY" => Some(Self::Gfdl13Only),
+                "GFDL_1_3_OR_LATER" => Some(Self::Gfdl13OrLater),
+                "GIFTWARE" => Some(Self::Giftware),
+                "GL2PS" => Some(Self::Gl2ps),
+                "GLIDE" => Some(Self::Glide),
+                "GLULXE" => Some(Self::Glulxe),
+                "GNUPLOT" => Some(Self::Gnuplot),
+                "GPL_1_0_ONLY" => Some(Self::Gpl10Only),
+                "GPL_1_0_OR_LATER" => Some(Self::Gpl10OrLater),
+                "GPL_2_0_ONLY" => Some(Self::Gpl20Only),
+                "GPL_2_0_OR_LATER" => Some(Self::Gpl20OrLater),
+                "GPL_3_0_ONLY" => Some(Self::Gpl30Only),
+                "GPL_3_0_OR_LATER" => Some(Self::Gpl30OrLater),
+                "G_SOAP_1_3B" => Some(Self::GSoap13b),
+                "HASKELL_REPORT" => Some(Self::HaskellReport),
+                "HPND" => Some(Self::Hpnd),
+                "IBM_PIBS" => Some(Self::IbmPibs),
+                "ICU" => Some(Self::Icu),
+                "IJG" => Some(Self::Ijg),
+                "IMAGE_MAGICK" => Some(Self::ImageMagick),
+                "I_MATIX" => Some(Self::IMatix),
+                "IMLIB2" => Some(Self::Imlib2),
+                "INFO_ZIP" => Some(Self::InfoZip),
+                "INTEL_ACPI" => Some(Self::IntelAcpi),
+                "INTEL" => Some(Self::Intel),
+                "INTERBASE_1_0" => Some(Self::Interbase10),
+                "IPA" => Some(Self::Ipa),
+                "IPL_1_0" => Some(Self::Ipl10),
+                "ISC" => Some(Self::Isc),
+                "JAS_PER_2_0" => Some(Self::JasPer20),
+                "JSON" => Some(Self::Json),
+                "LAL_1_2" => Some(Self::Lal12),
+                "LAL_1_3" => Some(Self::Lal13),
+                "LATEX2E" => Some(Self::Latex2e),
+                "LEPTONICA" => Some(Self::Leptonica),
+                "LGPL_2_0_ONLY" => Some(Self::Lgpl20Only),
+                "LGPL_2_0_OR_LATER" => Some(Self::Lgpl20OrLater),
+                "LGPL_2_1_ONLY" => Some(Self::Lgpl21Only),
+                "LGPL_2_1_OR_LATER" => Some(Self::Lgpl21OrLater),
+                "LGPL_3_0_ONLY" => Some(Self::Lgpl30Only),
+                "LGPL_3_0_OR_LATER" => Some(Self::Lgpl30OrLater),
+                "LGPLLR" => Some(Self::Lgpllr),
+                "LIBPNG" => Some(Self::Libpng),
+                "LIBTIFF" => Some(Self::Libtiff),
+                "LI_LI_Q_P_1_1" => Some(Self::LiLiQP11),
+                "LI_LI_Q_R_1_1" => Some(Self::LiLiQR11),
+                "LI_LI_Q_RPLUS_1_1" => Some(Self::LiLiQRplus11),
+                "LINUX_OPEN_IB" => Some(Self::LinuxOpenIb),
+                "LPL_1_0" => Some(Self::Lpl10),
+                "LPL_1_02" => Some(Self::Lpl102),
+                "LPPL_1_0" => Some(Self::Lppl10),
+                "LPPL_1_1" => Some(Self::Lppl11),
+                "LPPL_1_2" => Some(Self::Lppl12),
+                "LPPL_1_3A" => Some(Self::Lppl13a),
+                "LPPL_1_3C" => Some(Self::Lppl13c),
+                "MAKE_INDEX" => Some(Self::MakeIndex),
+                "MIR_OS" => Some(Self::MirOs),
+                "MIT_0" => Some(Self::Mit0),
+                "MIT_ADVERTISING" => Some(Self::MitAdvertising),
+                "MIT_CMU" => Some(Self::MitCmu),
+                "MIT_ENNA" => Some(Self::MitEnna),
+                "MIT_FEH" => Some(Self::MitFeh),
+                "MIT" => Some(Self::Mit),
+                "MITNFA" => Some(Self::Mitnfa),
+                "MOTOSOTO" => Some(Self::Motosoto),
+                "MPICH2" => Some(Self::Mpich2),
+                "MPL_1_0" => Some(Self::Mpl10),
+                "MPL_1_1" => Some(Self::Mpl11),
+                "MPL_2_0_NO_COPYLEFT_EXCEPTION" => Some(Self::Mpl20NoCopyleftException),
+                "MPL_2_0" => Some(Self::Mpl20),
+                "MS_PL" => Some(Self::MsPl),
+                "MS_RL" => Some(Self::MsRl),
+                "MTLL" => Some(Self::Mtll),
+                "MULTICS" => Some(Self::Multics),
+                "MUP" => Some(Self::Mup),
+                "NASA_1_3" => Some(Self::Nasa13),
+                "NAUMEN" => Some(Self::Naumen),
+                "NBPL_1_0" => Some(Self::Nbpl10),
+                "NCSA" => Some(Self::Ncsa),
+                "NET_SNMP" => Some(Self::NetSnmp),
+                "NET_CDF" => Some(Self::NetCdf),
+                "NEWSLETR" => Some(Self::Newsletr),
+                "NGPL" => Some(Self::Ngpl),
+                "NLOD_1_0" => Some(Self::Nlod10),
+                "NLPL" => Some(Self::Nlpl),
+                "NOKIA" => Some(Self::Nokia),
+                "NOSL" => Some(Self::Nosl),
+                "NOWEB" => Some(Self::Noweb),
+                "NPL_1_0" => Some(Self::Npl10),
+                "NPL_1_1" => Some(Self::Npl11),
+                "NPOSL_3_0" => Some(Self::Nposl30),
+                "NRL" => Some(Self::Nrl),
+                "NTP" => Some(Self::Ntp),
+                "OCCT_PL" => Some(Self::OcctPl),
+                "OCLC_2_0" => Some(Self::Oclc20),
+                "O_DB_L_1_0" => Some(Self::ODbL10),
+                "OFL_1_0" => Some(Self::Ofl10),
+                "OFL_1_1" => Some(Self::Ofl11),
+                "OGTSL" => Some(Self::Ogtsl),
+                "OLDAP_1_1" => Some(Self::Oldap11),
+                "OLDAP_1_2" => Some(Self::Oldap12),
+                "OLDAP_1_3" => Some(Self::Oldap13),
+                "OLDAP_1_4" => Some(Self::Oldap14),
+                "OLDAP_2_0_1" => Some(Self::Oldap201),
+                "OLDAP_2_0" => Some(Self::Oldap20),
+                "OLDAP_2_1" => Some(Self::Oldap21),
+                "OLDAP_2_2_1" => Some(Self::Oldap221),
+                "OLDAP_2_2_2" => Some(Self::Oldap222),
+                "OLDAP_2_2" => Some(Self::Oldap22),
+                "OLDAP_2_3" => Some(Self::Oldap23),
+                "OLDAP_2_4" => Some(Self::Oldap24),
+                "OLDAP_2_5" => Some(Self::Oldap25),
+                "OLDAP_2_6" => Some(Self::Oldap26),
+                "OLDAP_2_7" => Some(Self::Oldap27),
+                "OLDAP_2_8" => Some(Self::Oldap28),
+                "OML" => Some(Self::Oml),
+                "OPEN_SSL" => Some(Self::OpenSsl),
+                "OPL_1_0" => Some(Self::Opl10),
+                "OSET_PL_2_1" => Some(Self::OsetPl21),
+                "OSL_1_0" => Some(Self::Osl10),
+                "OSL_1_1" => Some(Self::Osl11),
+                "OSL_2_0" => Some(Self::Osl20),
+                "OSL_2_1" => Some(Self::Osl21),
+                "OSL_3_0" => Some(Self::Osl30),
+                "PDDL_1_0" => Some(Self::Pddl10),
+                "PHP_3_0" => Some(Self::Php30),
+                "PHP_3_01" => Some(Self::Php301),
+                "PLEXUS" => Some(Self::Plexus),
+                "POSTGRE_SQL" => Some(Self::PostgreSql),
+                "PSFRAG" => Some(Self::Psfrag),
+                "PSUTILS" => Some(Self::Psutils),
+                "PYTHON_2_0" => Some(Self::Python20),
+                "QHULL" => Some(Self::Qhull),
+                "QPL_1_0" => Some(Self::Qpl10),
+                "RDISC" => Some(Self::Rdisc),
+                "R_HE_COS_1_1" => Some(Self::RHeCos11),
+                "RPL_1_1" => Some(Self::Rpl11),
+                "RPL_1_5" => Some(Self::Rpl15),
+                "RPSL_1_0" => Some(Self::Rpsl10),
+                "RSA_MD" => Some(Self::RsaMd),
+                "RSCPL" => Some(Self::Rscpl),
+                "RUBY" => Some(Self::Ruby),
+                "SAX_PD" => Some(Self::SaxPd),
+                "SAXPATH" => Some(Self::Saxpath),
+                "SCEA" => Some(Self::Scea),
+                "SENDMAIL" => Some(Self::Sendmail),
+                "SGI_B_1_0" => Some(Self::SgiB10),
+                "SGI_B_1_1" => Some(Self::SgiB11),
+                "SGI_B_2_0" => Some(Self::SgiB20),
+                "SIM_PL_2_0" => Some(Self::SimPl20),
+                "SISSL_1_2" => Some(Self::Sissl12),
+                "SISSL" => Some(Self::Sissl),
+                "SLEEPYCAT" => Some(Self::Sleepycat),
+                "SMLNJ" => Some(Self::Smlnj),
+                "SMPPL" => Some(Self::Smppl),
+                "SNIA" => Some(Self::Snia),
+                "SPENCER_86" => Some(Self::Spencer86),
+                "SPENCER_94" => Some(Self::Spencer94),
+                "SPENCER_99" => Some(Self::Spencer99),
+                "SPL_1_0" => Some(Self::Spl10),
+                "SUGAR_CRM_1_1_3" => Some(Self::SugarCrm113),
+                "SWL" => Some(Self::Swl),
+                "TCL" => Some(Self::Tcl),
+                "TCP_WRAPPERS" => Some(Self::TcpWrappers),
+                "T_MATE" => Some(Self::TMate),
+                "TORQUE_1_1" => Some(Self::Torque11),
+                "TOSL" => Some(Self::Tosl),
+                "UNICODE_DFS_2015" => Some(Self::UnicodeDfs2015),
+                "UNICODE_DFS_2016" => Some(Self::UnicodeDfs2016),
+                "UNICODE_TOU" => Some(Self::UnicodeTou),
+                "UNLICENSE" => Some(Self::Unlicense),
+                "UPL_1_0" => Some(Self::Upl10),
+                "VIM" => Some(Self::Vim),
+                "VOSTROM" => Some(Self::Vostrom),
+                "VSL_1_0" => Some(Self::Vsl10),
+                "W3C_19980720" => Some(Self::W3c19980720),
+                "W3C_20150513" => Some(Self::W3c20150513),
+                "W3C" => Some(Self::W3c),
+                "WATCOM_1_0" => Some(Self::Watcom10),
+                "WSUIPA" => Some(Self::Wsuipa),
+                "WTFPL" => Some(Self::Wtfpl),
+                "X11" => Some(Self::X11),
+                "XEROX" => Some(Self::Xerox),
+                "X_FREE86_1_1" => Some(Self::XFree8611),
+                "XINETD" => Some(Self::Xinetd),
+                "XNET" => Some(Self::Xnet),
+                "XPP" => Some(Self::Xpp),
+                "X_SKAT" => Some(Self::XSkat),
+                "YPL_1_0" => Some(Self::Ypl10),
+                "YPL_1_1" => Some(Self::Ypl11),
+                "ZED" => Some(Self::Zed),
+                "ZEND_2_0" => Some(Self::Zend20),
+                "ZIMBRA_1_3" => Some(Self::Zimbra13),
+                "ZIMBRA_1_4" => Some(Self::Zimbra14),
+                "ZLIB_ACKNOWLEDGEMENT" => Some(Self::ZlibAcknowledgement),
+                "ZLIB" => Some(Self::Zlib),
+                "ZPL_1_1" => Some(Self::Zpl11),
+                "ZPL_2_0" => Some(Self::Zpl20),
+                "ZPL_2_1" => Some(Self::Zpl21),
+                _ => None,
+            }
+        }
+    }
+}
+/// What Search Comparator Codes are supported in search.
+/// See <http://hl7.org/fhir/search-comparator>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SearchComparatorCode {}
+/// Nested message and enum types in `SearchComparatorCode`.
+pub mod search_comparator_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Eq = 1,
+        Ne = 2,
+        Gt = 3,
+        Lt = 4,
+        Ge = 5,
+        Le = 6,
+        Sa = 7,
+        Eb = 8,
+        Ap = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Eq => "EQ",
+                Self::Ne => "NE",
+                Self::Gt => "GT",
+                Self::Lt => "LT",
+                Self::Ge => "GE",
+                Self::Le => "LE",
+                Self::Sa => "SA",
+                Self::Eb => "EB",
+                Self::Ap => "AP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EQ" => Some(Self::Eq),
+                "NE" => Some(Self::Ne),
+                "GT" => Some(Self::Gt),
+                "LT" => Some(Self::Lt),
+                "GE" => Some(Self::Ge),
+                "LE" => Some(Self::Le),
+                "SA" => Some(Self::Sa),
+                "EB" => Some(Self::Eb),
+                "AP" => Some(Self::Ap),
+                _ => None,
+            }
+        }
+    }
+}
+/// Why an entry is in the result set - whether it's included as a match or
+/// because of an _include requirement, or to convey information or warning
+/// information about the search process. See
+/// <http://hl7.org/fhir/search-entry-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SearchEntryModeCode {}
+/// Nested message and enum types in `SearchEntryModeCode`.
+pub mod search_entry_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Match = 1,
+        Include = 2,
+        Outcome = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Match => "MATCH",
+                Self::Include => "INCLUDE",
+                Self::Outcome => "OUTCOME",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MATCH" => Some(Self::Match),
+                "INCLUDE" => Some(Self::Include),
+                "OUTCOME" => Some(Self::Outcome),
+                _ => None,
+            }
+        }
+    }
+}
+/// A supported modifier for a search parameter.
+/// See <http://hl7.org/fhir/search-modifier-code>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SearchModifierCode {}
+/// Nested message and enum types in `SearchModifierCode`.
+pub mod search_modifier_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Missing = 1,
+        Exact = 2,
+        Contains = 3,
+        Not = 4,
+        Text = 5,
+        In = 6,
+        NotIn = 7,
+        Below = 8,
+        Above = 9,
+        Type = 10,
+        Identifier = 11,
+        OfType = 13,
+        CodeText = 14,
+        TextAdvanced = 15,
+        Iterate = 16,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Missing => "MISSING",
+                Self::Exact => "EXACT",
+                Self::Contains => "CONTAINS",
+                Self::Not => "NOT",
+                Self::Text => "TEXT",
+                Self::In => "IN",
+                Self::NotIn => "NOT_IN",
+                Self::Below => "BELOW",
+                Self::Above => "ABOVE",
+                Self::Type => "TYPE",
+                Self::Identifier => "IDENTIFIER",
+                Self::OfType => "OF_TYPE",
+                Self::CodeText => "CODE_TEXT",
+                Self::TextAdvanced => "TEXT_ADVANCED",
+                Self::Iterate => "ITERATE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MISSING" => Some(Self::Missing),
+                "EXACT" => Some(Self::Exact),
+                "CONTAINS" => Some(Self::Contains),
+                "NOT" => Some(Self::Not),
+                "TEXT" => Some(Self::Text),
+                "IN" => Some(Self::In),
+                "NOT_IN" => Some(Self::NotIn),
+                "BELOW" => Some(Self::Below),
+                "ABOVE" => Some(Self::Above),
+                "TYPE" => Some(Self::Type),
+                "IDENTIFIER" => Some(Self::Identifier),
+                "OF_TYPE" => Some(Self::OfType),
+                "CODE_TEXT" => Some(Self::CodeText),
+                "TEXT_ADVANCED" => Some(Self::TextAdvanced),
+                "ITERATE" => Some(Self::Iterate),
+                _ => None,
+            }
+        }
+    }
+}
+/// Data types allowed to be used for search parameters.
+/// See <http://hl7.org/fhir/search-param-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SearchParamTypeCode {}
+/// Nested message and enum types in `SearchParamTypeCode`.
+pub mod search_param_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Number = 1,
+        Date = 2,
+        String = 3,
+        Token = 4,
+        Reference = 5,
+        Composite = 6,
+        Quantity = 7,
+        Uri = 8,
+        Special = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Number => "NUMBER",
+                Self::Date => "DATE",
+                Self::String => "STRING",
+                Self::Token => "TOKEN",
+                Self::Reference => "REFERENCE",
+                Self::Composite => "COMPOSITE",
+                Self::Quantity => "QUANTITY",
+                Self::Uri => "URI",
+                Self::Special => "SPECIAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NUMBER" => Some(Self::Number),
+                "DATE" => Some(Self::Date),
+                "STRING" => Some(Self::String),
+                "TOKEN" => Some(Self::Token),
+                "REFERENCE" => Some(Self::Reference),
+                "COMPOSITE" => Some(Self::Composite),
+                "QUANTITY" => Some(Self::Quantity),
+                "URI" => Some(Self::Uri),
+                "SPECIAL" => Some(Self::Special),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a search parameter relates to the set of elements returned by evaluating
+/// its expression query. See <http://hl7.org/fhir/search-processingmode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SearchProcessingModeTypeCode {}
+/// Nested message and enum types in `SearchProcessingModeTypeCode`.
+pub mod search_processing_mode_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Normal = 1,
+        Phonetic = 2,
+        Other = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Normal => "NORMAL",
+                Self::Phonetic => "PHONETIC",
+                Self::Other => "OTHER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NORMAL" => Some(Self::Normal),
+                "PHONETIC" => Some(Self::Phonetic),
+                "OTHER" => Some(Self::Other),
+                _ => None,
+            }
+        }
+    }
+}
+/// Type if a sequence -- DNA, RNA, or amino acid sequence.
+/// See <http://hl7.org/fhir/sequence-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SequenceTypeCode {}
+/// Nested message and enum types in `SequenceTypeCode`.
+pub mod sequence_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Aa = 1,
+        Dna = 2,
+        Rna = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Aa => "AA",
+                Self::Dna => "DNA",
+                Self::Rna => "RNA",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AA" => Some(Self::Aa),
+                "DNA" => Some(Self::Dna),
+                "RNA" => Some(Self::Rna),
+                _ => None,
+            }
+        }
+    }
+}
+/// How slices are interpreted when evaluating an instance.
+/// See <http://hl7.org/fhir/resource-slicing-rules>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SlicingRulesCode {}
+/// Nested message and enum types in `SlicingRulesCode`.
+pub mod slicing_rules_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Closed = 1,
+        Open = 2,
+        OpenAtEnd = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Closed => "CLOSED",
+                Self::Open => "OPEN",
+                Self::OpenAtEnd => "OPEN_AT_END",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CLOSED" => Some(Self::Closed),
+                "OPEN" => Some(Self::Open),
+                "OPEN_AT_END" => Some(Self::OpenAtEnd),
+                _ => None,
+            }
+        }
+    }
+}
+/// The free/busy status of the slot.
+/// See <http://hl7.org/fhir/slotstatus>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SlotStatusCode {}
+/// Nested message and enum types in `SlotStatusCode`.
+pub mod slot_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Busy = 1,
+        Free = 2,
+        BusyUnavailable = 3,
+        BusyTentative = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Busy => "BUSY",
+                Self::Free => "FREE",
+                Self::BusyUnavailable => "BUSY_UNAVAILABLE",
+                Self::BusyTentative => "BUSY_TENTATIVE",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BUSY" => Some(Self::Busy),
+                "FREE" => Some(Self::Free),
+                "BUSY_UNAVAILABLE" => Some(Self::BusyUnavailable),
+                "BUSY_TENTATIVE" => Some(Self::BusyTentative),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The possible sort directions, ascending or descending.
+/// See <http://hl7.org/fhir/sort-direction>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SortDirectionCode {}
+/// Nested message and enum types in `SortDirectionCode`.
+pub mod sort_direction_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Ascending = 1,
+        Descending = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Ascending => "ASCENDING",
+                Self::Descending => "DESCENDING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ASCENDING" => Some(Self::Ascending),
+                "DESCENDING" => Some(Self::Descending),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes providing the combined status of the specimen.
+/// See <http://hl7.org/fhir/specimen-combined>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SpecimenCombinedCode {}
+/// Nested message and enum types in `SpecimenCombinedCode`.
+pub mod specimen_combined_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Grouped = 1,
+        Pooled = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Grouped => "GROUPED",
+                Self::Pooled => "POOLED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "GROUPED" => Some(Self::Grouped),
+                "POOLED" => Some(Self::Pooled),
+                _ => None,
+            }
+        }
+    }
+}
+/// Degree of preference of a type of conditioned specimen.
+/// See <http://hl7.org/fhir/specimen-contained-preference>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SpecimenContainedPreferenceCode {}
+/// Nested message and enum types in `SpecimenContainedPreferenceCode`.
+pub mod specimen_contained_preference_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preferred = 1,
+        Alternate = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preferred => "PREFERRED",
+                Self::Alternate => "ALTERNATE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PREFERRED" => Some(Self::Preferred),
+                "ALTERNATE" => Some(Self::Alternate),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes providing the status/availability of a specimen.
+/// See <http://hl7.org/fhir/specimen-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SpecimenStatusCode {}
+/// Nested message and enum types in `SpecimenStatusCode`.
+pub mod specimen_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Available = 1,
+        Unavailable = 2,
+        Unsatisfactory = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Available => "AVAILABLE",
+                Self::Unavailable => "UNAVAILABLE",
+                Self::Unsatisfactory => "UNSATISFACTORY",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AVAILABLE" => Some(Self::Available),
+                "UNAVAILABLE" => Some(Self::Unavailable),
+                "UNSATISFACTORY" => Some(Self::Unsatisfactory),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The validation status of the target
+/// See <http://hl7.org/fhir/CodeSystem/verificationresult-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StatusCode {}
+/// Nested message and enum types in `StatusCode`.
+pub mod status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Attested = 1,
+        Validated = 2,
+        InProcess = 3,
+        ReqRevalid = 4,
+        ValFail = 5,
+        RevalFail = 6,
+        EnteredInError = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Attested => "ATTESTED",
+                Self::Validated => "VALIDATED",
+                Self::InProcess => "IN_PROCESS",
+                Self::ReqRevalid => "REQ_REVALID",
+                Self::ValFail => "VAL_FAIL",
+                Self::RevalFail => "REVAL_FAIL",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ATTESTED" => Some(Self::Attested),
+                "VALIDATED" => Some(Self::Validated),
+                "IN_PROCESS" => Some(Self::InProcess),
+                "REQ_REVALID" => Some(Self::ReqRevalid),
+                "VAL_FAIL" => Some(Self::ValFail),
+                "REVAL_FAIL" => Some(Self::RevalFail),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Type for strand.
+/// See <http://hl7.org/fhir/strand-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StrandTypeCode {}
+/// Nested message and enum types in `StrandTypeCode`.
+pub mod strand_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Watson = 1,
+        Crick = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Watson => "WATSON",
+                Self::Crick => "CRICK",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "WATSON" => Some(Self::Watson),
+                "CRICK" => Some(Self::Crick),
+                _ => None,
+            }
+        }
+    }
+}
+/// Defines the type of structure that a definition is describing.
+/// See <http://hl7.org/fhir/structure-definition-kind>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureDefinitionKindCode {}
+/// Nested message and enum types in `StructureDefinitionKindCode`.
+pub mod structure_definition_kind_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        PrimitiveType = 1,
+        ComplexType = 2,
+        Resource = 3,
+        Logical = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::PrimitiveType => "PRIMITIVE_TYPE",
+                Self::ComplexType => "COMPLEX_TYPE",
+                Self::Resource => "RESOURCE",
+                Self::Logical => "LOGICAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PRIMITIVE_TYPE" => Some(Self::PrimitiveType),
+                "COMPLEX_TYPE" => Some(Self::ComplexType),
+                "RESOURCE" => Some(Self::Resource),
+                "LOGICAL" => Some(Self::Logical),
+                _ => None,
+            }
+        }
+    }
+}
+/// If this is the default rule set to apply for the source type, or this
+/// combination of types. See <http://hl7.org/fhir/map-group-type-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureMapGroupTypeModeCode {}
+/// Nested message and enum types in `StructureMapGroupTypeModeCode`.
+pub mod structure_map_group_type_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Types = 2,
+        TypeAndTypes = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Types => "TYPES",
+                Self::TypeAndTypes => "TYPE_AND_TYPES",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "TYPES" => Some(Self::Types),
+                "TYPE_AND_TYPES" => Some(Self::TypeAndTypes),
+                _ => None,
+            }
+        }
+    }
+}
+/// Mode for this instance of data.
+/// See <http://hl7.org/fhir/map-input-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureMapInputModeCode {}
+/// Nested message and enum types in `StructureMapInputModeCode`.
+pub mod structure_map_input_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Source = 1,
+        Target = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Source => "SOURCE",
+                Self::Target => "TARGET",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SOURCE" => Some(Self::Source),
+                "TARGET" => Some(Self::Target),
+                _ => None,
+            }
+        }
+    }
+}
+/// How the referenced structure is used in this mapping.
+/// See <http://hl7.org/fhir/map-model-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureMapModelModeCode {}
+/// Nested message and enum types in `StructureMapModelModeCode`.
+pub mod structure_map_model_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Source = 1,
+        Queried = 2,
+        Target = 3,
+        Produced = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Source => "SOURCE",
+                Self::Queried => "QUERIED",
+                Self::Target => "TARGET",
+                Self::Produced => "PRODUCED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SOURCE" => Some(Self::Source),
+                "QUERIED" => Some(Self::Queried),
+                "TARGET" => Some(Self::Target),
+                "PRODUCED" => Some(Self::Produced),
+                _ => None,
+            }
+        }
+    }
+}
+/// If field is a list, how to manage the source.
+/// See <http://hl7.org/fhir/map-source-list-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureMapSourceListModeCode {}
+/// Nested message and enum types in `StructureMapSourceListModeCode`.
+pub mod structure_map_source_list_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        First = 1,
+        NotFirst = 2,
+        Last = 3,
+        NotLast = 4,
+        OnlyOne = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::First => "FIRST",
+                Self::NotFirst => "NOT_FIRST",
+                Self::Last => "LAST",
+                Self::NotLast => "NOT_LAST",
+                Self::OnlyOne => "ONLY_ONE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FIRST" => Some(Self::First),
+                "NOT_FIRST" => Some(Self::NotFirst),
+                "LAST" => Some(Self::Last),
+                "NOT_LAST" => Some(Self::NotLast),
+                "ONLY_ONE" => Some(Self::OnlyOne),
+                _ => None,
+            }
+        }
+    }
+}
+/// If field is a list, how to manage the production.
+/// See <http://hl7.org/fhir/map-target-list-mode>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureMapTargetListModeCode {}
+/// Nested message and enum types in `StructureMapTargetListModeCode`.
+pub mod structure_map_target_list_mode_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        First = 1,
+        Share = 2,
+        Last = 3,
+        Single = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::First => "FIRST",
+                Self::Share => "SHARE",
+                Self::Last => "LAST",
+                Self::Single => "SINGLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FIRST" => Some(Self::First),
+                "SHARE" => Some(Self::Share),
+                "LAST" => Some(Self::Last),
+                "SINGLE" => Some(Self::Single),
+                _ => None,
+            }
+        }
+    }
+}
+/// How data is copied/created.
+/// See <http://hl7.org/fhir/map-transform>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StructureMapTransformCode {}
+/// Nested message and enum types in `StructureMapTransformCode`.
+pub mod structure_map_transform_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Create = 1,
+        Copy = 2,
+        Truncate = 3,
+        Escape = 4,
+        Cast = 5,
+        Append = 6,
+        Translate = 7,
+        Reference = 8,
+        DateOp = 9,
+        Uuid = 10,
+        Pointer = 11,
+        Evaluate = 12,
+        Cc = 13,
+        C = 14,
+        Qty = 15,
+        Id = 16,
+        Cp = 17,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Create => "CREATE",
+                Self::Copy => "COPY",
+                Self::Truncate => "TRUNCATE",
+                Self::Escape => "ESCAPE",
+                Self::Cast => "CAST",
+                Self::Append => "APPEND",
+                Self::Translate => "TRANSLATE",
+                Self::Reference => "REFERENCE",
+                Self::DateOp => "DATE_OP",
+                Self::Uuid => "UUID",
+                Self::Pointer => "POINTER",
+                Self::Evaluate => "EVALUATE",
+                Self::Cc => "CC",
+                Self::C => "C",
+                Self::Qty => "QTY",
+                Self::Id => "ID",
+                Self::Cp => "CP",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CREATE" => Some(Self::Create),
+                "COPY" => Some(Self::Copy),
+                "TRUNCATE" => Some(Self::Truncate),
+                "ESCAPE" => Some(Self::Escape),
+                "CAST" => Some(Self::Cast),
+                "APPEND" => Some(Self::Append),
+                "TRANSLATE" => Some(Self::Translate),
+                "REFERENCE" => Some(Self::Reference),
+                "DATE_OP" => Some(Self::DateOp),
+                "UUID" => Some(Self::Uuid),
+                "POINTER" => Some(Self::Pointer),
+                "EVALUATE" => Some(Self::Evaluate),
+                "CC" => Some(Self::Cc),
+                "C" => Some(Self::C),
+                "QTY" => Some(Self::Qty),
+                "ID" => Some(Self::Id),
+                "CP" => Some(Self::Cp),
+                _ => None,
+            }
+        }
+    }
+}
+/// Concepts for how a measure report consumer and receiver coordinate data
+/// exchange updates. The choices are snapshot or incremental updates See
+/// <http://hl7.org/fhir/CodeSystem/submit-data-update-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SubmitDataUpdateTypeCode {}
+/// Nested message and enum types in `SubmitDataUpdateTypeCode`.
+pub mod submit_data_update_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Incremental = 1,
+        Snapshot = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Incremental => "INCREMENTAL",
+                Self::Snapshot => "SNAPSHOT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "INCREMENTAL" => Some(Self::Incremental),
+                "SNAPSHOT" => Some(Self::Snapshot),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of notification represented by the status message.
+/// See <http://hl7.org/fhir/subscription-notification-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SubscriptionNotificationTypeCode {}
+/// Nested message and enum types in `SubscriptionNotificationTypeCode`.
+pub mod subscription_notification_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Handshake = 1,
+        Heartbeat = 2,
+        EventNotification = 3,
+        QueryStatus = 4,
+        QueryEvent = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Handshake => "HANDSHAKE",
+                Self::Heartbeat => "HEARTBEAT",
+                Self::EventNotification => "EVENT_NOTIFICATION",
+                Self::QueryStatus => "QUERY_STATUS",
+                Self::QueryEvent => "QUERY_EVENT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "HANDSHAKE" => Some(Self::Handshake),
+                "HEARTBEAT" => Some(Self::Heartbeat),
+                "EVENT_NOTIFICATION" => Some(Self::EventNotification),
+                "QUERY_STATUS" => Some(Self::QueryStatus),
+                "QUERY_EVENT" => Some(Self::QueryEvent),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes to represent how much resource content to send in the notification
+/// payload. See <http://hl7.org/fhir/subscription-payload-content>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SubscriptionPayloadContentCode {}
+/// Nested message and enum types in `SubscriptionPayloadContentCode`.
+pub mod subscription_payload_content_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Empty = 1,
+        IdOnly = 2,
+        FullResource = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Empty => "EMPTY",
+                Self::IdOnly => "ID_ONLY",
+                Self::FullResource => "FULL_RESOURCE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EMPTY" => Some(Self::Empty),
+                "ID_ONLY" => Some(Self::IdOnly),
+                "FULL_RESOURCE" => Some(Self::FullResource),
+                _ => None,
+            }
+        }
+    }
+}
+/// This codesystem defines a set of codes that can be used to filter
+/// Subscription triggers. See <http://hl7.org/fhir/subscription-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SubscriptionStatusCode {}
+/// Nested message and enum types in `SubscriptionStatusCode`.
+pub mod subscription_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Requested = 1,
+        Active = 2,
+        Error = 3,
+        Off = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Requested => "REQUESTED",
+                Self::Active => "ACTIVE",
+                Self::Error => "ERROR",
+                Self::Off => "OFF",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REQUESTED" => Some(Self::Requested),
+                "ACTIVE" => Some(Self::Active),
+                "ERROR" => Some(Self::Error),
+                "OFF" => Some(Self::Off),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Status of the supply delivery.
+/// See <http://hl7.org/fhir/supplydelivery-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SupplyDeliveryStatusCode {}
+/// Nested message and enum types in `SupplyDeliveryStatusCode`.
+pub mod supply_delivery_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        InProgress = 1,
+        Completed = 2,
+        Abandoned = 3,
+        EnteredInError = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Completed => "COMPLETED",
+                Self::Abandoned => "ABANDONED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "COMPLETED" => Some(Self::Completed),
+                "ABANDONED" => Some(Self::Abandoned),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// Status of the supply delivery.
+/// See <http://hl7.org/fhir/supplydelivery-supplyitemtype>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SupplyDeliverySupplyItemTypeCode {}
+/// Nested message and enum types in `SupplyDeliverySupplyItemTypeCode`.
+pub mod supply_delivery_supply_item_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Medication = 1,
+        Device = 2,
+        Biologicallyderivedproduct = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Medication => "MEDICATION",
+                Self::Device => "DEVICE",
+                Self::Biologicallyderivedproduct => "BIOLOGICALLYDERIVEDPRODUCT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MEDICATION" => Some(Self::Medication),
+                "DEVICE" => Some(Self::Device),
+                "BIOLOGICALLYDERIVEDPRODUCT" => Some(Self::Biologicallyderivedproduct),
+                _ => None,
+            }
+        }
+    }
+}
+/// Status of the supply request.
+/// See <http://hl7.org/fhir/supplyrequest-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SupplyRequestStatusCode {}
+/// Nested message and enum types in `SupplyRequestStatusCode`.
+pub mod supply_request_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Active = 2,
+        Suspended = 3,
+        Cancelled = 4,
+        Completed = 5,
+        EnteredInError = 6,
+        Unknown = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Active => "ACTIVE",
+                Self::Suspended => "SUSPENDED",
+                Self::Cancelled => "CANCELLED",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "ACTIVE" => Some(Self::Active),
+                "SUSPENDED" => Some(Self::Suspended),
+                "CANCELLED" => Some(Self::Cancelled),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Distinguishes whether the task is a proposal, plan or full order.
+/// See <http://hl7.org/fhir/task-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TaskIntentCode {}
+/// Nested message and enum types in `TaskIntentCode`.
+pub mod task_intent_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Unknown = 1,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The current status of the task.
+/// See <http://hl7.org/fhir/task-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TaskStatusCode {}
+/// Nested message and enum types in `TaskStatusCode`.
+pub mod task_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Draft = 1,
+        Requested = 2,
+        Received = 3,
+        Accepted = 4,
+        Rejected = 5,
+        Ready = 6,
+        Cancelled = 7,
+        InProgress = 8,
+        OnHold = 9,
+        Failed = 10,
+        Completed = 11,
+        EnteredInError = 12,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Draft => "DRAFT",
+                Self::Requested => "REQUESTED",
+                Self::Received => "RECEIVED",
+                Self::Accepted => "ACCEPTED",
+                Self::Rejected => "REJECTED",
+                Self::Ready => "READY",
+                Self::Cancelled => "CANCELLED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::OnHold => "ON_HOLD",
+                Self::Failed => "FAILED",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DRAFT" => Some(Self::Draft),
+                "REQUESTED" => Some(Self::Requested),
+                "RECEIVED" => Some(Self::Received),
+                "ACCEPTED" => Some(Self::Accepted),
+                "REJECTED" => Some(Self::Rejected),
+                "READY" => Some(Self::Ready),
+                "CANCELLED" => Some(Self::Cancelled),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "ON_HOLD" => Some(Self::OnHold),
+                "FAILED" => Some(Self::Failed),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The results of executing an action.
+/// See <http://hl7.org/fhir/report-action-result-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TestReportActionResultCode {}
+/// Nested message and enum types in `TestReportActionResultCode`.
+pub mod test_report_action_result_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Pass = 1,
+        Skip = 2,
+        Fail = 3,
+        Warning = 4,
+        Error = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Pass => "PASS",
+                Self::Skip => "SKIP",
+                Self::Fail => "FAIL",
+                Self::Warning => "WARNING",
+                Self::Error => "ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PASS" => Some(Self::Pass),
+                "SKIP" => Some(Self::Skip),
+                "FAIL" => Some(Self::Fail),
+                "WARNING" => Some(Self::Warning),
+                "ERROR" => Some(Self::Error),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of participant.
+/// See <http://hl7.org/fhir/report-participant-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TestReportParticipantTypeCode {}
+/// Nested message and enum types in `TestReportParticipantTypeCode`.
+pub mod test_report_participant_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        TestEngine = 1,
+        Client = 2,
+        Server = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::TestEngine => "TEST_ENGINE",
+                Self::Client => "CLIENT",
+                Self::Server => "SERVER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "TEST_ENGINE" => Some(Self::TestEngine),
+                "CLIENT" => Some(Self::Client),
+                "SERVER" => Some(Self::Server),
+                _ => None,
+            }
+        }
+    }
+}
+/// The reported execution result.
+/// See <http://hl7.org/fhir/report-result-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TestReportResultCode {}
+/// Nested message and enum types in `TestReportResultCode`.
+pub mod test_report_result_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Pass = 1,
+        Fail = 2,
+        Pending = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Pass => "PASS",
+                Self::Fail => "FAIL",
+                Self::Pending => "PENDING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PASS" => Some(Self::Pass),
+                "FAIL" => Some(Self::Fail),
+                "PENDING" => Some(Self::Pending),
+                _ => None,
+            }
+        }
+    }
+}
+/// The current status of the test report.
+/// See <http://hl7.org/fhir/report-status-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TestReportStatusCode {}
+/// Nested message and enum types in `TestReportStatusCode`.
+pub mod test_report_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Completed = 1,
+        InProgress = 2,
+        Waiting = 3,
+        Stopped = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Completed => "COMPLETED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Waiting => "WAITING",
+                Self::Stopped => "STOPPED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "COMPLETED" => Some(Self::Completed),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "WAITING" => Some(Self::Waiting),
+                "STOPPED" => Some(Self::Stopped),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The allowable request method or HTTP operation codes.
+/// See <http://hl7.org/fhir/http-operations>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TestScriptRequestMethodCode {}
+/// Nested message and enum types in `TestScriptRequestMethodCode`.
+pub mod test_script_request_method_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Delete = 1,
+        Get = 2,
+        Options = 3,
+        Patch = 4,
+        Post = 5,
+        Put = 6,
+        Head = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Delete => "DELETE",
+                Self::Get => "GET",
+                Self::Options => "OPTIONS",
+                Self::Patch => "PATCH",
+                Self::Post => "POST",
+                Self::Put => "PUT",
+                Self::Head => "HEAD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DELETE" => Some(Self::Delete),
+                "GET" => Some(Self::Get),
+                "OPTIONS" => Some(Self::Options),
+                "PATCH" => Some(Self::Patch),
+                "POST" => Some(Self::Post),
+                "PUT" => Some(Self::Put),
+                "HEAD" => Some(Self::Head),
+                _ => None,
+            }
+        }
+    }
+}
+/// Distinguishes whether the transport is a proposal, plan or full order.
+/// See <http://hl7.org/fhir/transport-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TransportIntentCode {}
+/// Nested message and enum types in `TransportIntentCode`.
+pub mod transport_intent_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Unknown = 1,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// Status of transport.
+/// See <http://hl7.org/fhir/transport-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TransportStatusCode {}
+/// Nested message and enum types in `TransportStatusCode`.
+pub mod transport_status_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        InProgress = 1,
+        Completed = 2,
+        Abandoned = 3,
+        Cancelled = 4,
+        Planned = 5,
+        EnteredInError = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Completed => "COMPLETED",
+                Self::Abandoned => "ABANDONED",
+                Self::Cancelled => "CANCELLED",
+                Self::Planned => "PLANNED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "COMPLETED" => Some(Self::Completed),
+                "ABANDONED" => Some(Self::Abandoned),
+                "CANCELLED" => Some(Self::Cancelled),
+                "PLANNED" => Some(Self::Planned),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of trigger.
+/// See <http://hl7.org/fhir/trigger-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TriggerTypeCode {}
+/// Nested message and enum types in `TriggerTypeCode`.
+pub mod trigger_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        NamedEvent = 1,
+        Periodic = 2,
+        DataChanged = 3,
+        DataAdded = 4,
+        DataModified = 5,
+        DataRemoved = 6,
+        DataAccessed = 7,
+        DataAccessEnded = 8,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::NamedEvent => "NAMED_EVENT",
+                Self::Periodic => "PERIODIC",
+                Self::DataChanged => "DATA_CHANGED",
+                Self::DataAdded => "DATA_ADDED",
+                Self::DataModified => "DATA_MODIFIED",
+                Self::DataRemoved => "DATA_REMOVED",
+                Self::DataAccessed => "DATA_ACCESSED",
+                Self::DataAccessEnded => "DATA_ACCESS_ENDED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "NAMED_EVENT" => Some(Self::NamedEvent),
+                "PERIODIC" => Some(Self::Periodic),
+                "DATA_CHANGED" => Some(Self::DataChanged),
+                "DATA_ADDED" => Some(Self::DataAdded),
+                "DATA_MODIFIED" => Some(Self::DataModified),
+                "DATA_REMOVED" => Some(Self::DataRemoved),
+                "DATA_ACCESSED" => Some(Self::DataAccessed),
+                "DATA_ACCESS_ENDED" => Some(Self::DataAccessEnded),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes providing the triggeredBy type of observation.
+/// See <http://hl7.org/fhir/observation-triggeredbytype>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TriggeredBytypeCode {}
+/// Nested message and enum types in `TriggeredBytypeCode`.
+pub mod triggered_bytype_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Reflex = 1,
+        Repeat = 2,
+        ReRun = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Reflex => "REFLEX",
+                Self::Repeat => "REPEAT",
+                Self::ReRun => "RE_RUN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "REFLEX" => Some(Self::Reflex),
+                "REPEAT" => Some(Self::Repeat),
+                "RE_RUN" => Some(Self::ReRun),
+                _ => None,
+            }
+        }
+    }
+}
+/// How a type relates to its baseDefinition.
+/// See <http://hl7.org/fhir/type-derivation-rule>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TypeDerivationRuleCode {}
+/// Nested message and enum types in `TypeDerivationRuleCode`.
+pub mod type_derivation_rule_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Specialization = 1,
+        Constraint = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Specialization => "SPECIALIZATION",
+                Self::Constraint => "CONSTRAINT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "SPECIALIZATION" => Some(Self::Specialization),
+                "CONSTRAINT" => Some(Self::Constraint),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes to identify how UDI data was entered.
+/// See <http://hl7.org/fhir/udi-entry-type>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UdiEntryTypeCode {}
+/// Nested message and enum types in `UDIEntryTypeCode`.
+pub mod udi_entry_type_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Barcode = 1,
+        Rfid = 2,
+        Manual = 3,
+        Card = 4,
+        SelfReported = 5,
+        ElectronicTransmission = 7,
+        Unknown = 6,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Barcode => "BARCODE",
+                Self::Rfid => "RFID",
+                Self::Manual => "MANUAL",
+                Self::Card => "CARD",
+                Self::SelfReported => "SELF_REPORTED",
+                Self::ElectronicTransmission => "ELECTRONIC_TRANSMISSION",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "BARCODE" => Some(Self::Barcode),
+                "RFID" => Some(Self::Rfid),
+                "MANUAL" => Some(Self::Manual),
+                "CARD" => Some(Self::Card),
+                "SELF_REPORTED" => Some(Self::SelfReported),
+                "ELECTRONIC_TRANSMISSION" => Some(Self::ElectronicTransmission),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The purpose of the Claim: predetermination, preauthorization, claim.
+/// See <http://hl7.org/fhir/claim-use>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UseCode {}
+/// Nested message and enum types in `UseCode`.
+pub mod use_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Claim = 1,
+        Preauthorization = 2,
+        Predetermination = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Claim => "CLAIM",
+                Self::Preauthorization => "PREAUTHORIZATION",
+                Self::Predetermination => "PREDETERMINATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CLAIM" => Some(Self::Claim),
+                "PREAUTHORIZATION" => Some(Self::Preauthorization),
+                "PREDETERMINATION" => Some(Self::Predetermination),
+                _ => None,
+            }
+        }
+    }
+}
+/// A coded concept listing the base codes.
+/// See <http://hl7.org/fhir/vision-base-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VisionBaseCode {}
+/// Nested message and enum types in `VisionBaseCode`.
+pub mod vision_base_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Up = 1,
+        Down = 2,
+        In = 3,
+        Out = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Up => "UP",
+                Self::Down => "DOWN",
+                Self::In => "IN",
+                Self::Out => "OUT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UP" => Some(Self::Up),
+                "DOWN" => Some(Self::Down),
+                "IN" => Some(Self::In),
+                "OUT" => Some(Self::Out),
+                _ => None,
+            }
+        }
+    }
+}
+/// A coded concept listing the eye codes.
+/// See <http://hl7.org/fhir/vision-eye-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VisionEyesCode {}
+/// Nested message and enum types in `VisionEyesCode`.
+pub mod vision_eyes_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Right = 1,
+        Left = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Right => "RIGHT",
+                Self::Left => "LEFT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "RIGHT" => Some(Self::Right),
+                "LEFT" => Some(Self::Left),
+                _ => None,
+            }
+        }
+    }
+}
+/// The set of weeks with in a month.
+/// See <http://hl7.org/fhir/week-of-month>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WeekOfMonthCode {}
+/// Nested message and enum types in `WeekOfMonthCode`.
+pub mod week_of_month_code {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        First = 1,
+        Second = 2,
+        Third = 3,
+        Fourth = 4,
+        Last = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::First => "FIRST",
+                Self::Second => "SECOND",
+                Self::Third => "THIRD",
+                Self::Fourth => "FOURTH",
+                Self::Last => "LAST",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "FIRST" => Some(Self::First),
+                "SECOND" => Some(Self::Second),
+                "THIRD" => Some(Self::Third),
+                "FOURTH" => Some(Self::Fourth),
+                "LAST" => Some(Self::Last),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes identifying the lifecycle stage of an adverse event.
+/// See <http://hl7.org/fhir/ValueSet/adverse-event-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AdverseEventStatusValueSet {}
+/// Nested message and enum types in `AdverseEventStatusValueSet`.
+pub mod adverse_event_status_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        InProgress = 1,
+        Completed = 2,
+        EnteredInError = 3,
+        Unknown = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::InProgress => "IN_PROGRESS",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Unknown => "UNKNOWN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "UNKNOWN" => Some(Self::Unknown),
+                _ => None,
+            }
+        }
+    }
+}
+/// The Participation status for a participant in response to a request for an
+/// appointment. See <http://hl7.org/fhir/ValueSet/appointmentresponse-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AppointmentResponseStatusValueSet {}
+/// Nested message and enum types in `AppointmentResponseStatusValueSet`.
+pub mod appointment_response_status_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Accepted = 1,
+        Declined = 2,
+        Tentative = 3,
+        NeedsAction = 4,
+        EnteredInError = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Accepted => "ACCEPTED",
+                Self::Declined => "DECLINED",
+                Self::Tentative => "TENTATIVE",
+                Self::NeedsAction => "NEEDS_ACTION",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACCEPTED" => Some(Self::Accepted),
+                "DECLINED" => Some(Self::Declined),
+                "TENTATIVE" => Some(Self::Tentative),
+                "NEEDS_ACTION" => Some(Self::NeedsAction),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// UCUM units for recording body length measures such as height and head
+/// circumference See <http://hl7.org/fhir/ValueSet/ucum-bodylength>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BodyLengthUnitsValueSet {}
+/// Nested message and enum types in `BodyLengthUnitsValueSet`.
+pub mod body_length_units_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Cm = 1,
+        InI = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Cm => "CM",
+                Self::InI => "IN_I",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CM" => Some(Self::Cm),
+                "IN_I" => Some(Self::InI),
+                _ => None,
+            }
+        }
+    }
+}
+/// UCUM units for recording Body Temperature
+/// See <http://hl7.org/fhir/ValueSet/ucum-bodytemp>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BodyTemperatureUnitsValueSet {}
+/// Nested message and enum types in `BodyTemperatureUnitsValueSet`.
+pub mod body_temperature_units_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Cel = 1,
+        DegF = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Cel => "CEL",
+                Self::DegF => "DEG_F",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CEL" => Some(Self::Cel),
+                "DEG_F" => Some(Self::DegF),
+                _ => None,
+            }
+        }
+    }
+}
+/// UCUM units for recording Body Weight
+/// See <http://hl7.org/fhir/ValueSet/ucum-bodyweight>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BodyWeightUnitsValueSet {}
+/// Nested message and enum types in `BodyWeightUnitsValueSet`.
+pub mod body_weight_units_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Kg = 1,
+        LbAv = 2,
+        G = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Kg => "KG",
+                Self::LbAv => "LB_AV",
+                Self::G => "G",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "KG" => Some(Self::Kg),
+                "LB_AV" => Some(Self::LbAv),
+                "G" => Some(Self::G),
+                _ => None,
+            }
+        }
+    }
+}
+/// Codes indicating the degree of authority/intentionality associated with a
+/// care plan. See <http://hl7.org/fhir/ValueSet/care-plan-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CarePlanIntentValueSet {}
+/// Nested message and enum types in `CarePlanIntentValueSet`.
+pub mod care_plan_intent_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Proposal = 1,
+        Plan = 2,
+        Order = 3,
+        Option = 4,
+        Directive = 5,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Proposal => "PROPOSAL",
+                Self::Plan => "PLAN",
+                Self::Order => "ORDER",
+                Self::Option => "OPTION",
+                Self::Directive => "DIRECTIVE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PROPOSAL" => Some(Self::Proposal),
+                "PLAN" => Some(Self::Plan),
+                "ORDER" => Some(Self::Order),
+                "OPTION" => Some(Self::Option),
+                "DIRECTIVE" => Some(Self::Directive),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes color codes - either the color names as defined in
+/// [CSS4](<https://www.w3.org/TR/css-color-4/>), or any valid RGB code
+/// (```#RRGGBB``` in Hex). See <http://hl7.org/fhir/ValueSet/color-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ColorCodesOrRgbValueSet {}
+/// Nested message and enum types in `ColorCodesOrRGBValueSet`.
+pub mod color_codes_or_rgb_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Aliceblue = 1,
+        Antiquewhite = 2,
+        Aqua = 3,
+        Aquamarine = 4,
+        Azure = 5,
+        Beige = 6,
+        Bisque = 7,
+        Black = 8,
+        Blanchedalmond = 9,
+        Blue = 10,
+        Blueviolet = 11,
+        Brown = 12,
+        Burlywood = 13,
+        Cadetblue = 14,
+        Chartreuse = 15,
+        Chocolate = 16,
+        Coral = 17,
+        Cornflowerblue = 18,
+        Cornsilk = 19,
+        Crimson = 20,
+        Cyan = 21,
+        Darkblue = 22,
+        Darkcyan = 23,
+        Darkgoldenrod = 24,
+        Darkgray = 25,
+        Darkgreen = 26,
+        Darkgrey = 27,
+        Darkkhaki = 28,
+        Darkmagenta = 29,
+        Darkolivegreen = 30,
+        Darkorange = 31,
+        Darkorchid = 32,
+        Darkred = 33,
+        Darksalmon = 34,
+        Darkseagreen = 35,
+        Darkslateblue = 36,
+        Darkslategray = 37,
+        Darkslategrey = 38,
+        Darkturquoise = 39,
+        Darkviolet = 40,
+        Deeppink = 41,
+        Deepskyblue = 42,
+        Dimgray = 43,
+        Dimgrey = 44,
+        Dodgerblue = 45,
+        Firebrick = 46,
+        Floralwhite = 47,
+        Forestgreen = 48,
+        Fuchsia = 49,
+        Gainsboro = 50,
+        Ghostwhite = 51,
+        Gold = 52,
+        Goldenrod = 53,
+        Gray = 54,
+        Green = 55,
+        Greenyellow = 56,
+        Grey = 57,
+        Honeydew = 58,
+        Hotpink = 59,
+        Indianred = 60,
+        Indigo = 61,
+        Ivory = 62,
+        Khaki = 63,
+        Lavender = 64,
+        Lavenderblush = 65,
+        Lawngreen = 66,
+        Lemonchiffon = 67,
+        Lightblue = 68,
+        Lightcoral = 69,
+        Lightcyan = 70,
+        Lightgoldenrodyellow = 71,
+        Lightgray = 72,
+        Lightgreen = 73,
+        Lightgrey = 74,
+        Lightpink = 75,
+        Lightsalmon = 76,
+        Lightseagreen = 77,
+        Lightskyblue = 78,
+        Lightslategray = 79,
+        Lightslategrey = 80,
+        Lightsteelblue = 81,
+        Lightyellow = 82,
+        Lime = 83,
+        Limegreen = 84,
+        Linen = 85,
+        Magenta = 86,
+        Maroon = 87,
+        Mediumaquamarine = 88,
+        Mediumblue = 89,
+        Mediumorchid = 90,
+        Mediumpurple = 91,
+        Mediumseagreen = 92,
+        Mediumslateblue = 93,
+        Mediumspringgreen = 94,
+        Mediumturquoise = 95,
+        Mediumvioletred = 96,
+        Midnightblue = 97,
+        Mintcream = 98,
+        Mistyrose = 99,
+        Moccasin = 100,
+        Navajowhite = 101,
+        Navy = 102,
+        Oldlace = 103,
+        Olive = 104,
+        Olivedrab = 105,
+        Orange = 106,
+        Orangered = 107,
+        Orchid = 108,
+        Palegoldenrod = 109,
+        Palegreen = 110,
+        Paleturquoise = 111,
+        Palevioletred = 112,
+        Papayawhip = 113,
+        Peachpuff = 114,
+        Peru = 115,
+        Pink = 116,
+        Plum = 117,
+        Powderblue = 118,
+        Purple = 119,
+        Rebeccapurple = 120,
+        Red = 121,
+        Rosybrown = 122,
+        Royalblue = 123,
+        Saddlebrown = 124,
+        Salmon = 125,
+        Sandybrown = 126,
+        Seagreen = 127,
+        Seashell = 128,
+        Sienna = 129,
+        Silver = 130,
+        Skyblue = 131,
+        Slateblue = 132,
+        Slategray = 133,
+        Slategrey = 134,
+        Snow = 135,
+        Springgreen = 136,
+        Steelblue = 137,
+        Tan = 138,
+        Teal = 139,
+        Thistle = 140,
+        Tomato = 141,
+        Turquoise = 142,
+        Violet = 143,
+        Wheat = 144,
+        White = 145,
+        Whitesmoke = 146,
+        Yellow = 147,
+        Yellowgreen = 148,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Aliceblue => "ALICEBLUE",
+                Self::Antiquewhite => "ANTIQUEWHITE",
+                Self::Aqua => "AQUA",
+                Self::Aquamarine => "AQUAMARINE",
+                Self::Azure => "AZURE",
+                Self::Beige => "BEIGE",
+                Self::Bisque => "BISQUE",
+                Self::Black => "BLACK",
+                Self::Blanchedalmond => "BLANCHEDALMOND",
+                Self::Blue => "BLUE",
+                Self::Blueviolet => "BLUEVIOLET",
+                Self::Brown => "BROWN",
+                Self::Burlywood => "BURLYWOOD",
+                Self::Cadetblue => "CADETBLUE",
+                Self::Chartreuse => "CHARTREUSE",
+                Self::Chocolate => "CHOCOLATE",
+                Self::Coral => "CORAL",
+                Self::Cornflowerblue => "CORNFLOWERBLUE",
+                Self::Cornsilk => "CORNSILK",
+                Self::Crimson => "CRIMSON",
+                Self::Cyan => "CYAN",
+                Self::Darkblue => "DARKBLUE",
+                Self::Darkcyan => "DARKCYAN",
+                Self::Darkgoldenrod => "DARKGOLDENROD",
+                Self::Darkgray => "DARKGRAY",
+                Self::Darkgreen => "DARKGREEN",
+                Self::Darkgrey => "DARKGREY",
+                Self::Darkkhaki => "DARKKHAKI",
+                Self::Darkmagenta => "DARKMAGENTA",
+                Self::Darkolivegreen => "DARKOLIVEGREEN",
+                Self::Darkorange => "DARKORANGE",
+                Self::Darkorchid => "DARKORCHID",
+                Self::Darkred => "DARKRED",
+                Self::Darksalmon => "DARKSALMON",
+                Self::Darkseagreen => "DARKSEAGREEN",
+                Self::Darkslateblue => "DARKSLATEBLUE",
+                Self::Darkslategray => "DARKSLATEGRAY",
+                Self::Darkslategrey => "DARKSLATEGREY",
+                Self::Darkturquoise => "DARKTURQUOISE",
+                Self::Darkviolet => "DARKVIOLET",
+                Self::Deeppink => "DEEPPINK",
+                Self::Deepskyblue => "DEEPSKYBLUE",
+                Self::Dimgray => "DIMGRAY",
+                Self::Dimgrey => "DIMGREY",
+                Self::Dodgerblue => "DODGERBLUE",
+                Self::Firebrick => "FIREBRICK",
+                Self::Floralwhite => "FLORALWHITE",
+                Self::Forestgreen => "FORESTGREEN",
+                Self::Fuchsia => "FUCHSIA",
+                Self::Gainsboro => "GAINSBORO",
+                Self::Ghostwhite => "GHOSTWHITE",
+                Self::Gold => "GOLD",
+                Self::Goldenrod => "GOLDENROD",
+                Self::Gray => "GRAY",
+                Self::Green => "GREEN",
+                Self::Greenyellow => "GREENYELLOW",
+                Self::Grey => "GREY",
+                Self::Honeydew => "HONEYDEW",
+                Self::Hotpink => "HOTPINK",
+                Self::Indianred => "INDIANRED",
+                Self::Indigo => "INDIGO",
+                Self::Ivory => "IVORY",
+                Self::Khaki => "KHAKI",
+                Self::Lavender => "LAVENDER",
+                Self::Lavenderblush => "LAVENDERBLUSH",
+                Self::Lawngreen => "LAWNGREEN",
+                Self::Lemonchiffon => "LEMONCHIFFON",
+                Self::Lightblue => "LIGHTBLUE",
+                Self::Lightcoral => "LIGHTCORAL",
+                Self::Lightcyan => "LIGHTCYAN",
+                Self::Lightgoldenrodyellow => "LIGHTGOLDENRODYELLOW",
+                Self::Lightgray => "LIGHTGRAY",
+                Self::Lightgreen => "LIGHTGREEN",
+                Self::Lightgrey => "LIGHTGREY",
+                Self::Lightpink => "LIGHTPINK",
+                Self::Lightsalmon => "LIGHTSALMON",
+                Self::Lightseagreen => "LIGHTSEAGREEN",
+                Self::Lightskyblue => "LIGHTSKYBLUE",
+                Self::Lightslategray => "LIGHTSLATEGRAY",
+                Self::Lightslategrey => "LIGHTSLATEGREY",
+                Self::Lightsteelblue => "LIGHTSTEELBLUE",
+                Self::Lightyellow => "LIGHTYELLOW",
+                Self::Lime => "LIME",
+                Self::Limegreen => "LIMEGREEN",
+                Self::Linen => "LINEN",
+                Self::Magenta => "MAGENTA",
+                Self::Maroon => "MAROON",
+                Self::Mediumaquamarine => "MEDIUMAQUAMARINE",
+                Self::Mediumblue => "MEDIUMBLUE",
+                Self::Mediumorchid => "MEDIUMORCHID",
+                Self::Mediumpurple => "MEDIUMPURPLE",
+                Self::Mediumseagreen => "MEDIUMSEAGREEN",
+                Self::Mediumslateblue => "MEDIUMSLATEBLUE",
+                Self::Mediumspringgreen => "MEDIUMSPRINGGREEN",
+                Self::Mediumturquoise => "MEDIUMTURQUOISE",
+                Self::Mediumvioletred => "MEDIUMVIOLETRED",
+                Self::Midnightblue => "MIDNIGHTBLUE",
+                Self::Mintcream => "MINTCREAM",
+                Self::Mistyrose => "MISTYROSE",
+                Self::Moccasin => "MOCCASIN",
+                Self::Navajowhite => "NAVAJOWHITE",
+                Self::Navy => "NAVY",
+                Self::Oldlace => "OLDLACE",
+                Self::Olive => "OLIVE",
+                Self::Olivedrab => "OLIVEDRAB",
+                Self::Orange => "ORANGE",
+                Self::Orangered => "ORANGERED",
+                Self::Orchid => "ORCHID",
+                Self::Palegoldenrod => "PALEGOLDENROD",
+                Self::Palegreen => "PALEGREEN",
+                Self::Paleturquoise => "PALETURQUOISE",
+                Self::Palevioletred => "PALEVIOLETRED",
+                Self::Papayawhip => "PAPAYAWHIP",
+                Self::Peachpuff => "PEACHPUFF",
+                Self::Peru => "PERU",
+                Self::Pink => "PINK",
+                Self::Plum => "PLUM",
+                Self::Powderblue => "POWDERBLUE",
+                Self::Purple => "PURPLE",
+                Self::Rebeccapurple => "REBECCAPURPLE",
+                Self::Red => "RED",
+                Self::Rosybrown => "ROSYBROWN",
+                Self::Royalblue => "ROYALBLUE",
+                Self::Saddlebrown => "SADDLEBROWN",
+                Self::Salmon => "SALMON",
+                Self::Sandybrown => "SANDYBROWN",
+                Self::Seagreen => "SEAGREEN",
+                Self::Seashell => "SEASHELL",
+                Self::Sienna => "SIENNA",
+                Self::Silver => "SILVER",
+                Self::Skyblue => "SKYBLUE",
+                Self::Slateblue => "SLATEBLUE",
+                Self::Slategray => "SLATEGRAY",
+                Self::Slategrey => "SLATEGREY",
+                Self::Snow => "SNOW",
+                Self::Springgreen => "SPRINGGREEN",
+                Self::Steelblue => "STEELBLUE",
+                Self::Tan => "TAN",
+                Self::Teal => "TEAL",
+                Self::Thistle => "THISTLE",
+                Self::Tomato => "TOMATO",
+                Self::Turquoise => "TURQUOISE",
+                Self::Violet => "VIOLET",
+                Self::Wheat => "WHEAT",
+                Self::White => "WHITE",
+                Self::Whitesmoke => "WHITESMOKE",
+                Self::Yellow => "YELLOW",
+                Self::Yellowgreen => "YELLOWGREEN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ALICEBLUE" => Some(Self::Aliceblue),
+                "ANTIQUEWHITE" => Some(Self::Antiquewhite),
+                "AQUA" => Some(Self::Aqua),
+                "AQUAMARINE" => Some(Self::Aquamarine),
+                "AZURE" => Some(Self::Azure),
+                "BEIGE" => Some(Self::Beige),
+                "BISQUE" => Some(Self::Bisque),
+                "BLACK" => Some(Self::Black),
+                "BLANCHEDALMOND" => Some(Self::Blanchedalmond),
+                "BLUE" => Some(Self::Blue),
+                "BLUEVIOLET" => Some(Self::Blueviolet),
+                "BROWN" => Some(Self::Brown),
+                "BURLYWOOD" => Some(Self::Burlywood),
+                "CADETBLUE" => Some(Self::Cadetblue),
+                "CHARTREUSE" => Some(Self::Chartreuse),
+                "CHOCOLATE" => Some(Self::Chocolate),
+                "CORAL" => Some(Self::Coral),
+                "CORNFLOWERBLUE" => Some(Self::Cornflowerblue),
+                "CORNSILK" => Some(Self::Cornsilk),
+                "CRIMSON" => Some(Self::Crimson),
+                "CYAN" => Some(Self::Cyan),
+                "DARKBLUE" => Some(Self::Darkblue),
+                "DARKCYAN" => Some(Self::Darkcyan),
+                "DARKGOLDENROD" => Some(Self::Darkgoldenrod),
+                "DARKGRAY" => Some(Self::Darkgray),
+                "DARKGREEN" => Some(Self::Darkgreen),
+                "DARKGREY" => Some(Self::Darkgrey),
+                "DARKKHAKI" => Some(Self::Darkkhaki),
+                "DARKMAGENTA" => Some(Self::Darkmagenta),
+                "DARKOLIVEGREEN" => Some(Self::Darkolivegreen),
+                "DARKORANGE" => Some(Self::Darkorange),
+                "DARKORCHID" => Some(Self::Darkorchid),
+                "DARKRED" => Some(Self::Darkred),
+                "DARKSALMON" => Some(Self::Darksalmon),
+                "DARKSEAGREEN" => Some(Self::Darkseagreen),
+                "DARKSLATEBLUE" => Some(Self::Darkslateblue),
+                "DARKSLATEGRAY" => Some(Self::Darkslategray),
+                "DARKSLATEGREY" => Some(Self::Darkslategrey),
+                "DARKTURQUOISE" => Some(Self::Darkturquoise),
+                "DARKVIOLET" => Some(Self::Darkviolet),
+                "DEEPPINK" => Some(Self::Deeppink),
+                "DEEPSKYBLUE" => Some(Self::Deepskyblue),
+                "DIMGRAY" => Some(Self::Dimgray),
+                "DIMGREY" => Some(Self::Dimgrey),
+                "DODGERBLUE" => Some(Self::Dodgerblue),
+                "FIREBRICK" => Some(Self::Firebrick),
+                "FLORALWHITE" => Some(Self::Floralwhite),
+                "FORESTGREEN" => Some(Self::Forestgreen),
+                "FUCHSIA" => Some(Self::Fuchsia),
+                "GAINSBORO" => Some(Self::Gainsboro),
+                "GHOSTWHITE" => Some(Self::Ghostwhite),
+                "GOLD" => Some(Self::Gold),
+                "GOLDENROD" => Some(Self::Goldenrod),
+                "GRAY" => Some(Self::Gray),
+                "GREEN" => Some(Self::Green),
+                "GREENYELLOW" => Some(Self::Greenyellow),
+                "GREY" => Some(Self::Grey),
+                "HONEYDEW" => Some(Self::Honeydew),
+                "HOTPINK" => Some(Self::Hotpink),
+                "INDIANRED" => Some(Self::Indianred),
+                "INDIGO" => Some(Self::Indigo),
+                "IVORY" => Some(Self::Ivory),
+                "KHAKI" => Some(Self::Khaki),
+                "LAVENDER" => Some(Self::Lavender),
+                "LAVENDERBLUSH" => Some(Self::Lavenderblush),
+                "LAWNGREEN" => Some(Self::Lawngreen),
+                "LEMONCHIFFON" => Some(Self::Lemonchiffon),
+                "LIGHTBLUE" => Some(Self::Lightblue),
+                "LIGHTCORAL" => Some(Self::Lightcoral),
+                "LIGHTCYAN" => Some(Self::Lightcyan),
+                "LIGHTGOLDENRODYELLOW" => Some(Self::Lightgoldenrodyellow),
+                "LIGHTGRAY" => Some(Self::Lightgray),
+                "LIGHTGREEN" => Some(Self::Lightgreen),
+                "LIGHTGREY" => Some(Self::Lightgrey),
+                "LIGHTPINK" => Some(Self::Lightpink),
+                "LIGHTSALMON" => Some(Self::Lightsalmon),
+                "LIGHTSEAGREEN" => Some(Self::Lightseagreen),
+                "LIGHTSKYBLUE" => Some(Self::Lightskyblue),
+                "LIGHTSLATEGRAY" => Some(Self::Lightslategray),
+                "LIGHTSLATEGREY" => Some(Self::Lightslategrey),
+                "LIGHTSTEELBLUE" => Some(Self::Lightsteelblue),
+                "LIGHTYELLOW" => Some(Self::Lightyellow),
+                "LIME" => Some(Self::Lime),
+                "LIMEGREEN" => Some(Self::Limegreen),
+                "LINEN" => Some(Self::Linen),
+                "MAGENTA" => Some(Self::Magenta),
+                "MAROON" => Some(Self::Maroon),
+                "MEDIUMAQUAMARINE" => Some(Self::Mediumaquamarine),
+                "MEDIUMBLUE" => Some(Self::Mediumblue),
+                "MEDIUMORCHID" => Some(Self::Mediumorchid),
+                "MEDIUMPURPLE" => Some(Self::Mediumpurple),
+                "MEDIUMSEAGREEN" => Some(Self::Mediumseagreen),
+                "MEDIUMSLATEBLUE" => Some(Self::Mediumslateblue),
+                "MEDIUMSPRINGGREEN" => Some(Self::Mediumspringgreen),
+                "MEDIUMTURQUOISE" => Some(Self::Mediumturquoise),
+                "MEDIUMVIOLETRED" => Some(Self::Mediumvioletred),
+                "MIDNIGHTBLUE" => Some(Self::Midnightblue),
+                "MINTCREAM" => Some(Self::Mintcream),
+                "MISTYROSE" => Some(Self::Mistyrose),
+                "MOCCASIN" => Some(Self::Moccasin),
+                "NAVAJOWHITE" => Some(Self::Navajowhite),
+                "NAVY" => Some(Self::Navy),
+                "OLDLACE" => Some(Self::Oldlace),
+                "OLIVE" => Some(Self::Olive),
+                "OLIVEDRAB" => Some(Self::Olivedrab),
+                "ORANGE" => Some(Self::Orange),
+                "ORANGERED" => Some(Self::Orangered),
+                "ORCHID" => Some(Self::Orchid),
+                "PALEGOLDENROD" => Some(Self::Palegoldenrod),
+                "PALEGREEN" => Some(Self::Palegreen),
+                "PALETURQUOISE" => Some(Self::Paleturquoise),
+                "PALEVIOLETRED" => Some(Self::Palevioletred),
+                "PAPAYAWHIP" => Some(Self::Papayawhip),
+                "PEACHPUFF" => Some(Self::Peachpuff),
+                "PERU" => Some(Self::Peru),
+                "PINK" => Some(Self::Pink),
+                "PLUM" => Some(Self::Plum),
+                "POWDERBLUE" => Some(Self::Powderblue),
+                "PURPLE" => Some(Self::Purple),
+                "REBECCAPURPLE" => Some(Self::Rebeccapurple),
+                "RED" => Some(Self::Red),
+                "ROSYBROWN" => Some(Self::Rosybrown),
+                "ROYALBLUE" => Some(Self::Royalblue),
+                "SADDLEBROWN" => Some(Self::Saddlebrown),
+                "SALMON" => Some(Self::Salmon),
+                "SANDYBROWN" => Some(Self::Sandybrown),
+                "SEAGREEN" => Some(Self::Seagreen),
+                "SEASHELL" => Some(Self::Seashell),
+                "SIENNA" => Some(Self::Sienna),
+                "SILVER" => Some(Self::Silver),
+                "SKYBLUE" => Some(Self::Skyblue),
+                "SLATEBLUE" => Some(Self::Slateblue),
+                "SLATEGRAY" => Some(Self::Slategray),
+                "SLATEGREY" => Some(Self::Slategrey),
+                "SNOW" => Some(Self::Snow),
+                "SPRINGGREEN" => Some(Self::Springgreen),
+                "STEELBLUE" => Some(Self::Steelblue),
+                "TAN" => Some(Self::Tan),
+                "TEAL" => Some(Self::Teal),
+                "THISTLE" => Some(Self::Thistle),
+                "TOMATO" => Some(Self::Tomato),
+                "TURQUOISE" => Some(Self::Turquoise),
+                "VIOLET" => Some(Self::Violet),
+                "WHEAT" => Some(Self::Wheat),
+                "WHITE" => Some(Self::White),
+                "WHITESMOKE" => Some(Self::Whitesmoke),
+                "YELLOW" => Some(Self::Yellow),
+                "YELLOWGREEN" => Some(Self::Yellowgreen),
+                _ => None,
+            }
+        }
+    }
+}
+/// This value set includes common codes from BCP-47 (see
+/// <http://tools.ietf.org/html/bcp47>) See <http://hl7.org/fhir/ValueSet/languages>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CommonLanguagesValueSet {}
+/// Nested message and enum types in `CommonLanguagesValueSet`.
+pub mod common_languages_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Ar = 1,
+        Bg = 2,
+        BgBg = 3,
+        Bn = 4,
+        Cs = 5,
+        CsCz = 6,
+        Bs = 7,
+        BsBa = 8,
+        Da = 9,
+        DaDk = 10,
+        De = 11,
+        DeAt = 12,
+        DeCh = 13,
+        DeDe = 14,
+        El = 15,
+        ElGr = 16,
+        En = 17,
+        EnAu = 18,
+        EnCa = 19,
+        EnGb = 20,
+        EnIn = 21,
+        EnNz = 22,
+        EnSg = 23,
+        EnUs = 24,
+        Es = 25,
+        EsAr = 26,
+        EsEs = 27,
+        EsUy = 28,
+        Et = 29,
+        EtEe = 30,
+        Fi = 31,
+        Fr = 32,
+        FrBe = 33,
+        FrCh = 34,
+        FrFr = 35,
+        FiFi = 36,
+        FrCa = 37,
+        Fy = 38,
+        FyNl = 39,
+        Hi = 40,
+        Hr = 41,
+        HrHr = 42,
+        Is = 43,
+        IsIs = 44,
+        It = 45,
+        ItCh = 46,
+        ItIt = 47,
+        Ja = 48,
+        Ko = 49,
+        Lt = 50,
+        LtLt = 51,
+        Lv = 52,
+        LvLv = 53,
+        Nl = 54,
+        NlBe = 55,
+        NlNl = 56,
+        No = 57,
+        NoNo = 58,
+        Pa = 59,
+        Pl = 60,
+        PlPl = 61,
+        Pt = 62,
+        PtPt = 63,
+        PtBr = 64,
+        Ro = 65,
+        RoRo = 66,
+        Ru = 67,
+        RuRu = 68,
+        Sk = 69,
+        SkSk = 70,
+        Sl = 71,
+        SlSi = 72,
+        Sr = 73,
+        SrRs = 74,
+        Sv = 75,
+        SvSe = 76,
+        Te = 77,
+        Zh = 78,
+        ZhCn = 79,
+        ZhHk = 80,
+        ZhSg = 81,
+        ZhTw = 82,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Ar => "AR",
+                Self::Bg => "BG",
+                Self::BgBg => "BG_BG",
+                Self::Bn => "BN",
+                Self::Cs => "CS",
+                Self::CsCz => "CS_CZ",
+                Self::Bs => "BS",
+                Self::BsBa => "BS_BA",
+                Self::Da => "DA",
+                Self::DaDk => "DA_DK",
+                Self::De => "DE",
+                Self::DeAt => "DE_AT",
+                Self::DeCh => "DE_CH",
+                Self::DeDe => "DE_DE",
+                Self::El => "EL",
+                Self::ElGr => "EL_GR",
+                Self::En => "EN",
+                Self::EnAu => "EN_AU",
+                Self::EnCa => "EN_CA",
+                Self::EnGb => "EN_GB",
+                Self::EnIn => "EN_IN",
+                Self::EnNz => "EN_NZ",
+                Self::EnSg => "EN_SG",
+                Self::EnUs => "EN_US",
+                Self::Es => "ES",
+                Self::EsAr => "ES_AR",
+                Self::EsEs => "ES_ES",
+                Self::EsUy => "ES_UY",
+                Self::Et => "ET",
+                Self::EtEe => "ET_EE",
+                Self::Fi => "FI",
+                Self::Fr => "FR",
+                Self::FrBe => "FR_BE",
+                Self::FrCh => "FR_CH",
+                Self::FrFr => "FR_FR",
+                Self::FiFi => "FI_FI",
+                Self::FrCa => "FR_CA",
+                Self::Fy => "FY",
+                Self::FyNl => "FY_NL",
+                Self::Hi => "HI",
+                Self::Hr => "HR",
+                Self::HrHr => "HR_HR",
+                Self::Is => "IS",
+                Self::IsIs => "IS_IS",
+                Self::It => "IT",
+                Self::ItCh => "IT_CH",
+                Self::ItIt => "IT_IT",
+                Self::Ja => "JA",
+                Self::Ko => "KO",
+                Self::Lt => "LT",
+                Self::LtLt => "LT_LT",
+                Self::Lv => "LV",
+                Self::LvLv => "LV_LV",
+                Self::Nl => "NL",
+                Self::NlBe => "NL_BE",
+                Self::NlNl => "NL_NL",
+                Self::No => "NO",
+                Self::NoNo => "NO_NO",
+                Self::Pa => "PA",
+                Self::Pl => "PL",
+                Self::PlPl => "PL_PL",
+                Self::Pt => "PT",
+                Self::PtPt => "PT_PT",
+                Self::PtBr => "PT_BR",
+                Self::Ro => "RO",
+                Self::RoRo => "RO_RO",
+                Self::Ru => "RU",
+                Self::RuRu => "RU_RU",
+                Self::Sk => "SK",
+                Self::SkSk => "SK_SK",
+                Self::Sl => "SL",
+                Self::SlSi => "SL_SI",
+                Self::Sr => "SR",
+                Self::SrRs => "SR_RS",
+                Self::Sv => "SV",
+                Self::SvSe => "SV_SE",
+                Self::Te => "TE",
+                Self::Zh => "ZH",
+                Self::ZhCn => "ZH_CN",
+                Self::ZhHk => "ZH_HK",
+                Self::ZhSg => "ZH_SG",
+                Self::ZhTw => "ZH_TW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "AR" => Some(Self::Ar),
+                "BG" => Some(Self::Bg),
+                "BG_BG" => Some(Self::BgBg),
+                "BN" => Some(Self::Bn),
+                "CS" => Some(Self::Cs),
+                "CS_CZ" => Some(Self::CsCz),
+                "BS" => Some(Self::Bs),
+                "BS_BA" => Some(Self::BsBa),
+                "DA" => Some(Self::Da),
+                "DA_DK" => Some(Self::DaDk),
+                "DE" => Some(Self::De),
+                "DE_AT" => Some(Self::DeAt),
+                "DE_CH" => Some(Self::DeCh),
+                "DE_DE" => Some(Self::DeDe),
+                "EL" => Some(Self::El),
+                "EL_GR" => Some(Self::ElGr),
+                "EN" => Some(Self::En),
+                "EN_AU" => Some(Self::EnAu),
+                "EN_CA" => Some(Self::EnCa),
+                "EN_GB" => Some(Self::EnGb),
+                "EN_IN" => Some(Self::EnIn),
+                "EN_NZ" => Some(Self::EnNz),
+                "EN_SG" => Some(Self::EnSg),
+                "EN_US" => Some(Self::EnUs),
+                "ES" => Some(Self::Es),
+                "ES_AR" => Some(Self::EsAr),
+                "ES_ES" => Some(Self::EsEs),
+                "ES_UY" => Some(Self::EsUy),
+                "ET" => Some(Self::Et),
+                "ET_EE" => Some(Self::EtEe),
+                "FI" => Some(Self::Fi),
+                "FR" => Some(Self::Fr),
+                "FR_BE" => Some(Self::FrBe),
+                "FR_CH" => Some(Self::FrCh),
+                "FR_FR" => Some(Self::FrFr),
+                "FI_FI" => Some(Self::FiFi),
+                "FR_CA" => Some(Self::FrCa),
+                "FY" => Some(Self::Fy),
+                "FY_NL" => Some(Self::FyNl),
+                "HI" => Some(Self::Hi),
+                "HR" => Some(Self::Hr),
+                "HR_HR" => Some(Self::HrHr),
+                "IS" => Some(Self::Is),
+                "IS_IS" => Some(Self::IsIs),
+                "IT" => Some(Self::It),
+                "IT_CH" => Some(Self::ItCh),
+                "IT_IT" => Some(Self::ItIt),
+                "JA" => Some(Self::Ja),
+                "KO" => Some(Self::Ko),
+                "LT" => Some(Self::Lt),
+                "LT_LT" => Some(Self::LtLt),
+                "LV" => Some(Self::Lv),
+                "LV_LV" => Some(Self::LvLv),
+                "NL" => Some(Self::Nl),
+                "NL_BE" => Some(Self::NlBe),
+                "NL_NL" => Some(Self::NlNl),
+                "NO" => Some(Self::No),
+                "NO_NO" => Some(Self::NoNo),
+                "PA" => Some(Self::Pa),
+                "PL" => Some(Self::Pl),
+                "PL_PL" => Some(Self::PlPl),
+                "PT" => Some(Self::Pt),
+                "PT_PT" => Some(Self::PtPt),
+                "PT_BR" => Some(Self::PtBr),
+                "RO" => Some(Self::Ro),
+                "RO_RO" => Some(Self::RoRo),
+                "RU" => Some(Self::Ru),
+                "RU_RU" => Some(Self::RuRu),
+                "SK" => Some(Self::Sk),
+                "SK_SK" => Some(Self::SkSk),
+                "SL" => Some(Self::Sl),
+                "SL_SI" => Some(Self::SlSi),
+                "SR" => Some(Self::Sr),
+                "SR_RS" => Some(Self::SrRs),
+                "SV" => Some(Self::Sv),
+                "SV_SE" => Some(Self::SvSe),
+                "TE" => Some(Self::Te),
+                "ZH" => Some(Self::Zh),
+                "ZH_CN" => Some(Self::ZhCn),
+                "ZH_HK" => Some(Self::ZhHk),
+                "ZH_SG" => Some(Self::ZhSg),
+                "ZH_TW" => Some(Self::ZhTw),
+                _ => None,
+            }
+        }
+    }
+}
+/// Indicates the status of a detected issue
+/// See <http://hl7.org/fhir/ValueSet/detectedissue-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DetectedIssueStatusValueSet {}
+/// Nested message and enum types in `DetectedIssueStatusValueSet`.
+pub mod detected_issue_status_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Preliminary = 1,
+        Final = 2,
+        EnteredInError = 3,
+        Mitigated = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Preliminary => "PRELIMINARY",
+                Self::Final => "FINAL",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::Mitigated => "MITIGATED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PRELIMINARY" => Some(Self::Preliminary),
+                "FINAL" => Some(Self::Final),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "MITIGATED" => Some(Self::Mitigated),
+                _ => None,
+            }
+        }
+    }
+}
+/// Real-world event relating to the schedule.
+/// See <http://hl7.org/fhir/ValueSet/event-timing>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EventTimingValueSet {}
+/// Nested message and enum types in `EventTimingValueSet`.
+pub mod event_timing_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Morn = 1,
+        MornEarly = 2,
+        MornLate = 3,
+        Noon = 4,
+        Aft = 5,
+        AftEarly = 6,
+        AftLate = 7,
+        Eve = 8,
+        EveEarly = 9,
+        EveLate = 10,
+        Night = 11,
+        Phs = 12,
+        Imd = 27,
+        Hs = 13,
+        Wake = 14,
+        C = 15,
+        Cm = 16,
+        Cd = 17,
+        Cv = 18,
+        Ac = 19,
+        Acm = 20,
+        Acd = 21,
+        Acv = 22,
+        Pc = 23,
+        Pcm = 24,
+        Pcd = 25,
+        Pcv = 26,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Morn => "MORN",
+                Self::MornEarly => "MORN_EARLY",
+                Self::MornLate => "MORN_LATE",
+                Self::Noon => "NOON",
+                Self::Aft => "AFT",
+                Self::AftEarly => "AFT_EARLY",
+                Self::AftLate => "AFT_LATE",
+                Self::Eve => "EVE",
+                Self::EveEarly => "EVE_EARLY",
+                Self::EveLate => "EVE_LATE",
+                Self::Night => "NIGHT",
+                Self::Phs => "PHS",
+                Self::Imd => "IMD",
+                Self::Hs => "HS",
+                Self::Wake => "WAKE",
+                Self::C => "C",
+                Self::Cm => "CM",
+                Self::Cd => "CD",
+                Self::Cv => "CV",
+                Self::Ac => "AC",
+                Self::Acm => "ACM",
+                Self::Acd => "ACD",
+                Self::Acv => "ACV",
+                Self::Pc => "PC",
+                Self::Pcm => "PCM",
+                Self::Pcd => "PCD",
+                Self::Pcv => "PCV",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "MORN" => Some(Self::Morn),
+                "MORN_EARLY" => Some(Self::MornEarly),
+                "MORN_LATE" => Some(Self::MornLate),
+                "NOON" => Some(Self::Noon),
+                "AFT" => Some(Self::Aft),
+                "AFT_EARLY" => Some(Self::AftEarly),
+                "AFT_LATE" => Some(Self::AftLate),
+                "EVE" => Some(Self::Eve),
+                "EVE_EARLY" => Some(Self::EveEarly),
+                "EVE_LATE" => Some(Self::EveLate),
+                "NIGHT" => Some(Self::Night),
+                "PHS" => Some(Self::Phs),
+                "IMD" => Some(Self::Imd),
+                "HS" => Some(Self::Hs),
+                "WAKE" => Some(Self::Wake),
+                "C" => Some(Self::C),
+                "CM" => Some(Self::Cm),
+                "CD" => Some(Self::Cd),
+                "CV" => Some(Self::Cv),
+                "AC" => Some(Self::Ac),
+                "ACM" => Some(Self::Acm),
+                "ACD" => Some(Self::Acd),
+                "ACV" => Some(Self::Acv),
+                "PC" => Some(Self::Pc),
+                "PCM" => Some(Self::Pcm),
+                "PCD" => Some(Self::Pcd),
+                "PCV" => Some(Self::Pcv),
+                _ => None,
+            }
+        }
+    }
+}
+/// The value set to instantiate this attribute should be drawn from a
+/// terminologically robust code system that consists of or contains concepts to
+/// support describing the current status of the evaluation for vaccine
+/// administration event. See
+/// <http://hl7.org/fhir/ValueSet/immunization-evaluation-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ImmunizationEvaluationStatusCodesValueSet {}
+/// Nested message and enum types in `ImmunizationEvaluationStatusCodesValueSet`.
+pub mod immunization_evaluation_status_codes_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Completed = 1,
+        EnteredInError = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                _ => None,
+            }
+        }
+    }
+}
+/// The value set to instantiate this attribute should be drawn from a
+/// terminologically robust code system that consists of or contains concepts to
+/// support describing the current status of the administered dose of vaccine.
+/// See <http://hl7.org/fhir/ValueSet/immunization-status>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ImmunizationStatusCodesValueSet {}
+/// Nested message and enum types in `ImmunizationStatusCodesValueSet`.
+pub mod immunization_status_codes_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Completed = 1,
+        EnteredInError = 2,
+        NotDone = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Completed => "COMPLETED",
+                Self::EnteredInError => "ENTERED_IN_ERROR",
+                Self::NotDone => "NOT_DONE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "COMPLETED" => Some(Self::Completed),
+                "ENTERED_IN_ERROR" => Some(Self::EnteredInError),
+                "NOT_DONE" => Some(Self::NotDone),
+                _ => None,
+            }
+        }
+    }
+}
+/// FHIR RESTful interaction codes used for SubscriptionTopic trigger.
+/// See <http://hl7.org/fhir/ValueSet/interaction-trigger>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InteractionTriggerValueSet {}
+/// Nested message and enum types in `InteractionTriggerValueSet`.
+pub mod interaction_trigger_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Create = 1,
+        Update = 2,
+        Delete = 3,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Create => "CREATE",
+                Self::Update => "UPDATE",
+                Self::Delete => "DELETE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "CREATE" => Some(Self::Create),
+                "UPDATE" => Some(Self::Update),
+                "DELETE" => Some(Self::Delete),
+                _ => None,
+            }
+        }
+    }
+}
+/// LDL Cholesterol codes - measured or calculated
+/// See <http://hl7.org/fhir/ValueSet/lipid-ldl-codes>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LdlCodesValueSet {}
+/// Nested message and enum types in `LDLCodesValueSet`.
+pub mod ldl_codes_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        LdlCholesterolAssay = 1,
+        CholesterolInLdlMassVolumeInSerumOrPlasmaByCalculation = 2,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::LdlCholesterolAssay => "LDL_CHOLESTEROL_ASSAY",
+                Self::CholesterolInLdlMassVolumeInSerumOrPlasmaByCalculation => {
+                    "CHOLESTEROL_IN_LDL_MASS_VOLUME_IN_SERUM_OR_PLASMA_BY_CALCULATION"
+                }
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "LDL_CHOLESTEROL_ASSAY" => Some(Self::LdlCholesterolAssay),
+                "CHOLESTEROL_IN_LDL_MASS_VOLUME_IN_SERUM_OR_PLASMA_BY_CALCULATION" => {
+                    Some(Self::CholesterolInLdlMassVolumeInSerumOrPlasmaByCalculation)
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of relationship to the cited artifact.
+/// See <http://hl7.org/fhir/ValueSet/related-artifact-type-all>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RelatedArtifactTypeExpandedValueSet {}
+/// Nested message and enum types in `RelatedArtifactTypeExpandedValueSet`.
+pub mod related_artifact_type_expanded_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Documentation = 1,
+        Justification = 2,
+        Citation = 3,
+        Predecessor = 4,
+        Successor = 5,
+        DerivedFrom = 6,
+        DependsOn = 7,
+        ComposedOf = 8,
+        PartOf = 9,
+        Amends = 10,
+        AmendedWith = 11,
+        Appends = 12,
+        AppendedWith = 13,
+        Cites = 14,
+        CitedBy = 15,
+        CommentsOn = 16,
+        CommentIn = 17,
+        Contains = 18,
+        ContainedIn = 19,
+        Corrects = 20,
+        CorrectionIn = 21,
+        Replaces = 22,
+        ReplacedWith = 23,
+        Retracts = 24,
+        RetractedBy = 25,
+        Signs = 26,
+        SimilarTo = 27,
+        Supports = 28,
+        SupportedWith = 29,
+        Transforms = 30,
+        TransformedInto = 31,
+        TransformedWith = 32,
+        Documents = 33,
+        SpecificationOf = 34,
+        CreatedWith = 35,
+        CiteAs = 36,
+        Reprint = 37,
+        ReprintOf = 38,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Documentation => "DOCUMENTATION",
+                Self::Justification => "JUSTIFICATION",
+                Self::Citation => "CITATION",
+                Self::Predecessor => "PREDECESSOR",
+                Self::Successor => "SUCCESSOR",
+                Self::DerivedFrom => "DERIVED_FROM",
+                Self::DependsOn => "DEPENDS_ON",
+                Self::ComposedOf => "COMPOSED_OF",
+                Self::PartOf => "PART_OF",
+                Self::Amends => "AMENDS",
+                Self::AmendedWith => "AMENDED_WITH",
+                Self::Appends => "APPENDS",
+                Self::AppendedWith => "APPENDED_WITH",
+                Self::Cites => "CITES",
+                Self::CitedBy => "CITED_BY",
+                Self::CommentsOn => "COMMENTS_ON",
+                Self::CommentIn => "COMMENT_IN",
+                Self::Contains => "CONTAINS",
+                Self::ContainedIn => "CONTAINED_IN",
+                Self::Corrects => "CORRECTS",
+                Self::CorrectionIn => "CORRECTION_IN",
+                Self::Replaces => "REPLACES",
+                Self::ReplacedWith => "REPLACED_WITH",
+                Self::Retracts => "RETRACTS",
+                Self::RetractedBy => "RETRACTED_BY",
+                Self::Signs => "SIGNS",
+                Self::SimilarTo => "SIMILAR_TO",
+                Self::Supports => "SUPPORTS",
+                Self::SupportedWith => "SUPPORTED_WITH",
+                Self::Transforms => "TRANSFORMS",
+                Self::TransformedInto => "TRANSFORMED_INTO",
+                Self::TransformedWith => "TRANSFORMED_WITH",
+                Self::Documents => "DOCUMENTS",
+                Self::SpecificationOf => "SPECIFICATION_OF",
+                Self::CreatedWith => "CREATED_WITH",
+                Self::CiteAs => "CITE_AS",
+                Self::Reprint => "REPRINT",
+                Self::ReprintOf => "REPRINT_OF",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "DOCUMENTATION" => Some(Self::Documentation),
+                "JUSTIFICATION" => Some(Self::Justification),
+                "CITATION" => Some(Self::Citation),
+                "PREDECESSOR" => Some(Self::Predecessor),
+                "SUCCESSOR" => Some(Self::Successor),
+                "DERIVED_FROM" => Some(Self::DerivedFrom),
+                "DEPENDS_ON" => Some(Self::DependsOn),
+                "COMPOSED_OF" => Some(Self::ComposedOf),
+                "PART_OF" => Some(Self::PartOf),
+                "AMENDS" => Some(Self::Amends),
+                "AMENDED_WITH" => Some(Self::AmendedWith),
+                "APPENDS" => Some(Self::Appends),
+                "APPENDED_WITH" => Some(Self::AppendedWith),
+                "CITES" => Some(Self::Cites),
+                "CITED_BY" => Some(Self::CitedBy),
+                "COMMENTS_ON" => Some(Self::CommentsOn),
+                "COMMENT_IN" => Some(Self::CommentIn),
+                "CONTAINS" => Some(Self::Contains),
+                "CONTAINED_IN" => Some(Self::ContainedIn),
+                "CORRECTS" => Some(Self::Corrects),
+                "CORRECTION_IN" => Some(Self::CorrectionIn),
+                "REPLACES" => Some(Self::Replaces),
+                "REPLACED_WITH" => Some(Self::ReplacedWith),
+                "RETRACTS" => Some(Self::Retracts),
+                "RETRACTED_BY" => Some(Self::RetractedBy),
+                "SIGNS" => Some(Self::Signs),
+                "SIMILAR_TO" => Some(Self::SimilarTo),
+                "SUPPORTS" => Some(Self::Supports),
+                "SUPPORTED_WITH" => Some(Self::SupportedWith),
+                "TRANSFORMS" => Some(Self::Transforms),
+                "TRANSFORMED_INTO" => Some(Self::TransformedInto),
+                "TRANSFORMED_WITH" => Some(Self::TransformedWith),
+                "DOCUMENTS" => Some(Self::Documents),
+                "SPECIFICATION_OF" => Some(Self::SpecificationOf),
+                "CREATED_WITH" => Some(Self::CreatedWith),
+                "CITE_AS" => Some(Self::CiteAs),
+                "REPRINT" => Some(Self::Reprint),
+                "REPRINT_OF" => Some(Self::ReprintOf),
+                _ => None,
+            }
+        }
+    }
+}
+/// All Resource Types that represent request resources
+/// See <http://hl7.org/fhir/ValueSet/request-resource-types>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RequestResourceTypesValueSet {}
+/// Nested message and enum types in `RequestResourceTypesValueSet`.
+pub mod request_resource_types_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Appointment = 1,
+        AppointmentResponse = 2,
+        CarePlan = 3,
+        Claim = 4,
+        CommunicationRequest = 5,
+        CoverageEligibilityRequest = 16,
+        DeviceRequest = 7,
+        EnrollmentRequest = 8,
+        ImmunizationRecommendation = 9,
+        MedicationRequest = 10,
+        NutritionOrder = 11,
+        RequestOrchestration = 17,
+        ServiceRequest = 12,
+        SupplyRequest = 13,
+        Task = 14,
+        Transport = 18,
+        VisionPrescription = 15,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Appointment => "APPOINTMENT",
+                Self::AppointmentResponse => "APPOINTMENT_RESPONSE",
+                Self::CarePlan => "CARE_PLAN",
+                Self::Claim => "CLAIM",
+                Self::CommunicationRequest => "COMMUNICATION_REQUEST",
+                Self::CoverageEligibilityRequest => "COVERAGE_ELIGIBILITY_REQUEST",
+                Self::DeviceRequest => "DEVICE_REQUEST",
+                Self::EnrollmentRequest => "ENROLLMENT_REQUEST",
+                Self::ImmunizationRecommendation => "IMMUNIZATION_RECOMMENDATION",
+                Self::MedicationRequest => "MEDICATION_REQUEST",
+                Self::NutritionOrder => "NUTRITION_ORDER",
+                Self::RequestOrchestration => "REQUEST_ORCHESTRATION",
+                Self::ServiceRequest => "SERVICE_REQUEST",
+                Self::SupplyRequest => "SUPPLY_REQUEST",
+                Self::Task => "TASK",
+                Self::Transport => "TRANSPORT",
+                Self::VisionPrescription => "VISION_PRESCRIPTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "APPOINTMENT" => Some(Self::Appointment),
+                "APPOINTMENT_RESPONSE" => Some(Self::AppointmentResponse),
+                "CARE_PLAN" => Some(Self::CarePlan),
+                "CLAIM" => Some(Self::Claim),
+                "COMMUNICATION_REQUEST" => Some(Self::CommunicationRequest),
+                "COVERAGE_ELIGIBILITY_REQUEST" => Some(Self::CoverageEligibilityRequest),
+                "DEVICE_REQUEST" => Some(Self::DeviceRequest),
+                "ENROLLMENT_REQUEST" => Some(Self::EnrollmentRequest),
+                "IMMUNIZATION_RECOMMENDATION" => Some(Self::ImmunizationRecommendation),
+                "MEDICATION_REQUEST" => Some(Self::MedicationRequest),
+                "NUTRITION_ORDER" => Some(Self::NutritionOrder),
+                "REQUEST_ORCHESTRATION" => Some(Self::RequestOrchestration),
+                "SERVICE_REQUEST" => Some(Self::ServiceRequest),
+                "SUPPLY_REQUEST" => Some(Self::SupplyRequest),
+                "TASK" => Some(Self::Task),
+                "TRANSPORT" => Some(Self::Transport),
+                "VISION_PRESCRIPTION" => Some(Self::VisionPrescription),
+                _ => None,
+            }
+        }
+    }
+}
+/// Concrete FHIR Resource Types
+/// See <http://hl7.org/fhir/ValueSet/resource-types>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ResourceTypeValueSet {}
+/// Nested message and enum types in `ResourceTypeValueSet`.
+pub mod resource_type_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Account = 1,
+        ActivityDefinition = 2,
+        ActorDefinition = 149,
+        AdministrableProductDefinition = 150,
+        AdverseEvent = 3,
+        AllergyIntolerance = 4,
+        Appointment = 5,
+        AppointmentResponse = 6,
+        ArtifactAssessment = 151,
+        AuditEvent = 7,
+        Basic = 8,
+        Binary = 9,
+        BiologicallyDerivedProduct = 10,
+        BiologicallyDerivedProductDispense = 152,
+        BodyStructure = 11,
+        Bundle = 12,
+        CapabilityStatement = 13,
+        CarePlan = 14,
+        CareTeam = 15,
+        ChargeItem = 17,
+        ChargeItemDefinition = 18,
+        Citation = 153,
+        Claim = 19,
+        ClaimResponse = 20,
+        ClinicalImpression = 21,
+        ClinicalUseDefinition = 154,
+        CodeSystem = 22,
+        Communication = 23,
+        CommunicationRequest = 24,
+        CompartmentDefinition = 25,
+        Composition = 26,
+        ConceptMap = 27,
+        Condition = 28,
+        ConditionDefinition = 155,
+        Consent = 29,
+        Contract = 30,
+        Coverage = 31,
+        CoverageEligibilityRequest = 32,
+        CoverageEligibilityResponse = 33,
+        DetectedIssue = 34,
+        Device = 35,
+        DeviceAssociation = 156,
+        DeviceDefinition = 36,
+        DeviceDispense = 157,
+        DeviceMetric = 37,
+        DeviceRequest = 38,
+        DeviceUsage = 158,
+        DiagnosticReport = 40,
+        DocumentReference = 42,
+        Encounter = 45,
+        EncounterHistory = 159,
+        Endpoint = 46,
+        EnrollmentRequest = 47,
+        EnrollmentResponse = 48,
+        EpisodeOfCare = 49,
+        EventDefinition = 50,
+        Evidence = 51,
+        EvidenceReport = 160,
+        EvidenceVariable = 52,
+        ExampleScenario = 53,
+        ExplanationOfBenefit = 54,
+        FamilyMemberHistory = 55,
+        Flag = 56,
+        FormularyItem = 161,
+        GenomicStudy = 162,
+        Goal = 57,
+        GraphDefinition = 58,
+        Group = 59,
+        GuidanceResponse = 60,
+        HealthcareService = 61,
+        ImagingSelection = 163,
+        ImagingStudy = 62,
+        Immunization = 63,
+        ImmunizationEvaluation = 64,
+        ImmunizationRecommendation = 65,
+        ImplementationGuide = 66,
+        Ingredient = 164,
+        InsurancePlan = 67,
+        InventoryItem = 165,
+        InventoryReport = 166,
+        Invoice = 68,
+        Library = 69,
+        Linkage = 70,
+        List = 71,
+        Location = 72,
+        ManufacturedItemDefinition = 167,
+        Measure = 73,
+        MeasureReport = 74,
+        Medication = 76,
+        MedicationAdministration = 77,
+        MedicationDispense = 78,
+        MedicationKnowledge = 79,
+        MedicationRequest = 80,
+        MedicationStatement = 81,
+        MedicinalProductDefinition = 168,
+        MessageDefinition = 92,
+        MessageHeader = 93,
+        MolecularSequence = 94,
+        NamingSystem = 95,
+        NutritionIntake = 169,
+        NutritionOrder = 96,
+        NutritionProduct = 170,
+        Observation = 97,
+        ObservationDefinition = 98,
+        OperationDefinition = 99,
+        OperationOutcome = 100,
+        Organization = 101,
+        OrganizationAffiliation = 102,
+        PackagedProductDefinition = 171,
+        Parameters = 103,
+        Patient = 104,
+        PaymentNotice = 105,
+        PaymentReconciliation = 106,
+        Permission = 172,
+        Person = 107,
+        PlanDefinition = 108,
+        Practitioner = 109,
+        PractitionerRole = 110,
+        Procedure = 111,
+        Provenance = 112,
+        Questionnaire = 113,
+        QuestionnaireResponse = 114,
+        RegulatedAuthorization = 173,
+        RelatedPerson = 115,
+        RequestOrchestration = 174,
+        Requirements = 175,
+        ResearchStudy = 119,
+        ResearchSubject = 120,
+        RiskAssessment = 122,
+        Schedule = 124,
+        SearchParameter = 125,
+        ServiceRequest = 126,
+        Slot = 127,
+        Specimen = 128,
+        SpecimenDefinition = 129,
+        StructureDefinition = 130,
+        StructureMap = 131,
+        Subscription = 132,
+        SubscriptionStatus = 176,
+        SubscriptionTopic = 177,
+        Substance = 133,
+        SubstanceDefinition = 178,
+        SubstanceNucleicAcid = 134,
+        SubstancePolymer = 135,
+        SubstanceProtein = 136,
+        SubstanceReferenceInformation = 137,
+        SubstanceSourceMaterial = 138,
+        SupplyDelivery = 140,
+        SupplyRequest = 141,
+        Task = 142,
+        TerminologyCapabilities = 143,
+        TestPlan = 179,
+        TestReport = 144,
+        TestScript = 145,
+        Transport = 180,
+        Set = 146,
+        VerificationResult = 147,
+        VisionPrescription = 148,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Account => "ACCOUNT",
+                Self::ActivityDefinition => "ACTIVITY_DEFINITION",
+                Self::ActorDefinition => "ACTOR_DEFINITION",
+                Self::AdministrableProductDefinition => {
+                    "ADMINISTRABLE_PRODUCT_DEFINITION"
+                }
+                Self::AdverseEvent => "ADVERSE_EVENT",
+                Self::AllergyIntolerance => "ALLERGY_INTOLERANCE",
+                Self::Appointment => "APPOINTMENT",
+                Self::AppointmentResponse => "APPOINTMENT_RESPONSE",
+                Self::ArtifactAssessment => "ARTIFACT_ASSESSMENT",
+                Self::AuditEvent => "AUDIT_EVENT",
+                Self::Basic => "BASIC",
+                Self::Binary => "BINARY",
+                Self::BiologicallyDerivedProduct => "BIOLOGICALLY_DERIVED_PRODUCT",
+                Self::BiologicallyDerivedProductDispense => {
+                    "BIOLOGICALLY_DERIVED_PRODUCT_DISPENSE"
+                }
+                Self::BodyStructure => "BODY_STRUCTURE",
+                Self::Bundle => "BUNDLE",
+                Self::CapabilityStatement => "CAPABILITY_STATEMENT",
+                Self::CarePlan => "CARE_PLAN",
+                Self::CareTeam => "CARE_TEAM",
+                Self::ChargeItem => "CHARGE_ITEM",
+                Self::ChargeItemDefinition => "CHARGE_ITEM_DEFINITION",
+                Self::Citation => "CITATION",
+                Self::Claim => "CLAIM",
+                Self::ClaimResponse => "CLAIM_RESPONSE",
+                Self::ClinicalImpression => "CLINICAL_IMPRESSION",
+                Self::ClinicalUseDefinition => "CLINICAL_USE_DEFINITION",
+                Self::CodeSystem => "CODE_SYSTEM",
+                Self::Communication => "COMMUNICATION",
+                Self::CommunicationRequest => "COMMUNICATION_REQUEST",
+                Self::CompartmentDefinition => "COMPARTMENT_DEFINITION",
+                Self::Composition => "COMPOSITION",
+                Self::ConceptMap => "CONCEPT_MAP",
+                Self::Condition => "CONDITION",
+                Self::ConditionDefinition => "CONDITION_DEFINITION",
+                Self::Consent => "CONSENT",
+                Self::Contract => "CONTRACT",
+                Self::Coverage => "COVERAGE",
+                Self::CoverageEligibilityRequest => "COVERAGE_ELIGIBILITY_REQUEST",
+                Self::CoverageEligibilityResponse => "COVERAGE_ELIGIBILITY_RESPONSE",
+                Self::DetectedIssue => "DETECTED_ISSUE",
+                Self::Device => "DEVICE",
+                Self::DeviceAssociation => "DEVICE_ASSOCIATION",
+                Self::DeviceDefinition => "DEVICE_DEFINITION",
+                Self::DeviceDispense => "DEVICE_DISPENSE",
+                Self::DeviceMetric => "DEVICE_METRIC",
+                Self::DeviceRequest => "DEVICE_REQUEST",
+                Self::DeviceUsage => "DEVICE_USAGE",
+                Self::DiagnosticReport => "DIAGNOSTIC_REPORT",
+                Self::DocumentReference => "DOCUMENT_REFERENCE",
+                Self::Encounter => "ENCOUNTER",
+                Self::EncounterHistory => "ENCOUNTER_HISTORY",
+                Self::Endpoint => "ENDPOINT",
+                Self::EnrollmentRequest => "ENROLLMENT_REQUEST",
+                Self::EnrollmentResponse => "ENROLLMENT_RESPONSE",
+                Self::EpisodeOfCare => "EPISODE_OF_CARE",
+                Self::EventDefinition => "EVENT_DEFINITION",
+                Self::Evidence => "EVIDENCE",
+                Self::EvidenceReport => "EVIDENCE_REPORT",
+                Self::EvidenceVariable => "EVIDENCE_VARIABLE",
+                Self::ExampleScenario => "EXAMPLE_SCENARIO",
+                Self::ExplanationOfBenefit => "EXPLANATION_OF_BENEFIT",
+                Self::FamilyMemberHistory => "FAMILY_MEMBER_HISTORY",
+                Self::Flag => "FLAG",
+                Self::FormularyItem => "FORMULARY_ITEM",
+                Self::GenomicStudy => "GENOMIC_STUDY",
+                Self::Goal => "GOAL",
+                Self::GraphDefinition => "GRAPH_DEFINITION",
+                Self::Group => "GROUP",
+                Self::GuidanceResponse => "GUIDANCE_RESPONSE",
+                Self::HealthcareService => "HEALTHCARE_SERVICE",
+                Self::ImagingSelection => "IMAGING_SELECTION",
+                Self::ImagingStudy => "IMAGING_STUDY",
+                Self::Immunization => "IMMUNIZATION",
+                Self::ImmunizationEvaluation => "IMMUNIZATION_EVALUATION",
+                Self::ImmunizationRecommendation => "IMMUNIZATION_RECOMMENDATION",
+                Self::ImplementationGuide => "IMPLEMENTATION_GUIDE",
+                Self::Ingredient => "INGREDIENT",
+                Self::InsurancePlan => "INSURANCE_PLAN",
+                Self::InventoryItem => "INVENTORY_ITEM",
+                Self::InventoryReport => "INVENTORY_REPORT",
+                Self::Invoice => "INVOICE",
+                Self::Library => "LIBRARY",
+                Self::Linkage => "LINKAGE",
+                Self::List => "LIST",
+                Self::Location => "LOCATION",
+                Self::ManufacturedItemDefinition => "MANUFACTURED_ITEM_DEFINITION",
+                Self::Measure => "MEASURE",
+                Self::MeasureReport => "MEASURE_REPORT",
+                Self::Medication => "MEDICATION",
+                Self::MedicationAdministration => "MEDICATION_ADMINISTRATION",
+                Self::MedicationDispense => "MEDICATION_DISPENSE",
+                Self::MedicationKnowledge => "MEDICATION_KNOWLEDGE",
+                Self::MedicationRequest => "MEDICATION_REQUEST",
+                Self::MedicationStatement => "MEDICATION_STATEMENT",
+                Self::MedicinalProductDefinition => "MEDICINAL_PRODUCT_DEFINITION",
+                Self::MessageDefinition => "MESSAGE_DEFINITION",
+                Self::MessageHeader => "MESSAGE_HEADER",
+                Self::MolecularSequence => "MOLECULAR_SEQUENCE",
+                Self::NamingSystem => "NAMING_SYSTEM",
+                Self::NutritionIntake => "NUTRITION_INTAKE",
+                Self::NutritionOrder => "NUTRITION_ORDER",
+                Self::NutritionProduct => "NUTRITION_PRODUCT",
+                Self::Observation => "OBSERVATION",
+                Self::ObservationDefinition => "OBSERVATION_DEFINITION",
+                Self::OperationDefinition => "OPERATION_DEFINITION",
+                Self::OperationOutcome => "OPERATION_OUTCOME",
+                Self::Organization => "ORGANIZATION",
+                Self::OrganizationAffiliation => "ORGANIZATION_AFFILIATION",
+                Self::PackagedProductDefinition => "PACKAGED_PRODUCT_DEFINITION",
+                Self::Parameters => "PARAMETERS",
+                Self::Patient => "PATIENT",
+                Self::PaymentNotice => "PAYMENT_NOTICE",
+                Self::PaymentReconciliation => "PAYMENT_RECONCILIATION",
+                Self::Permission => "PERMISSION",
+                Self::Person => "PERSON",
+                Self::PlanDefinition => "PLAN_DEFINITION",
+                Self::Practitioner => "PRACTITIONER",
+                Self::PractitionerRole => "PRACTITIONER_ROLE",
+                Self::Procedure => "PROCEDURE",
+                Self::Provenance => "PROVENANCE",
+                Self::Questionnaire => "QUESTIONNAIRE",
+                Self::QuestionnaireResponse => "QUESTIONNAIRE_RESPONSE",
+                Self::RegulatedAuthorization => "REGULATED_AUTHORIZATION",
+                Self::RelatedPerson => "RELATED_PERSON",
+                Self::RequestOrchestration => "REQUEST_ORCHESTRATION",
+                Self::Requirements => "REQUIREMENTS",
+                Self::ResearchStudy => "RESEARCH_STUDY",
+                Self::ResearchSubject => "RESEARCH_SUBJECT",
+                Self::RiskAssessment => "RISK_ASSESSMENT",
+                Self::Schedule => "SCHEDULE",
+                Self::SearchParameter => "SEARCH_PARAMETER",
+                Self::ServiceRequest => "SERVICE_REQUEST",
+                Self::Slot => "SLOT",
+                Self::Specimen => "SPECIMEN",
+                Self::SpecimenDefinition => "SPECIMEN_DEFINITION",
+                Self::StructureDefinition => "STRUCTURE_DEFINITION",
+                Self::StructureMap => "STRUCTURE_MAP",
+                Self::Subscription => "SUBSCRIPTION",
+                Self::SubscriptionStatus => "SUBSCRIPTION_STATUS",
+                Self::SubscriptionTopic => "SUBSCRIPTION_TOPIC",
+                Self::Substance => "SUBSTANCE",
+                Self::SubstanceDefinition => "SUBSTANCE_DEFINITION",
+                Self::SubstanceNucleicAcid => "SUBSTANCE_NUCLEIC_ACID",
+                Self::SubstancePolymer => "SUBSTANCE_POLYMER",
+                Self::SubstanceProtein => "SUBSTANCE_PROTEIN",
+                Self::SubstanceReferenceInformation => "SUBSTANCE_REFERENCE_INFORMATION",
+                Self::SubstanceSourceMaterial => "SUBSTANCE_SOURCE_MATERIAL",
+                Self::SupplyDelivery => "SUPPLY_DELIVERY",
+                Self::SupplyRequest => "SUPPLY_REQUEST",
+                Self::Task => "TASK",
+                Self::TerminologyCapabilities => "TERMINOLOGY_CAPABILITIES",
+                Self::TestPlan => "TEST_PLAN",
+                Self::TestReport => "TEST_REPORT",
+                Self::TestScript => "TEST_SCRIPT",
+                Self::Transport => "TRANSPORT",
+                Self::Set => "VALUE_SET",
+                Self::VerificationResult => "VERIFICATION_RESULT",
+                Self::VisionPrescription => "VISION_PRESCRIPTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACCOUNT" => Some(Self::Account),
+                "ACTIVITY_DEFINITION" => Some(Self::ActivityDefinition),
+                "ACTOR_DEFINITION" => Some(Self::ActorDefinition),
+                "ADMINISTRABLE_PRODUCT_DEFINITION" => {
+                    Some(Self::AdministrableProductDefinition)
+                }
+                "ADVERSE_EVENT" => Some(Self::AdverseEvent),
+                "ALLERGY_INTOLERANCE" => Some(Self::AllergyIntolerance),
+                "APPOINTMENT" => Some(Self::Appointment),
+                "APPOINTMENT_RESPONSE" => Some(Self::AppointmentResponse),
+                "ARTIFACT_ASSESSMENT" => Some(Self::ArtifactAssessment),
+                "AUDIT_EVENT" => Some(Self::AuditEvent),
+                "BASIC" => Some(Self::Basic),
+                "BINARY" => Some(Self::Binary),
+                "BIOLOGICALLY_DERIVED_PRODUCT" => Some(Self::BiologicallyDerivedProduct),
+                "BIOLOGICALLY_DERIVED_PRODUCT_DISPENSE" => {
+                    Some(Self::BiologicallyDerivedProductDispense)
+                }
+                "BODY_STRUCTURE" => Some(Self::BodyStructure),
+                "BUNDLE" => Some(Self::Bundle),
+                "CAPABILITY_STATEMENT" => Some(Self::CapabilityStatement),
+                "CARE_PLAN" => Some(Self::CarePlan),
+                "CARE_TEAM" => Some(Self::CareTeam),
+                "CHARGE_ITEM" => Some(Self::ChargeItem),
+                "CHARGE_ITEM_DEFINITION" => Some(Self::ChargeItemDefinition),
+                "CITATION" => Some(Self::Citation),
+                "CLAIM" => Some(Self::Claim),
+                "CLAIM_RESPONSE" => Some(Self::ClaimResponse),
+                "CLINICAL_IMPRESSION" => Some(Self::ClinicalImpression),
+                "CLINICAL_USE_DEFINITION" => Some(Self::ClinicalUseDefinition),
+                "CODE_SYSTEM" => Some(Self::CodeSystem),
+                "COMMUNICATION" => Some(Self::Communication),
+                "COMMUNICATION_REQUEST" => Some(Self::CommunicationRequest),
+                "COMPARTMENT_DEFINITION" => Some(Self::CompartmentDefinition),
+                "COMPOSITION" => Some(Self::Composition),
+                "CONCEPT_MAP" => Some(Self::ConceptMap),
+                "CONDITION" => Some(Self::Condition),
+                "CONDITION_DEFINITION" => Some(Self::ConditionDefinition),
+                "CONSENT" => Some(Self::Consent),
+                "CONTRACT" => Some(Self::Contract),
+                "COVERAGE" => Some(Self::Coverage),
+                "COVERAGE_ELIGIBILITY_REQUEST" => Some(Self::CoverageEligibilityRequest),
+                "COVERAGE_ELIGIBILITY_RESPONSE" => {
+                    Some(Self::CoverageEligibilityResponse)
+                }
+                "DETECTED_ISSUE" => Some(Self::DetectedIssue),
+                "DEVICE" => Some(Self::Device),
+                "DEVICE_ASSOCIATION" => Some(Self::DeviceAssociation),
+                "DEVICE_DEFINITION" => Some(Self::DeviceDefinition),
+                "DEVICE_DISPENSE" => Some(Self::DeviceDispense),
+                "DEVICE_METRIC" => Some(Self::DeviceMetric),
+                "DEVICE_REQUEST" => Some(Self::DeviceRequest),
+                "DEVICE_USAGE" => Some(Self::DeviceUsage),
+                "DIAGNOSTIC_REPORT" => Some(Self::DiagnosticReport),
+                "DOCUMENT_REFERENCE" => Some(Self::DocumentReference),
+                "ENCOUNTER" => Some(Self::Encounter),
+                "ENCOUNTER_HISTORY" => Some(Self::EncounterHistory),
+                "ENDPOINT" => Some(Self::Endpoint),
+                "ENROLLMENT_REQUEST" => Some(Self::EnrollmentRequest),
+                "ENROLLMENT_RESPONSE" => Some(Self::EnrollmentResponse),
+                "EPISODE_OF_CARE" => Some(Self::EpisodeOfCare),
+                "EVENT_DEFINITION" => Some(Self::EventDefinition),
+                "EVIDENCE" => Some(Self::Evidence),
+                "EVIDENCE_REPORT" => Some(Self::EvidenceReport),
+                "EVIDENCE_VARIABLE" => Some(Self::EvidenceVariable),
+                "EXAMPLE_SCENARIO" => Some(Self::ExampleScenario),
+                "EXPLANATION_OF_BENEFIT" => Some(Self::ExplanationOfBenefit),
+                "FAMILY_MEMBER_HISTORY" => Some(Self::FamilyMemberHistory),
+                "FLAG" => Some(Self::Flag),
+                "FORMULARY_ITEM" => Some(Self::FormularyItem),
+                "GENOMIC_STUDY" => Some(Self::GenomicStudy),
+                "GOAL" => Some(Self::Goal),
+                "GRAPH_DEFINITION" => Some(Self::GraphDefinition),
+                "GROUP" => Some(Self::Group),
+                "GUIDANCE_RESPONSE" => Some(Self::GuidanceResponse),
+                "HEALTHCARE_SERVICE" => Some(Self::HealthcareService),
+                "IMAGING_SELECTION" => Some(Self::ImagingSelection),
+                "IMAGING_STUDY" => Some(Self::ImagingStudy),
+                "IMMUNIZATION" => Some(Self::Immunization),
+                "IMMUNIZATION_EVALUATION" => Some(Self::ImmunizationEvaluation),
+                "IMMUNIZATION_RECOMMENDATION" => Some(Self::ImmunizationRecommendation),
+                "IMPLEMENTATION_GUIDE" => Some(Self::ImplementationGuide),
+                "INGREDIENT" => Some(Self::Ingredient),
+                "INSURANCE_PLAN" => Some(Self::InsurancePlan),
+                "INVENTORY_ITEM" => Some(Self::InventoryItem),
+                "INVENTORY_REPORT" => Some(Self::InventoryReport),
+                "INVOICE" => Some(Self::Invoice),
+                "LIBRARY" => Some(Self::Library),
+                "LINKAGE" => Some(Self::Linkage),
+                "LIST" => Some(Self::List),
+                "LOCATION" => Some(Self::Location),
+                "MANUFACTURED_ITEM_DEFINITION" => Some(Self::ManufacturedItemDefinition),
+                "MEASURE" => Some(Self::Measure),
+                "MEASURE_REPORT" => Some(Self::MeasureReport),
+                "MEDICATION" => Some(Self::Medication),
+                "MEDICATION_ADMINISTRATION" => Some(Self::MedicationAdministration),
+                "MEDICATION_DISPENSE" => Some(Self::MedicationDispense),
+                "MEDICATION_KNOWLEDGE" => Some(Self::MedicationKnowledge),
+                "MEDICATION_REQUEST" => Some(Self::MedicationRequest),
+                "MEDICATION_STATEMENT" => Some(Self::MedicationStatement),
+                "MEDICINAL_PRODUCT_DEFINITION" => Some(Self::MedicinalProductDefinition),
+                "MESSAGE_DEFINITION" => Some(Self::MessageDefinition),
+                "MESSAGE_HEADER" => Some(Self::MessageHeader),
+                "MOLECULAR_SEQUENCE" => Some(Self::MolecularSequence),
+                "NAMING_SYSTEM" => Some(Self::NamingSystem),
+                "NUTRITION_INTAKE" => Some(Self::NutritionIntake),
+                "NUTRITION_ORDER" => Some(Self::NutritionOrder),
+                "NUTRITION_PRODUCT" => Some(Self::NutritionProduct),
+                "OBSERVATION" => Some(Self::Observation),
+                "OBSERVATION_DEFINITION" => Some(Self::ObservationDefinition),
+                "OPERATION_DEFINITION" => Some(Self::OperationDefinition),
+                "OPERATION_OUTCOME" => Some(Self::OperationOutcome),
+                "ORGANIZATION" => Some(Self::Organization),
+                "ORGANIZATION_AFFILIATION" => Some(Self::OrganizationAffiliation),
+                "PACKAGED_PRODUCT_DEFINITION" => Some(Self::PackagedProductDefinition),
+                "PARAMETERS" => Some(Self::Parameters),
+                "PATIENT" => Some(Self::Patient),
+                "PAYMENT_NOTICE" => Some(Self::PaymentNotice),
+                "PAYMENT_RECONCILIATION" => Some(Self::PaymentReconciliation),
+                "PERMISSION" => Some(Self::Permission),
+                "PERSON" => Some(Self::Person),
+                "PLAN_DEFINITION" => Some(Self::PlanDefinition),
+                "PRACTITIONER" => Some(Self::Practitioner),
+                "PRACTITIONER_ROLE" => Some(Self::PractitionerRole),
+                "PROCEDURE" => Some(Self::Procedure),
+                "PROVENANCE" => Some(Self::Provenance),
+                "QUESTIONNAIRE" => Some(Self::Questionnaire),
+                "QUESTIONNAIRE_RESPONSE" => Some(Self::QuestionnaireResponse),
+                "REGULATED_AUTHORIZATION" => Some(Self::RegulatedAuthorization),
+                "RELATED_PERSON" => Some(Self::RelatedPerson),
+                "REQUEST_ORCHESTRATION" => Some(Self::RequestOrchestration),
+                "REQUIREMENTS" => Some(Self::Requirements),
+                "RESEARCH_STUDY" => Some(Self::ResearchStudy),
+                "RESEARCH_SUBJECT" => Some(Self::ResearchSubject),
+                "RISK_ASSESSMENT" => Some(Self::RiskAssessment),
+                "SCHEDULE" => Some(Self::Schedule),
+                "SEARCH_PARAMETER" => Some(Self::SearchParameter),
+                "SERVICE_REQUEST" => Some(Self::ServiceRequest),
+                "SLOT" => Some(Self::Slot),
+                "SPECIMEN" => Some(Self::Specimen),
+                "SPECIMEN_DEFINITION" => Some(Self::SpecimenDefinition),
+                "STRUCTURE_DEFINITION" => Some(Self::StructureDefinition),
+                "STRUCTURE_MAP" => Some(Self::StructureMap),
+                "SUBSCRIPTION" => Some(Self::Subscription),
+                "SUBSCRIPTION_STATUS" => Some(Self::SubscriptionStatus),
+                "SUBSCRIPTION_TOPIC" => Some(Self::SubscriptionTopic),
+                "SUBSTANCE" => Some(Self::Substance),
+                "SUBSTANCE_DEFINITION" => Some(Self::SubstanceDefinition),
+                "SUBSTANCE_NUCLEIC_ACID" => Some(Self::SubstanceNucleicAcid),
+                "SUBSTANCE_POLYMER" => Some(Self::SubstancePolymer),
+                "SUBSTANCE_PROTEIN" => Some(Self::SubstanceProtein),
+                "SUBSTANCE_REFERENCE_INFORMATION" => {
+                    Some(Self::SubstanceReferenceInformation)
+                }
+                "SUBSTANCE_SOURCE_MATERIAL" => Some(Self::SubstanceSourceMaterial),
+                "SUPPLY_DELIVERY" => Some(Self::SupplyDelivery),
+                "SUPPLY_REQUEST" => Some(Self::SupplyRequest),
+                "TASK" => Some(Self::Task),
+                "TERMINOLOGY_CAPABILITIES" => Some(Self::TerminologyCapabilities),
+                "TEST_PLAN" => Some(Self::TestPlan),
+                "TEST_REPORT" => Some(Self::TestReport),
+                "TEST_SCRIPT" => Some(Self::TestScript),
+                "TRANSPORT" => Some(Self::Transport),
+                "VALUE_SET" => Some(Self::Set),
+                "VERIFICATION_RESULT" => Some(Self::VerificationResult),
+                "VISION_PRESCRIPTION" => Some(Self::VisionPrescription),
+                _ => None,
+            }
+        }
+    }
+}
+/// Operations supported by REST at the system level.
+/// See <http://hl7.org/fhir/ValueSet/system-restful-interaction>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SystemRestfulInteractionValueSet {}
+/// Nested message and enum types in `SystemRestfulInteractionValueSet`.
+pub mod system_restful_interaction_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Transaction = 1,
+        Batch = 2,
+        SearchSystem = 3,
+        HistorySystem = 4,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Transaction => "TRANSACTION",
+                Self::Batch => "BATCH",
+                Self::SearchSystem => "SEARCH_SYSTEM",
+                Self::HistorySystem => "HISTORY_SYSTEM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "TRANSACTION" => Some(Self::Transaction),
+                "BATCH" => Some(Self::Batch),
+                "SEARCH_SYSTEM" => Some(Self::SearchSystem),
+                "HISTORY_SYSTEM" => Some(Self::HistorySystem),
+                _ => None,
+            }
+        }
+    }
+}
+/// Distinguishes whether the task is a proposal, plan or full order.
+/// See <http://hl7.org/fhir/ValueSet/task-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TaskIntentValueSet {}
+/// Nested message and enum types in `TaskIntentValueSet`.
+pub mod task_intent_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Unknown = 1,
+        Proposal = 2,
+        Plan = 3,
+        Order = 4,
+        OriginalOrder = 5,
+        ReflexOrder = 6,
+        FillerOrder = 7,
+        InstanceOrder = 8,
+        Option = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Unknown => "UNKNOWN",
+                Self::Proposal => "PROPOSAL",
+                Self::Plan => "PLAN",
+                Self::Order => "ORDER",
+                Self::OriginalOrder => "ORIGINAL_ORDER",
+                Self::ReflexOrder => "REFLEX_ORDER",
+                Self::FillerOrder => "FILLER_ORDER",
+                Self::InstanceOrder => "INSTANCE_ORDER",
+                Self::Option => "OPTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UNKNOWN" => Some(Self::Unknown),
+                "PROPOSAL" => Some(Self::Proposal),
+                "PLAN" => Some(Self::Plan),
+                "ORDER" => Some(Self::Order),
+                "ORIGINAL_ORDER" => Some(Self::OriginalOrder),
+                "REFLEX_ORDER" => Some(Self::ReflexOrder),
+                "FILLER_ORDER" => Some(Self::FillerOrder),
+                "INSTANCE_ORDER" => Some(Self::InstanceOrder),
+                "OPTION" => Some(Self::Option),
+                _ => None,
+            }
+        }
+    }
+}
+/// Distinguishes whether the transport is a proposal, plan or full order.
+/// See <http://hl7.org/fhir/ValueSet/transport-intent>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TransportIntentValueSet {}
+/// Nested message and enum types in `TransportIntentValueSet`.
+pub mod transport_intent_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Unknown = 1,
+        Proposal = 2,
+        Plan = 3,
+        Order = 4,
+        OriginalOrder = 5,
+        ReflexOrder = 6,
+        FillerOrder = 7,
+        InstanceOrder = 8,
+        Option = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Unknown => "UNKNOWN",
+                Self::Proposal => "PROPOSAL",
+                Self::Plan => "PLAN",
+                Self::Order => "ORDER",
+                Self::OriginalOrder => "ORIGINAL_ORDER",
+                Self::ReflexOrder => "REFLEX_ORDER",
+                Self::FillerOrder => "FILLER_ORDER",
+                Self::InstanceOrder => "INSTANCE_ORDER",
+                Self::Option => "OPTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "UNKNOWN" => Some(Self::Unknown),
+                "PROPOSAL" => Some(Self::Proposal),
+                "PLAN" => Some(Self::Plan),
+                "ORDER" => Some(Self::Order),
+                "ORIGINAL_ORDER" => Some(Self::OriginalOrder),
+                "REFLEX_ORDER" => Some(Self::ReflexOrder),
+                "FILLER_ORDER" => Some(Self::FillerOrder),
+                "INSTANCE_ORDER" => Some(Self::InstanceOrder),
+                "OPTION" => Some(Self::Option),
+                _ => None,
+            }
+        }
+    }
+}
+/// Operations supported by REST at the type or instance level.
+/// See <http://hl7.org/fhir/ValueSet/type-restful-interaction>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TypeRestfulInteractionValueSet {}
+/// Nested message and enum types in `TypeRestfulInteractionValueSet`.
+pub mod type_restful_interaction_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Read = 1,
+        Vread = 2,
+        Update = 3,
+        Patch = 4,
+        Delete = 5,
+        HistoryInstance = 6,
+        HistoryType = 7,
+        Create = 8,
+        SearchType = 9,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Read => "READ",
+                Self::Vread => "VREAD",
+                Self::Update => "UPDATE",
+                Self::Patch => "PATCH",
+                Self::Delete => "DELETE",
+                Self::HistoryInstance => "HISTORY_INSTANCE",
+                Self::HistoryType => "HISTORY_TYPE",
+                Self::Create => "CREATE",
+                Self::SearchType => "SEARCH_TYPE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "READ" => Some(Self::Read),
+                "VREAD" => Some(Self::Vread),
+                "UPDATE" => Some(Self::Update),
+                "PATCH" => Some(Self::Patch),
+                "DELETE" => Some(Self::Delete),
+                "HISTORY_INSTANCE" => Some(Self::HistoryInstance),
+                "HISTORY_TYPE" => Some(Self::HistoryType),
+                "CREATE" => Some(Self::Create),
+                "SEARCH_TYPE" => Some(Self::SearchType),
+                _ => None,
+            }
+        }
+    }
+}
+/// A unit of time (units from UCUM).
+/// See <http://hl7.org/fhir/ValueSet/units-of-time>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UnitsOfTimeValueSet {}
+/// Nested message and enum types in `UnitsOfTimeValueSet`.
+pub mod units_of_time_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        S = 1,
+        Min = 2,
+        H = 3,
+        D = 4,
+        Wk = 5,
+        Mo = 6,
+        A = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::S => "S",
+                Self::Min => "MIN",
+                Self::H => "H",
+                Self::D => "D",
+                Self::Wk => "WK",
+                Self::Mo => "MO",
+                Self::A => "A",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "S" => Some(Self::S),
+                "MIN" => Some(Self::Min),
+                "H" => Some(Self::H),
+                "D" => Some(Self::D),
+                "WK" => Some(Self::Wk),
+                "MO" => Some(Self::Mo),
+                "A" => Some(Self::A),
+                _ => None,
+            }
+        }
+    }
+}
+/// The type of comparator operator to use
+/// See <http://hl7.org/fhir/ValueSet/value-filter-comparator>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ValueFilterComparatorValueSet {}
+/// Nested message and enum types in `ValueFilterComparatorValueSet`.
+pub mod value_filter_comparator_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Eq = 1,
+        Gt = 2,
+        Lt = 3,
+        Ge = 4,
+        Le = 5,
+        Sa = 6,
+        Eb = 7,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Eq => "EQ",
+                Self::Gt => "GT",
+                Self::Lt => "LT",
+                Self::Ge => "GE",
+                Self::Le => "LE",
+                Self::Sa => "SA",
+                Self::Eb => "EB",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "EQ" => Some(Self::Eq),
+                "GT" => Some(Self::Gt),
+                "LT" => Some(Self::Lt),
+                "GE" => Some(Self::Ge),
+                "LE" => Some(Self::Le),
+                "SA" => Some(Self::Sa),
+                "EB" => Some(Self::Eb),
+                _ => None,
+            }
+        }
+    }
+}
+/// Current and past FHIR resource types (deleted or renamed), including abstract
+/// types See <http://hl7.org/fhir/ValueSet/version-independent-all-resource-types>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VersionIndependentResourceTypesAllValueSet {}
+/// Nested message and enum types in `VersionIndependentResourceTypesAllValueSet`.
+pub mod version_independent_resource_types_all_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Account = 1,
+        ActivityDefinition = 2,
+        ActorDefinition = 3,
+        AdministrableProductDefinition = 4,
+        AdverseEvent = 5,
+        AllergyIntolerance = 6,
+        Appointment = 7,
+        AppointmentResponse = 8,
+        ArtifactAssessment = 9,
+        AuditEvent = 10,
+        Basic = 11,
+        Binary = 12,
+        BiologicallyDerivedProduct = 13,
+        BiologicallyDerivedProductDispense = 14,
+        BodyStructure = 15,
+        Bundle = 16,
+        CanonicalResource = 17,
+        CapabilityStatement = 18,
+        CarePlan = 19,
+        CareTeam = 20,
+        ChargeItem = 21,
+        ChargeItemDefinition = 22,
+        Citation = 23,
+        Claim = 24,
+        ClaimResponse = 25,
+        ClinicalImpression = 26,
+        ClinicalUseDefinition = 27,
+        CodeSystem = 28,
+        Communication = 29,
+        CommunicationRequest = 30,
+        CompartmentDefinition = 31,
+        Composition = 32,
+        ConceptMap = 33,
+        Condition = 34,
+        ConditionDefinition = 35,
+        Consent = 36,
+        Contract = 37,
+        Coverage = 38,
+        CoverageEligibilityRequest = 39,
+        CoverageEligibilityResponse = 40,
+        DetectedIssue = 41,
+        Device = 42,
+        DeviceAssociation = 43,
+        DeviceDefinition = 44,
+        DeviceDispense = 45,
+        DeviceMetric = 46,
+        DeviceRequest = 47,
+        DeviceUsage = 48,
+        DiagnosticReport = 49,
+        DocumentReference = 50,
+        DomainResource = 51,
+        Encounter = 52,
+        EncounterHistory = 53,
+        Endpoint = 54,
+        EnrollmentRequest = 55,
+        EnrollmentResponse = 56,
+        EpisodeOfCare = 57,
+        EventDefinition = 58,
+        Evidence = 59,
+        EvidenceReport = 60,
+        EvidenceVariable = 61,
+        ExampleScenario = 62,
+        ExplanationOfBenefit = 63,
+        FamilyMemberHistory = 64,
+        Flag = 65,
+        FormularyItem = 66,
+        GenomicStudy = 67,
+        Goal = 68,
+        GraphDefinition = 69,
+        Group = 70,
+        GuidanceResponse = 71,
+        HealthcareService = 72,
+        ImagingSelection = 73,
+        ImagingStudy = 74,
+        Immunization = 75,
+        ImmunizationEvaluation = 76,
+        ImmunizationRecommendation = 77,
+        ImplementationGuide = 78,
+        Ingredient = 79,
+        InsurancePlan = 80,
+        InventoryItem = 81,
+        InventoryReport = 82,
+        Invoice = 83,
+        Library = 84,
+        Linkage = 85,
+        List = 86,
+        Location = 87,
+        ManufacturedItemDefinition = 88,
+        Measure = 89,
+        MeasureReport = 90,
+        Medication = 91,
+        MedicationAdministration = 92,
+        MedicationDispense = 93,
+        MedicationKnowledge = 94,
+        MedicationRequest = 95,
+        MedicationStatement = 96,
+        MedicinalProductDefinition = 97,
+        MessageDefinition = 98,
+        MessageHeader = 99,
+        MetadataResource = 100,
+        MolecularSequence = 101,
+        NamingSystem = 102,
+        NutritionIntake = 103,
+        NutritionOrder = 104,
+        NutritionProduct = 105,
+        Observation = 106,
+        ObservationDefinition = 107,
+        OperationDefinition = 108,
+        OperationOutcome = 109,
+        Organization = 110,
+        OrganizationAffiliation = 111,
+        PackagedProductDefinition = 112,
+        Parameters = 113,
+        Patient = 114,
+        PaymentNotice = 115,
+        PaymentReconciliation = 116,
+        Permission = 117,
+        Person = 118,
+        PlanDefinition = 119,
+        Practitioner = 120,
+        PractitionerRole = 121,
+        Procedure = 122,
+        Provenance = 123,
+        Questionnaire = 124,
+        QuestionnaireResponse = 125,
+        RegulatedAuthorization = 126,
+        RelatedPerson = 127,
+        RequestOrchestration = 128,
+        Requirements = 129,
+        ResearchStudy = 130,
+        ResearchSubject = 131,
+        Resource = 132,
+        RiskAssessment = 133,
+        Schedule = 134,
+        SearchParameter = 135,
+        ServiceRequest = 136,
+        Slot = 137,
+        Specimen = 138,
+        SpecimenDefinition = 139,
+        StructureDefinition = 140,
+        StructureMap = 141,
+        Subscription = 142,
+        SubscriptionStatus = 143,
+        SubscriptionTopic = 144,
+        Substance = 145,
+        SubstanceDefinition = 146,
+        SubstanceNucleicAcid = 147,
+        SubstancePolymer = 148,
+        SubstanceProtein = 149,
+        SubstanceReferenceInformation = 150,
+        SubstanceSourceMaterial = 151,
+        SupplyDelivery = 152,
+        SupplyRequest = 153,
+        Task = 154,
+        TerminologyCapabilities = 155,
+        TestPlan = 156,
+        TestReport = 157,
+        TestScript = 158,
+        Transport = 159,
+        Set = 160,
+        VerificationResult = 161,
+        VisionPrescription = 162,
+        BodySite = 163,
+        CatalogEntry = 164,
+        Conformance = 165,
+        DataElement = 166,
+        DeviceComponent = 167,
+        DeviceUseRequest = 168,
+        DeviceUseStatement = 169,
+        DiagnosticOrder = 170,
+        DocumentManifest = 171,
+        EffectEvidenceSynthesis = 172,
+        EligibilityRequest = 173,
+        EligibilityResponse = 174,
+        ExpansionProfile = 175,
+        ImagingManifest = 176,
+        ImagingObjectSelection = 177,
+        Media = 178,
+        MedicationOrder = 179,
+        MedicationUsage = 180,
+        MedicinalProduct = 181,
+        MedicinalProductAuthorization = 182,
+        MedicinalProductContraindication = 183,
+        MedicinalProductIndication = 184,
+        MedicinalProductIngredient = 185,
+        MedicinalProductInteraction = 186,
+        MedicinalProductManufactured = 187,
+        MedicinalProductPackaged = 188,
+        MedicinalProductPharmaceutical = 189,
+        MedicinalProductUndesirableEffect = 190,
+        Order = 191,
+        OrderResponse = 192,
+        ProcedureRequest = 193,
+        ProcessRequest = 194,
+        ProcessResponse = 195,
+        ReferralRequest = 196,
+        RequestGroup = 197,
+        ResearchDefinition = 198,
+        ResearchElementDefinition = 199,
+        RiskEvidenceSynthesis = 200,
+        Sequence = 201,
+        ServiceDefinition = 202,
+        SubstanceSpecification = 203,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Account => "ACCOUNT",
+                Self::ActivityDefinition => "ACTIVITY_DEFINITION",
+                Self::ActorDefinition => "ACTOR_DEFINITION",
+                Self::AdministrableProductDefinition => {
+                    "ADMINISTRABLE_PRODUCT_DEFINITION"
+                }
+                Self::AdverseEvent => "ADVERSE_EVENT",
+                Self::AllergyIntolerance => "ALLERGY_INTOLERANCE",
+                Self::Appointment => "APPOINTMENT",
+                Self::AppointmentResponse => "APPOINTMENT_RESPONSE",
+                Self::ArtifactAssessment => "ARTIFACT_ASSESSMENT",
+                Self::AuditEvent => "AUDIT_EVENT",
+                Self::Basic => "BASIC",
+                Self::Binary => "BINARY",
+                Self::BiologicallyDerivedProduct => "BIOLOGICALLY_DERIVED_PRODUCT",
+                Self::BiologicallyDerivedProductDispense => {
+                    "BIOLOGICALLY_DERIVED_PRODUCT_DISPENSE"
+                }
+                Self::BodyStructure => "BODY_STRUCTURE",
+                Self::Bundle => "BUNDLE",
+                Self::CanonicalResource => "CANONICAL_RESOURCE",
+                Self::CapabilityStatement => "CAPABILITY_STATEMENT",
+                Self::CarePlan => "CARE_PLAN",
+                Self::CareTeam => "CARE_TEAM",
+                Self::ChargeItem => "CHARGE_ITEM",
+                Self::ChargeItemDefinition => "CHARGE_ITEM_DEFINITION",
+                Self::Citation => "CITATION",
+                Self::Claim => "CLAIM",
+                Self::ClaimResponse => "CLAIM_RESPONSE",
+                Self::ClinicalImpression => "CLINICAL_IMPRESSION",
+                Self::ClinicalUseDefinition => "CLINICAL_USE_DEFINITION",
+                Self::CodeSystem => "CODE_SYSTEM",
+                Self::Communication => "COMMUNICATION",
+                Self::CommunicationRequest => "COMMUNICATION_REQUEST",
+                Self::CompartmentDefinition => "COMPARTMENT_DEFINITION",
+                Self::Composition => "COMPOSITION",
+                Self::ConceptMap => "CONCEPT_MAP",
+                Self::Condition => "CONDITION",
+                Self::ConditionDefinition => "CONDITION_DEFINITION",
+                Self::Consent => "CONSENT",
+                Self::Contract => "CONTRACT",
+                Self::Coverage => "COVERAGE",
+                Self::CoverageEligibilityRequest => "COVERAGE_ELIGIBILITY_REQUEST",
+                Self::CoverageEligibilityResponse => "COVERAGE_ELIGIBILITY_RESPONSE",
+                Self::DetectedIssue => "DETECTED_ISSUE",
+                Self::Device => "DEVICE",
+                Self::DeviceAssociation => "DEVICE_ASSOCIATION",
+                Self::DeviceDefinition => "DEVICE_DEFINITION",
+                Self::DeviceDispense => "DEVICE_DISPENSE",
+                Self::DeviceMetric => "DEVICE_METRIC",
+                Self::DeviceRequest => "DEVICE_REQUEST",
+                Self::DeviceUsage => "DEVICE_USAGE",
+                Self::DiagnosticReport => "DIAGNOSTIC_REPORT",
+                Self::DocumentReference => "DOCUMENT_REFERENCE",
+                Self::DomainResource => "DOMAIN_RESOURCE",
+                Self::Encounter => "ENCOUNTER",
+                Self::EncounterHistory => "ENCOUNTER_HISTORY",
+                Self::Endpoint => "ENDPOINT",
+                Self::EnrollmentRequest => "ENROLLMENT_REQUEST",
+                Self::EnrollmentResponse => "ENROLLMENT_RESPONSE",
+                Self::EpisodeOfCare => "EPISODE_OF_CARE",
+                Self::EventDefinition => "EVENT_DEFINITION",
+                Self::Evidence => "EVIDENCE",
+                Self::EvidenceReport => "EVIDENCE_REPORT",
+                Self::EvidenceVariable => "EVIDENCE_VARIABLE",
+                Self::ExampleScenario => "EXAMPLE_SCENARIO",
+                Self::ExplanationOfBenefit => "EXPLANATION_OF_BENEFIT",
+                Self::FamilyMemberHistory => "FAMILY_MEMBER_HISTORY",
+                Self::Flag => "FLAG",
+                Self::FormularyItem => "FORMULARY_ITEM",
+                Self::GenomicStudy => "GENOMIC_STUDY",
+                Self::Goal => "GOAL",
+                Self::GraphDefinition => "GRAPH_DEFINITION",
+                Self::Group => "GROUP",
+                Self::GuidanceResponse => "GUIDANCE_RESPONSE",
+                Self::HealthcareService => "HEALTHCARE_SERVICE",
+                Self::ImagingSelection => "IMAGING_SELECTION",
+                Self::ImagingStudy => "IMAGING_STUDY",
+                Self::Immunization => "IMMUNIZATION",
+                Self::ImmunizationEvaluation => "IMMUNIZATION_EVALUATION",
+                Self::ImmunizationRecommendation => "IMMUNIZATION_RECOMMENDATION",
+                Self::ImplementationGuide => "IMPLEMENTATION_GUIDE",
+                Self::Ingredient => "INGREDIENT",
+                Self::InsurancePlan => "INSURANCE_PLAN",
+                Self::InventoryItem => "INVENTORY_ITEM",
+                Self::InventoryReport => "INVENTORY_REPORT",
+                Self::Invoice => "INVOICE",
+                Self::Library => "LIBRARY",
+                Self::Linkage => "LINKAGE",
+                Self::List => "LIST",
+                Self::Location => "LOCATION",
+                Self::ManufacturedItemDefinition => "MANUFACTURED_ITEM_DEFINITION",
+                Self::Measure => "MEASURE",
+                Self::MeasureReport => "MEASURE_REPORT",
+                Self::Medication => "MEDICATION",
+                Self::MedicationAdministration => "MEDICATION_ADMINISTRATION",
+                Self::MedicationDispense => "MEDICATION_DISPENSE",
+                Self::MedicationKnowledge => "MEDICATION_KNOWLEDGE",
+                Self::MedicationRequest => "MEDICATION_REQUEST",
+                Self::MedicationStatement => "MEDICATION_STATEMENT",
+                Self::MedicinalProductDefinition => "MEDICINAL_PRODUCT_DEFINITION",
+                Self::MessageDefinition => "MESSAGE_DEFINITION",
+                Self::MessageHeader => "MESSAGE_HEADER",
+                Self::MetadataResource => "METADATA_RESOURCE",
+                Self::MolecularSequence => "MOLECULAR_SEQUENCE",
+                Self::NamingSystem => "NAMING_SYSTEM",
+                Self::NutritionIntake => "NUTRITION_INTAKE",
+                Self::NutritionOrder => "NUTRITION_ORDER",
+                Self::NutritionProduct => "NUTRITION_PRODUCT",
+                Self::Observation => "OBSERVATION",
+                Self::ObservationDefinition => "OBSERVATION_DEFINITION",
+                Self::OperationDefinition => "OPERATION_DEFINITION",
+                Self::OperationOutcome => "OPERATION_OUTCOME",
+                Self::Organization => "ORGANIZATION",
+                Self::OrganizationAffiliation => "ORGANIZATION_AFFILIATION",
+                Self::PackagedProductDefinition => "PACKAGED_PRODUCT_DEFINITION",
+                Self::Parameters => "PARAMETERS",
+                Self::Patient => "PATIENT",
+                Self::PaymentNotice => "PAYMENT_NOTICE",
+                Self::PaymentReconciliation => "PAYMENT_RECONCILIATION",
+                Self::Permission => "PERMISSION",
+                Self::Person => "PERSON",
+                Self::PlanDefinition => "PLAN_DEFINITION",
+                Self::Practitioner => "PRACTITIONER",
+                Self::PractitionerRole => "PRACTITIONER_ROLE",
+                Self::Procedure => "PROCEDURE",
+                Self::Provenance => "PROVENANCE",
+                Self::Questionnaire => "QUESTIONNAIRE",
+                Self::QuestionnaireResponse => "QUESTIONNAIRE_RESPONSE",
+                Self::RegulatedAuthorization => "REGULATED_AUTHORIZATION",
+                Self::RelatedPerson => "RELATED_PERSON",
+                Self::RequestOrchestration => "REQUEST_ORCHESTRATION",
+                Self::Requirements => "REQUIREMENTS",
+                Self::ResearchStudy => "RESEARCH_STUDY",
+                Self::ResearchSubject => "RESEARCH_SUBJECT",
+                Self::Resource => "RESOURCE",
+                Self::RiskAssessment => "RISK_ASSESSMENT",
+                Self::Schedule => "SCHEDULE",
+                Self::SearchParameter => "SEARCH_PARAMETER",
+                Self::ServiceRequest => "SERVICE_REQUEST",
+                Self::Slot => "SLOT",
+                Self::Specimen => "SPECIMEN",
+                Self::SpecimenDefinition => "SPECIMEN_DEFINITION",
+                Self::StructureDefinition => "STRUCTURE_DEFINITION",
+                Self::StructureMap => "STRUCTURE_MAP",
+                Self::Subscription => "SUBSCRIPTION",
+                Self::SubscriptionStatus => "SUBSCRIPTION_STATUS",
+                Self::SubscriptionTopic => "SUBSCRIPTION_TOPIC",
+                Self::Substance => "SUBSTANCE",
+                Self::SubstanceDefinition => "SUBSTANCE_DEFINITION",
+                Self::SubstanceNucleicAcid => "SUBSTANCE_NUCLEIC_ACID",
+                Self::SubstancePolymer => "SUBSTANCE_POLYMER",
+                Self::SubstanceProtein => "SUBSTANCE_PROTEIN",
+                Self::SubstanceReferenceInformation => "SUBSTANCE_REFERENCE_INFORMATION",
+                Self::SubstanceSourceMaterial => "SUBSTANCE_SOURCE_MATERIAL",
+                Self::SupplyDelivery => "SUPPLY_DELIVERY",
+                Self::SupplyRequest => "SUPPLY_REQUEST",
+                Self::Task => "TASK",
+                Self::TerminologyCapabilities => "TERMINOLOGY_CAPABILITIES",
+                Self::TestPlan => "TEST_PLAN",
+                Self::TestReport => "TEST_REPORT",
+                Self::TestScript => "TEST_SCRIPT",
+                Self::Transport => "TRANSPORT",
+                Self::Set => "VALUE_SET",
+                Self::VerificationResult => "VERIFICATION_RESULT",
+                Self::VisionPrescription => "VISION_PRESCRIPTION",
+                Self::BodySite => "BODY_SITE",
+                Self::CatalogEntry => "CATALOG_ENTRY",
+                Self::Conformance => "CONFORMANCE",
+                Self::DataElement => "DATA_ELEMENT",
+                Self::DeviceComponent => "DEVICE_COMPONENT",
+                Self::DeviceUseRequest => "DEVICE_USE_REQUEST",
+                Self::DeviceUseStatement => "DEVICE_USE_STATEMENT",
+                Self::DiagnosticOrder => "DIAGNOSTIC_ORDER",
+                Self::DocumentManifest => "DOCUMENT_MANIFEST",
+                Self::EffectEvidenceSynthesis => "EFFECT_EVIDENCE_SYNTHESIS",
+                Self::EligibilityRequest => "ELIGIBILITY_REQUEST",
+                Self::EligibilityResponse => "ELIGIBILITY_RESPONSE",
+                Self::ExpansionProfile => "EXPANSION_PROFILE",
+                Self::ImagingManifest => "IMAGING_MANIFEST",
+                Self::ImagingObjectSelection => "IMAGING_OBJECT_SELECTION",
+                Self::Media => "MEDIA",
+                Self::MedicationOrder => "MEDICATION_ORDER",
+                Self::MedicationUsage => "MEDICATION_USAGE",
+                Self::MedicinalProduct => "MEDICINAL_PRODUCT",
+                Self::MedicinalProductAuthorization => "MEDICINAL_PRODUCT_AUTHORIZATION",
+                Self::MedicinalProductContraindication => {
+                    "MEDICINAL_PRODUCT_CONTRAINDICATION"
+                }
+                Self::MedicinalProductIndication => "MEDICINAL_PRODUCT_INDICATION",
+                Self::MedicinalProductIngredient => "MEDICINAL_PRODUCT_INGREDIENT",
+                Self::MedicinalProductInteraction => "MEDICINAL_PRODUCT_INTERACTION",
+                Self::MedicinalProductManufactured => "MEDICINAL_PRODUCT_MANUFACTURED",
+                Self::MedicinalProductPackaged => "MEDICINAL_PRODUCT_PACKAGED",
+                Self::MedicinalProductPharmaceutical => {
+                    "MEDICINAL_PRODUCT_PHARMACEUTICAL"
+                }
+                Self::MedicinalProductUndesirableEffect => {
+                    "MEDICINAL_PRODUCT_UNDESIRABLE_EFFECT"
+                }
+                Self::Order => "ORDER",
+                Self::OrderResponse => "ORDER_RESPONSE",
+                Self::ProcedureRequest => "PROCEDURE_REQUEST",
+                Self::ProcessRequest => "PROCESS_REQUEST",
+                Self::ProcessResponse => "PROCESS_RESPONSE",
+                Self::ReferralRequest => "REFERRAL_REQUEST",
+                Self::RequestGroup => "REQUEST_GROUP",
+                Self::ResearchDefinition => "RESEARCH_DEFINITION",
+                Self::ResearchElementDefinition => "RESEARCH_ELEMENT_DEFINITION",
+                Self::RiskEvidenceSynthesis => "RISK_EVIDENCE_SYNTHESIS",
+                Self::Sequence => "SEQUENCE",
+                Self::ServiceDefinition => "SERVICE_DEFINITION",
+                Self::SubstanceSpecification => "SUBSTANCE_SPECIFICATION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "ACCOUNT" => Some(Self::Account),
+                "ACTIVITY_DEFINITION" => Some(Self::ActivityDefinition),
+                "ACTOR_DEFINITION" => Some(Self::ActorDefinition),
+                "ADMINISTRABLE_PRODUCT_DEFINITION" => {
+                    Some(Self::AdministrableProductDefinition)
+                }
+                "ADVERSE_EVENT" => Some(Self::AdverseEvent),
+                "ALLERGY_INTOLERANCE" => Some(Self::AllergyIntolerance),
+                "APPOINTMENT" => Some(Self::Appointment),
+                "APPOINTMENT_RESPONSE" => Some(Self::AppointmentResponse),
+                "ARTIFACT_ASSESSMENT" => Some(Self::ArtifactAssessment),
+                "AUDIT_EVENT" => Some(Self::AuditEvent),
+                "BASIC" => Some(Self::Basic),
+                "BINARY" => Some(Self::Binary),
+                "BIOLOGICALLY_DERIVED_PRODUCT" => Some(Self::BiologicallyDerivedProduct),
+                "BIOLOGICALLY_DERIVED_PRODUCT_DISPENSE" => {
+                    Some(Self::BiologicallyDerivedProductDispense)
+                }
+                "BODY_STRUCTURE" => Some(Self::BodyStructure),
+                "BUNDLE" => Some(Self::Bundle),
+                "CANONICAL_RESOURCE" => Some(Self::CanonicalResource),
+                "CAPABILITY_STATEMENT" => Some(Self::CapabilityStatement),
+                "CARE_PLAN" => Some(Self::CarePlan),
+                "CARE_TEAM" => Some(Self::CareTeam),
+                "CHARGE_ITEM" => Some(Self::ChargeItem),
+                "CHARGE_ITEM_DEFINITION" => Some(Self::ChargeItemDefinition),
+                "CITATION" => Some(Self::Citation),
+                "CLAIM" => Some(Self::Claim),
+                "CLAIM_RESPONSE" => Some(Self::ClaimResponse),
+                "CLINICAL_IMPRESSION" => Some(Self::ClinicalImpression),
+                "CLINICAL_USE_DEFINITION" => Some(Self::ClinicalUseDefinition),
+                "CODE_SYSTEM" => Some(Self::CodeSystem),
+                "COMMUNICATION" => Some(Self::Communication),
+                "COMMUNICATION_REQUEST" => Some(Self::CommunicationRequest),
+                "COMPARTMENT_DEFINITION" => Some(Self::CompartmentDefinition),
+                "COMPOSITION" => Some(Self::Composition),
+                "CONCEPT_MAP" => Some(Self::ConceptMap),
+                "CONDITION" => Some(Self::Condition),
+                "CONDITION_DEFINITION" => Some(Self::ConditionDefinition),
+                "CONSENT" => Some(Self::Consent),
+                "CONTRACT" => Some(Self::Contract),
+                "COVERAGE" => Some(Self::Coverage),
+                "COVERAGE_ELIGIBILITY_REQUEST" => Some(Self::CoverageEligibilityRequest),
+                "COVERAGE_ELIGIBILITY_RESPONSE" => {
+                    Some(Self::CoverageEligibilityResponse)
+                }
+                "DETECTED_ISSUE" => Some(Self::DetectedIssue),
+                "DEVICE" => Some(Self::Device),
+                "DEVICE_ASSOCIATION" => Some(Self::DeviceAssociation),
+                "DEVICE_DEFINITION" => Some(Self::DeviceDefinition),
+                "DEVICE_DISPENSE" => Some(Self::DeviceDispense),
+                "DEVICE_METRIC" => Some(Self::DeviceMetric),
+                "DEVICE_REQUEST" => Some(Self::DeviceRequest),
+                "DEVICE_USAGE" => Some(Self::DeviceUsage),
+                "DIAGNOSTIC_REPORT" => Some(Self::DiagnosticReport),
+                "DOCUMENT_REFERENCE" => Some(Self::DocumentReference),
+                "DOMAIN_RESOURCE" => Some(Self::DomainResource),
+                "ENCOUNTER" => Some(Self::Encounter),
+                "ENCOUNTER_HISTORY" => Some(Self::EncounterHistory),
+                "ENDPOINT" => Some(Self::Endpoint),
+                "ENROLLMENT_REQUEST" => Some(Self::EnrollmentRequest),
+                "ENROLLMENT_RESPONSE" => Some(Self::EnrollmentResponse),
+                "EPISODE_OF_CARE" => Some(Self::EpisodeOfCare),
+                "EVENT_DEFINITION" => Some(Self::EventDefinition),
+                "EVIDENCE" => Some(Self::Evidence),
+                "EVIDENCE_REPORT" => Some(Self::EvidenceReport),
+                "EVIDENCE_VARIABLE" => Some(Self::EvidenceVariable),
+                "EXAMPLE_SCENARIO" => Some(Self::ExampleScenario),
+                "EXPLANATION_OF_BENEFIT" => Some(Self::ExplanationOfBenefit),
+                "FAMILY_MEMBER_HISTORY" => Some(Self::FamilyMemberHistory),
+                "FLAG" => Some(Self::Flag),
+                "FORMULARY_ITEM" => Some(Self::FormularyItem),
+                "GENOMIC_STUDY" => Some(Self::GenomicStudy),
+                "GOAL" => Some(Self::Goal),
+                "GRAPH_DEFINITION" => Some(Self::GraphDefinition),
+                "GROUP" => Some(Self::Group),
+                "GUIDANCE_RESPONSE" => Some(Self::GuidanceResponse),
+                "HEALTHCARE_SERVICE" => Some(Self::HealthcareService),
+                "IMAGING_SELECTION" => Some(Self::ImagingSelection),
+                "IMAGING_STUDY" => Some(Self::ImagingStudy),
+                "IMMUNIZATION" => Some(Self::Immunization),
+                "IMMUNIZATION_EVALUATION" => Some(Self::ImmunizationEvaluation),
+                "IMMUNIZATION_RECOMMENDATION" => Some(Self::ImmunizationRecommendation),
+                "IMPLEMENTATION_GUIDE" => Some(Self::ImplementationGuide),
+                "INGREDIENT" => Some(Self::Ingredient),
+                "INSURANCE_PLAN" => Some(Self::InsurancePlan),
+                "INVENTORY_ITEM" => Some(Self::InventoryItem),
+                "INVENTORY_REPORT" => Some(Self::InventoryReport),
+                "INVOICE" => Some(Self::Invoice),
+                "LIBRARY" => Some(Self::Library),
+                "LINKAGE" => Some(Self::Linkage),
+                "LIST" => Some(Self::List),
+                "LOCATION" => Some(Self::Location),
+                "MANUFACTURED_ITEM_DEFINITION" => Some(Self::ManufacturedItemDefinition),
+                "MEASURE" => Some(Self::Measure),
+                "MEASURE_REPORT" => Some(Self::MeasureReport),
+                "MEDICATION" => Some(Self::Medication),
+                "MEDICATION_ADMINISTRATION" => Some(Self::MedicationAdministration),
+                "MEDICATION_DISPENSE" => Some(Self::MedicationDispense),
+                "MEDICATION_KNOWLEDGE" => Some(Self::MedicationKnowledge),
+                "MEDICATION_REQUEST" => Some(Self::MedicationRequest),
+                "MEDICATION_STATEMENT" => Some(Self::MedicationStatement),
+                "MEDICINAL_PRODUCT_DEFINITION" => Some(Self::MedicinalProductDefinition),
+                "MESSAGE_DEFINITION" => Some(Self::MessageDefinition),
+                "MESSAGE_HEADER" => Some(Self::MessageHeader),
+                "METADATA_RESOURCE" => Some(Self::MetadataResource),
+                "MOLECULAR_SEQUENCE" => Some(Self::MolecularSequence),
+                "NAMING_SYSTEM" => Some(Self::NamingSystem),
+                "NUTRITION_INTAKE" => Some(Self::NutritionIntake),
+                "NUTRITION_ORDER" => Some(Self::NutritionOrder),
+                "NUTRITION_PRODUCT" => Some(Self::NutritionProduct),
+                "OBSERVATION" => Some(Self::Observation),
+                "OBSERVATION_DEFINITION" => Some(Self::ObservationDefinition),
+                "OPERATION_DEFINITION" => Some(Self::OperationDefinition),
+                "OPERATION_OUTCOME" => Some(Self::OperationOutcome),
+                "ORGANIZATION" => Some(Self::Organization),
+                "ORGANIZATION_AFFILIATION" => Some(Self::OrganizationAffiliation),
+                "PACKAGED_PRODUCT_DEFINITION" => Some(Self::PackagedProductDefinition),
+                "PARAMETERS" => Some(Self::Parameters),
+                "PATIENT" => Some(Self::Patient),
+                "PAYMENT_NOTICE" => Some(Self::PaymentNotice),
+                "PAYMENT_RECONCILIATION" => Some(Self::PaymentReconciliation),
+                "PERMISSION" => Some(Self::Permission),
+                "PERSON" => Some(Self::Person),
+                "PLAN_DEFINITION" => Some(Self::PlanDefinition),
+                "PRACTITIONER" => Some(Self::Practitioner),
+                "PRACTITIONER_ROLE" => Some(Self::PractitionerRole),
+                "PROCEDURE" => Some(Self::Procedure),
+                "PROVENANCE" => Some(Self::Provenance),
+                "QUESTIONNAIRE" => Some(Self::Questionnaire),
+                "QUESTIONNAIRE_RESPONSE" => Some(Self::QuestionnaireResponse),
+                "REGULATED_AUTHORIZATION" => Some(Self::RegulatedAuthorization),
+                "RELATED_PERSON" => Some(Self::RelatedPerson),
+                "REQUEST_ORCHESTRATION" => Some(Self::RequestOrchestration),
+                "REQUIREMENTS" => Some(Self::Requirements),
+                "RESEARCH_STUDY" => Some(Self::ResearchStudy),
+                "RESEARCH_SUBJECT" => Some(Self::ResearchSubject),
+                "RESOURCE" => Some(Self::Resource),
+                "RISK_ASSESSMENT" => Some(Self::RiskAssessment),
+                "SCHEDULE" => Some(Self::Schedule),
+                "SEARCH_PARAMETER" => Some(Self::SearchParameter),
+                "SERVICE_REQUEST" => Some(Self::ServiceRequest),
+                "SLOT" => Some(Self::Slot),
+                "SPECIMEN" => Some(Self::Specimen),
+                "SPECIMEN_DEFINITION" => Some(Self::SpecimenDefinition),
+                "STRUCTURE_DEFINITION" => Some(Self::StructureDefinition),
+                "STRUCTURE_MAP" => Some(Self::StructureMap),
+                "SUBSCRIPTION" => Some(Self::Subscription),
+                "SUBSCRIPTION_STATUS" => Some(Self::SubscriptionStatus),
+                "SUBSCRIPTION_TOPIC" => Some(Self::SubscriptionTopic),
+                "SUBSTANCE" => Some(Self::Substance),
+                "SUBSTANCE_DEFINITION" => Some(Self::SubstanceDefinition),
+                "SUBSTANCE_NUCLEIC_ACID" => Some(Self::SubstanceNucleicAcid),
+                "SUBSTANCE_POLYMER" => Some(Self::SubstancePolymer),
+                "SUBSTANCE_PROTEIN" => Some(Self::SubstanceProtein),
+                "SUBSTANCE_REFERENCE_INFORMATION" => {
+                    Some(Self::SubstanceReferenceInformation)
+                }
+                "SUBSTANCE_SOURCE_MATERIAL" => Some(Self::SubstanceSourceMaterial),
+                "SUPPLY_DELIVERY" => Some(Self::SupplyDelivery),
+                "SUPPLY_REQUEST" => Some(Self::SupplyRequest),
+                "TASK" => Some(Self::Task),
+                "TERMINOLOGY_CAPABILITIES" => Some(Self::TerminologyCapabilities),
+                "TEST_PLAN" => Some(Self::TestPlan),
+                "TEST_REPORT" => Some(Self::TestReport),
+                "TEST_SCRIPT" => Some(Self::TestScript),
+                "TRANSPORT" => Some(Self::Transport),
+                "VALUE_SET" => Some(Self::Set),
+                "VERIFICATION_RESULT" => Some(Self::VerificationResult),
+                "VISION_PRESCRIPTION" => Some(Self::VisionPrescription),
+                "BODY_SITE" => Some(Self::BodySite),
+                "CATALOG_ENTRY" => Some(Self::CatalogEntry),
+                "CONFORMANCE" => Some(Self::Conformance),
+                "DATA_ELEMENT" => Some(Self::DataElement),
+                "DEVICE_COMPONENT" => Some(Self::DeviceComponent),
+                "DEVICE_USE_REQUEST" => Some(Self::DeviceUseRequest),
+                "DEVICE_USE_STATEMENT" => Some(Self::DeviceUseStatement),
+                "DIAGNOSTIC_ORDER" => Some(Self::DiagnosticOrder),
+                "DOCUMENT_MANIFEST" => Some(Self::DocumentManifest),
+                "EFFECT_EVIDENCE_SYNTHESIS" => Some(Self::EffectEvidenceSynthesis),
+                "ELIGIBILITY_REQUEST" => Some(Self::EligibilityRequest),
+                "ELIGIBILITY_RESPONSE" => Some(Self::EligibilityResponse),
+                "EXPANSION_PROFILE" => Some(Self::ExpansionProfile),
+                "IMAGING_MANIFEST" => Some(Self::ImagingManifest),
+                "IMAGING_OBJECT_SELECTION" => Some(Self::ImagingObjectSelection),
+                "MEDIA" => Some(Self::Media),
+                "MEDICATION_ORDER" => Some(Self::MedicationOrder),
+                "MEDICATION_USAGE" => Some(Self::MedicationUsage),
+                "MEDICINAL_PRODUCT" => Some(Self::MedicinalProduct),
+                "MEDICINAL_PRODUCT_AUTHORIZATION" => {
+                    Some(Self::MedicinalProductAuthorization)
+                }
+                "MEDICINAL_PRODUCT_CONTRAINDICATION" => {
+                    Some(Self::MedicinalProductContraindication)
+                }
+                "MEDICINAL_PRODUCT_INDICATION" => Some(Self::MedicinalProductIndication),
+                "MEDICINAL_PRODUCT_INGREDIENT" => Some(Self::MedicinalProductIngredient),
+                "MEDICINAL_PRODUCT_INTERACTION" => {
+                    Some(Self::MedicinalProductInteraction)
+                }
+                "MEDICINAL_PRODUCT_MANUFACTURED" => {
+                    Some(Self::MedicinalProductManufactured)
+                }
+                "MEDICINAL_PRODUCT_PACKAGED" => Some(Self::MedicinalProductPackaged),
+                "MEDICINAL_PRODUCT_PHARMACEUTICAL" => {
+                    Some(Self::MedicinalProductPharmaceutical)
+                }
+                "MEDICINAL_PRODUCT_UNDESIRABLE_EFFECT" => {
+                    Some(Self::MedicinalProductUndesirableEffect)
+                }
+                "ORDER" => Some(Self::Order),
+                "ORDER_RESPONSE" => Some(Self::OrderResponse),
+                "PROCEDURE_REQUEST" => Some(Self::ProcedureRequest),
+                "PROCESS_REQUEST" => Some(Self::ProcessRequest),
+                "PROCESS_RESPONSE" => Some(Self::ProcessResponse),
+                "REFERRAL_REQUEST" => Some(Self::ReferralRequest),
+                "REQUEST_GROUP" => Some(Self::RequestGroup),
+                "RESEARCH_DEFINITION" => Some(Self::ResearchDefinition),
+                "RESEARCH_ELEMENT_DEFINITION" => Some(Self::ResearchElementDefinition),
+                "RISK_EVIDENCE_SYNTHESIS" => Some(Self::RiskEvidenceSynthesis),
+                "SEQUENCE" => Some(Self::Sequence),
+                "SERVICE_DEFINITION" => Some(Self::ServiceDefinition),
+                "SUBSTANCE_SPECIFICATION" => Some(Self::SubstanceSpecification),
+                _ => None,
+            }
+        }
+    }
+}
+/// Common UCUM units for recording Vital Signs
+/// See <http://hl7.org/fhir/ValueSet/ucum-vitals-common>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VitalSignsUnitsValueSet {}
+/// Nested message and enum types in `VitalSignsUnitsValueSet`.
+pub mod vital_signs_units_value_set {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Value {
+        InvalidUninitialized = 0,
+        Percent = 1,
+        Cm = 2,
+        InI = 3,
+        Kg = 4,
+        G = 5,
+        LbAv = 6,
+        Cel = 7,
+        DegF = 8,
+        MmHg = 9,
+        PerMin = 10,
+        KgM2 = 11,
+        M2 = 12,
+    }
+    impl Value {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::InvalidUninitialized => "INVALID_UNINITIALIZED",
+                Self::Percent => "PERCENT",
+                Self::Cm => "CM",
+                Self::InI => "IN_I",
+                Self::Kg => "KG",
+                Self::G => "G",
+                Self::LbAv => "LB_AV",
+                Self::Cel => "CEL",
+                Self::DegF => "DEG_F",
+                Self::MmHg => "MM_HG",
+                Self::PerMin => "PER_MIN",
+                Self::KgM2 => "KG_M2",
+                Self::M2 => "M2",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "INVALID_UNINITIALIZED" => Some(Self::InvalidUninitialized),
+                "PERCENT" => Some(Self::Percent),
+                "CM" => Some(Self::Cm),
+                "IN_I" => Some(Self::InI),
+                "KG" => Some(Self::Kg),
+                "G" => Some(Self::G),
+                "LB_AV" => Some(Self::LbAv),
+                "CEL" => Some(Self::Cel),
+                "DEG_F" => Some(Self::DegF),
+                "MM_HG" => Some(Self::MmHg),
+                "PER_MIN" => Some(Self::PerMin),
+                "KG_M2" => Some(Self::KgM2),
+                "M2" => Some(Self::M2),
+                _ => None,
+            }
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Address.
+/// An address expressed using postal conventions (as opposed to GPS or other
+/// location definition formats). See
+/// <http://hl7.org/fhir/StructureDefinition/Address>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Address {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#use: ::core::option::Option<address::UseCode>,
+    #[prost(message, optional, tag = "4")]
+    pub r#type: ::core::option::Option<address::TypeCode>,
+    /// Text representation of the address
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<String>,
+    /// Street name, number, direction & P.O. Box etc.
+    #[prost(message, repeated, tag = "6")]
+    pub line: ::prost::alloc::vec::Vec<String>,
+    /// Name of city, town etc.
+    #[prost(message, optional, tag = "7")]
+    pub city: ::core::option::Option<String>,
+    /// District name (aka county)
+    #[prost(message, optional, tag = "8")]
+    pub district: ::core::option::Option<String>,
+    /// Sub-unit of country (abbreviations ok)
+    #[prost(message, optional, tag = "9")]
+    pub state: ::core::option::Option<String>,
+    /// Postal code for area
+    #[prost(message, optional, tag = "10")]
+    pub postal_code: ::core::option::Option<String>,
+    /// Country (e.g. may be ISO 3166 2 or 3 letter code)
+    #[prost(message, optional, tag = "11")]
+    pub country: ::core::option::Option<String>,
+    /// Time period when address was/is in use
+    #[prost(message, optional, tag = "12")]
+    pub period: ::core::option::Option<Period>,
+}
+/// Nested message and enum types in `Address`.
+pub mod address {
+    /// home | work | temp | old | billing - purpose of this address
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UseCode {
+        #[prost(enumeration = "super::address_use_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// postal | physical | both
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::address_type_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Age.
+/// A duration of time during which an organism (or a process) has existed.
+/// See <http://hl7.org/fhir/StructureDefinition/Age>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Age {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub comparator: ::core::option::Option<age::ComparatorCode>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Nested message and enum types in `Age`.
+pub mod age {
+    /// < | <= | >= | > | ad - how to understand the value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ComparatorCode {
+        #[prost(enumeration = "super::quantity_comparator_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Annotation.
+/// Text node with attribution.
+/// See <http://hl7.org/fhir/StructureDefinition/Annotation>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Annotation {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub author: ::core::option::Option<annotation::AuthorX>,
+    /// When the annotation was made
+    #[prost(message, optional, tag = "4")]
+    pub time: ::core::option::Option<DateTime>,
+    /// The annotation  - text content (as markdown)
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<Markdown>,
+}
+/// Nested message and enum types in `Annotation`.
+pub mod annotation {
+    /// Individual responsible for the annotation
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AuthorX {
+        #[prost(oneof = "author_x::Choice", tags = "1, 2")]
+        pub choice: ::core::option::Option<author_x::Choice>,
+    }
+    /// Nested message and enum types in `AuthorX`.
+    pub mod author_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Reference(super::super::Reference),
+            #[prost(message, tag = "2")]
+            StringValue(super::super::String),
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Attachment.
+/// Content in a format defined elsewhere.
+/// See <http://hl7.org/fhir/StructureDefinition/Attachment>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Attachment {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub content_type: ::core::option::Option<attachment::ContentTypeCode>,
+    /// Human language of the content (BCP-47)
+    #[prost(message, optional, tag = "4")]
+    pub language: ::core::option::Option<Code>,
+    /// Data inline, base64ed
+    #[prost(message, optional, tag = "5")]
+    pub data: ::core::option::Option<Base64Binary>,
+    /// Uri where the data can be found
+    #[prost(message, optional, tag = "6")]
+    pub url: ::core::option::Option<Url>,
+    /// Number of bytes of content (if url provided)
+    #[prost(message, optional, tag = "11")]
+    pub size: ::core::option::Option<Integer64>,
+    /// Hash of the data (sha-1, base64ed)
+    #[prost(message, optional, tag = "8")]
+    pub hash: ::core::option::Option<Base64Binary>,
+    /// Label to display in place of the data
+    #[prost(message, optional, tag = "9")]
+    pub title: ::core::option::Option<String>,
+    /// Date attachment was first created
+    #[prost(message, optional, tag = "10")]
+    pub creation: ::core::option::Option<DateTime>,
+    /// Height of the image in pixels (photo/video)
+    #[prost(message, optional, tag = "12")]
+    pub height: ::core::option::Option<PositiveInt>,
+    /// Width of the image in pixels (photo/video)
+    #[prost(message, optional, tag = "13")]
+    pub width: ::core::option::Option<PositiveInt>,
+    /// Number of frames if > 1 (photo)
+    #[prost(message, optional, tag = "14")]
+    pub frames: ::core::option::Option<PositiveInt>,
+    /// Length in seconds (audio / video)
+    #[prost(message, optional, tag = "15")]
+    pub duration: ::core::option::Option<Decimal>,
+    /// Number of printed pages
+    #[prost(message, optional, tag = "16")]
+    pub pages: ::core::option::Option<PositiveInt>,
+}
+/// Nested message and enum types in `Attachment`.
+pub mod attachment {
+    /// Mime type of the content, with charset etc.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ContentTypeCode {
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// This valueset is not enumerable, and so is represented as a string.
+        #[prost(string, tag = "4")]
+        pub value: ::prost::alloc::string::String,
+    }
+}
+/// Auto-generated from StructureDefinition for Availability.
+/// Availability data for an {item}.
+/// See <http://hl7.org/fhir/StructureDefinition/Availability>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Availability {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, repeated, tag = "3")]
+    pub available_time: ::prost::alloc::vec::Vec<availability::AvailableTime>,
+    #[prost(message, repeated, tag = "4")]
+    pub not_available_time: ::prost::alloc::vec::Vec<availability::NotAvailableTime>,
+}
+/// Nested message and enum types in `Availability`.
+pub mod availability {
+    /// Times the {item} is available
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AvailableTime {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        #[prost(message, repeated, tag = "3")]
+        pub days_of_week: ::prost::alloc::vec::Vec<available_time::DaysOfWeekCode>,
+        /// Always available? i.e. 24 hour service
+        #[prost(message, optional, tag = "4")]
+        pub all_day: ::core::option::Option<super::Boolean>,
+        /// Opening time of day (ignored if allDay = true)
+        #[prost(message, optional, tag = "5")]
+        pub available_start_time: ::core::option::Option<super::Time>,
+        /// Closing time of day (ignored if allDay = true)
+        #[prost(message, optional, tag = "6")]
+        pub available_end_time: ::core::option::Option<super::Time>,
+    }
+    /// Nested message and enum types in `AvailableTime`.
+    pub mod available_time {
+        /// mon | tue | wed | thu | fri | sat | sun
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DaysOfWeekCode {
+            #[prost(enumeration = "super::super::days_of_week_code::Value", tag = "1")]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+    /// Not available during this time due to provided reason
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NotAvailableTime {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Reason presented to the user explaining why time not available
+        #[prost(message, optional, tag = "3")]
+        pub description: ::core::option::Option<super::String>,
+        /// Service not available during this period
+        #[prost(message, optional, tag = "4")]
+        pub during: ::core::option::Option<super::Period>,
+    }
+}
+/// Auto-generated from StructureDefinition for BackboneElement.
+/// Base for elements defined inside a resource.
+/// See <http://hl7.org/fhir/StructureDefinition/BackboneElement>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BackboneElement {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for BackboneType.
+/// Base for datatypes that can carry modifier extensions.
+/// See <http://hl7.org/fhir/StructureDefinition/BackboneType>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BackboneType {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for Base.
+/// Base for all types and resources.
+/// See <http://hl7.org/fhir/StructureDefinition/Base>
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Base {}
+/// Auto-generated from StructureDefinition for base64Binary.
+/// Primitive Type base64Binary.
+/// See <http://hl7.org/fhir/StructureDefinition/base64Binary>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Base64Binary {
+    /// Primitive value for base64Binary
+    #[prost(bytes = "vec", tag = "1")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for boolean.
+/// Primitive Type boolean.
+/// See <http://hl7.org/fhir/StructureDefinition/boolean>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Boolean {
+    /// Primitive value for boolean
+    #[prost(bool, tag = "1")]
+    pub value: bool,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for canonical.
+/// Primitive Type canonical.
+/// See <http://hl7.org/fhir/StructureDefinition/canonical>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Canonical {
+    /// Primitive value for canonical
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for code.
+/// Primitive Type code.
+/// See <http://hl7.org/fhir/StructureDefinition/code>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Code {
+    /// Primitive value for code
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for CodeableConcept.
+/// Concept - reference to a terminology or just  text.
+/// See <http://hl7.org/fhir/StructureDefinition/CodeableConcept>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CodeableConcept {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Code defined by a terminology system
+    #[prost(message, repeated, tag = "3")]
+    pub coding: ::prost::alloc::vec::Vec<Coding>,
+    /// Plain text representation of the concept
+    #[prost(message, optional, tag = "4")]
+    pub text: ::core::option::Option<String>,
+}
+/// Auto-generated from StructureDefinition for CodeableReference.
+/// Reference to a resource or a concept.
+/// See <http://hl7.org/fhir/StructureDefinition/CodeableReference>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CodeableReference {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Reference to a concept (by class)
+    #[prost(message, optional, tag = "3")]
+    pub concept: ::core::option::Option<CodeableConcept>,
+    /// Reference to a resource (by instance)
+    #[prost(message, optional, tag = "4")]
+    pub reference: ::core::option::Option<Reference>,
+}
+/// Auto-generated from StructureDefinition for Coding.
+/// A reference to a code defined by a terminology system.
+/// See <http://hl7.org/fhir/StructureDefinition/Coding>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Coding {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Identity of the terminology system
+    #[prost(message, optional, tag = "3")]
+    pub system: ::core::option::Option<Uri>,
+    /// Version of the system - if relevant
+    #[prost(message, optional, tag = "4")]
+    pub version: ::core::option::Option<String>,
+    /// Symbol in syntax defined by the system
+    #[prost(message, optional, tag = "5")]
+    pub code: ::core::option::Option<Code>,
+    /// Representation defined by the system
+    #[prost(message, optional, tag = "6")]
+    pub display: ::core::option::Option<String>,
+    /// If this coding was chosen directly by the user
+    #[prost(message, optional, tag = "7")]
+    pub user_selected: ::core::option::Option<Boolean>,
+}
+/// Auto-generated from StructureDefinition for ContactDetail.
+/// Contact information.
+/// See <http://hl7.org/fhir/StructureDefinition/ContactDetail>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContactDetail {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Name of an individual to contact
+    #[prost(message, optional, tag = "3")]
+    pub name: ::core::option::Option<String>,
+    /// Contact details for individual or organization
+    #[prost(message, repeated, tag = "4")]
+    pub telecom: ::prost::alloc::vec::Vec<ContactPoint>,
+}
+/// Auto-generated from StructureDefinition for ContactPoint.
+/// Details of a Technology mediated contact point (phone, fax, email, etc.).
+/// See <http://hl7.org/fhir/StructureDefinition/ContactPoint>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContactPoint {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub system: ::core::option::Option<contact_point::SystemCode>,
+    /// The actual contact point details
+    #[prost(message, optional, tag = "4")]
+    pub value: ::core::option::Option<String>,
+    #[prost(message, optional, tag = "5")]
+    pub r#use: ::core::option::Option<contact_point::UseCode>,
+    /// Specify preferred order of use (1 = highest)
+    #[prost(message, optional, tag = "6")]
+    pub rank: ::core::option::Option<PositiveInt>,
+    /// Time period when the contact point was/is in use
+    #[prost(message, optional, tag = "7")]
+    pub period: ::core::option::Option<Period>,
+}
+/// Nested message and enum types in `ContactPoint`.
+pub mod contact_point {
+    /// phone | fax | email | pager | url | sms | other
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SystemCode {
+        #[prost(enumeration = "super::contact_point_system_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// home | work | temp | old | mobile - purpose of this contact point
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UseCode {
+        #[prost(enumeration = "super::contact_point_use_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Contributor.
+/// Contributor information.
+/// See <http://hl7.org/fhir/StructureDefinition/Contributor>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Contributor {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#type: ::core::option::Option<contributor::TypeCode>,
+    /// Who contributed the content
+    #[prost(message, optional, tag = "4")]
+    pub name: ::core::option::Option<String>,
+    /// Contact details of the contributor
+    #[prost(message, repeated, tag = "5")]
+    pub contact: ::prost::alloc::vec::Vec<ContactDetail>,
+}
+/// Nested message and enum types in `Contributor`.
+pub mod contributor {
+    /// author | editor | reviewer | endorser
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::contributor_type_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Count.
+/// A measured or measurable amount.
+/// See <http://hl7.org/fhir/StructureDefinition/Count>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Count {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub comparator: ::core::option::Option<count::ComparatorCode>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Nested message and enum types in `Count`.
+pub mod count {
+    /// < | <= | >= | > | ad - how to understand the value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ComparatorCode {
+        #[prost(enumeration = "super::quantity_comparator_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for DataRequirement.
+/// Describes a required data item.
+/// See <http://hl7.org/fhir/StructureDefinition/DataRequirement>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataRequirement {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "11")]
+    pub r#type: ::core::option::Option<data_requirement::TypeCode>,
+    /// The profile of the required data
+    #[prost(message, repeated, tag = "4")]
+    pub profile: ::prost::alloc::vec::Vec<Canonical>,
+    #[prost(message, optional, tag = "5")]
+    pub subject: ::core::option::Option<data_requirement::SubjectX>,
+    /// Indicates specific structure elements that are referenced by the knowledge
+    /// module
+    #[prost(message, repeated, tag = "6")]
+    pub must_support: ::prost::alloc::vec::Vec<String>,
+    #[prost(message, repeated, tag = "7")]
+    pub code_filter: ::prost::alloc::vec::Vec<data_requirement::CodeFilter>,
+    #[prost(message, repeated, tag = "8")]
+    pub date_filter: ::prost::alloc::vec::Vec<data_requirement::DateFilter>,
+    #[prost(message, repeated, tag = "12")]
+    pub value_filter: ::prost::alloc::vec::Vec<data_requirement::ValueFilter>,
+    /// Number of results
+    #[prost(message, optional, tag = "9")]
+    pub limit: ::core::option::Option<PositiveInt>,
+    #[prost(message, repeated, tag = "10")]
+    pub sort: ::prost::alloc::vec::Vec<data_requirement::Sort>,
+}
+/// Nested message and enum types in `DataRequirement`.
+pub mod data_requirement {
+    /// The type of the required data
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::fhir_types_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// E.g. Patient, Practitioner, RelatedPerson, Organization, Location, Device
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SubjectX {
+        #[prost(oneof = "subject_x::Choice", tags = "1, 2")]
+        pub choice: ::core::option::Option<subject_x::Choice>,
+    }
+    /// Nested message and enum types in `SubjectX`.
+    pub mod subject_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            CodeableConcept(super::super::CodeableConcept),
+            #[prost(message, tag = "2")]
+            Reference(super::super::Reference),
+        }
+    }
+    /// What codes are expected
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CodeFilter {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// A code-valued attribute to filter on
+        #[prost(message, optional, tag = "3")]
+        pub path: ::core::option::Option<super::String>,
+        /// A coded (token) parameter to search on
+        #[prost(message, optional, tag = "4")]
+        pub search_param: ::core::option::Option<super::String>,
+        /// ValueSet for the filter
+        #[prost(message, optional, tag = "5")]
+        pub value_set: ::core::option::Option<super::Canonical>,
+        /// What code is expected
+        #[prost(message, repeated, tag = "6")]
+        pub code: ::prost::alloc::vec::Vec<super::Coding>,
+    }
+    /// What dates/date ranges are expected
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DateFilter {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// A date-valued attribute to filter on
+        #[prost(message, optional, tag = "3")]
+        pub path: ::core::option::Option<super::String>,
+        /// A date valued parameter to search on
+        #[prost(message, optional, tag = "4")]
+        pub search_param: ::core::option::Option<super::String>,
+        #[prost(message, optional, tag = "5")]
+        pub value: ::core::option::Option<date_filter::ValueX>,
+    }
+    /// Nested message and enum types in `DateFilter`.
+    pub mod date_filter {
+        /// The value of the filter, as a Period, DateTime, or Duration value
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ValueX {
+            #[prost(oneof = "value_x::Choice", tags = "1, 2, 3")]
+            pub choice: ::core::option::Option<value_x::Choice>,
+        }
+        /// Nested message and enum types in `ValueX`.
+        pub mod value_x {
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Choice {
+                #[prost(message, tag = "1")]
+                DateTime(super::super::super::DateTime),
+                #[prost(message, tag = "2")]
+                Period(super::super::super::Period),
+                #[prost(message, tag = "3")]
+                Duration(super::super::super::Duration),
+            }
+        }
+    }
+    /// What values are expected
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ValueFilter {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// An attribute to filter on
+        #[prost(message, optional, tag = "3")]
+        pub path: ::core::option::Option<super::String>,
+        /// A parameter to search on
+        #[prost(message, optional, tag = "4")]
+        pub search_param: ::core::option::Option<super::String>,
+        #[prost(message, optional, tag = "5")]
+        pub comparator: ::core::option::Option<value_filter::ComparatorCode>,
+        #[prost(message, optional, tag = "6")]
+        pub value: ::core::option::Option<value_filter::ValueX>,
+    }
+    /// Nested message and enum types in `ValueFilter`.
+    pub mod value_filter {
+        /// eq | gt | lt | ge | le | sa | eb
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ComparatorCode {
+            #[prost(
+                enumeration = "super::super::value_filter_comparator_value_set::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+        /// The value of the filter, as a Period, DateTime, or Duration value
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ValueX {
+            #[prost(oneof = "value_x::Choice", tags = "1, 2, 3")]
+            pub choice: ::core::option::Option<value_x::Choice>,
+        }
+        /// Nested message and enum types in `ValueX`.
+        pub mod value_x {
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Choice {
+                #[prost(message, tag = "1")]
+                DateTime(super::super::super::DateTime),
+                #[prost(message, tag = "2")]
+                Period(super::super::super::Period),
+                #[prost(message, tag = "3")]
+                Duration(super::super::super::Duration),
+            }
+        }
+    }
+    /// Order of the results
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Sort {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The name of the attribute to perform the sort
+        #[prost(message, optional, tag = "3")]
+        pub path: ::core::option::Option<super::String>,
+        #[prost(message, optional, tag = "4")]
+        pub direction: ::core::option::Option<sort::DirectionCode>,
+    }
+    /// Nested message and enum types in `Sort`.
+    pub mod sort {
+        /// ascending | descending
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DirectionCode {
+            #[prost(enumeration = "super::super::sort_direction_code::Value", tag = "1")]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for DataType.
+/// Reuseable Types.
+/// See <http://hl7.org/fhir/StructureDefinition/DataType>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataType {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for date.
+/// Primitive Type date.
+/// See <http://hl7.org/fhir/StructureDefinition/date>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Date {
+    /// The absolute time of the event as a Unix epoch in microseconds.
+    #[prost(int64, tag = "1")]
+    pub value_us: i64,
+    /// The local timezone in which the event was recorded.
+    #[prost(string, tag = "2")]
+    pub timezone: ::prost::alloc::string::String,
+    #[prost(enumeration = "date::Precision", tag = "3")]
+    pub precision: i32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "4")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "5")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Nested message and enum types in `Date`.
+pub mod date {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Precision {
+        Unspecified = 0,
+        Year = 1,
+        Month = 2,
+        Day = 3,
+    }
+    impl Precision {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "PRECISION_UNSPECIFIED",
+                Self::Year => "YEAR",
+                Self::Month => "MONTH",
+                Self::Day => "DAY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PRECISION_UNSPECIFIED" => Some(Self::Unspecified),
+                "YEAR" => Some(Self::Year),
+                "MONTH" => Some(Self::Month),
+                "DAY" => Some(Self::Day),
+                _ => None,
+            }
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for dateTime.
+/// Primitive Type dateTime.
+/// See <http://hl7.org/fhir/StructureDefinition/dateTime>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DateTime {
+    /// The absolute time of the event as a Unix epoch in microseconds.
+    #[prost(int64, tag = "1")]
+    pub value_us: i64,
+    /// The local timezone in which the event was recorded.
+    #[prost(string, tag = "2")]
+    pub timezone: ::prost::alloc::string::String,
+    #[prost(enumeration = "date_time::Precision", tag = "3")]
+    pub precision: i32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "4")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "5")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Nested message and enum types in `DateTime`.
+pub mod date_time {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Precision {
+        Unspecified = 0,
+        Year = 1,
+        Month = 2,
+        Day = 3,
+        Second = 4,
+        Millisecond = 5,
+        Microsecond = 6,
+    }
+    impl Precision {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "PRECISION_UNSPECIFIED",
+                Self::Year => "YEAR",
+                Self::Month => "MONTH",
+                Self::Day => "DAY",
+                Self::Second => "SECOND",
+                Self::Millisecond => "MILLISECOND",
+                Self::Microsecond => "MICROSECOND",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PRECISION_UNSPECIFIED" => Some(Self::Unspecified),
+                "YEAR" => Some(Self::Year),
+                "MONTH" => Some(Self::Month),
+                "DAY" => Some(Self::Day),
+                "SECOND" => Some(Self::Second),
+                "MILLISECOND" => Some(Self::Millisecond),
+                "MICROSECOND" => Some(Self::Microsecond),
+                _ => None,
+            }
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for decimal.
+/// Primitive Type decimal.
+/// See <http://hl7.org/fhir/StructureDefinition/decimal>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Decimal {
+    /// Primitive value for decimal
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for Distance.
+/// A length - a value with a unit that is a physical distance.
+/// See <http://hl7.org/fhir/StructureDefinition/Distance>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Distance {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub comparator: ::core::option::Option<distance::ComparatorCode>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Nested message and enum types in `Distance`.
+pub mod distance {
+    /// < | <= | >= | > | ad - how to understand the value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ComparatorCode {
+        #[prost(enumeration = "super::quantity_comparator_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Dosage.
+/// How the medication is/was taken or should be taken.
+/// See <http://hl7.org/fhir/StructureDefinition/Dosage>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Dosage {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// The order of the dosage instructions
+    #[prost(message, optional, tag = "4")]
+    pub sequence: ::core::option::Option<Integer>,
+    /// Free text dosage instructions e.g. SIG
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<String>,
+    /// Supplemental instruction or warnings to the patient - e.g. "with meals",
+    /// "may cause drowsiness"
+    #[prost(message, repeated, tag = "6")]
+    pub additional_instruction: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Patient or consumer oriented instructions
+    #[prost(message, optional, tag = "7")]
+    pub patient_instruction: ::core::option::Option<String>,
+    /// When medication should be administered
+    #[prost(message, optional, tag = "8")]
+    pub timing: ::core::option::Option<Timing>,
+    /// Take "as needed"
+    #[prost(message, optional, tag = "17")]
+    pub as_needed: ::core::option::Option<Boolean>,
+    /// Take "as needed" (for x)
+    #[prost(message, repeated, tag = "18")]
+    pub as_needed_for: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Body site to administer to
+    #[prost(message, optional, tag = "10")]
+    pub site: ::core::option::Option<CodeableConcept>,
+    /// How drug should enter body
+    #[prost(message, optional, tag = "11")]
+    pub route: ::core::option::Option<CodeableConcept>,
+    /// Technique for administering medication
+    #[prost(message, optional, tag = "12")]
+    pub method: ::core::option::Option<CodeableConcept>,
+    #[prost(message, repeated, tag = "13")]
+    pub dose_and_rate: ::prost::alloc::vec::Vec<dosage::DoseAndRate>,
+    /// Upper limit on medication per unit of time
+    #[prost(message, repeated, tag = "19")]
+    pub max_dose_per_period: ::prost::alloc::vec::Vec<Ratio>,
+    /// Upper limit on medication per administration
+    #[prost(message, optional, tag = "15")]
+    pub max_dose_per_administration: ::core::option::Option<SimpleQuantity>,
+    /// Upper limit on medication per lifetime of the patient
+    #[prost(message, optional, tag = "16")]
+    pub max_dose_per_lifetime: ::core::option::Option<SimpleQuantity>,
+}
+/// Nested message and enum types in `Dosage`.
+pub mod dosage {
+    /// Amount of medication administered, to be administered or typical amount to
+    /// be administered
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DoseAndRate {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The kind of dose or rate specified
+        #[prost(message, optional, tag = "3")]
+        pub r#type: ::core::option::Option<super::CodeableConcept>,
+        #[prost(message, optional, tag = "4")]
+        pub dose: ::core::option::Option<dose_and_rate::DoseX>,
+        #[prost(message, optional, tag = "5")]
+        pub rate: ::core::option::Option<dose_and_rate::RateX>,
+    }
+    /// Nested message and enum types in `DoseAndRate`.
+    pub mod dose_and_rate {
+        /// Amount of medication per dose
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DoseX {
+            #[prost(oneof = "dose_x::Choice", tags = "1, 2")]
+            pub choice: ::core::option::Option<dose_x::Choice>,
+        }
+        /// Nested message and enum types in `DoseX`.
+        pub mod dose_x {
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Choice {
+                #[prost(message, tag = "1")]
+                Range(super::super::super::Range),
+                #[prost(message, tag = "2")]
+                Quantity(super::super::super::SimpleQuantity),
+            }
+        }
+        /// Amount of medication per unit of time
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct RateX {
+            #[prost(oneof = "rate_x::Choice", tags = "1, 2, 3")]
+            pub choice: ::core::option::Option<rate_x::Choice>,
+        }
+        /// Nested message and enum types in `RateX`.
+        pub mod rate_x {
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Choice {
+                #[prost(message, tag = "1")]
+                Ratio(super::super::super::Ratio),
+                #[prost(message, tag = "2")]
+                Range(super::super::super::Range),
+                #[prost(message, tag = "3")]
+                Quantity(super::super::super::SimpleQuantity),
+            }
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Duration.
+/// A length of time.
+/// See <http://hl7.org/fhir/StructureDefinition/Duration>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Duration {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub comparator: ::core::option::Option<duration::ComparatorCode>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Nested message and enum types in `Duration`.
+pub mod duration {
+    /// < | <= | >= | > | ad - how to understand the value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ComparatorCode {
+        #[prost(enumeration = "super::quantity_comparator_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Element.
+/// Base for all elements.
+/// See <http://hl7.org/fhir/StructureDefinition/Element>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Element {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for ElementDefinition.
+/// Definition of an element in a resource or extension.
+/// See <http://hl7.org/fhir/StructureDefinition/ElementDefinition>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ElementDefinition {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Path of the element in the hierarchy of elements
+    #[prost(message, optional, tag = "4")]
+    pub path: ::core::option::Option<String>,
+    #[prost(message, repeated, tag = "5")]
+    pub representation: ::prost::alloc::vec::Vec<element_definition::RepresentationCode>,
+    /// Name for this particular element (in a set of slices)
+    #[prost(message, optional, tag = "6")]
+    pub slice_name: ::core::option::Option<String>,
+    /// If this slice definition constrains an inherited slice definition (or not)
+    #[prost(message, optional, tag = "7")]
+    pub slice_is_constraining: ::core::option::Option<Boolean>,
+    /// Name for element to display with or prompt for element
+    #[prost(message, optional, tag = "8")]
+    pub label: ::core::option::Option<String>,
+    /// Corresponding codes in terminologies
+    #[prost(message, repeated, tag = "9")]
+    pub code: ::prost::alloc::vec::Vec<Coding>,
+    #[prost(message, optional, tag = "10")]
+    pub slicing: ::core::option::Option<element_definition::Slicing>,
+    /// Concise definition for space-constrained presentation
+    #[prost(message, optional, tag = "11")]
+    pub short: ::core::option::Option<String>,
+    /// Full formal definition as narrative text
+    #[prost(message, optional, tag = "12")]
+    pub definition: ::core::option::Option<Markdown>,
+    /// Comments about the use of this element
+    #[prost(message, optional, tag = "13")]
+    pub comment: ::core::option::Option<Markdown>,
+    /// Why this resource has been created
+    #[prost(message, optional, tag = "14")]
+    pub requirements: ::core::option::Option<Markdown>,
+    /// Other names
+    #[prost(message, repeated, tag = "15")]
+    pub alias: ::prost::alloc::vec::Vec<String>,
+    /// Minimum Cardinality
+    #[prost(message, optional, tag = "16")]
+    pub min: ::core::option::Option<UnsignedInt>,
+    /// Maximum Cardinality (a number or *)
+    #[prost(message, optional, tag = "17")]
+    pub max: ::core::option::Option<String>,
+    #[prost(message, optional, tag = "18")]
+    pub base: ::core::option::Option<element_definition::Base>,
+    /// Reference to definition of content for the element
+    #[prost(message, optional, tag = "19")]
+    pub content_reference: ::core::option::Option<Uri>,
+    #[prost(message, repeated, tag = "20")]
+    pub r#type: ::prost::alloc::vec::Vec<element_definition::TypeRef>,
+    #[prost(message, optional, tag = "21")]
+    pub default_value: ::core::option::Option<element_definition::DefaultValueX>,
+    /// Implicit meaning when this element is missing
+    #[prost(message, optional, tag = "22")]
+    pub meaning_when_missing: ::core::option::Option<Markdown>,
+    /// What the order of the elements means
+    #[prost(message, optional, tag = "23")]
+    pub order_meaning: ::core::option::Option<String>,
+    #[prost(message, optional, tag = "24")]
+    pub fixed: ::core::option::Option<element_definition::FixedX>,
+    #[prost(message, optional, tag = "25")]
+    pub pattern: ::core::option::Option<element_definition::PatternX>,
+    #[prost(message, repeated, tag = "26")]
+    pub example: ::prost::alloc::vec::Vec<element_definition::Example>,
+    #[prost(message, optional, tag = "27")]
+    pub min_value: ::core::option::Option<element_definition::MinValueX>,
+    #[prost(message, optional, tag = "28")]
+    pub max_value: ::core::option::Option<element_definition::MaxValueX>,
+    /// Max length for string type data
+    #[prost(message, optional, tag = "29")]
+    pub max_length: ::core::option::Option<Integer>,
+    /// Reference to invariant about presence
+    #[prost(message, repeated, tag = "30")]
+    pub condition: ::prost::alloc::vec::Vec<Id>,
+    #[prost(message, repeated, tag = "31")]
+    pub constraint: ::prost::alloc::vec::Vec<element_definition::Constraint>,
+    /// For primitives, that a value must be present - not replaced by an extension
+    #[prost(message, optional, tag = "38")]
+    pub must_have_value: ::core::option::Option<Boolean>,
+    /// Extensions that are allowed to replace a primitive value
+    #[prost(message, repeated, tag = "39")]
+    pub value_alternatives: ::prost::alloc::vec::Vec<Canonical>,
+    /// If the element must be supported (discouraged - see obligations)
+    #[prost(message, optional, tag = "32")]
+    pub must_support: ::core::option::Option<Boolean>,
+    /// If this modifies the meaning of other elements
+    #[prost(message, optional, tag = "33")]
+    pub is_modifier: ::core::option::Option<Boolean>,
+    /// Reason that this element is marked as a modifier
+    #[prost(message, optional, tag = "34")]
+    pub is_modifier_reason: ::core::option::Option<String>,
+    /// Include when _summary = true?
+    #[prost(message, optional, tag = "35")]
+    pub is_summary: ::core::option::Option<Boolean>,
+    #[prost(message, optional, tag = "36")]
+    pub binding: ::core::option::Option<element_definition::ElementDefinitionBinding>,
+    #[prost(message, repeated, tag = "37")]
+    pub mapping: ::prost::alloc::vec::Vec<element_definition::Mapping>,
+}
+/// Nested message and enum types in `ElementDefinition`.
+pub mod element_definition {
+    /// xmlAttr | xmlText | typeAttr | cdaText | xhtml
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RepresentationCode {
+        #[prost(enumeration = "super::property_representation_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// This element is sliced - slices follow
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Slicing {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        #[prost(message, repeated, tag = "3")]
+        pub discriminator: ::prost::alloc::vec::Vec<slicing::Discriminator>,
+        /// Text description of how slicing works (or not)
+        #[prost(message, optional, tag = "4")]
+        pub description: ::core::option::Option<super::String>,
+        /// If elements must be in same order as slices
+        #[prost(message, optional, tag = "5")]
+        pub ordered: ::core::option::Option<super::Boolean>,
+        #[prost(message, optional, tag = "6")]
+        pub rules: ::core::option::Option<slicing::RulesCode>,
+    }
+    /// Nested message and enum types in `Slicing`.
+    pub mod slicing {
+        /// Element values that are used to distinguish the slices
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Discriminator {
+            /// Unique id for inter-element referencing
+            #[prost(message, optional, tag = "1")]
+            pub id: ::core::option::Option<super::super::String>,
+            /// Additional content defined by implementations
+            #[prost(message, repeated, tag = "2")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            #[prost(message, optional, tag = "3")]
+            pub r#type: ::core::option::Option<discriminator::TypeCode>,
+            /// Path to element value
+            #[prost(message, optional, tag = "4")]
+            pub path: ::core::option::Option<super::super::String>,
+        }
+        /// Nested message and enum types in `Discriminator`.
+        pub mod discriminator {
+            /// value | exists | type | profile | position
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct TypeCode {
+                #[prost(
+                    enumeration = "super::super::super::discriminator_type_code::Value",
+                    tag = "1"
+                )]
+                pub value: i32,
+                #[prost(message, optional, tag = "2")]
+                pub id: ::core::option::Option<super::super::super::String>,
+                #[prost(message, repeated, tag = "3")]
+                pub extension: ::prost::alloc::vec::Vec<super::super::super::Extension>,
+            }
+        }
+        /// closed | open | openAtEnd
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct RulesCode {
+            #[prost(enumeration = "super::super::slicing_rules_code::Value", tag = "1")]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+    /// Base definition information for tools
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Base {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Path that identifies the base element
+        #[prost(message, optional, tag = "3")]
+        pub path: ::core::option::Option<super::String>,
+        /// Min cardinality of the base element
+        #[prost(message, optional, tag = "4")]
+        pub min: ::core::option::Option<super::UnsignedInt>,
+        /// Max cardinality of the base element
+        #[prost(message, optional, tag = "5")]
+        pub max: ::core::option::Option<super::String>,
+    }
+    /// Data type and Profile for this element
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeRef {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Data type or Resource (reference to definition)
+        #[prost(message, optional, tag = "3")]
+        pub code: ::core::option::Option<super::Uri>,
+        /// Profiles (StructureDefinition or IG) - one must apply
+        #[prost(message, repeated, tag = "4")]
+        pub profile: ::prost::alloc::vec::Vec<super::Canonical>,
+        /// Profile (StructureDefinition or IG) on the Reference/canonical target -
+        /// one must apply
+        #[prost(message, repeated, tag = "5")]
+        pub target_profile: ::prost::alloc::vec::Vec<super::Canonical>,
+        #[prost(message, repeated, tag = "6")]
+        pub aggregation: ::prost::alloc::vec::Vec<type_ref::AggregationCode>,
+        #[prost(message, optional, tag = "7")]
+        pub versioning: ::core::option::Option<type_ref::VersioningCode>,
+    }
+    /// Nested message and enum types in `TypeRef`.
+    pub mod type_ref {
+        /// contained | referenced | bundled - how aggregated
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct AggregationCode {
+            #[prost(
+                enumeration = "super::super::aggregation_mode_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+        /// either | independent | specific
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct VersioningCode {
+            #[prost(
+                enumeration = "super::super::reference_version_rules_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+    /// Specified value if missing from instance
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DefaultValueX {
+        #[prost(
+            oneof = "default_value_x::Choice",
+            tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 51, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 52, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 53, 37, 38, 39, 40, 41, 43, 44, 45, 46, 47, 48, 54, 55, 49, 50"
+        )]
+        pub choice: ::core::option::Option<default_value_x::Choice>,
+    }
+    /// Nested message and enum types in `DefaultValueX`.
+    pub mod default_value_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Base64Binary(super::super::Base64Binary),
+            #[prost(message, tag = "2")]
+            Boolean(super::super::Boolean),
+            #[prost(message, tag = "3")]
+            Canonical(super::super::Canonical),
+            #[prost(message, tag = "4")]
+            Code(super::super::Code),
+            #[prost(message, tag = "5")]
+            Date(super::super::Date),
+            #[prost(message, tag = "6")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "7")]
+            Decimal(super::super::Decimal),
+            #[prost(message, tag = "8")]
+            Id(super::super::Id),
+            #[prost(message, tag = "9")]
+            Instant(super::super::Instant),
+            #[prost(message, tag = "10")]
+            Integer(super::super::Integer),
+            #[prost(message, tag = "51")]
+            Integer64(super::super::Integer64),
+            #[prost(message, tag = "11")]
+            Markdown(super::super::Markdown),
+            #[prost(message, tag = "12")]
+            Oid(super::super::Oid),
+            #[prost(message, tag = "13")]
+            PositiveInt(super::super::PositiveInt),
+            #[prost(message, tag = "14")]
+            StringValue(super::super::String),
+            #[prost(message, tag = "15")]
+            Time(super::super::Time),
+            #[prost(message, tag = "16")]
+            UnsignedInt(super::super::UnsignedInt),
+            #[prost(message, tag = "17")]
+            Uri(super::super::Uri),
+            #[prost(message, tag = "18")]
+            Url(super::super::Url),
+            #[prost(message, tag = "19")]
+            Uuid(super::super::Uuid),
+            #[prost(message, tag = "20")]
+            Address(super::super::Address),
+            #[prost(message, tag = "21")]
+            Age(super::super::Age),
+            #[prost(message, tag = "22")]
+            Annotation(super::super::Annotation),
+            #[prost(message, tag = "23")]
+            Attachment(super::super::Attachment),
+            #[prost(message, tag = "24")]
+            CodeableConcept(super::super::CodeableConcept),
+            #[prost(message, tag = "52")]
+            CodeableReference(super::super::CodeableReference),
+            #[prost(message, tag = "25")]
+            Coding(super::super::Coding),
+            #[prost(message, tag = "26")]
+            ContactPoint(super::super::ContactPoint),
+            #[prost(message, tag = "27")]
+            Count(super::super::Count),
+            #[prost(message, tag = "28")]
+            Distance(super::super::Distance),
+            #[prost(message, tag = "29")]
+            Duration(super::super::Duration),
+            #[prost(message, tag = "30")]
+            HumanName(super::super::HumanName),
+            #[prost(message, tag = "31")]
+            Identifier(super::super::Identifier),
+            #[prost(message, tag = "32")]
+            Money(super::super::Money),
+            #[prost(message, tag = "33")]
+            Period(super::super::Period),
+            #[prost(message, tag = "34")]
+            Quantity(super::super::Quantity),
+            #[prost(message, tag = "35")]
+            Range(super::super::Range),
+            #[prost(message, tag = "36")]
+            Ratio(super::super::Ratio),
+            #[prost(message, tag = "53")]
+            RatioRange(super::super::RatioRange),
+            #[prost(message, tag = "37")]
+            Reference(super::super::Reference),
+            #[prost(message, tag = "38")]
+            SampledData(super::super::SampledData),
+            #[prost(message, tag = "39")]
+            Signature(super::super::Signature),
+            #[prost(message, tag = "40")]
+            Timing(super::super::Timing),
+            #[prost(message, tag = "41")]
+            ContactDetail(super::super::ContactDetail),
+            #[prost(message, tag = "43")]
+            DataRequirement(super::super::DataRequirement),
+            #[prost(message, tag = "44")]
+            Expression(super::super::Expression),
+            #[prost(message, tag = "45")]
+            ParameterDefinition(super::super::ParameterDefinition),
+            #[prost(message, tag = "46")]
+            RelatedArtifact(super::super::RelatedArtifact),
+            #[prost(message, tag = "47")]
+            TriggerDefinition(super::super::TriggerDefinition),
+            #[prost(message, tag = "48")]
+            UsageContext(super::super::UsageContext),
+            #[prost(message, tag = "54")]
+            Availability(super::super::Availability),
+            #[prost(message, tag = "55")]
+            ExtendedContactDetail(super::super::ExtendedContactDetail),
+            #[prost(message, tag = "49")]
+            Dosage(super::super::Dosage),
+            #[prost(message, tag = "50")]
+            Meta(super::super::Meta),
+        }
+    }
+    /// Value must be exactly this
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FixedX {
+        #[prost(
+            oneof = "fixed_x::Choice",
+            tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 51, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 52, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 53, 37, 38, 39, 40, 41, 43, 44, 45, 46, 47, 48, 54, 55, 49, 50"
+        )]
+        pub choice: ::core::option::Option<fixed_x::Choice>,
+    }
+    /// Nested message and enum types in `FixedX`.
+    pub mod fixed_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Base64Binary(super::super::Base64Binary),
+            #[prost(message, tag = "2")]
+            Boolean(super::super::Boolean),
+            #[prost(message, tag = "3")]
+            Canonical(super::super::Canonical),
+            #[prost(message, tag = "4")]
+            Code(super::super::Code),
+            #[prost(message, tag = "5")]
+            Date(super::super::Date),
+            #[prost(message, tag = "6")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "7")]
+            Decimal(super::super::Decimal),
+            #[prost(message, tag = "8")]
+            Id(super::super::Id),
+            #[prost(message, tag = "9")]
+            Instant(super::super::Instant),
+            #[prost(message, tag = "10")]
+            Integer(super::super::Integer),
+            #[prost(message, tag = "51")]
+            Integer64(super::super::Integer64),
+            #[prost(message, tag = "11")]
+            Markdown(super::super::Markdown),
+            #[prost(message, tag = "12")]
+            Oid(super::super::Oid),
+            #[prost(message, tag = "13")]
+            PositiveInt(super::super::PositiveInt),
+            #[prost(message, tag = "14")]
+            StringValue(super::super::String),
+            #[prost(message, tag = "15")]
+            Time(super::super::Time),
+            #[prost(message, tag = "16")]
+            UnsignedInt(super::super::UnsignedInt),
+            #[prost(message, tag = "17")]
+            Uri(super::super::Uri),
+            #[prost(message, tag = "18")]
+            Url(super::super::Url),
+            #[prost(message, tag = "19")]
+            Uuid(super::super::Uuid),
+            #[prost(message, tag = "20")]
+            Address(super::super::Address),
+            #[prost(message, tag = "21")]
+            Age(super::super::Age),
+            #[prost(message, tag = "22")]
+            Annotation(super::super::Annotation),
+            #[prost(message, tag = "23")]
+            Attachment(super::super::Attachment),
+            #[prost(message, tag = "24")]
+            CodeableConcept(super::super::CodeableConcept),
+            #[prost(message, tag = "52")]
+            CodeableReference(super::super::CodeableReference),
+            #[prost(message, tag = "25")]
+            Coding(super::super::Coding),
+            #[prost(message, tag = "26")]
+            ContactPoint(super::super::ContactPoint),
+            #[prost(message, tag = "27")]
+            Count(super::super::Count),
+            #[prost(message, tag = "28")]
+            Distance(super::super::Distance),
+            #[prost(message, tag = "29")]
+            Duration(super::super::Duration),
+            #[prost(message, tag = "30")]
+            HumanName(super::super::HumanName),
+            #[prost(message, tag = "31")]
+            Identifier(super::super::Identifier),
+            #[prost(message, tag = "32")]
+            Money(super::super::Money),
+            #[prost(message, tag = "33")]
+            Period(super::super::Period),
+            #[prost(message, tag = "34")]
+            Quantity(super::super::Quantity),
+            #[prost(message, tag = "35")]
+            Range(super::super::Range),
+            #[prost(message, tag = "36")]
+            Ratio(super::super::Ratio),
+            #[prost(message, tag = "53")]
+            RatioRange(super::super::RatioRange),
+            #[prost(message, tag = "37")]
+            Reference(super::super::Reference),
+            #[prost(message, tag = "38")]
+            SampledData(super::super::SampledData),
+            #[prost(message, tag = "39")]
+            Signature(super::super::Signature),
+            #[prost(message, tag = "40")]
+            Timing(super::super::Timing),
+            #[prost(message, tag = "41")]
+            ContactDetail(super::super::ContactDetail),
+            #[prost(message, tag = "43")]
+            DataRequirement(super::super::DataRequirement),
+            #[prost(message, tag = "44")]
+            Expression(super::super::Expression),
+            #[prost(message, tag = "45")]
+            ParameterDefinition(super::super::ParameterDefinition),
+            #[prost(message, tag = "46")]
+            RelatedArtifact(super::super::RelatedArtifact),
+            #[prost(message, tag = "47")]
+            TriggerDefinition(super::super::TriggerDefinition),
+            #[prost(message, tag = "48")]
+            UsageContext(super::super::UsageContext),
+            #[prost(message, tag = "54")]
+            Availability(super::super::Availability),
+            #[prost(message, tag = "55")]
+            ExtendedContactDetail(super::super::ExtendedContactDetail),
+            #[prost(message, tag = "49")]
+            Dosage(super::super::Dosage),
+            #[prost(message, tag = "50")]
+            Meta(super::super::Meta),
+        }
+    }
+    /// Value must have at least these property values
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PatternX {
+        #[prost(
+            oneof = "pattern_x::Choice",
+            tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 51, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 52, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 53, 37, 38, 39, 40, 41, 43, 44, 45, 46, 47, 48, 54, 55, 49, 50"
+        )]
+        pub choice: ::core::option::Option<pattern_x::Choice>,
+    }
+    /// Nested message and enum types in `PatternX`.
+    pub mod pattern_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Base64Binary(super::super::Base64Binary),
+            #[prost(message, tag = "2")]
+            Boolean(super::super::Boolean),
+            #[prost(message, tag = "3")]
+            Canonical(super::super::Canonical),
+            #[prost(message, tag = "4")]
+            Code(super::super::Code),
+            #[prost(message, tag = "5")]
+            Date(super::super::Date),
+            #[prost(message, tag = "6")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "7")]
+            Decimal(super::super::Decimal),
+            #[prost(message, tag = "8")]
+            Id(super::super::Id),
+            #[prost(message, tag = "9")]
+            Instant(super::super::Instant),
+            #[prost(message, tag = "10")]
+            Integer(super::super::Integer),
+            #[prost(message, tag = "51")]
+            Integer64(super::super::Integer64),
+            #[prost(message, tag = "11")]
+            Markdown(super::super::Markdown),
+            #[prost(message, tag = "12")]
+            Oid(super::super::Oid),
+            #[prost(message, tag = "13")]
+            PositiveInt(super::super::PositiveInt),
+            #[prost(message, tag = "14")]
+            StringValue(super::super::String),
+            #[prost(message, tag = "15")]
+            Time(super::super::Time),
+            #[prost(message, tag = "16")]
+            UnsignedInt(super::super::UnsignedInt),
+            #[prost(message, tag = "17")]
+            Uri(super::super::Uri),
+            #[prost(message, tag = "18")]
+            Url(super::super::Url),
+            #[prost(message, tag = "19")]
+            Uuid(super::super::Uuid),
+            #[prost(message, tag = "20")]
+            Address(super::super::Address),
+            #[prost(message, tag = "21")]
+            Age(super::super::Age),
+            #[prost(message, tag = "22")]
+            Annotation(super::super::Annotation),
+            #[prost(message, tag = "23")]
+            Attachment(super::super::Attachment),
+            #[prost(message, tag = "24")]
+            CodeableConcept(super::super::CodeableConcept),
+            #[prost(message, tag = "52")]
+            CodeableReference(super::super::CodeableReference),
+            #[prost(message, tag = "25")]
+            Coding(super::super::Coding),
+            #[prost(message, tag = "26")]
+            ContactPoint(super::super::ContactPoint),
+            #[prost(message, tag = "27")]
+            Count(super::super::Count),
+            #[prost(message, tag = "28")]
+            Distance(super::super::Distance),
+            #[prost(message, tag = "29")]
+            Duration(super::super::Duration),
+            #[prost(message, tag = "30")]
+            HumanName(super::super::HumanName),
+            #[prost(message, tag = "31")]
+            Identifier(super::super::Identifier),
+            #[prost(message, tag = "32")]
+            Money(super::super::Money),
+            #[prost(message, tag = "33")]
+            Period(super::super::Period),
+            #[prost(message, tag = "34")]
+            Quantity(super::super::Quantity),
+            #[prost(message, tag = "35")]
+            Range(super::super::Range),
+            #[prost(message, tag = "36")]
+            Ratio(super::super::Ratio),
+            #[prost(message, tag = "53")]
+            RatioRange(super::super::RatioRange),
+            #[prost(message, tag = "37")]
+            Reference(super::super::Reference),
+            #[prost(message, tag = "38")]
+            SampledData(super::super::SampledData),
+            #[prost(message, tag = "39")]
+            Signature(super::super::Signature),
+            #[prost(message, tag = "40")]
+            Timing(super::super::Timing),
+            #[prost(message, tag = "41")]
+            ContactDetail(super::super::ContactDetail),
+            #[prost(message, tag = "43")]
+            DataRequirement(super::super::DataRequirement),
+            #[prost(message, tag = "44")]
+            Expression(super::super::Expression),
+            #[prost(message, tag = "45")]
+            ParameterDefinition(super::super::ParameterDefinition),
+            #[prost(message, tag = "46")]
+            RelatedArtifact(super::super::RelatedArtifact),
+            #[prost(message, tag = "47")]
+            TriggerDefinition(super::super::TriggerDefinition),
+            #[prost(message, tag = "48")]
+            UsageContext(super::super::UsageContext),
+            #[prost(message, tag = "54")]
+            Availability(super::super::Availability),
+            #[prost(message, tag = "55")]
+            ExtendedContactDetail(super::super::ExtendedContactDetail),
+            #[prost(message, tag = "49")]
+            Dosage(super::super::Dosage),
+            #[prost(message, tag = "50")]
+            Meta(super::super::Meta),
+        }
+    }
+    /// Example value (as defined for type)
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Example {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Describes the purpose of this example
+        #[prost(message, optional, tag = "3")]
+        pub label: ::core::option::Option<super::String>,
+        #[prost(message, optional, tag = "4")]
+        pub value: ::core::option::Option<example::ValueX>,
+    }
+    /// Nested message and enum types in `Example`.
+    pub mod example {
+        /// Value of Example (one of allowed types)
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ValueX {
+            #[prost(
+                oneof = "value_x::Choice",
+                tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 51, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 52, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 53, 37, 38, 39, 40, 41, 43, 44, 45, 46, 47, 48, 54, 55, 49, 50"
+            )]
+            pub choice: ::core::option::Option<value_x::Choice>,
+        }
+        /// Nested message and enum types in `ValueX`.
+        pub mod value_x {
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Choice {
+                #[prost(message, tag = "1")]
+                Base64Binary(super::super::super::Base64Binary),
+                #[prost(message, tag = "2")]
+                Boolean(super::super::super::Boolean),
+                #[prost(message, tag = "3")]
+                Canonical(super::super::super::Canonical),
+                #[prost(message, tag = "4")]
+                Code(super::super::super::Code),
+                #[prost(message, tag = "5")]
+                Date(super::super::super::Date),
+                #[prost(message, tag = "6")]
+                DateTime(super::super::super::DateTime),
+                #[prost(message, tag = "7")]
+                Decimal(super::super::super::Decimal),
+                #[prost(message, tag = "8")]
+                Id(super::super::super::Id),
+                #[prost(message, tag = "9")]
+                Instant(super::super::super::Instant),
+                #[prost(message, tag = "10")]
+                Integer(super::super::super::Integer),
+                #[prost(message, tag = "51")]
+                Integer64(super::super::super::Integer64),
+                #[prost(message, tag = "11")]
+                Markdown(super::super::super::Markdown),
+                #[prost(message, tag = "12")]
+                Oid(super::super::super::Oid),
+                #[prost(message, tag = "13")]
+                PositiveInt(super::super::super::PositiveInt),
+                #[prost(message, tag = "14")]
+                StringValue(super::super::super::String),
+                #[prost(message, tag = "15")]
+                Time(super::super::super::Time),
+                #[prost(message, tag = "16")]
+                UnsignedInt(super::super::super::UnsignedInt),
+                #[prost(message, tag = "17")]
+                Uri(super::super::super::Uri),
+                #[prost(message, tag = "18")]
+                Url(super::super::super::Url),
+                #[prost(message, tag = "19")]
+                Uuid(super::super::super::Uuid),
+                #[prost(message, tag = "20")]
+                Address(super::super::super::Address),
+                #[prost(message, tag = "21")]
+                Age(super::super::super::Age),
+                #[prost(message, tag = "22")]
+                Annotation(super::super::super::Annotation),
+                #[prost(message, tag = "23")]
+                Attachment(super::super::super::Attachment),
+                #[prost(message, tag = "24")]
+                CodeableConcept(super::super::super::CodeableConcept),
+                #[prost(message, tag = "52")]
+                CodeableReference(super::super::super::CodeableReference),
+                #[prost(message, tag = "25")]
+                Coding(super::super::super::Coding),
+                #[prost(message, tag = "26")]
+                ContactPoint(super::super::super::ContactPoint),
+                #[prost(message, tag = "27")]
+                Count(super::super::super::Count),
+                #[prost(message, tag = "28")]
+                Distance(super::super::super::Distance),
+                #[prost(message, tag = "29")]
+                Duration(super::super::super::Duration),
+                #[prost(message, tag = "30")]
+                HumanName(super::super::super::HumanName),
+                #[prost(message, tag = "31")]
+                Identifier(super::super::super::Identifier),
+                #[prost(message, tag = "32")]
+                Money(super::super::super::Money),
+                #[prost(message, tag = "33")]
+                Period(super::super::super::Period),
+                #[prost(message, tag = "34")]
+                Quantity(super::super::super::Quantity),
+                #[prost(message, tag = "35")]
+                Range(super::super::super::Range),
+                #[prost(message, tag = "36")]
+                Ratio(super::super::super::Ratio),
+                #[prost(message, tag = "53")]
+                RatioRange(super::super::super::RatioRange),
+                #[prost(message, tag = "37")]
+                Reference(super::super::super::Reference),
+                #[prost(message, tag = "38")]
+                SampledData(super::super::super::SampledData),
+                #[prost(message, tag = "39")]
+                Signature(super::super::super::Signature),
+                #[prost(message, tag = "40")]
+                Timing(super::super::super::Timing),
+                #[prost(message, tag = "41")]
+                ContactDetail(super::super::super::ContactDetail),
+                #[prost(message, tag = "43")]
+                DataRequirement(super::super::super::DataRequirement),
+                #[prost(message, tag = "44")]
+                Expression(super::super::super::Expression),
+                #[prost(message, tag = "45")]
+                ParameterDefinition(super::super::super::ParameterDefinition),
+                #[prost(message, tag = "46")]
+                RelatedArtifact(super::super::super::RelatedArtifact),
+                #[prost(message, tag = "47")]
+                TriggerDefinition(super::super::super::TriggerDefinition),
+                #[prost(message, tag = "48")]
+                UsageContext(super::super::super::UsageContext),
+                #[prost(message, tag = "54")]
+                Availability(super::super::super::Availability),
+                #[prost(message, tag = "55")]
+                ExtendedContactDetail(super::super::super::ExtendedContactDetail),
+                #[prost(message, tag = "49")]
+                Dosage(super::super::super::Dosage),
+                #[prost(message, tag = "50")]
+                Meta(super::super::super::Meta),
+            }
+        }
+    }
+    /// Minimum Allowed Value (for some types)
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MinValueX {
+        #[prost(oneof = "min_value_x::Choice", tags = "1, 2, 3, 4, 5, 6, 10, 7, 8, 9")]
+        pub choice: ::core::option::Option<min_value_x::Choice>,
+    }
+    /// Nested message and enum types in `MinValueX`.
+    pub mod min_value_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Date(super::super::Date),
+            #[prost(message, tag = "2")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "3")]
+            Instant(super::super::Instant),
+            #[prost(message, tag = "4")]
+            Time(super::super::Time),
+            #[prost(message, tag = "5")]
+            Decimal(super::super::Decimal),
+            #[prost(message, tag = "6")]
+            Integer(super::super::Integer),
+            #[prost(message, tag = "10")]
+            Integer64(super::super::Integer64),
+            #[prost(message, tag = "7")]
+            PositiveInt(super::super::PositiveInt),
+            #[prost(message, tag = "8")]
+            UnsignedInt(super::super::UnsignedInt),
+            #[prost(message, tag = "9")]
+            Quantity(super::super::Quantity),
+        }
+    }
+    /// Maximum Allowed Value (for some types)
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MaxValueX {
+        #[prost(oneof = "max_value_x::Choice", tags = "1, 2, 3, 4, 5, 6, 10, 7, 8, 9")]
+        pub choice: ::core::option::Option<max_value_x::Choice>,
+    }
+    /// Nested message and enum types in `MaxValueX`.
+    pub mod max_value_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Date(super::super::Date),
+            #[prost(message, tag = "2")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "3")]
+            Instant(super::super::Instant),
+            #[prost(message, tag = "4")]
+            Time(super::super::Time),
+            #[prost(message, tag = "5")]
+            Decimal(super::super::Decimal),
+            #[prost(message, tag = "6")]
+            Integer(super::super::Integer),
+            #[prost(message, tag = "10")]
+            Integer64(super::super::Integer64),
+            #[prost(message, tag = "7")]
+            PositiveInt(super::super::PositiveInt),
+            #[prost(message, tag = "8")]
+            UnsignedInt(super::super::UnsignedInt),
+            #[prost(message, tag = "9")]
+            Quantity(super::super::Quantity),
+        }
+    }
+    /// Condition that must evaluate to true
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Constraint {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Target of 'condition' reference above
+        #[prost(message, optional, tag = "3")]
+        pub key: ::core::option::Option<super::Id>,
+        /// Why this constraint is necessary or appropriate
+        #[prost(message, optional, tag = "10")]
+        pub requirements: ::core::option::Option<super::Markdown>,
+        #[prost(message, optional, tag = "5")]
+        pub severity: ::core::option::Option<constraint::SeverityCode>,
+        /// Suppress warning or hint in profile
+        #[prost(message, optional, tag = "11")]
+        pub suppress: ::core::option::Option<super::Boolean>,
+        /// Human description of constraint
+        #[prost(message, optional, tag = "6")]
+        pub human: ::core::option::Option<super::String>,
+        /// FHIRPath expression of constraint
+        #[prost(message, optional, tag = "7")]
+        pub expression: ::core::option::Option<super::String>,
+        /// Reference to original source of constraint
+        #[prost(message, optional, tag = "9")]
+        pub source: ::core::option::Option<super::Canonical>,
+    }
+    /// Nested message and enum types in `Constraint`.
+    pub mod constraint {
+        /// error | warning
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct SeverityCode {
+            #[prost(
+                enumeration = "super::super::constraint_severity_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+    /// ValueSet details if this is coded
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ElementDefinitionBinding {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        #[prost(message, optional, tag = "3")]
+        pub strength: ::core::option::Option<element_definition_binding::StrengthCode>,
+        /// Intended use of codes in the bound value set
+        #[prost(message, optional, tag = "6")]
+        pub description: ::core::option::Option<super::Markdown>,
+        /// Source of value set
+        #[prost(message, optional, tag = "5")]
+        pub value_set: ::core::option::Option<super::Canonical>,
+        #[prost(message, repeated, tag = "7")]
+        pub additional: ::prost::alloc::vec::Vec<element_definition_binding::Additional>,
+    }
+    /// Nested message and enum types in `ElementDefinitionBinding`.
+    pub mod element_definition_binding {
+        /// required | extensible | preferred | example
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StrengthCode {
+            #[prost(
+                enumeration = "super::super::binding_strength_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+        /// Additional Bindings - more rules about the binding
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Additional {
+            /// Unique id for inter-element referencing
+            #[prost(message, optional, tag = "1")]
+            pub id: ::core::option::Option<super::super::String>,
+            /// Additional content defined by implementations
+            #[prost(message, repeated, tag = "2")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            #[prost(message, optional, tag = "3")]
+            pub purpose: ::core::option::Option<additional::PurposeCode>,
+            /// The value set for the additional binding
+            #[prost(message, optional, tag = "4")]
+            pub value_set: ::core::option::Option<super::super::Canonical>,
+            /// Documentation of the purpose of use of the binding
+            #[prost(message, optional, tag = "5")]
+            pub documentation: ::core::option::Option<super::super::Markdown>,
+            /// Concise documentation - for summary tables
+            #[prost(message, optional, tag = "6")]
+            pub short_doco: ::core::option::Option<super::super::String>,
+            /// Qualifies the usage - jurisdiction, gender, workflow status etc.
+            #[prost(message, repeated, tag = "7")]
+            pub usage: ::prost::alloc::vec::Vec<super::super::UsageContext>,
+            /// Whether binding can applies to all repeats, or just one
+            #[prost(message, optional, tag = "8")]
+            pub any: ::core::option::Option<super::super::Boolean>,
+        }
+        /// Nested message and enum types in `Additional`.
+        pub mod additional {
+            /// maximum | minimum | required | extensible | candidate | current |
+            /// preferred | ui | starter | component
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct PurposeCode {
+                #[prost(
+                    enumeration = "super::super::super::additional_binding_purpose_code::Value",
+                    tag = "1"
+                )]
+                pub value: i32,
+                #[prost(message, optional, tag = "2")]
+                pub id: ::core::option::Option<super::super::super::String>,
+                #[prost(message, repeated, tag = "3")]
+                pub extension: ::prost::alloc::vec::Vec<super::super::super::Extension>,
+            }
+        }
+    }
+    /// Map element to another set of definitions
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Mapping {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Reference to mapping declaration
+        #[prost(message, optional, tag = "3")]
+        pub identity: ::core::option::Option<super::Id>,
+        #[prost(message, optional, tag = "4")]
+        pub language: ::core::option::Option<mapping::LanguageCode>,
+        /// Details of the mapping
+        #[prost(message, optional, tag = "5")]
+        pub map: ::core::option::Option<super::String>,
+        /// Comments about the mapping or its use
+        #[prost(message, optional, tag = "7")]
+        pub comment: ::core::option::Option<super::Markdown>,
+    }
+    /// Nested message and enum types in `Mapping`.
+    pub mod mapping {
+        /// Computable language of mapping
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct LanguageCode {
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// This valueset is not enumerable, and so is represented as a string.
+            #[prost(string, tag = "4")]
+            pub value: ::prost::alloc::string::String,
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Expression.
+/// An expression that can be used to generate a value.
+/// See <http://hl7.org/fhir/StructureDefinition/Expression>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Expression {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Natural language description of the condition
+    #[prost(message, optional, tag = "3")]
+    pub description: ::core::option::Option<String>,
+    /// Short name assigned to expression for reuse
+    #[prost(message, optional, tag = "8")]
+    pub name: ::core::option::Option<Code>,
+    /// text/cql | text/fhirpath | application/x-fhir-query | etc.
+    #[prost(message, optional, tag = "5")]
+    pub language: ::core::option::Option<Code>,
+    /// Expression in specified language
+    #[prost(message, optional, tag = "6")]
+    pub expression: ::core::option::Option<String>,
+    /// Where the expression is found
+    #[prost(message, optional, tag = "7")]
+    pub reference: ::core::option::Option<Uri>,
+}
+/// Auto-generated from StructureDefinition for ExtendedContactDetail.
+/// Contact information.
+/// See <http://hl7.org/fhir/StructureDefinition/ExtendedContactDetail>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendedContactDetail {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// The type of contact
+    #[prost(message, optional, tag = "3")]
+    pub purpose: ::core::option::Option<CodeableConcept>,
+    /// Name of an individual to contact
+    #[prost(message, repeated, tag = "4")]
+    pub name: ::prost::alloc::vec::Vec<HumanName>,
+    /// Contact details (e.g.phone/fax/url)
+    #[prost(message, repeated, tag = "5")]
+    pub telecom: ::prost::alloc::vec::Vec<ContactPoint>,
+    /// Address for the contact
+    #[prost(message, optional, tag = "6")]
+    pub address: ::core::option::Option<Address>,
+    /// This contact detail is handled/monitored by a specific organization
+    #[prost(message, optional, tag = "7")]
+    pub organization: ::core::option::Option<Reference>,
+    /// Period that this contact was valid for usage
+    #[prost(message, optional, tag = "8")]
+    pub period: ::core::option::Option<Period>,
+}
+/// Auto-generated from StructureDefinition for Extension.
+/// Optional Extensions Element.
+/// See <http://hl7.org/fhir/StructureDefinition/Extension>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Extension {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// identifies the meaning of the extension
+    #[prost(message, optional, tag = "2")]
+    pub url: ::core::option::Option<Uri>,
+    #[prost(message, optional, tag = "4")]
+    pub value: ::core::option::Option<extension::ValueX>,
+}
+/// Nested message and enum types in `Extension`.
+pub mod extension {
+    /// Value of extension
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ValueX {
+        #[prost(
+            oneof = "value_x::Choice",
+            tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 50, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 51, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 52, 37, 38, 39, 40, 41, 43, 44, 45, 46, 47, 48, 53, 54, 49, 55"
+        )]
+        pub choice: ::core::option::Option<value_x::Choice>,
+    }
+    /// Nested message and enum types in `ValueX`.
+    pub mod value_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Base64Binary(super::super::Base64Binary),
+            #[prost(message, tag = "2")]
+            Boolean(super::super::Boolean),
+            #[prost(message, tag = "3")]
+            Canonical(super::super::Canonical),
+            #[prost(message, tag = "4")]
+            Code(super::super::Code),
+            #[prost(message, tag = "5")]
+            Date(super::super::Date),
+            #[prost(message, tag = "6")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "7")]
+            Decimal(super::super::Decimal),
+            #[prost(message, tag = "8")]
+            Id(super::super::Id),
+            #[prost(message, tag = "9")]
+            Instant(super::super::Instant),
+            #[prost(message, tag = "10")]
+            Integer(super::super::Integer),
+            #[prost(message, tag = "50")]
+            Integer64(super::super::Integer64),
+            #[prost(message, tag = "11")]
+            Markdown(super::super::Markdown),
+            #[prost(message, tag = "12")]
+            Oid(super::super::Oid),
+            #[prost(message, tag = "13")]
+            PositiveInt(super::super::PositiveInt),
+            #[prost(message, tag = "14")]
+            StringValue(super::super::String),
+            #[prost(message, tag = "15")]
+            Time(super::super::Time),
+            #[prost(message, tag = "16")]
+            UnsignedInt(super::super::UnsignedInt),
+            #[prost(message, tag = "17")]
+            Uri(super::super::Uri),
+            #[prost(message, tag = "18")]
+            Url(super::super::Url),
+            #[prost(message, tag = "19")]
+            Uuid(super::super::Uuid),
+            #[prost(message, tag = "20")]
+            Address(super::super::Address),
+            #[prost(message, tag = "21")]
+            Age(super::super::Age),
+            #[prost(message, tag = "22")]
+            Annotation(super::super::Annotation),
+            #[prost(message, tag = "23")]
+            Attachment(super::super::Attachment),
+            #[prost(message, tag = "24")]
+            CodeableConcept(super::super::CodeableConcept),
+            #[prost(message, tag = "51")]
+            CodeableReference(super::super::CodeableReference),
+            #[prost(message, tag = "25")]
+            Coding(super::super::Coding),
+            #[prost(message, tag = "26")]
+            ContactPoint(super::super::ContactPoint),
+            #[prost(message, tag = "27")]
+            Count(super::super::Count),
+            #[prost(message, tag = "28")]
+            Distance(super::super::Distance),
+            #[prost(message, tag = "29")]
+            Duration(super::super::Duration),
+            #[prost(message, tag = "30")]
+            HumanName(super::super::HumanName),
+            #[prost(message, tag = "31")]
+            Identifier(super::super::Identifier),
+            #[prost(message, tag = "32")]
+            Money(super::super::Money),
+            #[prost(message, tag = "33")]
+            Period(super::super::Period),
+            #[prost(message, tag = "34")]
+            Quantity(super::super::Quantity),
+            #[prost(message, tag = "35")]
+            Range(super::super::Range),
+            #[prost(message, tag = "36")]
+            Ratio(super::super::Ratio),
+            #[prost(message, tag = "52")]
+            RatioRange(super::super::RatioRange),
+            #[prost(message, tag = "37")]
+            Reference(super::super::Reference),
+            #[prost(message, tag = "38")]
+            SampledData(super::super::SampledData),
+            #[prost(message, tag = "39")]
+            Signature(super::super::Signature),
+            #[prost(message, tag = "40")]
+            Timing(super::super::Timing),
+            #[prost(message, tag = "41")]
+            ContactDetail(super::super::ContactDetail),
+            #[prost(message, tag = "43")]
+            DataRequirement(super::super::DataRequirement),
+            #[prost(message, tag = "44")]
+            Expression(super::super::Expression),
+            #[prost(message, tag = "45")]
+            ParameterDefinition(super::super::ParameterDefinition),
+            #[prost(message, tag = "46")]
+            RelatedArtifact(super::super::RelatedArtifact),
+            #[prost(message, tag = "47")]
+            TriggerDefinition(super::super::TriggerDefinition),
+            #[prost(message, tag = "48")]
+            UsageContext(super::super::UsageContext),
+            #[prost(message, tag = "53")]
+            Availability(super::super::Availability),
+            #[prost(message, tag = "54")]
+            ExtendedContactDetail(super::super::ExtendedContactDetail),
+            #[prost(message, tag = "49")]
+            Dosage(super::super::Dosage),
+            #[prost(message, tag = "55")]
+            Meta(super::super::Meta),
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for HumanName.
+/// Name of a human or other living entity - parts and usage.
+/// See <http://hl7.org/fhir/StructureDefinition/HumanName>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HumanName {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#use: ::core::option::Option<human_name::UseCode>,
+    /// Text representation of the full name
+    #[prost(message, optional, tag = "4")]
+    pub text: ::core::option::Option<String>,
+    /// Family name (often called 'Surname')
+    #[prost(message, optional, tag = "5")]
+    pub family: ::core::option::Option<String>,
+    /// Given names (not always 'first'). Includes middle names
+    #[prost(message, repeated, tag = "6")]
+    pub given: ::prost::alloc::vec::Vec<String>,
+    /// Parts that come before the name
+    #[prost(message, repeated, tag = "7")]
+    pub prefix: ::prost::alloc::vec::Vec<String>,
+    /// Parts that come after the name
+    #[prost(message, repeated, tag = "8")]
+    pub suffix: ::prost::alloc::vec::Vec<String>,
+    /// Time period when name was/is in use
+    #[prost(message, optional, tag = "9")]
+    pub period: ::core::option::Option<Period>,
+}
+/// Nested message and enum types in `HumanName`.
+pub mod human_name {
+    /// usual | official | temp | nickname | anonymous | old | maiden
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UseCode {
+        #[prost(enumeration = "super::name_use_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for id.
+/// Primitive Type id.
+/// See <http://hl7.org/fhir/StructureDefinition/id>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Id {
+    /// Primitive value for id
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for Identifier.
+/// An identifier intended for computation.
+/// See <http://hl7.org/fhir/StructureDefinition/Identifier>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Identifier {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#use: ::core::option::Option<identifier::UseCode>,
+    /// Description of identifier
+    #[prost(message, optional, tag = "4")]
+    pub r#type: ::core::option::Option<CodeableConcept>,
+    /// The namespace for the identifier value
+    #[prost(message, optional, tag = "5")]
+    pub system: ::core::option::Option<Uri>,
+    /// The value that is unique
+    #[prost(message, optional, tag = "6")]
+    pub value: ::core::option::Option<String>,
+    /// Time period when id is/was valid for use
+    #[prost(message, optional, tag = "7")]
+    pub period: ::core::option::Option<Period>,
+    /// Organization that issued id (may be just text)
+    #[prost(message, optional, boxed, tag = "8")]
+    pub assigner: ::core::option::Option<::prost::alloc::boxed::Box<Reference>>,
+}
+/// Nested message and enum types in `Identifier`.
+pub mod identifier {
+    /// usual | official | temp | secondary | old (If known)
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UseCode {
+        #[prost(enumeration = "super::identifier_use_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for instant.
+/// Primitive Type instant.
+/// See <http://hl7.org/fhir/StructureDefinition/instant>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Instant {
+    /// The absolute time of the event as a Unix epoch in microseconds.
+    #[prost(int64, tag = "1")]
+    pub value_us: i64,
+    /// The local timezone in which the event was recorded.
+    #[prost(string, tag = "2")]
+    pub timezone: ::prost::alloc::string::String,
+    #[prost(enumeration = "instant::Precision", tag = "3")]
+    pub precision: i32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "4")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "5")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Nested message and enum types in `Instant`.
+pub mod instant {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Precision {
+        Unspecified = 0,
+        Second = 1,
+        Millisecond = 2,
+        Microsecond = 3,
+    }
+    impl Precision {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "PRECISION_UNSPECIFIED",
+                Self::Second => "SECOND",
+                Self::Millisecond => "MILLISECOND",
+                Self::Microsecond => "MICROSECOND",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PRECISION_UNSPECIFIED" => Some(Self::Unspecified),
+                "SECOND" => Some(Self::Second),
+                "MILLISECOND" => Some(Self::Millisecond),
+                "MICROSECOND" => Some(Self::Microsecond),
+                _ => None,
+            }
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for integer.
+/// Primitive Type integer.
+/// See <http://hl7.org/fhir/StructureDefinition/integer>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Integer {
+    /// Primitive value for integer
+    #[prost(sint32, tag = "1")]
+    pub value: i32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for integer64.
+/// Primitive Type integer64.
+/// See <http://hl7.org/fhir/StructureDefinition/integer64>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Integer64 {
+    /// Primitive value for integer64
+    #[prost(sint64, tag = "1")]
+    pub value: i64,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for markdown.
+/// Primitive Type markdown.
+/// See <http://hl7.org/fhir/StructureDefinition/markdown>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Markdown {
+    /// Primitive value for markdown
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for MarketingStatus.
+/// The marketing status describes the date when a medicinal product is actually
+/// put on the market or the date as of which it is no longer available. See
+/// <http://hl7.org/fhir/StructureDefinition/MarketingStatus>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketingStatus {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// The country in which the marketing authorization has been granted shall be
+    /// specified It should be specified using the ISO 3166 ‑ 1 alpha-2 code
+    /// elements
+    #[prost(message, optional, tag = "4")]
+    pub country: ::core::option::Option<CodeableConcept>,
+    /// Where a Medicines Regulatory Agency has granted a marketing authorization
+    /// for which specific provisions within a jurisdiction apply, the jurisdiction
+    /// can be specified using an appropriate controlled terminology The controlled
+    /// term and the controlled term identifier shall be specified
+    #[prost(message, optional, tag = "5")]
+    pub jurisdiction: ::core::option::Option<CodeableConcept>,
+    /// This attribute provides information on the status of the marketing of the
+    /// medicinal product See ISO/TS 20443 for more information and examples
+    #[prost(message, optional, tag = "6")]
+    pub status: ::core::option::Option<CodeableConcept>,
+    /// The date when the Medicinal Product is placed on the market by the
+    /// Marketing Authorization Holder (or where applicable, the
+    /// manufacturer/distributor) in a country and/or jurisdiction shall be
+    /// provided A complete date consisting of day, month and year shall be
+    /// specified using the ISO 8601 date format NOTE “Placed on the market” refers
+    /// to the release of the Medicinal Product into the distribution chain
+    #[prost(message, optional, tag = "7")]
+    pub date_range: ::core::option::Option<Period>,
+    /// The date when the Medicinal Product is placed on the market by the
+    /// Marketing Authorization Holder (or where applicable, the
+    /// manufacturer/distributor) in a country and/or jurisdiction shall be
+    /// provided A complete date consisting of day, month and year shall be
+    /// specified using the ISO 8601 date format NOTE “Placed on the market” refers
+    /// to the release of the Medicinal Product into the distribution chain
+    #[prost(message, optional, tag = "8")]
+    pub restore_date: ::core::option::Option<DateTime>,
+}
+/// Auto-generated from StructureDefinition for Meta.
+/// Metadata about a resource.
+/// See <http://hl7.org/fhir/StructureDefinition/Meta>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Meta {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Version specific identifier
+    #[prost(message, optional, tag = "3")]
+    pub version_id: ::core::option::Option<Id>,
+    /// When the resource version last changed
+    #[prost(message, optional, tag = "4")]
+    pub last_updated: ::core::option::Option<Instant>,
+    /// Identifies where the resource comes from
+    #[prost(message, optional, tag = "5")]
+    pub source: ::core::option::Option<Uri>,
+    /// Profiles this resource claims to conform to
+    #[prost(message, repeated, tag = "6")]
+    pub profile: ::prost::alloc::vec::Vec<Canonical>,
+    /// Security Labels applied to this resource
+    #[prost(message, repeated, tag = "7")]
+    pub security: ::prost::alloc::vec::Vec<Coding>,
+    /// Tags applied to this resource
+    #[prost(message, repeated, tag = "8")]
+    pub tag: ::prost::alloc::vec::Vec<Coding>,
+}
+/// Auto-generated from StructureDefinition for MonetaryComponent.
+/// Availability data for an {item}.
+/// See <http://hl7.org/fhir/StructureDefinition/MonetaryComponent>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MonetaryComponent {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#type: ::core::option::Option<monetary_component::TypeCode>,
+    /// Codes may be used to differentiate between kinds of taxes, surcharges,
+    /// discounts etc.
+    #[prost(message, optional, tag = "4")]
+    pub code: ::core::option::Option<CodeableConcept>,
+    /// Factor used for calculating this component
+    #[prost(message, optional, tag = "5")]
+    pub factor: ::core::option::Option<Decimal>,
+    /// Explicit value amount to be used
+    #[prost(message, optional, tag = "6")]
+    pub amount: ::core::option::Option<Money>,
+}
+/// Nested message and enum types in `MonetaryComponent`.
+pub mod monetary_component {
+    /// base | surcharge | deduction | discount | tax | informational
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::price_component_type_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Money.
+/// An amount of economic utility in some recognized currency.
+/// See <http://hl7.org/fhir/StructureDefinition/Money>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Money {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub currency: ::core::option::Option<money::CurrencyCode>,
+}
+/// Nested message and enum types in `Money`.
+pub mod money {
+    /// ISO 4217 Currency Code
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CurrencyCode {
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// This valueset is not enumerable, and so is represented as a string.
+        #[prost(string, tag = "4")]
+        pub value: ::prost::alloc::string::String,
+    }
+}
+/// Auto-generated from StructureDefinition for MoneyQuantity.
+/// An amount of money. With regard to precision, see [Decimal
+/// Precision](datatypes.html#precision). See
+/// <http://hl7.org/fhir/StructureDefinition/MoneyQuantity>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoneyQuantity {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub comparator: ::core::option::Option<money_quantity::ComparatorCode>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Nested message and enum types in `MoneyQuantity`.
+pub mod money_quantity {
+    /// < | <= | >= | > | ad - how to understand the value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ComparatorCode {
+        #[prost(enumeration = "super::quantity_comparator_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Narrative.
+/// Human-readable summary of the resource (essential clinical and business
+/// information). See <http://hl7.org/fhir/StructureDefinition/Narrative>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Narrative {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub status: ::core::option::Option<narrative::StatusCode>,
+    /// Limited xhtml content
+    #[prost(message, optional, tag = "4")]
+    pub div: ::core::option::Option<Xhtml>,
+}
+/// Nested message and enum types in `Narrative`.
+pub mod narrative {
+    /// generated | extensions | additional | empty
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StatusCode {
+        #[prost(enumeration = "super::narrative_status_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for oid.
+/// Primitive Type oid.
+/// See <http://hl7.org/fhir/StructureDefinition/oid>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Oid {
+    /// Primitive value for oid
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for ParameterDefinition.
+/// Definition of a parameter to a module.
+/// See <http://hl7.org/fhir/StructureDefinition/ParameterDefinition>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParameterDefinition {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Name used to access the parameter value
+    #[prost(message, optional, tag = "3")]
+    pub name: ::core::option::Option<Code>,
+    #[prost(message, optional, tag = "4")]
+    pub r#use: ::core::option::Option<parameter_definition::UseCode>,
+    /// Minimum cardinality
+    #[prost(message, optional, tag = "5")]
+    pub min: ::core::option::Option<Integer>,
+    /// Maximum cardinality (a number of *)
+    #[prost(message, optional, tag = "6")]
+    pub max: ::core::option::Option<String>,
+    /// A brief description of the parameter
+    #[prost(message, optional, tag = "7")]
+    pub documentation: ::core::option::Option<String>,
+    #[prost(message, optional, tag = "10")]
+    pub r#type: ::core::option::Option<parameter_definition::TypeCode>,
+    /// What profile the value is expected to be
+    #[prost(message, optional, tag = "9")]
+    pub profile: ::core::option::Option<Canonical>,
+}
+/// Nested message and enum types in `ParameterDefinition`.
+pub mod parameter_definition {
+    /// in | out
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UseCode {
+        #[prost(enumeration = "super::operation_parameter_use_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// What type of value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::fhir_types_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Period.
+/// Time range defined by start and end date/time.
+/// See <http://hl7.org/fhir/StructureDefinition/Period>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Period {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Starting time with inclusive boundary
+    #[prost(message, optional, tag = "3")]
+    pub start: ::core::option::Option<DateTime>,
+    /// End time with inclusive boundary, if not ongoing
+    #[prost(message, optional, tag = "4")]
+    pub end: ::core::option::Option<DateTime>,
+}
+/// Auto-generated from StructureDefinition for positiveInt.
+/// Primitive Type positiveInt.
+/// See <http://hl7.org/fhir/StructureDefinition/positiveInt>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositiveInt {
+    /// Primitive value for positiveInt
+    #[prost(uint32, tag = "1")]
+    pub value: u32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for PrimitiveType.
+/// Parent type for DataTypes with a simple value.
+/// See <http://hl7.org/fhir/StructureDefinition/PrimitiveType>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrimitiveType {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for ProductShelfLife.
+/// The shelf-life and storage information for a medicinal product item or
+/// container can be described using this class. See
+/// <http://hl7.org/fhir/StructureDefinition/ProductShelfLife>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProductShelfLife {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// This describes the shelf life, taking into account various scenarios such
+    /// as shelf life of the packaged Medicinal Product itself, shelf life after
+    /// transformation where necessary and shelf life after the first opening of a
+    /// bottle, etc. The shelf life type shall be specified using an appropriate
+    /// controlled vocabulary The controlled term and the controlled term
+    /// identifier shall be specified
+    #[prost(message, optional, tag = "5")]
+    pub r#type: ::core::option::Option<CodeableConcept>,
+    #[prost(message, optional, tag = "8")]
+    pub period: ::core::option::Option<product_shelf_life::PeriodX>,
+    /// Special precautions for storage, if any, can be specified using an
+    /// appropriate controlled vocabulary The controlled term and the controlled
+    /// term identifier shall be specified
+    #[prost(message, repeated, tag = "7")]
+    pub special_precautions_for_storage: ::prost::alloc::vec::Vec<CodeableConcept>,
+}
+/// Nested message and enum types in `ProductShelfLife`.
+pub mod product_shelf_life {
+    /// The shelf life time period can be specified using a numerical value for the
+    /// period of time and its unit of time measurement The unit of measurement
+    /// shall be specified in accordance with ISO 11240 and the resulting
+    /// terminology The symbol and the symbol identifier shall be used
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PeriodX {
+        #[prost(oneof = "period_x::Choice", tags = "1, 2")]
+        pub choice: ::core::option::Option<period_x::Choice>,
+    }
+    /// Nested message and enum types in `PeriodX`.
+    pub mod period_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Duration(super::super::Duration),
+            #[prost(message, tag = "2")]
+            StringValue(super::super::String),
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Quantity.
+/// A measured or measurable amount.
+/// See <http://hl7.org/fhir/StructureDefinition/Quantity>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Quantity {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "4")]
+    pub comparator: ::core::option::Option<quantity::ComparatorCode>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Nested message and enum types in `Quantity`.
+pub mod quantity {
+    /// < | <= | >= | > | ad - how to understand the value
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ComparatorCode {
+        #[prost(enumeration = "super::quantity_comparator_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for Range.
+/// Set of values bounded by low and high.
+/// See <http://hl7.org/fhir/StructureDefinition/Range>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Range {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Low limit
+    #[prost(message, optional, tag = "3")]
+    pub low: ::core::option::Option<SimpleQuantity>,
+    /// High limit
+    #[prost(message, optional, tag = "4")]
+    pub high: ::core::option::Option<SimpleQuantity>,
+}
+/// Auto-generated from StructureDefinition for Ratio.
+/// A ratio of two Quantity values - a numerator and a denominator.
+/// See <http://hl7.org/fhir/StructureDefinition/Ratio>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ratio {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerator value
+    #[prost(message, optional, tag = "3")]
+    pub numerator: ::core::option::Option<Quantity>,
+    /// Denominator value
+    #[prost(message, optional, tag = "5")]
+    pub denominator: ::core::option::Option<SimpleQuantity>,
+}
+/// Auto-generated from StructureDefinition for RatioRange.
+/// Range of ratio values.
+/// See <http://hl7.org/fhir/StructureDefinition/RatioRange>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RatioRange {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Low Numerator limit
+    #[prost(message, optional, tag = "3")]
+    pub low_numerator: ::core::option::Option<SimpleQuantity>,
+    /// High Numerator limit
+    #[prost(message, optional, tag = "4")]
+    pub high_numerator: ::core::option::Option<SimpleQuantity>,
+    /// Denominator value
+    #[prost(message, optional, tag = "5")]
+    pub denominator: ::core::option::Option<SimpleQuantity>,
+}
+///   A reference from one resource to another. See
+///   <https://www.hl7.org/fhir/datatypes.html#Reference.>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Reference {
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional Content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Type the reference refers to (e.g. "Patient") - must be a resource in
+    /// resources
+    #[prost(message, optional, tag = "3")]
+    pub r#type: ::core::option::Option<Uri>,
+    /// Logical reference, when literal reference is not known
+    #[prost(message, optional, boxed, tag = "155")]
+    pub identifier: ::core::option::Option<::prost::alloc::boxed::Box<Identifier>>,
+    /// Text alternative for the resource.
+    #[prost(message, optional, tag = "156")]
+    pub display: ::core::option::Option<String>,
+    #[prost(
+        oneof = "reference::Reference",
+        tags = "4, 5, 6, 7, 8, 157, 158, 9, 10, 11, 12, 159, 13, 14, 15, 16, 160, 17, 18, 19, 20, 21, 23, 24, 161, 25, 26, 27, 162, 28, 29, 30, 31, 32, 33, 34, 163, 35, 36, 37, 38, 39, 40, 41, 164, 42, 165, 43, 44, 166, 46, 48, 51, 167, 52, 53, 54, 55, 56, 57, 168, 58, 59, 60, 61, 62, 169, 170, 63, 64, 65, 66, 67, 171, 68, 69, 70, 71, 72, 172, 73, 173, 174, 74, 75, 76, 77, 78, 175, 79, 80, 82, 83, 84, 85, 86, 87, 176, 98, 99, 100, 101, 177, 102, 178, 103, 104, 105, 106, 107, 108, 179, 109, 110, 111, 112, 180, 113, 114, 115, 116, 117, 118, 119, 120, 181, 121, 182, 183, 125, 126, 127, 129, 130, 131, 132, 133, 134, 135, 136, 137, 184, 185, 138, 186, 139, 140, 141, 142, 143, 145, 146, 147, 148, 187, 149, 150, 188, 151, 152, 153"
+    )]
+    pub reference: ::core::option::Option<reference::Reference>,
+}
+/// Nested message and enum types in `Reference`.
+pub mod reference {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Reference {
+        /// Field representing absolute URIs, which are untyped.
+        #[prost(message, tag = "4")]
+        Uri(super::String),
+        /// Field representing fragment URIs, which are untyped, and represented here
+        /// without the leading '#'
+        #[prost(message, tag = "5")]
+        Fragment(super::String),
+        /// Typed relative urls are represented here.
+        #[prost(message, tag = "6")]
+        ResourceId(super::ReferenceId),
+        #[prost(message, tag = "7")]
+        AccountId(super::ReferenceId),
+        #[prost(message, tag = "8")]
+        ActivityDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "157")]
+        ActorDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "158")]
+        AdministrableProductDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "9")]
+        AdverseEventId(super::ReferenceId),
+        #[prost(message, tag = "10")]
+        AllergyIntoleranceId(super::ReferenceId),
+        #[prost(message, tag = "11")]
+        AppointmentId(super::ReferenceId),
+        #[prost(message, tag = "12")]
+        AppointmentResponseId(super::ReferenceId),
+        #[prost(message, tag = "159")]
+        ArtifactAssessmentId(super::ReferenceId),
+        #[prost(message, tag = "13")]
+        AuditEventId(super::ReferenceId),
+        #[prost(message, tag = "14")]
+        BasicId(super::ReferenceId),
+        #[prost(message, tag = "15")]
+        BinaryId(super::ReferenceId),
+        #[prost(message, tag = "16")]
+        BiologicallyDerivedProductId(super::ReferenceId),
+        #[prost(message, tag = "160")]
+        BiologicallyDerivedProductDispenseId(super::ReferenceId),
+        #[prost(message, tag = "17")]
+        BodyStructureId(super::ReferenceId),
+        #[prost(message, tag = "18")]
+        BundleId(super::ReferenceId),
+        #[prost(message, tag = "19")]
+        CapabilityStatementId(super::ReferenceId),
+        #[prost(message, tag = "20")]
+        CarePlanId(super::ReferenceId),
+        #[prost(message, tag = "21")]
+        CareTeamId(super::ReferenceId),
+        #[prost(message, tag = "23")]
+        ChargeItemId(super::ReferenceId),
+        #[prost(message, tag = "24")]
+        ChargeItemDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "161")]
+        CitationId(super::ReferenceId),
+        #[prost(message, tag = "25")]
+        ClaimId(super::ReferenceId),
+        #[prost(message, tag = "26")]
+        ClaimResponseId(super::ReferenceId),
+        #[prost(message, tag = "27")]
+        ClinicalImpressionId(super::ReferenceId),
+        #[prost(message, tag = "162")]
+        ClinicalUseDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "28")]
+        CodeSystemId(super::ReferenceId),
+        #[prost(message, tag = "29")]
+        CommunicationId(super::ReferenceId),
+        #[prost(message, tag = "30")]
+        CommunicationRequestId(super::ReferenceId),
+        #[prost(message, tag = "31")]
+        CompartmentDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "32")]
+        CompositionId(super::ReferenceId),
+        #[prost(message, tag = "33")]
+        ConceptMapId(super::ReferenceId),
+        #[prost(message, tag = "34")]
+        ConditionId(super::ReferenceId),
+        #[prost(message, tag = "163")]
+        ConditionDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "35")]
+        ConsentId(super::ReferenceId),
+        #[prost(message, tag = "36")]
+        ContractId(super::ReferenceId),
+        #[prost(message, tag = "37")]
+        CoverageId(super::ReferenceId),
+        #[prost(message, tag = "38")]
+        CoverageEligibilityRequestId(super::ReferenceId),
+        #[prost(message, tag = "39")]
+        CoverageEligibilityResponseId(super::ReferenceId),
+        #[prost(message, tag = "40")]
+        DetectedIssueId(super::ReferenceId),
+        #[prost(message, tag = "41")]
+        DeviceId(super::ReferenceId),
+        #[prost(message, tag = "164")]
+        DeviceAssociationId(super::ReferenceId),
+        #[prost(message, tag = "42")]
+        DeviceDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "165")]
+        DeviceDispenseId(super::ReferenceId),
+        #[prost(message, tag = "43")]
+        DeviceMetricId(super::ReferenceId),
+        #[prost(message, tag = "44")]
+        DeviceRequestId(super::ReferenceId),
+        #[prost(message, tag = "166")]
+        DeviceUsageId(super::ReferenceId),
+        #[prost(message, tag = "46")]
+        DiagnosticReportId(super::ReferenceId),
+        #[prost(message, tag = "48")]
+        DocumentReferenceId(super::ReferenceId),
+        #[prost(message, tag = "51")]
+        EncounterId(super::ReferenceId),
+        #[prost(message, tag = "167")]
+        EncounterHistoryId(super::ReferenceId),
+        #[prost(message, tag = "52")]
+        EndpointId(super::ReferenceId),
+        #[prost(message, tag = "53")]
+        EnrollmentRequestId(super::ReferenceId),
+        #[prost(message, tag = "54")]
+        EnrollmentResponseId(super::ReferenceId),
+        #[prost(message, tag = "55")]
+        EpisodeOfCareId(super::ReferenceId),
+        #[prost(message, tag = "56")]
+        EventDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "57")]
+        EvidenceId(super::ReferenceId),
+        #[prost(message, tag = "168")]
+        EvidenceReportId(super::ReferenceId),
+        #[prost(message, tag = "58")]
+        EvidenceVariableId(super::ReferenceId),
+        #[prost(message, tag = "59")]
+        ExampleScenarioId(super::ReferenceId),
+        #[prost(message, tag = "60")]
+        ExplanationOfBenefitId(super::ReferenceId),
+        #[prost(message, tag = "61")]
+        FamilyMemberHistoryId(super::ReferenceId),
+        #[prost(message, tag = "62")]
+        FlagId(super::ReferenceId),
+        #[prost(message, tag = "169")]
+        FormularyItemId(super::ReferenceId),
+        #[prost(message, tag = "170")]
+        GenomicStudyId(super::ReferenceId),
+        #[prost(message, tag = "63")]
+        GoalId(super::ReferenceId),
+        #[prost(message, tag = "64")]
+        GraphDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "65")]
+        GroupId(super::ReferenceId),
+        #[prost(message, tag = "66")]
+        GuidanceResponseId(super::ReferenceId),
+        #[prost(message, tag = "67")]
+        HealthcareServiceId(super::ReferenceId),
+        #[prost(message, tag = "171")]
+        ImagingSelectionId(super::ReferenceId),
+        #[prost(message, tag = "68")]
+        ImagingStudyId(super::ReferenceId),
+        #[prost(message, tag = "69")]
+        ImmunizationId(super::ReferenceId),
+        #[prost(message, tag = "70")]
+        ImmunizationEvaluationId(super::ReferenceId),
+        #[prost(message, tag = "71")]
+        ImmunizationRecommendationId(super::ReferenceId),
+        #[prost(message, tag = "72")]
+        ImplementationGuideId(super::ReferenceId),
+        #[prost(message, tag = "172")]
+        IngredientId(super::ReferenceId),
+        #[prost(message, tag = "73")]
+        InsurancePlanId(super::ReferenceId),
+        #[prost(message, tag = "173")]
+        InventoryItemId(super::ReferenceId),
+        #[prost(message, tag = "174")]
+        InventoryReportId(super::ReferenceId),
+        #[prost(message, tag = "74")]
+        InvoiceId(super::ReferenceId),
+        #[prost(message, tag = "75")]
+        LibraryId(super::ReferenceId),
+        #[prost(message, tag = "76")]
+        LinkageId(super::ReferenceId),
+        #[prost(message, tag = "77")]
+        ListId(super::ReferenceId),
+        #[prost(message, tag = "78")]
+        LocationId(super::ReferenceId),
+        #[prost(message, tag = "175")]
+        ManufacturedItemDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "79")]
+        MeasureId(super::ReferenceId),
+        #[prost(message, tag = "80")]
+        MeasureReportId(super::ReferenceId),
+        #[prost(message, tag = "82")]
+        MedicationId(super::ReferenceId),
+        #[prost(message, tag = "83")]
+        MedicationAdministrationId(super::ReferenceId),
+        #[prost(message, tag = "84")]
+        MedicationDispenseId(super::ReferenceId),
+        #[prost(message, tag = "85")]
+        MedicationKnowledgeId(super::ReferenceId),
+        #[prost(message, tag = "86")]
+        MedicationRequestId(super::ReferenceId),
+        #[prost(message, tag = "87")]
+        MedicationStatementId(super::ReferenceId),
+        #[prost(message, tag = "176")]
+        MedicinalProductDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "98")]
+        MessageDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "99")]
+        MessageHeaderId(super::ReferenceId),
+        #[prost(message, tag = "100")]
+        MolecularSequenceId(super::ReferenceId),
+        #[prost(message, tag = "101")]
+        NamingSystemId(super::ReferenceId),
+        #[prost(message, tag = "177")]
+        NutritionIntakeId(super::ReferenceId),
+        #[prost(message, tag = "102")]
+        NutritionOrderId(super::ReferenceId),
+        #[prost(message, tag = "178")]
+        NutritionProductId(super::ReferenceId),
+        #[prost(message, tag = "103")]
+        ObservationId(super::ReferenceId),
+        #[prost(message, tag = "104")]
+        ObservationDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "105")]
+        OperationDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "106")]
+        OperationOutcomeId(super::ReferenceId),
+        #[prost(message, tag = "107")]
+        OrganizationId(super::ReferenceId),
+        #[prost(message, tag = "108")]
+        OrganizationAffiliationId(super::ReferenceId),
+        #[prost(message, tag = "179")]
+        PackagedProductDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "109")]
+        ParametersId(super::ReferenceId),
+        #[prost(message, tag = "110")]
+        PatientId(super::ReferenceId),
+        #[prost(message, tag = "111")]
+        PaymentNoticeId(super::ReferenceId),
+        #[prost(message, tag = "112")]
+        PaymentReconciliationId(super::ReferenceId),
+        #[prost(message, tag = "180")]
+        PermissionId(super::ReferenceId),
+        #[prost(message, tag = "113")]
+        PersonId(super::ReferenceId),
+        #[prost(message, tag = "114")]
+        PlanDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "115")]
+        PractitionerId(super::ReferenceId),
+        #[prost(message, tag = "116")]
+        PractitionerRoleId(super::ReferenceId),
+        #[prost(message, tag = "117")]
+        ProcedureId(super::ReferenceId),
+        #[prost(message, tag = "118")]
+        ProvenanceId(super::ReferenceId),
+        #[prost(message, tag = "119")]
+        QuestionnaireId(super::ReferenceId),
+        #[prost(message, tag = "120")]
+        QuestionnaireResponseId(super::ReferenceId),
+        #[prost(message, tag = "181")]
+        RegulatedAuthorizationId(super::ReferenceId),
+        #[prost(message, tag = "121")]
+        RelatedPersonId(super::ReferenceId),
+        #[prost(message, tag = "182")]
+        RequestOrchestrationId(super::ReferenceId),
+        #[prost(message, tag = "183")]
+        RequirementsId(super::ReferenceId),
+        #[prost(message, tag = "125")]
+        ResearchStudyId(super::ReferenceId),
+        #[prost(message, tag = "126")]
+        ResearchSubjectId(super::ReferenceId),
+        #[prost(message, tag = "127")]
+        RiskAssessmentId(super::ReferenceId),
+        #[prost(message, tag = "129")]
+        ScheduleId(super::ReferenceId),
+        #[prost(message, tag = "130")]
+        SearchParameterId(super::ReferenceId),
+        #[prost(message, tag = "131")]
+        ServiceRequestId(super::ReferenceId),
+        #[prost(message, tag = "132")]
+        SlotId(super::ReferenceId),
+        #[prost(message, tag = "133")]
+        SpecimenId(super::ReferenceId),
+        #[prost(message, tag = "134")]
+        SpecimenDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "135")]
+        StructureDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "136")]
+        StructureMapId(super::ReferenceId),
+        #[prost(message, tag = "137")]
+        SubscriptionId(super::ReferenceId),
+        #[prost(message, tag = "184")]
+        SubscriptionStatusId(super::ReferenceId),
+        #[prost(message, tag = "185")]
+        SubscriptionTopicId(super::ReferenceId),
+        #[prost(message, tag = "138")]
+        SubstanceId(super::ReferenceId),
+        #[prost(message, tag = "186")]
+        SubstanceDefinitionId(super::ReferenceId),
+        #[prost(message, tag = "139")]
+        SubstanceNucleicAcidId(super::ReferenceId),
+        #[prost(message, tag = "140")]
+        SubstancePolymerId(super::ReferenceId),
+        #[prost(message, tag = "141")]
+        SubstanceProteinId(super::ReferenceId),
+        #[prost(message, tag = "142")]
+        SubstanceReferenceInformationId(super::ReferenceId),
+        #[prost(message, tag = "143")]
+        SubstanceSourceMaterialId(super::ReferenceId),
+        #[prost(message, tag = "145")]
+        SupplyDeliveryId(super::ReferenceId),
+        #[prost(message, tag = "146")]
+        SupplyRequestId(super::ReferenceId),
+        #[prost(message, tag = "147")]
+        TaskId(super::ReferenceId),
+        #[prost(message, tag = "148")]
+        TerminologyCapabilitiesId(super::ReferenceId),
+        #[prost(message, tag = "187")]
+        TestPlanId(super::ReferenceId),
+        #[prost(message, tag = "149")]
+        TestReportId(super::ReferenceId),
+        #[prost(message, tag = "150")]
+        TestScriptId(super::ReferenceId),
+        #[prost(message, tag = "188")]
+        TransportId(super::ReferenceId),
+        #[prost(message, tag = "151")]
+        ValueSetId(super::ReferenceId),
+        #[prost(message, tag = "152")]
+        VerificationResultId(super::ReferenceId),
+        #[prost(message, tag = "153")]
+        VisionPrescriptionId(super::ReferenceId),
+    }
+}
+/// Typed representation of relative references for the Reference.reference
+/// field. For instance, a JSON FHIR reference of 'Patient/1234' is represented
+/// in FhirProto as {patientId {value:'1234'} }, where patientId is a field of
+/// type ReferenceId.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReferenceId {
+    /// Id of the resource being referenced.
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// History version, if present.
+    #[prost(message, optional, tag = "2")]
+    pub history: ::core::option::Option<Id>,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "3")]
+    pub id: ::core::option::Option<String>,
+    /// Additional Content defined by implementations
+    #[prost(message, repeated, tag = "4")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for RelatedArtifact.
+/// Related artifacts for a knowledge resource.
+/// See <http://hl7.org/fhir/StructureDefinition/RelatedArtifact>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelatedArtifact {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#type: ::core::option::Option<related_artifact::TypeCode>,
+    /// Additional classifiers
+    #[prost(message, repeated, tag = "10")]
+    pub classifier: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Short label
+    #[prost(message, optional, tag = "4")]
+    pub label: ::core::option::Option<String>,
+    /// Brief description of the related artifact
+    #[prost(message, optional, tag = "5")]
+    pub display: ::core::option::Option<String>,
+    /// Bibliographic citation for the artifact
+    #[prost(message, optional, tag = "6")]
+    pub citation: ::core::option::Option<Markdown>,
+    /// What document is being referenced
+    #[prost(message, optional, tag = "8")]
+    pub document: ::core::option::Option<Attachment>,
+    /// What artifact is being referenced
+    #[prost(message, optional, tag = "9")]
+    pub resource: ::core::option::Option<Canonical>,
+    /// What artifact, if not a conformance resource
+    #[prost(message, optional, tag = "11")]
+    pub resource_reference: ::core::option::Option<Reference>,
+    #[prost(message, optional, tag = "12")]
+    pub publication_status: ::core::option::Option<
+        related_artifact::PublicationStatusCode,
+    >,
+    /// Date of publication of the artifact being referred to
+    #[prost(message, optional, tag = "13")]
+    pub publication_date: ::core::option::Option<Date>,
+}
+/// Nested message and enum types in `RelatedArtifact`.
+pub mod related_artifact {
+    /// documentation | justification | citation | predecessor | successor |
+    /// derived-from | depends-on | composed-of | part-of | amends | amended-with |
+    /// appends | appended-with | cites | cited-by | comments-on | comment-in |
+    /// contains | contained-in | corrects | correction-in | replaces |
+    /// replaced-with | retracts | retracted-by | signs | similar-to | supports |
+    /// supported-with | transforms | transformed-into | transformed-with |
+    /// documents | specification-of | created-with | cite-as
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::related_artifact_type_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// draft | active | retired | unknown
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PublicationStatusCode {
+        #[prost(enumeration = "super::publication_status_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+}
+/// Auto-generated from StructureDefinition for SampledData.
+/// A series of measurements taken by a device.
+/// See <http://hl7.org/fhir/StructureDefinition/SampledData>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SampledData {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Zero value and units
+    #[prost(message, optional, tag = "3")]
+    pub origin: ::core::option::Option<SimpleQuantity>,
+    /// Number of intervalUnits between samples
+    #[prost(message, optional, tag = "10")]
+    pub interval: ::core::option::Option<Decimal>,
+    #[prost(message, optional, tag = "11")]
+    pub interval_unit: ::core::option::Option<sampled_data::IntervalUnitCode>,
+    /// Multiply data by this before adding to origin
+    #[prost(message, optional, tag = "5")]
+    pub factor: ::core::option::Option<Decimal>,
+    /// Lower limit of detection
+    #[prost(message, optional, tag = "6")]
+    pub lower_limit: ::core::option::Option<Decimal>,
+    /// Upper limit of detection
+    #[prost(message, optional, tag = "7")]
+    pub upper_limit: ::core::option::Option<Decimal>,
+    /// Number of sample points at each time point
+    #[prost(message, optional, tag = "8")]
+    pub dimensions: ::core::option::Option<PositiveInt>,
+    /// Defines the codes used in the data
+    #[prost(message, optional, tag = "12")]
+    pub code_map: ::core::option::Option<Canonical>,
+    /// Offsets, typically in time, at which data values were taken
+    #[prost(message, optional, tag = "13")]
+    pub offsets: ::core::option::Option<String>,
+    /// Decimal values with spaces, or "E" | "U" | "L", or another code
+    #[prost(message, optional, tag = "9")]
+    pub data: ::core::option::Option<String>,
+}
+/// Nested message and enum types in `SampledData`.
+pub mod sampled_data {
+    /// The measurement unit of the interval between samples
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IntervalUnitCode {
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// This valueset is not enumerable, and so is represented as a string.
+        #[prost(string, tag = "4")]
+        pub value: ::prost::alloc::string::String,
+    }
+}
+/// Auto-generated from StructureDefinition for Signature.
+/// A Signature - XML DigSig, JWS, Graphical image of signature, etc.
+/// See <http://hl7.org/fhir/StructureDefinition/Signature>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Signature {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Indication of the reason the entity signed the object(s)
+    #[prost(message, repeated, tag = "3")]
+    pub r#type: ::prost::alloc::vec::Vec<Coding>,
+    /// When the signature was created
+    #[prost(message, optional, tag = "4")]
+    pub when: ::core::option::Option<Instant>,
+    /// Who signed
+    #[prost(message, optional, tag = "5")]
+    pub who: ::core::option::Option<Reference>,
+    /// The party represented
+    #[prost(message, optional, tag = "6")]
+    pub on_behalf_of: ::core::option::Option<Reference>,
+    #[prost(message, optional, tag = "7")]
+    pub target_format: ::core::option::Option<signature::TargetFormatCode>,
+    #[prost(message, optional, tag = "8")]
+    pub sig_format: ::core::option::Option<signature::SigFormatCode>,
+    /// The actual signature content (XML DigSig. JWS, picture, etc.)
+    #[prost(message, optional, tag = "9")]
+    pub data: ::core::option::Option<Base64Binary>,
+}
+/// Nested message and enum types in `Signature`.
+pub mod signature {
+    /// The technical format of the signed resources
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TargetFormatCode {
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// This valueset is not enumerable, and so is represented as a string.
+        #[prost(string, tag = "4")]
+        pub value: ::prost::alloc::string::String,
+    }
+    /// The technical format of the signature
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SigFormatCode {
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// This valueset is not enumerable, and so is represented as a string.
+        #[prost(string, tag = "4")]
+        pub value: ::prost::alloc::string::String,
+    }
+}
+/// Auto-generated from StructureDefinition for SimpleQuantity.
+/// A fixed quantity (no comparator).
+/// See <http://hl7.org/fhir/StructureDefinition/SimpleQuantity>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimpleQuantity {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Numerical value (with implicit precision)
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<Decimal>,
+    /// Unit representation
+    #[prost(message, optional, tag = "5")]
+    pub unit: ::core::option::Option<String>,
+    /// System that defines coded unit form
+    #[prost(message, optional, tag = "6")]
+    pub system: ::core::option::Option<Uri>,
+    /// Coded form of the unit
+    #[prost(message, optional, tag = "7")]
+    pub code: ::core::option::Option<Code>,
+}
+/// Auto-generated from StructureDefinition for string.
+/// Primitive Type string.
+/// See <http://hl7.org/fhir/StructureDefinition/string>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct String {
+    /// Primitive value for string
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, boxed, tag = "2")]
+    pub id: ::core::option::Option<::prost::alloc::boxed::Box<String>>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for time.
+/// Primitive Type time.
+/// See <http://hl7.org/fhir/StructureDefinition/time>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Time {
+    /// The absolute time of the event as a Unix epoch in microseconds.
+    #[prost(int64, tag = "1")]
+    pub value_us: i64,
+    #[prost(enumeration = "time::Precision", tag = "2")]
+    pub precision: i32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "3")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "4")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Nested message and enum types in `Time`.
+pub mod time {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Precision {
+        Unspecified = 0,
+        Second = 1,
+        Millisecond = 2,
+        Microsecond = 3,
+    }
+    impl Precision {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "PRECISION_UNSPECIFIED",
+                Self::Second => "SECOND",
+                Self::Millisecond => "MILLISECOND",
+                Self::Microsecond => "MICROSECOND",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PRECISION_UNSPECIFIED" => Some(Self::Unspecified),
+                "SECOND" => Some(Self::Second),
+                "MILLISECOND" => Some(Self::Millisecond),
+                "MICROSECOND" => Some(Self::Microsecond),
+                _ => None,
+            }
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Timing.
+/// A timing schedule that specifies an event that may occur multiple times.
+/// See <http://hl7.org/fhir/StructureDefinition/Timing>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Timing {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored even if unrecognized
+    #[prost(message, repeated, tag = "3")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// When the event occurs
+    #[prost(message, repeated, tag = "4")]
+    pub event: ::prost::alloc::vec::Vec<DateTime>,
+    #[prost(message, optional, tag = "5")]
+    pub repeat: ::core::option::Option<timing::Repeat>,
+    /// C | BID | TID | QID | AM | PM | QD | QOD | +
+    #[prost(message, optional, tag = "6")]
+    pub code: ::core::option::Option<CodeableConcept>,
+}
+/// Nested message and enum types in `Timing`.
+pub mod timing {
+    /// When the event is to occur
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Repeat {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        #[prost(message, optional, tag = "3")]
+        pub bounds: ::core::option::Option<repeat::BoundsX>,
+        /// Number of times to repeat
+        #[prost(message, optional, tag = "4")]
+        pub count: ::core::option::Option<super::PositiveInt>,
+        /// Maximum number of times to repeat
+        #[prost(message, optional, tag = "5")]
+        pub count_max: ::core::option::Option<super::PositiveInt>,
+        /// How long when it happens
+        #[prost(message, optional, tag = "6")]
+        pub duration: ::core::option::Option<super::Decimal>,
+        /// How long when it happens (Max)
+        #[prost(message, optional, tag = "7")]
+        pub duration_max: ::core::option::Option<super::Decimal>,
+        #[prost(message, optional, tag = "8")]
+        pub duration_unit: ::core::option::Option<repeat::DurationUnitCode>,
+        /// Indicates the number of repetitions that should occur within a period.
+        /// I.e. Event occurs frequency times per period
+        #[prost(message, optional, tag = "9")]
+        pub frequency: ::core::option::Option<super::PositiveInt>,
+        /// Event occurs up to frequencyMax times per period
+        #[prost(message, optional, tag = "10")]
+        pub frequency_max: ::core::option::Option<super::PositiveInt>,
+        /// The duration to which the frequency applies. I.e. Event occurs frequency
+        /// times per period
+        #[prost(message, optional, tag = "11")]
+        pub period: ::core::option::Option<super::Decimal>,
+        /// Upper limit of period (3-4 hours)
+        #[prost(message, optional, tag = "12")]
+        pub period_max: ::core::option::Option<super::Decimal>,
+        #[prost(message, optional, tag = "13")]
+        pub period_unit: ::core::option::Option<repeat::PeriodUnitCode>,
+        #[prost(message, repeated, tag = "14")]
+        pub day_of_week: ::prost::alloc::vec::Vec<repeat::DayOfWeekCode>,
+        /// Time of day for action
+        #[prost(message, repeated, tag = "15")]
+        pub time_of_day: ::prost::alloc::vec::Vec<super::Time>,
+        #[prost(message, repeated, tag = "16")]
+        pub when: ::prost::alloc::vec::Vec<repeat::WhenCode>,
+        /// Minutes from event (before or after)
+        #[prost(message, optional, tag = "17")]
+        pub offset: ::core::option::Option<super::UnsignedInt>,
+    }
+    /// Nested message and enum types in `Repeat`.
+    pub mod repeat {
+        /// Length/Range of lengths, or (Start and/or end) limits
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct BoundsX {
+            #[prost(oneof = "bounds_x::Choice", tags = "1, 2, 3")]
+            pub choice: ::core::option::Option<bounds_x::Choice>,
+        }
+        /// Nested message and enum types in `BoundsX`.
+        pub mod bounds_x {
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Choice {
+                #[prost(message, tag = "1")]
+                Duration(super::super::super::Duration),
+                #[prost(message, tag = "2")]
+                Range(super::super::super::Range),
+                #[prost(message, tag = "3")]
+                Period(super::super::super::Period),
+            }
+        }
+        /// s | min | h | d | wk | mo | a - unit of time (UCUM)
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DurationUnitCode {
+            #[prost(
+                enumeration = "super::super::units_of_time_value_set::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+        /// s | min | h | d | wk | mo | a - unit of time (UCUM)
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct PeriodUnitCode {
+            #[prost(
+                enumeration = "super::super::units_of_time_value_set::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+        /// mon | tue | wed | thu | fri | sat | sun
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DayOfWeekCode {
+            #[prost(enumeration = "super::super::days_of_week_code::Value", tag = "1")]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+        /// Code for time period of occurrence
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct WhenCode {
+            #[prost(
+                enumeration = "super::super::event_timing_value_set::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for TriggerDefinition.
+/// Defines an expected trigger for a module.
+/// See <http://hl7.org/fhir/StructureDefinition/TriggerDefinition>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TriggerDefinition {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    #[prost(message, optional, tag = "3")]
+    pub r#type: ::core::option::Option<trigger_definition::TypeCode>,
+    /// Name or URI that identifies the event
+    #[prost(message, optional, tag = "4")]
+    pub name: ::core::option::Option<String>,
+    /// Coded definition of the event
+    #[prost(message, optional, tag = "8")]
+    pub code: ::core::option::Option<CodeableConcept>,
+    /// What event
+    #[prost(message, optional, tag = "9")]
+    pub subscription_topic: ::core::option::Option<Canonical>,
+    #[prost(message, optional, tag = "5")]
+    pub timing: ::core::option::Option<trigger_definition::TimingX>,
+    /// Triggering data of the event (multiple = 'and')
+    #[prost(message, repeated, tag = "6")]
+    pub data: ::prost::alloc::vec::Vec<DataRequirement>,
+    /// Whether the event triggers (boolean expression)
+    #[prost(message, optional, tag = "7")]
+    pub condition: ::core::option::Option<Expression>,
+}
+/// Nested message and enum types in `TriggerDefinition`.
+pub mod trigger_definition {
+    /// named-event | periodic | data-changed | data-added | data-modified |
+    /// data-removed | data-accessed | data-access-ended
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TypeCode {
+        #[prost(enumeration = "super::trigger_type_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// Timing of the event
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TimingX {
+        #[prost(oneof = "timing_x::Choice", tags = "1, 2, 3, 4")]
+        pub choice: ::core::option::Option<timing_x::Choice>,
+    }
+    /// Nested message and enum types in `TimingX`.
+    pub mod timing_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Timing(super::super::Timing),
+            #[prost(message, tag = "2")]
+            Reference(super::super::Reference),
+            #[prost(message, tag = "3")]
+            Date(super::super::Date),
+            #[prost(message, tag = "4")]
+            DateTime(super::super::DateTime),
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for unsignedInt.
+/// Primitive Type unsignedInt.
+/// See <http://hl7.org/fhir/StructureDefinition/unsignedInt>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnsignedInt {
+    /// Primitive value for unsignedInt
+    #[prost(uint32, tag = "1")]
+    pub value: u32,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for uri.
+/// Primitive Type uri.
+/// See <http://hl7.org/fhir/StructureDefinition/uri>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Uri {
+    /// Primitive value for uri
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for url.
+/// Primitive Type url.
+/// See <http://hl7.org/fhir/StructureDefinition/url>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Url {
+    /// Primitive value for url
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for UsageContext.
+/// Describes the context of use for a conformance or knowledge resource.
+/// See <http://hl7.org/fhir/StructureDefinition/UsageContext>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsageContext {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Type of context being specified
+    #[prost(message, optional, tag = "3")]
+    pub code: ::core::option::Option<Coding>,
+    #[prost(message, optional, tag = "4")]
+    pub value: ::core::option::Option<usage_context::ValueX>,
+}
+/// Nested message and enum types in `UsageContext`.
+pub mod usage_context {
+    /// Value that defines the context
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ValueX {
+        #[prost(oneof = "value_x::Choice", tags = "1, 2, 3, 4")]
+        pub choice: ::core::option::Option<value_x::Choice>,
+    }
+    /// Nested message and enum types in `ValueX`.
+    pub mod value_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            CodeableConcept(super::super::CodeableConcept),
+            #[prost(message, tag = "2")]
+            Quantity(super::super::Quantity),
+            #[prost(message, tag = "3")]
+            Range(super::super::Range),
+            #[prost(message, tag = "4")]
+            Reference(super::super::Reference),
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for uuid.
+/// Primitive Type uuid.
+/// See <http://hl7.org/fhir/StructureDefinition/uuid>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Uuid {
+    /// Primitive value for uuid
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "3")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+}
+/// Auto-generated from StructureDefinition for VirtualServiceDetail.
+/// Virtual Service Contact Details.
+/// See <http://hl7.org/fhir/StructureDefinition/VirtualServiceDetail>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VirtualServiceDetail {
+    /// Unique id for inter-element referencing
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<String>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "2")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Channel Type
+    #[prost(message, optional, tag = "3")]
+    pub channel_type: ::core::option::Option<Coding>,
+    #[prost(message, optional, tag = "4")]
+    pub address: ::core::option::Option<virtual_service_detail::AddressX>,
+    /// Address to see alternative connection details
+    #[prost(message, repeated, tag = "5")]
+    pub additional_info: ::prost::alloc::vec::Vec<Url>,
+    /// Maximum number of participants supported by the virtual service
+    #[prost(message, optional, tag = "6")]
+    pub max_participants: ::core::option::Option<PositiveInt>,
+    /// Session Key required by the virtual service
+    #[prost(message, optional, tag = "7")]
+    pub session_key: ::core::option::Option<String>,
+}
+/// Nested message and enum types in `VirtualServiceDetail`.
+pub mod virtual_service_detail {
+    /// Contact address/number
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AddressX {
+        #[prost(oneof = "address_x::Choice", tags = "1, 2, 3, 4")]
+        pub choice: ::core::option::Option<address_x::Choice>,
+    }
+    /// Nested message and enum types in `AddressX`.
+    pub mod address_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Url(super::super::Url),
+            #[prost(message, tag = "2")]
+            StringValue(super::super::String),
+            #[prost(message, tag = "3")]
+            ContactPoint(super::super::ContactPoint),
+            #[prost(message, tag = "4")]
+            ExtendedContactDetail(super::super::ExtendedContactDetail),
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for xhtml.
+/// Primitive Type xhtml.
+/// See <http://hl7.org/fhir/StructureDefinition/xhtml>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Xhtml {
+    /// Actual xhtml
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    /// xml:id (or equivalent in JSON)
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<String>,
+}
+/// Auto-generated from StructureDefinition for Patient.
+/// Information about an individual or animal receiving health care services.
+/// See <http://hl7.org/fhir/StructureDefinition/Patient>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Patient {
+    /// Logical id of this artifact
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// Metadata about the resource
+    #[prost(message, optional, tag = "2")]
+    pub meta: ::core::option::Option<Meta>,
+    /// A set of rules under which this content was created
+    #[prost(message, optional, tag = "3")]
+    pub implicit_rules: ::core::option::Option<Uri>,
+    /// Language of the resource content
+    #[prost(message, optional, tag = "4")]
+    pub language: ::core::option::Option<Code>,
+    /// Text summary of the resource, for human interpretation
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<Narrative>,
+    /// Contained, inline Resources
+    #[prost(message, repeated, tag = "6")]
+    pub contained: ::prost::alloc::vec::Vec<::prost_types::Any>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "8")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored
+    #[prost(message, repeated, tag = "9")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// An identifier for this patient
+    #[prost(message, repeated, tag = "10")]
+    pub identifier: ::prost::alloc::vec::Vec<Identifier>,
+    /// Whether this patient's record is in active use
+    #[prost(message, optional, tag = "11")]
+    pub active: ::core::option::Option<Boolean>,
+    /// A name associated with the patient
+    #[prost(message, repeated, tag = "12")]
+    pub name: ::prost::alloc::vec::Vec<HumanName>,
+    /// A contact detail for the individual
+    #[prost(message, repeated, tag = "13")]
+    pub telecom: ::prost::alloc::vec::Vec<ContactPoint>,
+    #[prost(message, optional, tag = "14")]
+    pub gender: ::core::option::Option<patient::GenderCode>,
+    /// The date of birth for the individual
+    #[prost(message, optional, tag = "15")]
+    pub birth_date: ::core::option::Option<Date>,
+    #[prost(message, optional, tag = "16")]
+    pub deceased: ::core::option::Option<patient::DeceasedX>,
+    /// An address for the individual
+    #[prost(message, repeated, tag = "17")]
+    pub address: ::prost::alloc::vec::Vec<Address>,
+    /// Marital (civil) status of a patient
+    #[prost(message, optional, tag = "18")]
+    pub marital_status: ::core::option::Option<CodeableConcept>,
+    #[prost(message, optional, tag = "19")]
+    pub multiple_birth: ::core::option::Option<patient::MultipleBirthX>,
+    /// Image of the patient
+    #[prost(message, repeated, tag = "20")]
+    pub photo: ::prost::alloc::vec::Vec<Attachment>,
+    #[prost(message, repeated, tag = "21")]
+    pub contact: ::prost::alloc::vec::Vec<patient::Contact>,
+    #[prost(message, repeated, tag = "22")]
+    pub communication: ::prost::alloc::vec::Vec<patient::Communication>,
+    /// Patient's nominated primary care provider
+    #[prost(message, repeated, tag = "23")]
+    pub general_practitioner: ::prost::alloc::vec::Vec<Reference>,
+    /// Organization that is the custodian of the patient record
+    #[prost(message, optional, tag = "24")]
+    pub managing_organization: ::core::option::Option<Reference>,
+    #[prost(message, repeated, tag = "25")]
+    pub link: ::prost::alloc::vec::Vec<patient::Link>,
+}
+/// Nested message and enum types in `Patient`.
+pub mod patient {
+    /// male | female | other | unknown
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GenderCode {
+        #[prost(enumeration = "super::administrative_gender_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// Indicates if the individual is deceased or not
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DeceasedX {
+        #[prost(oneof = "deceased_x::Choice", tags = "1, 2")]
+        pub choice: ::core::option::Option<deceased_x::Choice>,
+    }
+    /// Nested message and enum types in `DeceasedX`.
+    pub mod deceased_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Boolean(super::super::Boolean),
+            #[prost(message, tag = "2")]
+            DateTime(super::super::DateTime),
+        }
+    }
+    /// Whether patient is part of a multiple birth
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MultipleBirthX {
+        #[prost(oneof = "multiple_birth_x::Choice", tags = "1, 2")]
+        pub choice: ::core::option::Option<multiple_birth_x::Choice>,
+    }
+    /// Nested message and enum types in `MultipleBirthX`.
+    pub mod multiple_birth_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            Boolean(super::super::Boolean),
+            #[prost(message, tag = "2")]
+            Integer(super::super::Integer),
+        }
+    }
+    /// A contact party (e.g. guardian, partner, friend) for the patient
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Contact {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The kind of relationship
+        #[prost(message, repeated, tag = "4")]
+        pub relationship: ::prost::alloc::vec::Vec<super::CodeableConcept>,
+        /// A name associated with the contact person
+        #[prost(message, optional, tag = "5")]
+        pub name: ::core::option::Option<super::HumanName>,
+        /// A contact detail for the person
+        #[prost(message, repeated, tag = "6")]
+        pub telecom: ::prost::alloc::vec::Vec<super::ContactPoint>,
+        /// Address for the contact person
+        #[prost(message, optional, tag = "7")]
+        pub address: ::core::option::Option<super::Address>,
+        #[prost(message, optional, tag = "8")]
+        pub gender: ::core::option::Option<contact::GenderCode>,
+        /// Organization that is associated with the contact
+        #[prost(message, optional, tag = "9")]
+        pub organization: ::core::option::Option<super::Reference>,
+        /// The period during which this contact person or organization is valid to
+        /// be contacted relating to this patient
+        #[prost(message, optional, tag = "10")]
+        pub period: ::core::option::Option<super::Period>,
+    }
+    /// Nested message and enum types in `Contact`.
+    pub mod contact {
+        /// male | female | other | unknown
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct GenderCode {
+            #[prost(
+                enumeration = "super::super::administrative_gender_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+    /// A language which may be used to communicate with the patient about his or
+    /// her health
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Communication {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The language which can be used to communicate with the patient about his
+        /// or her health
+        #[prost(message, optional, tag = "4")]
+        pub language: ::core::option::Option<super::CodeableConcept>,
+        /// Language preference indicator
+        #[prost(message, optional, tag = "5")]
+        pub preferred: ::core::option::Option<super::Boolean>,
+    }
+    /// Link to a Patient or RelatedPerson resource that concerns the same actual
+    /// individual
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Link {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The other patient or related person resource that the link refers to
+        #[prost(message, optional, tag = "4")]
+        pub other: ::core::option::Option<super::Reference>,
+        #[prost(message, optional, tag = "5")]
+        pub r#type: ::core::option::Option<link::TypeCode>,
+    }
+    /// Nested message and enum types in `Link`.
+    pub mod link {
+        /// replaced-by | replaces | refer | seealso
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct TypeCode {
+            #[prost(enumeration = "super::super::link_type_code::Value", tag = "1")]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Condition.
+/// Detailed information about conditions, problems or diagnoses.
+/// See <http://hl7.org/fhir/StructureDefinition/Condition>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Condition {
+    /// Logical id of this artifact
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// Metadata about the resource
+    #[prost(message, optional, tag = "2")]
+    pub meta: ::core::option::Option<Meta>,
+    /// A set of rules under which this content was created
+    #[prost(message, optional, tag = "3")]
+    pub implicit_rules: ::core::option::Option<Uri>,
+    /// Language of the resource content
+    #[prost(message, optional, tag = "4")]
+    pub language: ::core::option::Option<Code>,
+    /// Text summary of the resource, for human interpretation
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<Narrative>,
+    /// Contained, inline Resources
+    #[prost(message, repeated, tag = "6")]
+    pub contained: ::prost::alloc::vec::Vec<::prost_types::Any>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "8")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored
+    #[prost(message, repeated, tag = "9")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// External Ids for this condition
+    #[prost(message, repeated, tag = "10")]
+    pub identifier: ::prost::alloc::vec::Vec<Identifier>,
+    /// active | recurrence | relapse | inactive | remission | resolved | unknown
+    #[prost(message, optional, tag = "11")]
+    pub clinical_status: ::core::option::Option<CodeableConcept>,
+    /// unconfirmed | provisional | differential | confirmed | refuted |
+    /// entered-in-error
+    #[prost(message, optional, tag = "12")]
+    pub verification_status: ::core::option::Option<CodeableConcept>,
+    /// problem-list-item | encounter-diagnosis
+    #[prost(message, repeated, tag = "13")]
+    pub category: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Subjective severity of condition
+    #[prost(message, optional, tag = "14")]
+    pub severity: ::core::option::Option<CodeableConcept>,
+    /// Identification of the condition, problem or diagnosis
+    #[prost(message, optional, tag = "15")]
+    pub code: ::core::option::Option<CodeableConcept>,
+    /// Anatomical location, if relevant
+    #[prost(message, repeated, tag = "16")]
+    pub body_site: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Who has the condition?
+    #[prost(message, optional, tag = "17")]
+    pub subject: ::core::option::Option<Reference>,
+    /// The Encounter during which this Condition was created
+    #[prost(message, optional, tag = "18")]
+    pub encounter: ::core::option::Option<Reference>,
+    #[prost(message, optional, tag = "19")]
+    pub onset: ::core::option::Option<condition::OnsetX>,
+    #[prost(message, optional, tag = "20")]
+    pub abatement: ::core::option::Option<condition::AbatementX>,
+    /// Date condition was first recorded
+    #[prost(message, optional, tag = "21")]
+    pub recorded_date: ::core::option::Option<DateTime>,
+    #[prost(message, repeated, tag = "27")]
+    pub participant: ::prost::alloc::vec::Vec<condition::Participant>,
+    #[prost(message, repeated, tag = "24")]
+    pub stage: ::prost::alloc::vec::Vec<condition::Stage>,
+    /// Supporting evidence for the verification status
+    #[prost(message, repeated, tag = "28")]
+    pub evidence: ::prost::alloc::vec::Vec<CodeableReference>,
+    /// Additional information about the Condition
+    #[prost(message, repeated, tag = "26")]
+    pub note: ::prost::alloc::vec::Vec<Annotation>,
+}
+/// Nested message and enum types in `Condition`.
+pub mod condition {
+    /// Estimated or actual date,  date-time, or age
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct OnsetX {
+        #[prost(oneof = "onset_x::Choice", tags = "1, 2, 3, 4, 5")]
+        pub choice: ::core::option::Option<onset_x::Choice>,
+    }
+    /// Nested message and enum types in `OnsetX`.
+    pub mod onset_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "2")]
+            Age(super::super::Age),
+            #[prost(message, tag = "3")]
+            Period(super::super::Period),
+            #[prost(message, tag = "4")]
+            Range(super::super::Range),
+            #[prost(message, tag = "5")]
+            StringValue(super::super::String),
+        }
+    }
+    /// When in resolution/remission
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AbatementX {
+        #[prost(oneof = "abatement_x::Choice", tags = "1, 2, 3, 4, 5")]
+        pub choice: ::core::option::Option<abatement_x::Choice>,
+    }
+    /// Nested message and enum types in `AbatementX`.
+    pub mod abatement_x {
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Choice {
+            #[prost(message, tag = "1")]
+            DateTime(super::super::DateTime),
+            #[prost(message, tag = "2")]
+            Age(super::super::Age),
+            #[prost(message, tag = "3")]
+            Period(super::super::Period),
+            #[prost(message, tag = "4")]
+            Range(super::super::Range),
+            #[prost(message, tag = "5")]
+            StringValue(super::super::String),
+        }
+    }
+    /// Who or what participated in the activities related to the condition and how
+    /// they were involved
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Participant {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Type of involvement
+        #[prost(message, optional, tag = "4")]
+        pub function: ::core::option::Option<super::CodeableConcept>,
+        /// Who or what participated in the activities related to the condition
+        #[prost(message, optional, tag = "5")]
+        pub actor: ::core::option::Option<super::Reference>,
+    }
+    /// Stage/grade, usually assessed formally
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Stage {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Simple summary (disease specific)
+        #[prost(message, optional, tag = "4")]
+        pub summary: ::core::option::Option<super::CodeableConcept>,
+        /// Formal record of assessment
+        #[prost(message, repeated, tag = "5")]
+        pub assessment: ::prost::alloc::vec::Vec<super::Reference>,
+        /// Kind of staging
+        #[prost(message, optional, tag = "6")]
+        pub r#type: ::core::option::Option<super::CodeableConcept>,
+    }
+}
+/// Auto-generated from StructureDefinition for Encounter.
+/// An interaction during which services are provided to the patient.
+/// See <http://hl7.org/fhir/StructureDefinition/Encounter>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Encounter {
+    /// Logical id of this artifact
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// Metadata about the resource
+    #[prost(message, optional, tag = "2")]
+    pub meta: ::core::option::Option<Meta>,
+    /// A set of rules under which this content was created
+    #[prost(message, optional, tag = "3")]
+    pub implicit_rules: ::core::option::Option<Uri>,
+    /// Language of the resource content
+    #[prost(message, optional, tag = "4")]
+    pub language: ::core::option::Option<Code>,
+    /// Text summary of the resource, for human interpretation
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<Narrative>,
+    /// Contained, inline Resources
+    #[prost(message, repeated, tag = "6")]
+    pub contained: ::prost::alloc::vec::Vec<::prost_types::Any>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "8")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored
+    #[prost(message, repeated, tag = "9")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Identifier(s) by which this encounter is known
+    #[prost(message, repeated, tag = "10")]
+    pub identifier: ::prost::alloc::vec::Vec<Identifier>,
+    #[prost(message, optional, tag = "11")]
+    pub status: ::core::option::Option<encounter::StatusCode>,
+    /// Classification of patient encounter context - e.g. Inpatient, outpatient
+    #[prost(message, repeated, tag = "33")]
+    pub class_value: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Indicates the urgency of the encounter
+    #[prost(message, optional, tag = "17")]
+    pub priority: ::core::option::Option<CodeableConcept>,
+    /// Specific type of encounter (e.g. e-mail consultation, surgical day-care,
+    /// ...)
+    #[prost(message, repeated, tag = "15")]
+    pub r#type: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Specific type of service
+    #[prost(message, repeated, tag = "34")]
+    pub service_type: ::prost::alloc::vec::Vec<CodeableReference>,
+    /// The patient or group related to this encounter
+    #[prost(message, optional, tag = "18")]
+    pub subject: ::core::option::Option<Reference>,
+    /// The current status of the subject in relation to the Encounter
+    #[prost(message, optional, tag = "35")]
+    pub subject_status: ::core::option::Option<CodeableConcept>,
+    /// Episode(s) of care that this encounter should be recorded against
+    #[prost(message, repeated, tag = "19")]
+    pub episode_of_care: ::prost::alloc::vec::Vec<Reference>,
+    /// The request that initiated this encounter
+    #[prost(message, repeated, tag = "20")]
+    pub based_on: ::prost::alloc::vec::Vec<Reference>,
+    /// The group(s) that are allocated to participate in this encounter
+    #[prost(message, repeated, tag = "36")]
+    pub care_team: ::prost::alloc::vec::Vec<Reference>,
+    /// Another Encounter this encounter is part of
+    #[prost(message, optional, tag = "32")]
+    pub part_of: ::core::option::Option<Reference>,
+    /// The organization (facility) responsible for this encounter
+    #[prost(message, optional, tag = "31")]
+    pub service_provider: ::core::option::Option<Reference>,
+    #[prost(message, repeated, tag = "21")]
+    pub participant: ::prost::alloc::vec::Vec<encounter::Participant>,
+    /// The appointment that scheduled this encounter
+    #[prost(message, repeated, tag = "22")]
+    pub appointment: ::prost::alloc::vec::Vec<Reference>,
+    /// Connection details of a virtual service (e.g. conference call)
+    #[prost(message, repeated, tag = "37")]
+    pub virtual_service: ::prost::alloc::vec::Vec<VirtualServiceDetail>,
+    /// The actual start and end time of the encounter
+    #[prost(message, optional, tag = "38")]
+    pub actual_period: ::core::option::Option<Period>,
+    /// The planned start date/time (or admission date) of the encounter
+    #[prost(message, optional, tag = "39")]
+    pub planned_start_date: ::core::option::Option<DateTime>,
+    /// The planned end date/time (or discharge date) of the encounter
+    #[prost(message, optional, tag = "40")]
+    pub planned_end_date: ::core::option::Option<DateTime>,
+    /// Actual quantity of time the encounter lasted (less time absent)
+    #[prost(message, optional, tag = "24")]
+    pub length: ::core::option::Option<Duration>,
+    #[prost(message, repeated, tag = "41")]
+    pub reason: ::prost::alloc::vec::Vec<encounter::Reason>,
+    #[prost(message, repeated, tag = "27")]
+    pub diagnosis: ::prost::alloc::vec::Vec<encounter::Diagnosis>,
+    /// The set of accounts that may be used for billing for this Encounter
+    #[prost(message, repeated, tag = "28")]
+    pub account: ::prost::alloc::vec::Vec<Reference>,
+    /// Diet preferences reported by the patient
+    #[prost(message, repeated, tag = "42")]
+    pub diet_preference: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Wheelchair, translator, stretcher, etc
+    #[prost(message, repeated, tag = "43")]
+    pub special_arrangement: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// Special courtesies (VIP, board member)
+    #[prost(message, repeated, tag = "44")]
+    pub special_courtesy: ::prost::alloc::vec::Vec<CodeableConcept>,
+    #[prost(message, optional, tag = "45")]
+    pub admission: ::core::option::Option<encounter::Admission>,
+    #[prost(message, repeated, tag = "30")]
+    pub location: ::prost::alloc::vec::Vec<encounter::Location>,
+}
+/// Nested message and enum types in `Encounter`.
+pub mod encounter {
+    /// planned | in-progress | on-hold | discharged | completed | cancelled |
+    /// discontinued | entered-in-error | unknown
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StatusCode {
+        #[prost(enumeration = "super::encounter_status_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// List of participants involved in the encounter
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Participant {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Role of participant in encounter
+        #[prost(message, repeated, tag = "4")]
+        pub r#type: ::prost::alloc::vec::Vec<super::CodeableConcept>,
+        /// Period of time during the encounter that the participant participated
+        #[prost(message, optional, tag = "5")]
+        pub period: ::core::option::Option<super::Period>,
+        /// The individual, device, or service participating in the encounter
+        #[prost(message, optional, tag = "7")]
+        pub actor: ::core::option::Option<super::Reference>,
+    }
+    /// The list of medical reasons that are expected to be addressed during the
+    /// episode of care
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Reason {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// What the reason value should be used for/as
+        #[prost(message, repeated, tag = "4")]
+        pub r#use: ::prost::alloc::vec::Vec<super::CodeableConcept>,
+        /// Reason the encounter takes place (core or reference)
+        #[prost(message, repeated, tag = "5")]
+        pub value: ::prost::alloc::vec::Vec<super::CodeableReference>,
+    }
+    /// The list of diagnosis relevant to this encounter
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Diagnosis {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The diagnosis relevant to the encounter
+        #[prost(message, repeated, tag = "7")]
+        pub condition: ::prost::alloc::vec::Vec<super::CodeableReference>,
+        /// Role that this diagnosis has within the encounter (e.g. admission,
+        /// billing, discharge …)
+        #[prost(message, repeated, tag = "8")]
+        pub r#use: ::prost::alloc::vec::Vec<super::CodeableConcept>,
+    }
+    /// Details about the admission to a healthcare service
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Admission {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Pre-admission identifier
+        #[prost(message, optional, tag = "4")]
+        pub pre_admission_identifier: ::core::option::Option<super::Identifier>,
+        /// The location/organization from which the patient came before admission
+        #[prost(message, optional, tag = "5")]
+        pub origin: ::core::option::Option<super::Reference>,
+        /// From where patient was admitted (physician referral, transfer)
+        #[prost(message, optional, tag = "6")]
+        pub admit_source: ::core::option::Option<super::CodeableConcept>,
+        /// Indicates that the patient is being re-admitted
+        #[prost(message, optional, tag = "7")]
+        pub re_admission: ::core::option::Option<super::CodeableConcept>,
+        /// Location/organization to which the patient is discharged
+        #[prost(message, optional, tag = "8")]
+        pub destination: ::core::option::Option<super::Reference>,
+        /// Category or kind of location after discharge
+        #[prost(message, optional, tag = "9")]
+        pub discharge_disposition: ::core::option::Option<super::CodeableConcept>,
+    }
+    /// List of locations where the patient has been
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Location {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Location the encounter takes place
+        #[prost(message, optional, tag = "4")]
+        pub location: ::core::option::Option<super::Reference>,
+        #[prost(message, optional, tag = "5")]
+        pub status: ::core::option::Option<location::StatusCode>,
+        /// The physical type of the location (usually the level in the location
+        /// hierarchy - bed, room, ward, virtual etc.)
+        #[prost(message, optional, tag = "8")]
+        pub form: ::core::option::Option<super::CodeableConcept>,
+        /// Time period during which the patient was present at the location
+        #[prost(message, optional, tag = "7")]
+        pub period: ::core::option::Option<super::Period>,
+    }
+    /// Nested message and enum types in `Location`.
+    pub mod location {
+        /// planned | active | reserved | completed
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StatusCode {
+            #[prost(
+                enumeration = "super::super::encounter_location_status_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+}
+/// Auto-generated from StructureDefinition for Appointment.
+/// A booking of a healthcare event among patient(s), practitioner(s), related
+/// person(s) and/or device(s) for a specific date/time. This may result in one
+/// or more Encounter(s). See <http://hl7.org/fhir/StructureDefinition/Appointment>
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Appointment {
+    /// Logical id of this artifact
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<Id>,
+    /// Metadata about the resource
+    #[prost(message, optional, tag = "2")]
+    pub meta: ::core::option::Option<Meta>,
+    /// A set of rules under which this content was created
+    #[prost(message, optional, tag = "3")]
+    pub implicit_rules: ::core::option::Option<Uri>,
+    /// Language of the resource content
+    #[prost(message, optional, tag = "4")]
+    pub language: ::core::option::Option<Code>,
+    /// Text summary of the resource, for human interpretation
+    #[prost(message, optional, tag = "5")]
+    pub text: ::core::option::Option<Narrative>,
+    /// Contained, inline Resources
+    #[prost(message, repeated, tag = "6")]
+    pub contained: ::prost::alloc::vec::Vec<::prost_types::Any>,
+    /// Additional content defined by implementations
+    #[prost(message, repeated, tag = "8")]
+    pub extension: ::prost::alloc::vec::Vec<Extension>,
+    /// Extensions that cannot be ignored
+    #[prost(message, repeated, tag = "9")]
+    pub modifier_extension: ::prost::alloc::vec::Vec<Extension>,
+    /// External Ids for this item
+    #[prost(message, repeated, tag = "10")]
+    pub identifier: ::prost::alloc::vec::Vec<Identifier>,
+    #[prost(message, optional, tag = "11")]
+    pub status: ::core::option::Option<appointment::StatusCode>,
+    /// The coded reason for the appointment being cancelled
+    #[prost(message, optional, tag = "32")]
+    pub cancellation_reason: ::core::option::Option<CodeableConcept>,
+    /// Classification when becoming an encounter
+    #[prost(message, repeated, tag = "33")]
+    pub class_value: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// A broad categorization of the service that is to be performed during this
+    /// appointment
+    #[prost(message, repeated, tag = "13")]
+    pub service_category: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// The specific service that is to be performed during this appointment
+    #[prost(message, repeated, tag = "34")]
+    pub service_type: ::prost::alloc::vec::Vec<CodeableReference>,
+    /// The specialty of a practitioner that would be required to perform the
+    /// service requested in this appointment
+    #[prost(message, repeated, tag = "15")]
+    pub specialty: ::prost::alloc::vec::Vec<CodeableConcept>,
+    /// The style of appointment or patient that has been booked in the slot (not
+    /// service type)
+    #[prost(message, optional, tag = "16")]
+    pub appointment_type: ::core::option::Option<CodeableConcept>,
+    /// Reason this appointment is scheduled
+    #[prost(message, repeated, tag = "35")]
+    pub reason: ::prost::alloc::vec::Vec<CodeableReference>,
+    /// Used to make informed decisions if needing to re-prioritize
+    #[prost(message, optional, tag = "36")]
+    pub priority: ::core::option::Option<CodeableConcept>,
+    /// Shown on a subject line in a meeting request, or appointment list
+    #[prost(message, optional, tag = "20")]
+    pub description: ::core::option::Option<String>,
+    /// Appointment replaced by this Appointment
+    #[prost(message, repeated, tag = "37")]
+    pub replaces: ::prost::alloc::vec::Vec<Reference>,
+    /// Connection details of a virtual service (e.g. conference call)
+    #[prost(message, repeated, tag = "38")]
+    pub virtual_service: ::prost::alloc::vec::Vec<VirtualServiceDetail>,
+    /// Additional information to support the appointment
+    #[prost(message, repeated, tag = "21")]
+    pub supporting_information: ::prost::alloc::vec::Vec<Reference>,
+    /// The previous appointment in a series
+    #[prost(message, optional, tag = "39")]
+    pub previous_appointment: ::core::option::Option<Reference>,
+    /// The originating appointment in a recurring set of appointments
+    #[prost(message, optional, tag = "40")]
+    pub originating_appointment: ::core::option::Option<Reference>,
+    /// When appointment is to take place
+    #[prost(message, optional, tag = "22")]
+    pub start: ::core::option::Option<Instant>,
+    /// When appointment is to conclude
+    #[prost(message, optional, tag = "23")]
+    pub end: ::core::option::Option<Instant>,
+    /// Can be less than start/end (e.g. estimate)
+    #[prost(message, optional, tag = "24")]
+    pub minutes_duration: ::core::option::Option<PositiveInt>,
+    /// Potential date/time interval(s) requested to allocate the appointment
+    /// within
+    #[prost(message, repeated, tag = "31")]
+    pub requested_period: ::prost::alloc::vec::Vec<Period>,
+    /// The slots that this appointment is filling
+    #[prost(message, repeated, tag = "25")]
+    pub slot: ::prost::alloc::vec::Vec<Reference>,
+    /// The set of accounts that may be used for billing for this Appointment
+    #[prost(message, repeated, tag = "41")]
+    pub account: ::prost::alloc::vec::Vec<Reference>,
+    /// The date that this appointment was initially created
+    #[prost(message, optional, tag = "26")]
+    pub created: ::core::option::Option<DateTime>,
+    /// When the appointment was cancelled
+    #[prost(message, optional, tag = "42")]
+    pub cancellation_date: ::core::option::Option<DateTime>,
+    /// Additional comments
+    #[prost(message, repeated, tag = "43")]
+    pub note: ::prost::alloc::vec::Vec<Annotation>,
+    /// Detailed information and instructions for the patient
+    #[prost(message, repeated, tag = "44")]
+    pub patient_instruction: ::prost::alloc::vec::Vec<CodeableReference>,
+    /// The request this appointment is allocated to assess
+    #[prost(message, repeated, tag = "29")]
+    pub based_on: ::prost::alloc::vec::Vec<Reference>,
+    /// The patient or group associated with the appointment
+    #[prost(message, optional, tag = "45")]
+    pub subject: ::core::option::Option<Reference>,
+    #[prost(message, repeated, tag = "30")]
+    pub participant: ::prost::alloc::vec::Vec<appointment::Participant>,
+    /// The sequence number in the recurrence
+    #[prost(message, optional, tag = "46")]
+    pub recurrence_id: ::core::option::Option<PositiveInt>,
+    /// Indicates that this appointment varies from a recurrence pattern
+    #[prost(message, optional, tag = "47")]
+    pub occurrence_changed: ::core::option::Option<Boolean>,
+    #[prost(message, repeated, tag = "48")]
+    pub recurrence_template: ::prost::alloc::vec::Vec<appointment::RecurrenceTemplate>,
+}
+/// Nested message and enum types in `Appointment`.
+pub mod appointment {
+    /// proposed | pending | booked | arrived | fulfilled | cancelled | noshow |
+    /// entered-in-error | checked-in | waitlist
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StatusCode {
+        #[prost(enumeration = "super::appointment_status_code::Value", tag = "1")]
+        pub value: i32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::String>,
+        #[prost(message, repeated, tag = "3")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+    }
+    /// Participants involved in appointment
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Participant {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Role of participant in the appointment
+        #[prost(message, repeated, tag = "4")]
+        pub r#type: ::prost::alloc::vec::Vec<super::CodeableConcept>,
+        /// Participation period of the actor
+        #[prost(message, optional, tag = "8")]
+        pub period: ::core::option::Option<super::Period>,
+        /// The individual, device, location, or service participating in the
+        /// appointment
+        #[prost(message, optional, tag = "5")]
+        pub actor: ::core::option::Option<super::Reference>,
+        /// The participant is required to attend (optional when false)
+        #[prost(message, optional, tag = "9")]
+        pub required: ::core::option::Option<super::Boolean>,
+        #[prost(message, optional, tag = "7")]
+        pub status: ::core::option::Option<participant::StatusCode>,
+    }
+    /// Nested message and enum types in `Participant`.
+    pub mod participant {
+        /// accepted | declined | tentative | needs-action
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StatusCode {
+            #[prost(
+                enumeration = "super::super::participation_status_code::Value",
+                tag = "1"
+            )]
+            pub value: i32,
+            #[prost(message, optional, tag = "2")]
+            pub id: ::core::option::Option<super::super::String>,
+            #[prost(message, repeated, tag = "3")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+        }
+    }
+    /// Details of the recurrence pattern/template used to generate occurrences
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RecurrenceTemplate {
+        /// Unique id for inter-element referencing
+        #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<super::String>,
+        /// Additional content defined by implementations
+        #[prost(message, repeated, tag = "2")]
+        pub extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// Extensions that cannot be ignored even if unrecognized
+        #[prost(message, repeated, tag = "3")]
+        pub modifier_extension: ::prost::alloc::vec::Vec<super::Extension>,
+        /// The timezone of the occurrences
+        #[prost(message, optional, tag = "4")]
+        pub timezone: ::core::option::Option<super::CodeableConcept>,
+        /// The frequency of the recurrence
+        #[prost(message, optional, tag = "5")]
+        pub recurrence_type: ::core::option::Option<super::CodeableConcept>,
+        /// The date when the recurrence should end
+        #[prost(message, optional, tag = "6")]
+        pub last_occurrence_date: ::core::option::Option<super::Date>,
+        /// The number of planned occurrences
+        #[prost(message, optional, tag = "7")]
+        pub occurrence_count: ::core::option::Option<super::PositiveInt>,
+        /// Specific dates for a recurring set of appointments (no template)
+        #[prost(message, repeated, tag = "8")]
+        pub occurrence_date: ::prost::alloc::vec::Vec<super::Date>,
+        #[prost(message, optional, tag = "9")]
+        pub weekly_template: ::core::option::Option<recurrence_template::WeeklyTemplate>,
+        #[prost(message, optional, tag = "10")]
+        pub monthly_template: ::core::option::Option<
+            recurrence_template::MonthlyTemplate,
+        >,
+        #[prost(message, optional, tag = "11")]
+        pub yearly_template: ::core::option::Option<recurrence_template::YearlyTemplate>,
+        /// Any dates that should be excluded from the series
+        #[prost(message, repeated, tag = "12")]
+        pub excluding_date: ::prost::alloc::vec::Vec<super::Date>,
+        /// Any recurrence IDs that should be excluded from the recurrence
+        #[prost(message, repeated, tag = "13")]
+        pub excluding_recurrence_id: ::prost::alloc::vec::Vec<super::PositiveInt>,
+    }
+    /// Nested message and enum types in `RecurrenceTemplate`.
+    pub mod recurrence_template {
+        /// Information about weekly recurring appointments
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct WeeklyTemplate {
+            /// Unique id for inter-element referencing
+            #[prost(message, optional, tag = "1")]
+            pub id: ::core::option::Option<super::super::String>,
+            /// Additional content defined by implementations
+            #[prost(message, repeated, tag = "2")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// Extensions that cannot be ignored even if unrecognized
+            #[prost(message, repeated, tag = "3")]
+            pub modifier_extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// Recurs on Mondays
+            #[prost(message, optional, tag = "4")]
+            pub monday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs on Tuesday
+            #[prost(message, optional, tag = "5")]
+            pub tuesday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs on Wednesday
+            #[prost(message, optional, tag = "6")]
+            pub wednesday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs on Thursday
+            #[prost(message, optional, tag = "7")]
+            pub thursday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs on Friday
+            #[prost(message, optional, tag = "8")]
+            pub friday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs on Saturday
+            #[prost(message, optional, tag = "9")]
+            pub saturday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs on Sunday
+            #[prost(message, optional, tag = "10")]
+            pub sunday: ::core::option::Option<super::super::Boolean>,
+            /// Recurs every nth week
+            #[prost(message, optional, tag = "11")]
+            pub week_interval: ::core::option::Option<super::super::PositiveInt>,
+        }
+        /// Information about monthly recurring appointments
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct MonthlyTemplate {
+            /// Unique id for inter-element referencing
+            #[prost(message, optional, tag = "1")]
+            pub id: ::core::option::Option<super::super::String>,
+            /// Additional content defined by implementations
+            #[prost(message, repeated, tag = "2")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// Extensions that cannot be ignored even if unrecognized
+            #[prost(message, repeated, tag = "3")]
+            pub modifier_extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// Recurs on a specific day of the month
+            #[prost(message, optional, tag = "4")]
+            pub day_of_month: ::core::option::Option<super::super::PositiveInt>,
+            /// Indicates which week of the month the appointment should occur
+            #[prost(message, optional, tag = "5")]
+            pub nth_week_of_month: ::core::option::Option<super::super::Coding>,
+            /// Indicates which day of the week the appointment should occur
+            #[prost(message, optional, tag = "6")]
+            pub day_of_week: ::core::option::Option<super::super::Coding>,
+            /// Recurs every nth month
+            #[prost(message, optional, tag = "7")]
+            pub month_interval: ::core::option::Option<super::super::PositiveInt>,
+        }
+        /// Information about yearly recurring appointments
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct YearlyTemplate {
+            /// Unique id for inter-element referencing
+            #[prost(message, optional, tag = "1")]
+            pub id: ::core::option::Option<super::super::String>,
+            /// Additional content defined by implementations
+            #[prost(message, repeated, tag = "2")]
+            pub extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// Extensions that cannot be ignored even if unrecognized
+            #[prost(message, repeated, tag = "3")]
+            pub modifier_extension: ::prost::alloc::vec::Vec<super::super::Extension>,
+            /// Recurs every nth year
+            #[prost(message, optional, tag = "4")]
+            pub year_interval: ::core::option::Option<super::super::PositiveInt>,
+        }
+    }
+}
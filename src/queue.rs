@@ -0,0 +1,762 @@
+//! Durable event queue between the sources and the sink
+//! (`sync.durable_queue`).
+//!
+//! Sources append each `SyncEvent` to JSONL segment files under
+//! `{sync.state_dir}/queue` and only return once it is on disk, so the binlog
+//! checkpoint never moves past a row that could still be lost, and a HAPI
+//! outage fills the disk instead of stalling the binlog reader. The sink
+//! reads from the last acknowledged offset and acknowledges each event once
+//! it has been synced, skipped or dead-lettered; a restart replays whatever
+//! came after that offset. Segments wholly before it are deleted.
+//!
+//! All file I/O for appends and acknowledgements runs on one writer thread,
+//! off the async runtime. It group-commits: the appends and acks that queued
+//! up while it was syncing share the next fsync. Reads run on the blocking
+//! pool.
+//!
+//! Unlike the dead-letter file, queued events carry full payloads (PHI), so
+//! the directory is created owner-only.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc as std_mpsc, Arc, Mutex, Weak};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tokio::sync::{oneshot, Notify};
+use tracing::{error, info, warn};
+
+use crate::config::SyncConfig;
+use crate::domain::resource::DomainResource;
+use crate::event::{ColumnOverrideValue, Op, Source, SyncEvent};
+
+/// Events read ahead of the sink.
+const READ_AHEAD: usize = 1024;
+const READ_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+/// Most commands the writer handles per fsync.
+const GROUP_COMMIT_MAX: usize = 1024;
+
+/// Where sources put events: straight onto the sink's channel, or into the
+/// durable queue.
+#[derive(Clone)]
+pub enum EventSender {
+    Channel(Sender<SyncEvent>),
+    Durable(DurableSender),
+}
+
+impl From<Sender<SyncEvent>> for EventSender {
+    fn from(tx: Sender<SyncEvent>) -> Self {
+        EventSender::Channel(tx)
+    }
+}
+
+impl EventSender {
+    /// Fails once the sink has gone away, or when the event couldn't be
+    /// written to the queue; either way the source should stop.
+    pub async fn send(&self, event: SyncEvent) -> Result<()> {
+        match self {
+            EventSender::Channel(tx) => tx
+                .send(event)
+                .await
+                .map_err(|_| anyhow::anyhow!("sink channel closed")),
+            EventSender::Durable(tx) => {
+                let key = event.idempotency_key().to_string();
+                tx.queue.append(event).await.inspect_err(|e| {
+                    error!("queue: failed to append {key}: {e:?}");
+                })
+            }
+        }
+    }
+}
+
+/// A producer handle on the durable queue. Every sender shares one guard, so
+/// only once the last is dropped does the reader drain what's left and close
+/// the sink's channel.
+#[derive(Clone)]
+pub struct DurableSender {
+    queue: Arc<DurableQueue>,
+    _open: Arc<ProducerGuard>,
+}
+
+struct ProducerGuard(Arc<DurableQueue>);
+
+impl Drop for ProducerGuard {
+    fn drop(&mut self) {
+        self.0.closed.store(true, Ordering::SeqCst);
+        self.0.appended.notify_one();
+    }
+}
+
+/// One queued event. The idempotency key and resource type are derived again
+/// by `SyncEvent::new` on the way out. Also how the sink's parking lot stores
+/// events, without an offset.
+#[derive(Serialize)]
+pub(crate) struct RecordRef<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<u64>,
+    source: &'a Source,
+    op: Op,
+    payload: &'a DomainResource,
+    occurred_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    column_values: &'a [ColumnOverrideValue],
+}
+
+impl<'a> RecordRef<'a> {
+    pub(crate) fn new(event: &'a SyncEvent, offset: Option<u64>) -> Self {
+        Self {
+            offset,
+            source: event.source(),
+            op: event.op(),
+            payload: event.payload(),
+            occurred_at: event.occurred_at(),
+            column_values: event.column_values(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Record {
+    #[serde(default)]
+    offset: Option<u64>,
+    source: Source,
+    op: Op,
+    payload: DomainResource,
+    occurred_at: DateTime<Utc>,
+    #[serde(default)]
+    column_values: Vec<ColumnOverrideValue>,
+}
+
+impl Record {
+    pub(crate) fn into_event(self) -> SyncEvent {
+        let event = SyncEvent::new(self.source, self.op, self.payload, self.occurred_at)
+            .with_column_values(self.column_values);
+        match self.offset {
+            Some(offset) => event.with_queue_offset(offset),
+            None => event,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AckFile {
+    offset: u64,
+}
+
+enum Command {
+    Append {
+        event: Box<SyncEvent>,
+        reply: oneshot::Sender<Result<()>>,
+    },
+    Ack(u64),
+    #[cfg(test)]
+    Flush(std_mpsc::Sender<()>),
+}
+
+struct Acks {
+    /// Every offset below this is done.
+    acked: u64,
+    /// Done offsets above `acked`, finished out of order by other workers.
+    done: BTreeSet<u64>,
+}
+
+pub struct DurableQueue {
+    dir: PathBuf,
+    commands: Option<std_mpsc::Sender<Command>>,
+    writer_thread: Option<JoinHandle<()>>,
+    /// The writer's last saved ack, where a new reader starts.
+    acked: Arc<AtomicU64>,
+    appended: Arc<Notify>,
+    /// The guard shared by every live sender. Weak, as the guard holds the
+    /// queue.
+    producers: Mutex<Weak<ProducerGuard>>,
+    closed: AtomicBool,
+}
+
+impl DurableQueue {
+    /// Opens `{state_dir}/queue`, dropping a torn last record left by a
+    /// crash mid-append, and starts the writer thread.
+    pub fn open(cfg: &SyncConfig) -> Result<Arc<Self>> {
+        let dir = Path::new(&cfg.state_dir).join("queue");
+        fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&dir, fs::Permissions::from_mode(0o700)).ok();
+        }
+
+        let mut segments: VecDeque<_> = list_segments(&dir)?.into();
+        let acked = load_ack(&dir)
+            .or_else(|| segments.front().map(|(first, _)| *first))
+            .unwrap_or(0);
+
+        let (file, len, next_offset) = match segments.back() {
+            Some((first, path)) => {
+                let (len, last_offset) = recover_segment(path)?;
+                let file = OpenOptions::new()
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("opening {}", path.display()))?;
+                (file, len, last_offset.map_or(*first, |o| o + 1).max(acked))
+            }
+            None => {
+                let file = create_segment(&dir, acked)?;
+                segments.push_back((acked, segment_path(&dir, acked)));
+                (file, 0, acked)
+            }
+        };
+        info!(
+            "queue: {} events waiting in {}",
+            next_offset - acked.min(next_offset),
+            dir.display()
+        );
+
+        let acked_shared = Arc::new(AtomicU64::new(acked));
+        let appended = Arc::new(Notify::new());
+        let writer = Writer {
+            dir: dir.clone(),
+            segment_bytes: cfg.queue_segment_bytes.max(1),
+            file,
+            len,
+            next_offset,
+            segments,
+            acks: Acks {
+                acked,
+                done: BTreeSet::new(),
+            },
+            acked: acked_shared.clone(),
+            appended: appended.clone(),
+        };
+        let (commands, rx) = std_mpsc::channel();
+        let writer_thread = std::thread::Builder::new()
+            .name("queue-writer".to_string())
+            .spawn(move || writer.run(rx))
+            .context("starting queue writer thread")?;
+
+        Ok(Arc::new(Self {
+            dir,
+            commands: Some(commands),
+            writer_thread: Some(writer_thread),
+            acked: acked_shared,
+            appended,
+            producers: Mutex::new(Weak::new()),
+            closed: AtomicBool::new(false),
+        }))
+    }
+
+    pub fn sender(self: &Arc<Self>) -> EventSender {
+        let mut producers = self.producers.lock().unwrap_or_else(|e| e.into_inner());
+        let guard = producers.upgrade().unwrap_or_else(|| {
+            let guard = Arc::new(ProducerGuard(self.clone()));
+            *producers = Arc::downgrade(&guard);
+            guard
+        });
+        EventSender::Durable(DurableSender {
+            queue: self.clone(),
+            _open: guard,
+        })
+    }
+
+    /// Starts reading from the last acknowledged offset. The returned
+    /// channel closes once every sender is dropped and the queue is drained.
+    pub fn reader(self: &Arc<Self>) -> Receiver<SyncEvent> {
+        let (tx, rx) = mpsc::channel(READ_AHEAD);
+        tokio::spawn(run_reader(self.clone(), tx));
+        rx
+    }
+
+    /// Appends `event` and waits for it to reach the disk.
+    async fn append(&self, event: SyncEvent) -> Result<()> {
+        let (reply, written) = oneshot::channel();
+        self.command(Command::Append { event: Box::new(event), reply })?;
+        written.await.map_err(|_| anyhow::anyhow!("queue writer stopped"))?
+    }
+
+    /// Marks `offset` done. The saved offset only moves past a contiguous run
+    /// of done offsets, so with several sink workers a restart may replay a
+    /// few events that had already synced.
+    pub fn ack(&self, offset: u64) {
+        if let Err(e) = self.command(Command::Ack(offset)) {
+            warn!("queue: failed to acknowledge offset {offset}: {e:?}");
+        }
+    }
+
+    /// Waits until the writer has handled everything sent before.
+    #[cfg(test)]
+    fn flush(&self) {
+        let (done, flushed) = std_mpsc::channel();
+        self.command(Command::Flush(done)).unwrap();
+        flushed.recv().unwrap();
+    }
+
+    fn command(&self, command: Command) -> Result<()> {
+        self.commands
+            .as_ref()
+            .context("queue closed")?
+            .send(command)
+            .map_err(|_| anyhow::anyhow!("queue writer stopped"))
+    }
+}
+
+impl Drop for DurableQueue {
+    /// Lets the writer finish what it was sent, so no ack is lost on shutdown.
+    fn drop(&mut self) {
+        drop(self.commands.take());
+        if let Some(thread) = self.writer_thread.take() {
+            thread.join().ok();
+        }
+    }
+}
+
+/// Owns the newest segment and the ack file, on the writer thread.
+struct Writer {
+    dir: PathBuf,
+    segment_bytes: u64,
+    file: File,
+    len: u64,
+    next_offset: u64,
+    /// `(first offset, path)` of every segment on disk, oldest first.
+    segments: VecDeque<(u64, PathBuf)>,
+    acks: Acks,
+    acked: Arc<AtomicU64>,
+    appended: Arc<Notify>,
+}
+
+impl Writer {
+    fn run(mut self, commands: std_mpsc::Receiver<Command>) {
+        while let Ok(first) = commands.recv() {
+            // Taken up front, so every ack handled below is for an offset
+            // synced by an earlier batch.
+            let batch: Vec<Command> = std::iter::once(first)
+                .chain(commands.try_iter().take(GROUP_COMMIT_MAX - 1))
+                .collect();
+
+            let mut replies = Vec::new();
+            let mut acks = Vec::new();
+            #[cfg(test)]
+            let mut flushes = Vec::new();
+            for command in batch {
+                match command {
+                    Command::Append { event, reply } => replies.push((reply, self.write(&event))),
+                    Command::Ack(offset) => acks.push(offset),
+                    #[cfg(test)]
+                    Command::Flush(done) => flushes.push(done),
+                }
+            }
+
+            if replies.iter().any(|(_, written)| written.is_ok()) {
+                if let Err(e) = self.file.sync_data() {
+                    for (_, written) in replies.iter_mut().filter(|(_, w)| w.is_ok()) {
+                        *written = Err(anyhow::anyhow!("syncing queue segment: {e}"));
+                    }
+                }
+                self.appended.notify_one();
+            }
+            for (reply, written) in replies {
+                let _ = reply.send(written);
+            }
+            self.ack(acks);
+            #[cfg(test)]
+            for done in flushes {
+                let _ = done.send(());
+            }
+        }
+    }
+
+    /// Writes one record; synced with the rest of its batch.
+    fn write(&mut self, event: &SyncEvent) -> Result<()> {
+        let line = serde_json::to_string(&RecordRef::new(event, Some(self.next_offset)))
+            .context("serializing queued event")?;
+
+        let record_len = line.len() as u64 + 1;
+        if self.len > 0 && self.len + record_len > self.segment_bytes {
+            // The reader moves on once a later segment exists.
+            self.file.sync_data().context("syncing queue segment")?;
+            self.file = create_segment(&self.dir, self.next_offset)?;
+            self.segments.push_back((self.next_offset, segment_path(&self.dir, self.next_offset)));
+            self.len = 0;
+        }
+        if let Err(e) = writeln!(self.file, "{line}") {
+            // Don't leave a partial record ahead of the next one.
+            self.file.set_len(self.len).ok();
+            return Err(e).context("appending to queue segment");
+        }
+        self.len += record_len;
+        self.next_offset += 1;
+        Ok(())
+    }
+
+    /// Saves the ack file once for the whole batch, if the watermark moved.
+    fn ack(&mut self, offsets: Vec<u64>) {
+        let before = self.acks.acked;
+        for offset in offsets {
+            if offset >= self.next_offset {
+                warn!("queue: ignoring ack for offset {offset}, which hasn't been appended");
+                continue;
+            }
+            if offset >= self.acks.acked {
+                self.acks.done.insert(offset);
+            }
+        }
+        while let Some(next) = self.acks.done.first().copied().filter(|o| *o == self.acks.acked) {
+            self.acks.done.remove(&next);
+            self.acks.acked += 1;
+        }
+        if self.acks.acked == before {
+            return;
+        }
+
+        self.acked.store(self.acks.acked, Ordering::SeqCst);
+        if let Err(e) = save_ack(&self.dir, self.acks.acked) {
+            warn!("queue: failed to persist acknowledged offset {}: {e:?}", self.acks.acked);
+        }
+        self.remove_acked_segments();
+    }
+
+    /// Deletes every segment whose records are all below the saved ack. The
+    /// newest segment is the one being written and is always kept.
+    fn remove_acked_segments(&mut self) {
+        while self.segments.get(1).is_some_and(|(first, _)| *first <= self.acks.acked) {
+            let Some((_, path)) = self.segments.pop_front() else {
+                break;
+            };
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("queue: failed to remove acknowledged segment {}: {e}", path.display());
+            }
+        }
+    }
+}
+
+/// Read position within one segment.
+struct Cursor {
+    first: u64,
+    path: PathBuf,
+    pos: u64,
+}
+
+async fn run_reader(queue: Arc<DurableQueue>, tx: Sender<SyncEvent>) {
+    let mut next = queue.acked.load(Ordering::SeqCst);
+    let mut cursor: Option<Cursor> = None;
+
+    loop {
+        // Checked before reading, so an append that lands after the read
+        // still has its notification waiting.
+        let closed = queue.closed.load(Ordering::SeqCst);
+        let dir = queue.dir.clone();
+        let (read, returned) = tokio::task::spawn_blocking(move || {
+            let read = read_batch(&dir, &mut cursor, next);
+            (read, cursor)
+        })
+        .await
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()));
+        cursor = returned;
+        let events = match read {
+            Ok(events) => events,
+            Err(e) => {
+                error!("queue: read failed at offset {next}: {e:?}");
+                tokio::time::sleep(READ_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+
+        if events.is_empty() {
+            if closed {
+                info!("queue: producers closed and queue drained");
+                return;
+            }
+            queue.appended.notified().await;
+            continue;
+        }
+        for event in events {
+            next = event.queue_offset().map_or(next, |o| o + 1);
+            if tx.send(event).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Reads the complete records at or after `next`, moving on to the following
+/// segment when the current one is exhausted.
+fn read_batch(dir: &Path, cursor: &mut Option<Cursor>, next: u64) -> Result<Vec<SyncEvent>> {
+    if cursor.is_none() {
+        let segments = list_segments(dir)?;
+        let Some((first, path)) = segments.into_iter().take_while(|(first, _)| *first <= next).last() else {
+            return Ok(Vec::new());
+        };
+        *cursor = Some(Cursor { first, path, pos: 0 });
+    }
+
+    loop {
+        let Some(current) = cursor.as_mut() else {
+            return Ok(Vec::new());
+        };
+        let events = read_segment(current, next)?;
+        if !events.is_empty() {
+            return Ok(events);
+        }
+
+        // Segments only rotate once the previous one is complete.
+        let later = list_segments(dir)?.into_iter().find(|(first, _)| *first > current.first);
+        match later {
+            Some((first, path)) => *cursor = Some(Cursor { first, path, pos: 0 }),
+            None => return Ok(Vec::new()),
+        }
+    }
+}
+
+fn read_segment(cursor: &mut Cursor, next: u64) -> Result<Vec<SyncEvent>> {
+    let mut file = File::open(&cursor.path).with_context(|| format!("opening {}", cursor.path.display()))?;
+    file.seek(SeekFrom::Start(cursor.pos))?;
+    let mut reader = BufReader::new(file);
+
+    let mut events = Vec::new();
+    let mut line = String::new();
+    while events.len() < READ_AHEAD {
+        line.clear();
+        let n = reader.read_line(&mut line)?;
+        // End of the segment, or a record still being written.
+        if n == 0 || !line.ends_with('\n') {
+            break;
+        }
+        cursor.pos += n as u64;
+        match serde_json::from_str::<Record>(&line) {
+            Ok(record) if record.offset.is_some_and(|o| o >= next) => events.push(record.into_event()),
+            Ok(_) => {}
+            Err(e) => error!(
+                "queue: skipping unreadable record in {} at byte {}: {e}",
+                cursor.path.display(),
+                cursor.pos - n as u64
+            ),
+        }
+    }
+    Ok(events)
+}
+
+/// `(first offset, path)` of every segment, oldest first.
+fn list_segments(dir: &Path) -> Result<Vec<(u64, PathBuf)>> {
+    let mut segments = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("listing {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+            continue;
+        }
+        if let Some(first) = path.file_stem().and_then(|s| s.to_str()).and_then(|s| s.parse().ok()) {
+            segments.push((first, path));
+        }
+    }
+    segments.sort();
+    Ok(segments)
+}
+
+fn segment_path(dir: &Path, first: u64) -> PathBuf {
+    dir.join(format!("{first:020}.jsonl"))
+}
+
+fn create_segment(dir: &Path, first: u64) -> Result<File> {
+    let path = segment_path(dir, first);
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(&path).with_context(|| format!("creating {}", path.display()))
+}
+
+/// Truncates a torn last record and returns the segment's length and last
+/// offset.
+fn recover_segment(path: &Path) -> Result<(u64, Option<u64>)> {
+    let contents = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    let mut len = 0u64;
+    let mut last_offset = None;
+    for line in contents.split_inclusive(|b| *b == b'\n') {
+        if !line.ends_with(b"\n") {
+            break;
+        }
+        match serde_json::from_slice::<Record>(line) {
+            Ok(record) => last_offset = record.offset,
+            Err(_) => break,
+        }
+        len += line.len() as u64;
+    }
+
+    if len < contents.len() as u64 {
+        warn!("queue: dropping torn record at the end of {}", path.display());
+        let file = OpenOptions::new().write(true).open(path)?;
+        file.set_len(len)?;
+        file.sync_all().ok();
+    }
+    Ok((len, last_offset))
+}
+
+fn load_ack(dir: &Path) -> Option<u64> {
+    let contents = fs::read_to_string(dir.join("ack.json")).ok()?;
+    serde_json::from_str::<AckFile>(&contents).ok().map(|a| a.offset)
+}
+
+/// Written like the binlog checkpoint: temp file, then rename.
+fn save_ack(dir: &Path, offset: u64) -> Result<()> {
+    let path = dir.join("ack.json");
+    let tmp_path = dir.join("ack.json.tmp");
+    {
+        let mut file = File::create(&tmp_path).with_context(|| format!("creating {}", tmp_path.display()))?;
+        file.write_all(serde_json::to_string(&AckFile { offset })?.as_bytes())?;
+        file.sync_all().ok();
+    }
+    fs::rename(&tmp_path, &path).with_context(|| format!("renaming {}", tmp_path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::appointment::DomainAppointment;
+
+    fn cfg(name: &str) -> SyncConfig {
+        let dir = std::env::temp_dir().join(format!("fhir-sync-queue-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        SyncConfig {
+            state_dir: dir.to_string_lossy().into_owned(),
+            queue_segment_bytes: 1,
+            ..Default::default()
+        }
+    }
+
+    fn event(appointment_no: &str) -> SyncEvent {
+        let appointment: DomainAppointment = serde_json::from_value(serde_json::json!({
+            "appointment_no": appointment_no,
+            "demographic_no": "42",
+        }))
+        .unwrap();
+        SyncEvent::new(
+            Source::OscarBinlog {
+                table: "appointment".to_string(),
+            },
+            Op::Upsert,
+            DomainResource::Appointment(appointment),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn restart_resumes_after_last_ack() {
+        let cfg = cfg("resume");
+        let queue = DurableQueue::open(&cfg).unwrap();
+        let tx = queue.sender();
+        for no in ["1", "2", "3"] {
+            tx.send(event(no)).await.unwrap();
+        }
+        drop(tx);
+
+        let mut rx = queue.reader();
+        let first = rx.recv().await.unwrap();
+        assert_eq!(first.idempotency_key(), "oscar:appointment:Appointment:1");
+        // Out of order: 2 is done, but 1 isn't acknowledged until after.
+        queue.ack(1);
+        queue.flush();
+        assert_eq!(load_ack(&queue.dir), None);
+        queue.ack(0);
+        queue.flush();
+        assert_eq!(load_ack(&queue.dir), Some(2));
+        // One record per segment; the first two are gone.
+        assert_eq!(list_segments(&queue.dir).unwrap().len(), 1);
+        drop(rx);
+        drop(queue);
+
+        let queue = DurableQueue::open(&cfg).unwrap();
+        drop(queue.sender());
+        let mut rx = queue.reader();
+        let replayed = rx.recv().await.unwrap();
+        assert_eq!(replayed.idempotency_key(), "oscar:appointment:Appointment:3");
+        assert_eq!(replayed.queue_offset(), Some(2));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn torn_last_record_is_dropped_on_open() {
+        let cfg = SyncConfig {
+            queue_segment_bytes: 1 << 20,
+            ..cfg("torn")
+        };
+        let queue = DurableQueue::open(&cfg).unwrap();
+        let tx = queue.sender();
+        for no in ["1", "2"] {
+            tx.send(event(no)).await.unwrap();
+        }
+        drop(tx);
+        let dir = queue.dir.clone();
+        drop(queue);
+
+        // A crash mid-append leaves half a record behind.
+        let (_, path) = list_segments(&dir).unwrap().pop().unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"offset":2,"source":{"#).unwrap();
+        drop(file);
+
+        let queue = DurableQueue::open(&cfg).unwrap();
+        let tx = queue.sender();
+        tx.send(event("3")).await.unwrap();
+        drop(tx);
+        let mut rx = queue.reader();
+        let mut read = Vec::new();
+        while let Some(event) = rx.recv().await {
+            read.push((event.queue_offset(), event.idempotency_key().to_string()));
+        }
+        assert_eq!(
+            read,
+            vec![
+                (Some(0), "oscar:appointment:Appointment:1".to_string()),
+                (Some(1), "oscar:appointment:Appointment:2".to_string()),
+                (Some(2), "oscar:appointment:Appointment:3".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn ack_before_append_is_ignored() {
+        let cfg = cfg("early-ack");
+        let queue = DurableQueue::open(&cfg).unwrap();
+        // Nothing is at offset 0 yet; acknowledging it mustn't skip the
+        // event that lands there next.
+        queue.ack(0);
+        queue.flush();
+        assert_eq!(load_ack(&queue.dir), None);
+
+        let tx = queue.sender();
+        tx.send(event("1")).await.unwrap();
+        drop(tx);
+        drop(queue);
+
+        let queue = DurableQueue::open(&cfg).unwrap();
+        drop(queue.sender());
+        let mut rx = queue.reader();
+        let event = rx.recv().await.unwrap();
+        assert_eq!(event.queue_offset(), Some(0));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn queue_stays_open_until_the_last_sender_drops() {
+        let cfg = cfg("senders");
+        let queue = DurableQueue::open(&cfg).unwrap();
+        let first = queue.sender();
+        let second = queue.sender();
+        drop(first);
+
+        let mut rx = queue.reader();
+        second.send(event("1")).await.unwrap();
+        let event = rx.recv().await.unwrap();
+        assert_eq!(event.queue_offset(), Some(0));
+        drop(second);
+        assert!(rx.recv().await.is_none());
+    }
+}
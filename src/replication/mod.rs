@@ -16,7 +16,7 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::{mpsc, Notify};
 use tracing::{error, info, warn};
 
-use crate::config::{Config, ReplicationLink, ReplicationNode};
+use crate::config::{Config, ReplicationNode};
 
 /// Shared echo-suppression state: key = `{node_name}/{resourceType}/{id}`
 /// mapping to the version known to have been written by this fabric.
@@ -11,7 +11,7 @@ use tracing::{info, warn};
 
 use tokio::sync::mpsc;
 
-use crate::config::{Config, ReplicationLink, ReplicationMode, ReplicationNode};
+use crate::config::{Config, ReplicationLink, ReplicationNode};
 use crate::dispatch::DispatchNotification;
 
 use super::conflict;
@@ -204,7 +204,7 @@ async fn poll_one_cycle(
                             .get(&format!("{}/{}", event.resource_type, event.id))
                             .map(String::as_str);
                         if !conflict::check_and_resolve(
-                            &client,
+                            client,
                             link,
                             target_node,
                             token_for_node(&target_node.token_env).as_deref(),
@@ -220,8 +220,8 @@ async fn poll_one_cycle(
                         {
                             continue;
                         }
-                        match replicate_with_retry(
-                            &client,
+                        if let Some(result) = replicate_with_retry(
+                            client,
                             link,
                             source_node,
                             target_node,
@@ -232,16 +232,12 @@ async fn poll_one_cycle(
                             max_attempts,
                             base_ms,
                         )
-                        .await?
-                        {
-                            Some(result) => {
-                                counters.inc_replicated();
-                                let key = format!("{}/{}", event.resource_type, event.id);
-                                cp.last_versionids_seen.insert(key, result.target_version.clone());
-                                let echo_key = format!("{}/{}/{}", target_node.name, event.resource_type, result.target_id);
-                                state.echo.lock().unwrap().insert(echo_key, result.target_version);
-                            }
-                            None => {}
+                        .await? {
+                            counters.inc_replicated();
+                            let key = format!("{}/{}", event.resource_type, event.id);
+                            cp.last_versionids_seen.insert(key, result.target_version.clone());
+                            let echo_key = format!("{}/{}/{}", target_node.name, event.resource_type, result.target_id);
+                            state.echo.lock().unwrap().insert(echo_key, result.target_version);
                         }
                     } else {
                         warn!("replication link {}: upsert entry without resource for {}/{}", link.name, event.resource_type, event.id);
@@ -249,7 +245,7 @@ async fn poll_one_cycle(
                 }
                 HistoryOp::Delete => {
                     match replicate_delete_with_retry(
-                        &client,
+                        client,
                         link,
                         source_node,
                         target_node,
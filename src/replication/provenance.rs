@@ -1,7 +1,6 @@
 //! Provenance stamping for successful upsert replications.
 
 use anyhow::Result;
-use serde_json::Value;
 use tracing::warn;
 
 /// Posts a `Provenance` resource to `target_base` recording the source
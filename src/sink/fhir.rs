@@ -1,42 +1,75 @@
 //! Sink task: consumes `SyncEvent`s and conditionally upserts a FHIR R4B
 //! `Patient` into HAPI (D5). Owns the `rx` end of the channel — there is
-//! exactly one consumer (D4).
+//! exactly one consumer (D4). That consumer only routes events, and replays
+//! of parked ones, to a pool of `sync.workers` workers, partitioned by
+//! `DomainResource::ordering_key` so each patient's events stay in order.
 //!
 //! Failed syncs are retried with exponential backoff
 //! (`cfg.sync.retry_max_attempts` / `retry_base_ms`); on exhaustion the
 //! event is appended to `cfg.sync.dead_letter_path` and the stream keeps
-//! running — one bad record must never take down the process.
+//! running — one bad record must never take down the process. Events whose
+//! conditional references don't resolve yet are parked instead
+//! (`cfg.sync.parking_path`) and replayed once the reference is synced.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::io::Write;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use anyhow::{Context, Result};
 use fhirbolt::model::r4b::resources::{
     Appointment, AppointmentParticipant, Bundle, BundleEntry, BundleEntryRequest, CareTeam,
-    CareTeamParticipant, Patient, PatientDeceased, PatientLink, Practitioner,
+    CareTeamParticipant, Patient, PatientCommunication, PatientDeceased, PatientLink, Practitioner,
 };
 use fhirbolt::model::r4b::Resource as FhirResource;
 use fhirbolt::model::r4b::types::{
-    Address, CodeableConcept, Coding, ContactPoint, Extension, ExtensionValue, HumanName,
-    Identifier, Meta, Reference,
+    Address, Attachment, CodeableConcept, Coding, ContactPoint, Extension, ExtensionValue,
+    HumanName, Identifier, Meta, Period, Reference,
 };
-use chrono::{LocalResult, NaiveDate, NaiveTime, TimeZone};
-use tokio::sync::mpsc::{Receiver, Sender};
-use tracing::{error, info, warn};
+use chrono::{DateTime, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+use tokio::sync::mpsc::{Receiver, Sender, UnboundedSender};
+use tracing::{debug, error, info, warn};
 
 use crate::auth::TokenProvider;
-use crate::config::{Config, FhirConfig, OscarConfig, WritebackConfig};
-use crate::dispatch::DispatchNotification;
+use crate::config::{Config, DeletePolicy, FhirConfig, OscarConfig};
+use crate::dispatch::{DispatchNotification, DispatchOp};
 use crate::domain::appointment::DomainAppointment;
-use crate::domain::care_team::DomainCareTeam;
-use crate::domain::patient::{AddressKind, AddressUse, DomainAddress, DomainPatient};
+use crate::domain::care_team::{CareTeamMember, CareTeamRole, DomainCareTeam, DomainCareTeamParticipant};
+use crate::domain::patient::{DomainAddress, DomainPatient};
 use crate::domain::practitioner::DomainPractitioner;
 use crate::domain::resource::DomainResource;
-use crate::event::{Op, ResourceType, Source, SyncEvent};
-
+use crate::event::{Op, SyncEvent};
+use crate::queue::DurableQueue;
+
+mod batch;
+mod billing;
+mod column_overrides;
+mod content_hash;
+mod communication;
+mod delete;
+mod episode_of_care;
+mod if_match;
+mod location;
+mod organization;
 mod oscar2;
+mod parking;
+mod practitioner_role;
+mod questionnaire;
+mod referral_doctor;
+mod related_person;
+mod schedule;
+mod specialist;
+mod status_maps;
+mod waiting_list;
 use crate::metrics::SharedMetrics;
+use column_overrides::apply_column_overrides;
+use delete::apply_delete_policy;
+use content_hash::{event_content_hash, ContentHashStore};
+use parking::{ParkingLot, UnresolvedReference};
+
+pub use status_maps::sync_status_maps;
 
 pub(crate) const META_SOURCE: &str = "urn:arsmedicatech:fhir-sync:oscar";
 
@@ -46,59 +79,434 @@ pub async fn run(
     mut rx: Receiver<SyncEvent>,
     metrics: SharedMetrics,
     dispatch_tx: Option<Sender<DispatchNotification>>,
+    queue: Option<Arc<DurableQueue>>,
 ) -> Result<()> {
     let client = reqwest::Client::new();
     let token_provider = match cfg.fhir.keycloak.as_ref() {
-        Some(kc) => Some(TokenProvider::new(kc, client.clone())?),
+        Some(kc) => Some(Arc::new(TokenProvider::new(kc, client.clone())?)),
+        None => None,
+    };
+
+    let content_hashes = match cfg.sync.content_hash_path.as_deref() {
+        Some(path) => Some(Arc::new(Mutex::new(ContentHashStore::open(path)?))),
+        None => None,
+    };
+    if cfg.sync.force_resync {
+        info!("fhir sink: force resync; writing every event regardless of content hash");
+    }
+
+    let parking = match cfg.sync.parking_path.as_deref() {
+        Some(path) => {
+            let lot = ParkingLot::open(path)?;
+            if lot.len() > 0 {
+                info!("fhir sink: {} events parked waiting on unresolved references", lot.len());
+            }
+            Some(Arc::new(Mutex::new(lot)))
+        }
         None => None,
     };
+    let sweeper = parking
+        .clone()
+        .map(|p| tokio::spawn(expire_parked(cfg.clone(), p, metrics.clone())));
+
+    let workers = cfg.sync.workers.max(1);
+    let capacity = cfg.sync.worker_queue_capacity.max(1);
+    // Released parked events come back through here, so each replays on the
+    // worker that owns its ordering key.
+    let (replay_tx, mut replay_rx) = tokio::sync::mpsc::unbounded_channel();
+    let worker = Worker {
+        cfg: cfg.clone(),
+        client: client.clone(),
+        token_provider: token_provider.clone(),
+        content_hashes,
+        metrics,
+        dispatch_tx,
+        queue,
+        parking: parking.clone(),
+        replays: replay_tx.clone(),
+    };
+    let mut senders = Vec::with_capacity(workers);
+    let mut handles = Vec::with_capacity(workers);
+    for _ in 0..workers {
+        let (tx, worker_rx) = tokio::sync::mpsc::channel(capacity);
+        senders.push(tx);
+        handles.push(tokio::spawn(worker.clone().run(worker_rx)));
+    }
+    drop(worker);
+    if let Some(parking) = parking {
+        tokio::spawn(replay_resolved(client, cfg, token_provider, parking, replay_tx));
+    } else {
+        drop(replay_tx);
+    }
+    info!("fhir sink: {workers} workers, {capacity} queued events each");
+
+    loop {
+        let event = tokio::select! {
+            biased;
+            Some(event) = replay_rx.recv() => event,
+            received = rx.recv() => match received {
+                Some(event) => event,
+                None => break,
+            },
+        };
+        let worker = partition(&event.payload().ordering_key(), workers);
+        // Waits while that worker's queue is full, which backs up `rx` and
+        // in turn the source.
+        if senders[worker].send(event).await.is_err() {
+            error!("fhir sink: worker {worker} stopped; shutting down the sink");
+            break;
+        }
+    }
+    // Replays still waiting here stay in the parking log, and are replayed
+    // by `replay_resolved` on the next run.
+    drop(replay_rx);
+
+    drop(senders);
+    for handle in handles {
+        if let Err(e) = handle.await {
+            error!("fhir sink: worker panicked: {e:?}");
+        }
+    }
+    if let Some(sweeper) = sweeper {
+        sweeper.abort();
+    }
+    Ok(())
+}
+
+/// Worker index for an ordering key; stable for the life of the process.
+fn partition(ordering_key: &str, workers: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    ordering_key.hash(&mut hasher);
+    (hasher.finish() % workers as u64) as usize
+}
+
+/// What each sink worker shares with the others.
+#[derive(Clone)]
+struct Worker {
+    cfg: Config,
+    client: reqwest::Client,
+    token_provider: Option<Arc<TokenProvider>>,
+    content_hashes: Option<Arc<Mutex<ContentHashStore>>>,
+    metrics: SharedMetrics,
+    dispatch_tx: Option<Sender<DispatchNotification>>,
+    queue: Option<Arc<DurableQueue>>,
+    parking: Option<Arc<Mutex<ParkingLot>>>,
+    /// Back to the dispatcher, for released parked events.
+    replays: UnboundedSender<SyncEvent>,
+}
+
+impl Worker {
+    /// Syncs events from `rx` in arrival order until it closes, acknowledging
+    /// each one on the durable queue once it's done with.
+    async fn run(self, mut rx: Receiver<SyncEvent>) {
+        let cfg = &self.cfg;
+        let metrics = &self.metrics;
+        let token_provider = self.token_provider.as_deref();
+        let batch_max_events = cfg.sync.batch_max_events.max(1);
+        let batch_max_wait = Duration::from_millis(cfg.sync.batch_max_wait_ms);
+
+        while let Some(event) = rx.recv().await {
+            let mut events = vec![event];
+            if batch_max_events > 1 {
+                let deadline = tokio::time::Instant::now() + batch_max_wait;
+                while events.len() < batch_max_events {
+                    match tokio::time::timeout_at(deadline, rx.recv()).await {
+                        Ok(Some(event)) => events.push(event),
+                        // Timed out, or the channel closed; the outer loop sees the close.
+                        _ => break,
+                    }
+                }
+            }
+
+            // A later write of the resource may have superseded a replay
+            // since it was released.
+            if let Some(parking) = &self.parking {
+                let lot = parking.lock().unwrap_or_else(|e| e.into_inner());
+                events.retain(|event| {
+                    let due = lot.is_replaying(event);
+                    if !due {
+                        debug!("fhir sink: dropping replay of {}; superseded by a later write", event.idempotency_key());
+                    }
+                    due
+                });
+            }
+
+            // Builds each resource once more just to hash it; cheap next to a
+            // round-trip to HAPI.
+            let mut hashes = Vec::with_capacity(events.len());
+            if let Some(store) = &self.content_hashes {
+                let mut changed = Vec::with_capacity(events.len());
+                for event in events {
+                    let hash = event_content_hash(cfg, &event);
+                    let unchanged = !cfg.sync.force_resync
+                        && hash.as_ref().is_some_and(|(target, hash)| {
+                            store.lock().unwrap_or_else(|e| e.into_inner()).is_unchanged(target, hash)
+                        });
+                    if unchanged {
+                        debug!("fhir sink: {} unchanged since last write; skipping", event.idempotency_key());
+                        metrics.inc_unchanged();
+                        ack(self.queue.as_deref(), &event);
+                        self.settle(&event, false).await;
+                        continue;
+                    }
+                    changed.push(event);
+                    hashes.push(hash);
+                }
+                events = changed;
+            }
+            if events.is_empty() {
+                continue;
+            }
+
+            let outcomes = if events.len() == 1 {
+                vec![sync_with_retry(&self.client, cfg, token_provider, &events[0], metrics).await]
+            } else {
+                batch::sync_batch(&self.client, cfg, token_provider, &events, metrics).await
+            };
+            for (i, (event, outcome)) in events.iter().zip(outcomes).enumerate() {
+                if outcome.as_ref().is_ok_and(|r| r.applied) {
+                    let hash = hashes.get(i).and_then(Option::as_ref);
+                    record_content_hash(self.content_hashes.as_deref(), event, hash);
+                }
+                let synced = outcome.is_ok();
+                let parking = self.parking.as_ref();
+                let parked_on = finish_event(cfg, event, outcome, metrics, self.dispatch_tx.as_ref(), parking).await;
+                ack(self.queue.as_deref(), event);
+                let mut released = self.settle(event, synced).await;
+                if let Some(reference) = parked_on {
+                    released.extend(recheck_parked(&self.client, cfg, token_provider, parking, reference).await);
+                }
+                self.replay(released);
+            }
+        }
+    }
+
+    /// Done with `event`: removes the parking-lot entry a replay came from
+    /// and, when it synced, drops the parked events for its resource it
+    /// supersedes and takes the ones waiting on it.
+    async fn settle(&self, event: &SyncEvent, synced: bool) -> Vec<SyncEvent> {
+        let Some(parking) = &self.parking else {
+            return Vec::new();
+        };
+        let replayed = event.parked().map(|(reference, at)| (reference.to_string(), at));
+        if !synced && replayed.is_none() {
+            return Vec::new();
+        }
+        let resource = parking::resource_key(event);
+        let before = replayed.as_ref().map_or(DateTime::<Utc>::MAX_UTC, |(_, at)| *at);
+        let reference = parking::resolved_reference(&self.cfg.fhir, event).filter(|_| synced);
+
+        let (superseded, released) = with_parking(parking, move |lot| {
+            if let Some((reference, at)) = replayed {
+                lot.finish(&reference, at);
+            }
+            if !synced {
+                return (0, Vec::new());
+            }
+            let superseded = lot.supersede(&resource, before);
+            (superseded, reference.map(|r| lot.release(&r)).unwrap_or_default())
+        })
+        .await;
+        if superseded > 0 {
+            info!(
+                "fhir sink: {} synced; dropped {superseded} parked events it supersedes",
+                event.idempotency_key()
+            );
+        }
+        if !released.is_empty() {
+            info!(
+                "fhir sink: {} synced; replaying {} parked events",
+                event.idempotency_key(),
+                released.len()
+            );
+        }
+        released
+    }
+
+    /// Hands released events to the dispatcher. Once the sink is shutting
+    /// down they stay in the parking log for the next run.
+    fn replay(&self, events: Vec<SyncEvent>) {
+        for event in events {
+            if self.replays.send(event).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// Records the content hash of a write the server applied.
+fn record_content_hash(
+    content_hashes: Option<&Mutex<ContentHashStore>>,
+    event: &SyncEvent,
+    hash: Option<&(String, String)>,
+) {
+    let (Some(store), Some((target, hash))) = (content_hashes, hash) else {
+        return;
+    };
+    let recorded = store.lock().unwrap_or_else(|e| e.into_inner()).record(target, hash);
+    if let Err(e) = recorded {
+        warn!("fhir sink: failed to record content hash for {}: {e:?}", event.idempotency_key());
+    }
+}
+
+/// Runs `f` against the parking lot on a blocking thread, since its changes
+/// are written to the log as they're made.
+async fn with_parking<T, F>(parking: &Arc<Mutex<ParkingLot>>, f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce(&mut ParkingLot) -> T + Send + 'static,
+{
+    let parking = parking.clone();
+    tokio::task::spawn_blocking(move || f(&mut parking.lock().unwrap_or_else(|e| e.into_inner())))
+        .await
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+}
+
+/// Takes back the events just parked on `reference` if it resolves after
+/// all. With several workers the target can sync on another one between
+/// HAPI's rejection and the park, and that worker's release has then
+/// already run.
+async fn recheck_parked(
+    client: &reqwest::Client,
+    cfg: &Config,
+    token_provider: Option<&TokenProvider>,
+    parking: Option<&Arc<Mutex<ParkingLot>>>,
+    reference: String,
+) -> Vec<SyncEvent> {
+    let (Some(parking), Some((resource_type, identifier))) = (parking, reference.split_once("?identifier=")) else {
+        return Vec::new();
+    };
+    let Ok(token) = request_token(&cfg.fhir, token_provider).await else {
+        return Vec::new();
+    };
+    let base = cfg.fhir.base_url.trim_end_matches('/');
+    match if_match::find_current(client, token.as_deref(), base, resource_type, identifier).await {
+        Ok(Some(_)) => {
+            info!("fhir sink: {reference} resolved while parking; replaying now");
+            with_parking(parking, move |lot| lot.release(&reference)).await
+        }
+        _ => Vec::new(),
+    }
+}
 
-    while let Some(event) = rx.recv().await {
-        let key = event.idempotency_key().to_string();
+/// Replays the events parked on references that resolved before this run,
+/// e.g. released just as the last one shut down.
+async fn replay_resolved(
+    client: reqwest::Client,
+    cfg: Config,
+    token_provider: Option<Arc<TokenProvider>>,
+    parking: Arc<Mutex<ParkingLot>>,
+    replays: UnboundedSender<SyncEvent>,
+) {
+    let references = parking.lock().unwrap_or_else(|e| e.into_inner()).references();
+    for reference in references {
+        let tp = token_provider.as_deref();
+        for event in recheck_parked(&client, &cfg, tp, Some(&parking), reference).await {
+            if replays.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
 
-        match sync_with_retry(&client, &cfg, token_provider.as_ref(), &event, &metrics).await {
-            Ok(result) => {
-                metrics.inc_synced();
-                if let Some(tx) = &dispatch_tx {
-                    if !result.fhir_id.is_empty() {
-                        let n = build_dispatch_notification(&event, &cfg, &result);
+/// Dead-letters events parked for longer than `sync.parking_ttl_secs`.
+async fn expire_parked(cfg: Config, parking: Arc<Mutex<ParkingLot>>, metrics: SharedMetrics) {
+    let ttl = Duration::from_secs(cfg.sync.parking_ttl_secs);
+    let mut interval = tokio::time::interval(ttl.clamp(Duration::from_secs(1), Duration::from_secs(60)));
+    loop {
+        interval.tick().await;
+        let expired = with_parking(&parking, move |lot| lot.expire(ttl)).await;
+        for (reference, event) in expired {
+            let err = anyhow::Error::new(UnresolvedReference { reference })
+                .context(format!("still unresolved after {}s parked", ttl.as_secs()));
+            error!("fhir sink: giving up on parked {}: {err}", event.idempotency_key());
+            metrics.inc_dead_lettered();
+            if let Err(dl_err) = write_dead_letter(&cfg.sync.dead_letter_path, &event, &err) {
+                error!("fhir sink: failed to write dead letter for {}: {dl_err:?}", event.idempotency_key());
+            }
+        }
+    }
+}
+
+/// Synced, skipped, parked or dead-lettered: a restart needn't replay `event`.
+fn ack(queue: Option<&DurableQueue>, event: &SyncEvent) {
+    if let (Some(queue), Some(offset)) = (queue, event.queue_offset()) {
+        queue.ack(offset);
+    }
+}
+
+/// Records one event's final outcome: dispatches a notification on success,
+/// parks it when a reference it needs isn't on the server yet, dead-letters
+/// any other failure. Returns the reference it was parked on.
+async fn finish_event(
+    cfg: &Config,
+    event: &SyncEvent,
+    outcome: Result<FhirResult, SyncFailure>,
+    metrics: &SharedMetrics,
+    dispatch_tx: Option<&Sender<DispatchNotification>>,
+    parking: Option<&Arc<Mutex<ParkingLot>>>,
+) -> Option<String> {
+    let key = event.idempotency_key();
+
+    match outcome {
+        Ok(result) => {
+            metrics.inc_synced();
+            if let Some(tx) = dispatch_tx {
+                match delete::dispatch_op(&cfg.fhir, event) {
+                    None => {}
+                    Some(_) if result.fhir_id.is_empty() => {
+                        warn!("fhir sink: HAPI success but no fhir_id for {key}; not dispatching");
+                    }
+                    Some(op) => {
+                        let n = build_dispatch_notification(event, cfg, &result, op);
                         if tx.try_send(n).is_err() {
                             warn!("fhir sink: dispatch channel full or closed, dropping notification");
                             metrics.inc_dispatch_dropped();
                         }
-                    } else {
-                        warn!("fhir sink: HAPI success but no fhir_id for {key}; not dispatching");
                     }
                 }
             }
-            Err(e) => {
-                let err = match e {
-                    SyncFailure::Retryable(inner) | SyncFailure::Permanent(inner) => inner,
-                };
-                error!("fhir sink: exhausted retries for {key}: {err:?}");
-                metrics.inc_dead_lettered();
-                if let Err(dl_err) = write_dead_letter(&cfg.sync.dead_letter_path, &event, &err) {
-                    // PHI note (spec §8): never let a dead-letter write failure crash the
-                    // stream either — log identifier only and move on.
-                    error!("fhir sink: failed to write dead letter for {key}: {dl_err:?}");
+        }
+        Err(e) => {
+            let err = match e {
+                SyncFailure::Retryable(inner) | SyncFailure::Permanent(inner) => inner,
+            };
+            if let (Some(parking), Some(unresolved)) = (parking, err.downcast_ref::<UnresolvedReference>()) {
+                let reference = unresolved.reference.clone();
+                let (parked_on, parked_event) = (reference.clone(), event.clone());
+                let parked = with_parking(parking, move |lot| lot.park(&parked_on, &parked_event)).await;
+                match parked {
+                    Ok(()) => {
+                        info!("fhir sink: parked {key} until {reference} is synced");
+                        metrics.inc_parked();
+                        return Some(reference);
+                    }
+                    Err(park_err) => error!("fhir sink: failed to park {key}: {park_err:?}"),
                 }
             }
+            error!("fhir sink: exhausted retries for {key}: {err:?}");
+            metrics.inc_dead_lettered();
+            if let Err(dl_err) = write_dead_letter(&cfg.sync.dead_letter_path, event, &err) {
+                // PHI note (spec §8): never let a dead-letter write failure crash the
+                // stream either — log identifier only and move on.
+                error!("fhir sink: failed to write dead letter for {key}: {dl_err:?}");
+            }
         }
     }
-
-    Ok(())
+    None
 }
 
 fn build_dispatch_notification(
     event: &SyncEvent,
     cfg: &Config,
     result: &FhirResult,
+    op: DispatchOp,
 ) -> DispatchNotification {
     DispatchNotification {
         resource_type: event.resource_type().as_path().to_string(),
         fhir_id: result.fhir_id.clone(),
         fhir_version_id: result.version_id.clone(),
-        op: event.op(),
+        op,
         source: event.source().clone(),
         idempotency_key: event.idempotency_key().to_string(),
         occurred_at: event.occurred_at(),
@@ -166,7 +574,7 @@ fn write_dead_letter(path: &str, event: &SyncEvent, err: &anyhow::Error) -> Resu
         .open(path)
         .with_context(|| format!("opening dead letter file {path}"))?;
 
-    let record = serde_json::json!({
+    let mut record = serde_json::json!({
         "idempotency_key": event.idempotency_key(),
         "source": format!("{:?}", event.source()),
         "op": format!("{:?}", event.op()),
@@ -174,6 +582,17 @@ fn write_dead_letter(path: &str, event: &SyncEvent, err: &anyhow::Error) -> Resu
         "source_id": event.payload().source_id(),
         "error": err.to_string(),
     });
+    if let Some(unresolved) = err.downcast_ref::<UnresolvedReference>() {
+        record["unresolved_reference"] = unresolved.reference.clone().into();
+    }
+    if let Some(conflict) = err.downcast_ref::<if_match::WriteConflict>() {
+        record["conflict"] = serde_json::json!({
+            "resource": conflict.resource,
+            "expected_version": conflict.expected_version,
+            "server_version": conflict.server_version,
+            "server_source": conflict.server_source,
+        });
+    }
 
     writeln!(file, "{record}").context("writing dead letter record")?;
     Ok(())
@@ -200,6 +619,10 @@ impl std::fmt::Display for SyncFailure {
 struct FhirResult {
     fhir_id: String,
     version_id: Option<String>,
+    /// False when the server was left as it was rather than given this
+    /// event's resource: an ignored delete, or a write conflict skipped
+    /// under `write_conflict_policy = "skip"`.
+    applied: bool,
 }
 
 fn build_put_request(
@@ -210,7 +633,22 @@ fn build_put_request(
 ) -> reqwest::RequestBuilder {
     let resource_path = event.resource_type().as_path();
     let base = format!("{}/{resource_path}", fhir_cfg.base_url.trim_end_matches('/'));
+    let identifier = conditional_identifier(fhir_cfg, event);
+
+    let mut req = client
+        .put(&base)
+        .query(&[("identifier", identifier.as_str())])
+        .header("Content-Type", "application/fhir+json");
+
+    if let Some(token) = token {
+        req = req.bearer_auth(token);
+    }
+
+    req
+}
 
+/// `{system}|{value}` identifying the resource `event` writes.
+fn conditional_identifier(fhir_cfg: &FhirConfig, event: &SyncEvent) -> String {
     let (identifier_system, source_id) = match event.payload() {
         DomainResource::Patient(p) => (&fhir_cfg.oscar_demographic_system, p.demographic_no.as_str()),
         DomainResource::Practitioner(p) => (&fhir_cfg.oscar_provider_system, p.provider_no.as_str()),
@@ -229,19 +667,42 @@ fn build_put_request(
         DomainResource::FamilyMemberHistory(f) => (&fhir_cfg.oscar_cpp_condition_system, f.note_id.as_str()),
         DomainResource::CareTeam(c) => (&fhir_cfg.oscar_care_team_system, c.demographic_no.as_str()),
         DomainResource::ServiceRequest(r) => (&fhir_cfg.oscar_consult_request_system, r.request_id.as_str()),
+        DomainResource::RelatedPerson(r) => (&fhir_cfg.oscar_demographic_contact_system, r.contact_id.as_str()),
+        DomainResource::Specialist(s) => (&fhir_cfg.oscar_specialist_system, s.spec_id.as_str()),
+        DomainResource::Organization(o) => (organization::identifier_system(o, fhir_cfg), o.source_id.as_str()),
+        DomainResource::Schedule(s) => (&fhir_cfg.oscar_schedule_system, s.provider_no.as_str()),
+        DomainResource::Slot(s) => (&fhir_cfg.oscar_slot_system, s.slot_id.as_str()),
+        DomainResource::EpisodeOfCare(e) => (&fhir_cfg.oscar_admission_system, e.admission_id.as_str()),
+        DomainResource::Location(l) => (&fhir_cfg.oscar_site_system, l.site_id.as_str()),
+        DomainResource::PractitionerRole(r) => (&fhir_cfg.oscar_practitioner_role_system, r.provider_no.as_str()),
+        DomainResource::Questionnaire(q) => (&fhir_cfg.oscar_eform_system, q.fid.as_str()),
+        DomainResource::QuestionnaireResponse(r) => (&fhir_cfg.oscar_eform_data_system, r.fdid.as_str()),
+        DomainResource::Communication(c) => (&fhir_cfg.oscar_message_system, c.message_id.as_str()),
+        DomainResource::Claim(c) => (billing::claim_identifier_system(c.source, fhir_cfg), c.claim_id.as_str()),
+        DomainResource::ChargeItem(c) => {
+            (billing::charge_item_identifier_system(c.source, fhir_cfg), c.item.line_id.as_str())
+        }
+        DomainResource::WaitingListAppointment(e) | DomainResource::WaitingListRequest(e) => {
+            (&fhir_cfg.oscar_waiting_list_system, e.entry_id.as_str())
+        }
+        DomainResource::ReferralDoctor(d) => (&fhir_cfg.oscar_referral_doctor_system, d.billing_no.as_str()),
     };
-    let identifier = format!("{}|{}", identifier_system, source_id);
-
-    let mut req = client
-        .put(&base)
-        .query(&[("identifier", identifier.as_str())])
-        .header("Content-Type", "application/fhir+json");
-
-    if let Some(token) = token {
-        req = req.bearer_auth(token);
-    }
+    format!("{}|{}", identifier_system, source_id)
+}
 
-    req
+/// Bearer token for the next request: from Keycloak when configured,
+/// otherwise from `fhir.token_env`.
+async fn request_token(
+    fhir_cfg: &FhirConfig,
+    token_provider: Option<&TokenProvider>,
+) -> Result<Option<String>, SyncFailure> {
+    Ok(match token_provider {
+        Some(tp) => Some(tp.token().await.map_err(SyncFailure::Retryable)?),
+        None => fhir_cfg
+            .token_env
+            .as_ref()
+            .and_then(|key| std::env::var(key).ok()),
+    })
 }
 
 async fn sync_one(
@@ -251,13 +712,22 @@ async fn sync_one(
     event: &SyncEvent,
 ) -> Result<FhirResult, SyncFailure> {
     let fhir_cfg = &cfg.fhir;
-    let token: Option<String> = match token_provider {
-        Some(tp) => Some(tp.token().await.map_err(SyncFailure::Retryable)?),
-        None => fhir_cfg
-            .token_env
-            .as_ref()
-            .and_then(|key| std::env::var(key).ok()),
-    };
+    let token = request_token(fhir_cfg, token_provider).await?;
+
+    match delete::delete_policy(fhir_cfg, event) {
+        Some(DeletePolicy::HardDelete) => {
+            return delete::hard_delete(client, fhir_cfg, token.as_deref(), event).await;
+        }
+        Some(DeletePolicy::Ignore) => {
+            info!("fhir sink: ignoring delete {}", event.idempotency_key());
+            return Ok(FhirResult {
+                fhir_id: String::new(),
+                version_id: None,
+                applied: false,
+            });
+        }
+        _ => {}
+    }
 
     match event.payload() {
         DomainResource::Patient(patient) => {
@@ -290,6 +760,51 @@ async fn sync_one(
         DomainResource::ServiceRequest(request) => {
             oscar2::sync_service_request(client, fhir_cfg, token, event, request, &cfg.oscar).await
         }
+        DomainResource::RelatedPerson(related) => {
+            related_person::sync_related_person(client, fhir_cfg, token, event, related).await
+        }
+        DomainResource::Specialist(specialist) => {
+            specialist::sync_specialist(client, fhir_cfg, token, event, specialist).await
+        }
+        DomainResource::Organization(org) => {
+            organization::sync_organization(client, fhir_cfg, token, event, org).await
+        }
+        DomainResource::Schedule(sched) => {
+            schedule::sync_schedule(client, fhir_cfg, token, event, sched).await
+        }
+        DomainResource::Slot(slot) => {
+            schedule::sync_slot(client, fhir_cfg, token, event, slot, &cfg.oscar).await
+        }
+        DomainResource::EpisodeOfCare(episode) => {
+            episode_of_care::sync_episode_of_care(client, fhir_cfg, token, event, episode, &cfg.oscar).await
+        }
+        DomainResource::Location(location) => {
+            location::sync_location(client, fhir_cfg, token, event, location).await
+        }
+        DomainResource::PractitionerRole(role) => {
+            practitioner_role::sync_practitioner_role(client, fhir_cfg, token, event, role, &cfg.oscar).await
+        }
+        DomainResource::Questionnaire(q) => {
+            questionnaire::sync_questionnaire(client, fhir_cfg, token, event, q, &cfg.oscar).await
+        }
+        DomainResource::QuestionnaireResponse(response) => {
+            questionnaire::sync_questionnaire_response(client, fhir_cfg, token, event, response, &cfg.oscar).await
+        }
+        DomainResource::Communication(communication) => {
+            communication::sync_communication(client, fhir_cfg, token, event, communication, &cfg.oscar).await
+        }
+        DomainResource::Claim(claim) => {
+            billing::sync_claim(client, fhir_cfg, token, event, claim, &cfg.oscar).await
+        }
+        DomainResource::ChargeItem(charge) => {
+            billing::sync_charge_item(client, fhir_cfg, token, event, charge, &cfg.oscar).await
+        }
+        DomainResource::WaitingListAppointment(entry) | DomainResource::WaitingListRequest(entry) => {
+            waiting_list::sync_waiting_list_entry(client, fhir_cfg, token, event, entry, &cfg.oscar).await
+        }
+        DomainResource::ReferralDoctor(doctor) => {
+            referral_doctor::sync_referral_doctor(client, fhir_cfg, token, event, doctor).await
+        }
     }
 }
 
@@ -300,15 +815,17 @@ async fn sync_patient(
     event: &SyncEvent,
     patient: &DomainPatient,
 ) -> Result<FhirResult, SyncFailure> {
-    let mut fhir_patient = build_patient(patient, fhir_cfg);
-    if event.op() == Op::Delete {
-        fhir_patient.active = Some(false.into());
-    }
-
+    let fhir_patient = build_patient(patient, fhir_cfg);
+    let fhir_patient = apply_delete_policy(FhirResource::Patient(Box::new(fhir_patient)), fhir_cfg, event);
+    let fhir_patient = apply_column_overrides(fhir_patient, event);
     let body = fhirbolt::json::to_string(&fhir_patient, None)
         .context("serializing FHIR Patient")
         .map_err(SyncFailure::Permanent)?;
 
+    if fhir_cfg.if_match_writes {
+        return if_match::put_if_match(client, fhir_cfg, token.as_deref(), event, body).await;
+    }
+
     let req = build_put_request(client, fhir_cfg, token.as_deref(), event).body(body);
 
     let resp = req
@@ -320,11 +837,7 @@ async fn sync_patient(
 
     if !status.is_success() {
         let text = resp.text().await.unwrap_or_default();
-        let err = anyhow::anyhow!("HAPI conditional PUT failed ({status}): {text}");
-        if status.is_client_error() && status.as_u16() != 429 {
-            return Err(SyncFailure::Permanent(err));
-        }
-        return Err(SyncFailure::Retryable(err));
+        return Err(classify_http_error(status, &text, "HAPI conditional PUT"));
     }
 
     let location = resp
@@ -337,6 +850,7 @@ async fn sync_patient(
     let mut result = FhirResult {
         fhir_id: String::new(),
         version_id: None,
+        applied: true,
     };
 
     if !body_text.trim().is_empty() {
@@ -376,15 +890,17 @@ async fn sync_practitioner(
     event: &SyncEvent,
     practitioner: &DomainPractitioner,
 ) -> Result<FhirResult, SyncFailure> {
-    let mut fhir_practitioner = build_practitioner(practitioner, fhir_cfg);
-    if event.op() == Op::Delete {
-        fhir_practitioner.active = Some(false.into());
-    }
-
+    let fhir_practitioner = build_practitioner(practitioner, fhir_cfg);
+    let fhir_practitioner = apply_delete_policy(FhirResource::Practitioner(Box::new(fhir_practitioner)), fhir_cfg, event);
+    let fhir_practitioner = apply_column_overrides(fhir_practitioner, event);
     let body = fhirbolt::json::to_string(&fhir_practitioner, None)
         .context("serializing FHIR Practitioner")
         .map_err(SyncFailure::Permanent)?;
 
+    if fhir_cfg.if_match_writes {
+        return if_match::put_if_match(client, fhir_cfg, token.as_deref(), event, body).await;
+    }
+
     let req = build_put_request(client, fhir_cfg, token.as_deref(), event).body(body);
 
     let resp = req
@@ -396,11 +912,7 @@ async fn sync_practitioner(
 
     if !status.is_success() {
         let text = resp.text().await.unwrap_or_default();
-        let err = anyhow::anyhow!("HAPI conditional PUT failed ({status}): {text}");
-        if status.is_client_error() && status.as_u16() != 429 {
-            return Err(SyncFailure::Permanent(err));
-        }
-        return Err(SyncFailure::Retryable(err));
+        return Err(classify_http_error(status, &text, "HAPI conditional PUT"));
     }
 
     let location = resp
@@ -413,6 +925,7 @@ async fn sync_practitioner(
     let mut result = FhirResult {
         fhir_id: String::new(),
         version_id: None,
+        applied: true,
     };
 
     if !body_text.trim().is_empty() {
@@ -447,6 +960,11 @@ async fn sync_practitioner(
 
 const OSCAR_APPOINTMENT_STATUS_SYSTEM: &str = "https://arsmedicatech.com/fhir/sid/oscar-appointment-status";
 const OSCAR_BOOKING_SOURCE_URL: &str = "https://arsmedicatech.com/fhir/StructureDefinition/oscar-booking-source";
+const OSCAR_HEALTH_CARD_VERSION_URL: &str = "https://arsmedicatech.com/fhir/StructureDefinition/oscar-health-card-version";
+const OSCAR_ROSTER_URL: &str = "https://arsmedicatech.com/fhir/StructureDefinition/oscar-roster";
+const OSCAR_DATE_JOINED_URL: &str = "https://arsmedicatech.com/fhir/StructureDefinition/oscar-date-joined";
+const BCP47_SYSTEM: &str = "urn:ietf:bcp:47";
+const SNOMED_SYSTEM: &str = "http://snomed.info/sct";
 
 async fn sync_appointment(
     client: &reqwest::Client,
@@ -482,17 +1000,14 @@ async fn sync_appointment(
 
     if !status.is_success() {
         let text = resp.text().await.unwrap_or_default();
-        let err = anyhow::anyhow!("HAPI transaction Bundle failed ({status}): {text}");
-        if status.is_client_error() && status.as_u16() != 429 {
-            return Err(SyncFailure::Permanent(err));
-        }
-        return Err(SyncFailure::Retryable(err));
+        return Err(classify_http_error(status, &text, "HAPI transaction Bundle"));
     }
 
     let body_text = resp.text().await.unwrap_or_default();
     let mut result = FhirResult {
         fhir_id: String::new(),
         version_id: None,
+        applied: true,
     };
 
     if !body_text.trim().is_empty() {
@@ -547,15 +1062,8 @@ async fn sync_care_team(
     event: &SyncEvent,
     care_team: &DomainCareTeam,
 ) -> Result<FhirResult, SyncFailure> {
-    // Deletions on the demographic row do not alter CareTeam membership:
-    // removal is a human decision in AMT-Social (D2).
-    if event.op() == Op::Delete {
-        return Ok(FhirResult {
-            fhir_id: String::new(),
-            version_id: None,
-        });
-    }
-
+    // Deletes stop in `sync_one`: CareTeam's delete policy is always
+    // `ignore` (D2). Role changes are reconciled below.
     let base = fhir_cfg.base_url.trim_end_matches('/');
     let identifier = format!(
         "{}|{}",
@@ -604,50 +1112,37 @@ async fn sync_care_team(
             }
         };
 
-    // Existing CareTeam: ensure the current MRP is present (D2).
-    // We resolve the Oscar identifier to the set of matching Practitioner IDs, because HAPI may
-    // store the participant as a literal `Practitioner/<id>` after resolving the conditional
-    // reference. Without this, an exact-string compare would re-append the same MRP every sync.
-    let prov_sys: String =
-        url::form_urlencoded::byte_serialize(fhir_cfg.oscar_provider_system.as_bytes()).collect();
-    let prov_val: String =
-        url::form_urlencoded::byte_serialize(care_team.provider_no.as_bytes()).collect();
-    let expected_member = format!("Practitioner?identifier={prov_sys}|{prov_val}");
-    let practitioner_ids = resolve_practitioner_ids(
-        client,
-        token.as_deref(),
-        base,
-        &fhir_cfg.oscar_provider_system,
-        &care_team.provider_no,
-    )
-    .await?;
-    let already_present = initial_resource
-        .participant
-        .iter()
-        .any(|p| participant_has_member(p, &expected_member, &practitioner_ids));
-
-    if already_present {
-        info!(
-            "fhir sink: {} CareTeam {} already contains MRP {}; no-op",
-            event.idempotency_key(),
-            initial_id,
-            care_team.provider_no
-        );
-        return Ok(FhirResult {
-            fhir_id: initial_id,
-            version_id: Some(initial_version),
-        });
+    // Existing CareTeam: reconcile the Oscar-managed roles (D2). Each desired
+    // member is resolved to its Practitioner IDs up front, because HAPI may store
+    // the participant as a literal `Practitioner/<id>` after resolving the
+    // conditional reference; an exact-string compare would re-append it every sync.
+    let mut desired = Vec::with_capacity(care_team.participants.len());
+    for participant in &care_team.participants {
+        let (system, value) = care_team_member_identifier(&participant.member, fhir_cfg);
+        let ids = resolve_practitioner_ids(client, token.as_deref(), base, system, value).await?;
+        desired.push((participant, ids));
     }
 
-    // Append the MRP and PUT with If-Match. On 409/412, re-read once and retry.
     let mut current_id = initial_id;
     let mut current_version = initial_version;
     let mut current_resource = initial_resource;
 
+    // PUT with If-Match. On 409/412, re-read once and reconcile against the winner.
     for attempt in 0..2 {
-        current_resource
-            .participant
-            .push(build_care_team_participant(care_team, fhir_cfg));
+        let Some(participants) = reconcile_care_team_participants(&current_resource.participant, &desired, fhir_cfg)
+        else {
+            info!(
+                "fhir sink: {} CareTeam {} participants already current; no-op",
+                event.idempotency_key(),
+                current_id
+            );
+            return Ok(FhirResult {
+                fhir_id: current_id,
+                version_id: Some(current_version),
+                applied: true,
+            });
+        };
+        current_resource.participant = participants;
 
         let body = fhirbolt::json::to_string(&current_resource, None)
             .context("serializing updated CareTeam")
@@ -674,10 +1169,9 @@ async fn sync_care_team(
             let body_text = resp.text().await.unwrap_or_default();
             let result = parse_care_team_put_response(&body_text, &current_id)?;
             info!(
-                "fhir sink: synced {} -> CareTeam {} appended MRP {} (version_id={:?})",
+                "fhir sink: synced {} -> CareTeam {} reconciled participants (version_id={:?})",
                 event.idempotency_key(),
                 result.fhir_id,
-                care_team.provider_no,
                 result.version_id
             );
             return Ok(result);
@@ -696,25 +1190,6 @@ async fn sync_care_team(
                 current_id = id;
                 current_version = version;
                 current_resource = resource;
-                // If the winner already has the MRP, this is now a no-op.
-                let practitioner_ids = resolve_practitioner_ids(
-                    client,
-                    token.as_deref(),
-                    base,
-                    &fhir_cfg.oscar_provider_system,
-                    &care_team.provider_no,
-                )
-                .await?;
-                if current_resource
-                    .participant
-                    .iter()
-                    .any(|p| participant_has_member(p, &expected_member, &practitioner_ids))
-                {
-                    return Ok(FhirResult {
-                        fhir_id: current_id,
-                        version_id: Some(current_version),
-                    });
-                }
                 continue;
             }
         }
@@ -726,26 +1201,92 @@ async fn sync_care_team(
     )))
 }
 
-fn build_care_team_participant(care_team: &DomainCareTeam, fhir_cfg: &FhirConfig) -> CareTeamParticipant {
-    let sys: String =
-        url::form_urlencoded::byte_serialize(fhir_cfg.oscar_provider_system.as_bytes()).collect();
-    let val: String =
-        url::form_urlencoded::byte_serialize(care_team.provider_no.as_bytes()).collect();
+/// The Practitioner identifier (system, value) a CareTeam member is keyed on.
+fn care_team_member_identifier<'a>(member: &'a CareTeamMember, fhir_cfg: &'a FhirConfig) -> (&'a str, &'a str) {
+    match member {
+        CareTeamMember::Provider(provider_no) => (&fhir_cfg.oscar_provider_system, provider_no),
+        CareTeamMember::Specialist(spec_id) => (&fhir_cfg.oscar_specialist_system, spec_id),
+        CareTeamMember::ReferralDoctor(billing_no) => (&fhir_cfg.oscar_referral_doctor_system, billing_no),
+    }
+}
+
+/// The Oscar role a participant was synced for, read back from its SNOMED
+/// role coding. Participants without one were added in AMT and are never
+/// touched.
+fn managed_care_team_role(participant: &CareTeamParticipant) -> Option<CareTeamRole> {
+    participant
+        .role
+        .iter()
+        .flat_map(|r| r.coding.iter())
+        .filter(|c| c.system.as_ref().and_then(|s| s.value.as_deref()) == Some(SNOMED_SYSTEM))
+        .find_map(|c| c.code.as_ref().and_then(|v| v.value.as_deref()).and_then(CareTeamRole::from_snomed_code))
+}
+
+/// Reconciles an existing CareTeam's participants with the desired Oscar
+/// roles: managed participants no longer in Oscar are removed, missing ones
+/// appended, and everything else is kept in place. Returns `None` when
+/// nothing changes.
+fn reconcile_care_team_participants(
+    existing: &[CareTeamParticipant],
+    desired: &[(&DomainCareTeamParticipant, HashSet<String>)],
+    fhir_cfg: &FhirConfig,
+) -> Option<Vec<CareTeamParticipant>> {
+    let expected: Vec<String> = desired
+        .iter()
+        .map(|(p, _)| {
+            let (system, value) = care_team_member_identifier(&p.member, fhir_cfg);
+            oscar2::conditional_reference(system, value, "Practitioner")
+                .reference
+                .and_then(|r| r.value)
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut present = vec![false; desired.len()];
+    let mut changed = false;
+    let mut out = Vec::with_capacity(existing.len());
+
+    for participant in existing {
+        let Some(role) = managed_care_team_role(participant) else {
+            out.push(participant.clone());
+            continue;
+        };
+        let matched = desired.iter().enumerate().position(|(i, (p, ids))| {
+            !present[i] && p.role == role && participant_has_member(participant, &expected[i], ids)
+        });
+        match matched {
+            Some(i) => {
+                present[i] = true;
+                out.push(participant.clone());
+            }
+            None => changed = true,
+        }
+    }
+
+    for (i, (p, _)) in desired.iter().enumerate() {
+        if !present[i] {
+            out.push(build_care_team_participant(p, fhir_cfg));
+            changed = true;
+        }
+    }
+
+    changed.then_some(out)
+}
+
+fn build_care_team_participant(participant: &DomainCareTeamParticipant, fhir_cfg: &FhirConfig) -> CareTeamParticipant {
+    let (system, value) = care_team_member_identifier(&participant.member, fhir_cfg);
 
     CareTeamParticipant {
         role: vec![CodeableConcept {
             coding: vec![Coding {
-                system: Some("http://snomed.info/sct".to_string().into()),
-                code: Some("446050000".to_string().into()),
-                display: Some("Primary care physician".to_string().into()),
+                system: Some(SNOMED_SYSTEM.to_string().into()),
+                code: Some(participant.role.snomed_code().to_string().into()),
+                display: Some(participant.role.snomed_display().to_string().into()),
                 ..Default::default()
             }],
             ..Default::default()
         }],
-        member: Some(Box::new(Reference {
-            reference: Some(format!("Practitioner?identifier={sys}|{val}").into()),
-            ..Default::default()
-        })),
+        member: Some(Box::new(oscar2::conditional_reference(system, value, "Practitioner"))),
         ..Default::default()
     }
 }
@@ -780,7 +1321,11 @@ fn build_care_team(care_team: &DomainCareTeam, fhir_cfg: &FhirConfig) -> CareTea
             reference: Some(format!("Patient?identifier={demo_sys}|{demo_val}").into()),
             ..Default::default()
         })),
-        participant: vec![build_care_team_participant(care_team, fhir_cfg)],
+        participant: care_team
+            .participants
+            .iter()
+            .map(|p| build_care_team_participant(p, fhir_cfg))
+            .collect(),
         ..Default::default()
     }
 }
@@ -991,6 +1536,14 @@ fn participant_has_member(
 }
 
 fn classify_http_error(status: reqwest::StatusCode, text: &str, context: &str) -> SyncFailure {
+    // Retrying won't help until the referenced resource is synced; the
+    // caller parks it instead.
+    if status.is_client_error() {
+        if let Some(unresolved) = parking::unresolved_reference(text) {
+            let message = format!("{context} failed ({status}): {unresolved}");
+            return SyncFailure::Permanent(anyhow::Error::new(unresolved).context(message));
+        }
+    }
     let err = anyhow::anyhow!("{context} failed ({status}): {text}");
     if status.is_client_error() && status.as_u16() != 429 {
         SyncFailure::Permanent(err)
@@ -1003,6 +1556,7 @@ fn parse_bundle_response(body_text: &str) -> Result<FhirResult, SyncFailure> {
     let mut result = FhirResult {
         fhir_id: String::new(),
         version_id: None,
+        applied: true,
     };
 
     if !body_text.trim().is_empty() {
@@ -1048,6 +1602,7 @@ fn parse_care_team_put_response(body_text: &str, fallback_id: &str) -> Result<Fh
     let mut result = FhirResult {
         fhir_id: fallback_id.to_string(),
         version_id: None,
+        applied: true,
     };
 
     if !body_text.trim().is_empty() {
@@ -1108,6 +1663,206 @@ fn map_gender(sex: Option<&str>) -> &'static str {
     }
 }
 
+/// Health card NamingSystem for an Oscar `hc_type`. A missing `hc_type`
+/// keeps the historical BC PHN behaviour; an unlisted one (Oscar's "OT")
+/// uses the out-of-country system rather than mislabelling it as BC.
+fn health_card_system<'a>(hc_type: Option<&str>, cfg: &'a FhirConfig) -> &'a String {
+    match hc_type.map(str::trim).filter(|t| !t.is_empty()) {
+        None => &cfg.bc_phn_system,
+        Some(t) => match cfg.health_card_systems.get(&t.to_ascii_uppercase()) {
+            Some(system) => system,
+            None if t.eq_ignore_ascii_case("BC") => &cfg.bc_phn_system,
+            None => &cfg.out_of_country_health_card_system,
+        },
+    }
+}
+
+/// Builds the health card identifier: system chosen by `hc_type`, card
+/// expiry as `period.end`, and the version code (`ver`) as an extension.
+fn build_health_card_identifier(hin: &str, payload: &DomainPatient, cfg: &FhirConfig) -> Identifier {
+    let mut identifier = Identifier {
+        system: Some(health_card_system(payload.hc_type.as_deref(), cfg).clone().into()),
+        value: Some(hin.to_string().into()),
+        ..Default::default()
+    };
+
+    if let Some(expiry) = &payload.hc_renew_date {
+        if NaiveDate::parse_from_str(expiry, "%Y-%m-%d").is_ok() {
+            identifier.period = Some(Box::new(Period {
+                end: Some(expiry.clone().into()),
+                ..Default::default()
+            }));
+        } else {
+            warn!(
+                "build_patient: ignoring unparseable hc_renew_date '{expiry}' for demographic_no={}",
+                payload.demographic_no
+            );
+        }
+    }
+
+    if let Some(version) = &payload.hc_version {
+        identifier.extension.push(Extension {
+            url: OSCAR_HEALTH_CARD_VERSION_URL.to_string(),
+            value: Some(ExtensionValue::String(version.clone().into())),
+            ..Default::default()
+        });
+    }
+
+    identifier
+}
+
+/// Official name (with middle names as extra given names and `title` as a
+/// prefix), a `usual` name for `pref_name`, and a `nickname` for `alias`.
+/// Write-back reads the name that has a family, so the official name stays
+/// first.
+fn build_patient_names(payload: &DomainPatient) -> Vec<HumanName> {
+    let mut names = Vec::new();
+
+    if payload.first_name.is_some() || payload.last_name.is_some() || payload.middle_names.is_some() {
+        let mut given: Vec<_> = payload.first_name.clone().into_iter().map(Into::into).collect();
+        given.extend(
+            payload
+                .middle_names
+                .iter()
+                .flat_map(|m| m.split_whitespace())
+                .map(|m| m.to_string().into()),
+        );
+        names.push(HumanName {
+            r#use: Some("official".into()),
+            family: payload.last_name.clone().map(Into::into),
+            given,
+            prefix: payload.title.clone().map(|t| vec![t.into()]).unwrap_or_default(),
+            ..Default::default()
+        });
+    }
+
+    if let Some(pref_name) = &payload.pref_name {
+        names.push(HumanName {
+            r#use: Some("usual".into()),
+            family: payload.last_name.clone().map(Into::into),
+            given: vec![pref_name.clone().into()],
+            ..Default::default()
+        });
+    }
+
+    if let Some(alias) = &payload.alias {
+        names.push(HumanName {
+            r#use: Some("nickname".into()),
+            text: Some(alias.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    names
+}
+
+/// BCP-47 code for the languages Oscar clinics commonly record. Anything
+/// else is carried as text only.
+fn language_code(language: &str) -> Option<&'static str> {
+    let code = match language.trim().to_ascii_lowercase().as_str() {
+        "english" | "eng" | "en" => "en",
+        "french" | "fre" | "fra" | "fr" => "fr",
+        "spanish" => "es",
+        "punjabi" => "pa",
+        "hindi" => "hi",
+        "urdu" => "ur",
+        "chinese" | "mandarin" => "zh",
+        "cantonese" => "yue",
+        "tagalog" | "filipino" => "tl",
+        "korean" => "ko",
+        "japanese" => "ja",
+        "vietnamese" => "vi",
+        "arabic" => "ar",
+        "farsi" | "persian" => "fa",
+        "portuguese" => "pt",
+        "italian" => "it",
+        "german" => "de",
+        "russian" => "ru",
+        "polish" => "pl",
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// `communication` from `spoken_lang` and `official_lang`. The spoken
+/// language is preferred when present; a language recorded in both columns
+/// is emitted once.
+fn build_patient_communication(payload: &DomainPatient) -> Vec<PatientCommunication> {
+    let mut out: Vec<PatientCommunication> = Vec::new();
+    let mut seen: Vec<String> = Vec::new();
+
+    for (language, preferred) in [
+        (&payload.spoken_lang, true),
+        (&payload.official_lang, payload.spoken_lang.is_none()),
+    ] {
+        let Some(language) = language else { continue };
+        let key = language_code(language)
+            .map(str::to_string)
+            .unwrap_or_else(|| language.trim().to_ascii_lowercase());
+        if seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+
+        out.push(PatientCommunication {
+            language: Box::new(CodeableConcept {
+                coding: language_code(language)
+                    .map(|code| Coding {
+                        system: Some(BCP47_SYSTEM.to_string().into()),
+                        code: Some(code.to_string().into()),
+                        ..Default::default()
+                    })
+                    .into_iter()
+                    .collect(),
+                text: Some(language.clone().into()),
+                ..Default::default()
+            }),
+            preferred: preferred.then(|| true.into()),
+            ..Default::default()
+        });
+    }
+
+    out
+}
+
+/// Roster enrolment (`roster_status` + `roster_date`) and `date_joined` as
+/// Oscar-specific extensions; FHIR `Patient` has no equivalent elements.
+fn build_patient_enrolment_extensions(payload: &DomainPatient) -> Vec<Extension> {
+    let mut out = Vec::new();
+
+    if payload.roster_status.is_some() || payload.roster_date.is_some() {
+        let mut roster = Extension {
+            url: OSCAR_ROSTER_URL.to_string(),
+            ..Default::default()
+        };
+        if let Some(status) = &payload.roster_status {
+            roster.extension.push(Extension {
+                url: "status".to_string(),
+                value: Some(ExtensionValue::Code(status.clone().into())),
+                ..Default::default()
+            });
+        }
+        if let Some(date) = &payload.roster_date {
+            roster.extension.push(Extension {
+                url: "date".to_string(),
+                value: Some(ExtensionValue::Date(date.clone().into())),
+                ..Default::default()
+            });
+        }
+        out.push(roster);
+    }
+
+    if let Some(joined) = &payload.date_joined {
+        out.push(Extension {
+            url: OSCAR_DATE_JOINED_URL.to_string(),
+            value: Some(ExtensionValue::Date(joined.clone().into())),
+            ..Default::default()
+        });
+    }
+
+    out
+}
+
 fn build_patient(payload: &DomainPatient, cfg: &FhirConfig) -> Patient {
     let mut patient = Patient::default();
 
@@ -1122,31 +1877,29 @@ fn build_patient(payload: &DomainPatient, cfg: &FhirConfig) -> Patient {
         ..Default::default()
     });
 
-    // Provincial PHN, only if this Oscar instance has it.
+    // Provincial health card, only if this Oscar instance has it.
     if let Some(hin) = &payload.hin {
-        patient.identifier.push(Identifier {
-            system: Some(cfg.bc_phn_system.clone().into()),
-            value: Some(hin.clone().into()),
-            ..Default::default()
-        });
+        patient.identifier.push(build_health_card_identifier(hin, payload, cfg));
     }
 
-    if payload.first_name.is_some() || payload.last_name.is_some() {
-        patient.name.push(HumanName {
-            family: payload.last_name.clone().map(Into::into),
-            given: payload
-                .first_name
-                .clone()
-                .map(|g| vec![g.into()])
-                .unwrap_or_default(),
+    if let Some(chart_no) = &payload.chart_no {
+        patient.identifier.push(Identifier {
+            r#use: Some("secondary".into()),
+            system: Some(cfg.oscar_chart_no_system.clone().into()),
+            value: Some(chart_no.clone().into()),
             ..Default::default()
         });
     }
 
+    patient.name = build_patient_names(payload);
+
     if let Some(dob) = &payload.date_of_birth {
         patient.birth_date = Some(dob.clone().into());
     }
 
+    patient.communication = build_patient_communication(payload);
+    patient.extension = build_patient_enrolment_extensions(payload);
+
     // Never omitted — falls back to "unknown".
     patient.gender = Some(map_gender(payload.sex.as_deref()).into());
 
@@ -1170,6 +1923,25 @@ fn build_patient(payload: &DomainPatient, cfg: &FhirConfig) -> Patient {
         patient.address.push(build_address(addr));
     }
 
+    // Only `Contact`-backed links; demographic-backed ones are Patients in
+    // their own right and are synced solely as RelatedPerson.
+    for contact in payload.contacts.iter().filter(|c| c.active && c.linked_demographic_no.is_none()) {
+        patient.contact.push(related_person::build_patient_contact(contact));
+    }
+
+    // Already base64-encoded and size-capped by the mapper.
+    if let Some(photo) = &payload.photo {
+        patient.photo.push(Attachment {
+            content_type: Some(photo.content_type.clone().into()),
+            data: Some(photo.data.clone().into()),
+            size: u32::try_from(photo.size).ok().map(Into::into),
+            creation: photo.creation.clone().map(Into::into),
+            ..Default::default()
+        });
+    }
+
+    patient.general_practitioner = build_general_practitioners(payload, cfg);
+
     // `patient_status` and `demographic_merged` both influence active/deceased/link.
     let (active, deceased, link) = patient_lifecycle(payload, cfg);
     patient.active = Some(active.into());
@@ -1179,6 +1951,31 @@ fn build_patient(payload: &DomainPatient, cfg: &FhirConfig) -> Patient {
     patient
 }
 
+/// MRP first, then the family doctor: as the resolved specialist when
+/// `spec_id` is set, otherwise as the external Practitioner keyed on the
+/// billing number. A doctor with neither cannot be referenced and is skipped.
+fn build_general_practitioners(payload: &DomainPatient, cfg: &FhirConfig) -> Vec<Reference> {
+    let mut refs = Vec::new();
+
+    if let Some(mrp) = &payload.mrp_provider_no {
+        refs.push(oscar2::practitioner_ref(cfg, mrp));
+    }
+
+    if let Some(doctor) = &payload.family_doctor {
+        if let Some(spec_id) = &doctor.spec_id {
+            refs.push(oscar2::specialist_ref(cfg, spec_id));
+        } else if let Some(billing_no) = &doctor.billing_no {
+            refs.push(oscar2::conditional_reference(
+                &cfg.oscar_referral_doctor_system,
+                billing_no,
+                "Practitioner",
+            ));
+        }
+    }
+
+    refs
+}
+
 fn build_address(addr: &DomainAddress) -> Address {
     Address {
         r#use: Some(addr.use_.as_str().into()),
@@ -1424,6 +2221,8 @@ fn build_appointment(
         });
     }
 
+    // A delete skips status mapping; `fhir.delete_policy` sets the final
+    // status in `build_appointment_bundle`.
     let fhir_status = if op == Op::Delete {
         "cancelled"
     } else {
@@ -1538,6 +2337,17 @@ fn build_appointment(
         });
     }
 
+    // Only a resolved site becomes a Location; single-site clinics keep
+    // `appointment.location` as free text with nothing to reference.
+    if let Some(site_id) = &payload.site_id {
+        participants.push(AppointmentParticipant {
+            actor: Some(Box::new(location::site_ref(fhir_cfg, site_id))),
+            required: Some("required".into()),
+            status: "accepted".into(),
+            ..Default::default()
+        });
+    }
+
     if participants.is_empty() {
         return Err(SyncFailure::Permanent(anyhow::anyhow!(
             "unmappable_appointment: appointment_no={} has no participants",
@@ -1568,7 +2378,14 @@ fn build_appointment_bundle(
     bundle.r#type = "transaction".into();
     bundle.entry.push(BundleEntry {
         full_url: Some(format!("urn:uuid:{}", event.idempotency_key()).into()),
-        resource: Some(FhirResource::Appointment(Box::new(fhir_appointment.clone()))),
+        resource: Some(apply_column_overrides(
+            apply_delete_policy(
+                FhirResource::Appointment(Box::new(fhir_appointment.clone())),
+                fhir_cfg,
+                event,
+            ),
+            event,
+        )),
         request: Some(BundleEntryRequest {
             method: "PUT".into(),
             url: conditional_url.into(),
@@ -1584,8 +2401,10 @@ mod tests {
     use super::*;
     use crate::config::{
         Config, DatabaseConfig, DispatchConfig, FhirConfig, OscarConfig, ReplicationConfig,
-        ServerConfig, SyncConfig,
+        ServerConfig, SyncConfig, WritebackConfig,
     };
+    use crate::domain::patient::{AddressKind, AddressUse};
+    use crate::event::Source;
     use std::sync::atomic::{AtomicU32, Ordering};
     use std::sync::Arc;
     use std::time::Duration;
@@ -1617,12 +2436,31 @@ mod tests {
             default_appointment_type: None,
             default_program_id: None,
             care_team_enabled: true,
+            related_person_enabled: true,
+            schedule_enabled: true,
+            schedule_horizon_days: 90,
+            schedule_unavailable_codes: vec!["_".to_string()],
+            practitioner_role_enabled: true,
+            provider_type_role_map: crate::config::OscarConfig::default().provider_type_role_map,
+            eform_enabled: true,
+            eform_questionnaire_map: Default::default(),
+            communication_enabled: true,
+            exclude_unlinked_messages: true,
+            billing_enabled: true,
+            billing_resource: Default::default(),
+            billing_status_map: Default::default(),
+            waiting_list_enabled: true,
+            waiting_list_resource: Default::default(),
+            patient_photo_enabled: true,
+            patient_photo_max_bytes: 262_144,
+            patient_photo_thumbnail_px: None,
+            column_overrides: Vec::new(),
             consult_response_status_map: crate::config::OscarConfig::default().consult_response_status_map,
             consult_request_status_map: crate::config::OscarConfig::default().consult_request_status_map,
         }
     }
 
-    fn appt_payload() -> DomainAppointment {
+    pub(super) fn appt_payload() -> DomainAppointment {
         DomainAppointment {
             appointment_no: "1".to_string(),
             demographic_no: Some("101".to_string()),
@@ -1637,6 +2475,7 @@ mod tests {
             urgency: Some("3".to_string()),
             createdatetime: Some("2026-08-09 16:30:00".to_string()),
             location: Some("Room A".to_string()),
+            site_id: None,
             booking_source: Some("OSCAR".to_string()),
             type_: Some("Regular".to_string()),
         }
@@ -1664,6 +2503,22 @@ mod tests {
         assert!(appt.participant[1].actor.as_ref().unwrap().reference.as_ref().unwrap().value.as_ref().unwrap().starts_with("Practitioner?identifier"));
     }
 
+    #[test]
+    fn partitions_appointments_with_their_patient() {
+        let key = DomainResource::Appointment(appt_payload()).ordering_key();
+        assert_eq!(key, "patient/101");
+
+        let without_patient = DomainAppointment {
+            demographic_no: None,
+            ..appt_payload()
+        };
+        assert_eq!(DomainResource::Appointment(without_patient).ordering_key(), "appointment/1");
+
+        let worker = partition(&key, 8);
+        assert!(worker < 8);
+        assert_eq!(partition(&key, 8), worker);
+    }
+
     #[test]
     fn build_appointment_bundle_contains_conditional_put() {
         let cfg = fhir_cfg();
@@ -1738,6 +2593,15 @@ mod tests {
         assert!(appt.participant[0].actor.as_ref().unwrap().reference.as_ref().unwrap().value.as_ref().unwrap().starts_with("Patient"));
     }
 
+    #[test]
+    fn build_appointment_adds_location_participant_for_resolved_site() {
+        let mut payload = appt_payload();
+        payload.site_id = Some("2".to_string());
+        let appt = build_appointment(&payload, &fhir_cfg(), &oscar_cfg(), Op::Upsert).unwrap();
+        assert_eq!(appt.participant.len(), 3);
+        assert!(appt.participant[2].actor.as_ref().unwrap().reference.as_ref().unwrap().value.as_ref().unwrap().starts_with("Location?identifier"));
+    }
+
     #[test]
     fn gender_mapping_never_omits() {
         assert_eq!(map_gender(Some("M")), "male");
@@ -1759,13 +2623,8 @@ mod tests {
             first_name: Some("Alice".to_string()),
             last_name: Some("Smith".to_string()),
             date_of_birth: Some("1990-03-05".to_string()),
-            addresses: Vec::new(),
-            patient_status: None,
-            merged_to: None,
             sex: Some("F".to_string()),
-            phone: None,
-            email: None,
-            hin: None,
+            ..Default::default()
         };
 
         let patient = build_patient(&payload, &fhir_cfg());
@@ -1773,22 +2632,80 @@ mod tests {
         assert!(patient.address.is_empty());
         assert_eq!(patient.identifier.len(), 1);
         assert_eq!(patient.identifier[0].value, Some("123".to_string().into()));
+        assert!(patient.photo.is_empty());
+    }
+
+    #[test]
+    fn build_patient_inlines_photo() {
+        let payload = DomainPatient {
+            demographic_no: "123".to_string(),
+            photo: Some(crate::domain::patient::DomainPatientPhoto {
+                content_type: "image/jpeg".to_string(),
+                data: "/9j/4AAQ".to_string(),
+                size: 6,
+                creation: Some("2026-01-05".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        let patient = build_patient(&payload, &fhir_cfg());
+        assert_eq!(patient.photo.len(), 1);
+        let photo = &patient.photo[0];
+        assert_eq!(photo.content_type.as_ref().and_then(|c| c.value.as_deref()), Some("image/jpeg"));
+        assert_eq!(photo.data.as_ref().and_then(|d| d.value.as_deref()), Some("/9j/4AAQ"));
+        assert_eq!(photo.size.as_ref().and_then(|s| s.value), Some(6));
+        assert_eq!(photo.creation.as_ref().and_then(|c| c.value.as_deref()), Some("2026-01-05"));
+    }
+
+    #[test]
+    fn build_patient_maps_names_languages_chart_and_roster() {
+        let payload = DomainPatient {
+            demographic_no: "123".to_string(),
+            first_name: Some("Jane".to_string()),
+            last_name: Some("Smith".to_string()),
+            middle_names: Some("Marie Ann".to_string()),
+            pref_name: Some("Janie".to_string()),
+            title: Some("MS".to_string()),
+            alias: Some("J. Smythe".to_string()),
+            official_lang: Some("English".to_string()),
+            spoken_lang: Some("Punjabi".to_string()),
+            chart_no: Some("C-42".to_string()),
+            roster_status: Some("RO".to_string()),
+            roster_date: Some("2020-01-15".to_string()),
+            date_joined: Some("2019-06-01".to_string()),
+            ..Default::default()
+        };
+
+        let patient = build_patient(&payload, &fhir_cfg());
+
+        assert_eq!(patient.name.len(), 3);
+        assert_eq!(patient.name[0].r#use, Some("official".into()));
+        assert_eq!(patient.name[0].given.len(), 3);
+        assert_eq!(patient.name[0].prefix, vec!["MS".to_string().into()]);
+        assert_eq!(patient.name[1].r#use, Some("usual".into()));
+        assert_eq!(patient.name[1].given, vec!["Janie".to_string().into()]);
+        assert_eq!(patient.name[2].r#use, Some("nickname".into()));
+
+        assert_eq!(patient.communication.len(), 2);
+        assert_eq!(patient.communication[0].language.coding[0].code, Some("pa".to_string().into()));
+        assert_eq!(patient.communication[0].preferred.as_ref().and_then(|p| p.value), Some(true));
+        assert!(patient.communication[1].preferred.is_none());
+
+        assert_eq!(patient.identifier.len(), 2);
+        assert_eq!(patient.identifier[1].value, Some("C-42".to_string().into()));
+
+        assert_eq!(patient.extension.len(), 2);
+        assert_eq!(patient.extension[0].url, OSCAR_ROSTER_URL);
+        assert_eq!(patient.extension[0].extension.len(), 2);
+        assert_eq!(patient.extension[1].url, OSCAR_DATE_JOINED_URL);
     }
 
     #[test]
     fn build_patient_adds_hin_identifier_when_present() {
         let payload = DomainPatient {
             demographic_no: "123".to_string(),
-            first_name: None,
-            last_name: None,
-            date_of_birth: None,
-            addresses: Vec::new(),
-            patient_status: None,
-            merged_to: None,
-            sex: None,
-            phone: None,
-            email: None,
             hin: Some("9999888877".to_string()),
+            ..Default::default()
         };
 
         let patient = build_patient(&payload, &fhir_cfg());
@@ -1797,6 +2714,85 @@ mod tests {
             patient.identifier[1].value,
             Some("9999888877".to_string().into())
         );
+        assert_eq!(
+            patient.identifier[1].system,
+            Some(fhir_cfg().bc_phn_system.into())
+        );
+    }
+
+    #[test]
+    fn build_patient_sets_general_practitioner_from_mrp_and_family_doctor() {
+        let mut payload = DomainPatient {
+            demographic_no: "123".to_string(),
+            mrp_provider_no: Some("101".to_string()),
+            family_doctor: Some(crate::domain::referral_doctor::DomainReferralDoctor {
+                billing_no: Some("12345".to_string()),
+                first_name: Some("John".to_string()),
+                last_name: Some("Smith".to_string()),
+                spec_id: None,
+            }),
+            ..Default::default()
+        };
+
+        let refs = |p: &Patient| -> Vec<String> {
+            p.general_practitioner
+                .iter()
+                .filter_map(|r| r.reference.as_ref().and_then(|r| r.value.clone()))
+                .collect()
+        };
+
+        let patient = build_patient(&payload, &fhir_cfg());
+        assert_eq!(
+            refs(&patient),
+            vec![
+                "Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-provider|101".to_string(),
+                "Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-referral-doctor|12345"
+                    .to_string(),
+            ]
+        );
+
+        payload.family_doctor.as_mut().unwrap().spec_id = Some("7".to_string());
+        let patient = build_patient(&payload, &fhir_cfg());
+        assert_eq!(
+            refs(&patient)[1],
+            "Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-professional-specialist|7"
+        );
+    }
+
+    #[test]
+    fn build_patient_health_card_follows_hc_type() {
+        let mut payload = DomainPatient {
+            demographic_no: "123".to_string(),
+            hin: Some("1234567890".to_string()),
+            hc_type: Some("ON".to_string()),
+            hc_version: Some("AB".to_string()),
+            hc_renew_date: Some("2027-05-31".to_string()),
+            ..Default::default()
+        };
+
+        let patient = build_patient(&payload, &fhir_cfg());
+        let hc = &patient.identifier[1];
+        assert_eq!(
+            hc.system,
+            Some("https://fhir.infoway-inforoute.ca/NamingSystem/ca-on-patient-hcn".to_string().into())
+        );
+        assert_eq!(
+            hc.period.as_ref().and_then(|p| p.end.as_ref()).and_then(|e| e.value.clone()),
+            Some("2027-05-31".to_string())
+        );
+        assert_eq!(hc.extension.len(), 1);
+        assert_eq!(hc.extension[0].url, OSCAR_HEALTH_CARD_VERSION_URL);
+
+        payload.hc_type = Some("OT".to_string());
+        let patient = build_patient(&payload, &fhir_cfg());
+        assert_eq!(
+            patient.identifier[1].system,
+            Some(fhir_cfg().out_of_country_health_card_system.into())
+        );
+
+        payload.hc_type = Some("bc".to_string());
+        let patient = build_patient(&payload, &fhir_cfg());
+        assert_eq!(patient.identifier[1].system, Some(fhir_cfg().bc_phn_system.into()));
     }
 
     #[test]
@@ -1825,11 +2821,9 @@ mod tests {
                 },
             ],
             patient_status: Some("AC".to_string()),
-            merged_to: None,
             sex: Some("M".to_string()),
-            phone: None,
-            email: None,
             hin: Some("9123456781".to_string()),
+            ..Default::default()
         };
 
         let patient = build_patient(&payload, &fhir_cfg());
@@ -1837,7 +2831,7 @@ mod tests {
         assert_eq!(patient.address[0].r#type.as_ref().map(|c| c.value.clone()), Some(Some("postal".to_string())));
         assert_eq!(patient.address[1].r#type.as_ref().map(|c| c.value.clone()), Some(Some("physical".to_string())));
         assert_eq!(patient.address[0].country.as_ref().map(|c| c.value.clone()), Some(Some("CA".to_string())));
-        assert_eq!(patient.active.as_ref().map(|b| b.value).flatten(), Some(true));
+        assert_eq!(patient.active.as_ref().and_then(|b| b.value), Some(true));
         assert_eq!(patient.identifier.len(), 2); // no ver
     }
 
@@ -1856,18 +2850,14 @@ mod tests {
                 use_: AddressUse::Home,
                 kind: AddressKind::Postal,
             }],
-            patient_status: None,
-            merged_to: None,
             sex: Some("O".to_string()),
-            phone: None,
-            email: None,
-            hin: None,
+            ..Default::default()
         };
 
         let patient = build_patient(&payload, &fhir_cfg());
         assert_eq!(patient.address.len(), 1);
         assert!(patient.address[0].r#line.is_empty());
-        assert_eq!(patient.gender.as_ref().map(|c| c.value.clone()).flatten().as_deref(), Some("other"));
+        assert_eq!(patient.gender.as_ref().and_then(|c| c.value.clone()).as_deref(), Some("other"));
     }
 
     #[test]
@@ -1877,17 +2867,13 @@ mod tests {
             first_name: Some("Luc".to_string()),
             last_name: Some("Tremblay".to_string()),
             date_of_birth: Some("1943-11-30".to_string()),
-            addresses: Vec::new(),
             patient_status: Some("DE".to_string()),
-            merged_to: None,
             sex: Some("M".to_string()),
-            phone: None,
-            email: None,
-            hin: None,
+            ..Default::default()
         };
 
         let patient = build_patient(&payload, &fhir_cfg());
-        assert_eq!(patient.active.as_ref().map(|b| b.value).flatten(), Some(false));
+        assert_eq!(patient.active.as_ref().and_then(|b| b.value), Some(false));
         assert!(matches!(patient.deceased, Some(PatientDeceased::Boolean(_))));
     }
 
@@ -1895,23 +2881,53 @@ mod tests {
     fn build_patient_marks_replaced_by_link() {
         let payload = DomainPatient {
             demographic_no: "106".to_string(),
-            first_name: None,
-            last_name: None,
-            date_of_birth: None,
-            addresses: Vec::new(),
-            patient_status: None,
             merged_to: Some("101".to_string()),
-            sex: None,
-            phone: None,
-            email: None,
-            hin: None,
+            ..Default::default()
         };
 
         let patient = build_patient(&payload, &fhir_cfg());
-        assert_eq!(patient.active.as_ref().map(|b| b.value).flatten(), Some(false));
+        assert_eq!(patient.active.as_ref().and_then(|b| b.value), Some(false));
         assert_eq!(patient.link.len(), 1);
         assert_eq!(patient.link[0].r#type.value.as_deref(), Some("replaced-by"));
-        assert!(patient.link[0].other.reference.as_ref().map(|s| s.value.as_deref()).flatten().unwrap_or("").contains("101"));
+        assert!(patient.link[0].other.reference.as_ref().and_then(|s| s.value.as_deref()).unwrap_or("").contains("101"));
+    }
+
+    #[test]
+    fn build_patient_emits_contact_for_contact_backed_links_only() {
+        let contact = |id: &str, linked: Option<&str>, active: bool| crate::domain::related_person::DomainRelatedPerson {
+            contact_id: id.to_string(),
+            demographic_no: "123".to_string(),
+            contact_type: if linked.is_some() { "1" } else { "2" }.to_string(),
+            linked_demographic_no: linked.map(str::to_string),
+            role: Some("Spouse".to_string()),
+            category: Some("personal".to_string()),
+            first_name: Some("Bob".to_string()),
+            last_name: Some("Smith".to_string()),
+            home_phone: Some("604-555-0100".to_string()),
+            work_phone: None,
+            cell_phone: None,
+            email: None,
+            substitute_decision_maker: false,
+            emergency_contact: true,
+            active,
+        };
+        let payload = DomainPatient {
+            demographic_no: "123".to_string(),
+            contacts: vec![
+                contact("1", None, true),
+                contact("2", Some("456"), true),
+                contact("3", None, false),
+            ],
+            ..Default::default()
+        };
+
+        let patient = build_patient(&payload, &fhir_cfg());
+        assert_eq!(patient.contact.len(), 1);
+        assert_eq!(patient.contact[0].relationship.len(), 2);
+        assert_eq!(
+            patient.contact[0].name.as_ref().and_then(|n| n.family.as_ref()).and_then(|f| f.value.as_deref()),
+            Some("Smith")
+        );
     }
 
     #[test]
@@ -1949,7 +2965,7 @@ mod tests {
         assert_eq!(p.birth_date.as_ref().and_then(|d| d.value.as_deref()), Some("1980-04-15"));
         assert_eq!(p.telecom.len(), 3);
         assert_eq!(p.address.len(), 1);
-        assert_eq!(p.active.as_ref().map(|b| b.value).flatten(), Some(true));
+        assert_eq!(p.active.as_ref().and_then(|b| b.value), Some(true));
     }
 
     #[test]
@@ -1973,7 +2989,7 @@ mod tests {
         };
 
         let p = build_practitioner(&payload, &fhir_cfg());
-        assert_eq!(p.active.as_ref().map(|b| b.value).flatten(), Some(false));
+        assert_eq!(p.active.as_ref().and_then(|b| b.value), Some(false));
     }
 
     #[test]
@@ -1986,19 +3002,13 @@ mod tests {
         let event = SyncEvent::new(
             Source::OscarBinlog { table: "demographic".to_string() },
             Op::Upsert,
-            DomainResource::Patient(DomainPatient {
+            DomainResource::Patient(Box::new(DomainPatient {
                 demographic_no: "123".to_string(),
                 first_name: Some("Alice".to_string()),
                 last_name: Some("Smith".to_string()),
-                date_of_birth: None,
-                addresses: Vec::new(),
-                patient_status: None,
-                merged_to: None,
-                sex: None,
-                phone: None,
                 email: Some("alice@example.com".to_string()),
-                hin: None,
-            }),
+                ..Default::default()
+            })),
             chrono::Utc::now(),
         );
 
@@ -2019,19 +3029,10 @@ mod tests {
         let event = SyncEvent::new(
             Source::OscarBinlog { table: "demographic".to_string() },
             Op::Upsert,
-            DomainResource::Patient(DomainPatient {
+            DomainResource::Patient(Box::new(DomainPatient {
                 demographic_no: "121".to_string(),
-                first_name: None,
-                last_name: None,
-                date_of_birth: None,
-                addresses: Vec::new(),
-                patient_status: None,
-                merged_to: None,
-                sex: None,
-                phone: None,
-                email: None,
-                hin: None,
-            }),
+                ..Default::default()
+            })),
             chrono::Utc::now(),
         );
 
@@ -2048,6 +3049,23 @@ mod tests {
         assert!(!url.contains('|'), "expected no literal pipe in URL: {url}");
     }
 
+    #[tokio::test]
+    async fn ignored_delete_is_not_applied() {
+        let cfg: Config = toml::from_str("[fhir.delete_policy]\nPatient = \"ignore\"").unwrap();
+        let event = SyncEvent::new(
+            Source::OscarBinlog { table: "demographic".to_string() },
+            Op::Delete,
+            DomainResource::Patient(Box::new(DomainPatient {
+                demographic_no: "121".to_string(),
+                ..Default::default()
+            })),
+            chrono::Utc::now(),
+        );
+
+        let result = sync_one(&reqwest::Client::new(), &cfg, None, &event).await.unwrap();
+        assert!(!result.applied);
+    }
+
     async fn run_with_http_status(status_line: &str, max_attempts: u32) -> (Result<(), SyncFailure>, u32) {
         let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
         let port = listener.local_addr().unwrap().port();
@@ -2059,45 +3077,42 @@ mod tests {
         );
 
         tokio::spawn(async move {
-            loop {
-                match tokio::time::timeout(Duration::from_millis(100), listener.accept()).await {
-                    Ok(Ok((mut socket, _))) => {
-                        let mut buf = [0u8; 4096];
-                        let mut pos = 0;
-                        let mut header_end = None;
-                        while header_end.is_none() {
-                            let n = socket.read(&mut buf[pos..]).await.unwrap();
-                            pos += n;
-                            if let Some(i) =
-                                buf[..pos].windows(4).position(|w| w == b"\r\n\r\n")
-                            {
-                                header_end = Some(i + 4);
-                            }
-                        }
-                        let header_end = header_end.unwrap();
-                        let header = String::from_utf8_lossy(&buf[..header_end]);
-                        let content_len = header
-                            .to_lowercase()
-                            .lines()
-                            .find_map(|l| {
-                                l.strip_prefix("content-length:")
-                                    .and_then(|v| v.trim().parse::<usize>().ok())
-                            });
-                        if let Some(len) = content_len {
-                            let body_have = pos.saturating_sub(header_end);
-                            let mut remaining = len.saturating_sub(body_have);
-                            while remaining > 0 {
-                                let to_read = remaining.min(4096);
-                                let n = socket.read(&mut buf[..to_read]).await.unwrap();
-                                remaining -= n;
-                            }
-                        }
-
-                        a.fetch_add(1, Ordering::Relaxed);
-                        socket.write_all(response.as_bytes()).await.unwrap();
+            while let Ok(Ok((mut socket, _))) =
+                tokio::time::timeout(Duration::from_millis(100), listener.accept()).await
+            {
+                let mut buf = [0u8; 4096];
+                let mut pos = 0;
+                let mut header_end = None;
+                while header_end.is_none() {
+                    let n = socket.read(&mut buf[pos..]).await.unwrap();
+                    pos += n;
+                    if let Some(i) =
+                        buf[..pos].windows(4).position(|w| w == b"\r\n\r\n")
+                    {
+                        header_end = Some(i + 4);
+                    }
+                }
+                let header_end = header_end.unwrap();
+                let header = String::from_utf8_lossy(&buf[..header_end]);
+                let content_len = header
+                    .to_lowercase()
+                    .lines()
+                    .find_map(|l| {
+                        l.strip_prefix("content-length:")
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                    });
+                if let Some(len) = content_len {
+                    let body_have = pos.saturating_sub(header_end);
+                    let mut remaining = len.saturating_sub(body_have);
+                    while remaining > 0 {
+                        let to_read = remaining.min(4096);
+                        let n = socket.read(&mut buf[..to_read]).await.unwrap();
+                        remaining -= n;
                     }
-                    _ => break,
                 }
+
+                a.fetch_add(1, Ordering::Relaxed);
+                socket.write_all(response.as_bytes()).await.unwrap();
             }
         });
 
@@ -2129,31 +3144,34 @@ mod tests {
                 retry_max_attempts: max_attempts,
                 retry_base_ms: 1,
                 dead_letter_path: "".into(),
+                batch_max_events: 1,
+                batch_max_wait_ms: 0,
+                workers: 1,
+                worker_queue_capacity: 1,
+                content_hash_path: None,
+                force_resync: false,
+                durable_queue: false,
+                state_dir: "".into(),
+                queue_segment_bytes: 1024,
+                parking_path: None,
+                parking_ttl_secs: 60,
             },
             replication: ReplicationConfig::default(),
             dispatch: DispatchConfig::default(),
             oscar_enabled: true,
             oscar: OscarConfig::default(),
             writeback: WritebackConfig::default(),
+            terminology: Default::default(),
             debug: None,
         };
 
         let event = SyncEvent::new(
             Source::OscarBinlog { table: "demographic".to_string() },
             Op::Upsert,
-            DomainResource::Patient(DomainPatient {
+            DomainResource::Patient(Box::new(DomainPatient {
                 demographic_no: "121".into(),
-                first_name: None,
-                last_name: None,
-                date_of_birth: None,
-                addresses: Vec::new(),
-                patient_status: None,
-                merged_to: None,
-                sex: None,
-                phone: None,
-                email: None,
-                hin: None,
-            }),
+                ..Default::default()
+            })),
             chrono::Utc::now(),
         );
 
@@ -2185,7 +3203,10 @@ mod tests {
         let ct = build_care_team(
             &DomainCareTeam {
                 demographic_no: "101".to_string(),
-                provider_no: "P-001".to_string(),
+                participants: vec![DomainCareTeamParticipant {
+                    role: CareTeamRole::Mrp,
+                    member: CareTeamMember::Provider("P-001".to_string()),
+                }],
             },
             &cfg,
         );
@@ -2240,25 +3261,87 @@ mod tests {
         );
     }
 
+    fn care_team_participant(role: CareTeamRole, member: CareTeamMember) -> DomainCareTeamParticipant {
+        DomainCareTeamParticipant { role, member }
+    }
+
+    #[test]
+    fn build_care_team_participant_uses_role_code_and_member_system() {
+        let cfg = fhir_cfg();
+        let p = build_care_team_participant(
+            &care_team_participant(CareTeamRole::ReferralDoctor, CareTeamMember::ReferralDoctor("12345".to_string())),
+            &cfg,
+        );
+        assert_eq!(
+            p.role[0].coding[0].code.as_ref().and_then(|c| c.value.as_deref()),
+            Some("158965000")
+        );
+        assert_eq!(
+            p.member.as_ref().and_then(|m| m.reference.as_ref()).and_then(|r| r.value.as_deref()),
+            Some("Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-referral-doctor|12345")
+        );
+        assert_eq!(managed_care_team_role(&p), Some(CareTeamRole::ReferralDoctor));
+    }
+
+    #[test]
+    fn reconcile_care_team_adds_removes_and_keeps_unmanaged() {
+        let cfg = fhir_cfg();
+        let old_mrp = build_care_team_participant(
+            &care_team_participant(CareTeamRole::Mrp, CareTeamMember::Provider("100".to_string())),
+            &cfg,
+        );
+        // Nurse stored by HAPI as a literal reference after resolution.
+        let mut nurse = build_care_team_participant(
+            &care_team_participant(CareTeamRole::Nurse, CareTeamMember::Provider("201".to_string())),
+            &cfg,
+        );
+        nurse.member = Some(Box::new(Reference {
+            reference: Some("Practitioner/55".to_string().into()),
+            ..Default::default()
+        }));
+        let unmanaged = CareTeamParticipant {
+            member: Some(Box::new(Reference {
+                reference: Some("Practitioner/99".to_string().into()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        let new_mrp = care_team_participant(CareTeamRole::Mrp, CareTeamMember::Provider("101".to_string()));
+        let desired_nurse = care_team_participant(CareTeamRole::Nurse, CareTeamMember::Provider("201".to_string()));
+        let desired = vec![
+            (&new_mrp, HashSet::new()),
+            (&desired_nurse, HashSet::from(["55".to_string()])),
+        ];
+
+        let out = reconcile_care_team_participants(&[old_mrp, nurse, unmanaged], &desired, &cfg).unwrap();
+        let refs: Vec<&str> = out
+            .iter()
+            .filter_map(|p| p.member.as_ref().and_then(|m| m.reference.as_ref()).and_then(|r| r.value.as_deref()))
+            .collect();
+        assert_eq!(
+            refs,
+            vec![
+                "Practitioner/55",
+                "Practitioner/99",
+                "Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-provider|101",
+            ]
+        );
+
+        // Reconciling the result again is a no-op.
+        assert!(reconcile_care_team_participants(&out, &desired, &cfg).is_none());
+    }
+
     #[test]
     fn care_team_idempotency_key_differs_from_patient_for_same_row() {
         let now = chrono::Utc::now();
         let patient_event = SyncEvent::new(
             Source::OscarBinlog { table: "demographic".to_string() },
             Op::Upsert,
-            DomainResource::Patient(DomainPatient {
+            DomainResource::Patient(Box::new(DomainPatient {
                 demographic_no: "101".to_string(),
-                first_name: None,
-                last_name: None,
-                date_of_birth: None,
-                addresses: Vec::new(),
-                patient_status: None,
-                merged_to: None,
-                sex: None,
-                phone: None,
-                email: None,
-                hin: None,
-            }),
+                ..Default::default()
+            })),
             now,
         );
         let care_team_event = SyncEvent::new(
@@ -2266,7 +3349,10 @@ mod tests {
             Op::Upsert,
             DomainResource::CareTeam(DomainCareTeam {
                 demographic_no: "101".to_string(),
-                provider_no: "P-001".to_string(),
+                participants: vec![DomainCareTeamParticipant {
+                    role: CareTeamRole::Mrp,
+                    member: CareTeamMember::Provider("P-001".to_string()),
+                }],
             }),
             now,
         );
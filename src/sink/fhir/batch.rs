@@ -0,0 +1,299 @@
+//! Batched sink writes: several events' conditional PUTs in one transaction
+//! Bundle (`sync.batch_max_events`).
+//!
+//! HAPI answers a transaction with one response entry per request entry, in
+//! order, so each event's result is read back from its own entry. If the
+//! batch fails as a whole, every event in it goes through `sync_with_retry`
+//! on its own, so one bad resource only dead-letters itself. An event that
+//! can't go in a bundle (e.g. a hard delete) closes it: it and everything
+//! after it are sent one by one, in order.
+
+use std::collections::HashSet;
+
+use fhirbolt::model::r4b::resources::{Bundle, BundleEntry};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use tracing::warn;
+
+use crate::auth::TokenProvider;
+use crate::config::Config;
+use crate::domain::resource::DomainResource;
+use crate::event::SyncEvent;
+use crate::metrics::SharedMetrics;
+
+use super::delete::skips_put;
+use super::oscar2::{build_conditional_put_bundle, response_entry_result, send_transaction};
+use super::{
+    billing, build_appointment, build_appointment_bundle, build_patient, build_practitioner, communication,
+    episode_of_care, location, organization, oscar2, practitioner_role, questionnaire, referral_doctor,
+    related_person, request_token, schedule, specialist, sync_with_retry, waiting_list, FhirResult, SyncFailure,
+};
+
+/// Syncs `events` in order and returns one outcome per event.
+pub(super) async fn sync_batch(
+    client: &reqwest::Client,
+    cfg: &Config,
+    token_provider: Option<&TokenProvider>,
+    events: &[SyncEvent],
+    metrics: &SharedMetrics,
+) -> Vec<Result<FhirResult, SyncFailure>> {
+    let mut outcomes: Vec<Option<Result<FhirResult, SyncFailure>>> = events.iter().map(|_| None).collect();
+
+    if events.len() > 1 {
+        let (bundle, batched) = plan_batch(cfg, events, &mut outcomes);
+        if batched.len() > 1 {
+            match send_batch(client, cfg, token_provider, &bundle, &batched).await {
+                Ok(results) => {
+                    for ((i, _), result) in batched.iter().zip(results) {
+                        outcomes[*i] = Some(Ok(result));
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "fhir sink: transaction batch of {} events failed, retrying one by one: {e}",
+                        batched.len()
+                    );
+                    metrics.inc_batch_fallbacks();
+                }
+            }
+        }
+    }
+
+    let mut results = Vec::with_capacity(events.len());
+    for (event, outcome) in events.iter().zip(outcomes) {
+        let outcome = match outcome {
+            Some(outcome) => outcome,
+            None => sync_with_retry(client, cfg, token_provider, event, metrics).await,
+        };
+        results.push(outcome);
+    }
+    results
+}
+
+/// The transaction Bundle for the leading run of `events` that can share
+/// one, with (event index, its first entry) for each. Build failures are
+/// recorded in `outcomes`.
+///
+/// The bundle closes at the first event that has to be sent on its own, so
+/// nothing after it is written ahead of it.
+fn plan_batch(
+    cfg: &Config,
+    events: &[SyncEvent],
+    outcomes: &mut [Option<Result<FhirResult, SyncFailure>>],
+) -> (Bundle, Vec<(usize, usize)>) {
+    let mut bundle = Bundle::default();
+    bundle.r#type = "transaction".into();
+    let mut batched: Vec<(usize, usize)> = Vec::new();
+    let mut urls: HashSet<String> = HashSet::new();
+
+    for (i, event) in events.iter().enumerate() {
+        // If-Match writes read before they write; sent on their own below.
+        if cfg.fhir.if_match_writes
+            && matches!(event.payload(), DomainResource::Patient(_) | DomainResource::Practitioner(_))
+        {
+            break;
+        }
+        // Hard and ignored deletes aren't PUTs; sent on their own below.
+        if skips_put(&cfg.fhir, event) {
+            break;
+        }
+        let entries = match event_bundle(cfg, event) {
+            Some(Ok(b)) => b.entry,
+            Some(Err(e)) => {
+                outcomes[i] = Some(Err(e));
+                continue;
+            }
+            // Not a plain conditional PUT; sent on its own below.
+            None => break,
+        };
+        // A transaction can't touch the same resource twice; the second
+        // change to it closes the bundle, like any event sent on its own.
+        let entry_urls: Vec<String> = entries.iter().filter_map(entry_url).collect();
+        if entries.is_empty() || entry_urls.iter().any(|u| urls.contains(u)) {
+            break;
+        }
+        urls.extend(entry_urls);
+        batched.push((i, bundle.entry.len()));
+        bundle.entry.extend(entries);
+    }
+    (bundle, batched)
+}
+
+/// Sends one batch and reads each event's result from its response entry.
+async fn send_batch(
+    client: &reqwest::Client,
+    cfg: &Config,
+    token_provider: Option<&TokenProvider>,
+    bundle: &Bundle,
+    batched: &[(usize, usize)],
+) -> Result<Vec<FhirResult>, SyncFailure> {
+    let token = request_token(&cfg.fhir, token_provider).await?;
+    let response = send_transaction(client, &cfg.fhir, token, bundle)
+        .await?
+        .unwrap_or_default();
+    batched
+        .iter()
+        .map(|(_, entry)| match response.entry.get(*entry) {
+            Some(entry) => response_entry_result(entry),
+            None => Ok(FhirResult {
+                fhir_id: String::new(),
+                version_id: None,
+                applied: true,
+            }),
+        })
+        .collect()
+}
+
+/// The transaction `sync_one` would send for `event`, or `None` for
+/// resources that need more than one request (CareTeam's read-modify-write).
+pub(super) fn event_bundle(cfg: &Config, event: &SyncEvent) -> Option<Result<Bundle, SyncFailure>> {
+    let fhir_cfg = &cfg.fhir;
+    let oscar_cfg = &cfg.oscar;
+    let bundle = match event.payload() {
+        DomainResource::Patient(patient) => {
+            let fhir_patient = build_patient(patient, fhir_cfg);
+            Ok(build_conditional_put_bundle(
+                fhir_cfg,
+                FhirResource::Patient(Box::new(fhir_patient)),
+                &fhir_cfg.oscar_demographic_system,
+                event,
+            ))
+        }
+        DomainResource::Practitioner(practitioner) => {
+            let fhir_practitioner = build_practitioner(practitioner, fhir_cfg);
+            Ok(build_conditional_put_bundle(
+                fhir_cfg,
+                FhirResource::Practitioner(Box::new(fhir_practitioner)),
+                &fhir_cfg.oscar_provider_system,
+                event,
+            ))
+        }
+        DomainResource::Appointment(appointment) => build_appointment(appointment, fhir_cfg, oscar_cfg, event.op())
+            .map(|a| build_appointment_bundle(&a, fhir_cfg, event)),
+        DomainResource::CareTeam(_) => return None,
+        DomainResource::Encounter(e) => oscar2::encounter_bundle(fhir_cfg, event, e, oscar_cfg),
+        DomainResource::DocumentReference(d) => oscar2::document_reference_bundle(fhir_cfg, event, d, oscar_cfg),
+        DomainResource::Condition(c) => oscar2::condition_bundle(fhir_cfg, event, c),
+        DomainResource::FamilyMemberHistory(f) => oscar2::family_member_history_bundle(fhir_cfg, event, f),
+        DomainResource::DiagnosticReport(r) => oscar2::diagnostic_report_bundle(fhir_cfg, event, r, oscar_cfg),
+        DomainResource::ServiceRequest(r) => oscar2::service_request_bundle(fhir_cfg, event, r, oscar_cfg),
+        DomainResource::RelatedPerson(r) => related_person::related_person_bundle(fhir_cfg, event, r),
+        DomainResource::Specialist(s) => specialist::specialist_bundle(fhir_cfg, event, s),
+        DomainResource::Organization(o) => organization::organization_bundle(fhir_cfg, event, o),
+        DomainResource::Schedule(s) => schedule::schedule_bundle(fhir_cfg, event, s),
+        DomainResource::Slot(s) => schedule::slot_bundle(fhir_cfg, event, s, oscar_cfg),
+        DomainResource::EpisodeOfCare(e) => episode_of_care::episode_of_care_bundle(fhir_cfg, event, e, oscar_cfg),
+        DomainResource::Location(l) => location::location_bundle(fhir_cfg, event, l),
+        DomainResource::PractitionerRole(r) => {
+            practitioner_role::practitioner_role_bundle(fhir_cfg, event, r, oscar_cfg)
+        }
+        DomainResource::Questionnaire(q) => questionnaire::questionnaire_bundle(fhir_cfg, event, q, oscar_cfg),
+        DomainResource::QuestionnaireResponse(r) => {
+            questionnaire::questionnaire_response_bundle(fhir_cfg, event, r, oscar_cfg)
+        }
+        DomainResource::Communication(c) => communication::communication_bundle(fhir_cfg, event, c, oscar_cfg),
+        DomainResource::Claim(c) => billing::claim_bundle(fhir_cfg, event, c, oscar_cfg),
+        DomainResource::ChargeItem(c) => billing::charge_item_bundle(fhir_cfg, event, c, oscar_cfg),
+        DomainResource::WaitingListAppointment(e) | DomainResource::WaitingListRequest(e) => {
+            waiting_list::waiting_list_entry_bundle(fhir_cfg, event, e, oscar_cfg)
+        }
+        DomainResource::ReferralDoctor(d) => referral_doctor::referral_doctor_bundle(fhir_cfg, event, d),
+    };
+    Some(bundle)
+}
+
+fn entry_url(entry: &BundleEntry) -> Option<String> {
+    entry.request.as_ref().and_then(|r| r.url.value.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::appointment::DomainAppointment;
+    use crate::event::{Op, Source};
+
+    /// Appointments need the Oscar timezone to build.
+    fn config(extra: &str) -> Config {
+        toml::from_str(&format!("[oscar]\ntimezone = \"America/Vancouver\"\n{extra}")).unwrap()
+    }
+
+    fn event(appointment_no: &str) -> SyncEvent {
+        event_with_op(appointment_no, Op::Upsert)
+    }
+
+    fn event_with_op(appointment_no: &str, op: Op) -> SyncEvent {
+        let appointment = DomainAppointment {
+            appointment_no: appointment_no.to_string(),
+            ..super::super::tests::appt_payload()
+        };
+        SyncEvent::new(
+            Source::OscarBinlog {
+                table: "appointment".to_string(),
+            },
+            op,
+            DomainResource::Appointment(appointment),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[test]
+    fn event_bundle_matches_conditional_put() {
+        let cfg = config("");
+        let a = event_bundle(&cfg, &event("1")).unwrap().unwrap();
+        let b = event_bundle(&cfg, &event("2")).unwrap().unwrap();
+
+        let urls: Vec<Option<String>> = a.entry.iter().chain(&b.entry).map(entry_url).collect();
+        assert_eq!(urls.len(), 2);
+        assert!(urls[0].as_deref().unwrap().starts_with("Appointment?identifier="));
+        assert_ne!(urls[0], urls[1]);
+    }
+
+    #[test]
+    fn bundle_closes_before_a_delete_sent_on_its_own() {
+        let cfg = config("[fhir.delete_policy]\nAppointment = \"hard_delete\"\n");
+        let events = [event("1"), event_with_op("2", Op::Delete), event("2"), event("3")];
+        let mut outcomes: Vec<Option<Result<FhirResult, SyncFailure>>> = events.iter().map(|_| None).collect();
+
+        // The upsert of 2 must not be committed ahead of its delete.
+        let (bundle, batched) = plan_batch(&cfg, &events, &mut outcomes);
+        assert_eq!(batched, vec![(0, 0)]);
+        assert_eq!(bundle.entry.len(), 1);
+        assert!(outcomes.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn bundle_closes_before_an_if_match_write() {
+        let cfg = config("[fhir]\nif_match_writes = true\n");
+        let patient = SyncEvent::new(
+            Source::OscarBinlog {
+                table: "demographic".to_string(),
+            },
+            Op::Upsert,
+            DomainResource::Patient(
+                serde_json::from_value(serde_json::json!({
+                    "demographic_no": "101",
+                    "addresses": [],
+                    "contacts": [],
+                }))
+                .unwrap(),
+            ),
+            chrono::Utc::now(),
+        );
+        let events = [event("1"), event("2"), patient, event("3")];
+        let mut outcomes: Vec<Option<Result<FhirResult, SyncFailure>>> = events.iter().map(|_| None).collect();
+
+        // Appointment 3 may reference the new Patient, so it waits for it.
+        let (_, batched) = plan_batch(&cfg, &events, &mut outcomes);
+        assert_eq!(batched.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn bundle_closes_at_a_repeated_resource() {
+        let cfg = config("");
+        let events = [event("1"), event("2"), event("1"), event("3")];
+        let mut outcomes: Vec<Option<Result<FhirResult, SyncFailure>>> = events.iter().map(|_| None).collect();
+
+        // Appointment 3 mustn't commit ahead of the second change to 1.
+        let (_, batched) = plan_batch(&cfg, &events, &mut outcomes);
+        assert_eq!(batched.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![0, 1]);
+    }
+}
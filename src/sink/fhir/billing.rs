@@ -0,0 +1,418 @@
+//! Oscar billing -> FHIR `Claim` / `ChargeItem`.
+//!
+//! By default each billing header becomes one `Claim` with an item per
+//! service line; with `oscar.billing_resource = "charge_item"` each line is
+//! published as its own `ChargeItem` instead. Status codes go through
+//! `oscar.billing_status_map`, which speaks `ChargeItem.status`; the coarser
+//! `Claim.status` is derived from it.
+
+use fhirbolt::model::r4b::resources::{
+    Bundle, ChargeItem, ChargeItemOccurrence, ChargeItemPerformer, Claim, ClaimDiagnosis,
+    ClaimDiagnosisDiagnosis, ClaimInsurance, ClaimItem, ClaimItemServiced,
+};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{CodeableConcept, Coding, Identifier, Meta, Money, Quantity, Reference};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::claim::{BillingSource, DomainChargeItem, DomainClaim, DomainClaimItem};
+use crate::event::Op;
+
+use super::oscar2::{
+    build_conditional_put_bundle, encounter_ref, patient_ref, practitioner_ref, send_transaction_bundle,
+};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+const CLAIM_TYPE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/claim-type";
+const PROCESS_PRIORITY_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/processpriority";
+const CURRENCY: &str = "CAD";
+
+pub(super) async fn sync_claim(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    claim: &DomainClaim,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let identifier_system = claim_identifier_system(claim.source, fhir_cfg);
+    let bundle = claim_bundle(fhir_cfg, event, claim, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", identifier_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn claim_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    claim: &DomainClaim,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let identifier_system = claim_identifier_system(claim.source, fhir_cfg);
+    let fhir_claim = build_claim(claim, fhir_cfg, oscar_cfg, event.op())?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Claim(Box::new(fhir_claim)),
+        identifier_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+pub(super) async fn sync_charge_item(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    charge: &DomainChargeItem,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let identifier_system = charge_item_identifier_system(charge.source, fhir_cfg);
+    let bundle = charge_item_bundle(fhir_cfg, event, charge, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", identifier_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn charge_item_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    charge: &DomainChargeItem,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let identifier_system = charge_item_identifier_system(charge.source, fhir_cfg);
+    let fhir_charge = build_charge_item(charge, fhir_cfg, oscar_cfg, event.op())?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::ChargeItem(Box::new(fhir_charge)),
+        identifier_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Identifier system for a Claim, chosen by billing module.
+pub(super) fn claim_identifier_system(source: BillingSource, fhir_cfg: &FhirConfig) -> &String {
+    match source {
+        BillingSource::Bc => &fhir_cfg.oscar_billing_system,
+        BillingSource::On => &fhir_cfg.oscar_billing_on_system,
+    }
+}
+
+/// Identifier system for a ChargeItem, chosen by billing module.
+pub(super) fn charge_item_identifier_system(source: BillingSource, fhir_cfg: &FhirConfig) -> &String {
+    match source {
+        BillingSource::Bc => &fhir_cfg.oscar_billingmaster_system,
+        BillingSource::On => &fhir_cfg.oscar_billing_on_item_system,
+    }
+}
+
+fn service_code_system(source: BillingSource, fhir_cfg: &FhirConfig) -> &String {
+    match source {
+        BillingSource::Bc => &fhir_cfg.msp_service_code_system,
+        BillingSource::On => &fhir_cfg.ohip_service_code_system,
+    }
+}
+
+/// Maps an Oscar billing status code to a `ChargeItem.status`. A deleted
+/// row is always `entered-in-error`; a missing code is still `billable`.
+fn charge_item_status(
+    code: Option<&str>,
+    active: bool,
+    op: Op,
+    oscar_cfg: &OscarConfig,
+) -> Result<String, SyncFailure> {
+    if !active || op == Op::Delete {
+        return Ok("entered-in-error".to_string());
+    }
+    let Some(code) = code else {
+        return Ok("billable".to_string());
+    };
+    oscar_cfg
+        .billing_status_map
+        .get(code)
+        .cloned()
+        .ok_or_else(|| SyncFailure::Permanent(anyhow::anyhow!("unmapped_billing_status: {code}")))
+}
+
+/// `Claim.status` only distinguishes live, withdrawn and erroneous claims.
+fn claim_status(charge_item_status: &str) -> &'static str {
+    match charge_item_status {
+        "entered-in-error" => "entered-in-error",
+        "not-billable" | "aborted" => "cancelled",
+        "planned" => "draft",
+        _ => "active",
+    }
+}
+
+fn money(value: &str) -> Money {
+    Money {
+        value: Some(value.to_string().into()),
+        currency: Some(CURRENCY.to_string().into()),
+        ..Default::default()
+    }
+}
+
+fn coded(system: &str, code: &str) -> CodeableConcept {
+    CodeableConcept {
+        coding: vec![Coding {
+            system: Some(system.to_string().into()),
+            code: Some(code.to_string().into()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn units(item: &DomainClaimItem) -> Option<Box<Quantity>> {
+    item.units.as_ref().map(|u| {
+        Box::new(Quantity {
+            value: Some(u.clone().into()),
+            ..Default::default()
+        })
+    })
+}
+
+/// Builds the `Claim`. Diagnostic codes are collected across lines into
+/// `Claim.diagnosis`, and each item points back at its own codes through
+/// `diagnosisSequence`. Coverage is the provincial plan, carried by display
+/// only since Oscar has no Coverage resource to reference.
+fn build_claim(
+    claim: &DomainClaim,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<Claim, SyncFailure> {
+    let status = charge_item_status(claim.status_code.as_deref(), claim.active, op, oscar_cfg)?;
+
+    let mut c = Claim::default();
+
+    c.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    c.identifier.push(Identifier {
+        system: Some(claim_identifier_system(claim.source, fhir_cfg).clone().into()),
+        value: Some(claim.claim_id.clone().into()),
+        ..Default::default()
+    });
+
+    c.status = claim_status(&status).to_string().into();
+    c.r#type = Box::new(coded(CLAIM_TYPE_SYSTEM, "professional"));
+    c.r#use = "claim".to_string().into();
+    c.patient = Box::new(patient_ref(fhir_cfg, &claim.demographic_no));
+    c.priority = Box::new(coded(PROCESS_PRIORITY_SYSTEM, "normal"));
+    if let Some(created) = &claim.created {
+        c.created = created.clone().into();
+    }
+    if let Some(provider_no) = &claim.provider_no {
+        c.provider = Box::new(practitioner_ref(fhir_cfg, provider_no));
+    }
+
+    let plan = match claim.source {
+        BillingSource::Bc => "MSP",
+        BillingSource::On => "OHIP",
+    };
+    c.insurance.push(ClaimInsurance {
+        sequence: 1u32.into(),
+        focal: true.into(),
+        coverage: Box::new(Reference {
+            display: Some(plan.to_string().into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let mut dx_codes: Vec<&str> = Vec::new();
+    for code in claim.items.iter().flat_map(|i| i.dx_codes.iter()) {
+        if !dx_codes.contains(&code.as_str()) {
+            dx_codes.push(code);
+        }
+    }
+    c.diagnosis = dx_codes
+        .iter()
+        .enumerate()
+        .map(|(i, code)| ClaimDiagnosis {
+            sequence: (i as u32 + 1).into(),
+            diagnosis: ClaimDiagnosisDiagnosis::CodeableConcept(Box::new(coded(
+                &fhir_cfg.billing_diagnosis_code_system,
+                code,
+            ))),
+            ..Default::default()
+        })
+        .collect();
+
+    let service_code_system = service_code_system(claim.source, fhir_cfg);
+    let encounter = claim.encounter_value.as_deref().map(|e| encounter_ref(fhir_cfg, e));
+    c.item = claim
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| ClaimItem {
+            sequence: (i as u32 + 1).into(),
+            diagnosis_sequence: item
+                .dx_codes
+                .iter()
+                .filter_map(|code| dx_codes.iter().position(|d| d == code))
+                .map(|p| (p as u32 + 1).into())
+                .collect(),
+            product_or_service: Box::new(coded(service_code_system, &item.service_code)),
+            serviced: item
+                .service_date
+                .as_ref()
+                .or(claim.service_date.as_ref())
+                .map(|d| ClaimItemServiced::Date(d.clone().into())),
+            quantity: units(item),
+            net: item.fee.as_deref().map(|f| Box::new(money(f))),
+            encounter: encounter.iter().cloned().collect(),
+            ..Default::default()
+        })
+        .collect();
+
+    c.total = claim.total.as_deref().map(|t| Box::new(money(t)));
+
+    Ok(c)
+}
+
+/// Builds a `ChargeItem` for one billing line.
+fn build_charge_item(
+    charge: &DomainChargeItem,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<ChargeItem, SyncFailure> {
+    let status = charge_item_status(charge.status_code.as_deref(), charge.active, op, oscar_cfg)?;
+    let item = &charge.item;
+
+    let mut ci = ChargeItem::default();
+
+    ci.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    ci.identifier.push(Identifier {
+        system: Some(charge_item_identifier_system(charge.source, fhir_cfg).clone().into()),
+        value: Some(item.line_id.clone().into()),
+        ..Default::default()
+    });
+
+    ci.status = status.into();
+    ci.code = Box::new(coded(service_code_system(charge.source, fhir_cfg), &item.service_code));
+    ci.subject = Box::new(patient_ref(fhir_cfg, &charge.demographic_no));
+    ci.context = charge
+        .encounter_value
+        .as_deref()
+        .map(|e| Box::new(encounter_ref(fhir_cfg, e)));
+    ci.occurrence = item
+        .service_date
+        .as_ref()
+        .map(|d| ChargeItemOccurrence::DateTime(d.clone().into()));
+    if let Some(provider_no) = &charge.provider_no {
+        ci.performer.push(ChargeItemPerformer {
+            actor: Box::new(practitioner_ref(fhir_cfg, provider_no)),
+            ..Default::default()
+        });
+    }
+    ci.quantity = units(item);
+    ci.price_override = item.fee.as_deref().map(|f| Box::new(money(f)));
+    ci.reason = item
+        .dx_codes
+        .iter()
+        .map(|code| coded(&fhir_cfg.billing_diagnosis_code_system, code))
+        .collect();
+
+    Ok(ci)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim() -> DomainClaim {
+        DomainClaim {
+            claim_id: "500".to_string(),
+            source: BillingSource::Bc,
+            demographic_no: "123".to_string(),
+            provider_no: Some("1001".to_string()),
+            appointment_no: Some("88".to_string()),
+            encounter_value: Some("note-uuid-1".to_string()),
+            service_date: Some("2026-03-02".to_string()),
+            created: Some("2026-03-04".to_string()),
+            status_code: Some("B".to_string()),
+            total: Some("60.00".to_string()),
+            items: vec![
+                DomainClaimItem {
+                    line_id: "900".to_string(),
+                    service_code: "00100".to_string(),
+                    dx_codes: vec!["250".to_string(), "401".to_string()],
+                    fee: Some("30.00".to_string()),
+                    units: Some("1".to_string()),
+                    service_date: None,
+                    status_code: None,
+                },
+                DomainClaimItem {
+                    line_id: "901".to_string(),
+                    service_code: "00101".to_string(),
+                    dx_codes: vec!["401".to_string()],
+                    fee: Some("30.00".to_string()),
+                    units: None,
+                    service_date: None,
+                    status_code: None,
+                },
+            ],
+            active: true,
+        }
+    }
+
+    #[test]
+    fn build_claim_maps_items_diagnoses_and_encounter() {
+        let c = build_claim(&claim(), &FhirConfig::default(), &OscarConfig::default(), Op::Upsert).unwrap();
+        assert_eq!(c.status.value.as_deref(), Some("active"));
+        assert_eq!(c.diagnosis.len(), 2);
+        assert_eq!(c.item.len(), 2);
+        assert_eq!(c.item[1].diagnosis_sequence.len(), 1);
+        assert_eq!(c.item[1].diagnosis_sequence[0].value, Some(2));
+        assert_eq!(
+            c.item[0].product_or_service.coding[0].code.as_ref().and_then(|c| c.value.as_deref()),
+            Some("00100")
+        );
+        assert_eq!(c.item[0].encounter.len(), 1);
+        assert_eq!(c.total.as_ref().and_then(|t| t.value.as_ref()).and_then(|v| v.value.as_deref()), Some("60.00"));
+    }
+
+    #[test]
+    fn claim_status_follows_billing_status_map() {
+        let mut cl = claim();
+        cl.status_code = Some("N".to_string());
+        let c = build_claim(&cl, &FhirConfig::default(), &OscarConfig::default(), Op::Upsert).unwrap();
+        assert_eq!(c.status.value.as_deref(), Some("cancelled"));
+
+        let c = build_claim(&claim(), &FhirConfig::default(), &OscarConfig::default(), Op::Delete).unwrap();
+        assert_eq!(c.status.value.as_deref(), Some("entered-in-error"));
+
+        cl.status_code = Some("?".to_string());
+        let err = build_claim(&cl, &FhirConfig::default(), &OscarConfig::default(), Op::Upsert);
+        assert!(matches!(err, Err(SyncFailure::Permanent(_))));
+    }
+
+    #[test]
+    fn build_charge_item_per_line() {
+        let charges = claim().into_charge_items();
+        let ci = build_charge_item(&charges[0], &FhirConfig::default(), &OscarConfig::default(), Op::Upsert).unwrap();
+        assert_eq!(ci.status.value.as_deref(), Some("billed"));
+        assert_eq!(ci.identifier[0].value.as_ref().and_then(|v| v.value.as_deref()), Some("900"));
+        assert!(ci.context.is_some());
+        assert_eq!(ci.reason.len(), 2);
+        assert!(matches!(ci.occurrence, Some(ChargeItemOccurrence::DateTime(_))));
+    }
+}
@@ -0,0 +1,166 @@
+//! `oscar.column_overrides`: custom Oscar column values written into the
+//! built resource after the standard mapping.
+//!
+//! Overrides are applied on the resource's JSON form, so any element path
+//! works without a builder knowing about it. A value that can't be applied
+//! is logged and the resource is sent as the standard mapping built it.
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use fhirbolt::model::r4b::Resource as FhirResource;
+use serde_json::{Map, Value};
+use tracing::warn;
+
+use crate::config::ColumnValueType;
+use crate::event::{ColumnOverrideValue, SyncEvent};
+
+pub(super) fn apply_column_overrides(resource: FhirResource, event: &SyncEvent) -> FhirResource {
+    if event.column_values().is_empty() {
+        return resource;
+    }
+    match try_apply(&resource, event.column_values()) {
+        Ok(Some(updated)) => updated,
+        Ok(None) => resource,
+        Err(err) => {
+            warn!(
+                "column overrides not applied to {} {}: {err:#}",
+                event.resource_type().as_path(),
+                event.payload().source_id()
+            );
+            resource
+        }
+    }
+}
+
+fn try_apply(resource: &FhirResource, values: &[ColumnOverrideValue]) -> Result<Option<FhirResource>> {
+    let json = fhirbolt::json::to_string(resource, None).context("serializing resource")?;
+    let mut json: Value = serde_json::from_str(&json).context("re-reading resource")?;
+    let resource_type = json
+        .get("resourceType")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    let mut applied = false;
+    for value in values.iter().filter(|v| v.spec.resource == resource_type) {
+        let object = json.as_object_mut().context("resource is not an object")?;
+        apply_one(object, value).with_context(|| format!("applying {}", value.spec.column))?;
+        applied = true;
+    }
+    if !applied {
+        return Ok(None);
+    }
+
+    let json = serde_json::to_string(&json).context("serializing overridden resource")?;
+    let resource = fhirbolt::json::from_str(&json, None).context("decoding overridden resource")?;
+    Ok(Some(resource))
+}
+
+fn apply_one(resource: &mut Map<String, Value>, value: &ColumnOverrideValue) -> Result<()> {
+    let (key, json_value) = typed_value(value.spec.value_type, &value.value)?;
+
+    if let Some(url) = &value.spec.extension_url {
+        let extensions = resource
+            .entry("extension")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .context("extension is not an array")?;
+        extensions.retain(|e| e.get("url").and_then(Value::as_str) != Some(url.as_str()));
+        let mut extension = Map::new();
+        extension.insert("url".to_string(), Value::String(url.clone()));
+        extension.insert(format!("value{key}"), json_value);
+        extensions.push(Value::Object(extension));
+        return Ok(());
+    }
+
+    let path = value.spec.element.as_deref().unwrap_or_default();
+    let mut segments: Vec<&str> = path.split('.').collect();
+    let leaf = segments.pop().unwrap_or_default();
+    let mut object = resource;
+    for segment in segments {
+        object = object
+            .entry(segment)
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .with_context(|| format!("{segment} in {path} is not a single object"))?;
+    }
+    object.insert(leaf.to_string(), json_value);
+    Ok(())
+}
+
+/// The `value[x]` suffix and JSON value for one column value.
+fn typed_value(value_type: ColumnValueType, raw: &str) -> Result<(&'static str, Value)> {
+    Ok(match value_type {
+        ColumnValueType::String => ("String", Value::String(raw.to_string())),
+        ColumnValueType::Code => ("Code", Value::String(raw.to_string())),
+        ColumnValueType::Date => {
+            let date = raw.get(..10).unwrap_or(raw);
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").with_context(|| format!("invalid date {raw:?}"))?;
+            ("Date", Value::String(date.to_string()))
+        }
+        ColumnValueType::Boolean => {
+            let flag = match raw.to_ascii_lowercase().as_str() {
+                "1" | "y" | "yes" | "true" => true,
+                "0" | "n" | "no" | "false" => false,
+                _ => anyhow::bail!("invalid boolean {raw:?}"),
+            };
+            ("Boolean", Value::Bool(flag))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ColumnOverride;
+
+    fn value(spec: ColumnOverride, value: &str) -> ColumnOverrideValue {
+        ColumnOverrideValue {
+            spec,
+            value: value.to_string(),
+        }
+    }
+
+    fn spec(element: Option<&str>, extension_url: Option<&str>, value_type: ColumnValueType) -> ColumnOverride {
+        ColumnOverride {
+            column: "demographic.custom".to_string(),
+            resource: "Patient".to_string(),
+            element: element.map(str::to_string),
+            extension_url: extension_url.map(str::to_string),
+            value_type,
+        }
+    }
+
+    #[test]
+    fn applies_extensions_and_elements() {
+        let mut patient = serde_json::json!({
+            "resourceType": "Patient",
+            "extension": [{"url": "urn:vip", "valueBoolean": false}]
+        });
+        let object = patient.as_object_mut().unwrap();
+
+        apply_one(object, &value(spec(None, Some("urn:vip"), ColumnValueType::Boolean), "Y")).unwrap();
+        apply_one(object, &value(spec(Some("deceasedDateTime"), None, ColumnValueType::Date), "2024-03-01 00:00:00")).unwrap();
+        apply_one(object, &value(spec(Some("maritalStatus.text"), None, ColumnValueType::String), "Married")).unwrap();
+
+        assert_eq!(
+            patient,
+            serde_json::json!({
+                "resourceType": "Patient",
+                "extension": [{"url": "urn:vip", "valueBoolean": true}],
+                "deceasedDateTime": "2024-03-01",
+                "maritalStatus": {"text": "Married"}
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_values_and_paths() {
+        let mut patient = serde_json::json!({"resourceType": "Patient", "name": [{"family": "Doe"}]});
+        let object = patient.as_object_mut().unwrap();
+
+        assert!(apply_one(object, &value(spec(None, Some("urn:vip"), ColumnValueType::Boolean), "maybe")).is_err());
+        assert!(apply_one(object, &value(spec(Some("birthDate"), None, ColumnValueType::Date), "0000-00-00")).is_err());
+        assert!(apply_one(object, &value(spec(Some("name.text"), None, ColumnValueType::String), "Jane")).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! Oscar messenger -> FHIR `Communication`.
+//!
+//! One Communication per `messagetbl` message, keyed on `messageid`. The
+//! first chart the message is attached to becomes `subject`; any further
+//! charts are listed under `about`, so a message filed against several
+//! patients shows up on each of them.
+
+use fhirbolt::model::r4b::resources::{Bundle, Communication, CommunicationPayload, CommunicationPayloadContent};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{CodeableConcept, Identifier, Meta};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::communication::DomainCommunication;
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, patient_ref, practitioner_ref, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_communication(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    communication: &DomainCommunication,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = communication_bundle(fhir_cfg, event, communication, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_message_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn communication_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    communication: &DomainCommunication,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_communication = build_communication(communication, fhir_cfg, oscar_cfg, event.op())?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Communication(Box::new(fhir_communication)),
+        &fhir_cfg.oscar_message_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Builds the `Communication`. A deleted message is `entered-in-error`
+/// rather than removed, so the chart keeps a trace of it.
+fn build_communication(
+    communication: &DomainCommunication,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<Communication, SyncFailure> {
+    let mut c = Communication::default();
+
+    c.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    c.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_message_system.clone().into()),
+        value: Some(communication.message_id.clone().into()),
+        ..Default::default()
+    });
+
+    let active = communication.active && op != Op::Delete;
+    c.status = if active { "completed" } else { "entered-in-error" }.to_string().into();
+
+    let mut patients = communication.demographic_nos.iter().map(|d| patient_ref(fhir_cfg, d));
+    c.subject = patients.next().map(Box::new);
+    c.about = patients.collect();
+
+    c.sender = communication
+        .sender_provider_no
+        .as_deref()
+        .map(|p| Box::new(practitioner_ref(fhir_cfg, p)));
+    c.recipient = communication
+        .recipient_provider_nos
+        .iter()
+        .map(|p| practitioner_ref(fhir_cfg, p))
+        .collect();
+
+    if let Some(date) = &communication.sent_date {
+        let sent = match (&communication.sent_time, oscar_cfg.timezone.as_deref()) {
+            (Some(time), Some(tz)) => super::to_appointment_instant(date, time, tz)?,
+            _ => date.clone(),
+        };
+        c.sent = Some(sent.into());
+    }
+
+    c.topic = communication.subject.clone().map(|subject| {
+        Box::new(CodeableConcept {
+            text: Some(subject.into()),
+            ..Default::default()
+        })
+    });
+
+    if let Some(body) = &communication.body {
+        c.payload.push(CommunicationPayload {
+            content: CommunicationPayloadContent::String(body.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    Ok(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oscar_cfg() -> OscarConfig {
+        OscarConfig {
+            timezone: Some("America/Vancouver".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn communication() -> DomainCommunication {
+        DomainCommunication {
+            message_id: "77".to_string(),
+            sender_provider_no: Some("1001".to_string()),
+            recipient_provider_nos: vec!["1002".to_string(), "1003".to_string()],
+            demographic_nos: vec!["123".to_string(), "456".to_string()],
+            sent_date: Some("2026-02-01".to_string()),
+            sent_time: Some("09:30:00".to_string()),
+            subject: Some("Lab results".to_string()),
+            body: Some("Please call the patient.".to_string()),
+            active: true,
+        }
+    }
+
+    #[test]
+    fn build_communication_maps_people_and_content() {
+        let c = build_communication(&communication(), &FhirConfig::default(), &oscar_cfg(), Op::Upsert).unwrap();
+        assert_eq!(c.status.value.as_deref(), Some("completed"));
+        assert_eq!(
+            c.subject.as_ref().and_then(|s| s.reference.as_ref()).and_then(|r| r.value.as_deref()),
+            Some("Patient?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-demographic|123")
+        );
+        assert_eq!(c.about.len(), 1);
+        assert!(c.sender.is_some());
+        assert_eq!(c.recipient.len(), 2);
+        assert_eq!(c.sent.as_ref().and_then(|s| s.value.as_deref()), Some("2026-02-01T09:30:00-08:00"));
+        assert_eq!(
+            c.topic.as_ref().and_then(|t| t.text.as_ref()).and_then(|t| t.value.as_deref()),
+            Some("Lab results")
+        );
+        assert_eq!(c.payload.len(), 1);
+    }
+
+    #[test]
+    fn deleted_message_is_entered_in_error() {
+        let c = build_communication(&communication(), &FhirConfig::default(), &oscar_cfg(), Op::Delete).unwrap();
+        assert_eq!(c.status.value.as_deref(), Some("entered-in-error"));
+    }
+}
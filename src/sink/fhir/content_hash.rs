@@ -0,0 +1,164 @@
+//! Content-hash store (`sync.content_hash_path`): skips a write when the
+//! built resource is identical to the last one successfully written to the
+//! same target.
+//!
+//! Entries are keyed by the conditional PUT URL (`Patient?identifier=…`),
+//! not the event's idempotency key, so backfill, binlog and webhook writes
+//! of one resource share an entry; otherwise a change written by one source
+//! could hide a revert written by another. The file is an append-only JSONL
+//! log, compacted each time it is opened.
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use fhirbolt::model::r4b::resources::Bundle;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::{Config, DeletePolicy};
+use crate::event::SyncEvent;
+
+use super::batch::event_bundle;
+use super::delete::delete_policy;
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    key: String,
+    hash: String,
+}
+
+pub(super) struct ContentHashStore {
+    hashes: HashMap<String, String>,
+    file: File,
+}
+
+impl ContentHashStore {
+    pub(super) fn open(path: &str) -> Result<Self> {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).ok();
+        }
+
+        let mut hashes = HashMap::new();
+        if let Ok(contents) = fs::read_to_string(path) {
+            // A torn last line from a crash is skipped, not fatal.
+            for record in contents.lines().filter_map(|l| serde_json::from_str::<Record>(l).ok()) {
+                hashes.insert(record.key, record.hash);
+            }
+        }
+
+        let tmp_path = format!("{path}.tmp");
+        {
+            let mut tmp = File::create(&tmp_path).with_context(|| format!("creating {tmp_path}"))?;
+            for (key, hash) in &hashes {
+                let line = serde_json::to_string(&Record {
+                    key: key.clone(),
+                    hash: hash.clone(),
+                })
+                .context("serializing content hash")?;
+                writeln!(tmp, "{line}").context("writing content hashes")?;
+            }
+            tmp.sync_all().ok();
+        }
+        fs::rename(&tmp_path, path).with_context(|| format!("renaming {tmp_path} -> {path}"))?;
+
+        let file = OpenOptions::new()
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening content hash store {path}"))?;
+        Ok(Self { hashes, file })
+    }
+
+    pub(super) fn is_unchanged(&self, key: &str, hash: &str) -> bool {
+        self.hashes.get(key).is_some_and(|h| h == hash)
+    }
+
+    /// Records a successful write.
+    pub(super) fn record(&mut self, key: &str, hash: &str) -> Result<()> {
+        if self.is_unchanged(key, hash) {
+            return Ok(());
+        }
+        let line = serde_json::to_string(&Record {
+            key: key.to_string(),
+            hash: hash.to_string(),
+        })
+        .context("serializing content hash")?;
+        writeln!(self.file, "{line}").context("appending content hash")?;
+        self.hashes.insert(key.to_string(), hash.to_string());
+        Ok(())
+    }
+}
+
+/// Stands in for the content of a hard-deleted resource, so a later write of
+/// its old content isn't skipped as unchanged.
+const DELETED: &str = "deleted";
+
+/// `(target, hash)` for the resource `event` would write, or `None` when it
+/// isn't a single conditional PUT (CareTeam, ignored deletes) or fails to
+/// build.
+pub(super) fn event_content_hash(cfg: &Config, event: &SyncEvent) -> Option<(String, String)> {
+    let bundle = event_bundle(cfg, event)?.ok()?;
+    let (target, hash) = bundle_content_hash(&bundle)?;
+    match delete_policy(&cfg.fhir, event) {
+        Some(DeletePolicy::HardDelete) => Some((target, DELETED.to_string())),
+        Some(DeletePolicy::Ignore) => None,
+        _ => Some((target, hash)),
+    }
+}
+
+fn bundle_content_hash(bundle: &Bundle) -> Option<(String, String)> {
+    let [entry] = bundle.entry.as_slice() else {
+        return None;
+    };
+    let target = entry.request.as_ref()?.url.value.clone()?;
+    let resource = entry.resource.as_ref()?;
+
+    let json = fhirbolt::json::to_string(resource, None).ok()?;
+    Some((target, canonical_hash(&json)?))
+}
+
+/// serde_json is built with `preserve_order`, so keys are sorted explicitly
+/// to hash the same content the same way whatever order it was written in.
+fn canonical_hash(json: &str) -> Option<String> {
+    let mut value: serde_json::Value = serde_json::from_str(json).ok()?;
+    value.sort_all_objects();
+    Some(hex::encode(Sha256::digest(value.to_string().as_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tmp_path(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("fhir-sync-content-hash-{}", std::process::id()));
+        dir.join(name).to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn store_survives_reopen() {
+        let path = tmp_path("hashes.jsonl");
+        let _ = fs::remove_file(&path);
+
+        let mut store = ContentHashStore::open(&path).unwrap();
+        assert!(!store.is_unchanged("Patient?identifier=a|1", "h1"));
+        store.record("Patient?identifier=a|1", "h1").unwrap();
+        store.record("Patient?identifier=a|1", "h2").unwrap();
+        drop(store);
+
+        let store = ContentHashStore::open(&path).unwrap();
+        assert!(store.is_unchanged("Patient?identifier=a|1", "h2"));
+        assert!(!store.is_unchanged("Patient?identifier=a|1", "h1"));
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn hash_ignores_key_order() {
+        let a = canonical_hash(r#"{"resourceType":"Patient","name":[{"family":"Doe","given":["J"]}]}"#);
+        let b = canonical_hash(r#"{"name":[{"given":["J"],"family":"Doe"}],"resourceType":"Patient"}"#);
+        assert!(a.is_some());
+        assert_eq!(a, b);
+        assert_ne!(a, canonical_hash(r#"{"resourceType":"Patient","name":[{"family":"Roe"}]}"#));
+    }
+}
@@ -0,0 +1,200 @@
+//! Delete semantics (`fhir.delete_policy`): what an Oscar row delete does to
+//! the resource it was synced to.
+//!
+//! `soft_delete` and `entered_in_error` are tombstones written over the built
+//! resource in `build_conditional_put_bundle` (and the Patient and
+//! Practitioner PUTs), so every source of a resource type gets the same one.
+//! `hard_delete` DELETEs the resource by id and `ignore` leaves it as it
+//! was; neither sends a resource body. `upsert` re-PUTs the built resource
+//! untouched.
+
+use anyhow::Context;
+use fhirbolt::model::r4b::types::{CodeableConcept, Coding};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use tracing::info;
+
+use crate::config::{DeletePolicy, FhirConfig};
+use crate::dispatch::DispatchOp;
+use crate::event::{Op, SyncEvent};
+
+use super::if_match::find_current;
+use super::oscar2::{CONDITION_CLINICAL_SYSTEM, CONDITION_VERIFICATION_SYSTEM};
+use super::{classify_http_error, conditional_identifier, FhirResult, SyncFailure};
+
+/// The policy for `event`, or `None` when it isn't a delete.
+pub(super) fn delete_policy(fhir_cfg: &FhirConfig, event: &SyncEvent) -> Option<DeletePolicy> {
+    (event.op() == Op::Delete).then(|| fhir_cfg.delete_policy_for(event.resource_type().as_path()))
+}
+
+/// True for a delete that writes no resource body (`hard_delete`, `ignore`).
+pub(super) fn skips_put(fhir_cfg: &FhirConfig, event: &SyncEvent) -> bool {
+    matches!(
+        delete_policy(fhir_cfg, event),
+        Some(DeletePolicy::HardDelete | DeletePolicy::Ignore)
+    )
+}
+
+/// The dispatch `op` for a synced event, or `None` for an ignored delete:
+/// it wrote nothing, so there is nothing to announce.
+pub(super) fn dispatch_op(fhir_cfg: &FhirConfig, event: &SyncEvent) -> Option<DispatchOp> {
+    match delete_policy(fhir_cfg, event) {
+        None | Some(DeletePolicy::Upsert) => Some(DispatchOp::Upsert),
+        Some(DeletePolicy::SoftDelete) => Some(DispatchOp::SoftDelete),
+        Some(DeletePolicy::EnteredInError) => Some(DispatchOp::EnteredInError),
+        Some(DeletePolicy::HardDelete) => Some(DispatchOp::Delete),
+        Some(DeletePolicy::Ignore) => None,
+    }
+}
+
+/// Tombstones `resource` when `event` is a soft or entered-in-error delete.
+pub(super) fn apply_delete_policy(resource: FhirResource, fhir_cfg: &FhirConfig, event: &SyncEvent) -> FhirResource {
+    match delete_policy(fhir_cfg, event) {
+        Some(policy @ (DeletePolicy::SoftDelete | DeletePolicy::EnteredInError)) => tombstone(resource, policy),
+        _ => resource,
+    }
+}
+
+fn tombstone(mut resource: FhirResource, policy: DeletePolicy) -> FhirResource {
+    let entered_in_error = policy == DeletePolicy::EnteredInError;
+    let status = |withdrawn: &str| if entered_in_error { "entered-in-error" } else { withdrawn }.to_string();
+    match &mut resource {
+        FhirResource::Patient(p) => p.active = Some(false.into()),
+        FhirResource::Practitioner(p) => p.active = Some(false.into()),
+        FhirResource::Appointment(a) => a.status = status("cancelled").into(),
+        FhirResource::Encounter(e) => e.status = status("cancelled").into(),
+        FhirResource::DiagnosticReport(r) => r.status = status("cancelled").into(),
+        FhirResource::ServiceRequest(r) => r.status = status("revoked").into(),
+        FhirResource::DocumentReference(d) => d.status = "entered-in-error".into(),
+        FhirResource::FamilyMemberHistory(f) => f.status = "entered-in-error".into(),
+        FhirResource::Condition(c) if entered_in_error => {
+            // con-5: no clinicalStatus on an entered-in-error Condition.
+            c.clinical_status = None;
+            c.verification_status = Some(Box::new(status_concept(CONDITION_VERIFICATION_SYSTEM, "entered-in-error")));
+        }
+        FhirResource::Condition(c) => {
+            c.clinical_status = Some(Box::new(status_concept(CONDITION_CLINICAL_SYSTEM, "inactive")));
+        }
+        // Tombstoned by their own builders from `event.op()`.
+        _ => {}
+    }
+    resource
+}
+
+fn status_concept(system: &str, code: &str) -> CodeableConcept {
+    CodeableConcept {
+        coding: vec![Coding {
+            system: Some(system.into()),
+            code: Some(code.into()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+/// DELETEs the resource `event` was synced to, found by identifier. Nothing
+/// to delete (never synced, or already gone) succeeds with no `fhir_id`.
+/// HAPI refuses to delete a resource others still reference (409), which
+/// dead-letters the event.
+pub(super) async fn hard_delete(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<&str>,
+    event: &SyncEvent,
+) -> Result<FhirResult, SyncFailure> {
+    let base = fhir_cfg.base_url.trim_end_matches('/');
+    let resource_type = event.resource_type().as_path();
+    let identifier = conditional_identifier(fhir_cfg, event);
+
+    let Some(current) = find_current(client, token, base, resource_type, &identifier).await? else {
+        info!(
+            "fhir sink: {} has no {resource_type} on the server to delete",
+            event.idempotency_key()
+        );
+        return Ok(FhirResult {
+            fhir_id: String::new(),
+            version_id: None,
+            applied: true,
+        });
+    };
+
+    let mut req = client.delete(format!("{base}/{resource_type}/{}", current.id));
+    if let Some(t) = token {
+        req = req.bearer_auth(t);
+    }
+    let resp = req
+        .send()
+        .await
+        .with_context(|| format!("DELETE {resource_type}/{}", current.id))
+        .map_err(SyncFailure::Retryable)?;
+    let status = resp.status();
+    if !status.is_success() {
+        let text = resp.text().await.unwrap_or_default();
+        return Err(classify_http_error(status, &text, &format!("{resource_type} DELETE")));
+    }
+
+    info!(
+        "fhir sink: deleted {} -> {resource_type}/{}",
+        event.idempotency_key(),
+        current.id
+    );
+    Ok(FhirResult {
+        fhir_id: current.id,
+        version_id: None,
+        applied: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::resource::DomainResource;
+    use crate::event::Source;
+    use fhirbolt::model::r4b::resources::{Appointment, Condition};
+
+    #[test]
+    fn tombstones_follow_policy() {
+        let appointment = || FhirResource::Appointment(Box::new(Appointment {
+            status: "booked".into(),
+            ..Default::default()
+        }));
+        let FhirResource::Appointment(a) = tombstone(appointment(), DeletePolicy::SoftDelete) else {
+            unreachable!()
+        };
+        assert_eq!(a.status.value.as_deref(), Some("cancelled"));
+        let FhirResource::Appointment(a) = tombstone(appointment(), DeletePolicy::EnteredInError) else {
+            unreachable!()
+        };
+        assert_eq!(a.status.value.as_deref(), Some("entered-in-error"));
+
+        let condition = FhirResource::Condition(Box::new(Condition {
+            clinical_status: Some(Box::new(status_concept(CONDITION_CLINICAL_SYSTEM, "active"))),
+            ..Default::default()
+        }));
+        let FhirResource::Condition(c) = tombstone(condition, DeletePolicy::EnteredInError) else {
+            unreachable!()
+        };
+        assert!(c.clinical_status.is_none());
+        let code = c.verification_status.as_ref().and_then(|v| v.coding[0].code.as_ref());
+        assert_eq!(code.and_then(|c| c.value.as_deref()), Some("entered-in-error"));
+    }
+
+    #[test]
+    fn ignored_deletes_are_not_dispatched() {
+        let event = |op| {
+            SyncEvent::new(
+                Source::OscarBinlog {
+                    table: "appointment".to_string(),
+                },
+                op,
+                DomainResource::Appointment(super::super::tests::appt_payload()),
+                chrono::Utc::now(),
+            )
+        };
+        let mut fhir_cfg = FhirConfig::default();
+        assert_eq!(dispatch_op(&fhir_cfg, &event(Op::Upsert)), Some(DispatchOp::Upsert));
+        assert_eq!(dispatch_op(&fhir_cfg, &event(Op::Delete)), Some(DispatchOp::SoftDelete));
+
+        fhir_cfg.delete_policy.insert("Appointment".to_string(), DeletePolicy::Ignore);
+        assert_eq!(dispatch_op(&fhir_cfg, &event(Op::Delete)), None);
+    }
+}
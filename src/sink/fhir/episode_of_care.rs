@@ -0,0 +1,212 @@
+//! Oscar CAISI `admission` -> FHIR `EpisodeOfCare`.
+//!
+//! The program is carried twice: as `managingOrganization` (a conditional
+//! reference to the `program`-backed Organization) and as a `type` coding,
+//! so AMT can filter enrolments by program without resolving the reference.
+
+use fhirbolt::model::r4b::resources::{Bundle, EpisodeOfCare};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{CodeableConcept, Coding, Identifier, Meta, Period};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::episode_of_care::DomainEpisodeOfCare;
+use crate::event::Op;
+
+use super::oscar2::{
+    build_conditional_put_bundle, conditional_reference, patient_ref, practitioner_ref, send_transaction_bundle,
+};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_episode_of_care(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    episode: &DomainEpisodeOfCare,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = episode_of_care_bundle(fhir_cfg, event, episode, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_admission_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn episode_of_care_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    episode: &DomainEpisodeOfCare,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_episode = build_episode_of_care(episode, fhir_cfg, oscar_cfg, event.op())?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::EpisodeOfCare(Box::new(fhir_episode)),
+        &fhir_cfg.oscar_admission_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Oscar `admission_status` -> FHIR `EpisodeOfCare.status`. A binlog delete
+/// is `entered-in-error`: CAISI discharges rather than deletes, so a deleted
+/// row was never a real enrolment. Unknown statuses fail permanently rather
+/// than guess.
+fn episode_status(episode: &DomainEpisodeOfCare, op: Op) -> Result<&'static str, SyncFailure> {
+    if op == Op::Delete {
+        return Ok("entered-in-error");
+    }
+    match episode.status.as_deref() {
+        Some("current") => Ok("active"),
+        Some("discharged") => Ok("finished"),
+        None if episode.discharge_date.is_some() => Ok("finished"),
+        None => Ok("active"),
+        Some(other) => Err(SyncFailure::Permanent(anyhow::anyhow!(
+            "unmapped_admission_status: {other}"
+        ))),
+    }
+}
+
+/// Converts a naive local `admission`/`discharge` datetime into a FHIR
+/// `dateTime`. Date-only values are passed through unchanged.
+fn local_date_time(value: &str, tz: &str) -> Result<String, SyncFailure> {
+    match value.trim().split_once([' ', 'T']) {
+        Some((date, time)) => super::to_appointment_instant(date, time, tz),
+        None => Ok(value.trim().to_string()),
+    }
+}
+
+fn build_episode_of_care(
+    episode: &DomainEpisodeOfCare,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<EpisodeOfCare, SyncFailure> {
+    let tz = oscar_cfg
+        .timezone
+        .as_deref()
+        .ok_or_else(|| SyncFailure::Permanent(anyhow::anyhow!("missing oscar timezone")))?;
+
+    let mut e = EpisodeOfCare::default();
+
+    e.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    e.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_admission_system.clone().into()),
+        value: Some(episode.admission_id.clone().into()),
+        ..Default::default()
+    });
+
+    e.status = episode_status(episode, op)?.to_string().into();
+    e.patient = Box::new(patient_ref(fhir_cfg, &episode.demographic_no));
+
+    e.r#type.push(CodeableConcept {
+        coding: vec![Coding {
+            system: Some(fhir_cfg.oscar_program_system.clone().into()),
+            code: Some(episode.program_id.clone().into()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+    e.managing_organization = Some(Box::new(conditional_reference(
+        &fhir_cfg.oscar_program_system,
+        &episode.program_id,
+        "Organization",
+    )));
+
+    if let Some(provider_no) = &episode.provider_no {
+        e.care_manager = Some(Box::new(practitioner_ref(fhir_cfg, provider_no)));
+    }
+
+    let start = episode.admission_date.as_deref().map(|d| local_date_time(d, tz)).transpose()?;
+    let end = episode.discharge_date.as_deref().map(|d| local_date_time(d, tz)).transpose()?;
+    if start.is_some() || end.is_some() {
+        e.period = Some(Box::new(Period {
+            start: start.map(Into::into),
+            end: end.map(Into::into),
+            ..Default::default()
+        }));
+    }
+
+    Ok(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oscar_cfg() -> OscarConfig {
+        OscarConfig {
+            timezone: Some("America/Vancouver".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn episode() -> DomainEpisodeOfCare {
+        DomainEpisodeOfCare {
+            admission_id: "7".to_string(),
+            demographic_no: "123".to_string(),
+            program_id: "10016".to_string(),
+            provider_no: Some("101".to_string()),
+            admission_date: Some("2024-01-02 09:30:00".to_string()),
+            discharge_date: None,
+            status: Some("current".to_string()),
+        }
+    }
+
+    #[test]
+    fn build_episode_of_care_maps_program_period_and_manager() {
+        let cfg = FhirConfig::default();
+        let e = build_episode_of_care(&episode(), &cfg, &oscar_cfg(), Op::Upsert).unwrap();
+        assert_eq!(e.status.value.as_deref(), Some("active"));
+        assert_eq!(
+            e.managing_organization
+                .as_ref()
+                .and_then(|r| r.reference.as_ref())
+                .and_then(|r| r.value.as_deref()),
+            Some("Organization?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-program|10016")
+        );
+        assert_eq!(e.r#type[0].coding[0].code, Some("10016".to_string().into()));
+        assert!(e.care_manager.is_some());
+        let period = e.period.as_ref().unwrap();
+        assert_eq!(
+            period.start.as_ref().and_then(|s| s.value.as_deref()),
+            Some("2024-01-02T09:30:00-08:00")
+        );
+        assert!(period.end.is_none());
+    }
+
+    #[test]
+    fn discharged_admission_is_finished() {
+        let mut ep = episode();
+        ep.status = Some("discharged".to_string());
+        ep.discharge_date = Some("2024-02-01".to_string());
+        let e = build_episode_of_care(&ep, &FhirConfig::default(), &oscar_cfg(), Op::Upsert).unwrap();
+        assert_eq!(e.status.value.as_deref(), Some("finished"));
+        assert_eq!(
+            e.period.as_ref().and_then(|p| p.end.as_ref()).and_then(|d| d.value.as_deref()),
+            Some("2024-02-01")
+        );
+    }
+
+    #[test]
+    fn delete_is_entered_in_error() {
+        let e = build_episode_of_care(&episode(), &FhirConfig::default(), &oscar_cfg(), Op::Delete).unwrap();
+        assert_eq!(e.status.value.as_deref(), Some("entered-in-error"));
+    }
+
+    #[test]
+    fn unmapped_status_is_permanent_failure() {
+        let mut ep = episode();
+        ep.status = Some("pending".to_string());
+        let err = build_episode_of_care(&ep, &FhirConfig::default(), &oscar_cfg(), Op::Upsert);
+        assert!(matches!(err, Err(SyncFailure::Permanent(_))));
+    }
+}
@@ -0,0 +1,310 @@
+//! Optimistic-concurrency writes (`fhir.if_match_writes`) for the resources
+//! sent by `build_put_request`.
+//!
+//! The current resource is read by identifier first. If its `meta.source`
+//! is still ours it is replaced with `If-Match` on the version read; if it
+//! was last written by someone else (e.g. edited in AMT), or a 412 shows it
+//! changed between read and write, `fhir.write_conflict_policy` decides.
+
+use anyhow::Context;
+use tracing::{info, warn};
+
+use crate::config::WriteConflictPolicy;
+use crate::event::SyncEvent;
+
+use super::{
+    build_put_request, classify_http_error, conditional_identifier, parse_location_id, parse_location_version_id,
+    FhirConfig, FhirResult, SyncFailure, META_SOURCE,
+};
+
+/// The server's copy of a resource, as far as concurrency cares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Current {
+    pub(super) id: String,
+    version_id: String,
+    source: Option<String>,
+}
+
+impl Current {
+    /// HAPI may suffix `meta.source` with `#<request id>`.
+    fn written_by_us(&self) -> bool {
+        self.source
+            .as_deref()
+            .is_some_and(|s| s.split('#').next() == Some(META_SOURCE))
+    }
+}
+
+/// A write refused under `WriteConflictPolicy::DeadLetter`. Identifiers
+/// only: the versions themselves stay in HAPI's history.
+#[derive(Debug)]
+pub(super) struct WriteConflict {
+    /// e.g. `Patient/123`.
+    pub(super) resource: String,
+    /// Version the write was based on; `None` when the server copy was
+    /// already someone else's when read.
+    pub(super) expected_version: Option<String>,
+    pub(super) server_version: String,
+    pub(super) server_source: Option<String>,
+}
+
+impl std::fmt::Display for WriteConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "write_conflict: {} is at version {} (meta.source={}), expected {}",
+            self.resource,
+            self.server_version,
+            self.server_source.as_deref().unwrap_or("none"),
+            self.expected_version.as_deref().unwrap_or("a version written by fhir-sync"),
+        )
+    }
+}
+
+impl std::error::Error for WriteConflict {}
+
+/// PUTs `body` for `event`, guarded by `If-Match` on the version read.
+pub(super) async fn put_if_match(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<&str>,
+    event: &SyncEvent,
+    body: String,
+) -> Result<FhirResult, SyncFailure> {
+    let base = fhir_cfg.base_url.trim_end_matches('/');
+    let resource_type = event.resource_type().as_path();
+    let identifier = conditional_identifier(fhir_cfg, event);
+
+    let mut current = find_current(client, token, base, resource_type, &identifier).await?;
+    let mut expected_version: Option<String> = None;
+
+    // At most one re-read: a second 412 is left to the retry loop.
+    loop {
+        let Some(cur) = current else {
+            // Not on the server yet; the conditional PUT creates it.
+            let resp = build_put_request(client, fhir_cfg, token, event)
+                .body(body)
+                .send()
+                .await
+                .with_context(|| "sending conditional PUT to HAPI")
+                .map_err(SyncFailure::Retryable)?;
+            return put_result(resp, "").await;
+        };
+
+        let changed_since_read = expected_version.is_some();
+        if changed_since_read || !cur.written_by_us() {
+            match fhir_cfg.write_conflict_policy {
+                WriteConflictPolicy::OscarWins => {}
+                WriteConflictPolicy::Skip => {
+                    info!(
+                        "fhir sink: {} {resource_type}/{} changed outside fhir-sync; leaving version {} in place",
+                        event.idempotency_key(),
+                        cur.id,
+                        cur.version_id
+                    );
+                    return Ok(FhirResult {
+                        fhir_id: cur.id,
+                        version_id: Some(cur.version_id),
+                        applied: false,
+                    });
+                }
+                WriteConflictPolicy::DeadLetter => {
+                    return Err(SyncFailure::Permanent(anyhow::Error::new(WriteConflict {
+                        resource: format!("{resource_type}/{}", cur.id),
+                        expected_version,
+                        server_version: cur.version_id,
+                        server_source: cur.source,
+                    })));
+                }
+            }
+        }
+
+        let resp = put_version(client, token, base, resource_type, &cur, body.clone()).await?;
+        let status = resp.status();
+        if status.is_success() {
+            return put_result(resp, &cur.id).await;
+        }
+
+        let text = resp.text().await.unwrap_or_default();
+        if status.as_u16() == 412 {
+            if changed_since_read {
+                return Err(SyncFailure::Retryable(anyhow::anyhow!(
+                    "{resource_type} {identifier} kept changing between read and write"
+                )));
+            }
+            warn!(
+                "fhir sink: {resource_type}/{} changed since version {}; re-reading",
+                cur.id, cur.version_id
+            );
+            expected_version = Some(cur.version_id);
+            current = find_current(client, token, base, resource_type, &identifier).await?;
+            continue;
+        }
+        return Err(classify_http_error(status, &text, &format!("{resource_type} If-Match PUT")));
+    }
+}
+
+async fn put_version(
+    client: &reqwest::Client,
+    token: Option<&str>,
+    base: &str,
+    resource_type: &str,
+    current: &Current,
+    body: String,
+) -> Result<reqwest::Response, SyncFailure> {
+    // The id in the body must match the URL for an update by id.
+    let mut json: serde_json::Value = serde_json::from_str(&body)
+        .context("re-reading resource body")
+        .map_err(SyncFailure::Permanent)?;
+    json["id"] = serde_json::Value::String(current.id.clone());
+
+    let mut req = client
+        .put(format!("{base}/{resource_type}/{}", current.id))
+        .header("Content-Type", "application/fhir+json")
+        .header("If-Match", format!("W/\"{}\"", current.version_id))
+        .body(json.to_string());
+    if let Some(t) = token {
+        req = req.bearer_auth(t);
+    }
+    req.send()
+        .await
+        .with_context(|| format!("PUT {resource_type}/{}", current.id))
+        .map_err(SyncFailure::Retryable)
+}
+
+/// Reads the id and version from a successful PUT response.
+async fn put_result(resp: reqwest::Response, fallback_id: &str) -> Result<FhirResult, SyncFailure> {
+    let status = resp.status();
+    if !status.is_success() {
+        let text = resp.text().await.unwrap_or_default();
+        return Err(classify_http_error(status, &text, "HAPI conditional PUT"));
+    }
+
+    let location = resp
+        .headers()
+        .get("location")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let body_text = resp.text().await.unwrap_or_default();
+    let value: serde_json::Value = serde_json::from_str(&body_text).unwrap_or_default();
+
+    let mut result = FhirResult {
+        fhir_id: value.get("id").and_then(|v| v.as_str()).unwrap_or(fallback_id).to_string(),
+        version_id: value
+            .pointer("/meta/versionId")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        applied: true,
+    };
+    if result.fhir_id.is_empty() {
+        if let Some(loc) = location {
+            result.fhir_id = parse_location_id(&loc).unwrap_or_default();
+            result.version_id = parse_location_version_id(&loc);
+        }
+    }
+    Ok(result)
+}
+
+/// Searches `{resource_type}?identifier=` and returns the first match. The
+/// search Bundle is walked as plain JSON (see `find_existing_care_team`).
+pub(super) async fn find_current(
+    client: &reqwest::Client,
+    token: Option<&str>,
+    base: &str,
+    resource_type: &str,
+    identifier: &str,
+) -> Result<Option<Current>, SyncFailure> {
+    let mut req = client
+        .get(format!("{base}/{resource_type}"))
+        .query(&[("identifier", identifier)])
+        .header("Accept", "application/fhir+json");
+    if let Some(t) = token {
+        req = req.bearer_auth(t);
+    }
+
+    let resp = req
+        .send()
+        .await
+        .with_context(|| format!("searching {resource_type}"))
+        .map_err(SyncFailure::Retryable)?;
+    let status = resp.status();
+    if !status.is_success() {
+        let text = resp.text().await.unwrap_or_default();
+        return Err(classify_http_error(status, &text, &format!("{resource_type} search")));
+    }
+
+    let body_text = resp.text().await.unwrap_or_default();
+    let raw: serde_json::Value = serde_json::from_str(&body_text)
+        .with_context(|| format!("parsing {resource_type} search Bundle as JSON"))
+        .map_err(SyncFailure::Retryable)?;
+    Ok(current_from_search(&raw, resource_type))
+}
+
+fn current_from_search(search: &serde_json::Value, resource_type: &str) -> Option<Current> {
+    search
+        .get("entry")?
+        .as_array()?
+        .iter()
+        .filter_map(|e| e.get("resource"))
+        .filter(|r| r.get("resourceType").and_then(|v| v.as_str()) == Some(resource_type))
+        .find_map(|r| {
+            Some(Current {
+                id: r.get("id")?.as_str()?.to_string(),
+                version_id: r.pointer("/meta/versionId")?.as_str()?.to_string(),
+                source: r.pointer("/meta/source").and_then(|v| v.as_str()).map(String::from),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_current_version_from_search() {
+        let search = serde_json::json!({
+            "resourceType": "Bundle",
+            "type": "searchset",
+            "entry": [
+                {"resource": {"resourceType": "OperationOutcome"}},
+                {
+                    "resource": {
+                        "resourceType": "Patient",
+                        "id": "123",
+                        "meta": {"versionId": "4", "source": "urn:amt"}
+                    },
+                    "search": {"mode": "match"}
+                }
+            ]
+        });
+        assert_eq!(
+            current_from_search(&search, "Patient"),
+            Some(Current {
+                id: "123".to_string(),
+                version_id: "4".to_string(),
+                source: Some("urn:amt".to_string()),
+            })
+        );
+        assert_eq!(current_from_search(&serde_json::json!({"entry": []}), "Patient"), None);
+
+        let ours = Current {
+            id: "123".to_string(),
+            version_id: "5".to_string(),
+            source: Some(format!("{META_SOURCE}#a1b2c3")),
+        };
+        assert!(ours.written_by_us());
+    }
+
+    #[test]
+    fn conflict_message_names_both_versions() {
+        let conflict = WriteConflict {
+            resource: "Patient/123".to_string(),
+            expected_version: Some("4".to_string()),
+            server_version: "5".to_string(),
+            server_source: Some("urn:amt".to_string()),
+        };
+        assert_eq!(
+            conflict.to_string(),
+            "write_conflict: Patient/123 is at version 5 (meta.source=urn:amt), expected 4"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Oscar multi-site `site` -> FHIR `Location`, managed by the `clinic`
+//! Organization.
+
+use fhirbolt::model::r4b::resources::{Bundle, Location};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{Address, ContactPoint, Identifier, Meta, Reference};
+use tracing::info;
+
+use crate::domain::location::DomainLocation;
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, conditional_reference, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_location(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    location: &DomainLocation,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = location_bundle(fhir_cfg, event, location)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_site_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn location_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    location: &DomainLocation,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_location = build_location(location, fhir_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Location(Box::new(fhir_location)),
+        &fhir_cfg.oscar_site_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Conditional reference to the `Location` for a `site_id`, used by
+/// `Appointment.participant`.
+pub(super) fn site_ref(fhir_cfg: &FhirConfig, site_id: &str) -> Reference {
+    conditional_reference(&fhir_cfg.oscar_site_system, site_id, "Location")
+}
+
+fn build_location(location: &DomainLocation, fhir_cfg: &FhirConfig, op: Op) -> Location {
+    let mut l = Location::default();
+
+    l.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    l.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_site_system.clone().into()),
+        value: Some(location.site_id.clone().into()),
+        ..Default::default()
+    });
+
+    let active = location.active && op != Op::Delete;
+    l.status = Some(if active { "active" } else { "inactive" }.into());
+    l.name = location.name.clone().map(Into::into);
+    l.alias = location.short_name.clone().map(|s| vec![s.into()]).unwrap_or_default();
+    l.mode = Some("instance".into());
+
+    for (value, system) in [(&location.phone, "phone"), (&location.fax, "fax")] {
+        if let Some(v) = value {
+            l.telecom.push(ContactPoint {
+                system: Some(system.into()),
+                value: Some(v.clone().into()),
+                r#use: Some("work".into()),
+                ..Default::default()
+            });
+        }
+    }
+
+    if location.address_line.is_some()
+        || location.city.is_some()
+        || location.province.is_some()
+        || location.postal.is_some()
+    {
+        l.address = Some(Box::new(Address {
+            r#use: Some("work".into()),
+            line: location.address_line.clone().map(|a| vec![a.into()]).unwrap_or_default(),
+            city: location.city.clone().map(Into::into),
+            state: location.province.clone().map(Into::into),
+            postal_code: location.postal.clone().map(Into::into),
+            country: Some("CA".into()),
+            ..Default::default()
+        }));
+    }
+
+    if let Some(clinic_no) = &location.clinic_no {
+        l.managing_organization = Some(Box::new(conditional_reference(
+            &fhir_cfg.oscar_clinic_system,
+            clinic_no,
+            "Organization",
+        )));
+    }
+
+    l
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location() -> DomainLocation {
+        DomainLocation {
+            site_id: "2".to_string(),
+            name: Some("Downtown".to_string()),
+            short_name: Some("DT".to_string()),
+            address_line: Some("1 Main St".to_string()),
+            city: Some("Vancouver".to_string()),
+            province: Some("BC".to_string()),
+            postal: None,
+            phone: Some("604-555-0100".to_string()),
+            fax: None,
+            clinic_no: Some("1".to_string()),
+            active: true,
+        }
+    }
+
+    #[test]
+    fn build_location_maps_name_alias_and_clinic() {
+        let l = build_location(&location(), &FhirConfig::default(), Op::Upsert);
+        assert_eq!(l.status, Some("active".into()));
+        assert_eq!(l.name, Some("Downtown".to_string().into()));
+        assert_eq!(l.alias.len(), 1);
+        assert_eq!(l.telecom.len(), 1);
+        assert!(l.address.is_some());
+        assert_eq!(
+            l.managing_organization
+                .as_ref()
+                .and_then(|r| r.reference.as_ref())
+                .and_then(|r| r.value.as_deref()),
+            Some("Organization?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-clinic|1")
+        );
+    }
+
+    #[test]
+    fn build_location_delete_is_inactive() {
+        let l = build_location(&location(), &FhirConfig::default(), Op::Delete);
+        assert_eq!(l.status, Some("inactive".into()));
+    }
+}
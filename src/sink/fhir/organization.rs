@@ -0,0 +1,155 @@
+//! Oscar-sourced FHIR `Organization`s. Oscar has no organization table of its
+//! own, so each source table gets its own identifier system.
+
+use fhirbolt::model::r4b::resources::{Bundle, Organization};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{Address, ContactPoint, Identifier, Meta};
+use tracing::{info, warn};
+
+use crate::domain::organization::DomainOrganization;
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_organization(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    org: &DomainOrganization,
+) -> Result<FhirResult, SyncFailure> {
+    let identifier_system = identifier_system(org, fhir_cfg);
+    let bundle = organization_bundle(fhir_cfg, event, org)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", identifier_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn organization_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    org: &DomainOrganization,
+) -> Result<Bundle, SyncFailure> {
+    let identifier_system = identifier_system(org, fhir_cfg);
+    let fhir_org = build_organization(org, fhir_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Organization(Box::new(fhir_org)),
+        identifier_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Identifier system for an Organization, chosen by its source table.
+pub(super) fn identifier_system<'a>(org: &DomainOrganization, fhir_cfg: &'a FhirConfig) -> &'a String {
+    match org.source_table.as_str() {
+        "professionalSpecialists" => &fhir_cfg.oscar_specialist_clinic_system,
+        "program" => &fhir_cfg.oscar_program_system,
+        "clinic" => &fhir_cfg.oscar_clinic_system,
+        other => {
+            warn!("organization sink: no identifier system for source table {other:?}; using specialist clinic system");
+            &fhir_cfg.oscar_specialist_clinic_system
+        }
+    }
+}
+
+fn build_organization(org: &DomainOrganization, fhir_cfg: &FhirConfig, op: Op) -> Organization {
+    let mut o = Organization::default();
+
+    o.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    o.identifier.push(Identifier {
+        system: Some(identifier_system(org, fhir_cfg).clone().into()),
+        value: Some(org.source_id.clone().into()),
+        ..Default::default()
+    });
+
+    o.active = Some((org.active && op != Op::Delete).into());
+    o.name = org.name.clone().map(Into::into);
+
+    for (value, system) in [(&org.phone, "phone"), (&org.fax, "fax"), (&org.email, "email")] {
+        if let Some(v) = value {
+            o.telecom.push(ContactPoint {
+                system: Some(system.into()),
+                value: Some(v.clone().into()),
+                r#use: (system != "email").then(|| "work".into()),
+                ..Default::default()
+            });
+        }
+    }
+
+    if org.address_line.is_some() || org.city.is_some() || org.province.is_some() || org.postal.is_some() {
+        o.address.push(Address {
+            r#use: Some("work".into()),
+            line: org.address_line.clone().map(|l| vec![l.into()]).unwrap_or_default(),
+            city: org.city.clone().map(Into::into),
+            state: org.province.clone().map(Into::into),
+            postal_code: org.postal.clone().map(Into::into),
+            country: Some("CA".into()),
+            ..Default::default()
+        });
+    }
+
+    o
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn org() -> DomainOrganization {
+        DomainOrganization {
+            source_table: "professionalSpecialists".to_string(),
+            source_id: "12".to_string(),
+            name: None,
+            address_line: Some("1 Main St".to_string()),
+            city: None,
+            province: None,
+            postal: None,
+            phone: Some("604-555-0101".to_string()),
+            fax: Some("604-555-0102".to_string()),
+            email: None,
+            active: true,
+        }
+    }
+
+    #[test]
+    fn build_organization_maps_identifier_telecom_and_address() {
+        let cfg = FhirConfig::default();
+        let o = build_organization(&org(), &cfg, Op::Upsert);
+        assert_eq!(
+            o.identifier[0].system,
+            Some(cfg.oscar_specialist_clinic_system.clone().into())
+        );
+        assert_eq!(o.telecom.len(), 2);
+        assert_eq!(o.telecom[1].system, Some("fax".into()));
+        assert_eq!(o.address.len(), 1);
+        assert_eq!(o.active.as_ref().and_then(|b| b.value), Some(true));
+    }
+
+    #[test]
+    fn build_organization_delete_sets_inactive() {
+        let o = build_organization(&org(), &FhirConfig::default(), Op::Delete);
+        assert_eq!(o.active.as_ref().and_then(|b| b.value), Some(false));
+    }
+
+    #[test]
+    fn program_organization_uses_program_system() {
+        let cfg = FhirConfig::default();
+        let mut program = org();
+        program.source_table = "program".to_string();
+        program.name = Some("OSCAR".to_string());
+        let o = build_organization(&program, &cfg, Op::Upsert);
+        assert_eq!(o.identifier[0].system, Some(cfg.oscar_program_system.clone().into()));
+        assert_eq!(o.name, Some("OSCAR".to_string().into()));
+    }
+}
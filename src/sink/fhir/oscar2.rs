@@ -1,6 +1,6 @@
 use anyhow::Context;
 use base64ct::{Base64, Encoding};
-use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use fhirbolt::model::r4b::resources::{
     Bundle, BundleEntry, BundleEntryRequest, Condition, ConditionAbatement, ConditionOnset,
     DiagnosticReport, DiagnosticReportEffective, DocumentReference, DocumentReferenceContent,
@@ -18,14 +18,16 @@ use crate::domain::diagnostic_report::DomainDiagnosticReport;
 use crate::domain::document_reference::DomainDocumentReference;
 use crate::domain::encounter::DomainEncounter;
 use crate::domain::service_request::DomainServiceRequest;
-use crate::event::{Op, ResourceType};
+use crate::event::Op;
 
-use super::{FhirConfig, FhirResult, OscarConfig, SyncEvent, SyncFailure, parse_location_id, parse_location_version_id, META_SOURCE};
+use super::column_overrides::apply_column_overrides;
+use super::delete::apply_delete_policy;
+use super::{classify_http_error, FhirConfig, FhirResult, OscarConfig, SyncEvent, SyncFailure, parse_location_id, parse_location_version_id, META_SOURCE};
 
 const ACT_CODE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v3-ActCode";
 const PARTICIPATION_TYPE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v3-ParticipationType";
-const CONDITION_CLINICAL_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/condition-clinical";
-const CONDITION_VERIFICATION_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/condition-ver-status";
+pub(super) const CONDITION_CLINICAL_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/condition-clinical";
+pub(super) const CONDITION_VERIFICATION_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/condition-ver-status";
 const UOM_SYSTEM: &str = "http://unitsofmeasure.org";
 
 // ---------------------------------------------------------------------------
@@ -40,12 +42,7 @@ pub(super) async fn sync_encounter(
     encounter: &DomainEncounter,
     oscar_cfg: &OscarConfig,
 ) -> Result<FhirResult, SyncFailure> {
-    let fhir_encounter = build_encounter(encounter, fhir_cfg, oscar_cfg)?;
-    let bundle = build_conditional_put_bundle(
-        FhirResource::Encounter(Box::new(fhir_encounter)),
-        &fhir_cfg.oscar_note_system,
-        event,
-    );
+    let bundle = encounter_bundle(fhir_cfg, event, encounter, oscar_cfg)?;
     let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
     let identifier = format!("{}|{}", fhir_cfg.oscar_note_system, event.payload().source_id());
     info!(
@@ -55,6 +52,22 @@ pub(super) async fn sync_encounter(
     Ok(result)
 }
 
+pub(super) fn encounter_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    encounter: &DomainEncounter,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_encounter = build_encounter(encounter, fhir_cfg, oscar_cfg)?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Encounter(Box::new(fhir_encounter)),
+        &fhir_cfg.oscar_note_system,
+        event,
+    );
+    Ok(bundle)
+}
+
 pub(super) async fn sync_document_reference(
     client: &reqwest::Client,
     fhir_cfg: &FhirConfig,
@@ -63,12 +76,7 @@ pub(super) async fn sync_document_reference(
     doc: &DomainDocumentReference,
     oscar_cfg: &OscarConfig,
 ) -> Result<FhirResult, SyncFailure> {
-    let fhir_doc = build_document_reference(doc, fhir_cfg, oscar_cfg)?;
-    let bundle = build_conditional_put_bundle(
-        FhirResource::DocumentReference(Box::new(fhir_doc)),
-        &fhir_cfg.oscar_note_document_system,
-        event,
-    );
+    let bundle = document_reference_bundle(fhir_cfg, event, doc, oscar_cfg)?;
     let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
     let identifier = format!("{}|{}", fhir_cfg.oscar_note_document_system, event.payload().source_id());
     info!(
@@ -78,6 +86,22 @@ pub(super) async fn sync_document_reference(
     Ok(result)
 }
 
+pub(super) fn document_reference_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    doc: &DomainDocumentReference,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_doc = build_document_reference(doc, fhir_cfg, oscar_cfg)?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::DocumentReference(Box::new(fhir_doc)),
+        &fhir_cfg.oscar_note_document_system,
+        event,
+    );
+    Ok(bundle)
+}
+
 pub(super) async fn sync_condition(
     client: &reqwest::Client,
     fhir_cfg: &FhirConfig,
@@ -85,19 +109,53 @@ pub(super) async fn sync_condition(
     event: &SyncEvent,
     condition: &DomainCondition,
 ) -> Result<FhirResult, SyncFailure> {
-    let fhir_condition = build_condition(condition, fhir_cfg)?;
-    let identifier_system = if condition.source_table == "dxresearch" {
+    let identifier_system = condition_identifier_system(condition, fhir_cfg);
+    let bundle = condition_bundle(fhir_cfg, event, condition)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", identifier_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+/// Identifier system for a Condition: dxresearch rows and CPP notes are
+/// numbered independently.
+fn condition_identifier_system<'a>(condition: &DomainCondition, fhir_cfg: &'a FhirConfig) -> &'a String {
+    if condition.source_table == "dxresearch" {
         &fhir_cfg.oscar_dxresearch_system
     } else {
         &fhir_cfg.oscar_cpp_condition_system
-    };
+    }
+}
+
+pub(super) fn condition_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    condition: &DomainCondition,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_condition = build_condition(condition, fhir_cfg, event.op())?;
+    let identifier_system = condition_identifier_system(condition, fhir_cfg);
     let bundle = build_conditional_put_bundle(
+        fhir_cfg,
         FhirResource::Condition(Box::new(fhir_condition)),
         identifier_system,
         event,
     );
+    Ok(bundle)
+}
+
+pub(super) async fn sync_family_member_history(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    fh: &DomainFamilyMemberHistory,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = family_member_history_bundle(fhir_cfg, event, fh)?;
     let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
-    let identifier = format!("{}|{}", identifier_system, event.payload().source_id());
+    let identifier = format!("{}|{}", fhir_cfg.oscar_cpp_condition_system, event.payload().source_id());
     info!(
         "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
         event.idempotency_key(), identifier, result.fhir_id, result.version_id
@@ -105,21 +163,32 @@ pub(super) async fn sync_condition(
     Ok(result)
 }
 
-pub(super) async fn sync_family_member_history(
-    client: &reqwest::Client,
+pub(super) fn family_member_history_bundle(
     fhir_cfg: &FhirConfig,
-    token: Option<String>,
     event: &SyncEvent,
     fh: &DomainFamilyMemberHistory,
-) -> Result<FhirResult, SyncFailure> {
+) -> Result<Bundle, SyncFailure> {
     let fhir_fh = build_family_member_history(fh, fhir_cfg)?;
     let bundle = build_conditional_put_bundle(
+        fhir_cfg,
         FhirResource::FamilyMemberHistory(Box::new(fhir_fh)),
         &fhir_cfg.oscar_cpp_condition_system,
         event,
     );
+    Ok(bundle)
+}
+
+pub(super) async fn sync_diagnostic_report(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    report: &DomainDiagnosticReport,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = diagnostic_report_bundle(fhir_cfg, event, report, oscar_cfg)?;
     let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
-    let identifier = format!("{}|{}", fhir_cfg.oscar_cpp_condition_system, event.payload().source_id());
+    let identifier = format!("{}|{}", fhir_cfg.oscar_consult_response_system, event.payload().source_id());
     info!(
         "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
         event.idempotency_key(), identifier, result.fhir_id, result.version_id
@@ -127,22 +196,33 @@ pub(super) async fn sync_family_member_history(
     Ok(result)
 }
 
-pub(super) async fn sync_diagnostic_report(
-    client: &reqwest::Client,
+pub(super) fn diagnostic_report_bundle(
     fhir_cfg: &FhirConfig,
-    token: Option<String>,
     event: &SyncEvent,
     report: &DomainDiagnosticReport,
     oscar_cfg: &OscarConfig,
-) -> Result<FhirResult, SyncFailure> {
+) -> Result<Bundle, SyncFailure> {
     let fhir_report = build_diagnostic_report(report, fhir_cfg, oscar_cfg, event.op())?;
     let bundle = build_conditional_put_bundle(
+        fhir_cfg,
         FhirResource::DiagnosticReport(Box::new(fhir_report)),
         &fhir_cfg.oscar_consult_response_system,
         event,
     );
+    Ok(bundle)
+}
+
+pub(super) async fn sync_service_request(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    request: &DomainServiceRequest,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = service_request_bundle(fhir_cfg, event, request, oscar_cfg)?;
     let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
-    let identifier = format!("{}|{}", fhir_cfg.oscar_consult_response_system, event.payload().source_id());
+    let identifier = format!("{}|{}", fhir_cfg.oscar_consult_request_system, event.payload().source_id());
     info!(
         "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
         event.idempotency_key(), identifier, result.fhir_id, result.version_id
@@ -150,34 +230,28 @@ pub(super) async fn sync_diagnostic_report(
     Ok(result)
 }
 
-pub(super) async fn sync_service_request(
-    client: &reqwest::Client,
+pub(super) fn service_request_bundle(
     fhir_cfg: &FhirConfig,
-    token: Option<String>,
     event: &SyncEvent,
     request: &DomainServiceRequest,
     oscar_cfg: &OscarConfig,
-) -> Result<FhirResult, SyncFailure> {
+) -> Result<Bundle, SyncFailure> {
     let fhir_request = build_service_request(request, fhir_cfg, oscar_cfg, event.op())?;
     let bundle = build_conditional_put_bundle(
+        fhir_cfg,
         FhirResource::ServiceRequest(Box::new(fhir_request)),
         &fhir_cfg.oscar_consult_request_system,
         event,
     );
-    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
-    let identifier = format!("{}|{}", fhir_cfg.oscar_consult_request_system, event.payload().source_id());
-    info!(
-        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
-        event.idempotency_key(), identifier, result.fhir_id, result.version_id
-    );
-    Ok(result)
+    Ok(bundle)
 }
 
 // ---------------------------------------------------------------------------
 // Shared transaction helpers
 // ---------------------------------------------------------------------------
 
-fn build_conditional_put_bundle(
+pub(super) fn build_conditional_put_bundle(
+    fhir_cfg: &FhirConfig,
     fhir_resource: FhirResource,
     identifier_system: &str,
     event: &SyncEvent,
@@ -190,7 +264,7 @@ fn build_conditional_put_bundle(
     bundle.r#type = "transaction".into();
     bundle.entry.push(BundleEntry {
         full_url: Some(format!("urn:uuid:{}", event.idempotency_key()).into()),
-        resource: Some(fhir_resource),
+        resource: Some(apply_column_overrides(apply_delete_policy(fhir_resource, fhir_cfg, event), event)),
         request: Some(BundleEntryRequest {
             method: "PUT".into(),
             url: conditional_url.into(),
@@ -201,12 +275,31 @@ fn build_conditional_put_bundle(
     bundle
 }
 
-async fn send_transaction_bundle(
+pub(super) async fn send_transaction_bundle(
     client: &reqwest::Client,
     fhir_cfg: &FhirConfig,
     token: Option<String>,
     bundle: &Bundle,
 ) -> Result<FhirResult, SyncFailure> {
+    let response_bundle = send_transaction(client, fhir_cfg, token, bundle).await?;
+    match response_bundle.as_ref().and_then(|b| b.entry.first()) {
+        Some(entry) => response_entry_result(entry),
+        None => Ok(FhirResult {
+            fhir_id: String::new(),
+            version_id: None,
+            applied: true,
+        }),
+    }
+}
+
+/// POSTs a transaction Bundle and returns HAPI's response Bundle, if it sent
+/// a readable one.
+pub(super) async fn send_transaction(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    bundle: &Bundle,
+) -> Result<Option<Bundle>, SyncFailure> {
     let body = fhirbolt::json::to_string(bundle, None)
         .context("serializing FHIR transaction Bundle")
         .map_err(SyncFailure::Permanent)?;
@@ -230,52 +323,53 @@ async fn send_transaction_bundle(
 
     if !status.is_success() {
         let text = resp.text().await.unwrap_or_default();
-        let err = anyhow::anyhow!("HAPI transaction Bundle failed ({status}): {text}");
-        if status.is_client_error() && status.as_u16() != 429 {
-            return Err(SyncFailure::Permanent(err));
-        }
-        return Err(SyncFailure::Retryable(err));
+        return Err(classify_http_error(status, &text, "HAPI transaction Bundle"));
     }
 
     let body_text = resp.text().await.unwrap_or_default();
+    if body_text.trim().is_empty() {
+        return Ok(None);
+    }
+    Ok(fhirbolt::json::from_str::<Bundle>(&body_text, None).ok())
+}
+
+/// Reads the id and version HAPI assigned from one transaction-response
+/// entry. A non-2xx entry status is a permanent failure.
+pub(super) fn response_entry_result(entry: &BundleEntry) -> Result<FhirResult, SyncFailure> {
     let mut result = FhirResult {
         fhir_id: String::new(),
         version_id: None,
+        applied: true,
+    };
+    let Some(response) = &entry.response else {
+        return Ok(result);
     };
 
-    if !body_text.trim().is_empty() {
-        if let Ok(response_bundle) = fhirbolt::json::from_str::<Bundle>(&body_text, None) {
-            if let Some(entry) = response_bundle.entry.first() {
-                if let Some(response) = &entry.response {
-                    let entry_status = response
-                        .status
-                        .value
-                        .as_deref()
-                        .unwrap_or("")
-                        .to_ascii_lowercase();
-                    if !entry_status.starts_with('2') {
-                        return Err(SyncFailure::Permanent(anyhow::anyhow!(
-                            "HAPI transaction Bundle entry failed with status '{entry_status}'"
-                        )));
-                    }
-
-                    if let Some(loc) = response
-                        .location
-                        .as_ref()
-                        .and_then(|l| l.value.as_deref())
-                    {
-                        result.fhir_id = parse_location_id(loc).unwrap_or_default();
-                        result.version_id = parse_location_version_id(loc);
-                    }
-
-                    if result.fhir_id.is_empty() {
-                        if let Some(etag) = response.etag.as_ref().and_then(|e| e.value.as_deref()) {
-                            result.fhir_id = parse_location_id(etag).unwrap_or_default();
-                            result.version_id = parse_location_version_id(etag);
-                        }
-                    }
-                }
-            }
+    let entry_status = response
+        .status
+        .value
+        .as_deref()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    if !entry_status.starts_with('2') {
+        return Err(SyncFailure::Permanent(anyhow::anyhow!(
+            "HAPI transaction Bundle entry failed with status '{entry_status}'"
+        )));
+    }
+
+    if let Some(loc) = response
+        .location
+        .as_ref()
+        .and_then(|l| l.value.as_deref())
+    {
+        result.fhir_id = parse_location_id(loc).unwrap_or_default();
+        result.version_id = parse_location_version_id(loc);
+    }
+
+    if result.fhir_id.is_empty() {
+        if let Some(etag) = response.etag.as_ref().and_then(|e| e.value.as_deref()) {
+            result.fhir_id = parse_location_id(etag).unwrap_or_default();
+            result.version_id = parse_location_version_id(etag);
         }
     }
 
@@ -286,7 +380,7 @@ async fn send_transaction_bundle(
 // Reference builders
 // ---------------------------------------------------------------------------
 
-fn conditional_reference(system: &str, value: &str, resource: &str) -> Reference {
+pub(super) fn conditional_reference(system: &str, value: &str, resource: &str) -> Reference {
     let sys: String = url::form_urlencoded::byte_serialize(system.as_bytes()).collect();
     let val: String = url::form_urlencoded::byte_serialize(value.as_bytes()).collect();
     Reference {
@@ -295,19 +389,23 @@ fn conditional_reference(system: &str, value: &str, resource: &str) -> Reference
     }
 }
 
-fn patient_ref(fhir_cfg: &FhirConfig, demographic_no: &str) -> Reference {
+pub(super) fn patient_ref(fhir_cfg: &FhirConfig, demographic_no: &str) -> Reference {
     conditional_reference(&fhir_cfg.oscar_demographic_system, demographic_no, "Patient")
 }
 
-fn practitioner_ref(fhir_cfg: &FhirConfig, provider_no: &str) -> Reference {
+pub(super) fn practitioner_ref(fhir_cfg: &FhirConfig, provider_no: &str) -> Reference {
     conditional_reference(&fhir_cfg.oscar_provider_system, provider_no, "Practitioner")
 }
 
+pub(super) fn specialist_ref(fhir_cfg: &FhirConfig, spec_id: &str) -> Reference {
+    conditional_reference(&fhir_cfg.oscar_specialist_system, spec_id, "Practitioner")
+}
+
 fn appointment_ref(fhir_cfg: &FhirConfig, appointment_no: &str) -> Reference {
     conditional_reference(&fhir_cfg.oscar_appointment_system, appointment_no, "Appointment")
 }
 
-fn encounter_ref(fhir_cfg: &FhirConfig, note_id_value: &str) -> Reference {
+pub(super) fn encounter_ref(fhir_cfg: &FhirConfig, note_id_value: &str) -> Reference {
     conditional_reference(&fhir_cfg.oscar_note_system, note_id_value, "Encounter")
 }
 
@@ -499,7 +597,7 @@ fn build_document_reference(
     Ok(dr)
 }
 
-fn build_condition(condition: &DomainCondition, fhir_cfg: &FhirConfig) -> Result<Condition, SyncFailure> {
+fn build_condition(condition: &DomainCondition, fhir_cfg: &FhirConfig, op: Op) -> Result<Condition, SyncFailure> {
     let mut cond = Condition::default();
 
     cond.meta = Some(Box::new(Meta {
@@ -507,11 +605,7 @@ fn build_condition(condition: &DomainCondition, fhir_cfg: &FhirConfig) -> Result
         ..Default::default()
     }));
 
-    let identifier_system = if condition.source_table == "dxresearch" {
-        &fhir_cfg.oscar_dxresearch_system
-    } else {
-        &fhir_cfg.oscar_cpp_condition_system
-    };
+    let identifier_system = condition_identifier_system(condition, fhir_cfg);
     cond.identifier.push(Identifier {
         system: Some(identifier_system.clone().into()),
         value: Some(condition.source_id.clone().into()),
@@ -540,16 +634,45 @@ fn build_condition(condition: &DomainCondition, fhir_cfg: &FhirConfig) -> Result
         }));
     }
 
+    let terminology = crate::terminology::terminology();
+    let mut translated: Vec<Coding> = Vec::new();
+    if let (Some(terminology), Some(system), Some(code)) =
+        (terminology, condition.coding_system.as_deref(), condition.code.as_deref())
+    {
+        // A delete only tombstones what is already there; don't strand it on a code.
+        if op != Op::Delete
+            && condition.source_table == "dxresearch"
+            && terminology.source_code_exists(system, code) == Some(false)
+        {
+            return Err(SyncFailure::Permanent(anyhow::anyhow!(
+                "unknown_dx_code: {system} {code} for dxresearch_no={}",
+                condition.source_id
+            )));
+        }
+        translated = terminology
+            .translate(system, code)
+            .iter()
+            .map(|t| Coding {
+                system: Some(t.system.clone().into()),
+                code: Some(t.code.clone().into()),
+                display: t.display.clone().map(Into::into),
+                ..Default::default()
+            })
+            .collect();
+    }
+
     if condition.coding_system.as_deref() == Some("icd9") && condition.code.is_some() {
         let code = condition.code.as_deref().unwrap();
+        let mut coding = vec![Coding {
+            system: Some(fhir_cfg.icd9_system.clone().into()),
+            code: Some(code.into()),
+            display: condition.display.clone().map(|d| d.into()),
+            ..Default::default()
+        }];
+        coding.append(&mut translated);
         cond.code = Some(Box::new(CodeableConcept {
             text: condition.problem_description.clone().map(|t| t.into()),
-            coding: vec![Coding {
-                system: Some(fhir_cfg.icd9_system.clone().into()),
-                code: Some(code.into()),
-                display: condition.display.clone().map(|d| d.into()),
-                ..Default::default()
-            }],
+            coding,
             ..Default::default()
         }));
     } else if condition.problem_description.is_some() {
@@ -558,9 +681,11 @@ fn build_condition(condition: &DomainCondition, fhir_cfg: &FhirConfig) -> Result
             ..Default::default()
         }));
     } else if condition.code.is_some() {
-        // Unknown coding_system but we have a code; emit text only.
+        // No system URL for a non-ICD-9 source code; emit it as text, with
+        // any translations as the codings.
         cond.code = Some(Box::new(CodeableConcept {
             text: condition.code.clone().map(|c| c.into()),
+            coding: translated,
             ..Default::default()
         }));
     }
@@ -852,6 +977,12 @@ fn build_service_request(
         sr.requester = Some(Box::new(practitioner_ref(fhir_cfg, provider_no)));
     }
 
+    // The specialist the referral was sent to, synced from
+    // `professionalSpecialists` under its own identifier system.
+    if let Some(spec_id) = &req.spec_id {
+        sr.performer.push(specialist_ref(fhir_cfg, spec_id));
+    }
+
     if let Some(reason) = &req.reason {
         sr.code = Some(Box::new(CodeableConcept {
             text: Some(reason.clone().into()),
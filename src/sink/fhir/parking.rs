@@ -0,0 +1,538 @@
+//! Parking lot (`sync.parking_path`): events HAPI rejected because a
+//! conditional reference matched nothing yet (HAPI-1091), e.g. an
+//! Appointment whose Patient hasn't been synced.
+//!
+//! Parked events are keyed by the missing reference, percent-decoded
+//! (`Patient?identifier=system|value`), and replayed once an event writing
+//! that target succeeds on any worker. A replay goes back through the
+//! dispatcher to the worker owning its ordering key, and leaves the log only
+//! once the sink is done with it. A parked event is dropped instead once a
+//! later write of its resource succeeds. Events still parked after
+//! `sync.parking_ttl_secs` are dead-lettered.
+//!
+//! The file is an append-only JSONL log of parked events and removals,
+//! compacted when it is opened and whenever removals outnumber what's still
+//! parked. Callers keep it off the async runtime. Unlike the dead-letter
+//! file, parked events carry full payloads (PHI).
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::FhirConfig;
+use crate::event::SyncEvent;
+use crate::queue::{Record, RecordRef};
+
+use super::conditional_identifier;
+use super::delete::skips_put;
+
+/// A write HAPI refused because `reference` matched no resource.
+#[derive(Debug)]
+pub(super) struct UnresolvedReference {
+    /// e.g. `Patient?identifier=https://…/oscar-demographic|123`.
+    pub(super) reference: String,
+}
+
+impl std::fmt::Display for UnresolvedReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unresolved_reference: no resource matches {}",
+            self.reference
+        )
+    }
+}
+
+impl std::error::Error for UnresolvedReference {}
+
+/// The reference named in a HAPI-1091 error body, e.g.
+/// `HAPI-1091: Invalid match URL "Patient?identifier=…|123" - No resources match this search`.
+pub(super) fn unresolved_reference(text: &str) -> Option<UnresolvedReference> {
+    let rest = &text[text.find("HAPI-1091")?..];
+    let query = rest.find("?identifier=")?;
+    let start = rest[..query]
+        .rfind(|c: char| !c.is_ascii_alphabetic())
+        .map_or(0, |i| i + 1);
+    // The body is JSON, so the closing quote is usually escaped.
+    let end = rest[query..]
+        .find(|c: char| c == '"' || c == '\\' || c.is_whitespace())
+        .map_or(rest.len(), |i| query + i);
+    Some(UnresolvedReference {
+        reference: normalize(&rest[start..end])?,
+    })
+}
+
+/// `Type?identifier=system|value` with the identifier percent-decoded; some
+/// builders encode the system and some don't.
+fn normalize(reference: &str) -> Option<String> {
+    let (resource_type, query) = reference.split_once('?')?;
+    let (_, identifier) =
+        url::form_urlencoded::parse(query.as_bytes()).find(|(k, _)| k == "identifier")?;
+    Some(format!("{resource_type}?identifier={identifier}"))
+}
+
+/// The reference other events would use for the resource `event` writes,
+/// or `None` when it leaves nothing to reference (hard-deleted or ignored).
+pub(super) fn resolved_reference(fhir_cfg: &FhirConfig, event: &SyncEvent) -> Option<String> {
+    if skips_put(fhir_cfg, event) {
+        return None;
+    }
+    Some(format!(
+        "{}?identifier={}",
+        event.resource_type().as_path(),
+        conditional_identifier(fhir_cfg, event)
+    ))
+}
+
+/// Removals kept in the log before it's worth compacting.
+const COMPACT_MIN_REMOVALS: usize = 1024;
+
+#[derive(Serialize)]
+struct EntryRef<'a> {
+    reference: &'a str,
+    parked_at: DateTime<Utc>,
+    event: RecordRef<'a>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    reference: String,
+    parked_at: DateTime<Utc>,
+    event: Record,
+}
+
+/// The entry parked on `reference` at `parked_at` is gone: replayed,
+/// superseded or expired.
+#[derive(Serialize, Deserialize)]
+struct Removal {
+    reference: String,
+    removed: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Line {
+    Parked(Box<Entry>),
+    Removed(Removal),
+}
+
+/// Identifies the resource an event writes, across sources.
+pub(super) fn resource_key(event: &SyncEvent) -> String {
+    format!("{}/{}", event.resource_type().as_path(), event.payload().source_id())
+}
+
+pub(super) struct ParkingLot {
+    path: String,
+    /// Waiting events by the reference they need, oldest first.
+    parked: HashMap<String, Vec<(DateTime<Utc>, SyncEvent)>>,
+    /// Released events the sink isn't done with, by reference and parked
+    /// time. They stay in the log until `finish`, so a crash replays them.
+    replaying: HashMap<(String, DateTime<Utc>), SyncEvent>,
+    /// Parked and replaying entries per `resource_key`, so a write that
+    /// supersedes nothing doesn't scan the lot.
+    resources: HashMap<String, usize>,
+    /// Parked times are unique, so a removal names exactly one entry.
+    last_parked_at: DateTime<Utc>,
+    file: File,
+    /// Removal lines appended since the last compaction.
+    removals: usize,
+}
+
+impl ParkingLot {
+    pub(super) fn open(path: &str) -> Result<Self> {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).ok();
+        }
+
+        let mut parked: HashMap<String, Vec<_>> = HashMap::new();
+        if let Ok(contents) = fs::read_to_string(path) {
+            // A torn last line from a crash is skipped, not fatal.
+            for line in contents
+                .lines()
+                .filter_map(|l| serde_json::from_str::<Line>(l).ok())
+            {
+                match line {
+                    Line::Parked(entry) => parked
+                        .entry(entry.reference)
+                        .or_default()
+                        .push((entry.parked_at, entry.event.into_event())),
+                    Line::Removed(removal) => {
+                        // A replay parked again keeps its time; the removal
+                        // of the old entry, logged after, takes just one.
+                        if let Some(waiting) = parked.get_mut(&removal.reference) {
+                            if let Some(i) = waiting.iter().position(|(at, _)| *at == removal.removed) {
+                                waiting.remove(i);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        parked.retain(|_, waiting| !waiting.is_empty());
+        for waiting in parked.values_mut() {
+            waiting.sort_by_key(|(at, _)| *at);
+        }
+
+        let mut resources: HashMap<String, usize> = HashMap::new();
+        for (_, event) in parked.values().flatten() {
+            *resources.entry(resource_key(event)).or_default() += 1;
+        }
+        let last_parked_at = parked
+            .values()
+            .flatten()
+            .map(|(at, _)| *at)
+            .max()
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+        let replaying = HashMap::new();
+        let file = compact(path, &parked, &replaying)?;
+        Ok(Self {
+            path: path.to_string(),
+            parked,
+            replaying,
+            resources,
+            last_parked_at,
+            file,
+            removals: 0,
+        })
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.parked.values().map(Vec::len).sum::<usize>() + self.replaying.len()
+    }
+
+    /// The references events are waiting on.
+    pub(super) fn references(&self) -> Vec<String> {
+        self.parked.keys().cloned().collect()
+    }
+
+    /// Parks `event` until `reference` is synced. The event's queue offset
+    /// isn't kept: it's acknowledged once parked, so the entry is synced to
+    /// disk first. A replay parked again keeps its parked time, which orders
+    /// it among other events for the same resource. Fails, leaving nothing
+    /// parked, when it can't be.
+    pub(super) fn park(&mut self, reference: &str, event: &SyncEvent) -> Result<()> {
+        let parked_at = match event.parked() {
+            Some((_, at)) => at,
+            None => Utc::now().max(self.last_parked_at + chrono::Duration::nanoseconds(1)),
+        };
+        let event = SyncEvent::new(
+            event.source().clone(),
+            event.op(),
+            event.payload().clone(),
+            event.occurred_at(),
+        )
+        .with_column_values(event.column_values().to_vec());
+        let line = serde_json::to_string(&EntryRef {
+            reference,
+            parked_at,
+            event: RecordRef::new(&event, None),
+        })
+        .context("serializing parked event")?;
+        writeln!(self.file, "{line}").context("appending parked event")?;
+        self.file.sync_data().context("syncing parked events")?;
+        self.last_parked_at = self.last_parked_at.max(parked_at);
+        *self.resources.entry(resource_key(&event)).or_default() += 1;
+        let waiting = self.parked.entry(reference.to_string()).or_default();
+        let i = waiting.partition_point(|(at, _)| *at < parked_at);
+        waiting.insert(i, (parked_at, event));
+        Ok(())
+    }
+
+    /// Takes the events waiting on `reference`, oldest first, each marked
+    /// with its entry. They stay in the log until `finish`.
+    pub(super) fn release(&mut self, reference: &str) -> Vec<SyncEvent> {
+        let Some(waiting) = self.parked.remove(reference) else {
+            return Vec::new();
+        };
+        waiting
+            .into_iter()
+            .map(|(at, event)| {
+                self.replaying.insert((reference.to_string(), at), event.clone());
+                event.with_parked(reference.to_string(), at)
+            })
+            .collect()
+    }
+
+    /// Whether a released event is still due; false once a later write of
+    /// its resource has superseded it.
+    pub(super) fn is_replaying(&self, event: &SyncEvent) -> bool {
+        event
+            .parked()
+            .is_none_or(|(reference, at)| self.replaying.contains_key(&(reference.to_string(), at)))
+    }
+
+    /// Removes the entry a replay was released from once the sink is done
+    /// with it, whatever the outcome.
+    pub(super) fn finish(&mut self, reference: &str, parked_at: DateTime<Utc>) {
+        if let Some(event) = self.replaying.remove(&(reference.to_string(), parked_at)) {
+            self.forget(&event);
+            self.remove_or_warn(reference, parked_at);
+        }
+    }
+
+    /// Drops the entries for `resource` parked before `before`: a write of
+    /// the resource has superseded them. Returns how many were dropped.
+    pub(super) fn supersede(&mut self, resource: &str, before: DateTime<Utc>) -> usize {
+        if !self.resources.contains_key(resource) {
+            return 0;
+        }
+        let stale = |at: &DateTime<Utc>, parked: &SyncEvent| *at < before && resource_key(parked) == resource;
+
+        let mut dropped = Vec::new();
+        for (reference, waiting) in self.parked.iter_mut() {
+            let (old, keep): (Vec<_>, Vec<_>) = waiting.drain(..).partition(|(at, e)| stale(at, e));
+            *waiting = keep;
+            dropped.extend(old.into_iter().map(|(at, e)| (reference.clone(), at, e)));
+        }
+        self.parked.retain(|_, waiting| !waiting.is_empty());
+        let replays: Vec<_> = self
+            .replaying
+            .iter()
+            .filter(|((_, at), e)| stale(at, e))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in replays {
+            if let Some(e) = self.replaying.remove(&key) {
+                dropped.push((key.0, key.1, e));
+            }
+        }
+
+        for (reference, at, event) in &dropped {
+            self.forget(event);
+            self.remove_or_warn(reference, *at);
+        }
+        dropped.len()
+    }
+
+    /// Takes the events parked for longer than `ttl`, with their reference.
+    pub(super) fn expire(&mut self, ttl: Duration) -> Vec<(String, SyncEvent)> {
+        let Some(cutoff) = chrono::Duration::from_std(ttl)
+            .ok()
+            .and_then(|ttl| Utc::now().checked_sub_signed(ttl))
+        else {
+            return Vec::new();
+        };
+        let mut expired = Vec::new();
+        for (reference, waiting) in self.parked.iter_mut() {
+            let (old, keep): (Vec<_>, Vec<_>) = waiting.drain(..).partition(|(at, _)| *at < cutoff);
+            *waiting = keep;
+            expired.extend(old.into_iter().map(|(at, e)| (reference.clone(), at, e)));
+        }
+        self.parked.retain(|_, waiting| !waiting.is_empty());
+        for (reference, at, event) in &expired {
+            self.forget(event);
+            self.remove_or_warn(reference, *at);
+        }
+        expired.into_iter().map(|(reference, _, e)| (reference, e)).collect()
+    }
+
+    fn forget(&mut self, event: &SyncEvent) {
+        let resource = resource_key(event);
+        if let Some(count) = self.resources.get_mut(&resource) {
+            *count -= 1;
+            if *count == 0 {
+                self.resources.remove(&resource);
+            }
+        }
+    }
+
+    /// Memory stays authoritative; a lost removal only means a restart
+    /// replays or expires that event again.
+    fn remove_or_warn(&mut self, reference: &str, removed: DateTime<Utc>) {
+        let logged = serde_json::to_string(&Removal {
+            reference: reference.to_string(),
+            removed,
+        })
+        .context("serializing parking removal")
+        .and_then(|line| writeln!(self.file, "{line}").context("appending parking removal"));
+        if let Err(e) = logged {
+            warn!(
+                "fhir sink: failed to update parked events in {}: {e:?}",
+                self.path
+            );
+            return;
+        }
+
+        self.removals += 1;
+        if self.removals >= COMPACT_MIN_REMOVALS && self.removals > self.len() {
+            match compact(&self.path, &self.parked, &self.replaying) {
+                Ok(file) => {
+                    self.file = file;
+                    self.removals = 0;
+                }
+                Err(e) => warn!(
+                    "fhir sink: failed to compact parked events in {}: {e:?}",
+                    self.path
+                ),
+            }
+        }
+    }
+}
+
+/// Rewrites the log as just the parked and replaying events, like the
+/// content-hash compaction: temp file, then rename. Returns the log opened
+/// for append.
+fn compact(
+    path: &str,
+    parked: &HashMap<String, Vec<(DateTime<Utc>, SyncEvent)>>,
+    replaying: &HashMap<(String, DateTime<Utc>), SyncEvent>,
+) -> Result<File> {
+    let tmp_path = format!("{path}.tmp");
+    {
+        let mut options = OpenOptions::new();
+        options.create(true).write(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut tmp = options
+            .open(&tmp_path)
+            .with_context(|| format!("creating {tmp_path}"))?;
+        let waiting = parked
+            .iter()
+            .flat_map(|(reference, waiting)| waiting.iter().map(move |(at, e)| (reference, at, e)));
+        let replays = replaying.iter().map(|((reference, at), e)| (reference, at, e));
+        for (reference, parked_at, event) in waiting.chain(replays) {
+            let line = serde_json::to_string(&EntryRef {
+                reference,
+                parked_at: *parked_at,
+                event: RecordRef::new(event, None),
+            })
+            .context("serializing parked event")?;
+            writeln!(tmp, "{line}").context("writing parked events")?;
+        }
+        tmp.sync_all().ok();
+    }
+    fs::rename(&tmp_path, path).with_context(|| format!("renaming {tmp_path} -> {path}"))?;
+
+    OpenOptions::new()
+        .append(true)
+        .open(path)
+        .with_context(|| format!("opening parked events {path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::resource::DomainResource;
+    use crate::event::{Op, Source};
+
+    #[test]
+    fn hapi_1091_reference_is_decoded() {
+        let body = r#"{"resourceType":"OperationOutcome","issue":[{"severity":"error","code":"processing","diagnostics":"HAPI-1091: Invalid match URL \"Patient?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-demographic|123\" - No resources match this search"}]}"#;
+        assert_eq!(
+            unresolved_reference(body).unwrap().reference,
+            "Patient?identifier=https://arsmedicatech.com/fhir/sid/oscar-demographic|123"
+        );
+        assert!(unresolved_reference(r#"{"diagnostics":"HAPI-0450: Failed to parse"}"#).is_none());
+    }
+
+    #[test]
+    fn parked_until_reference_is_synced() {
+        let dir = std::env::temp_dir().join(format!("fhir-sync-parking-{}", std::process::id()));
+        let path = dir.join("parked.jsonl").to_string_lossy().into_owned();
+        let _ = fs::remove_file(&path);
+        let fhir_cfg = FhirConfig::default();
+
+        let appointment = SyncEvent::new(
+            Source::OscarBinlog {
+                table: "appointment".to_string(),
+            },
+            Op::Upsert,
+            DomainResource::Appointment(super::super::tests::appt_payload()),
+            Utc::now(),
+        );
+        let patient = SyncEvent::new(
+            Source::OscarBinlog {
+                table: "demographic".to_string(),
+            },
+            Op::Upsert,
+            DomainResource::Patient(
+                serde_json::from_value(serde_json::json!({
+                    "demographic_no": "101",
+                    "addresses": [],
+                    "contacts": [],
+                }))
+                .unwrap(),
+            ),
+            Utc::now(),
+        );
+        let reference = resolved_reference(&fhir_cfg, &patient).unwrap();
+
+        let mut lot = ParkingLot::open(&path).unwrap();
+        lot.park(&reference, &appointment).unwrap();
+        lot.park("Patient?identifier=other|1", &appointment)
+            .unwrap();
+        drop(lot);
+
+        let mut lot = ParkingLot::open(&path).unwrap();
+        assert_eq!(lot.len(), 2);
+        assert!(lot.expire(Duration::from_secs(60)).is_empty());
+        let released = lot.release(&reference);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].idempotency_key(), appointment.idempotency_key());
+        assert!(lot.release(&reference).is_empty());
+        drop(lot);
+
+        // Released but never finished: a restart still has it.
+        let mut lot = ParkingLot::open(&path).unwrap();
+        assert_eq!(lot.len(), 2);
+        let released = lot.release(&reference);
+        assert!(lot.is_replaying(&released[0]));
+        let (parked_on, parked_at) = released[0].parked().unwrap();
+        lot.finish(parked_on, parked_at);
+        assert_eq!(lot.len(), 1);
+        drop(lot);
+
+        // The finished replay was logged, so only the other reference is left.
+        let mut lot = ParkingLot::open(&path).unwrap();
+        assert_eq!(lot.len(), 1);
+        assert_eq!(lot.expire(Duration::ZERO).len(), 1);
+        assert_eq!(ParkingLot::open(&path).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn later_write_supersedes_parked_events() {
+        let dir = std::env::temp_dir().join(format!("fhir-sync-parking-{}", std::process::id()));
+        let path = dir.join("superseded.jsonl").to_string_lossy().into_owned();
+        let _ = fs::remove_file(&path);
+        let appointment = SyncEvent::new(
+            Source::OscarBinlog {
+                table: "appointment".to_string(),
+            },
+            Op::Upsert,
+            DomainResource::Appointment(super::super::tests::appt_payload()),
+            Utc::now(),
+        );
+        let resource = resource_key(&appointment);
+
+        let mut lot = ParkingLot::open(&path).unwrap();
+        lot.park("Patient?identifier=a|1", &appointment).unwrap();
+        lot.park("Patient?identifier=a|1", &appointment).unwrap();
+        let released = lot.release("Patient?identifier=a|1");
+        let (_, first_at) = released[0].parked().unwrap();
+        lot.park("Location?identifier=b|2", &appointment).unwrap();
+
+        // A replay only supersedes what was parked before it.
+        assert_eq!(lot.supersede(&resource, first_at), 0);
+        assert_eq!(lot.supersede(&resource, released[1].parked().unwrap().1), 1);
+        assert!(!lot.is_replaying(&released[0]));
+        assert!(lot.is_replaying(&released[1]));
+        // A new event supersedes the rest.
+        assert_eq!(lot.supersede(&resource, DateTime::<Utc>::MAX_UTC), 2);
+        assert!(!lot.is_replaying(&released[1]));
+        assert_eq!(lot.len(), 0);
+        drop(lot);
+
+        assert_eq!(ParkingLot::open(&path).unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,217 @@
+//! Oscar `provider` role columns -> FHIR `PractitionerRole`.
+//!
+//! One role per provider, keyed on `provider_no` under its own system so it
+//! sits alongside the provider's `Practitioner` without sharing an
+//! identifier. Deactivating the provider in Oscar (`status` 0) deactivates
+//! the role rather than deleting it.
+
+use fhirbolt::model::r4b::resources::{Bundle, PractitionerRole};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{CodeableConcept, Coding, Extension, ExtensionValue, Identifier, Meta};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::practitioner_role::DomainPractitionerRole;
+use crate::event::Op;
+
+use super::location::site_ref;
+use super::oscar2::{build_conditional_put_bundle, conditional_reference, practitioner_ref, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+const OSCAR_PROVIDER_TYPE_SYSTEM: &str = "https://arsmedicatech.com/fhir/CodeSystem/oscar-provider-type";
+const OSCAR_PROVIDER_SPECIALTY_SYSTEM: &str = "https://arsmedicatech.com/fhir/CodeSystem/oscar-provider-specialty";
+const OSCAR_PROVIDER_TEAM_URL: &str = "https://arsmedicatech.com/fhir/StructureDefinition/oscar-provider-team";
+const OSCAR_PROVIDER_SUPERVISOR_URL: &str = "https://arsmedicatech.com/fhir/StructureDefinition/oscar-provider-supervisor";
+
+pub(super) async fn sync_practitioner_role(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    role: &DomainPractitionerRole,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = practitioner_role_bundle(fhir_cfg, event, role, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_practitioner_role_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn practitioner_role_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    role: &DomainPractitionerRole,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_role = build_practitioner_role(role, fhir_cfg, oscar_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::PractitionerRole(Box::new(fhir_role)),
+        &fhir_cfg.oscar_practitioner_role_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Builds the `PractitionerRole`. `code` always carries the raw Oscar
+/// provider type; the configured role code is added when the type is
+/// mapped, and `job_title` rides along as a text-only code.
+fn build_practitioner_role(
+    role: &DomainPractitionerRole,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> PractitionerRole {
+    let mut r = PractitionerRole::default();
+
+    r.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    r.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_practitioner_role_system.clone().into()),
+        value: Some(role.provider_no.clone().into()),
+        ..Default::default()
+    });
+
+    r.active = Some((role.active && op != Op::Delete).into());
+    r.practitioner = Some(Box::new(practitioner_ref(fhir_cfg, &role.provider_no)));
+
+    if let Some(provider_type) = &role.provider_type {
+        let mut coding = vec![Coding {
+            system: Some(OSCAR_PROVIDER_TYPE_SYSTEM.to_string().into()),
+            code: Some(provider_type.clone().into()),
+            ..Default::default()
+        }];
+        if let Some(code) = oscar_cfg.provider_type_role_map.get(provider_type) {
+            coding.push(Coding {
+                system: Some(fhir_cfg.practitioner_role_code_system.clone().into()),
+                code: Some(code.clone().into()),
+                ..Default::default()
+            });
+        }
+        r.code.push(CodeableConcept {
+            coding,
+            text: Some(provider_type.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    if let Some(job_title) = &role.job_title {
+        r.code.push(CodeableConcept {
+            text: Some(job_title.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    if let Some(specialty) = &role.specialty {
+        r.specialty.push(CodeableConcept {
+            coding: vec![Coding {
+                system: Some(OSCAR_PROVIDER_SPECIALTY_SYSTEM.to_string().into()),
+                code: Some(specialty.clone().into()),
+                ..Default::default()
+            }],
+            text: Some(specialty.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    if let Some(clinic_no) = &role.clinic_no {
+        r.organization = Some(Box::new(conditional_reference(
+            &fhir_cfg.oscar_clinic_system,
+            clinic_no,
+            "Organization",
+        )));
+    }
+    r.location = role.site_ids.iter().map(|site_id| site_ref(fhir_cfg, site_id)).collect();
+
+    if let Some(team) = &role.team {
+        r.extension.push(Extension {
+            url: OSCAR_PROVIDER_TEAM_URL.to_string(),
+            value: Some(ExtensionValue::String(team.clone().into())),
+            ..Default::default()
+        });
+    }
+    if let Some(supervisor) = &role.supervisor {
+        r.extension.push(Extension {
+            url: OSCAR_PROVIDER_SUPERVISOR_URL.to_string(),
+            value: Some(ExtensionValue::Reference(Box::new(practitioner_ref(fhir_cfg, supervisor)))),
+            ..Default::default()
+        });
+    }
+
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role() -> DomainPractitionerRole {
+        DomainPractitionerRole {
+            provider_no: "1001".to_string(),
+            provider_type: Some("doctor".to_string()),
+            specialty: Some("Family Medicine".to_string()),
+            team: Some("Blue".to_string()),
+            supervisor: Some("1002".to_string()),
+            job_title: None,
+            clinic_no: Some("1".to_string()),
+            site_ids: vec!["2".to_string(), "3".to_string()],
+            active: true,
+        }
+    }
+
+    #[test]
+    fn build_practitioner_role_maps_type_specialty_and_links() {
+        let cfg = FhirConfig::default();
+        let r = build_practitioner_role(&role(), &cfg, &OscarConfig::default(), Op::Upsert);
+
+        assert_eq!(r.active.as_ref().and_then(|a| a.value), Some(true));
+        assert_eq!(
+            r.practitioner.as_ref().and_then(|p| p.reference.as_ref()).and_then(|r| r.value.as_deref()),
+            Some("Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-provider|1001")
+        );
+        let codes: Vec<_> = r.code[0]
+            .coding
+            .iter()
+            .filter_map(|c| c.code.as_ref().and_then(|v| v.value.as_deref()))
+            .collect();
+        assert_eq!(codes, vec!["doctor", "doctor"]);
+        assert_eq!(
+            r.code[0].coding[1].system.as_ref().and_then(|s| s.value.as_deref()),
+            Some(cfg.practitioner_role_code_system.as_str())
+        );
+        assert_eq!(r.specialty[0].text, Some("Family Medicine".to_string().into()));
+        assert_eq!(
+            r.organization.as_ref().and_then(|o| o.reference.as_ref()).and_then(|r| r.value.as_deref()),
+            Some("Organization?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-clinic|1")
+        );
+        assert_eq!(r.location.len(), 2);
+        assert_eq!(r.extension.len(), 2);
+    }
+
+    #[test]
+    fn unmapped_provider_type_keeps_raw_type_only() {
+        let mut rl = role();
+        rl.provider_type = Some("receptionist".to_string());
+        let r = build_practitioner_role(&rl, &FhirConfig::default(), &OscarConfig::default(), Op::Upsert);
+        assert_eq!(r.code[0].coding.len(), 1);
+        assert_eq!(r.code[0].text, Some("receptionist".to_string().into()));
+    }
+
+    #[test]
+    fn inactive_provider_or_delete_deactivates_role() {
+        let mut rl = role();
+        rl.active = false;
+        let r = build_practitioner_role(&rl, &FhirConfig::default(), &OscarConfig::default(), Op::Upsert);
+        assert_eq!(r.active.as_ref().and_then(|a| a.value), Some(false));
+
+        let r = build_practitioner_role(&role(), &FhirConfig::default(), &OscarConfig::default(), Op::Delete);
+        assert_eq!(r.active.as_ref().and_then(|a| a.value), Some(false));
+    }
+}
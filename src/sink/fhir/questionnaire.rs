@@ -0,0 +1,318 @@
+//! Oscar eForms -> FHIR `Questionnaire` / `QuestionnaireResponse`.
+//!
+//! Each `eform` definition is published as a generated Questionnaire whose
+//! canonical is `eform_questionnaire_base_url/<fid>`, unless the `fid` is
+//! mapped to an external canonical. Each filled-in `eform_data` row becomes
+//! a QuestionnaireResponse pointing at that canonical, with one item per
+//! `eform_values` field.
+
+use fhirbolt::model::r4b::resources::{
+    Bundle, Questionnaire, QuestionnaireItem, QuestionnaireResponse, QuestionnaireResponseItem,
+    QuestionnaireResponseItemAnswer, QuestionnaireResponseItemAnswerValue,
+};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{Identifier, Meta};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::questionnaire::{DomainQuestionnaire, DomainQuestionnaireResponse};
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, patient_ref, practitioner_ref, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_questionnaire(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    questionnaire: &DomainQuestionnaire,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = questionnaire_bundle(fhir_cfg, event, questionnaire, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_eform_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn questionnaire_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    questionnaire: &DomainQuestionnaire,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_questionnaire = build_questionnaire(questionnaire, fhir_cfg, oscar_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Questionnaire(Box::new(fhir_questionnaire)),
+        &fhir_cfg.oscar_eform_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+pub(super) async fn sync_questionnaire_response(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    response: &DomainQuestionnaireResponse,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = questionnaire_response_bundle(fhir_cfg, event, response, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_eform_data_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn questionnaire_response_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    response: &DomainQuestionnaireResponse,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_response = build_questionnaire_response(response, fhir_cfg, oscar_cfg, event.op())?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::QuestionnaireResponse(Box::new(fhir_response)),
+        &fhir_cfg.oscar_eform_data_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Canonical URL of the Questionnaire for an eForm `fid`: the configured
+/// one if mapped, else the generated one.
+fn questionnaire_canonical(fid: &str, fhir_cfg: &FhirConfig, oscar_cfg: &OscarConfig) -> String {
+    oscar_cfg
+        .eform_questionnaire_map
+        .get(fid)
+        .cloned()
+        .unwrap_or_else(|| format!("{}/{}", fhir_cfg.eform_questionnaire_base_url.trim_end_matches('/'), fid))
+}
+
+/// Builds the generated `Questionnaire`. Every field is a free-text
+/// `string` item: eForm inputs carry no type information once stored.
+fn build_questionnaire(
+    questionnaire: &DomainQuestionnaire,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Questionnaire {
+    let mut q = Questionnaire::default();
+
+    q.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    q.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_eform_system.clone().into()),
+        value: Some(questionnaire.fid.clone().into()),
+        ..Default::default()
+    });
+
+    q.url = Some(questionnaire_canonical(&questionnaire.fid, fhir_cfg, oscar_cfg).into());
+    q.title = questionnaire.form_name.clone().map(Into::into);
+    q.description = questionnaire.subject.clone().map(Into::into);
+    let active = questionnaire.active && op != Op::Delete;
+    q.status = if active { "active" } else { "retired" }.to_string().into();
+    q.subject_type.push("Patient".to_string().into());
+
+    q.item = questionnaire
+        .var_names
+        .iter()
+        .map(|name| QuestionnaireItem {
+            link_id: name.clone().into(),
+            text: Some(name.clone().into()),
+            r#type: "string".to_string().into(),
+            ..Default::default()
+        })
+        .collect();
+
+    q
+}
+
+/// Builds the `QuestionnaireResponse`. A form deleted from the chart
+/// (`status` 0) or a deleted row is `entered-in-error`; patient-independent
+/// forms have no subject, while a patient form without one fails
+/// permanently.
+fn build_questionnaire_response(
+    response: &DomainQuestionnaireResponse,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<QuestionnaireResponse, SyncFailure> {
+    let mut qr = QuestionnaireResponse::default();
+
+    qr.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    qr.identifier = Some(Box::new(Identifier {
+        system: Some(fhir_cfg.oscar_eform_data_system.clone().into()),
+        value: Some(response.fdid.clone().into()),
+        ..Default::default()
+    }));
+
+    qr.questionnaire = Some(questionnaire_canonical(&response.fid, fhir_cfg, oscar_cfg).into());
+
+    let current = response.current && op != Op::Delete;
+    qr.status = if current { "completed" } else { "entered-in-error" }.to_string().into();
+
+    match (&response.demographic_no, response.patient_independent) {
+        (Some(demographic_no), _) => qr.subject = Some(Box::new(patient_ref(fhir_cfg, demographic_no))),
+        (None, true) => {}
+        (None, false) => {
+            return Err(SyncFailure::Permanent(anyhow::anyhow!(
+                "eform_data fdid={} has no demographic_no",
+                response.fdid
+            )))
+        }
+    }
+
+    if let Some(provider_no) = &response.provider_no {
+        qr.author = Some(Box::new(practitioner_ref(fhir_cfg, provider_no)));
+    }
+
+    if let Some(date) = &response.form_date {
+        let authored = match (&response.form_time, oscar_cfg.timezone.as_deref()) {
+            (Some(time), Some(tz)) => super::to_appointment_instant(date, time, tz)?,
+            _ => date.clone(),
+        };
+        qr.authored = Some(authored.into());
+    }
+
+    qr.item = response
+        .answers
+        .iter()
+        .map(|a| QuestionnaireResponseItem {
+            link_id: a.var_name.clone().into(),
+            answer: vec![QuestionnaireResponseItemAnswer {
+                value: Some(QuestionnaireResponseItemAnswerValue::String(a.var_value.clone().into())),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+        .collect();
+
+    Ok(qr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::questionnaire::DomainQuestionnaireAnswer;
+
+    fn oscar_cfg() -> OscarConfig {
+        OscarConfig {
+            timezone: Some("America/Vancouver".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn response() -> DomainQuestionnaireResponse {
+        DomainQuestionnaireResponse {
+            fdid: "10".to_string(),
+            fid: "3".to_string(),
+            form_name: Some("PHQ-9".to_string()),
+            demographic_no: Some("123".to_string()),
+            provider_no: Some("1001".to_string()),
+            form_date: Some("2026-01-05".to_string()),
+            form_time: Some("10:15:00".to_string()),
+            current: true,
+            patient_independent: false,
+            answers: vec![
+                DomainQuestionnaireAnswer {
+                    var_name: "q1".to_string(),
+                    var_value: "2".to_string(),
+                },
+                DomainQuestionnaireAnswer {
+                    var_name: "total".to_string(),
+                    var_value: "14".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn build_questionnaire_response_maps_items_and_references() {
+        let qr = build_questionnaire_response(&response(), &FhirConfig::default(), &oscar_cfg(), Op::Upsert).unwrap();
+        assert_eq!(qr.status.value.as_deref(), Some("completed"));
+        assert_eq!(
+            qr.questionnaire.as_ref().and_then(|q| q.value.as_deref()),
+            Some("https://arsmedicatech.com/fhir/Questionnaire/oscar-eform/3")
+        );
+        assert_eq!(
+            qr.subject.as_ref().and_then(|s| s.reference.as_ref()).and_then(|r| r.value.as_deref()),
+            Some("Patient?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-demographic|123")
+        );
+        assert!(qr.author.is_some());
+        assert_eq!(
+            qr.authored.as_ref().and_then(|a| a.value.as_deref()),
+            Some("2026-01-05T10:15:00-08:00")
+        );
+        assert_eq!(qr.item.len(), 2);
+        assert_eq!(qr.item[1].link_id.value.as_deref(), Some("total"));
+    }
+
+    #[test]
+    fn configured_canonical_and_deleted_status() {
+        let mut cfg = oscar_cfg();
+        cfg.eform_questionnaire_map
+            .insert("3".to_string(), "http://loinc.org/q/44249-1".to_string());
+        let mut r = response();
+        r.current = false;
+        let qr = build_questionnaire_response(&r, &FhirConfig::default(), &cfg, Op::Upsert).unwrap();
+        assert_eq!(
+            qr.questionnaire.as_ref().and_then(|q| q.value.as_deref()),
+            Some("http://loinc.org/q/44249-1")
+        );
+        assert_eq!(qr.status.value.as_deref(), Some("entered-in-error"));
+    }
+
+    #[test]
+    fn patient_independent_form_has_no_subject() {
+        let mut r = response();
+        r.demographic_no = None;
+        r.patient_independent = true;
+        let qr = build_questionnaire_response(&r, &FhirConfig::default(), &oscar_cfg(), Op::Upsert).unwrap();
+        assert!(qr.subject.is_none());
+
+        r.patient_independent = false;
+        let err = build_questionnaire_response(&r, &FhirConfig::default(), &oscar_cfg(), Op::Upsert);
+        assert!(matches!(err, Err(SyncFailure::Permanent(_))));
+    }
+
+    #[test]
+    fn build_questionnaire_generates_string_items() {
+        let q = build_questionnaire(
+            &DomainQuestionnaire {
+                fid: "3".to_string(),
+                form_name: Some("PHQ-9".to_string()),
+                subject: None,
+                var_names: vec!["q1".to_string(), "total".to_string()],
+                active: true,
+            },
+            &FhirConfig::default(),
+            &oscar_cfg(),
+            Op::Upsert,
+        );
+        assert_eq!(q.status.value.as_deref(), Some("active"));
+        assert_eq!(
+            q.url.as_ref().and_then(|u| u.value.as_deref()),
+            Some("https://arsmedicatech.com/fhir/Questionnaire/oscar-eform/3")
+        );
+        assert_eq!(q.item.len(), 2);
+        assert_eq!(q.item[0].r#type.value.as_deref(), Some("string"));
+    }
+}
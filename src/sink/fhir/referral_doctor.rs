@@ -0,0 +1,113 @@
+//! Oscar `demographic.family_doctor` -> FHIR `Practitioner`.
+//!
+//! Only doctors that did not resolve to a `professionalSpecialists` row reach
+//! the sink. They are keyed on their billing number under a dedicated system
+//! so a conditional PUT can never land on a `provider_no`-keyed Practitioner
+//! that happens to carry the same MSP number.
+
+use fhirbolt::model::r4b::resources::{Bundle, Practitioner};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{HumanName, Identifier, Meta};
+use tracing::info;
+
+use crate::domain::referral_doctor::DomainExternalDoctor;
+
+use super::oscar2::{build_conditional_put_bundle, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_referral_doctor(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    doctor: &DomainExternalDoctor,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = referral_doctor_bundle(fhir_cfg, event, doctor)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_referral_doctor_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn referral_doctor_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    doctor: &DomainExternalDoctor,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_practitioner = build_referral_doctor(doctor, fhir_cfg)?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Practitioner(Box::new(fhir_practitioner)),
+        &fhir_cfg.oscar_referral_doctor_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// A patient row going away says nothing about the doctor, so deletes are
+/// ignored here: the Practitioner is upserted the same way either way.
+fn build_referral_doctor(doctor: &DomainExternalDoctor, fhir_cfg: &FhirConfig) -> Result<Practitioner, SyncFailure> {
+    let billing_no = &doctor.billing_no;
+
+    let mut practitioner = Practitioner::default();
+
+    practitioner.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    practitioner.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_referral_doctor_system.clone().into()),
+        value: Some(billing_no.to_string().into()),
+        ..Default::default()
+    });
+    practitioner.identifier.push(Identifier {
+        system: Some(fhir_cfg.bc_msp_practitioner_system.clone().into()),
+        value: Some(billing_no.to_string().into()),
+        ..Default::default()
+    });
+
+    if doctor.first_name.is_some() || doctor.last_name.is_some() {
+        practitioner.name.push(HumanName {
+            given: doctor
+                .first_name
+                .clone()
+                .map(|g| vec![g.into()])
+                .unwrap_or_default(),
+            family: doctor.last_name.clone().map(Into::into),
+            ..Default::default()
+        });
+    }
+
+    Ok(practitioner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doctor() -> DomainExternalDoctor {
+        DomainExternalDoctor {
+            billing_no: "12345".to_string(),
+            first_name: Some("John".to_string()),
+            last_name: Some("Smith".to_string()),
+        }
+    }
+
+    #[test]
+    fn build_referral_doctor_keys_on_billing_number() {
+        let cfg = FhirConfig::default();
+        let p = build_referral_doctor(&doctor(), &cfg).unwrap();
+        assert_eq!(p.identifier.len(), 2);
+        assert_eq!(
+            p.identifier[0].system.as_ref().and_then(|s| s.value.as_deref()),
+            Some(cfg.oscar_referral_doctor_system.as_str())
+        );
+        assert_eq!(p.identifier[1].value, Some("12345".to_string().into()));
+        assert_eq!(p.name[0].family, Some("Smith".to_string().into()));
+    }
+
+}
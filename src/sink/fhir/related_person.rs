@@ -0,0 +1,302 @@
+//! Oscar `DemographicContact` -> FHIR `RelatedPerson` / `Patient.contact`.
+//!
+//! Oscar records the relationship as free text ("Mother", "Spouse", ...), so
+//! the text is always carried verbatim and a v3-RoleCode coding is added only
+//! for the common family roles. The SDM and emergency-contact flags become
+//! additional `relationship` codings so both resources carry them.
+
+use fhirbolt::model::r4b::resources::{Bundle, PatientContact, RelatedPerson};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{CodeableConcept, Coding, ContactPoint, HumanName, Identifier, Meta};
+use tracing::info;
+
+use crate::domain::related_person::DomainRelatedPerson;
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, patient_ref, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+const ROLE_CODE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v3-RoleCode";
+const CONTACT_ROLE_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v2-0131";
+const OSCAR_CONTACT_ROLE_SYSTEM: &str = "https://arsmedicatech.com/fhir/CodeSystem/oscar-contact-role";
+
+pub(super) async fn sync_related_person(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    related: &DomainRelatedPerson,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = related_person_bundle(fhir_cfg, event, related)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_demographic_contact_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn related_person_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    related: &DomainRelatedPerson,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_related = build_related_person(related, fhir_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::RelatedPerson(Box::new(fhir_related)),
+        &fhir_cfg.oscar_demographic_contact_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+/// Builds the `RelatedPerson`. A binlog delete or soft-deleted link is sent
+/// as `active = false` rather than a FHIR DELETE so AMT keeps the history.
+fn build_related_person(related: &DomainRelatedPerson, fhir_cfg: &FhirConfig, op: Op) -> RelatedPerson {
+    let mut rp = RelatedPerson::default();
+
+    rp.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    rp.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_demographic_contact_system.clone().into()),
+        value: Some(related.contact_id.clone().into()),
+        ..Default::default()
+    });
+
+    rp.active = Some((related.active && op != Op::Delete).into());
+    rp.patient = Box::new(patient_ref(fhir_cfg, &related.demographic_no));
+    rp.relationship = relationship(related);
+    rp.name = name(related).into_iter().collect();
+    rp.telecom = telecom(related);
+
+    rp
+}
+
+/// Builds one `Patient.contact` entry for a `Contact`-backed link.
+pub(super) fn build_patient_contact(related: &DomainRelatedPerson) -> PatientContact {
+    PatientContact {
+        relationship: relationship(related),
+        name: name(related).map(Box::new),
+        telecom: telecom(related),
+        ..Default::default()
+    }
+}
+
+fn relationship(related: &DomainRelatedPerson) -> Vec<CodeableConcept> {
+    let mut out = Vec::new();
+
+    if let Some(role) = &related.role {
+        out.push(CodeableConcept {
+            coding: role_code(role)
+                .map(|(code, display)| coding(ROLE_CODE_SYSTEM, code, display))
+                .into_iter()
+                .collect(),
+            text: Some(role.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    if related.emergency_contact {
+        out.push(CodeableConcept {
+            coding: vec![coding(CONTACT_ROLE_SYSTEM, "C", "Emergency Contact")],
+            ..Default::default()
+        });
+    }
+
+    if related
+        .role
+        .as_deref()
+        .is_some_and(|r| r.to_ascii_lowercase().contains("next of kin"))
+    {
+        out.push(CodeableConcept {
+            coding: vec![coding(CONTACT_ROLE_SYSTEM, "N", "Next-of-Kin")],
+            ..Default::default()
+        });
+    }
+
+    if related.substitute_decision_maker {
+        out.push(CodeableConcept {
+            coding: vec![coding(OSCAR_CONTACT_ROLE_SYSTEM, "sdm", "Substitute Decision Maker")],
+            ..Default::default()
+        });
+    }
+
+    out
+}
+
+/// Maps Oscar's free-text relationship onto v3-RoleCode for the common
+/// family roles. Anything else is carried as text only.
+fn role_code(role: &str) -> Option<(&'static str, &'static str)> {
+    let code = match role.trim().to_ascii_lowercase().as_str() {
+        "mother" => ("MTH", "mother"),
+        "father" => ("FTH", "father"),
+        "parent" => ("PRN", "parent"),
+        "spouse" | "husband" | "wife" => ("SPS", "spouse"),
+        "partner" | "common law" | "common-law" => ("DOMPART", "domestic partner"),
+        "son" => ("SONC", "son"),
+        "daughter" => ("DAUC", "daughter"),
+        "child" => ("CHILD", "child"),
+        "brother" => ("BRO", "brother"),
+        "sister" => ("SIS", "sister"),
+        "sibling" => ("SIB", "sibling"),
+        "grandmother" => ("GRMTH", "grandmother"),
+        "grandfather" => ("GRFTH", "grandfather"),
+        "grandparent" => ("GRPRN", "grandparent"),
+        "aunt" => ("AUNT", "aunt"),
+        "uncle" => ("UNCLE", "uncle"),
+        "guardian" => ("GUARD", "guardian"),
+        "friend" => ("FRND", "unrelated friend"),
+        "neighbour" | "neighbor" => ("NBOR", "neighbor"),
+        _ => return None,
+    };
+    Some(code)
+}
+
+fn coding(system: &str, code: &str, display: &str) -> Coding {
+    Coding {
+        system: Some(system.to_string().into()),
+        code: Some(code.to_string().into()),
+        display: Some(display.to_string().into()),
+        ..Default::default()
+    }
+}
+
+fn name(related: &DomainRelatedPerson) -> Option<HumanName> {
+    if related.first_name.is_none() && related.last_name.is_none() {
+        return None;
+    }
+    Some(HumanName {
+        family: related.last_name.clone().map(Into::into),
+        given: related
+            .first_name
+            .clone()
+            .map(|g| vec![g.into()])
+            .unwrap_or_default(),
+        ..Default::default()
+    })
+}
+
+fn telecom(related: &DomainRelatedPerson) -> Vec<ContactPoint> {
+    let phones = [
+        (&related.home_phone, "home"),
+        (&related.work_phone, "work"),
+        (&related.cell_phone, "mobile"),
+    ];
+
+    let mut out: Vec<ContactPoint> = phones
+        .into_iter()
+        .filter_map(|(value, use_)| {
+            value.as_ref().map(|v| ContactPoint {
+                system: Some("phone".into()),
+                value: Some(v.clone().into()),
+                r#use: Some(use_.into()),
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    if let Some(email) = &related.email {
+        out.push(ContactPoint {
+            system: Some("email".into()),
+            value: Some(email.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn related() -> DomainRelatedPerson {
+        DomainRelatedPerson {
+            contact_id: "5".to_string(),
+            demographic_no: "123".to_string(),
+            contact_type: "2".to_string(),
+            linked_demographic_no: None,
+            role: Some("Mother".to_string()),
+            category: Some("personal".to_string()),
+            first_name: Some("Ann".to_string()),
+            last_name: Some("Smith".to_string()),
+            home_phone: Some("604-555-0100".to_string()),
+            work_phone: None,
+            cell_phone: Some("604-555-0199".to_string()),
+            email: None,
+            substitute_decision_maker: true,
+            emergency_contact: true,
+            active: true,
+        }
+    }
+
+    fn codes(concepts: &[CodeableConcept]) -> Vec<String> {
+        concepts
+            .iter()
+            .flat_map(|c| c.coding.iter())
+            .filter_map(|c| c.code.as_ref().and_then(|v| v.value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn build_related_person_maps_role_flags_and_telecom() {
+        let rp = build_related_person(&related(), &FhirConfig::default(), Op::Upsert);
+        assert_eq!(rp.active.as_ref().and_then(|a| a.value), Some(true));
+        assert_eq!(
+            rp.patient.reference.as_ref().and_then(|r| r.value.as_deref()),
+            Some("Patient?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-demographic|123")
+        );
+        assert_eq!(codes(&rp.relationship), vec!["MTH", "C", "sdm"]);
+        assert_eq!(
+            rp.relationship[0].text.as_ref().and_then(|t| t.value.as_deref()),
+            Some("Mother")
+        );
+        assert_eq!(rp.name.len(), 1);
+        assert_eq!(rp.telecom.len(), 2);
+        assert_eq!(
+            rp.telecom[1].r#use.as_ref().and_then(|u| u.value.as_deref()),
+            Some("mobile")
+        );
+    }
+
+    #[test]
+    fn build_related_person_delete_sets_inactive() {
+        let rp = build_related_person(&related(), &FhirConfig::default(), Op::Delete);
+        assert_eq!(rp.active.as_ref().and_then(|a| a.value), Some(false));
+    }
+
+    #[test]
+    fn unrecognised_role_is_text_only() {
+        let mut r = related();
+        r.role = Some("Case worker".to_string());
+        r.substitute_decision_maker = false;
+        r.emergency_contact = false;
+        let rel = relationship(&r);
+        assert_eq!(rel.len(), 1);
+        assert!(rel[0].coding.is_empty());
+    }
+
+    #[test]
+    fn next_of_kin_role_adds_v2_code() {
+        let mut r = related();
+        r.role = Some("Next of Kin".to_string());
+        r.substitute_decision_maker = false;
+        r.emergency_contact = false;
+        assert_eq!(codes(&relationship(&r)), vec!["N"]);
+    }
+
+    #[test]
+    fn patient_contact_omits_name_when_unresolved() {
+        let mut r = related();
+        r.first_name = None;
+        r.last_name = None;
+        let contact = build_patient_contact(&r);
+        assert!(contact.name.is_none());
+        assert_eq!(contact.telecom.len(), 2);
+    }
+}
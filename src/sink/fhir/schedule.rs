@@ -0,0 +1,221 @@
+//! Oscar `scheduledate` + `scheduletemplate` -> FHIR `Schedule` / `Slot`.
+//!
+//! One Schedule per provider anchors every Slot; Slots are keyed on
+//! provider, date and start time so re-expanding a provider-day overwrites
+//! the same resources instead of accumulating new ones.
+
+use fhirbolt::model::r4b::resources::{Bundle, Schedule, Slot};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{CodeableConcept, Identifier, Meta};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::schedule::{DomainSchedule, DomainSlot, SlotStatus};
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, conditional_reference, practitioner_ref, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_schedule(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    schedule: &DomainSchedule,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = schedule_bundle(fhir_cfg, event, schedule)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_schedule_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn schedule_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    schedule: &DomainSchedule,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_schedule = build_schedule(schedule, fhir_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Schedule(Box::new(fhir_schedule)),
+        &fhir_cfg.oscar_schedule_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+pub(super) async fn sync_slot(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    slot: &DomainSlot,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = slot_bundle(fhir_cfg, event, slot, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_slot_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn slot_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    slot: &DomainSlot,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_slot = build_slot(slot, fhir_cfg, oscar_cfg, event.op())?;
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Slot(Box::new(fhir_slot)),
+        &fhir_cfg.oscar_slot_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+fn build_schedule(schedule: &DomainSchedule, fhir_cfg: &FhirConfig, op: Op) -> Schedule {
+    let mut s = Schedule::default();
+
+    s.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    s.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_schedule_system.clone().into()),
+        value: Some(schedule.provider_no.clone().into()),
+        ..Default::default()
+    });
+
+    s.active = Some((op != Op::Delete).into());
+    s.actor.push(practitioner_ref(fhir_cfg, &schedule.provider_no));
+
+    s
+}
+
+/// Builds the `Slot`. Start and end go through `to_appointment_instant` so
+/// slots and appointments agree on DST handling. A delete is sent as
+/// `busy-unavailable` so AMT stops offering the time without losing history.
+fn build_slot(
+    slot: &DomainSlot,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<Slot, SyncFailure> {
+    let tz = oscar_cfg
+        .timezone
+        .as_deref()
+        .ok_or_else(|| SyncFailure::Permanent(anyhow::anyhow!("missing oscar timezone")))?;
+
+    let mut s = Slot::default();
+
+    s.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    s.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_slot_system.clone().into()),
+        value: Some(slot.slot_id.clone().into()),
+        ..Default::default()
+    });
+
+    s.schedule = Box::new(conditional_reference(
+        &fhir_cfg.oscar_schedule_system,
+        &slot.provider_no,
+        "Schedule",
+    ));
+
+    let status = if op == Op::Delete { SlotStatus::BusyUnavailable } else { slot.status };
+    s.status = status.as_str().to_string().into();
+
+    s.start = super::to_appointment_instant(&slot.start_date, &slot.start_time, tz)?.into();
+    s.end = super::to_appointment_instant(&slot.end_date, &slot.end_time, tz)?.into();
+
+    if let Some(description) = &slot.description {
+        s.service_type.push(CodeableConcept {
+            text: Some(description.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oscar_cfg() -> OscarConfig {
+        OscarConfig {
+            timezone: Some("America/Vancouver".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn slot() -> DomainSlot {
+        DomainSlot {
+            slot_id: "101:2026-03-08:0900".to_string(),
+            provider_no: "101".to_string(),
+            start_date: "2026-03-08".to_string(),
+            start_time: "09:00:00".to_string(),
+            end_date: "2026-03-08".to_string(),
+            end_time: "09:15:00".to_string(),
+            status: SlotStatus::Busy,
+            code: "1".to_string(),
+            description: Some("15 min visit".to_string()),
+        }
+    }
+
+    #[test]
+    fn build_schedule_references_provider() {
+        let s = build_schedule(
+            &DomainSchedule { provider_no: "101".to_string() },
+            &FhirConfig::default(),
+            Op::Upsert,
+        );
+        assert_eq!(s.active.as_ref().and_then(|a| a.value), Some(true));
+        assert_eq!(
+            s.actor[0].reference.as_ref().and_then(|r| r.value.as_deref()),
+            Some("Practitioner?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-provider|101")
+        );
+    }
+
+    #[test]
+    fn build_slot_uses_local_timezone_after_dst_change() {
+        // 2026-03-08 is the spring-forward date in Vancouver: PDT, -07:00.
+        let s = build_slot(&slot(), &FhirConfig::default(), &oscar_cfg(), Op::Upsert).unwrap();
+        assert_eq!(s.start.value.as_deref(), Some("2026-03-08T09:00:00-07:00"));
+        assert_eq!(s.end.value.as_deref(), Some("2026-03-08T09:15:00-07:00"));
+        assert_eq!(s.status.value.as_deref(), Some("busy"));
+        assert_eq!(
+            s.schedule.reference.as_ref().and_then(|r| r.value.as_deref()),
+            Some("Schedule?identifier=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-schedule|101")
+        );
+        assert_eq!(
+            s.service_type[0].text.as_ref().and_then(|t| t.value.as_deref()),
+            Some("15 min visit")
+        );
+    }
+
+    #[test]
+    fn build_slot_delete_is_busy_unavailable() {
+        let s = build_slot(&slot(), &FhirConfig::default(), &oscar_cfg(), Op::Delete).unwrap();
+        assert_eq!(s.status.value.as_deref(), Some("busy-unavailable"));
+    }
+
+    #[test]
+    fn build_slot_requires_timezone() {
+        let err = build_slot(&slot(), &FhirConfig::default(), &OscarConfig::default(), Op::Upsert);
+        assert!(matches!(err, Err(SyncFailure::Permanent(_))));
+    }
+}
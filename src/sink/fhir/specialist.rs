@@ -0,0 +1,165 @@
+//! Oscar `professionalSpecialists` -> FHIR `Practitioner`.
+//!
+//! Specialists are address-book entries rather than Oscar users, so they get
+//! their own identifier system and never collide with `provider_no`-keyed
+//! Practitioners.
+
+use fhirbolt::model::r4b::resources::{Bundle, Practitioner, PractitionerQualification};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{Address, CodeableConcept, ContactPoint, HumanName, Identifier, Meta};
+use tracing::info;
+
+use crate::domain::specialist::DomainSpecialist;
+use crate::event::Op;
+
+use super::oscar2::{build_conditional_put_bundle, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+pub(super) async fn sync_specialist(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    specialist: &DomainSpecialist,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = specialist_bundle(fhir_cfg, event, specialist)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_specialist_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn specialist_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    specialist: &DomainSpecialist,
+) -> Result<Bundle, SyncFailure> {
+    let fhir_practitioner = build_specialist(specialist, fhir_cfg, event.op());
+    let bundle = build_conditional_put_bundle(
+        fhir_cfg,
+        FhirResource::Practitioner(Box::new(fhir_practitioner)),
+        &fhir_cfg.oscar_specialist_system,
+        event,
+    );
+    Ok(bundle)
+}
+
+fn build_specialist(specialist: &DomainSpecialist, fhir_cfg: &FhirConfig, op: Op) -> Practitioner {
+    let mut practitioner = Practitioner::default();
+
+    practitioner.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+
+    practitioner.identifier.push(Identifier {
+        system: Some(fhir_cfg.oscar_specialist_system.clone().into()),
+        value: Some(specialist.spec_id.clone().into()),
+        ..Default::default()
+    });
+
+    if let Some(referral_no) = &specialist.referral_no {
+        practitioner.identifier.push(Identifier {
+            system: Some(fhir_cfg.bc_msp_practitioner_system.clone().into()),
+            value: Some(referral_no.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    practitioner.active = Some((specialist.active && op != Op::Delete).into());
+
+    if specialist.first_name.is_some() || specialist.last_name.is_some() {
+        practitioner.name.push(HumanName {
+            given: specialist
+                .first_name
+                .clone()
+                .map(|g| vec![g.into()])
+                .unwrap_or_default(),
+            family: specialist.last_name.clone().map(Into::into),
+            suffix: specialist
+                .pro_letters
+                .clone()
+                .map(|s| vec![s.into()])
+                .unwrap_or_default(),
+            ..Default::default()
+        });
+    }
+
+    for (value, system) in [
+        (&specialist.phone, "phone"),
+        (&specialist.fax, "fax"),
+        (&specialist.email, "email"),
+    ] {
+        if let Some(v) = value {
+            practitioner.telecom.push(ContactPoint {
+                system: Some(system.into()),
+                value: Some(v.clone().into()),
+                r#use: Some("work".into()),
+                ..Default::default()
+            });
+        }
+    }
+
+    if let Some(addr) = &specialist.address {
+        practitioner.address.push(Address {
+            r#use: Some("work".into()),
+            text: Some(addr.clone().into()),
+            ..Default::default()
+        });
+    }
+
+    if let Some(specialty) = &specialist.specialty {
+        practitioner.qualification.push(PractitionerQualification {
+            code: Box::new(CodeableConcept {
+                text: Some(specialty.clone().into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    practitioner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specialist() -> DomainSpecialist {
+        DomainSpecialist {
+            spec_id: "12".to_string(),
+            first_name: Some("Grace".to_string()),
+            last_name: Some("Hopper".to_string()),
+            pro_letters: Some("MD".to_string()),
+            specialty: Some("Cardiology".to_string()),
+            address: Some("1 Main St".to_string()),
+            phone: Some("604-555-0101".to_string()),
+            fax: Some("604-555-0102".to_string()),
+            email: None,
+            referral_no: Some("12345".to_string()),
+            active: true,
+        }
+    }
+
+    #[test]
+    fn build_specialist_maps_identifiers_name_and_specialty() {
+        let cfg = FhirConfig::default();
+        let p = build_specialist(&specialist(), &cfg, Op::Upsert);
+        assert_eq!(p.identifier.len(), 2);
+        assert_eq!(p.identifier[0].system, Some(cfg.oscar_specialist_system.clone().into()));
+        assert_eq!(p.identifier[1].value, Some("12345".to_string().into()));
+        assert_eq!(p.name[0].suffix.len(), 1);
+        assert_eq!(p.telecom.len(), 2);
+        assert_eq!(p.qualification.len(), 1);
+        assert_eq!(p.active.as_ref().and_then(|b| b.value), Some(true));
+    }
+
+    #[test]
+    fn build_specialist_delete_sets_inactive() {
+        let p = build_specialist(&specialist(), &FhirConfig::default(), Op::Delete);
+        assert_eq!(p.active.as_ref().and_then(|b| b.value), Some(false));
+    }
+}
@@ -0,0 +1,379 @@
+//! Oscar status maps as terminology resources: a `CodeSystem` per set of
+//! raw Oscar status codes and a `ConceptMap` per configured map, upserted at
+//! startup so the TOML maps are visible on the FHIR server. With
+//! `fhir.status_maps_from_server` the maps are read back from those
+//! ConceptMaps instead.
+
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{Context, Result};
+use fhirbolt::model::r4b::resources::{
+    Bundle, BundleEntry, BundleEntryRequest, CodeSystem, CodeSystemConcept, ConceptMap, ConceptMapGroup,
+    ConceptMapGroupElement, ConceptMapGroupElementTarget,
+};
+use fhirbolt::model::r4b::types::Meta;
+use fhirbolt::model::r4b::Resource as FhirResource;
+use tracing::{info, warn};
+
+use super::oscar2::send_transaction_bundle;
+use super::{Config, META_SOURCE, OSCAR_APPOINTMENT_STATUS_SYSTEM};
+use crate::auth::TokenProvider;
+
+const OSCAR_CONSULT_REQUEST_STATUS_SYSTEM: &str = "https://arsmedicatech.com/fhir/sid/oscar-consult-request-status";
+const OSCAR_CONSULT_RESPONSE_STATUS_SYSTEM: &str =
+    "https://arsmedicatech.com/fhir/sid/oscar-consult-response-status";
+const CONCEPT_MAP_BASE_URL: &str = "https://arsmedicatech.com/fhir/ConceptMap";
+const APPOINTMENT_STATUS_SYSTEM: &str = "http://hl7.org/fhir/appointmentstatus";
+const REQUEST_STATUS_SYSTEM: &str = "http://hl7.org/fhir/request-status";
+const DIAGNOSTIC_REPORT_STATUS_SYSTEM: &str = "http://hl7.org/fhir/diagnostic-report-status";
+
+/// Which configured map a ConceptMap carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatusMap {
+    Appointment,
+    ConsultRequest,
+    ConsultResponse,
+    WritebackAppointment,
+}
+
+const STATUS_MAPS: [StatusMap; 4] = [
+    StatusMap::Appointment,
+    StatusMap::ConsultRequest,
+    StatusMap::ConsultResponse,
+    StatusMap::WritebackAppointment,
+];
+
+impl StatusMap {
+    fn name(self) -> &'static str {
+        match self {
+            StatusMap::Appointment => "oscar-appointment-status",
+            StatusMap::ConsultRequest => "oscar-consult-request-status",
+            StatusMap::ConsultResponse => "oscar-consult-response-status",
+            StatusMap::WritebackAppointment => "oscar-writeback-appointment-status",
+        }
+    }
+
+    fn url(self) -> String {
+        format!("{CONCEPT_MAP_BASE_URL}/{}", self.name())
+    }
+
+    /// Source and target systems; each map's keys are source codes.
+    fn systems(self) -> (&'static str, &'static str) {
+        match self {
+            StatusMap::Appointment => (OSCAR_APPOINTMENT_STATUS_SYSTEM, APPOINTMENT_STATUS_SYSTEM),
+            StatusMap::ConsultRequest => (OSCAR_CONSULT_REQUEST_STATUS_SYSTEM, REQUEST_STATUS_SYSTEM),
+            StatusMap::ConsultResponse => (OSCAR_CONSULT_RESPONSE_STATUS_SYSTEM, DIAGNOSTIC_REPORT_STATUS_SYSTEM),
+            StatusMap::WritebackAppointment => (APPOINTMENT_STATUS_SYSTEM, OSCAR_APPOINTMENT_STATUS_SYSTEM),
+        }
+    }
+
+    /// Whether Oscar's raw codes are the map's keys (source side) rather
+    /// than its values.
+    fn oscar_is_source(self) -> bool {
+        self != StatusMap::WritebackAppointment
+    }
+
+    fn map(self, cfg: &Config) -> &HashMap<String, String> {
+        match self {
+            StatusMap::Appointment => &cfg.oscar.appointment_status_map,
+            StatusMap::ConsultRequest => &cfg.oscar.consult_request_status_map,
+            StatusMap::ConsultResponse => &cfg.oscar.consult_response_status_map,
+            StatusMap::WritebackAppointment => &cfg.writeback.appointment_status_map,
+        }
+    }
+
+    fn map_mut(self, cfg: &mut Config) -> &mut HashMap<String, String> {
+        match self {
+            StatusMap::Appointment => &mut cfg.oscar.appointment_status_map,
+            StatusMap::ConsultRequest => &mut cfg.oscar.consult_request_status_map,
+            StatusMap::ConsultResponse => &mut cfg.oscar.consult_response_status_map,
+            StatusMap::WritebackAppointment => &mut cfg.writeback.appointment_status_map,
+        }
+    }
+}
+
+/// Publishes the status maps, or loads them from the server when
+/// `fhir.status_maps_from_server` is set. Never fatal: the sink can run on
+/// the TOML maps alone, so failures are logged.
+pub async fn sync_status_maps(cfg: &mut Config) {
+    if !cfg.fhir.publish_status_maps && !cfg.fhir.status_maps_from_server {
+        return;
+    }
+
+    let client = reqwest::Client::new();
+    let token = match fetch_token(cfg, &client).await {
+        Ok(token) => token,
+        Err(e) => {
+            warn!("status maps: failed to get a FHIR token: {e:?}");
+            return;
+        }
+    };
+
+    if cfg.fhir.status_maps_from_server {
+        for map in STATUS_MAPS {
+            match fetch_concept_map(&client, cfg, token.as_deref(), &map.url()).await {
+                Ok(Some(concept_map)) => {
+                    let entries = concept_map_entries(&concept_map);
+                    info!("status maps: loaded {} codes from {}", entries.len(), map.url());
+                    *map.map_mut(cfg) = entries;
+                }
+                Ok(None) => warn!("status maps: no ConceptMap {}; keeping the configured map", map.url()),
+                Err(e) => warn!("status maps: failed to load {}; keeping the configured map: {e:?}", map.url()),
+            }
+        }
+        return;
+    }
+
+    let bundle = build_status_map_bundle(cfg);
+    if bundle.entry.is_empty() {
+        return;
+    }
+    match send_transaction_bundle(&client, &cfg.fhir, token, &bundle).await {
+        Ok(_) => info!("status maps: published {} CodeSystems/ConceptMaps", bundle.entry.len()),
+        Err(e) => warn!("status maps: failed to publish: {e}"),
+    }
+}
+
+async fn fetch_token(cfg: &Config, client: &reqwest::Client) -> Result<Option<String>> {
+    match cfg.fhir.keycloak.as_ref() {
+        Some(kc) => Ok(Some(TokenProvider::new(kc, client.clone())?.token().await?)),
+        None => Ok(None),
+    }
+}
+
+/// One transaction upserting every non-empty CodeSystem and ConceptMap,
+/// each keyed on its canonical `url`.
+fn build_status_map_bundle(cfg: &Config) -> Bundle {
+    let mut bundle = Bundle::default();
+    bundle.r#type = "transaction".into();
+
+    for (system, codes) in oscar_code_systems(cfg) {
+        if codes.is_empty() {
+            continue;
+        }
+        let code_system = build_code_system(system, &codes);
+        bundle.entry.push(conditional_put_entry(
+            "CodeSystem",
+            system,
+            FhirResource::CodeSystem(Box::new(code_system)),
+        ));
+    }
+    for map in STATUS_MAPS {
+        if map.map(cfg).is_empty() {
+            continue;
+        }
+        let url = map.url();
+        let concept_map = build_concept_map(map, map.map(cfg));
+        bundle.entry.push(conditional_put_entry(
+            "ConceptMap",
+            &url,
+            FhirResource::ConceptMap(Box::new(concept_map)),
+        ));
+    }
+    bundle
+}
+
+fn conditional_put_entry(resource_type: &str, url: &str, resource: FhirResource) -> BundleEntry {
+    let encoded: String = url::form_urlencoded::byte_serialize(url.as_bytes()).collect();
+    BundleEntry {
+        resource: Some(resource),
+        request: Some(BundleEntryRequest {
+            method: "PUT".into(),
+            url: format!("{resource_type}?url={encoded}").into(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Raw Oscar codes per Oscar status system, gathered from whichever side of
+/// each map is Oscar's.
+fn oscar_code_systems(cfg: &Config) -> Vec<(&'static str, BTreeSet<String>)> {
+    let mut systems: Vec<(&'static str, BTreeSet<String>)> = Vec::new();
+    for map in STATUS_MAPS {
+        let (source, target) = map.systems();
+        let (system, codes): (&'static str, Vec<&String>) = if map.oscar_is_source() {
+            (source, map.map(cfg).keys().collect())
+        } else {
+            (target, map.map(cfg).values().collect())
+        };
+        let index = match systems.iter().position(|(s, _)| *s == system) {
+            Some(i) => i,
+            None => {
+                systems.push((system, BTreeSet::new()));
+                systems.len() - 1
+            }
+        };
+        systems[index].1.extend(codes.into_iter().cloned());
+    }
+    systems
+}
+
+fn build_code_system(system: &str, codes: &BTreeSet<String>) -> CodeSystem {
+    CodeSystem {
+        meta: Some(Box::new(Meta {
+            source: Some(META_SOURCE.into()),
+            ..Default::default()
+        })),
+        url: Some(system.to_string().into()),
+        name: Some(system.rsplit('/').next().unwrap_or(system).to_string().into()),
+        status: "active".into(),
+        // Only codes named in a map are known; sites may use others.
+        content: "fragment".into(),
+        case_sensitive: Some(true.into()),
+        concept: codes
+            .iter()
+            .map(|code| CodeSystemConcept {
+                code: code.clone().into(),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn build_concept_map(map: StatusMap, entries: &HashMap<String, String>) -> ConceptMap {
+    let (source, target) = map.systems();
+    let mut codes: Vec<(&String, &String)> = entries.iter().collect();
+    codes.sort();
+
+    ConceptMap {
+        meta: Some(Box::new(Meta {
+            source: Some(META_SOURCE.into()),
+            ..Default::default()
+        })),
+        url: Some(map.url().into()),
+        name: Some(map.name().to_string().into()),
+        status: "active".into(),
+        group: vec![ConceptMapGroup {
+            source: Some(source.to_string().into()),
+            target: Some(target.to_string().into()),
+            element: codes
+                .into_iter()
+                .map(|(from, to)| ConceptMapGroupElement {
+                    code: Some(from.clone().into()),
+                    target: vec![ConceptMapGroupElementTarget {
+                        code: Some(to.clone().into()),
+                        equivalence: "equivalent".into(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+/// Source code -> first usable target code, across every group.
+fn concept_map_entries(concept_map: &ConceptMap) -> HashMap<String, String> {
+    let mut entries = HashMap::new();
+    for element in concept_map.group.iter().flat_map(|g| &g.element) {
+        let Some(from) = element.code.as_ref().and_then(|c| c.value.clone()) else {
+            continue;
+        };
+        let to = element
+            .target
+            .iter()
+            .filter(|t| !matches!(t.equivalence.value.as_deref(), Some("unmatched" | "disjoint")))
+            .find_map(|t| t.code.as_ref().and_then(|c| c.value.clone()));
+        if let Some(to) = to {
+            entries.entry(from).or_insert(to);
+        }
+    }
+    entries
+}
+
+/// Looks up a ConceptMap by canonical URL. Walks the search Bundle as plain
+/// JSON for the same reason as `find_existing_care_team`.
+async fn fetch_concept_map(
+    client: &reqwest::Client,
+    cfg: &Config,
+    token: Option<&str>,
+    url: &str,
+) -> Result<Option<ConceptMap>> {
+    let base = cfg.fhir.base_url.trim_end_matches('/');
+    let mut req = client
+        .get(format!("{base}/ConceptMap"))
+        .query(&[("url", url)])
+        .header("Accept", "application/fhir+json");
+    if let Some(t) = token {
+        req = req.bearer_auth(t);
+    }
+
+    let resp = req.send().await.context("searching ConceptMap")?;
+    let status = resp.status();
+    let body_text = resp.text().await.unwrap_or_default();
+    if !status.is_success() {
+        anyhow::bail!("ConceptMap search failed ({status}): {body_text}");
+    }
+
+    let raw: serde_json::Value = serde_json::from_str(&body_text).context("parsing ConceptMap search Bundle")?;
+    let resource = raw
+        .get("entry")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("resource"))
+        .find(|r| r.get("resourceType").and_then(|v| v.as_str()) == Some("ConceptMap"));
+    let Some(resource) = resource else {
+        return Ok(None);
+    };
+    let concept_map = fhirbolt::json::from_str(&resource.to_string(), None).context("parsing ConceptMap")?;
+    Ok(Some(concept_map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cfg() -> Config {
+        let mut cfg: Config = toml::from_str("").unwrap();
+        cfg.writeback.appointment_status_map = HashMap::from([("cancelled".to_string(), "C".to_string())]);
+        cfg.oscar.appointment_status_map.insert("a".to_string(), "booked".to_string());
+        cfg
+    }
+
+    #[test]
+    fn concept_map_round_trips() {
+        let cfg = cfg();
+        let concept_map = build_concept_map(StatusMap::Appointment, &cfg.oscar.appointment_status_map);
+        assert_eq!(
+            concept_map.url.as_ref().and_then(|u| u.value.as_deref()),
+            Some("https://arsmedicatech.com/fhir/ConceptMap/oscar-appointment-status")
+        );
+        assert_eq!(
+            concept_map.group[0].source.as_ref().and_then(|s| s.value.as_deref()),
+            Some(OSCAR_APPOINTMENT_STATUS_SYSTEM)
+        );
+        assert_eq!(concept_map_entries(&concept_map), cfg.oscar.appointment_status_map);
+    }
+
+    #[test]
+    fn bundle_publishes_code_systems_and_non_empty_maps() {
+        let bundle = build_status_map_bundle(&cfg());
+        let urls: Vec<&str> = bundle
+            .entry
+            .iter()
+            .filter_map(|e| e.request.as_ref())
+            .filter_map(|r| r.url.value.as_deref())
+            .collect();
+
+        // The consult response map ships empty, so it has neither.
+        assert_eq!(urls.len(), 5);
+        assert!(urls.iter().all(|u| !u.contains("consult-response")));
+        assert!(urls.contains(
+            &"CodeSystem?url=https%3A%2F%2Farsmedicatech.com%2Ffhir%2Fsid%2Foscar-appointment-status"
+        ));
+
+        // The write-back map's Oscar codes join the appointment CodeSystem.
+        let systems = oscar_code_systems(&cfg());
+        let (_, appointment_codes) = systems
+            .iter()
+            .find(|(s, _)| *s == OSCAR_APPOINTMENT_STATUS_SYSTEM)
+            .unwrap();
+        assert!(appointment_codes.contains("C"));
+        assert!(appointment_codes.contains("a"));
+    }
+}
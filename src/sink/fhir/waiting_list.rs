@@ -0,0 +1,290 @@
+//! Oscar waiting lists -> FHIR `Appointment` (status `waitlist`) or
+//! `ServiceRequest`, per `oscar.waiting_list_resource`.
+//!
+//! Each `waitingList` row is keyed on its `id`. The list itself is carried
+//! as a coding in a local CodeSystem (code = `listID`, display = list name),
+//! so AMT can group its queue by list. When the entry leaves the list the
+//! resource is ended rather than deleted: a waitlist Appointment becomes
+//! `cancelled`, a ServiceRequest `completed` if booked or `revoked` if not.
+
+use fhirbolt::model::r4b::resources::{Appointment, AppointmentParticipant, Bundle, ServiceRequest};
+use fhirbolt::model::r4b::Resource as FhirResource;
+use fhirbolt::model::r4b::types::{
+    Annotation, AnnotationAuthor, CodeableConcept, Coding, Extension, ExtensionValue, Identifier, Meta,
+};
+use tracing::info;
+
+use crate::config::OscarConfig;
+use crate::domain::waiting_list::{DomainWaitingListEntry, WaitingListState};
+use crate::event::{Op, ResourceType};
+
+use super::oscar2::{build_conditional_put_bundle, patient_ref, practitioner_ref, send_transaction_bundle};
+use super::{FhirConfig, FhirResult, SyncEvent, SyncFailure, META_SOURCE};
+
+const OSCAR_WAITING_LIST_NAME_SYSTEM: &str = "https://arsmedicatech.com/fhir/CodeSystem/oscar-waiting-list";
+const OSCAR_WAITING_LIST_POSITION_URL: &str =
+    "https://arsmedicatech.com/fhir/StructureDefinition/oscar-waiting-list-position";
+
+pub(super) async fn sync_waiting_list_entry(
+    client: &reqwest::Client,
+    fhir_cfg: &FhirConfig,
+    token: Option<String>,
+    event: &SyncEvent,
+    entry: &DomainWaitingListEntry,
+    oscar_cfg: &OscarConfig,
+) -> Result<FhirResult, SyncFailure> {
+    let bundle = waiting_list_entry_bundle(fhir_cfg, event, entry, oscar_cfg)?;
+    let result = send_transaction_bundle(client, fhir_cfg, token, &bundle).await?;
+    let identifier = format!("{}|{}", fhir_cfg.oscar_waiting_list_system, event.payload().source_id());
+    info!(
+        "fhir sink: synced {} -> {} (fhir_id={} version_id={:?})",
+        event.idempotency_key(), identifier, result.fhir_id, result.version_id
+    );
+    Ok(result)
+}
+
+pub(super) fn waiting_list_entry_bundle(
+    fhir_cfg: &FhirConfig,
+    event: &SyncEvent,
+    entry: &DomainWaitingListEntry,
+    oscar_cfg: &OscarConfig,
+) -> Result<Bundle, SyncFailure> {
+    let resource = match event.resource_type() {
+        ResourceType::ServiceRequest => FhirResource::ServiceRequest(Box::new(
+            build_waiting_list_request(entry, fhir_cfg, oscar_cfg, event.op())?,
+        )),
+        _ => FhirResource::Appointment(Box::new(build_waiting_list_appointment(
+            entry,
+            fhir_cfg,
+            oscar_cfg,
+            event.op(),
+        )?)),
+    };
+    let bundle = build_conditional_put_bundle(fhir_cfg, resource, &fhir_cfg.oscar_waiting_list_system, event);
+    Ok(bundle)
+}
+
+fn list_concept(entry: &DomainWaitingListEntry) -> CodeableConcept {
+    CodeableConcept {
+        coding: vec![Coding {
+            system: Some(OSCAR_WAITING_LIST_NAME_SYSTEM.to_string().into()),
+            code: Some(entry.list_id.clone().into()),
+            display: entry.list_name.clone().map(Into::into),
+            ..Default::default()
+        }],
+        text: entry.list_name.clone().map(Into::into),
+        ..Default::default()
+    }
+}
+
+/// `onListSince` as a FHIR `dateTime`, localised when it carries a time.
+fn on_list_since(entry: &DomainWaitingListEntry, oscar_cfg: &OscarConfig) -> Result<Option<String>, SyncFailure> {
+    let Some(since) = entry.on_list_since.as_deref() else {
+        return Ok(None);
+    };
+    match (since.split_once([' ', 'T']), oscar_cfg.timezone.as_deref()) {
+        (Some((date, time)), Some(tz)) => super::to_appointment_instant(date, time, tz).map(Some),
+        (Some((date, _)), None) => Ok(Some(date.to_string())),
+        (None, _) => Ok(Some(since.to_string())),
+    }
+}
+
+fn identifier(entry: &DomainWaitingListEntry, fhir_cfg: &FhirConfig) -> Identifier {
+    Identifier {
+        system: Some(fhir_cfg.oscar_waiting_list_system.clone().into()),
+        value: Some(entry.entry_id.clone().into()),
+        ..Default::default()
+    }
+}
+
+/// Builds the `waitlist` Appointment. `position` becomes `priority`
+/// (FHIR's lower-is-sooner matches Oscar's queue order).
+fn build_waiting_list_appointment(
+    entry: &DomainWaitingListEntry,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<Appointment, SyncFailure> {
+    let mut appt = Appointment::default();
+
+    appt.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+    appt.identifier.push(identifier(entry, fhir_cfg));
+
+    let waiting = entry.is_waiting() && op != Op::Delete;
+    appt.status = if waiting { "waitlist" } else { "cancelled" }.into();
+    if !waiting {
+        let reason = match &entry.state {
+            WaitingListState::Booked { .. } => "Booked from waiting list",
+            _ => "Removed from waiting list",
+        };
+        appt.cancelation_reason = Some(Box::new(CodeableConcept {
+            text: Some(reason.to_string().into()),
+            ..Default::default()
+        }));
+    }
+
+    appt.service_type.push(list_concept(entry));
+    appt.description = entry.list_name.clone().map(Into::into);
+    appt.comment = entry.note.clone().map(Into::into);
+    appt.priority = entry.position.map(Into::into);
+    appt.created = on_list_since(entry, oscar_cfg)?.map(Into::into);
+
+    appt.participant.push(AppointmentParticipant {
+        actor: Some(Box::new(patient_ref(fhir_cfg, &entry.demographic_no))),
+        required: Some("required".into()),
+        status: "needs-action".into(),
+        ..Default::default()
+    });
+    if let Some(provider_no) = &entry.list_provider_no {
+        appt.participant.push(AppointmentParticipant {
+            actor: Some(Box::new(practitioner_ref(fhir_cfg, provider_no))),
+            required: Some("required".into()),
+            status: "needs-action".into(),
+            ..Default::default()
+        });
+    }
+
+    Ok(appt)
+}
+
+/// Builds the ServiceRequest. FHIR's `priority` is a coarse urgency, so
+/// the queue `position` rides along as an extension instead.
+fn build_waiting_list_request(
+    entry: &DomainWaitingListEntry,
+    fhir_cfg: &FhirConfig,
+    oscar_cfg: &OscarConfig,
+    op: Op,
+) -> Result<ServiceRequest, SyncFailure> {
+    let mut sr = ServiceRequest::default();
+
+    sr.meta = Some(Box::new(Meta {
+        source: Some(META_SOURCE.into()),
+        ..Default::default()
+    }));
+    sr.identifier.push(identifier(entry, fhir_cfg));
+
+    sr.intent = "order".into();
+    sr.status = match (&entry.state, op) {
+        (_, Op::Delete) | (WaitingListState::Removed, _) => "revoked",
+        (WaitingListState::Booked { .. }, _) => "completed",
+        (WaitingListState::Waiting, _) => "active",
+    }
+    .into();
+
+    sr.category.push(list_concept(entry));
+    sr.code = entry.list_name.clone().map(|name| {
+        Box::new(CodeableConcept {
+            text: Some(name.into()),
+            ..Default::default()
+        })
+    });
+    sr.subject = Box::new(patient_ref(fhir_cfg, &entry.demographic_no));
+    if let Some(provider_no) = &entry.list_provider_no {
+        sr.performer.push(practitioner_ref(fhir_cfg, provider_no));
+    }
+    sr.authored_on = on_list_since(entry, oscar_cfg)?.map(Into::into);
+
+    if let Some(note) = &entry.note {
+        sr.note.push(Annotation {
+            author: Some(AnnotationAuthor::String("Oscar waiting list".to_string().into())),
+            text: note.clone().into(),
+            ..Default::default()
+        });
+    }
+
+    if let Some(position) = entry.position {
+        sr.extension.push(Extension {
+            url: OSCAR_WAITING_LIST_POSITION_URL.to_string(),
+            value: Some(ExtensionValue::PositiveInt(position.into())),
+            ..Default::default()
+        });
+    }
+
+    Ok(sr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oscar_cfg() -> OscarConfig {
+        OscarConfig {
+            timezone: Some("America/Vancouver".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn entry(state: WaitingListState) -> DomainWaitingListEntry {
+        DomainWaitingListEntry {
+            entry_id: "5".to_string(),
+            list_id: "2".to_string(),
+            list_name: Some("Physio".to_string()),
+            list_provider_no: Some("1001".to_string()),
+            demographic_no: "123".to_string(),
+            position: Some(3),
+            note: Some("Prefers mornings".to_string()),
+            on_list_since: Some("2026-01-10 09:00:00".to_string()),
+            state,
+        }
+    }
+
+    #[test]
+    fn waiting_entry_is_waitlist_appointment() {
+        let appt = build_waiting_list_appointment(
+            &entry(WaitingListState::Waiting),
+            &FhirConfig::default(),
+            &oscar_cfg(),
+            Op::Upsert,
+        )
+        .unwrap();
+        assert_eq!(appt.status.value.as_deref(), Some("waitlist"));
+        assert_eq!(appt.priority.as_ref().and_then(|p| p.value), Some(3));
+        assert_eq!(appt.description.as_ref().and_then(|d| d.value.as_deref()), Some("Physio"));
+        assert_eq!(appt.comment.as_ref().and_then(|c| c.value.as_deref()), Some("Prefers mornings"));
+        assert_eq!(appt.created.as_ref().and_then(|c| c.value.as_deref()), Some("2026-01-10T09:00:00-08:00"));
+        assert_eq!(appt.participant.len(), 2);
+        assert_eq!(
+            appt.service_type[0].coding[0].code.as_ref().and_then(|c| c.value.as_deref()),
+            Some("2")
+        );
+    }
+
+    #[test]
+    fn ended_entry_cancels_appointment() {
+        let appt = build_waiting_list_appointment(
+            &entry(WaitingListState::Booked { appointment_no: "88".to_string() }),
+            &FhirConfig::default(),
+            &oscar_cfg(),
+            Op::Upsert,
+        )
+        .unwrap();
+        assert_eq!(appt.status.value.as_deref(), Some("cancelled"));
+        assert!(appt.cancelation_reason.is_some());
+    }
+
+    #[test]
+    fn service_request_status_follows_state() {
+        let cases = [
+            (WaitingListState::Waiting, "active"),
+            (WaitingListState::Booked { appointment_no: "88".to_string() }, "completed"),
+            (WaitingListState::Removed, "revoked"),
+        ];
+        for (state, expected) in cases {
+            let sr = build_waiting_list_request(&entry(state), &FhirConfig::default(), &oscar_cfg(), Op::Upsert)
+                .unwrap();
+            assert_eq!(sr.status.value.as_deref(), Some(expected));
+        }
+        let sr = build_waiting_list_request(
+            &entry(WaitingListState::Waiting),
+            &FhirConfig::default(),
+            &oscar_cfg(),
+            Op::Upsert,
+        )
+        .unwrap();
+        assert_eq!(sr.note.len(), 1);
+        assert_eq!(sr.extension.len(), 1);
+    }
+}
@@ -21,77 +21,142 @@ use mysql_binlog_connector_rust::{
     column::column_value::ColumnValue,
     event::event_data::EventData,
 };
-use tokio::sync::mpsc::Sender;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::checkpoint::{self, Checkpoint};
-use crate::config::{Config, DatabaseConfig};
+use crate::config::{Config, DatabaseConfig, OscarConfig};
+use crate::domain::claim::BillingSource;
 use crate::domain::resource::DomainResource;
 use crate::event::{Op, Source as EventSource, SyncEvent};
+use crate::mapping::admission::row_to_domain_episode_of_care;
 use crate::mapping::appointment::row_to_domain_appointment;
-use crate::mapping::care_team::row_to_domain_care_team;
+use crate::mapping::billing::{claim_resources, load_claim_details, reload_claim, row_to_domain_claim};
+use crate::mapping::care_team::{
+    is_care_team_ext_key, load_care_team_roles, reload_care_team, row_to_domain_care_team,
+};
 use crate::mapping::casemgmt_note::row_to_casemgmt_note_resources;
-use crate::mapping::demographic::{row_to_domain_patient, row_to_merged_patient, ColumnMap};
+use crate::mapping::client_image::load_patient_photo;
+use crate::mapping::clinic::row_to_clinic_organization;
+use crate::mapping::column_override::{column_override_values, load_column_override_values};
+use crate::mapping::connection_url;
+use crate::mapping::demographic::{lookup, row_to_domain_patient, row_to_merged_patient, ColumnMap};
+use crate::mapping::demographic_contact::{
+    load_patient_contacts, reload_patient, row_to_domain_related_person,
+};
 use crate::mapping::consultation_request::row_to_domain_service_request;
 use crate::mapping::consultation_response::row_to_domain_diagnostic_report;
 use crate::mapping::dxresearch::row_to_domain_condition;
-use crate::mapping::provider::row_to_domain_practitioner;
+use crate::mapping::eform::{
+    load_eform_values, load_questionnaire_items, reload_questionnaire_response, row_to_domain_questionnaire,
+    row_to_domain_questionnaire_response,
+};
+use crate::mapping::message::{load_message_links, reload_communication, row_to_domain_communication};
+use crate::mapping::professional_specialist::row_to_specialist_resources;
+use crate::mapping::program::row_to_program_organization;
+use crate::mapping::site::{resolve_site_id, row_to_site_location};
+use crate::mapping::provider::{
+    load_practitioner_role_links, row_to_domain_practitioner, row_to_domain_practitioner_role,
+};
+use crate::mapping::referral_doctor::resolve_family_doctor;
+use crate::mapping::schedule::{
+    appointment_provider_day, load_provider_day, provider_days_for_template, scheduledate_provider_day, ProviderDay,
+};
+use crate::mapping::waiting_list::{
+    load_waiting_list_details, row_to_domain_waiting_list_entry, waiting_list_resources,
+};
 use crate::metrics::SharedMetrics;
+use crate::queue::EventSender;
 use crate::sources::{RowChange, RowOp, SourcePosition, TableRef};
 
 const DEMOGRAPHIC_TABLE: &str = "demographic";
 const DEMOGRAPHIC_MERGED_TABLE: &str = "demographic_merged";
+const DEMOGRAPHIC_CONTACT_TABLE: &str = "DemographicContact";
+const DEMOGRAPHIC_EXT_TABLE: &str = "demographicExt";
 const PROVIDER_TABLE: &str = "provider";
+const PROFESSIONAL_SPECIALISTS_TABLE: &str = "professionalSpecialists";
 const APPOINTMENT_TABLE: &str = "appointment";
 const CASEMGMT_NOTE_TABLE: &str = "casemgmt_note";
 const DXRESEARCH_TABLE: &str = "dxresearch";
 const CONSULTATION_RESPONSE_TABLE: &str = "consultationResponse";
 const CONSULTATION_REQUESTS_TABLE: &str = "consultationRequests";
+const SCHEDULEDATE_TABLE: &str = "scheduledate";
+const SCHEDULETEMPLATE_TABLE: &str = "scheduletemplate";
+const PROGRAM_TABLE: &str = "program";
+const ADMISSION_TABLE: &str = "admission";
+const CLINIC_TABLE: &str = "clinic";
+const SITE_TABLE: &str = "site";
+const EFORM_TABLE: &str = "eform";
+const EFORM_DATA_TABLE: &str = "eform_data";
+const EFORM_VALUES_TABLE: &str = "eform_values";
+const MESSAGE_TABLE: &str = "messagetbl";
+const MESSAGE_LIST_TABLE: &str = "messagelisttbl";
+const MSG_DEMO_MAP_TABLE: &str = "msgDemoMap";
+const BILLING_TABLE: &str = "billing";
+const BILLINGMASTER_TABLE: &str = "billingmaster";
+const BILLING_ON_CHEADER1_TABLE: &str = "billing_on_cheader1";
+const BILLING_ON_ITEM_TABLE: &str = "billing_on_item";
+const WAITING_LIST_TABLE: &str = "waitingList";
+const CLIENT_IMAGE_TABLE: &str = "client_image";
+
+/// Every table the listener resolves a column map for.
+const WATCHED_TABLES: &[&str] = &[
+    DEMOGRAPHIC_TABLE,
+    DEMOGRAPHIC_MERGED_TABLE,
+    DEMOGRAPHIC_CONTACT_TABLE,
+    DEMOGRAPHIC_EXT_TABLE,
+    PROVIDER_TABLE,
+    PROFESSIONAL_SPECIALISTS_TABLE,
+    APPOINTMENT_TABLE,
+    CASEMGMT_NOTE_TABLE,
+    DXRESEARCH_TABLE,
+    CONSULTATION_RESPONSE_TABLE,
+    CONSULTATION_REQUESTS_TABLE,
+    SCHEDULEDATE_TABLE,
+    SCHEDULETEMPLATE_TABLE,
+    PROGRAM_TABLE,
+    ADMISSION_TABLE,
+    CLINIC_TABLE,
+    SITE_TABLE,
+    EFORM_TABLE,
+    EFORM_DATA_TABLE,
+    EFORM_VALUES_TABLE,
+    MESSAGE_TABLE,
+    MESSAGE_LIST_TABLE,
+    MSG_DEMO_MAP_TABLE,
+    BILLING_TABLE,
+    BILLINGMASTER_TABLE,
+    BILLING_ON_CHEADER1_TABLE,
+    BILLING_ON_ITEM_TABLE,
+    WAITING_LIST_TABLE,
+    CLIENT_IMAGE_TABLE,
+];
+
+/// Tables every Oscar install has; a missing one is a configuration error.
+/// The rest belong to optional features and may be absent (e.g. Ontario
+/// billing tables on a BC install).
+const CORE_TABLES: &[&str] = &[
+    DEMOGRAPHIC_TABLE,
+    DEMOGRAPHIC_MERGED_TABLE,
+    PROVIDER_TABLE,
+    APPOINTMENT_TABLE,
+    CASEMGMT_NOTE_TABLE,
+    DXRESEARCH_TABLE,
+    CONSULTATION_RESPONSE_TABLE,
+    CONSULTATION_REQUESTS_TABLE,
+];
 const MAX_CONSECUTIVE_READ_ERRORS: u32 = 5;
 const READ_ERROR_BACKOFF_MS: u64 = 100;
 
 /// Runs the MariaDB binlog listener to completion (or until the sink
 /// channel closes). Intended to be spawned as a top-level tokio task.
-pub async fn run(cfg: Config, tx: Sender<SyncEvent>, metrics: SharedMetrics) -> Result<()> {
+pub async fn run(cfg: Config, tx: EventSender, metrics: SharedMetrics) -> Result<()> {
     let db = cfg.database.clone();
 
     if db.server_id == 0 {
         bail!("database.server_id must be non-zero and != Oscar's server-id (F12)");
     }
 
-    let mut column_maps = HashMap::new();
-    column_maps.insert(
-        DEMOGRAPHIC_TABLE.to_string(),
-        resolve_column_map_for_table(&db, DEMOGRAPHIC_TABLE).await?,
-    );
-    column_maps.insert(
-        DEMOGRAPHIC_MERGED_TABLE.to_string(),
-        resolve_column_map_for_table(&db, DEMOGRAPHIC_MERGED_TABLE).await?,
-    );
-    column_maps.insert(
-        PROVIDER_TABLE.to_string(),
-        resolve_column_map_for_table(&db, PROVIDER_TABLE).await?,
-    );
-    column_maps.insert(
-        APPOINTMENT_TABLE.to_string(),
-        resolve_column_map_for_table(&db, APPOINTMENT_TABLE).await?,
-    );
-    column_maps.insert(
-        CASEMGMT_NOTE_TABLE.to_string(),
-        resolve_column_map_for_table(&db, CASEMGMT_NOTE_TABLE).await?,
-    );
-    column_maps.insert(
-        DXRESEARCH_TABLE.to_string(),
-        resolve_column_map_for_table(&db, DXRESEARCH_TABLE).await?,
-    );
-    column_maps.insert(
-        CONSULTATION_RESPONSE_TABLE.to_string(),
-        resolve_column_map_for_table(&db, CONSULTATION_RESPONSE_TABLE).await?,
-    );
-    column_maps.insert(
-        CONSULTATION_REQUESTS_TABLE.to_string(),
-        resolve_column_map_for_table(&db, CONSULTATION_REQUESTS_TABLE).await?,
-    );
+    let column_maps = resolve_column_maps(&db, &cfg.oscar, WATCHED_TABLES).await?;
 
     let (mut current_filename, start_position) = resolve_start_position(&cfg).await?;
 
@@ -260,10 +325,7 @@ async fn resolve_start_position(cfg: &Config) -> Result<(String, u32)> {
 pub(crate) async fn capture_binlog_position(db: &DatabaseConfig) -> Result<(String, u32)> {
     use mysql_async::prelude::*;
 
-    let url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        db.user, db.password, db.host, db.port, db.schema
-    );
+    let url = connection_url(db);
 
     let pool = mysql_async::Pool::new(url.as_str());
     let mut conn = pool
@@ -309,7 +371,7 @@ async fn emit_row(
     table: &str,
     column_maps: &HashMap<String, ColumnMap>,
     cfg: &Config,
-    tx: &Sender<SyncEvent>,
+    tx: &EventSender,
     metrics: &SharedMetrics,
     values: &[ColumnValue],
     row_op: RowOp,
@@ -318,7 +380,8 @@ async fn emit_row(
     pos: u32,
 ) -> bool {
     let Some(columns) = column_maps.get(table) else {
-        warn!("mariadb_binlog: no column map resolved for {table}");
+        // Its feature is switched off, or the table was missing at startup.
+        debug!("mariadb_binlog: no column map resolved for {table}");
         return true;
     };
 
@@ -337,20 +400,96 @@ async fn emit_row(
 
     let resources: Vec<DomainResource> = match table {
         DEMOGRAPHIC_TABLE => {
-            let mut out: Vec<DomainResource> = row_to_domain_patient(&change, columns)
-                .into_iter()
-                .map(DomainResource::Patient)
-                .collect();
+            let mut patient = row_to_domain_patient(&change, columns);
+            let mut out: Vec<DomainResource> = Vec::new();
+            if let Some(p) = patient.as_mut() {
+                if sync_op == Op::Upsert {
+                    match resolve_family_doctor(&cfg.database, p).await {
+                        Ok(doctor) => out.extend(doctor.map(DomainResource::ReferralDoctor)),
+                        Err(e) => warn!(
+                            "mariadb_binlog: failed to resolve family doctor for demographic_no={}: {e:?}",
+                            p.demographic_no
+                        ),
+                    }
+                }
+                if cfg.oscar.related_person_enabled && sync_op == Op::Upsert {
+                    match load_patient_contacts(&cfg.database, &p.demographic_no).await {
+                        Ok(contacts) => p.contacts = contacts,
+                        Err(e) => warn!(
+                            "mariadb_binlog: failed to load contacts for demographic_no={}: {e:?}",
+                            p.demographic_no
+                        ),
+                    }
+                }
+                if cfg.oscar.patient_photo_enabled && sync_op == Op::Upsert {
+                    match load_patient_photo(&cfg.database, &cfg.oscar, &p.demographic_no).await {
+                        Ok(photo) => p.photo = photo,
+                        Err(e) => warn!(
+                            "mariadb_binlog: failed to load photo for demographic_no={}: {e:?}",
+                            p.demographic_no
+                        ),
+                    }
+                }
+            }
+            out.extend(patient.map(|patient| DomainResource::Patient(Box::new(patient))));
             if cfg.oscar.care_team_enabled {
-                if let Some(ct) = row_to_domain_care_team(&change, columns, &cfg.oscar) {
+                if let Some(mut ct) = row_to_domain_care_team(&change, columns, &cfg.oscar) {
+                    if sync_op == Op::Upsert {
+                        if let Err(e) = load_care_team_roles(&cfg.database, &mut ct).await {
+                            warn!(
+                                "mariadb_binlog: failed to load care-team roles for demographic_no={}: {e:?}",
+                                ct.demographic_no
+                            );
+                        }
+                    }
                     out.push(DomainResource::CareTeam(ct));
                 }
             }
             out
         }
-        DEMOGRAPHIC_MERGED_TABLE => row_to_merged_patient(&change, columns).into_iter().map(DomainResource::Patient).collect(),
-        PROVIDER_TABLE => row_to_domain_practitioner(&change, columns).into_iter().map(DomainResource::Practitioner).collect(),
-        APPOINTMENT_TABLE => row_to_domain_appointment(&change, columns).into_iter().map(DomainResource::Appointment).collect(),
+        DEMOGRAPHIC_CONTACT_TABLE if cfg.oscar.related_person_enabled => {
+            match row_to_domain_related_person(&change, columns, &cfg.database).await {
+                Ok(Some(related)) => vec![DomainResource::RelatedPerson(related)],
+                Ok(None) => Vec::new(),
+                Err(e) => {
+                    warn!("mariadb_binlog: failed to map DemographicContact row: {e:?}");
+                    Vec::new()
+                }
+            }
+        }
+        DEMOGRAPHIC_MERGED_TABLE => row_to_merged_patient(&change, columns).into_iter().map(|patient| DomainResource::Patient(Box::new(patient))).collect(),
+        PROVIDER_TABLE => {
+            let mut out: Vec<DomainResource> =
+                row_to_domain_practitioner(&change, columns).into_iter().map(DomainResource::Practitioner).collect();
+            if cfg.oscar.practitioner_role_enabled {
+                if let Some(mut role) = row_to_domain_practitioner_role(&change, columns) {
+                    if let Err(e) = load_practitioner_role_links(&cfg.database, &mut role).await {
+                        warn!(
+                            "mariadb_binlog: failed to load role links for provider_no={}: {e:?}",
+                            role.provider_no
+                        );
+                    }
+                    out.push(DomainResource::PractitionerRole(role));
+                }
+            }
+            out
+        }
+        PROFESSIONAL_SPECIALISTS_TABLE => row_to_specialist_resources(&change, columns),
+        APPOINTMENT_TABLE => {
+            let mut appointment = row_to_domain_appointment(&change, columns);
+            if let Some(a) = appointment.as_mut() {
+                if let Some(location) = a.location.as_deref() {
+                    match resolve_site_id(&cfg.database, location).await {
+                        Ok(site_id) => a.site_id = site_id,
+                        Err(e) => warn!(
+                            "mariadb_binlog: failed to resolve site for appointment_no={}: {e:?}",
+                            a.appointment_no
+                        ),
+                    }
+                }
+            }
+            appointment.into_iter().map(DomainResource::Appointment).collect()
+        }
         CASEMGMT_NOTE_TABLE => row_to_casemgmt_note_resources(&change, columns, None),
         DXRESEARCH_TABLE => row_to_domain_condition(&change, columns).into_iter().map(DomainResource::Condition).collect(),
         CONSULTATION_RESPONSE_TABLE => match row_to_domain_diagnostic_report(&change, columns, &cfg.database).await {
@@ -365,20 +504,126 @@ async fn emit_row(
             .into_iter()
             .map(DomainResource::ServiceRequest)
             .collect(),
+        CLINIC_TABLE => row_to_clinic_organization(&change, columns).into_iter().map(DomainResource::Organization).collect(),
+        SITE_TABLE => match row_to_site_location(&change, columns, &cfg.database).await {
+            Ok(location) => location.into_iter().map(DomainResource::Location).collect(),
+            Err(e) => {
+                warn!("mariadb_binlog: failed to map site row: {e:?}");
+                Vec::new()
+            }
+        },
+        PROGRAM_TABLE => row_to_program_organization(&change, columns).into_iter().map(DomainResource::Organization).collect(),
+        ADMISSION_TABLE => row_to_domain_episode_of_care(&change, columns).into_iter().map(DomainResource::EpisodeOfCare).collect(),
+        // Schedule rows carry no resource of their own; the affected
+        // provider-days are re-expanded by `refresh_resources` below.
+        SCHEDULEDATE_TABLE | SCHEDULETEMPLATE_TABLE => Vec::new(),
+        // Likewise, a care-team key only refreshes the patient's CareTeam.
+        DEMOGRAPHIC_EXT_TABLE => Vec::new(),
+        EFORM_TABLE if cfg.oscar.eform_enabled => {
+            let mut questionnaire = row_to_domain_questionnaire(&change, columns)
+                .filter(|q| !cfg.oscar.eform_questionnaire_map.contains_key(&q.fid));
+            if let Some(q) = questionnaire.as_mut() {
+                if let Err(e) = load_questionnaire_items(&cfg.database, q).await {
+                    warn!("mariadb_binlog: failed to load eform fields for fid={}: {e:?}", q.fid);
+                }
+            }
+            questionnaire.into_iter().map(DomainResource::Questionnaire).collect()
+        }
+        EFORM_DATA_TABLE if cfg.oscar.eform_enabled => {
+            let mut response = row_to_domain_questionnaire_response(&change, columns);
+            if let Some(r) = response.as_mut().filter(|_| sync_op == Op::Upsert) {
+                if let Err(e) = load_eform_values(&cfg.database, r).await {
+                    warn!("mariadb_binlog: failed to load eform_values for fdid={}: {e:?}", r.fdid);
+                }
+            }
+            response.into_iter().map(DomainResource::QuestionnaireResponse).collect()
+        }
+        // An eForm value only refreshes its QuestionnaireResponse.
+        EFORM_TABLE | EFORM_DATA_TABLE | EFORM_VALUES_TABLE => Vec::new(),
+        MESSAGE_TABLE if cfg.oscar.communication_enabled => {
+            let mut communication = row_to_domain_communication(&change, columns);
+            if let Some(c) = communication.as_mut() {
+                if let Err(e) = load_message_links(&cfg.database, c).await {
+                    warn!("mariadb_binlog: failed to load links for messageid={}: {e:?}", c.message_id);
+                }
+            }
+            communication
+                .filter(|c| c.is_patient_linked() || !cfg.oscar.exclude_unlinked_messages)
+                .into_iter()
+                .map(DomainResource::Communication)
+                .collect()
+        }
+        // Recipient and chart links only refresh their Communication.
+        MESSAGE_TABLE | MESSAGE_LIST_TABLE | MSG_DEMO_MAP_TABLE => Vec::new(),
+        BILLING_TABLE | BILLING_ON_CHEADER1_TABLE if cfg.oscar.billing_enabled => {
+            let source = if table == BILLING_TABLE { BillingSource::Bc } else { BillingSource::On };
+            match row_to_domain_claim(&change, columns, source) {
+                Some(mut claim) => {
+                    if let Err(e) = load_claim_details(&cfg.database, &mut claim).await {
+                        warn!("mariadb_binlog: failed to load {table} {} details: {e:?}", claim.claim_id);
+                    }
+                    claim_resources(claim, &cfg.oscar)
+                }
+                None => Vec::new(),
+            }
+        }
+        // A billing line only refreshes its claim.
+        BILLING_TABLE | BILLINGMASTER_TABLE | BILLING_ON_CHEADER1_TABLE | BILLING_ON_ITEM_TABLE => Vec::new(),
+        WAITING_LIST_TABLE if cfg.oscar.waiting_list_enabled => {
+            match row_to_domain_waiting_list_entry(&change, columns) {
+                Some(mut entry) => {
+                    if let Err(e) = load_waiting_list_details(&cfg.database, &mut entry).await {
+                        warn!(
+                            "mariadb_binlog: failed to load waiting list details for id={}: {e:?}",
+                            entry.entry_id
+                        );
+                    }
+                    waiting_list_resources(entry, &cfg.oscar)
+                }
+                None => Vec::new(),
+            }
+        }
+        WAITING_LIST_TABLE => Vec::new(),
+        // A photo only refreshes its Patient.
+        CLIENT_IMAGE_TABLE => Vec::new(),
         _ => return true,
     };
 
-    if resources.is_empty() {
+    let refreshes = refresh_resources(table, &change, &resources, column_maps, cfg).await;
+
+    if resources.is_empty() && refreshes.is_empty() {
         return true;
     }
 
-    for resource in resources {
+    let events = resources
+        .into_iter()
+        .map(|r| (sync_op, r, false))
+        .chain(refreshes.into_iter().map(|r| (Op::Upsert, r, true)));
+
+    for (op, resource, refreshed) in events {
+        let overrides = &cfg.oscar.column_overrides;
+        let column_values = if !refreshed {
+            column_override_values(overrides, &change, columns, resource.resource_type())
+        } else {
+            // The row behind a refresh isn't this one; re-read its columns.
+            load_column_override_values(&cfg.database, overrides, &resource)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "mariadb_binlog: failed to load override columns for {} {}: {e:?}",
+                        resource.source_table(),
+                        resource.source_id()
+                    );
+                    Vec::new()
+                })
+        };
         let sync_event = SyncEvent::new(
             EventSource::OscarBinlog { table: table.to_string() },
-            sync_op,
+            op,
             resource,
             chrono::Utc::now(),
-        );
+        )
+        .with_column_values(column_values);
 
         metrics.inc_received();
         if tx.send(sync_event).await.is_err() {
@@ -388,17 +633,220 @@ async fn emit_row(
     true
 }
 
+/// Resources derived from other rows that a change to `table` invalidates.
+/// Always sent as upserts: removing a contact or an appointment changes the
+/// Patient or Slot, it doesn't delete it.
+///
+/// - A `Contact`-backed link is also carried on the owning Patient as
+///   `Patient.contact`, so the Patient is re-read and re-sent.
+/// - A `client_image` change re-reads the patient's latest photo, so the
+///   Patient is re-read and re-sent.
+/// - A `demographicExt` resident/nurse/midwife entry is a CareTeam role, so
+///   the patient's CareTeam is rebuilt and re-sent.
+/// - An `eform_values` change re-reads its `eform_data` row and re-sends the
+///   QuestionnaireResponse with every current answer.
+/// - A `messagelisttbl` or `msgDemoMap` change re-reads its message and
+///   re-sends the Communication with its current recipients and charts.
+/// - A `billingmaster` or `billing_on_item` line change re-reads its billing
+///   header and re-sends the Claim (or its ChargeItems) with every line.
+/// - A `scheduledate`, `scheduletemplate` or `appointment` change re-expands
+///   every provider-day it touches into `Schedule` + `Slot`s. Only the row's
+///   after-image is known, so an appointment moved to another day frees its
+///   old slot on that day's next change or backfill.
+async fn refresh_resources(
+    table: &str,
+    change: &RowChange,
+    resources: &[DomainResource],
+    column_maps: &HashMap<String, ColumnMap>,
+    cfg: &Config,
+) -> Vec<DomainResource> {
+    let mut refreshes: Vec<DomainResource> = Vec::new();
+
+    for resource in resources {
+        let DomainResource::RelatedPerson(related) = resource else {
+            continue;
+        };
+        if related.linked_demographic_no.is_some() {
+            continue;
+        }
+        let Some(demographic_columns) = column_maps.get(DEMOGRAPHIC_TABLE) else {
+            continue;
+        };
+        match reload_patient(&cfg.database, &cfg.oscar, demographic_columns, &related.demographic_no).await {
+            Ok(Some(patient)) => refreshes.push(DomainResource::Patient(Box::new(patient))),
+            Ok(None) => {}
+            Err(e) => warn!(
+                "mariadb_binlog: failed to refresh demographic_no={} after contact change: {e:?}",
+                related.demographic_no
+            ),
+        }
+    }
+
+    if table == DEMOGRAPHIC_EXT_TABLE && cfg.oscar.care_team_enabled {
+        let ext_columns = column_maps.get(DEMOGRAPHIC_EXT_TABLE);
+        let demographic_columns = column_maps.get(DEMOGRAPHIC_TABLE);
+        if let (Some(ext_columns), Some(demographic_columns)) = (ext_columns, demographic_columns) {
+            let is_role = lookup(change, ext_columns, "key_val").is_some_and(is_care_team_ext_key);
+            if let Some(demographic_no) = lookup(change, ext_columns, "demographic_no").filter(|_| is_role) {
+                match reload_care_team(&cfg.database, demographic_columns, &cfg.oscar, demographic_no).await {
+                    Ok(Some(ct)) => refreshes.push(DomainResource::CareTeam(ct)),
+                    Ok(None) => {}
+                    Err(e) => warn!(
+                        "mariadb_binlog: failed to refresh CareTeam for demographic_no={demographic_no} after demographicExt change: {e:?}"
+                    ),
+                }
+            }
+        }
+    }
+
+    if table == CLIENT_IMAGE_TABLE && cfg.oscar.patient_photo_enabled {
+        let image_columns = column_maps.get(CLIENT_IMAGE_TABLE);
+        let demographic_columns = column_maps.get(DEMOGRAPHIC_TABLE);
+        if let (Some(image_columns), Some(demographic_columns)) = (image_columns, demographic_columns) {
+            if let Some(demographic_no) = lookup(change, image_columns, "demographic_no") {
+                match reload_patient(&cfg.database, &cfg.oscar, demographic_columns, demographic_no).await {
+                    Ok(Some(patient)) => refreshes.push(DomainResource::Patient(Box::new(patient))),
+                    Ok(None) => {}
+                    Err(e) => warn!(
+                        "mariadb_binlog: failed to refresh demographic_no={demographic_no} after client_image change: {e:?}"
+                    ),
+                }
+            }
+        }
+    }
+
+    if table == EFORM_VALUES_TABLE && cfg.oscar.eform_enabled {
+        let values_columns = column_maps.get(EFORM_VALUES_TABLE);
+        let data_columns = column_maps.get(EFORM_DATA_TABLE);
+        if let (Some(values_columns), Some(data_columns)) = (values_columns, data_columns) {
+            if let Some(fdid) = lookup(change, values_columns, "fdid") {
+                match reload_questionnaire_response(&cfg.database, data_columns, fdid).await {
+                    Ok(Some(response)) => refreshes.push(DomainResource::QuestionnaireResponse(response)),
+                    Ok(None) => {}
+                    Err(e) => warn!(
+                        "mariadb_binlog: failed to refresh QuestionnaireResponse for fdid={fdid} after eform_values change: {e:?}"
+                    ),
+                }
+            }
+        }
+    }
+
+    if cfg.oscar.communication_enabled {
+        let message_id_column = match table {
+            MESSAGE_LIST_TABLE => Some("message"),
+            MSG_DEMO_MAP_TABLE => Some("messageID"),
+            _ => None,
+        };
+        let link_columns = column_maps.get(table);
+        let message_columns = column_maps.get(MESSAGE_TABLE);
+        if let (Some(column), Some(link_columns), Some(message_columns)) =
+            (message_id_column, link_columns, message_columns)
+        {
+            if let Some(message_id) = lookup(change, link_columns, column) {
+                match reload_communication(&cfg.database, message_columns, message_id).await {
+                    Ok(Some(c)) if c.is_patient_linked() || !cfg.oscar.exclude_unlinked_messages => {
+                        refreshes.push(DomainResource::Communication(c))
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!(
+                        "mariadb_binlog: failed to refresh Communication for messageid={message_id} after {table} change: {e:?}"
+                    ),
+                }
+            }
+        }
+    }
+
+    if cfg.oscar.billing_enabled {
+        let header = match table {
+            BILLINGMASTER_TABLE => Some((BillingSource::Bc, "billing_no")),
+            BILLING_ON_ITEM_TABLE => Some((BillingSource::On, "ch1_id")),
+            _ => None,
+        };
+        if let Some((source, claim_column)) = header {
+            let line_columns = column_maps.get(table);
+            let header_columns = column_maps.get(source.header_table());
+            if let (Some(line_columns), Some(header_columns)) = (line_columns, header_columns) {
+                if let Some(claim_id) = lookup(change, line_columns, claim_column) {
+                    match reload_claim(&cfg.database, header_columns, source, claim_id).await {
+                        Ok(Some(claim)) => refreshes.extend(claim_resources(claim, &cfg.oscar)),
+                        Ok(None) => {}
+                        Err(e) => warn!(
+                            "mariadb_binlog: failed to refresh {} {claim_id} after {table} change: {e:?}",
+                            source.header_table()
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    if !cfg.oscar.schedule_enabled {
+        return refreshes;
+    }
+    let Some(columns) = column_maps.get(table) else {
+        return refreshes;
+    };
+
+    let days: Vec<ProviderDay> = match table {
+        SCHEDULEDATE_TABLE => scheduledate_provider_day(change, columns).into_iter().collect(),
+        APPOINTMENT_TABLE => appointment_provider_day(change, columns).into_iter().collect(),
+        SCHEDULETEMPLATE_TABLE => {
+            match provider_days_for_template(&cfg.database, &cfg.oscar, change, columns).await {
+                Ok(days) => days,
+                Err(e) => {
+                    warn!("mariadb_binlog: failed to find provider-days for scheduletemplate change: {e:?}");
+                    Vec::new()
+                }
+            }
+        }
+        _ => Vec::new(),
+    };
+
+    for day in days {
+        match load_provider_day(&cfg.database, &cfg.oscar, &day).await {
+            Ok(expanded) => refreshes.extend(expanded),
+            Err(e) => warn!(
+                "mariadb_binlog: failed to expand schedule for provider_no={} on {}: {e:?}",
+                day.provider_no, day.date
+            ),
+        }
+    }
+
+    refreshes
+}
+
 fn is_target_table(tables: &HashMap<u64, TableRef>, table_id: u64, schema: &str) -> Option<String> {
     tables.get(&table_id).and_then(|t| {
         if t.schema == schema
             && (t.table == DEMOGRAPHIC_TABLE
                 || t.table == DEMOGRAPHIC_MERGED_TABLE
+                || t.table == DEMOGRAPHIC_CONTACT_TABLE
+                || t.table == DEMOGRAPHIC_EXT_TABLE
                 || t.table == PROVIDER_TABLE
+                || t.table == PROFESSIONAL_SPECIALISTS_TABLE
                 || t.table == APPOINTMENT_TABLE
                 || t.table == CASEMGMT_NOTE_TABLE
                 || t.table == DXRESEARCH_TABLE
                 || t.table == CONSULTATION_RESPONSE_TABLE
-                || t.table == CONSULTATION_REQUESTS_TABLE)
+                || t.table == CONSULTATION_REQUESTS_TABLE
+                || t.table == SCHEDULEDATE_TABLE
+                || t.table == SCHEDULETEMPLATE_TABLE
+                || t.table == PROGRAM_TABLE
+                || t.table == ADMISSION_TABLE
+                || t.table == CLINIC_TABLE
+                || t.table == SITE_TABLE
+                || t.table == EFORM_TABLE
+                || t.table == EFORM_DATA_TABLE
+                || t.table == EFORM_VALUES_TABLE
+                || t.table == MESSAGE_TABLE
+                || t.table == MESSAGE_LIST_TABLE
+                || t.table == MSG_DEMO_MAP_TABLE
+                || t.table == BILLING_TABLE
+                || t.table == BILLINGMASTER_TABLE
+                || t.table == BILLING_ON_CHEADER1_TABLE
+                || t.table == BILLING_ON_ITEM_TABLE
+                || t.table == WAITING_LIST_TABLE
+                || t.table == CLIENT_IMAGE_TABLE)
         {
             Some(t.table.clone())
         } else {
@@ -446,24 +894,77 @@ fn normalize_time_string(s: &str) -> String {
     s.to_string()
 }
 
-/// Resolves a single Oscar table's column name -> ordinal index.
-/// Backwards-compatible alias for the original single-table call.
-pub(crate) async fn resolve_column_map(db: &DatabaseConfig) -> Result<ColumnMap> {
-    resolve_column_map_for_table(db, DEMOGRAPHIC_TABLE).await
-}
-
 /// Resolves `table` column name -> ordinal index via `information_schema.columns` (D3).
 /// Self-healing across Oscar schema variants; never hand-maintain a column list.
 pub(crate) async fn resolve_column_map_for_table(
     db: &DatabaseConfig,
     table: &str,
 ) -> Result<ColumnMap> {
+    match query_column_map(db, table).await? {
+        Some(map) => Ok(map),
+        None => bail!(
+            "no columns resolved for {}.{} — check schema name and privileges",
+            db.schema,
+            table
+        ),
+    }
+}
+
+/// Whether `table` is read under `oscar`'s feature switches.
+pub(crate) fn table_enabled(oscar: &OscarConfig, table: &str) -> bool {
+    match table {
+        DEMOGRAPHIC_CONTACT_TABLE => oscar.related_person_enabled,
+        DEMOGRAPHIC_EXT_TABLE => oscar.care_team_enabled,
+        SCHEDULEDATE_TABLE | SCHEDULETEMPLATE_TABLE => oscar.schedule_enabled,
+        EFORM_TABLE | EFORM_DATA_TABLE | EFORM_VALUES_TABLE => oscar.eform_enabled,
+        MESSAGE_TABLE | MESSAGE_LIST_TABLE | MSG_DEMO_MAP_TABLE => oscar.communication_enabled,
+        BILLING_TABLE | BILLINGMASTER_TABLE | BILLING_ON_CHEADER1_TABLE | BILLING_ON_ITEM_TABLE => {
+            oscar.billing_enabled
+        }
+        WAITING_LIST_TABLE => oscar.waiting_list_enabled,
+        CLIENT_IMAGE_TABLE => oscar.patient_photo_enabled,
+        _ => true,
+    }
+}
+
+/// Resolves the column maps of the enabled tables among `tables`. A missing
+/// core table is an error; a missing optional table leaves its feature
+/// unavailable, with a warning, and has no entry in the map.
+pub(crate) async fn resolve_column_maps(
+    db: &DatabaseConfig,
+    oscar: &OscarConfig,
+    tables: &[&str],
+) -> Result<HashMap<String, ColumnMap>> {
+    let mut column_maps = HashMap::new();
+    for &table in tables {
+        if !table_enabled(oscar, table) {
+            continue;
+        }
+        let map = if CORE_TABLES.contains(&table) {
+            resolve_column_map_for_table(db, table).await?
+        } else {
+            match query_column_map(db, table).await? {
+                Some(map) => map,
+                None => {
+                    warn!(
+                        "mariadb_binlog: {}.{table} not found; the feature that reads it is unavailable",
+                        db.schema
+                    );
+                    continue;
+                }
+            }
+        };
+        column_maps.insert(table.to_string(), map);
+    }
+    Ok(column_maps)
+}
+
+/// `table`'s column map, or `None` when it has no columns (the table doesn't
+/// exist, or isn't visible to us).
+async fn query_column_map(db: &DatabaseConfig, table: &str) -> Result<Option<ColumnMap>> {
     use mysql_async::prelude::*;
 
-    let url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        db.user, db.password, db.host, db.port, db.schema
-    );
+    let url = connection_url(db);
 
     let pool = mysql_async::Pool::new(url.as_str());
     let mut conn = pool
@@ -485,11 +986,7 @@ pub(crate) async fn resolve_column_map_for_table(
     let _ = pool.disconnect().await;
 
     if rows.is_empty() {
-        bail!(
-            "no columns resolved for {}.{} — check schema name and privileges",
-            db.schema,
-            table
-        );
+        return Ok(None);
     }
 
     let map: ColumnMap = rows
@@ -504,13 +1001,26 @@ pub(crate) async fn resolve_column_map_for_table(
         table
     );
 
-    Ok(map)
+    Ok(Some(map))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn optional_tables_follow_their_feature_switch() {
+        let mut oscar = OscarConfig::default();
+        assert!(WATCHED_TABLES.iter().all(|t| table_enabled(&oscar, t)));
+
+        oscar.billing_enabled = false;
+        oscar.eform_enabled = false;
+        assert!(!table_enabled(&oscar, BILLING_ON_ITEM_TABLE));
+        assert!(!table_enabled(&oscar, EFORM_VALUES_TABLE));
+        assert!(table_enabled(&oscar, MESSAGE_TABLE));
+        assert!(CORE_TABLES.iter().all(|t| table_enabled(&oscar, t)));
+    }
+
     #[test]
     fn is_target_table_matches_schema_and_table() {
         let mut tables = HashMap::new();
@@ -0,0 +1,305 @@
+//! Local terminology: code translation tables loaded from disk at startup.
+//!
+//! Each configured table maps codes in one Oscar `dxresearch.coding_system`
+//! (e.g. `icd9`) to codes in other systems (ICD-10-CA, SNOMED CT). The sink
+//! adds the translations to `Condition.code.coding` next to the source code,
+//! and dead-letters codes the reference data doesn't know.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use fhirbolt::model::r4b::resources::ConceptMap;
+use tracing::info;
+
+use crate::config::{TerminologyConfig, TranslationTableConfig};
+
+static TERMINOLOGY: OnceLock<Terminology> = OnceLock::new();
+
+/// One target code for a source code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatedCoding {
+    pub system: String,
+    pub code: String,
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct Terminology {
+    /// Oscar coding system -> source code key -> translations.
+    tables: HashMap<String, HashMap<String, Vec<TranslatedCoding>>>,
+    validate_source_codes: bool,
+}
+
+/// Loads every configured table into the process-wide terminology used by
+/// the sink. Fatal on an unreadable or malformed file.
+pub fn load_terminology(cfg: &TerminologyConfig) -> Result<()> {
+    let terminology = Terminology::load(cfg)?;
+    let _ = TERMINOLOGY.set(terminology);
+    Ok(())
+}
+
+/// The loaded terminology; `None` before `load_terminology` has run.
+pub fn terminology() -> Option<&'static Terminology> {
+    TERMINOLOGY.get()
+}
+
+impl Terminology {
+    pub fn load(cfg: &TerminologyConfig) -> Result<Self> {
+        let mut terminology = Terminology {
+            tables: HashMap::new(),
+            validate_source_codes: cfg.validate_source_codes,
+        };
+
+        for table in &cfg.translations {
+            let text = std::fs::read_to_string(&table.path)
+                .with_context(|| format!("reading translation table {}", table.path))?;
+            let rows = if table.path.to_ascii_lowercase().ends_with(".json") {
+                parse_concept_map(&text)
+            } else {
+                parse_csv(&text, table)
+            }
+            .with_context(|| format!("parsing translation table {}", table.path))?;
+
+            info!(
+                "terminology: loaded {} translations for {} from {}",
+                rows.len(),
+                table.source,
+                table.path
+            );
+            let entries = terminology.tables.entry(table.source.clone()).or_default();
+            for (source_code, target) in rows {
+                let targets = entries.entry(code_key(&source_code)).or_default();
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+        }
+
+        Ok(terminology)
+    }
+
+    /// Translations for `code` in the Oscar `coding_system`. Codes match with
+    /// or without the ICD decimal point.
+    pub fn translate(&self, coding_system: &str, code: &str) -> &[TranslatedCoding] {
+        self.tables
+            .get(coding_system)
+            .and_then(|t| t.get(&code_key(code)))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// `Some(false)` when `code` is missing from all reference data for
+    /// `coding_system` (the `diagnosticcode` cache for ICD-9, and the source
+    /// side of any loaded table) and validation is on. `None` when there is
+    /// nothing to check against.
+    pub fn source_code_exists(&self, coding_system: &str, code: &str) -> Option<bool> {
+        if !self.validate_source_codes {
+            return None;
+        }
+        let in_table = self.tables.get(coding_system).map(|t| t.contains_key(&code_key(code)));
+        let in_diagnostic_codes = (coding_system == "icd9")
+            .then(|| crate::mapping::dxresearch::is_known_diagnostic_code(code))
+            .flatten();
+        match (in_table, in_diagnostic_codes) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(false) || b.unwrap_or(false)),
+        }
+    }
+}
+
+/// Oscar stores ICD-9 codes undotted; tables commonly carry the dot.
+fn code_key(code: &str) -> String {
+    code.trim().chars().filter(|c| *c != '.').collect::<String>().to_ascii_uppercase()
+}
+
+/// `unmatched` / `disjoint` targets record that there is no translation.
+fn is_usable_equivalence(equivalence: Option<&str>) -> bool {
+    !matches!(equivalence, Some("unmatched" | "disjoint"))
+}
+
+/// Reads `source_code`, `target_code` and the optional `target_system`,
+/// `target_display` and `equivalence` columns, by header name.
+fn parse_csv(text: &str, table: &TranslationTableConfig) -> Result<Vec<(String, TranslatedCoding)>> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let header = split_csv_line(lines.next().context("empty translation table")?);
+    let column = |name: &str| header.iter().position(|h| h.trim().eq_ignore_ascii_case(name));
+
+    let source_col = column("source_code").context("missing source_code column")?;
+    let target_col = column("target_code").context("missing target_code column")?;
+    let system_col = column("target_system");
+    let display_col = column("target_display");
+    let equivalence_col = column("equivalence");
+    if system_col.is_none() && table.target_system.is_none() {
+        anyhow::bail!("no target_system column and no target_system configured");
+    }
+
+    let mut rows = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let fields = split_csv_line(line);
+        let field = |col: Option<usize>| {
+            col.and_then(|c| fields.get(c))
+                .map(|f| f.trim())
+                .filter(|f| !f.is_empty())
+                .map(str::to_string)
+        };
+        let (Some(source), Some(target)) = (field(Some(source_col)), field(Some(target_col))) else {
+            anyhow::bail!("row {} is missing a source or target code", i + 2);
+        };
+        if !is_usable_equivalence(field(equivalence_col).as_deref()) {
+            continue;
+        }
+        let Some(system) = field(system_col).or_else(|| table.target_system.clone()) else {
+            anyhow::bail!("row {} has no target_system", i + 2);
+        };
+        rows.push((
+            source,
+            TranslatedCoding {
+                system,
+                code: target,
+                display: field(display_col),
+            },
+        ));
+    }
+    Ok(rows)
+}
+
+/// Splits one CSV line, honouring double-quoted fields and `""` escapes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+/// Reads every `group.element.target` of a FHIR `ConceptMap`. Each group
+/// names its own target system.
+fn parse_concept_map(text: &str) -> Result<Vec<(String, TranslatedCoding)>> {
+    let concept_map: ConceptMap = fhirbolt::json::from_str(text, None).context("decoding ConceptMap")?;
+
+    let mut rows = Vec::new();
+    for group in &concept_map.group {
+        let Some(system) = group.target.as_ref().and_then(|t| t.value.clone()) else {
+            anyhow::bail!("ConceptMap group has no target system");
+        };
+        for element in &group.element {
+            let Some(source) = element.code.as_ref().and_then(|c| c.value.clone()) else {
+                continue;
+            };
+            for target in &element.target {
+                if !is_usable_equivalence(target.equivalence.value.as_deref()) {
+                    continue;
+                }
+                let Some(code) = target.code.as_ref().and_then(|c| c.value.clone()) else {
+                    continue;
+                };
+                rows.push((
+                    source.clone(),
+                    TranslatedCoding {
+                        system: system.clone(),
+                        code,
+                        display: target.display.as_ref().and_then(|d| d.value.clone()),
+                    },
+                ));
+            }
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICD10CA: &str = "https://fhir.infoway-inforoute.ca/CodeSystem/icd10ca";
+
+    fn table() -> TranslationTableConfig {
+        TranslationTableConfig {
+            path: "icd9.csv".to_string(),
+            source: "icd9".to_string(),
+            target_system: Some(ICD10CA.to_string()),
+        }
+    }
+
+    fn terminology(rows: Vec<(String, TranslatedCoding)>) -> Terminology {
+        let mut codes: HashMap<String, Vec<TranslatedCoding>> = HashMap::new();
+        for (source, target) in rows {
+            codes.entry(code_key(&source)).or_default().push(target);
+        }
+        Terminology {
+            tables: HashMap::from([("icd9".to_string(), codes)]),
+            validate_source_codes: true,
+        }
+    }
+
+    #[test]
+    fn parses_csv_table() {
+        let csv = "source_code,target_code,target_display,equivalence\n\
+                   250.00,E11.9,\"Type 2 diabetes, without complication\",equivalent\n\
+                   401,I10,Essential hypertension,\n\
+                   799,,,\n";
+        assert!(parse_csv(csv, &table()).is_err());
+
+        let rows = parse_csv(&csv.replace("799,,,\n", "799,R69,,unmatched\n"), &table()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, "250.00");
+        assert_eq!(rows[0].1.system, ICD10CA);
+        assert_eq!(rows[0].1.display.as_deref(), Some("Type 2 diabetes, without complication"));
+
+        let t = terminology(rows);
+        assert_eq!(t.translate("icd9", "25000")[0].code, "E11.9");
+        assert_eq!(t.translate("icd9", "401")[0].code, "I10");
+        assert!(t.translate("icd9", "799").is_empty());
+        assert!(t.translate("ichppccode", "401").is_empty());
+    }
+
+    #[test]
+    fn parses_concept_map() {
+        let json = r#"{
+            "resourceType": "ConceptMap",
+            "status": "active",
+            "group": [{
+                "source": "http://hl7.org/fhir/sid/icd-9-cm",
+                "target": "http://snomed.info/sct",
+                "element": [
+                    {"code": "401", "target": [{"code": "38341003", "display": "Hypertension", "equivalence": "equivalent"}]},
+                    {"code": "799", "target": [{"equivalence": "unmatched"}]}
+                ]
+            }]
+        }"#;
+        let rows = parse_concept_map(json).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].0, "401");
+        assert_eq!(rows[0].1.system, "http://snomed.info/sct");
+        assert_eq!(rows[0].1.code, "38341003");
+    }
+
+    #[test]
+    fn source_code_exists_checks_tables() {
+        let t = terminology(parse_csv("source_code,target_code\n401,I10\n", &table()).unwrap());
+        assert_eq!(t.source_code_exists("icd9", "401"), Some(true));
+        assert_eq!(t.source_code_exists("icd9", "999"), Some(false));
+        // No reference data for this system.
+        assert_eq!(t.source_code_exists("ichppccode", "999"), None);
+
+        let t = Terminology {
+            validate_source_codes: false,
+            ..t
+        };
+        assert_eq!(t.source_code_exists("icd9", "999"), None);
+    }
+}
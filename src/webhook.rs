@@ -1,7 +1,8 @@
 use axum::{Json, Router, routing::post};
-use crate::domain::patient::{AddressKind, AddressUse, DomainAddress, DomainPatient};
+use crate::domain::patient::DomainPatient;
 use crate::domain::resource::DomainResource;
 use crate::event::{Op, Source, SyncEvent};
+use crate::queue::EventSender;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::extract::Extension;
@@ -9,20 +10,20 @@ use std::net::SocketAddr;
 
 #[axum::debug_handler]
 pub async fn handle_upsert(
-    Extension(tx): Extension<tokio::sync::mpsc::Sender<SyncEvent>>,
+    Extension(tx): Extension<EventSender>,
     Json(dto): Json<DomainPatient>,
 ) -> impl IntoResponse + Send {
     handle_upsert_internal(tx, dto).await
 }
 
 async fn handle_upsert_internal(
-    tx: tokio::sync::mpsc::Sender<SyncEvent>,
+    tx: EventSender,
     patient: DomainPatient,
 ) -> StatusCode {
     let event = SyncEvent::new(
         Source::Webhook,
         Op::Upsert,
-        DomainResource::Patient(patient),
+        DomainResource::Patient(Box::new(patient)),
         chrono::Utc::now(),
     );
 
@@ -32,7 +33,7 @@ async fn handle_upsert_internal(
     }
 }
 
-pub async fn run_webhook_server(tx: tokio::sync::mpsc::Sender<SyncEvent>, port: u16) -> anyhow::Result<()> {
+pub async fn run_webhook_server(tx: EventSender, port: u16) -> anyhow::Result<()> {
     let app = Router::new()
         .route("/patient", post(handle_upsert))
         .layer(Extension(tx));
@@ -49,6 +50,7 @@ pub async fn run_webhook_server(tx: tokio::sync::mpsc::Sender<SyncEvent>, port:
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::patient::{AddressKind, AddressUse, DomainAddress};
 
     // Helper function to create a test patient
     fn create_test_patient() -> DomainPatient {
@@ -65,12 +67,11 @@ mod tests {
                 use_: AddressUse::Home,
                 kind: AddressKind::Postal,
             }],
-            patient_status: None,
-            merged_to: None,
             sex: Some("male".to_string()),
             phone: Some("+1-555-123-4567".to_string()),
             email: Some("john.doe@example.com".to_string()),
             hin: Some("1234567890".to_string()),
+            ..Default::default()
         }
     }
 
@@ -81,7 +82,7 @@ mod tests {
         let test_patient = create_test_patient();
         
         // Call the function directly (simulating what the HTTP handler does)
-        let result = handle_upsert_internal(tx, test_patient).await;
+        let result = handle_upsert_internal(tx.into(), test_patient).await;
         
         // Verify the function returns success
         assert_eq!(result, StatusCode::OK);
@@ -97,19 +98,10 @@ mod tests {
         let (tx, _rx) = tokio::sync::mpsc::channel::<SyncEvent>(1);
         let minimal_patient = DomainPatient {
             demographic_no: "67890".to_string(),
-            first_name: None,
-            last_name: None,
-            date_of_birth: None,
-            addresses: Vec::new(),
-            patient_status: None,
-            merged_to: None,
-            sex: None,
-            phone: None,
-            email: None,
-            hin: None,
+            ..Default::default()
         };
         
-        let result = handle_upsert_internal(tx, minimal_patient).await;
+        let result = handle_upsert_internal(tx.into(), minimal_patient).await;
         assert_eq!(result, StatusCode::OK);
     }
 
@@ -134,16 +126,7 @@ mod tests {
     fn test_domain_patient_with_optional_fields() {
         let patient = DomainPatient {
             demographic_no: "67890".to_string(),
-            first_name: None,
-            last_name: None,
-            date_of_birth: None,
-            addresses: Vec::new(),
-            patient_status: None,
-            merged_to: None,
-            sex: None,
-            phone: None,
-            email: None,
-            hin: None,
+            ..Default::default()
         };
         
         let json = serde_json::to_string(&patient).unwrap();
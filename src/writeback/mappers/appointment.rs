@@ -17,7 +17,6 @@ pub struct AppointmentRow {
     pub status: Option<String>,
     pub reason: Option<String>,
     pub booking_source: Option<String>,
-    pub lastupdateuser: Option<String>,
 }
 
 /// Maps a FHIR `Appointment` resource to an Oscar `appointment` row.
@@ -19,7 +19,6 @@ pub enum MappingError {
     UnmappedGender(String),
     UnmappedAppointmentStatus(String),
     NonexistentLocalTime(String),
-    AmbiguousLocalTime(String),
     MissingField(String),
     InvalidValue { field: String, value: String },
     PlaceholderPatient,
@@ -38,7 +37,6 @@ impl fmt::Display for MappingError {
                 write!(f, "unmapped appointment status: {v}")
             }
             MappingError::NonexistentLocalTime(v) => write!(f, "nonexistent local time: {v}"),
-            MappingError::AmbiguousLocalTime(v) => write!(f, "ambiguous local time: {v}"),
             MappingError::MissingField(v) => write!(f, "missing required field: {v}"),
             MappingError::InvalidValue { field, value } => {
                 write!(f, "invalid value for {field}: {value}")
@@ -7,12 +7,10 @@ use super::MappingError;
 
 #[derive(Debug, Default)]
 pub struct NoteRow {
-    pub uuid: Option<String>,
     pub demographic_no: Option<String>,
     pub provider_no: Option<String>,
     pub signing_provider_no: Option<String>,
     pub observation_date: Option<String>,
-    pub update_date: Option<String>,
     pub encounter_type: Option<String>,
     pub note: Option<String>,
     pub signed: bool,
@@ -27,9 +25,9 @@ pub struct NoteRow {
 /// `oscar-note-document` identifier means a brand-new note; a present value
 /// means a new revision of that existing note.
 ///
-/// `observation_date` comes from `DocumentReference.date`.  `update_date` is
-/// intentionally left empty; the sink fills it with the current time when it
-/// appends the new `casemgmt_note` row.
+/// `observation_date` comes from `DocumentReference.date`.  The row carries
+/// no `update_date`; the sink stamps the current time when it appends the
+/// new `casemgmt_note` row.
 pub fn fhir_document_reference_to_row(
     doc_ref: &Value,
     oscar_note_document_system: &str,
@@ -183,7 +181,6 @@ mod tests {
         assert!(!row.archived);
         assert_eq!(row.observation_date, Some("2026-08-06 09:20:00".to_string()));
         assert_eq!(row.appointment_no, Some("5".to_string()));
-        assert!(row.update_date.is_none());
     }
 
     #[test]
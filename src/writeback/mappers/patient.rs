@@ -62,10 +62,21 @@ pub fn fhir_patient_to_row(
         .and_then(Value::as_array)
         .map(|a| a.to_vec())
         .unwrap_or_default();
+    let is_use = |n: &Value, u: &str| n.get("use").and_then(Value::as_str) == Some(u);
     let name = names
         .iter()
-        .find(|n| n.get("family").is_some())
+        .find(|n| n.get("family").is_some() && !is_use(n, "usual") && !is_use(n, "nickname"))
+        .or_else(|| names.iter().find(|n| n.get("family").is_some()))
         .or_else(|| names.first());
+    // A `usual` name's first given is Oscar's preferred name.
+    let usual_given = names
+        .iter()
+        .find(|n| is_use(n, "usual"))
+        .and_then(|n| n.get("given"))
+        .and_then(Value::as_array)
+        .and_then(|g| g.first())
+        .and_then(Value::as_str)
+        .map(String::from);
     if let Some(name) = name {
         let given: Vec<String> = name
             .get("given")
@@ -84,10 +95,11 @@ pub fn fhir_patient_to_row(
             .get("family")
             .and_then(Value::as_str)
             .map(String::from);
-        row.pref_name = name
-            .get("text")
-            .and_then(Value::as_str)
-            .map(String::from);
+        row.pref_name = usual_given.or_else(|| {
+            name.get("text")
+                .and_then(Value::as_str)
+                .map(String::from)
+        });
         let prefixes: Vec<String> = name
             .get("prefix")
             .and_then(Value::as_array)
@@ -254,6 +266,18 @@ mod tests {
         assert_eq!(row.patient_status, Some("AC".to_string()));
     }
 
+    #[test]
+    fn usual_name_supplies_pref_name_without_displacing_official() {
+        let mut p = patient("1990-03-15", "female");
+        p["name"] = serde_json::json!([
+            { "use": "usual", "family": "Smith", "given": ["Janie"] },
+            { "use": "official", "family": "Smith", "given": ["Jane"] }
+        ]);
+        let (_, row) = fhir_patient_to_row(&p, "https://arsmedicatech.com/fhir/sid/oscar-demographic").unwrap();
+        assert_eq!(row.first_name, Some("Jane".to_string()));
+        assert_eq!(row.pref_name, Some("Janie".to_string()));
+    }
+
     #[test]
     fn inactive_patient_maps_to_in_status() {
         let mut p = patient("1990-03-15", "female");
@@ -123,17 +123,15 @@ fn code_to_service_and_reason(
     let reason = text.clone().or_else(|| first_display.clone());
 
     let mut service_id = None;
-    for candidate in [&text, &first_display] {
-        if let Some(c) = candidate {
-            if let Some(id) = consult_service_map.get(&c.to_lowercase()) {
-                service_id = Some(id.clone());
-                break;
-            }
+    for c in [&text, &first_display].into_iter().flatten() {
+        if let Some(id) = consult_service_map.get(&c.to_lowercase()) {
+            service_id = Some(id.clone());
+            break;
         }
     }
 
     let service_id = service_id.unwrap_or_else(|| {
-        let looked_at = text.as_deref().or_else(|| first_display.as_deref()).unwrap_or("?");
+        let looked_at = text.as_deref().or(first_display.as_deref()).unwrap_or("?");
         warn!("unmapped consultation service {looked_at:?}; using SEE NOTES (57)");
         "57".to_string()
     });
@@ -10,7 +10,6 @@
 //! run while the Oscar row is still uncommitted.
 
 use anyhow::{Context, Result};
-use chrono::TimeZone;
 use chrono_tz::Tz;
 use mysql_async::{prelude::*, Conn, Params, Value};
 use tracing::{info, instrument};
@@ -176,7 +175,7 @@ impl OscarTx {
             cols.push("lastUpdateUser".to_string());
             params.push(Value::Bytes(self.sentinel.as_bytes().to_vec()));
 
-            let placeholders = std::iter::repeat("?").take(cols.len()).collect::<Vec<_>>();
+            let placeholders = std::iter::repeat_n("?", cols.len()).collect::<Vec<_>>();
             let sql = format!(
                 "INSERT INTO demographic ({}) VALUES ({})",
                 cols.join(", "),
@@ -272,7 +271,7 @@ impl OscarTx {
             cols.push("lastupdateuser".to_string());
             params.push(Value::Bytes(self.sentinel.as_bytes().to_vec()));
 
-            let placeholders = std::iter::repeat("?").take(cols.len()).collect::<Vec<_>>();
+            let placeholders = std::iter::repeat_n("?", cols.len()).collect::<Vec<_>>();
             let sql = format!(
                 "INSERT INTO appointment ({}) VALUES ({})",
                 cols.join(", "),
@@ -353,7 +352,7 @@ impl OscarTx {
             params.push(Value::Bytes(a.as_bytes().to_vec()));
         }
 
-        let placeholders = std::iter::repeat("?").take(cols.len()).collect::<Vec<_>>();
+        let placeholders = std::iter::repeat_n("?", cols.len()).collect::<Vec<_>>();
         let sql = format!(
             "INSERT INTO casemgmt_note ({}) VALUES ({})",
             cols.join(", "),
@@ -465,7 +464,7 @@ impl OscarTx {
             cols.push("lastUpdateDate".to_string());
             params.push(Value::Bytes(now.as_bytes().to_vec()));
 
-            let placeholders = std::iter::repeat("?").take(cols.len()).collect::<Vec<_>>();
+            let placeholders = std::iter::repeat_n("?", cols.len()).collect::<Vec<_>>();
             let sql = format!(
                 "INSERT INTO consultationRequests ({}) VALUES ({})",
                 cols.join(", "),